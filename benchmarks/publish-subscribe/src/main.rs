@@ -11,39 +11,126 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use core::mem::MaybeUninit;
+use core::sync::atomic::Ordering;
 
 use clap::Parser;
+use iceoryx2::port::subscriber::Subscriber;
+use iceoryx2::port::update_connections::UpdateConnections;
 use iceoryx2::prelude::*;
+use iceoryx2::sample::Sample;
 use iceoryx2_bb_log::set_log_level;
 use iceoryx2_bb_posix::barrier::*;
 use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_bb_posix::thread::ThreadBuilder;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 
 const ITERATIONS: u64 = 10000000;
 
+fn receive<T: Service>(subscriber: &Subscriber<T, [u8], ()>) -> Sample<T, [u8], ()> {
+    loop {
+        if let Some(sample) = subscriber.receive().unwrap() {
+            return sample;
+        }
+    }
+}
+
+fn consume<T: Service>(sample: &Sample<T, [u8], ()>, args: &Args) {
+    if args.prefetch {
+        sample.prefetch();
+    }
+
+    if args.read_payload {
+        core::hint::black_box(
+            sample
+                .payload()
+                .iter()
+                .fold(0u8, |sum, value| sum.wrapping_add(*value)),
+        );
+    }
+}
+
+fn perform_single_threaded_benchmark<T: Service>(
+    args: &Args,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let node = NodeBuilder::new().create::<T>()?;
+    let service = node
+        .service_builder(&ServiceName::new("single_threaded")?)
+        .publish_subscribe::<[u8]>()
+        .history_size(0)
+        .subscriber_max_buffer_size(1)
+        .enable_safe_overflow(true)
+        .create()?;
+
+    let publisher = service
+        .publisher_builder()
+        .initial_max_slice_len(args.payload_size)
+        .create()?;
+    let subscriber = service.subscriber_builder().create()?;
+
+    let start = Time::now().expect("failed to acquire time");
+    for _ in 0..args.iterations {
+        let sample = unsafe {
+            publisher
+                .loan_slice_uninit(args.payload_size)?
+                .assume_init()
+        };
+        sample.send()?;
+        consume(&receive(&subscriber), args);
+    }
+    let stop = start.elapsed().expect("failed to measure time");
+
+    println!(
+        "{} ::: Iterations: {}, Time: {} s, Loan-Send-Receive: {} ns, Sample Size: {}, Single Threaded",
+        core::any::type_name::<T>(),
+        args.iterations,
+        stop.as_secs_f64(),
+        stop.as_nanos() / args.iterations as u128,
+        args.payload_size,
+    );
+
+    Ok(())
+}
+
 fn perform_benchmark<T: Service>(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     let service_name_a2b = ServiceName::new("a2b")?;
     let service_name_b2a = ServiceName::new("b2a")?;
     let node = NodeBuilder::new().create::<T>()?;
+    let max_subscribers = args
+        .max_subscribers
+        .max(1 + args.number_of_additional_subscribers);
+    let pool_allocation_strategy = if args.bump_allocation {
+        PoolAllocationStrategy::Bump
+    } else {
+        PoolAllocationStrategy::FreeList
+    };
+    let loan_init_policy = if args.zero_loans {
+        LoanInitPolicy::Zeroed
+    } else {
+        LoanInitPolicy::Uninit
+    };
 
     let service_a2b = node
         .service_builder(&service_name_a2b)
         .publish_subscribe::<[u8]>()
         .max_publishers(1 + args.number_of_additional_publishers)
-        .max_subscribers(1 + args.number_of_additional_subscribers)
+        .max_subscribers(max_subscribers)
         .history_size(0)
         .subscriber_max_buffer_size(1)
         .enable_safe_overflow(true)
+        .sample_cache_line_aligned(args.cache_line_aligned)
+        .pool_allocation_strategy(pool_allocation_strategy)
         .create()?;
 
     let service_b2a = node
         .service_builder(&service_name_b2a)
         .publish_subscribe::<[u8]>()
         .max_publishers(1 + args.number_of_additional_publishers)
-        .max_subscribers(1 + args.number_of_additional_subscribers)
+        .max_subscribers(max_subscribers)
         .history_size(0)
         .subscriber_max_buffer_size(1)
         .enable_safe_overflow(true)
+        .sample_cache_line_aligned(args.cache_line_aligned)
+        .pool_allocation_strategy(pool_allocation_strategy)
         .create()?;
 
     let mut additional_publishers = Vec::new();
@@ -59,6 +146,7 @@ fn perform_benchmark<T: Service>(args: &Args) -> Result<(), Box<dyn std::error::
         additional_subscribers.push(service_b2a.subscriber_builder().create()?);
     }
 
+    let fast_path_active = IoxAtomicBool::new(false);
     let start_benchmark_barrier_handle = BarrierHandle::new();
     let startup_barrier_handle = BarrierHandle::new();
     let startup_barrier = BarrierBuilder::new(3)
@@ -75,11 +163,14 @@ fn perform_benchmark<T: Service>(args: &Args) -> Result<(), Box<dyn std::error::
             let sender_a2b = service_a2b
                 .publisher_builder()
                 .initial_max_slice_len(args.payload_size)
+                .loan_init_policy(loan_init_policy)
                 .create()
                 .unwrap();
             let receiver_b2a = service_b2a.subscriber_builder().create().unwrap();
 
             startup_barrier.wait();
+            sender_a2b.update_connections().unwrap();
+            fast_path_active.store(sender_a2b.fast_path_active(), Ordering::Relaxed);
             start_benchmark_barrier.wait();
 
             let mut sample = if args.send_copy {
@@ -103,7 +194,7 @@ fn perform_benchmark<T: Service>(args: &Args) -> Result<(), Box<dyn std::error::
                         .unwrap()
                         .assume_init()
                 };
-                while receiver_b2a.receive().unwrap().is_none() {}
+                consume(&receive(&receiver_b2a), args);
             }
         });
 
@@ -114,6 +205,7 @@ fn perform_benchmark<T: Service>(args: &Args) -> Result<(), Box<dyn std::error::
             let sender_b2a = service_b2a
                 .publisher_builder()
                 .initial_max_slice_len(args.payload_size)
+                .loan_init_policy(loan_init_policy)
                 .create()
                 .unwrap();
             let receiver_a2b = service_a2b.subscriber_builder().create().unwrap();
@@ -135,7 +227,7 @@ fn perform_benchmark<T: Service>(args: &Args) -> Result<(), Box<dyn std::error::
                     }
                 };
 
-                while receiver_a2b.receive().unwrap().is_none() {}
+                consume(&receive(&receiver_a2b), args);
 
                 sample.send().unwrap();
             }
@@ -150,12 +242,15 @@ fn perform_benchmark<T: Service>(args: &Args) -> Result<(), Box<dyn std::error::
 
     let stop = start.elapsed().expect("failed to measure time");
     println!(
-        "{} ::: Iterations: {}, Time: {} s, Latency: {} ns, Sample Size: {}",
+        "{} ::: Iterations: {}, Time: {} s, Latency: {} ns, Sample Size: {}, Fast Path: {}, Pool Allocation: {:?}, Loan Init Policy: {:?}",
         core::any::type_name::<T>(),
         args.iterations,
         stop.as_secs_f64(),
         stop.as_nanos() / (args.iterations as u128 * 2),
-        args.payload_size
+        args.payload_size,
+        fast_path_active.load(Ordering::Relaxed),
+        pool_allocation_strategy,
+        loan_init_policy
     );
 
     Ok(())
@@ -198,6 +293,31 @@ struct Args {
     /// The number of additional subscribers per service in the setup.
     #[clap(long, default_value_t = 0)]
     number_of_additional_subscribers: usize,
+    /// The maximum number of subscribers per service. It is raised to the number of
+    /// subscribers in the setup when it is smaller.
+    #[clap(long, default_value_t = 1)]
+    max_subscribers: usize,
+    /// Align every sample slot to a cache line boundary to avoid false sharing between
+    /// concurrent readers.
+    #[clap(long)]
+    cache_line_aligned: bool,
+    /// Read every byte of the received payload, like a consumer that processes the data.
+    #[clap(long)]
+    read_payload: bool,
+    /// Prefetch the payload of every received sample into the cache before it is read.
+    #[clap(long)]
+    prefetch: bool,
+    /// Hand out the sample slots with the bump allocation strategy instead of the free-list.
+    #[clap(long)]
+    bump_allocation: bool,
+    /// Fill the payload of every loaned sample with zeros instead of handing it out
+    /// uninitialized.
+    #[clap(long)]
+    zero_loans: bool,
+    /// Instead of the ping-pong between two threads, loan, send and receive every sample on a
+    /// single thread to measure the per-operation overhead of the ports.
+    #[clap(long)]
+    single_threaded: bool,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -212,12 +332,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut at_least_one_benchmark_did_run = false;
 
     if args.bench_ipc || args.bench_all {
-        perform_benchmark::<ipc::Service>(&args)?;
+        if args.single_threaded {
+            perform_single_threaded_benchmark::<ipc::Service>(&args)?;
+        } else {
+            perform_benchmark::<ipc::Service>(&args)?;
+        }
         at_least_one_benchmark_did_run = true;
     }
 
     if args.bench_local || args.bench_all {
-        perform_benchmark::<local::Service>(&args)?;
+        if args.single_threaded {
+            perform_single_threaded_benchmark::<local::Service>(&args)?;
+        } else {
+            perform_benchmark::<local::Service>(&args)?;
+        }
         at_least_one_benchmark_did_run = true;
     }
 
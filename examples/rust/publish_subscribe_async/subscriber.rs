@@ -0,0 +1,48 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(unix)]
+use examples_common::TransmissionData;
+#[cfg(unix)]
+use iceoryx2::prelude::*;
+
+// tokio's AsyncFd is only available on unix platforms
+#[cfg(not(unix))]
+fn main() {
+    println!("This example requires a unix platform.");
+}
+
+#[cfg(unix)]
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let node = NodeBuilder::new().create::<ipc::Service>()?;
+
+    let service = node
+        .service_builder(&"My/Funk/ServiceName".try_into()?)
+        .publish_subscribe::<TransmissionData>()
+        .open_or_create()?;
+
+    let subscriber = service.subscriber_builder().create()?;
+
+    loop {
+        tokio::select! {
+            sample = subscriber.receive_async() => {
+                println!("received: {:?}", *sample?);
+            }
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    println!("exit");
+
+    Ok(())
+}
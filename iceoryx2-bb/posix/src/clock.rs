@@ -30,12 +30,23 @@ use iceoryx2_pal_posix::posix::errno::Errno;
 use iceoryx2_pal_posix::*;
 use serde::{Deserialize, Serialize};
 
+/// Failures that can occur when the current time is acquired, for instance with [`Time::now()`].
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum TimeError {
+    /// The requested [`ClockType`] is not available on the platform.
     ClockTypeIsNotSupported,
+    /// The clock could not be read, contains the errno of the failed call.
     UnknownError(i32),
 }
 
+impl core::fmt::Display for TimeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "TimeError::{:?}", self)
+    }
+}
+
+impl std::error::Error for TimeError {}
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum NanosleepError {
     InterruptedBySignal(Duration),
@@ -212,9 +223,48 @@ impl Time {
         );
     }
 
-    /// Returns the current time with [`ClockType::default()`]
+    /// Returns the current time of the [`ClockType::Monotonic`] clock. It never jumps when the
+    /// system time is adjusted and is therefore suited to compute ages and deadlines. On
+    /// platforms without a monotonic clock, see [`Feature::MonotonicClock`], the
+    /// [`ClockType::Realtime`] clock is used instead. The [`Time::clock_type()`] of the
+    /// returned [`Time`] states which clock was used.
+    ///
+    /// It fails with [`TimeError::ClockTypeIsNotSupported`] when the clock is unavailable and
+    /// with [`TimeError::UnknownError`] when it could not be read.
+    ///
+    /// # Examples
+    /// ```
+    /// use iceoryx2_bb_posix::clock::*;
+    ///
+    /// let start = Time::now().unwrap();
+    /// // do something
+    /// let later = Time::now().unwrap();
+    /// assert!(start.as_duration() <= later.as_duration());
+    /// ```
     pub fn now() -> Result<Self, TimeError> {
-        Self::now_with_clock(ClockType::default())
+        if Feature::MonotonicClock.is_available() {
+            Self::now_with_clock(ClockType::Monotonic)
+        } else {
+            Self::now_with_clock(ClockType::Realtime)
+        }
+    }
+
+    /// Returns the current time of the [`ClockType::Realtime`] clock, the system time since
+    /// the unix epoch. It can jump forward and backward when the system time is adjusted, use
+    /// [`Time::now()`] to measure durations.
+    ///
+    /// It fails with [`TimeError::ClockTypeIsNotSupported`] when the clock is unavailable and
+    /// with [`TimeError::UnknownError`] when it could not be read.
+    ///
+    /// # Examples
+    /// ```
+    /// use iceoryx2_bb_posix::clock::*;
+    ///
+    /// let now = Time::now_realtime().unwrap();
+    /// println!("seconds since epoch: {}", now.seconds());
+    /// ```
+    pub fn now_realtime() -> Result<Self, TimeError> {
+        Self::now_with_clock(ClockType::Realtime)
     }
 
     /// Returns the elapsed time which has passed between Time and now as [`Duration`].
@@ -246,6 +296,7 @@ impl Time {
         self.nanoseconds
     }
 
+    /// Returns the [`ClockType`] the [`Time`] was acquired with
     pub fn clock_type(&self) -> ClockType {
         self.clock_type
     }
@@ -40,8 +40,11 @@
 //!             process.get_priority().expect("failed to get priority"));
 //! ```
 use core::fmt::Display;
+use core::time::Duration;
 
+use crate::file::{AccessMode, FileBuilder};
 use crate::handle_errno;
+use crate::system_configuration::SystemInfo;
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_system_types::file_path::*;
@@ -60,6 +63,17 @@ pub enum ProcessExecutablePathError {
     UnableToRead,
 }
 
+/// Describes why the start time of a [`Process`] could not be acquired with
+/// [`Process::start_time()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ProcessStartTimeError {
+    /// The process status is unavailable, either since the process does not exist or since
+    /// the platform does not provide a `/proc` file system.
+    UnableToRead,
+    /// The process status could not be parsed.
+    InvalidProcessStatus,
+}
+
 enum_gen! { ProcessSendSignalError
   entry:
     InsufficientPermissions,
@@ -189,6 +203,55 @@ impl Process {
         Ok(path)
     }
 
+    /// Returns the point in time when the [`Process`] was started, measured from the boot of
+    /// the system. It is acquired from the `/proc` file system and does not depend on any
+    /// clock that can be adjusted. Since it never changes during the lifetime of a
+    /// [`Process`], it allows to detect whether the process id was reused by another process.
+    pub fn start_time(&self) -> Result<Duration, ProcessStartTimeError> {
+        let msg = "Unable to acquire the start time of the process";
+        let mut buffer = [0u8; 1024];
+
+        let status = fail!(from self,
+            when Self::read_proc_file(&format!("/proc/{}/stat", self.pid.0), &mut buffer),
+            "{} since the process status could not be read.", msg);
+        // the executable name is enclosed in parentheses and may contain whitespaces,
+        // the start time is the 20th field after it
+        let start_time_in_ticks = status
+            .rfind(')')
+            .and_then(|pos| status[pos + 1..].split_whitespace().nth(19))
+            .and_then(|v| v.parse::<u64>().ok());
+        let start_time_in_ticks = match start_time_in_ticks {
+            Some(v) => v,
+            None => {
+                fail!(from self, with ProcessStartTimeError::InvalidProcessStatus,
+                    "{} since the start time is missing in the process status.", msg);
+            }
+        };
+
+        let ticks_per_second = (SystemInfo::NumberOfClockTicksPerSecond.value() as u64).max(1);
+        Ok(Duration::from_secs(start_time_in_ticks / ticks_per_second)
+            + Duration::from_nanos(
+                (start_time_in_ticks % ticks_per_second) * 1_000_000_000 / ticks_per_second,
+            ))
+    }
+
+    fn read_proc_file<'a>(
+        path: &str,
+        buffer: &'a mut [u8],
+    ) -> Result<&'a str, ProcessStartTimeError> {
+        let path =
+            FilePath::new(path.as_bytes()).map_err(|_| ProcessStartTimeError::UnableToRead)?;
+        let file = FileBuilder::new(&path)
+            .open_existing(AccessMode::Read)
+            .map_err(|_| ProcessStartTimeError::UnableToRead)?;
+        let len = file
+            .read(buffer)
+            .map_err(|_| ProcessStartTimeError::UnableToRead)?;
+
+        core::str::from_utf8(&buffer[..len as usize])
+            .map_err(|_| ProcessStartTimeError::InvalidProcessStatus)
+    }
+
     /// Sends a signal to the process.
     pub fn send_signal(&self, signal: Signal) -> Result<(), ProcessSendSignalError> {
         if unsafe { posix::kill(self.pid.0, signal as i32) } == 0 {
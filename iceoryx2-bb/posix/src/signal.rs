@@ -403,6 +403,16 @@ impl SignalHandler {
         }
     }
 
+    /// Returns the last signal which was raised like [`SignalHandler::last_signal()`] but
+    /// without resetting it.
+    pub fn peek_last_signal() -> Option<NonFatalFetchableSignal> {
+        Self::instance();
+        match LAST_SIGNAL.load(Ordering::Relaxed) {
+            posix::MAX_SIGNAL_VALUE => None,
+            v => Some((v as i32).into()),
+        }
+    }
+
     /// Returns true if ([`NonFatalFetchableSignal::Interrupt`] or
     /// [`NonFatalFetchableSignal::Terminate`]) was emitted
     /// for instance by pressing CTRL+c, otherwise false
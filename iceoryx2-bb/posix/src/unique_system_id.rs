@@ -36,9 +36,22 @@
 //!     }
 //! }
 //! ```
+//!
+//! When ids of multiple hosts are compared, like in a bridge to another host, a
+//! [`GlobalUniqueSystemId`] shall be used. It combines the [`UniqueSystemId`] with the
+//! [`host_id()`] of the current host.
+//!
+//! ```no_run
+//! use iceoryx2_bb_posix::unique_system_id::*;
+//!
+//! let id = UniqueSystemId::new_global().expect("Failed to create GlobalUniqueSystemId");
+//! println!("host {:x}, local id {}", id.host_id(), id.local_id());
+//! ```
 
+use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::enum_gen;
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU32;
 use iceoryx2_pal_posix::posix;
 use serde::{Deserialize, Serialize};
@@ -49,20 +62,71 @@ use core::{
 };
 
 use crate::{
-    clock::Time,
+    access_mode::AccessMode,
+    clock::{Time, TimeError},
+    file::FileBuilder,
     process::{Process, ProcessId},
     semaphore::ClockType,
 };
 
-enum_gen! { UniqueSystemIdCreationError
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+// the nanoseconds are always below 2^30, the highest bit records the clock of the creation time
+const MONOTONIC_CLOCK_FLAG: u32 = 1 << 31;
+// bit positions of the fields in the underlying value, the layout of the former in-memory
+// representation on little-endian hosts is kept so that existing values stay valid
+const PID_SHIFT: u32 = 0;
+const SECONDS_SHIFT: u32 = 32;
+const NANOSECONDS_SHIFT: u32 = 64;
+const COUNTER_SHIFT: u32 = 96;
+const MACHINE_ID_PATHS: [&[u8]; 2] = [b"/etc/machine-id", b"/var/lib/dbus/machine-id"];
+
+enum_gen! {
+/// Describes the failures that can occur when a [`UniqueSystemId`] is created. The underlying
+/// cause is available via [`std::error::Error::source()`].
+    UniqueSystemIdCreationError
   entry:
-    FailedToAcquireTime
+    FailedToAcquireTime(TimeError),
+    FailedToAcquireHostId
 }
 
+impl Display for UniqueSystemIdCreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "UniqueSystemIdCreationError::{:?}", self)
+    }
+}
+
+impl std::error::Error for UniqueSystemIdCreationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            UniqueSystemIdCreationError::FailedToAcquireTime(e) => Some(e),
+            UniqueSystemIdCreationError::FailedToAcquireHostId => None,
+        }
+    }
+}
+
+enum_gen! {
+/// Describes why a 128-bit value is not a valid [`UniqueSystemId`], see
+/// [`UniqueSystemId::try_from_u128()`].
+    UniqueSystemIdValidationError
+  entry:
+    InvalidProcessId,
+    InvalidNanoseconds
+}
+
+impl Display for UniqueSystemIdValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "UniqueSystemIdValidationError::{:?}", self)
+    }
+}
+
+impl std::error::Error for UniqueSystemIdValidationError {}
+
 /// Creates a system wide unique id. There does not exist another process which has generated the
 /// same id. There will never be another process on the same system with the same id.
 /// The [`UniqueSystemId`] is generated by the processes current process id and the current system
-/// time using the [`ClockType::Monotonic`].
+/// time using the [`ClockType::default()`]. When this clock is unavailable, the other
+/// [`ClockType`] is used instead and [`UniqueSystemId::creation_time()`] reports the clock that
+/// was actually used.
 #[derive(Eq, Hash, PartialEq, Clone, Copy, PartialOrd, Ord, Serialize, Deserialize)]
 #[repr(C)]
 pub struct UniqueSystemId {
@@ -90,38 +154,119 @@ impl Display for UniqueSystemId {
     }
 }
 
+/// Converts the underlying value of a [`UniqueSystemId`] back into a [`UniqueSystemId`] without
+/// any validation. It shall only be used for values that were acquired with
+/// [`UniqueSystemId::value()`] in a trusted context, values from untrusted sources must be
+/// converted with [`UniqueSystemId::try_from_u128()`].
 impl From<u128> for UniqueSystemId {
     fn from(value: u128) -> Self {
-        unsafe { core::mem::transmute(value) }
+        Self {
+            pid: (value >> PID_SHIFT) as u32,
+            seconds: (value >> SECONDS_SHIFT) as u32,
+            nanoseconds: (value >> NANOSECONDS_SHIFT) as u32,
+            counter: (value >> COUNTER_SHIFT) as u32,
+        }
     }
 }
 
 impl UniqueSystemId {
-    /// Creates a new system wide unique id
+    /// Creates a new system wide unique id. When the current time can be acquired neither with
+    /// the [`ClockType::default()`] nor with the alternate [`ClockType`] it fails with
+    /// [`UniqueSystemIdCreationError::FailedToAcquireTime`].
     pub fn new() -> Result<Self, UniqueSystemIdCreationError> {
         let msg = "Failed to create UniqueSystemId";
         let pid = Process::from_self().id().value() as _;
-        let now = fail!(from "UniqueSystemId::new()",
-                        when Time::now_with_clock(ClockType::default()),
-                        with UniqueSystemIdCreationError::FailedToAcquireTime,
-                        "{} since the current time could not be acquired.", msg);
+        let now = match Self::acquire_creation_time(Time::now_with_clock) {
+            Ok(now) => now,
+            Err(e) => {
+                fail!(from "UniqueSystemId::new()",
+                    with UniqueSystemIdCreationError::FailedToAcquireTime(e),
+                    "{} since the current time could not be acquired ({:?}).", msg, e);
+            }
+        };
 
         Ok(Self::create(pid, now))
     }
 
+    /// Creates a new [`GlobalUniqueSystemId`] which is unique across all hosts that have a
+    /// distinct [`host_id()`].
+    pub fn new_global() -> Result<GlobalUniqueSystemId, UniqueSystemIdCreationError> {
+        let host_id = host_id()?;
+        Ok(GlobalUniqueSystemId::new(host_id, Self::new()?))
+    }
+
+    /// Converts the underlying value of a [`UniqueSystemId`] back into a [`UniqueSystemId`].
+    /// In contrast to [`From<u128>`], it verifies that the value contains a plausible process
+    /// id and a valid creation time and returns an [`UniqueSystemIdValidationError`] otherwise.
+    /// It shall be used for values that are received from untrusted sources.
+    pub fn try_from_u128(value: u128) -> Result<Self, UniqueSystemIdValidationError> {
+        let origin = "UniqueSystemId::try_from_u128()";
+        let msg = "Unable to convert value into UniqueSystemId";
+        let id = UniqueSystemId::from(value);
+
+        if id.pid == 0 || id.pid > posix::pid_t::MAX as u32 {
+            fail!(from origin, with UniqueSystemIdValidationError::InvalidProcessId,
+                "{} {} since it contains the invalid process id {}.", msg, value, id.pid);
+        }
+
+        let nanoseconds = id.nanoseconds & !MONOTONIC_CLOCK_FLAG;
+        if nanoseconds >= NANOSECONDS_PER_SECOND {
+            fail!(from origin, with UniqueSystemIdValidationError::InvalidNanoseconds,
+                "{} {} since the nanoseconds {} of the creation time exceed one second.",
+                msg, value, nanoseconds);
+        }
+
+        Ok(id)
+    }
+
+    /// Acquires the current time with the [`ClockType::default()`] and falls back to the other
+    /// [`ClockType`] when it is unavailable. When both fail, the failure of the
+    /// [`ClockType::default()`] is returned.
+    fn acquire_creation_time<F: FnMut(ClockType) -> Result<Time, TimeError>>(
+        mut now: F,
+    ) -> Result<Time, TimeError> {
+        let preferred = ClockType::default();
+        let alternate = match preferred {
+            ClockType::Monotonic => ClockType::Realtime,
+            ClockType::Realtime => ClockType::Monotonic,
+        };
+
+        match now(preferred) {
+            Ok(time) => Ok(time),
+            Err(e) => match now(alternate) {
+                Ok(time) => {
+                    warn!(from "UniqueSystemId::new()",
+                        "The {:?} clock is unavailable ({:?}), the {:?} clock is used instead.",
+                        preferred, e, alternate);
+                    Ok(time)
+                }
+                Err(_) => Err(e),
+            },
+        }
+    }
+
     fn create(pid: u32, now: Time) -> UniqueSystemId {
         static COUNTER: IoxAtomicU32 = IoxAtomicU32::new(0);
+        let clock_flag = match now.clock_type() {
+            ClockType::Monotonic => MONOTONIC_CLOCK_FLAG,
+            ClockType::Realtime => 0,
+        };
+
         UniqueSystemId {
             pid,
             seconds: now.seconds() as u32,
-            nanoseconds: now.nanoseconds(),
+            nanoseconds: now.nanoseconds() | clock_flag,
             counter: COUNTER.fetch_add(1, Ordering::Relaxed),
         }
     }
 
-    /// Returns the underlying value of the new system wide unique id
+    /// Returns the underlying value of the new system wide unique id. The encoding does not
+    /// depend on the endianness of the host.
     pub fn value(&self) -> u128 {
-        unsafe { core::mem::transmute(*self) }
+        (self.pid as u128) << PID_SHIFT
+            | (self.seconds as u128) << SECONDS_SHIFT
+            | (self.nanoseconds as u128) << NANOSECONDS_SHIFT
+            | (self.counter as u128) << COUNTER_SHIFT
     }
 
     /// Returns the [`ProcessId`] which was used to create the [`UniqueSystemId`]
@@ -129,18 +274,114 @@ impl UniqueSystemId {
         ProcessId::new(self.pid as posix::pid_t)
     }
 
-    /// Returns the [`Time`] when the [`UniqueSystemId`] was created
+    /// Returns the [`Time`] when the [`UniqueSystemId`] was created together with the
+    /// [`ClockType`] that was used to acquire it.
     pub fn creation_time(&self) -> Time {
         Time {
-            clock_type: ClockType::default(),
+            clock_type: if self.nanoseconds & MONOTONIC_CLOCK_FLAG != 0 {
+                ClockType::Monotonic
+            } else {
+                ClockType::Realtime
+            },
             seconds: self.seconds as u64,
-            nanoseconds: self.nanoseconds,
+            nanoseconds: self.nanoseconds & !MONOTONIC_CLOCK_FLAG,
+        }
+    }
+}
+
+/// A [`UniqueSystemId`] that is extended by the identifier of the host it was created on. In
+/// contrast to the [`UniqueSystemId`], which is only unique on the current host, it is unique
+/// across all hosts with a distinct [`host_id()`], like the hosts of a cluster.
+#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy, PartialOrd, Ord, Serialize, Deserialize)]
+#[repr(C)]
+pub struct GlobalUniqueSystemId {
+    host_id: u64,
+    local_id: UniqueSystemId,
+}
+
+impl Display for GlobalUniqueSystemId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:016x}-{}", self.host_id, self.local_id)
+    }
+}
+
+impl GlobalUniqueSystemId {
+    /// Combines a host identifier with a [`UniqueSystemId`] that was created on this host.
+    /// Use [`UniqueSystemId::new_global()`] to create a [`GlobalUniqueSystemId`] for the
+    /// current host.
+    pub fn new(host_id: u64, local_id: UniqueSystemId) -> Self {
+        Self { host_id, local_id }
+    }
+
+    /// Returns the identifier of the host on which the [`GlobalUniqueSystemId`] was created.
+    pub fn host_id(&self) -> u64 {
+        self.host_id
+    }
+
+    /// Returns the [`UniqueSystemId`] that is unique on the host it was created on.
+    pub fn local_id(&self) -> UniqueSystemId {
+        self.local_id
+    }
+}
+
+/// Returns a stable identifier of the current host. It is the 64-bit FNV-1a hash of the
+/// machine id that is stored in `/etc/machine-id` or `/var/lib/dbus/machine-id` and does
+/// not change when the host is rebooted. Fails with
+/// [`UniqueSystemIdCreationError::FailedToAcquireHostId`] when none of them is
+/// available, like on macOS and Windows, which store the machine id elsewhere.
+pub fn host_id() -> Result<u64, UniqueSystemIdCreationError> {
+    lazy_static::lazy_static! {
+        static ref HOST_ID: Option<u64> = read_host_id();
+    }
+
+    match *HOST_ID {
+        Some(id) => Ok(id),
+        None => {
+            fail!(from "host_id()", with UniqueSystemIdCreationError::FailedToAcquireHostId,
+                "Failed to acquire the host id since no machine id is available.");
         }
     }
 }
 
+fn read_host_id() -> Option<u64> {
+    for path in MACHINE_ID_PATHS {
+        let path = match FilePath::new(path) {
+            Ok(path) => path,
+            Err(_) => continue,
+        };
+
+        let file = match FileBuilder::new(&path).open_existing(AccessMode::Read) {
+            Ok(file) => file,
+            Err(_) => continue,
+        };
+
+        let mut content = String::new();
+        if file.read_to_string(&mut content).is_err() {
+            continue;
+        }
+
+        let machine_id = content.trim();
+        if !machine_id.is_empty() {
+            return Some(fnv1a_hash(machine_id.as_bytes()));
+        }
+    }
+
+    None
+}
+
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+use crate::system_configuration::Feature;
 #[cfg(test)]
-use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::{assert_that, test_requires};
 
 #[test]
 // ensures the unique_system_id is unique when a process creates the id simultaneously.
@@ -181,3 +422,60 @@ fn test_unique_system_id_across_processes() {
     assert_that!(id1.creation_time(), eq id2.creation_time());
     assert_that!(id1.value(), ne id2.value());
 }
+
+#[test]
+fn unique_system_id_falls_back_to_alternate_clock_when_default_clock_fails() {
+    test_requires!(Feature::MonotonicClock.is_available());
+
+    let preferred = ClockType::default();
+    let mut requested_clocks = vec![];
+
+    let sut = UniqueSystemId::acquire_creation_time(|clock_type| {
+        requested_clocks.push(clock_type);
+        if clock_type == preferred {
+            Err(TimeError::ClockTypeIsNotSupported)
+        } else {
+            Time::now_with_clock(clock_type)
+        }
+    })
+    .unwrap();
+
+    assert_that!(requested_clocks, len 2);
+    assert_that!(sut.clock_type(), ne preferred);
+
+    let pid = Process::from_self().id().value() as _;
+    let id = UniqueSystemId::create(pid, sut);
+    assert_that!(id.creation_time(), eq sut);
+    assert_that!(UniqueSystemId::try_from_u128(id.value()), eq Ok(id));
+}
+
+#[test]
+fn unique_system_id_value_encodes_fields_independent_of_endianness() {
+    let sut = UniqueSystemId {
+        pid: 0x1234_5678,
+        seconds: 0x9abc_def0,
+        nanoseconds: 999_999_999 | MONOTONIC_CLOCK_FLAG,
+        counter: 0x0fed_cba9,
+    };
+
+    let value = sut.value();
+    assert_that!(value as u32, eq sut.pid);
+    assert_that!((value >> 32) as u32, eq sut.seconds);
+    assert_that!((value >> 64) as u32, eq sut.nanoseconds);
+    assert_that!((value >> 96) as u32, eq sut.counter);
+
+    assert_that!(UniqueSystemId::from(value), eq sut);
+    assert_that!(UniqueSystemId::try_from_u128(value), eq Ok(sut));
+
+    let id = UniqueSystemId::new().unwrap();
+    assert_that!(UniqueSystemId::from(id.value()), eq id);
+    #[cfg(target_endian = "little")]
+    assert_that!(id.value(), eq unsafe { core::mem::transmute::<UniqueSystemId, u128>(id) });
+}
+
+#[test]
+fn unique_system_id_creation_fails_when_all_clocks_fail() {
+    let sut = UniqueSystemId::acquire_creation_time(|_| Err(TimeError::UnknownError(5)));
+
+    assert_that!(sut, eq Err(TimeError::UnknownError(5)));
+}
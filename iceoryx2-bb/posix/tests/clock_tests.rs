@@ -75,6 +75,35 @@ fn clock_time_now_is_monotonic_with_monotonic_clock() {
     assert_that!(start2.elapsed().unwrap(), time_at_least TIMEOUT);
 }
 
+#[test]
+fn clock_time_now_never_decreases_across_successive_calls() {
+    let mut previous = Time::now().unwrap();
+    for _ in 0..1000 {
+        let current = Time::now().unwrap();
+        assert_that!(current.clock_type(), eq previous.clock_type());
+        assert_that!(current.as_duration(), ge previous.as_duration());
+        previous = current;
+    }
+}
+
+#[test]
+fn clock_time_now_uses_monotonic_clock_when_available() {
+    test_requires!(Feature::MonotonicClock.is_available());
+
+    assert_that!(Time::now().unwrap().clock_type(), eq ClockType::Monotonic);
+}
+
+#[test]
+fn clock_time_now_realtime_uses_realtime_clock() {
+    let now = Time::now_realtime().unwrap();
+    let system_time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap();
+
+    assert_that!(now.clock_type(), eq ClockType::Realtime);
+    assert_that!(system_time.as_secs().abs_diff(now.seconds()), le 1);
+}
+
 #[test]
 fn clock_time_as_timespec_works() {
     let now = Time::now().unwrap();
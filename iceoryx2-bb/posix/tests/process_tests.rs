@@ -46,6 +46,22 @@ pub fn process_is_alive_works() {
     assert_that!(process2.is_alive(), eq false);
 }
 
+#[test]
+pub fn process_start_time_works() {
+    let process = Process::from_self();
+    let start_time = process.start_time();
+
+    if let Ok(start_time) = start_time {
+        std::thread::sleep(core::time::Duration::from_millis(50));
+        assert_that!(process.start_time().unwrap(), eq start_time);
+    } else {
+        assert_that!(start_time.err(), eq Some(ProcessStartTimeError::UnableToRead));
+    }
+
+    let process = Process::from_pid(ProcessId::new(posix::pid_t::MAX - 1));
+    assert_that!(process.start_time().err(), eq Some(ProcessStartTimeError::UnableToRead));
+}
+
 #[test]
 pub fn process_executable_path_works() {
     let process = Process::from_self();
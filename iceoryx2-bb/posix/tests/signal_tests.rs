@@ -305,3 +305,22 @@ fn signal_termination_requested_with_interrupt_works() {
     );
     assert_that!(SignalHandler::termination_requested(), eq false);
 }
+
+#[test]
+fn signal_peek_last_signal_does_not_reset_the_last_signal() {
+    test_requires!(POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING);
+
+    let _test = TestFixture::new();
+
+    SignalHandler::last_signal();
+    assert_that!(SignalHandler::peek_last_signal(), eq None);
+    assert_that!(Process::from_self().send_signal(Signal::Terminate), is_ok);
+
+    assert_that!(
+        || { SignalHandler::peek_last_signal() },
+        block_until Some(NonFatalFetchableSignal::Terminate)
+    );
+    assert_that!(SignalHandler::peek_last_signal(), eq Some(NonFatalFetchableSignal::Terminate));
+    assert_that!(SignalHandler::last_signal(), eq Some(NonFatalFetchableSignal::Terminate));
+    assert_that!(SignalHandler::peek_last_signal(), eq None);
+}
@@ -13,7 +13,9 @@
 use core::time::Duration;
 use std::{collections::HashSet, sync::Barrier};
 
-use iceoryx2_bb_posix::{process::Process, system_configuration::SystemInfo, unique_system_id::*};
+use iceoryx2_bb_posix::{
+    clock::TimeError, process::Process, system_configuration::SystemInfo, unique_system_id::*,
+};
 use iceoryx2_bb_testing::{assert_that, watchdog::Watchdog};
 
 #[test]
@@ -70,3 +72,107 @@ fn unique_system_id_concurrently_created_ids_are_unique() {
         }
     });
 }
+
+#[test]
+fn unique_system_id_creation_error_chains_to_underlying_cause() {
+    use std::error::Error;
+
+    let sut = UniqueSystemIdCreationError::FailedToAcquireTime(TimeError::ClockTypeIsNotSupported);
+
+    assert_that!(format!("{}", sut), eq "UniqueSystemIdCreationError::FailedToAcquireTime(ClockTypeIsNotSupported)");
+    let source = sut.source();
+    assert_that!(source, is_some);
+    assert_that!(format!("{}", source.unwrap()), eq "TimeError::ClockTypeIsNotSupported");
+}
+
+#[test]
+fn unique_system_id_try_from_u128_accepts_valid_value() {
+    let id = UniqueSystemId::new().unwrap();
+
+    let sut = UniqueSystemId::try_from_u128(id.value());
+
+    assert_that!(sut, eq Ok(id));
+}
+
+#[test]
+fn unique_system_id_try_from_u128_rejects_invalid_process_id() {
+    let id = UniqueSystemId::new().unwrap();
+    let pid_mask = u32::MAX as u128;
+
+    let sut = UniqueSystemId::try_from_u128(id.value() & !pid_mask);
+    assert_that!(sut, eq Err(UniqueSystemIdValidationError::InvalidProcessId));
+
+    let sut = UniqueSystemId::try_from_u128(id.value() | pid_mask);
+    assert_that!(sut, eq Err(UniqueSystemIdValidationError::InvalidProcessId));
+}
+
+#[test]
+fn unique_system_id_try_from_u128_rejects_invalid_nanoseconds() {
+    let id = UniqueSystemId::new().unwrap();
+    let nanoseconds_mask = (u32::MAX as u128) << 64;
+
+    let sut = UniqueSystemId::try_from_u128(id.value() | nanoseconds_mask);
+
+    assert_that!(sut, eq Err(UniqueSystemIdValidationError::InvalidNanoseconds));
+}
+
+// the machine id is only available on Linux
+#[cfg(target_os = "linux")]
+#[test]
+fn global_unique_system_id_contains_host_id_and_local_id() {
+    let sut = UniqueSystemId::new_global().unwrap();
+
+    assert_that!(sut.host_id(), eq host_id().unwrap());
+    assert_that!(sut.local_id().pid(), eq Process::from_self().id());
+}
+
+// the machine id is only available on Linux
+#[cfg(target_os = "linux")]
+#[test]
+fn global_unique_system_ids_of_the_same_host_are_unique() {
+    let sut1 = UniqueSystemId::new_global().unwrap();
+    let sut2 = UniqueSystemId::new_global().unwrap();
+
+    assert_that!(sut1.host_id(), eq sut2.host_id());
+    assert_that!(sut1, ne sut2);
+}
+
+// the machine id is only available on Linux
+#[cfg(target_os = "linux")]
+#[test]
+fn host_id_is_stable() {
+    assert_that!(host_id().unwrap(), eq host_id().unwrap());
+}
+
+#[test]
+fn global_unique_system_ids_with_colliding_local_ids_differ_by_host_id() {
+    let local_id = UniqueSystemId::new().unwrap();
+    let colliding_local_id = UniqueSystemId::from(local_id.value());
+
+    let sut1 = GlobalUniqueSystemId::new(0x1234, local_id);
+    let sut2 = GlobalUniqueSystemId::new(0x5678, colliding_local_id);
+    let sut3 = GlobalUniqueSystemId::new(0x1234, colliding_local_id);
+
+    assert_that!(sut1.local_id(), eq sut2.local_id());
+    assert_that!(sut1, ne sut2);
+    assert_that!(format!("{}", sut1), ne format!("{}", sut2));
+    assert_that!(sut1, eq sut3);
+}
+
+#[test]
+fn global_unique_system_id_display_contains_host_and_local_id() {
+    let local_id = UniqueSystemId::new().unwrap();
+    let sut = GlobalUniqueSystemId::new(0xabc, local_id);
+
+    assert_that!(format!("{}", sut), eq format!("0000000000000abc-{}", local_id.value()));
+}
+
+#[test]
+fn unique_system_id_creation_error_without_underlying_cause_has_no_source() {
+    use std::error::Error;
+
+    let sut = UniqueSystemIdCreationError::FailedToAcquireHostId;
+
+    assert_that!(format!("{}", sut), eq "UniqueSystemIdCreationError::FailedToAcquireHostId");
+    assert_that!(sut.source(), is_none);
+}
@@ -86,6 +86,14 @@ pub enum DynamicStorageCreateError {
     InternalError,
 }
 
+impl core::fmt::Display for DynamicStorageCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "DynamicStorageCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for DynamicStorageCreateError {}
+
 /// Describes failures when opening a new [`DynamicStorage`]
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum DynamicStorageOpenError {
@@ -95,6 +103,14 @@ pub enum DynamicStorageOpenError {
     InternalError,
 }
 
+impl core::fmt::Display for DynamicStorageOpenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "DynamicStorageOpenError::{:?}", self)
+    }
+}
+
+impl std::error::Error for DynamicStorageOpenError {}
+
 enum_gen! {
     DynamicStorageOpenOrCreateError
   mapping:
@@ -388,6 +388,14 @@ where
         let shared_memory_map = unsafe { &mut *self.shared_memory_map.get() };
         shared_memory_map.len()
     }
+
+    fn segment_region(&self, segment_id: SegmentId) -> Option<(usize, usize)> {
+        let key = SlotMapKey::new(segment_id.value() as usize);
+        let shared_memory_map = unsafe { &*self.shared_memory_map.get() };
+        shared_memory_map
+            .get(key)
+            .map(|entry| (entry.shm.payload_start_address(), entry.shm.size()))
+    }
 }
 
 #[derive(Debug)]
@@ -729,6 +737,14 @@ where
         self.state().shared_memory_map.len()
     }
 
+    fn size(&self) -> usize {
+        self.state()
+            .shared_memory_map
+            .iter()
+            .map(|(_, entry)| entry.shm.size())
+            .sum()
+    }
+
     fn allocate(&self, layout: Layout) -> Result<ShmPointer, ResizableShmAllocationError> {
         let msg = "Unable to allocate memory";
         let state = self.state_mut();
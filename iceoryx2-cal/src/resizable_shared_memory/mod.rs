@@ -195,6 +195,10 @@ pub trait ResizableSharedMemoryView<Allocator: ShmAllocator, Shm: SharedMemory<A
 
     /// Returns the number of active [`SharedMemory`] segments.
     fn number_of_active_segments(&self) -> usize;
+
+    /// Returns the payload start address and the payload size of the [`SharedMemory`] segment
+    /// with the provided [`SegmentId`]. If the segment is not mapped, it returns [`None`].
+    fn segment_region(&self, segment_id: SegmentId) -> Option<(usize, usize)>;
 }
 
 /// The [`ResizableSharedMemory`] can be only owned by exactly one process that is allowed to
@@ -222,6 +226,9 @@ pub trait ResizableSharedMemory<Allocator: ShmAllocator, Shm: SharedMemory<Alloc
     /// Returns the number of active [`SharedMemory`] segments.
     fn number_of_active_segments(&self) -> usize;
 
+    /// Returns the sum of the sizes of all active [`SharedMemory`] segments.
+    fn size(&self) -> usize;
+
     /// Allocates a new piece of [`SharedMemory`] if the provided [`Layout`] exceeds the current
     /// supported [`Layout`], the memory would be out-of-memory or the number of chunks exceeds the
     /// current supported amount of chunks, a new [`SharedMemory`] segment will be created. If this
@@ -0,0 +1,45 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Implements [`Serialize`] for JSON files.
+
+use iceoryx2_bb_log::fail;
+
+use crate::serialize::Serialize;
+
+use super::{DeserializeError, SerializeError};
+
+/// json [`Serialize`]
+pub struct Json {}
+
+impl Serialize for Json {
+    fn serialize<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, SerializeError> {
+        match serde_json::to_vec(value) {
+            Ok(buffer) => Ok(buffer),
+            Err(e) => {
+                fail!(from "Json::serialize",
+                with SerializeError::InternalError,
+                    "Failed to serialize object since the error ({}) occurred.", e);
+            }
+        }
+    }
+
+    fn deserialize<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T, DeserializeError> {
+        match serde_json::from_slice(bytes) {
+            Ok(result) => Ok(result),
+            Err(e) => {
+                fail!(from "Json::deserialize",
+                with DeserializeError::InternalError, "Failed to deserialize object ({}).", e);
+            }
+        }
+    }
+}
@@ -31,7 +31,8 @@
 //!     let shm_name = FileName::new(b"myShmName").unwrap();
 //!     let allocator_config = pool_allocator::Config {
 //!         // we want to allocate [`u64`]
-//!         bucket_layout: Layout::new::<u64>()
+//!         bucket_layout: Layout::new::<u64>(),
+//!         ..Default::default()
 //!     };
 //!     let shm = Shm::Builder::new(&shm_name).size(1024).create(&allocator_config).unwrap();
 //!     let mut shm_pointer = shm.allocate(Layout::new::<u64>()).unwrap();
@@ -44,7 +45,8 @@
 //!     let shm_name = FileName::new(b"myShmName").unwrap();
 //!     let allocator_config = pool_allocator::Config {
 //!         // we want to allocate [`u64`]
-//!         bucket_layout: Layout::new::<u64>()
+//!         bucket_layout: Layout::new::<u64>(),
+//!         ..Default::default()
 //!     };
 //!     let shm = Shm::Builder::new(&shm_name).size(1024).open().unwrap();
 //!     let mut shm_pointer = shm.allocate(Layout::new::<u64>()).unwrap();
@@ -19,6 +19,7 @@ use core::{alloc::Layout, fmt::Debug, ptr::NonNull};
 pub use iceoryx2_bb_elementary::allocator::AllocationError;
 use iceoryx2_bb_elementary::{allocator::BaseAllocator, enum_gen};
 pub use pointer_offset::*;
+use serde::{Deserialize, Serialize};
 
 /// Trait that identifies a configuration of a [`ShmAllocator`].
 pub trait ShmAllocatorConfig: Copy + Default + Debug {}
@@ -47,6 +48,21 @@ pub enum AllocationStrategy {
     Static,
 }
 
+/// Describes how a pool based [`ShmAllocator`] hands out its equally sized buckets.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default, Serialize, Deserialize)]
+pub enum PoolAllocationStrategy {
+    /// The free buckets are managed in a lock-free free-list. Buckets can be acquired and
+    /// released concurrently in any order.
+    #[default]
+    FreeList,
+    /// A cursor is bumped over the buckets in a round-robin fashion and acquires the next free
+    /// one. Every bucket has a generation counter that is increased when the bucket is
+    /// acquired and when it is released, an even generation marks a free bucket. Buckets
+    /// are recycled without a free-list which makes it faster when the buckets are mostly
+    /// released in the order they were acquired, like with a single producer.
+    Bump,
+}
+
 /// Describes error that may occur when a [`ShmAllocator`] is initialized.
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub enum ShmAllocatorInitError {
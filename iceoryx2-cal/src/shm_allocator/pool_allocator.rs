@@ -13,24 +13,28 @@
 use core::{alloc::Layout, ptr::NonNull, sync::atomic::Ordering};
 
 use crate::shm_allocator::{ShmAllocator, ShmAllocatorConfig};
-use iceoryx2_bb_elementary::allocator::BaseAllocator;
+use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
 use iceoryx2_bb_log::fail;
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU32, IoxAtomicUsize};
 
 use super::{
-    AllocationStrategy, PointerOffset, SharedMemorySetupHint, ShmAllocationError,
-    ShmAllocatorInitError,
+    AllocationStrategy, PointerOffset, PoolAllocationStrategy, SharedMemorySetupHint,
+    ShmAllocationError, ShmAllocatorInitError,
 };
 
 #[derive(Clone, Copy, Debug)]
 pub struct Config {
     pub bucket_layout: Layout,
+    pub strategy: PoolAllocationStrategy,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
             bucket_layout: unsafe { Layout::from_size_align_unchecked(1024, 8) },
+            strategy: PoolAllocationStrategy::default(),
         }
     }
 }
@@ -46,6 +50,10 @@ pub struct PoolAllocator {
     base_address: usize,
     max_supported_alignment_by_memory: usize,
     number_of_used_buckets: IoxAtomicUsize,
+    strategy: PoolAllocationStrategy,
+    // only used with PoolAllocationStrategy::Bump, an odd generation marks a used bucket
+    generations: RelocatablePointer<IoxAtomicU32>,
+    next_bucket: IoxAtomicU32,
 }
 
 impl PoolAllocator {
@@ -57,14 +65,62 @@ impl PoolAllocator {
         self.allocator.number_of_buckets()
     }
 
+    pub fn strategy(&self) -> PoolAllocationStrategy {
+        self.strategy
+    }
+
     /// # Safety
     ///
     ///  * provided [`PointerOffset`] must be allocated with [`PoolAllocator::allocate()`]
     pub unsafe fn deallocate_bucket(&self, offset: PointerOffset) {
         self.number_of_used_buckets.fetch_sub(1, Ordering::Relaxed);
-        self.allocator.deallocate_bucket(NonNull::new_unchecked(
-            (offset.offset() + self.allocator.start_address()) as *mut u8,
-        ));
+        match self.strategy {
+            PoolAllocationStrategy::FreeList => {
+                self.allocator.deallocate_bucket(NonNull::new_unchecked(
+                    (offset.offset() + self.allocator.start_address()) as *mut u8,
+                ))
+            }
+            PoolAllocationStrategy::Bump => {
+                self.generation(offset.offset() / self.allocator.bucket_size())
+                    .fetch_add(1, Ordering::Release);
+            }
+        }
+    }
+
+    fn generation(&self, index: usize) -> &IoxAtomicU32 {
+        debug_assert!(index < self.number_of_buckets() as usize);
+        unsafe { &*self.generations.as_ptr().add(index) }
+    }
+
+    fn acquire_bucket_index(&self) -> Option<usize> {
+        let number_of_buckets = self.number_of_buckets() as usize;
+        let start = self.next_bucket.load(Ordering::Relaxed) as usize;
+
+        for n in 0..number_of_buckets {
+            let index = (start + n) % number_of_buckets;
+            let generation = self.generation(index);
+            let current = generation.load(Ordering::Relaxed);
+            if current % 2 == 0
+                && generation
+                    .compare_exchange(
+                        current,
+                        current.wrapping_add(1),
+                        Ordering::Acquire,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+            {
+                self.next_bucket
+                    .store(((index + 1) % number_of_buckets) as u32, Ordering::Relaxed);
+                return Some(index);
+            }
+        }
+
+        None
+    }
+
+    fn bump_management_size(number_of_buckets: usize) -> usize {
+        (number_of_buckets + 1) * core::mem::size_of::<IoxAtomicU32>()
     }
 }
 
@@ -137,15 +193,23 @@ impl ShmAllocator for PoolAllocator {
             payload_size: max_chunk_layout.size() * max_number_of_chunks,
             config: Self::Configuration {
                 bucket_layout: max_chunk_layout,
+                strategy: PoolAllocationStrategy::default(),
             },
         }
     }
 
     fn management_size(memory_size: usize, config: &Self::Configuration) -> usize {
-        iceoryx2_bb_memory::pool_allocator::PoolAllocator::memory_size(
-            config.bucket_layout,
-            memory_size,
-        )
+        match config.strategy {
+            PoolAllocationStrategy::FreeList => {
+                iceoryx2_bb_memory::pool_allocator::PoolAllocator::memory_size(
+                    config.bucket_layout,
+                    memory_size,
+                )
+            }
+            PoolAllocationStrategy::Bump => {
+                Self::bump_management_size(memory_size / config.bucket_layout.size())
+            }
+        }
     }
 
     fn relative_start_address(&self) -> usize {
@@ -166,6 +230,9 @@ impl ShmAllocator for PoolAllocator {
             base_address: (managed_memory.as_ptr() as *mut u8) as usize,
             max_supported_alignment_by_memory,
             number_of_used_buckets: IoxAtomicUsize::new(0),
+            strategy: config.strategy,
+            generations: RelocatablePointer::new_uninit(),
+            next_bucket: IoxAtomicU32::new(0),
         }
     }
 
@@ -184,9 +251,27 @@ impl ShmAllocator for PoolAllocator {
                 msg, self.max_alignment(), self.max_supported_alignment_by_memory);
         }
 
-        fail!(from self, when self.allocator.init(mgmt_allocator),
-            with ShmAllocatorInitError::AllocationFailed,
-            "{} since the allocation of the allocator managment memory failed.", msg);
+        match self.strategy {
+            PoolAllocationStrategy::FreeList => {
+                fail!(from self, when self.allocator.init(mgmt_allocator),
+                    with ShmAllocatorInitError::AllocationFailed,
+                    "{} since the allocation of the allocator managment memory failed.", msg);
+            }
+            PoolAllocationStrategy::Bump => {
+                let number_of_buckets = self.number_of_buckets() as usize;
+                let memory = fail!(from self, when mgmt_allocator.allocate(Layout::from_size_align_unchecked(
+                        number_of_buckets * core::mem::size_of::<IoxAtomicU32>(),
+                        core::mem::align_of::<IoxAtomicU32>())),
+                    with ShmAllocatorInitError::AllocationFailed,
+                    "{} since the allocation of the generation counters failed.", msg);
+                self.generations.init(memory);
+
+                let generations = self.generations.as_ptr() as *mut IoxAtomicU32;
+                for n in 0..number_of_buckets {
+                    generations.add(n).write(IoxAtomicU32::new(0));
+                }
+            }
+        }
         Ok(())
     }
 
@@ -202,11 +287,31 @@ impl ShmAllocator for PoolAllocator {
                 msg, layout.align(), self.max_alignment());
         }
 
-        let chunk = fail!(from self, when self.allocator.allocate(layout), "{}.", msg);
+        let offset = match self.strategy {
+            PoolAllocationStrategy::FreeList => {
+                let chunk = fail!(from self, when self.allocator.allocate(layout), "{}.", msg);
+                (chunk.as_ptr() as *const u8) as usize - self.allocator.start_address()
+            }
+            PoolAllocationStrategy::Bump => {
+                if layout.size() > self.bucket_size() {
+                    fail!(from self, with AllocationError::SizeTooLarge.into(),
+                        "{} since the requested size {} is greater than the maximum supported size of {}.",
+                        msg, layout.size(), self.bucket_size());
+                }
+
+                match self.acquire_bucket_index() {
+                    Some(index) => index * self.bucket_size(),
+                    None => {
+                        fail!(from self, with AllocationError::OutOfMemory.into(),
+                            "{} since no more buckets are available to allocate {} bytes with an alignment of {}.",
+                            msg, layout.size(), layout.align());
+                    }
+                }
+            }
+        };
+
         self.number_of_used_buckets.fetch_add(1, Ordering::Relaxed);
-        Ok(PointerOffset::new(
-            (chunk.as_ptr() as *const u8) as usize - self.allocator.start_address(),
-        ))
+        Ok(PointerOffset::new(offset))
     }
 
     unsafe fn deallocate(&self, offset: PointerOffset, _layout: Layout) {
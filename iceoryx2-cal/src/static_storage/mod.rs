@@ -34,6 +34,14 @@ pub enum StaticStorageCreateError {
     InternalError,
 }
 
+impl core::fmt::Display for StaticStorageCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "StaticStorageCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for StaticStorageCreateError {}
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum StaticStorageOpenError {
     DoesNotExist,
@@ -42,6 +50,14 @@ pub enum StaticStorageOpenError {
     InternalError,
 }
 
+impl core::fmt::Display for StaticStorageOpenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "StaticStorageOpenError::{:?}", self)
+    }
+}
+
+impl std::error::Error for StaticStorageOpenError {}
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum StaticStorageReadError {
     BufferTooSmall,
@@ -50,6 +66,14 @@ pub enum StaticStorageReadError {
     CreationNotComplete,
 }
 
+impl core::fmt::Display for StaticStorageReadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "StaticStorageReadError::{:?}", self)
+    }
+}
+
+impl std::error::Error for StaticStorageReadError {}
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum StaticStorageUnlockError {
     InsufficientPermissions,
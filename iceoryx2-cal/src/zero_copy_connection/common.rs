@@ -191,6 +191,8 @@ pub mod details {
         number_of_segments: u8,
         state: IoxAtomicU8,
         init_state: IoxAtomicU64,
+        acknowledgment: IoxAtomicU64,
+        history_end: IoxAtomicU64,
         enable_safe_overflow: bool,
     }
 
@@ -215,6 +217,8 @@ pub mod details {
                 segment_details: unsafe { RelocatableVec::new_uninit(number_of_segments as usize) },
                 state: IoxAtomicU8::new(State::None.value()),
                 init_state: IoxAtomicU64::new(0),
+                acknowledgment: IoxAtomicU64::new(0),
+                history_end: IoxAtomicU64::new(0),
                 enable_safe_overflow,
                 max_borrowed_samples,
                 number_of_samples_per_segment,
@@ -613,6 +617,26 @@ pub mod details {
             self.try_send(ptr, sample_size)
         }
 
+        fn would_block(&self) -> bool {
+            let storage = self.storage.get();
+            !storage.enable_safe_overflow && storage.submission_channel.is_full()
+        }
+
+        fn number_of_queued_samples(&self) -> usize {
+            self.storage.get().submission_channel.len()
+        }
+
+        fn last_acknowledgment(&self) -> u64 {
+            self.storage.get().acknowledgment.load(Ordering::Relaxed)
+        }
+
+        fn mark_history_end(&self, value: u64) {
+            self.storage
+                .get()
+                .history_end
+                .fetch_max(value, Ordering::Release);
+        }
+
         fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
             let msg = "Unable to reclaim sample";
 
@@ -736,6 +760,17 @@ pub mod details {
             }
         }
 
+        fn acknowledge(&self, value: u64) {
+            self.storage
+                .get()
+                .acknowledgment
+                .fetch_max(value, Ordering::Relaxed);
+        }
+
+        fn history_end(&self) -> u64 {
+            self.storage.get().history_end.load(Ordering::Acquire)
+        }
+
         fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError> {
             match unsafe { self.storage.get().completion_channel.push(ptr.as_value()) } {
                 true => {
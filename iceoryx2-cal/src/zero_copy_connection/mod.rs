@@ -152,6 +152,24 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
         sample_size: usize,
     ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
 
+    /// Returns true when the receive buffer is full and safe overflow is disabled, meaning
+    /// that [`ZeroCopySender::blocking_send()`] would block and [`ZeroCopySender::try_send()`]
+    /// would fail with [`ZeroCopySendError::ReceiveBufferFull`].
+    fn would_block(&self) -> bool;
+
+    /// Returns the number of samples that were sent but not yet received. The value may be
+    /// out-of-date as soon as it is acquired since the receiver operates concurrently.
+    fn number_of_queued_samples(&self) -> usize;
+
+    /// Returns the largest value the receiver has passed to [`ZeroCopyReceiver::acknowledge()`]
+    /// or `0` when nothing was acknowledged so far.
+    fn last_acknowledgment(&self) -> u64;
+
+    /// Announces to the receiver that all samples up to `value` were sent before it was
+    /// connected. Must be called before they are sent. Smaller values than the last announced
+    /// one are ignored.
+    fn mark_history_end(&self, value: u64);
+
     fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError>;
 
     /// # Safety
@@ -166,6 +184,14 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
 pub trait ZeroCopyReceiver: Debug + ZeroCopyPortDetails + NamedConcept {
     fn has_data(&self) -> bool;
     fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError>;
+
+    /// Signals the sender that all samples up to `value` were consumed. Smaller values than
+    /// the last acknowledged one are ignored.
+    fn acknowledge(&self, value: u64);
+
+    /// Returns the largest value the sender has passed to [`ZeroCopySender::mark_history_end()`]
+    /// or `0` when nothing was announced so far.
+    fn history_end(&self) -> u64;
     fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError>;
 }
 
@@ -109,6 +109,31 @@ mod resizable_shared_memory {
         assert_that!(sut.number_of_active_segments(), eq 4);
     }
 
+    #[test]
+    fn size_contains_all_active_segments<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(128)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        sut.allocate(Layout::new::<u8>()).unwrap();
+        let initial_size = sut.size();
+        assert_that!(initial_size, gt 0);
+
+        sut.allocate(Layout::new::<u64>()).unwrap();
+        assert_that!(sut.number_of_active_segments(), eq 2);
+        assert_that!(sut.size(), gt initial_size);
+    }
+
     #[test]
     fn allocate_more_than_hinted_works<
         Shm: SharedMemory<DefaultAllocator>,
@@ -43,4 +43,7 @@ mod serialize {
 
     #[instantiate_tests(<iceoryx2_cal::serialize::cdr::Cdr>)]
     mod cdr {}
+
+    #[instantiate_tests(<iceoryx2_cal::serialize::json::Json>)]
+    mod json {}
 }
@@ -22,7 +22,9 @@ mod shared_memory {
     use iceoryx2_cal::{
         named_concept::NamedConceptBuilder,
         shared_memory::*,
-        shm_allocator::{pool_allocator::PoolAllocator, ShmAllocationError, ShmAllocator},
+        shm_allocator::{
+            pool_allocator::PoolAllocator, PoolAllocationStrategy, ShmAllocationError, ShmAllocator,
+        },
     };
 
     type DefaultAllocator = PoolAllocator;
@@ -34,6 +36,7 @@ mod shared_memory {
     const DEFAULT_LAYOUT: Layout = unsafe { Layout::from_size_align_unchecked(CHUNK_SIZE, 1) };
     const SHM_CONFIG: AllocatorConfig = AllocatorConfig {
         bucket_layout: DEFAULT_LAYOUT,
+        strategy: PoolAllocationStrategy::FreeList,
     };
 
     #[test]
@@ -202,6 +205,50 @@ mod shared_memory {
         assert_that!(chunk, is_ok);
     }
 
+    #[test]
+    fn allocation_with_bump_strategy_and_client_works<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        let shm_config = AllocatorConfig {
+            bucket_layout: DEFAULT_LAYOUT,
+            strategy: PoolAllocationStrategy::Bump,
+        };
+        let mut chunks = vec![];
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .create(&shm_config)
+            .unwrap();
+
+        for _ in 0..NUMBER_OF_CHUNKS / 2 {
+            let chunk = sut_create.allocate(DEFAULT_LAYOUT);
+            assert_that!(chunk, is_ok);
+            chunks.push(chunk.unwrap());
+        }
+
+        let sut_open = Sut::Builder::new(&name).config(&config).open().unwrap();
+        for _ in 0..NUMBER_OF_CHUNKS / 2 {
+            let chunk = sut_open.allocate(DEFAULT_LAYOUT);
+            assert_that!(chunk, is_ok);
+            chunks.push(chunk.unwrap());
+        }
+
+        let chunk = sut_open.allocate(DEFAULT_LAYOUT);
+        assert_that!(
+            chunk.err(), eq
+            Some(ShmAllocationError::AllocationError(AllocationError::OutOfMemory))
+        );
+
+        unsafe {
+            sut_create.deallocate(chunks[3].offset, DEFAULT_LAYOUT);
+        }
+
+        let chunk = sut_open.allocate(DEFAULT_LAYOUT);
+        assert_that!(chunk, is_ok);
+        assert_that!(chunk.unwrap().offset, eq chunks[3].offset);
+    }
+
     #[test]
     fn allocated_chunks_have_correct_alignment<Sut: SharedMemory<DefaultAllocator>>() {
         let name = generate_name();
@@ -211,6 +258,7 @@ mod shared_memory {
             let layout = unsafe { Layout::from_size_align_unchecked(128, 2_usize.pow(i)) };
             let shm_config = AllocatorConfig {
                 bucket_layout: layout,
+                ..Default::default()
             };
 
             for n in 0..=i {
@@ -18,7 +18,10 @@ mod shm_allocator_pool_allocator {
     use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_cal::{
-        shm_allocator::{pool_allocator::*, AllocationStrategy, ShmAllocationError, ShmAllocator},
+        shm_allocator::{
+            pool_allocator::*, AllocationStrategy, PoolAllocationStrategy, ShmAllocationError,
+            ShmAllocator,
+        },
         zero_copy_connection::PointerOffset,
     };
 
@@ -35,6 +38,10 @@ mod shm_allocator_pool_allocator {
 
     impl TestContext {
         fn new(bucket_layout: Layout) -> Self {
+            Self::new_with_strategy(bucket_layout, PoolAllocationStrategy::FreeList)
+        }
+
+        fn new_with_strategy(bucket_layout: Layout, strategy: PoolAllocationStrategy) -> Self {
             let mut payload_memory = Box::new([0u8; MEM_SIZE]);
             let base_address =
                 unsafe { NonNull::<[u8]>::new_unchecked(&mut payload_memory[0..PAYLOAD_SIZE]) };
@@ -42,7 +49,10 @@ mod shm_allocator_pool_allocator {
                 unsafe { NonNull::new_unchecked(payload_memory[PAYLOAD_SIZE..].as_mut_ptr()) },
                 MEM_SIZE,
             );
-            let config = &Config { bucket_layout };
+            let config = &Config {
+                bucket_layout,
+                strategy,
+            };
             let mut sut = Box::new(unsafe {
                 PoolAllocator::new_uninit(MAX_SUPPORTED_ALIGNMENT, base_address, config)
             });
@@ -179,10 +189,9 @@ mod shm_allocator_pool_allocator {
         assert_that!(hint.payload_size, eq increased_layout.size() * (test_context.sut.number_of_buckets() + 1) as usize);
     }
 
-    #[test]
-    fn allocate_and_release_all_buckets_works() {
+    fn allocate_and_release_all_buckets_works_with(strategy: PoolAllocationStrategy) {
         const REPETITIONS: usize = 10;
-        let test_context = TestContext::new(BUCKET_CONFIG);
+        let test_context = TestContext::new_with_strategy(BUCKET_CONFIG, strategy);
 
         for _ in 0..REPETITIONS {
             let mut mem_set = HashSet::new();
@@ -206,9 +215,20 @@ mod shm_allocator_pool_allocator {
     }
 
     #[test]
-    fn allocate_twice_release_once_until_memory_is_exhausted_works() {
+    fn allocate_and_release_all_buckets_works() {
+        allocate_and_release_all_buckets_works_with(PoolAllocationStrategy::FreeList)
+    }
+
+    #[test]
+    fn allocate_and_release_all_buckets_with_bump_strategy_works() {
+        allocate_and_release_all_buckets_works_with(PoolAllocationStrategy::Bump)
+    }
+
+    fn allocate_twice_release_once_until_memory_is_exhausted_works_with(
+        strategy: PoolAllocationStrategy,
+    ) {
         const REPETITIONS: usize = 10;
-        let test_context = TestContext::new(BUCKET_CONFIG);
+        let test_context = TestContext::new_with_strategy(BUCKET_CONFIG, strategy);
 
         for _ in 0..REPETITIONS {
             let mut mem_set = HashSet::new();
@@ -244,6 +264,65 @@ mod shm_allocator_pool_allocator {
         }
     }
 
+    #[test]
+    fn allocate_twice_release_once_until_memory_is_exhausted_works() {
+        allocate_twice_release_once_until_memory_is_exhausted_works_with(
+            PoolAllocationStrategy::FreeList,
+        )
+    }
+
+    #[test]
+    fn allocate_twice_release_once_until_memory_is_exhausted_with_bump_strategy_works() {
+        allocate_twice_release_once_until_memory_is_exhausted_works_with(
+            PoolAllocationStrategy::Bump,
+        )
+    }
+
+    #[test]
+    fn bump_strategy_recycles_buckets_in_acquisition_order() {
+        let test_context =
+            TestContext::new_with_strategy(BUCKET_CONFIG, PoolAllocationStrategy::Bump);
+        let number_of_buckets = test_context.sut.number_of_buckets() as usize;
+
+        for n in 0..3 * number_of_buckets {
+            let memory = unsafe { test_context.sut.allocate(BUCKET_CONFIG).unwrap() };
+            assert_that!(
+                memory.offset(),
+                eq(n % number_of_buckets) * BUCKET_CONFIG.size()
+            );
+            unsafe { test_context.sut.deallocate(memory, BUCKET_CONFIG) };
+        }
+    }
+
+    #[test]
+    fn bump_strategy_skips_buckets_that_are_still_in_use() {
+        let test_context =
+            TestContext::new_with_strategy(BUCKET_CONFIG, PoolAllocationStrategy::Bump);
+        let number_of_buckets = test_context.sut.number_of_buckets() as usize;
+
+        let mut memory = vec![];
+        for _ in 0..number_of_buckets {
+            memory.push(unsafe { test_context.sut.allocate(BUCKET_CONFIG).unwrap() });
+        }
+
+        unsafe { test_context.sut.deallocate(memory[5], BUCKET_CONFIG) };
+        unsafe { test_context.sut.deallocate(memory[2], BUCKET_CONFIG) };
+
+        let first = unsafe { test_context.sut.allocate(BUCKET_CONFIG).unwrap() };
+        let second = unsafe { test_context.sut.allocate(BUCKET_CONFIG).unwrap() };
+        assert_that!(first, eq memory[2]);
+        assert_that!(second, eq memory[5]);
+        assert_that!(unsafe { test_context.sut.allocate(BUCKET_CONFIG) }, eq Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)));
+    }
+
+    #[test]
+    fn bump_strategy_allocate_with_unsupported_size_fails() {
+        let test_context =
+            TestContext::new_with_strategy(BUCKET_CONFIG, PoolAllocationStrategy::Bump);
+        let layout = Layout::from_size_align(BUCKET_CONFIG.size() + 1, 1).unwrap();
+        assert_that!(unsafe { test_context.sut.allocate(layout) }, eq Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge)));
+    }
+
     #[test]
     fn allocated_memory_has_correct_alignment_uniform_alignment_case() {
         for i in 0..12 {
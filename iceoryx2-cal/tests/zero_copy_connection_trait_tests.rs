@@ -392,6 +392,146 @@ mod zero_copy_connection {
         assert_that!(result.err().unwrap(), eq ZeroCopySendError::ReceiveBufferFull);
     }
 
+    #[test]
+    fn sender_would_block_when_buffer_is_full<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 12;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(sut_sender.would_block(), eq false);
+            let sample_offset = SAMPLE_SIZE * i;
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
+                is_ok
+            );
+        }
+
+        assert_that!(sut_sender.would_block(), eq true);
+    }
+
+    #[test]
+    fn number_of_queued_samples_is_tracked_correctly<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 12;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(sut_sender.number_of_queued_samples(), eq i);
+            let sample_offset = SAMPLE_SIZE * i;
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
+                is_ok
+            );
+        }
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(sut_sender.number_of_queued_samples(), eq BUFFER_SIZE - i);
+            let sample = sut_receiver.receive().unwrap().unwrap();
+            assert_that!(sut_receiver.release(sample), is_ok);
+        }
+
+        assert_that!(sut_sender.number_of_queued_samples(), eq 0);
+    }
+
+    #[test]
+    fn acknowledgment_is_forwarded_to_sender<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.last_acknowledgment(), eq 0);
+
+        sut_receiver.acknowledge(5);
+        assert_that!(sut_sender.last_acknowledgment(), eq 5);
+
+        sut_receiver.acknowledge(3);
+        assert_that!(sut_sender.last_acknowledgment(), eq 5);
+
+        sut_receiver.acknowledge(8);
+        assert_that!(sut_sender.last_acknowledgment(), eq 8);
+    }
+
+    #[test]
+    fn history_end_is_forwarded_to_receiver<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_receiver.history_end(), eq 0);
+
+        sut_sender.mark_history_end(7);
+        assert_that!(sut_receiver.history_end(), eq 7);
+
+        sut_sender.mark_history_end(2);
+        assert_that!(sut_receiver.history_end(), eq 7);
+    }
+
+    #[test]
+    fn sender_with_safe_overflow_never_blocks<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 12;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .enable_safe_overflow(true)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            let sample_offset = SAMPLE_SIZE * i;
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
+                is_ok
+            );
+        }
+
+        assert_that!(sut_sender.would_block(), eq false);
+    }
+
     #[test]
     fn send_until_overflow_works<Sut: ZeroCopyConnection>() {
         let name = generate_name();
@@ -18,6 +18,7 @@
 #include "iox/path.hpp"
 #include "iox2/config_creation_error.hpp"
 #include "iox2/internal/iceoryx2.hpp"
+#include "iox2/static_config_format.hpp"
 #include "iox2/unable_to_deliver_strategy.hpp"
 
 namespace iox2 {
@@ -58,6 +59,17 @@ class Node {
     auto cleanup_dead_nodes_on_destruction() && -> bool;
     /// Enable/disable the cleanup dead nodes on destruction
     void set_cleanup_dead_nodes_on_destruction(bool value) &&;
+    /// When true, [`Node::wait()`](Node::wait()) removes all ports of the
+    /// services the [`Node`](Node) is registered at whose owning process is no
+    /// longer alive.
+    auto reap_dead_ports_on_wait() && -> bool;
+    /// Enable/disable the reaping of dead ports on wait
+    void set_reap_dead_ports_on_wait(bool value) &&;
+    /// The minimum time between two reapings of dead ports in
+    /// [`Node::wait()`](Node::wait()).
+    auto reap_dead_ports_interval() && -> iox::units::Duration;
+    /// Set the minimum time between two reapings of dead ports
+    void set_reap_dead_ports_interval(const iox::units::Duration& value) &&;
 
   private:
     friend class Global;
@@ -90,6 +102,15 @@ class Service {
     auto creation_timeout() && -> iox::units::Duration;
     /// Set the creation timeout
     void set_creation_timeout(const iox::units::Duration& value) &&;
+    /// Defines how often it is tried again to open the dynamic config of a
+    /// service whose creator has not yet finished the initialization
+    auto dynamic_config_open_retries() && -> size_t;
+    /// Set how often it is tried again to open the dynamic config of a service
+    void set_dynamic_config_open_retries(size_t value) &&;
+    /// The time between two attempts to open the dynamic config of a service
+    auto dynamic_config_open_retry_delay() && -> iox::units::Duration;
+    /// Set the time between two attempts to open the dynamic config of a service
+    void set_dynamic_config_open_retry_delay(const iox::units::Duration& value) &&;
     /// The suffix of a one-to-one connection
     auto connection_suffix() && -> const char*;
     /// Set the suffix of a one-to-one connection
@@ -98,6 +119,20 @@ class Service {
     auto event_connection_suffix() && -> const char*;
     /// Set the suffix of a one-to-one connection
     void set_event_connection_suffix(const iox::FileName& value) &&;
+    /// When true, service names are converted to lowercase before they are mapped to the
+    /// resources of a service. Changing it is not retroactive for existing services.
+    auto case_insensitive_names() && -> bool;
+    /// Enable/disable the case insensitive mapping of service names
+    void set_case_insensitive_names(bool value) &&;
+    /// The format in which the static configuration of a newly created service is stored
+    auto static_config_format() && -> StaticConfigFormat;
+    /// Define the format in which the static configuration of a newly created service is stored
+    void set_static_config_format(StaticConfigFormat value) &&;
+    /// When true, opening an existing service removes all ports whose owning process is no
+    /// longer alive.
+    auto cleanup_dead_ports_on_open() && -> bool;
+    /// Enable/disable the cleanup of dead ports when a service is opened
+    void set_cleanup_dead_ports_on_open(bool value) &&;
 
   private:
     friend class Global;
@@ -183,6 +218,11 @@ class PublishSubscribe {
     auto subscriber_expired_connection_buffer() && -> size_t;
     /// Set the expired connection buffer size
     void set_subscriber_expired_connection_buffer(size_t value) &&;
+    /// The maximum number of sample slots a [`Service`] may require in the
+    /// worst case, summed up over the data segments of all [`Publisher`]s.
+    auto max_sample_slots() && -> size_t;
+    /// Set the maximum number of sample slots
+    void set_max_sample_slots(size_t value) &&;
 
   private:
     friend class Defaults;
@@ -28,6 +28,10 @@ enum class ConfigCreationError : uint8_t {
     ConfigFileDoesNotExist,
     /// The config file could not be opened due to an internal error
     UnableToOpenConfigFile,
+    /// An environment variable that overrides a setting contains a value that cannot be parsed.
+    MalformedEnvironmentVariable,
+    /// The loaded settings are invalid.
+    InvalidConfig,
 };
 
 } // namespace iox2
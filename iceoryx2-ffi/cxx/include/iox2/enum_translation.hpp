@@ -33,6 +33,7 @@
 #include "iox2/service_error_enums.hpp"
 #include "iox2/service_type.hpp"
 #include "iox2/signal_handling_mode.hpp"
+#include "iox2/static_config_format.hpp"
 #include "iox2/subscriber_error.hpp"
 #include "iox2/type_variant.hpp"
 #include "iox2/unable_to_deliver_strategy.hpp"
@@ -106,6 +107,8 @@ constexpr auto from<int, iox2::NodeCreationFailure>(const int value) noexcept ->
         return iox2::NodeCreationFailure::InsufficientPermissions;
     case iox2_node_creation_failure_e_INTERNAL_ERROR:
         return iox2::NodeCreationFailure::InternalError;
+    case iox2_node_creation_failure_e_INVALID_CONFIG:
+        return iox2::NodeCreationFailure::InvalidConfig;
     }
 
     IOX_UNREACHABLE();
@@ -120,6 +123,8 @@ from<iox2::NodeCreationFailure, iox2_node_creation_failure_e>(const iox2::NodeCr
         return iox2_node_creation_failure_e_INSUFFICIENT_PERMISSIONS;
     case iox2::NodeCreationFailure::InternalError:
         return iox2_node_creation_failure_e_INTERNAL_ERROR;
+    case iox2::NodeCreationFailure::InvalidConfig:
+        return iox2_node_creation_failure_e_INVALID_CONFIG;
     }
 
     IOX_UNREACHABLE();
@@ -201,6 +206,8 @@ constexpr auto from<int, iox2::NodeWaitFailure>(const int value) noexcept -> iox
         return iox2::NodeWaitFailure::TerminationRequest;
     case iox2_node_wait_failure_e_INTERRUPT:
         return iox2::NodeWaitFailure::Interrupt;
+    case iox2_node_wait_failure_e_INTERNAL_ERROR:
+        return iox2::NodeWaitFailure::InternalError;
     }
 
     IOX_UNREACHABLE();
@@ -214,6 +221,8 @@ constexpr auto from<iox2::NodeWaitFailure, iox2_node_wait_failure_e>(const iox2:
         return iox2_node_wait_failure_e_TERMINATION_REQUEST;
     case iox2::NodeWaitFailure::Interrupt:
         return iox2_node_wait_failure_e_INTERRUPT;
+    case iox2::NodeWaitFailure::InternalError:
+        return iox2_node_wait_failure_e_INTERNAL_ERROR;
     }
 
     IOX_UNREACHABLE();
@@ -560,6 +569,20 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_LAST_VALUE_MODE:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleLastValueMode;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_POOL_ALLOCATION_STRATEGY:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatiblePoolAllocationStrategy;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_COALESCING_WINDOW:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleCoalescingWindow;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SLOW_SUBSCRIBER_EVICTION:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleSlowSubscriberEviction;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SAMPLE_MEMORY_PINNING:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleSampleMemoryPinning;
+    case iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_MESSAGE_SIZE:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedMaxMessageSize;
+    case iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_POOL_CAPACITY:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedPoolCapacity;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenOrCreateError::OpenInsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -570,11 +593,23 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_STALE_SERVICE_HANDLE:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenStaleServiceHandle;
 
     case iox2_pub_sub_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE:
         return iox2::PublishSubscribeOpenOrCreateError::CreateServiceInCorruptedState;
     case iox2_pub_sub_open_or_create_error_e_C_SUBSCRIBER_BUFFER_MUST_BE_LARGER_THAN_HISTORY_SIZE:
         return iox2::PublishSubscribeOpenOrCreateError::CreateSubscriberBufferMustBeLargerThanHistorySize;
+    case iox2_pub_sub_open_or_create_error_e_C_PAYLOAD_TYPE_HAS_ZERO_SIZE:
+        return iox2::PublishSubscribeOpenOrCreateError::CreatePayloadTypeHasZeroSize;
+    case iox2_pub_sub_open_or_create_error_e_C_EXCEEDS_MAX_SAMPLE_SLOTS:
+        return iox2::PublishSubscribeOpenOrCreateError::CreateExceedsMaxSampleSlots;
+    case iox2_pub_sub_open_or_create_error_e_C_POOL_CAPACITY_BELOW_REQUIRED_MINIMUM:
+        return iox2::PublishSubscribeOpenOrCreateError::CreatePoolCapacityBelowRequiredMinimum;
+    case iox2_pub_sub_open_or_create_error_e_C_SUBSCRIBER_MAX_BUFFER_SIZE_IS_ZERO:
+        return iox2::PublishSubscribeOpenOrCreateError::CreateSubscriberMaxBufferSizeIsZero;
+    case iox2_pub_sub_open_or_create_error_e_C_SUBSCRIBER_MAX_BORROWED_SAMPLES_IS_ZERO:
+        return iox2::PublishSubscribeOpenOrCreateError::CreateSubscriberMaxBorrowedSamplesIsZero;
     case iox2_pub_sub_open_or_create_error_e_C_ALREADY_EXISTS:
         return iox2::PublishSubscribeOpenOrCreateError::CreateAlreadyExists;
     case iox2_pub_sub_open_or_create_error_e_C_INSUFFICIENT_PERMISSIONS:
@@ -622,6 +657,20 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenError::IncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_LAST_VALUE_MODE:
+        return iox2::PublishSubscribeOpenError::IncompatibleLastValueMode;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_POOL_ALLOCATION_STRATEGY:
+        return iox2::PublishSubscribeOpenError::IncompatiblePoolAllocationStrategy;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_COALESCING_WINDOW:
+        return iox2::PublishSubscribeOpenError::IncompatibleCoalescingWindow;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SLOW_SUBSCRIBER_EVICTION:
+        return iox2::PublishSubscribeOpenError::IncompatibleSlowSubscriberEviction;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SAMPLE_MEMORY_PINNING:
+        return iox2::PublishSubscribeOpenError::IncompatibleSampleMemoryPinning;
+    case iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_MESSAGE_SIZE:
+        return iox2::PublishSubscribeOpenError::DoesNotSupportRequestedMaxMessageSize;
+    case iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_POOL_CAPACITY:
+        return iox2::PublishSubscribeOpenError::DoesNotSupportRequestedPoolCapacity;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenError::InsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -632,6 +681,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::ExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenError::IsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_STALE_SERVICE_HANDLE:
+        return iox2::PublishSubscribeOpenError::StaleServiceHandle;
     default:
         IOX_UNREACHABLE();
     }
@@ -665,6 +716,20 @@ constexpr auto from<iox2::PublishSubscribeOpenError, iox2_pub_sub_open_or_create
         return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::PublishSubscribeOpenError::IncompatibleOverflowBehavior:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR;
+    case iox2::PublishSubscribeOpenError::IncompatibleLastValueMode:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_LAST_VALUE_MODE;
+    case iox2::PublishSubscribeOpenError::IncompatiblePoolAllocationStrategy:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_POOL_ALLOCATION_STRATEGY;
+    case iox2::PublishSubscribeOpenError::IncompatibleCoalescingWindow:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_COALESCING_WINDOW;
+    case iox2::PublishSubscribeOpenError::IncompatibleSlowSubscriberEviction:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SLOW_SUBSCRIBER_EVICTION;
+    case iox2::PublishSubscribeOpenError::IncompatibleSampleMemoryPinning:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SAMPLE_MEMORY_PINNING;
+    case iox2::PublishSubscribeOpenError::DoesNotSupportRequestedMaxMessageSize:
+        return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_MESSAGE_SIZE;
+    case iox2::PublishSubscribeOpenError::DoesNotSupportRequestedPoolCapacity:
+        return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_POOL_CAPACITY;
     case iox2::PublishSubscribeOpenError::InsufficientPermissions:
         return iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::PublishSubscribeOpenError::ServiceInCorruptedState:
@@ -675,6 +740,8 @@ constexpr auto from<iox2::PublishSubscribeOpenError, iox2_pub_sub_open_or_create
         return iox2_pub_sub_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::PublishSubscribeOpenError::IsMarkedForDestruction:
         return iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::PublishSubscribeOpenError::StaleServiceHandle:
+        return iox2_pub_sub_open_or_create_error_e_O_STALE_SERVICE_HANDLE;
     default:
         IOX_UNREACHABLE();
     }
@@ -695,6 +762,16 @@ constexpr auto from<int, iox2::PublishSubscribeCreateError>(const int value) noe
         return iox2::PublishSubscribeCreateError::ServiceInCorruptedState;
     case iox2_pub_sub_open_or_create_error_e_C_SUBSCRIBER_BUFFER_MUST_BE_LARGER_THAN_HISTORY_SIZE:
         return iox2::PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize;
+    case iox2_pub_sub_open_or_create_error_e_C_PAYLOAD_TYPE_HAS_ZERO_SIZE:
+        return iox2::PublishSubscribeCreateError::PayloadTypeHasZeroSize;
+    case iox2_pub_sub_open_or_create_error_e_C_EXCEEDS_MAX_SAMPLE_SLOTS:
+        return iox2::PublishSubscribeCreateError::ExceedsMaxSampleSlots;
+    case iox2_pub_sub_open_or_create_error_e_C_POOL_CAPACITY_BELOW_REQUIRED_MINIMUM:
+        return iox2::PublishSubscribeCreateError::PoolCapacityBelowRequiredMinimum;
+    case iox2_pub_sub_open_or_create_error_e_C_SUBSCRIBER_MAX_BUFFER_SIZE_IS_ZERO:
+        return iox2::PublishSubscribeCreateError::SubscriberMaxBufferSizeIsZero;
+    case iox2_pub_sub_open_or_create_error_e_C_SUBSCRIBER_MAX_BORROWED_SAMPLES_IS_ZERO:
+        return iox2::PublishSubscribeCreateError::SubscriberMaxBorrowedSamplesIsZero;
     case iox2_pub_sub_open_or_create_error_e_C_ALREADY_EXISTS:
         return iox2::PublishSubscribeCreateError::AlreadyExists;
     case iox2_pub_sub_open_or_create_error_e_C_INSUFFICIENT_PERMISSIONS:
@@ -718,6 +795,16 @@ constexpr auto from<iox2::PublishSubscribeCreateError, iox2_pub_sub_open_or_crea
         return iox2_pub_sub_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE;
     case iox2::PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize:
         return iox2_pub_sub_open_or_create_error_e_C_SUBSCRIBER_BUFFER_MUST_BE_LARGER_THAN_HISTORY_SIZE;
+    case iox2::PublishSubscribeCreateError::PayloadTypeHasZeroSize:
+        return iox2_pub_sub_open_or_create_error_e_C_PAYLOAD_TYPE_HAS_ZERO_SIZE;
+    case iox2::PublishSubscribeCreateError::ExceedsMaxSampleSlots:
+        return iox2_pub_sub_open_or_create_error_e_C_EXCEEDS_MAX_SAMPLE_SLOTS;
+    case iox2::PublishSubscribeCreateError::PoolCapacityBelowRequiredMinimum:
+        return iox2_pub_sub_open_or_create_error_e_C_POOL_CAPACITY_BELOW_REQUIRED_MINIMUM;
+    case iox2::PublishSubscribeCreateError::SubscriberMaxBufferSizeIsZero:
+        return iox2_pub_sub_open_or_create_error_e_C_SUBSCRIBER_MAX_BUFFER_SIZE_IS_ZERO;
+    case iox2::PublishSubscribeCreateError::SubscriberMaxBorrowedSamplesIsZero:
+        return iox2_pub_sub_open_or_create_error_e_C_SUBSCRIBER_MAX_BORROWED_SAMPLES_IS_ZERO;
     case iox2::PublishSubscribeCreateError::AlreadyExists:
         return iox2_pub_sub_open_or_create_error_e_C_ALREADY_EXISTS;
     case iox2::PublishSubscribeCreateError::InsufficientPermissions:
@@ -767,6 +854,20 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOverflowBehavior:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleLastValueMode:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_LAST_VALUE_MODE;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatiblePoolAllocationStrategy:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_POOL_ALLOCATION_STRATEGY;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleCoalescingWindow:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_COALESCING_WINDOW;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleSlowSubscriberEviction:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SLOW_SUBSCRIBER_EVICTION;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleSampleMemoryPinning:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SAMPLE_MEMORY_PINNING;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedMaxMessageSize:
+        return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_MESSAGE_SIZE;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedPoolCapacity:
+        return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_POOL_CAPACITY;
     case iox2::PublishSubscribeOpenOrCreateError::OpenInsufficientPermissions:
         return iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::PublishSubscribeOpenOrCreateError::OpenServiceInCorruptedState:
@@ -777,10 +878,22 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIsMarkedForDestruction:
         return iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenStaleServiceHandle:
+        return iox2_pub_sub_open_or_create_error_e_O_STALE_SERVICE_HANDLE;
     case iox2::PublishSubscribeOpenOrCreateError::CreateServiceInCorruptedState:
         return iox2_pub_sub_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE;
     case iox2::PublishSubscribeOpenOrCreateError::CreateSubscriberBufferMustBeLargerThanHistorySize:
         return iox2_pub_sub_open_or_create_error_e_C_SUBSCRIBER_BUFFER_MUST_BE_LARGER_THAN_HISTORY_SIZE;
+    case iox2::PublishSubscribeOpenOrCreateError::CreatePayloadTypeHasZeroSize:
+        return iox2_pub_sub_open_or_create_error_e_C_PAYLOAD_TYPE_HAS_ZERO_SIZE;
+    case iox2::PublishSubscribeOpenOrCreateError::CreateExceedsMaxSampleSlots:
+        return iox2_pub_sub_open_or_create_error_e_C_EXCEEDS_MAX_SAMPLE_SLOTS;
+    case iox2::PublishSubscribeOpenOrCreateError::CreatePoolCapacityBelowRequiredMinimum:
+        return iox2_pub_sub_open_or_create_error_e_C_POOL_CAPACITY_BELOW_REQUIRED_MINIMUM;
+    case iox2::PublishSubscribeOpenOrCreateError::CreateSubscriberMaxBufferSizeIsZero:
+        return iox2_pub_sub_open_or_create_error_e_C_SUBSCRIBER_MAX_BUFFER_SIZE_IS_ZERO;
+    case iox2::PublishSubscribeOpenOrCreateError::CreateSubscriberMaxBorrowedSamplesIsZero:
+        return iox2_pub_sub_open_or_create_error_e_C_SUBSCRIBER_MAX_BORROWED_SAMPLES_IS_ZERO;
     case iox2::PublishSubscribeOpenOrCreateError::CreateAlreadyExists:
         return iox2_pub_sub_open_or_create_error_e_C_ALREADY_EXISTS;
     case iox2::PublishSubscribeOpenOrCreateError::CreateInsufficientPermissions:
@@ -947,6 +1060,10 @@ constexpr auto from<int, iox2::PublisherCreateError>(const int value) noexcept -
         return iox2::PublisherCreateError::ExceedsMaxSupportedPublishers;
     case iox2_publisher_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT:
         return iox2::PublisherCreateError::UnableToCreateDataSegment;
+    case iox2_publisher_create_error_e_OUTDATED_PAYLOAD_SCHEMA:
+        return iox2::PublisherCreateError::OutdatedPayloadSchema;
+    case iox2_publisher_create_error_e_SEND_TIMESTAMP_NOT_SUPPORTED:
+        return iox2::PublisherCreateError::SendTimestampNotSupported;
     }
 
     IOX_UNREACHABLE();
@@ -961,6 +1078,10 @@ from<iox2::PublisherCreateError, iox2_publisher_create_error_e>(const iox2::Publ
         return iox2_publisher_create_error_e_EXCEEDS_MAX_SUPPORTED_PUBLISHERS;
     case iox2::PublisherCreateError::UnableToCreateDataSegment:
         return iox2_publisher_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
+    case iox2::PublisherCreateError::OutdatedPayloadSchema:
+        return iox2_publisher_create_error_e_OUTDATED_PAYLOAD_SCHEMA;
+    case iox2::PublisherCreateError::SendTimestampNotSupported:
+        return iox2_publisher_create_error_e_SEND_TIMESTAMP_NOT_SUPPORTED;
     }
 
     IOX_UNREACHABLE();
@@ -980,6 +1101,10 @@ constexpr auto from<int, iox2::SubscriberCreateError>(const int value) noexcept
         return iox2::SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService;
     case iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS:
         return iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers;
+    case iox2_subscriber_create_error_e_BUFFER_TOO_SMALL_FOR_HISTORY:
+        return iox2::SubscriberCreateError::BufferTooSmallForHistory;
+    case iox2_subscriber_create_error_e_MAX_BORROWED_SAMPLES_EXCEEDS_MAX_SUPPORTED_BORROWED_SAMPLES_OF_SERVICE:
+        return iox2::SubscriberCreateError::MaxBorrowedSamplesExceedsMaxSupportedBorrowedSamplesOfService;
     }
 
     IOX_UNREACHABLE();
@@ -994,6 +1119,10 @@ from<iox2::SubscriberCreateError, iox2_subscriber_create_error_e>(const iox2::Su
         return iox2_subscriber_create_error_e_BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE;
     case iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers:
         return iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS;
+    case iox2::SubscriberCreateError::BufferTooSmallForHistory:
+        return iox2_subscriber_create_error_e_BUFFER_TOO_SMALL_FOR_HISTORY;
+    case iox2::SubscriberCreateError::MaxBorrowedSamplesExceedsMaxSupportedBorrowedSamplesOfService:
+        return iox2_subscriber_create_error_e_MAX_BORROWED_SAMPLES_EXCEEDS_MAX_SUPPORTED_BORROWED_SAMPLES_OF_SERVICE;
     }
 
     IOX_UNREACHABLE();
@@ -1023,6 +1152,8 @@ constexpr auto from<int, iox2::PublisherSendError>(const int value) noexcept ->
         return iox2::PublisherSendError::LoanErrorInternalFailure;
     case iox2_publisher_send_error_e_CONNECTION_ERROR:
         return iox2::PublisherSendError::ConnectionError;
+    case iox2_publisher_send_error_e_EXCEEDS_MAX_MESSAGE_SIZE:
+        return iox2::PublisherSendError::ExceedsMaxMessageSize;
     }
 
     IOX_UNREACHABLE();
@@ -1047,6 +1178,8 @@ from<iox2::PublisherSendError, iox2_publisher_send_error_e>(const iox2::Publishe
         return iox2_publisher_send_error_e_LOAN_ERROR_INTERNAL_FAILURE;
     case iox2::PublisherSendError::ConnectionError:
         return iox2_publisher_send_error_e_CONNECTION_ERROR;
+    case iox2::PublisherSendError::ExceedsMaxMessageSize:
+        return iox2_publisher_send_error_e_EXCEEDS_MAX_MESSAGE_SIZE;
     }
 
     IOX_UNREACHABLE();
@@ -1067,6 +1200,10 @@ constexpr auto from<int, iox2::SubscriberReceiveError>(const int value) noexcept
         return iox2::SubscriberReceiveError::UnableToMapPublishersDataSegment;
     case iox2_subscriber_receive_error_e_EXCEEDS_MAX_BORROWED_SAMPLES:
         return iox2::SubscriberReceiveError::ExceedsMaxBorrowedSamples;
+    case iox2_subscriber_receive_error_e_DISCONNECTED:
+        return iox2::SubscriberReceiveError::Disconnected;
+    case iox2_subscriber_receive_error_e_UNABLE_TO_PIN_SAMPLE_MEMORY:
+        return iox2::SubscriberReceiveError::UnableToPinSampleMemory;
     }
 
     IOX_UNREACHABLE();
@@ -1083,6 +1220,10 @@ from<iox2::SubscriberReceiveError, iox2_subscriber_receive_error_e>(const iox2::
         return iox2_subscriber_receive_error_e_UNABLE_TO_MAP_PUBLISHERS_DATA_SEGMENT;
     case iox2::SubscriberReceiveError::ExceedsMaxBorrowedSamples:
         return iox2_subscriber_receive_error_e_EXCEEDS_MAX_BORROWED_SAMPLES;
+    case iox2::SubscriberReceiveError::Disconnected:
+        return iox2_subscriber_receive_error_e_DISCONNECTED;
+    case iox2::SubscriberReceiveError::UnableToPinSampleMemory:
+        return iox2_subscriber_receive_error_e_UNABLE_TO_PIN_SAMPLE_MEMORY;
     }
 
     IOX_UNREACHABLE();
@@ -1216,6 +1357,31 @@ constexpr auto from<iox2::UnableToDeliverStrategy, int>(const iox2::UnableToDeli
     IOX_UNREACHABLE();
 }
 
+template <>
+constexpr auto from<int, iox2::StaticConfigFormat>(const int value) noexcept -> iox2::StaticConfigFormat {
+    const auto variant = static_cast<iox2_static_config_format_e>(value);
+    switch (variant) {
+    case iox2_static_config_format_e_TOML:
+        return iox2::StaticConfigFormat::Toml;
+    case iox2_static_config_format_e_JSON:
+        return iox2::StaticConfigFormat::Json;
+    }
+
+    IOX_UNREACHABLE();
+}
+
+template <>
+constexpr auto from<iox2::StaticConfigFormat, int>(const iox2::StaticConfigFormat value) noexcept -> int {
+    switch (value) {
+    case iox2::StaticConfigFormat::Toml:
+        return iox2_static_config_format_e_TOML;
+    case iox2::StaticConfigFormat::Json:
+        return iox2_static_config_format_e_JSON;
+    }
+
+    IOX_UNREACHABLE();
+}
+
 template <>
 constexpr auto from<int, iox2::ConnectionFailure>(const int value) noexcept -> iox2::ConnectionFailure {
     const auto variant = static_cast<iox2_connection_failure_e>(value);
@@ -1264,6 +1430,10 @@ constexpr auto from<int, iox2::ConfigCreationError>(const int value) noexcept ->
     case iox2_config_creation_error_e_INVALID_FILE_PATH:
         // unreachable since this error case is excluded by using the strong type iox::FilePath
         IOX_UNREACHABLE();
+    case iox2_config_creation_error_e_MALFORMED_ENVIRONMENT_VARIABLE:
+        return iox2::ConfigCreationError::MalformedEnvironmentVariable;
+    case iox2_config_creation_error_e_INVALID_CONFIG:
+        return iox2::ConfigCreationError::InvalidConfig;
     }
 
     IOX_UNREACHABLE();
@@ -1284,6 +1454,10 @@ from<iox2::ConfigCreationError, iox2_config_creation_error_e>(const iox2::Config
         return iox2_config_creation_error_e_CONFIG_FILE_DOES_NOT_EXIST;
     case iox2::ConfigCreationError::UnableToOpenConfigFile:
         return iox2_config_creation_error_e_UNABLE_TO_OPEN_CONFIG_FILE;
+    case iox2::ConfigCreationError::MalformedEnvironmentVariable:
+        return iox2_config_creation_error_e_MALFORMED_ENVIRONMENT_VARIABLE;
+    case iox2::ConfigCreationError::InvalidConfig:
+        return iox2_config_creation_error_e_INVALID_CONFIG;
     }
 
     IOX_UNREACHABLE();
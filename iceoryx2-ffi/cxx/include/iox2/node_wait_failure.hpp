@@ -23,6 +23,8 @@ enum class NodeWaitFailure : uint8_t {
     TerminationRequest,
     /// SIGINT signal was received
     Interrupt,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
 };
 
 } // namespace iox2
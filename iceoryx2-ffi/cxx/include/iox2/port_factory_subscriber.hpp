@@ -30,6 +30,11 @@ class PortFactorySubscriber {
     /// Defines the required buffer size of the [`Subscriber`]. Smallest possible value is `1`.
     IOX_BUILDER_OPTIONAL(uint64_t, buffer_size);
 
+    /// Defines how many [`Sample`]s the [`Subscriber`] can borrow at most from
+    /// every connected [`Publisher`]. It must not exceed the max borrowed
+    /// samples of the [`Service`]. Smallest possible value is `1`.
+    IOX_BUILDER_OPTIONAL(uint64_t, max_borrowed_samples);
+
   public:
     PortFactorySubscriber(const PortFactorySubscriber&) = delete;
     PortFactorySubscriber(PortFactorySubscriber&&) = default;
@@ -60,6 +65,8 @@ inline auto
 PortFactorySubscriber<S, Payload, UserHeader>::create() && -> iox::expected<Subscriber<S, Payload, UserHeader>,
                                                                             SubscriberCreateError> {
     m_buffer_size.and_then([&](auto value) { iox2_port_factory_subscriber_builder_set_buffer_size(&m_handle, value); });
+    m_max_borrowed_samples.and_then(
+        [&](auto value) { iox2_port_factory_subscriber_builder_set_max_borrowed_samples(&m_handle, value); });
 
     iox2_subscriber_h sub_handle {};
     auto result = iox2_port_factory_subscriber_builder_create(m_handle, nullptr, &sub_handle);
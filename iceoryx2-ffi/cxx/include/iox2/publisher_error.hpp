@@ -29,6 +29,13 @@ enum class PublisherCreateError : uint8_t {
     /// The datasegment in which the payload of the [`Publisher`] is stored,
     /// could not be created.
     UnableToCreateDataSegment,
+    /// The [`Service`] was created with a newer schema of the payload. A
+    /// [`Publisher`] with an older schema would not initialize the appended
+    /// fields.
+    OutdatedPayloadSchema,
+    /// The [`Publisher`] records the send timestamp but the sample header of the
+    /// [`Service`] has no room for it.
+    SendTimestampNotSupported,
 };
 
 /// Defines a failure that can occur in [`Publisher::loan()`] and
@@ -81,6 +88,9 @@ enum class PublisherSendError : uint8_t {
     /// A failure occurred while establishing a connection to a
     /// [`Subscriber`]
     ConnectionError,
+    /// The used length of the [`SampleMut`] exceeds the maximum message size of
+    /// the [`Service`] or the size of its payload.
+    ExceedsMaxMessageSize,
 };
 
 } // namespace iox2
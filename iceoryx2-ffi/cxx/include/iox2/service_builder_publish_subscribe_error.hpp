@@ -49,6 +49,21 @@ enum class PublishSubscribeOpenError : uint8_t {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`] required last value mode is not compatible.
+    IncompatibleLastValueMode,
+    /// The [`Service`] uses a different pool allocation strategy than required.
+    IncompatiblePoolAllocationStrategy,
+    /// The [`Service`] uses a different coalescing window than required.
+    IncompatibleCoalescingWindow,
+    /// The [`Service`] uses a different slow subscriber eviction policy than required.
+    IncompatibleSlowSubscriberEviction,
+    /// The [`Service`] has a different sample memory pinning setting than required.
+    IncompatibleSampleMemoryPinning,
+    /// The [`Service`] supports a smaller maximum message size than requested.
+    DoesNotSupportRequestedMaxMessageSize,
+    /// The [`Service`] provides fewer sample slots per [`Publisher`] than the
+    /// requested pool capacity.
+    DoesNotSupportRequestedPoolCapacity,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing,
@@ -67,6 +82,9 @@ enum class PublishSubscribeOpenError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`ServiceHandle`] refers to a [`Service`] that was removed or
+    /// recreated in the meantime.
+    StaleServiceHandle,
 };
 
 /// Errors that can occur when a new [`MessagingPattern::PublishSubscribe`] [`Service`] shall be created.
@@ -79,6 +97,22 @@ enum class PublishSubscribeCreateError : uint8_t {
     /// at least the size
     /// of the history. Otherwise, how could it hold the whole history?
     SubscriberBufferMustBeLargerThanHistorySize,
+    /// The payload type has a size of zero, like an empty struct. Such a
+    /// payload carries no data, use a [`MessagingPattern::Event`] [`Service`]
+    /// to signal occurrences instead.
+    PayloadTypeHasZeroSize,
+    /// The worst-case number of sample slots of the [`Service`] exceeds the
+    /// configured maximum number of sample slots.
+    ExceedsMaxSampleSlots,
+    /// The requested pool capacity is smaller than the number of sample slots
+    /// a [`Publisher`] requires at least.
+    PoolCapacityBelowRequiredMinimum,
+    /// The [`Config`] defines a subscriber max buffer size of zero and no
+    /// other value was provided to the builder.
+    SubscriberMaxBufferSizeIsZero,
+    /// The [`Config`] defines subscriber max borrowed samples of zero and no
+    /// other value was provided to the builder.
+    SubscriberMaxBorrowedSamplesIsZero,
     /// The [`Service`] already exists.
     AlreadyExists,
     /// The process has insufficient permissions to create the [`Service`].
@@ -127,6 +161,21 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     OpenDoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     OpenIncompatibleOverflowBehavior,
+    /// The [`Service`] required last value mode is not compatible.
+    OpenIncompatibleLastValueMode,
+    /// The [`Service`] uses a different pool allocation strategy than required.
+    OpenIncompatiblePoolAllocationStrategy,
+    /// The [`Service`] uses a different coalescing window than required.
+    OpenIncompatibleCoalescingWindow,
+    /// The [`Service`] uses a different slow subscriber eviction policy than required.
+    OpenIncompatibleSlowSubscriberEviction,
+    /// The [`Service`] has a different sample memory pinning setting than required.
+    OpenIncompatibleSampleMemoryPinning,
+    /// The [`Service`] supports a smaller maximum message size than requested.
+    OpenDoesNotSupportRequestedMaxMessageSize,
+    /// The [`Service`] provides fewer sample slots per [`Publisher`] than the
+    /// requested pool capacity.
+    OpenDoesNotSupportRequestedPoolCapacity,
     /// The process has not enough permissions to open the [`Service`]
     OpenInsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing,
@@ -145,6 +194,9 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     OpenIsMarkedForDestruction,
+    /// The [`ServiceHandle`] refers to a [`Service`] that was removed or
+    /// recreated in the meantime.
+    OpenStaleServiceHandle,
 
     /// Some underlying resources of the [`Service`] are either missing,
     /// corrupted or unaccessible.
@@ -154,6 +206,22 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     /// at least the size
     /// of the history. Otherwise, how could it hold the whole history?
     CreateSubscriberBufferMustBeLargerThanHistorySize,
+    /// The payload type has a size of zero, like an empty struct. Such a
+    /// payload carries no data, use a [`MessagingPattern::Event`] [`Service`]
+    /// to signal occurrences instead.
+    CreatePayloadTypeHasZeroSize,
+    /// The worst-case number of sample slots of the [`Service`] exceeds the
+    /// configured maximum number of sample slots.
+    CreateExceedsMaxSampleSlots,
+    /// The requested pool capacity is smaller than the number of sample slots
+    /// a [`Publisher`] requires at least.
+    CreatePoolCapacityBelowRequiredMinimum,
+    /// The [`Config`] defines a subscriber max buffer size of zero and no
+    /// other value was provided to the builder.
+    CreateSubscriberMaxBufferSizeIsZero,
+    /// The [`Config`] defines subscriber max borrowed samples of zero and no
+    /// other value was provided to the builder.
+    CreateSubscriberMaxBorrowedSamplesIsZero,
     /// The [`Service`] already exists.
     CreateAlreadyExists,
     /// The process has insufficient permissions to create the [`Service`].
@@ -0,0 +1,29 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#ifndef IOX2_STATIC_CONFIG_FORMAT_HPP
+#define IOX2_STATIC_CONFIG_FORMAT_HPP
+
+#include <cstdint>
+
+namespace iox2 {
+/// Defines the format in which the static configuration of a [`Service`] is
+/// stored. The format of an existing [`Service`] is detected when it is opened.
+enum class StaticConfigFormat : uint8_t {
+    /// Human readable TOML
+    Toml,
+    /// Compact JSON
+    Json
+};
+} // namespace iox2
+
+#endif
@@ -27,7 +27,16 @@ enum class SubscriberReceiveError : uint8_t {
     FailedToEstablishConnection,
 
     /// Failures when mapping the corresponding data segment
-    UnableToMapPublishersDataSegment
+    UnableToMapPublishersDataSegment,
+
+    /// The [`Subscriber`] was disconnected by a [`Publisher`] since it did not
+    /// free buffer space in time. It no longer receives any samples and must be
+    /// recreated.
+    Disconnected,
+
+    /// The memory of the received [`Sample`] could not be locked. The [`Sample`]
+    /// is released again.
+    UnableToPinSampleMemory
 };
 
 /// Describes the failures when a new [`Subscriber`] is created via the
@@ -42,6 +51,15 @@ enum class SubscriberCreateError : uint8_t {
     /// When the [`Subscriber`] requires a larger buffer size than the
     /// [`Service`] offers the creation will fail.
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
+
+    /// The buffer of the [`Subscriber`] is smaller than the history of the
+    /// [`Service`] and a late-joining [`Subscriber`] would only receive a
+    /// truncated history.
+    BufferTooSmallForHistory,
+
+    /// When the [`Subscriber`] requires more borrowed samples than the
+    /// [`Service`] supports the creation will fail.
+    MaxBorrowedSamplesExceedsMaxSupportedBorrowedSamplesOfService,
 };
 
 } // namespace iox2
@@ -365,6 +365,14 @@ auto PublishSubscribe::subscriber_expired_connection_buffer() && -> size_t {
 void PublishSubscribe::set_subscriber_expired_connection_buffer(size_t value) && {
     iox2_config_defaults_publish_subscribe_set_subscriber_expired_connection_buffer(m_config, value);
 }
+
+auto PublishSubscribe::max_sample_slots() && -> size_t {
+    return iox2_config_defaults_publish_subscribe_max_sample_slots(m_config);
+}
+
+void PublishSubscribe::set_max_sample_slots(size_t value) && {
+    iox2_config_defaults_publish_subscribe_set_max_sample_slots(m_config, value);
+}
 /////////////////////////
 // END: PublishSubscribe
 /////////////////////////
@@ -421,6 +429,27 @@ void Service::set_creation_timeout(const iox::units::Duration& value) && {
     iox2_config_global_service_set_creation_timeout(m_config, duration.tv_sec, duration.tv_nsec);
 }
 
+auto Service::dynamic_config_open_retries() && -> size_t {
+    return iox2_config_global_service_dynamic_config_open_retries(m_config);
+}
+
+void Service::set_dynamic_config_open_retries(size_t value) && {
+    iox2_config_global_service_set_dynamic_config_open_retries(m_config, value);
+}
+
+auto Service::dynamic_config_open_retry_delay() && -> iox::units::Duration {
+    uint64_t secs = 0;
+    uint32_t nsecs = 0;
+    iox2_config_global_service_dynamic_config_open_retry_delay(m_config, &secs, &nsecs);
+
+    return iox::units::Duration::fromSeconds(secs) + iox::units::Duration::fromNanoseconds(nsecs);
+}
+
+void Service::set_dynamic_config_open_retry_delay(const iox::units::Duration& value) && {
+    auto duration = value.timespec();
+    iox2_config_global_service_set_dynamic_config_open_retry_delay(m_config, duration.tv_sec, duration.tv_nsec);
+}
+
 auto Service::connection_suffix() && -> const char* {
     return iox2_config_global_service_connection_suffix(m_config);
 }
@@ -436,6 +465,31 @@ auto Service::event_connection_suffix() && -> const char* {
 void Service::set_event_connection_suffix(const iox::FileName& value) && {
     iox2_config_global_service_set_event_connection_suffix(m_config, value.as_string().c_str());
 }
+
+auto Service::case_insensitive_names() && -> bool {
+    return iox2_config_global_service_case_insensitive_names(m_config);
+}
+
+void Service::set_case_insensitive_names(bool value) && {
+    iox2_config_global_service_set_case_insensitive_names(m_config, value);
+}
+
+auto Service::static_config_format() && -> StaticConfigFormat {
+    return iox::into<StaticConfigFormat>(iox2_config_global_service_static_config_format(m_config));
+}
+
+void Service::set_static_config_format(StaticConfigFormat value) && {
+    iox2_config_global_service_set_static_config_format(
+        m_config, static_cast<iox2_static_config_format_e>(iox::into<int>(value)));
+}
+
+auto Service::cleanup_dead_ports_on_open() && -> bool {
+    return iox2_config_global_service_cleanup_dead_ports_on_open(m_config);
+}
+
+void Service::set_cleanup_dead_ports_on_open(bool value) && {
+    iox2_config_global_service_set_cleanup_dead_ports_on_open(m_config, value);
+}
 /////////////////////////
 // END: Service
 /////////////////////////
@@ -494,6 +548,27 @@ auto Node::cleanup_dead_nodes_on_destruction() && -> bool {
 void Node::set_cleanup_dead_nodes_on_destruction(bool value) && {
     iox2_config_global_node_set_cleanup_dead_nodes_on_destruction(m_config, value);
 }
+
+auto Node::reap_dead_ports_on_wait() && -> bool {
+    return iox2_config_global_node_reap_dead_ports_on_wait(m_config);
+}
+
+void Node::set_reap_dead_ports_on_wait(bool value) && {
+    iox2_config_global_node_set_reap_dead_ports_on_wait(m_config, value);
+}
+
+auto Node::reap_dead_ports_interval() && -> iox::units::Duration {
+    uint64_t secs = 0;
+    uint32_t nsecs = 0;
+    iox2_config_global_node_reap_dead_ports_interval(m_config, &secs, &nsecs);
+
+    return iox::units::Duration::fromSeconds(secs) + iox::units::Duration::fromNanoseconds(nsecs);
+}
+
+void Node::set_reap_dead_ports_interval(const iox::units::Duration& value) && {
+    auto duration = value.timespec();
+    iox2_config_global_node_set_reap_dead_ports_interval(m_config, duration.tv_sec, duration.tv_nsec);
+}
 /////////////////////////
 // END: Node
 /////////////////////////
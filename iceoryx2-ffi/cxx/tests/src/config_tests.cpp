@@ -181,6 +181,14 @@ TEST(Config, defaults_publish_subscribe_subscriber_expired_connection_buffer) {
     ASSERT_THAT(config.defaults().publish_subscribe().subscriber_expired_connection_buffer(), Eq(test_value));
 }
 
+TEST(Config, defaults_publish_subscribe_max_sample_slots) {
+    const size_t test_value = 4096;
+    auto config = Config();
+
+    config.defaults().publish_subscribe().set_max_sample_slots(test_value);
+    ASSERT_THAT(config.defaults().publish_subscribe().max_sample_slots(), Eq(test_value));
+}
+
 TEST(Config, global_service_directory) {
     const auto test_value = iox::Path::create("look/there/flies/a/dead/pidgin").expect("");
     auto config = Config();
@@ -221,6 +229,22 @@ TEST(Config, global_service_creation_timeout) {
     ASSERT_THAT(config.global().service().creation_timeout(), Eq(test_value));
 }
 
+TEST(Config, global_service_dynamic_config_open_retries) {
+    const size_t test_value = 37;
+    auto config = Config();
+
+    config.global().service().set_dynamic_config_open_retries(test_value);
+    ASSERT_THAT(config.global().service().dynamic_config_open_retries(), Eq(test_value));
+}
+
+TEST(Config, global_service_dynamic_config_open_retry_delay) {
+    const auto test_value = iox::units::Duration::fromMilliseconds(42);
+    auto config = Config();
+
+    config.global().service().set_dynamic_config_open_retry_delay(test_value);
+    ASSERT_THAT(config.global().service().dynamic_config_open_retry_delay(), Eq(test_value));
+}
+
 TEST(Config, global_service_connection_suffix) {
     const auto test_value = iox::FileName::create("what_dinosaur_ancester_has_the_pidgin").expect("");
     auto config = Config();
@@ -237,6 +261,33 @@ TEST(Config, global_service_event_connection_suffix) {
     ASSERT_THAT(config.global().service().event_connection_suffix(), StrEq(test_value.as_string().c_str()));
 }
 
+TEST(Config, global_service_case_insensitive_names) {
+    auto config = Config();
+
+    config.global().service().set_case_insensitive_names(true);
+    ASSERT_THAT(config.global().service().case_insensitive_names(), Eq(true));
+    config.global().service().set_case_insensitive_names(false);
+    ASSERT_THAT(config.global().service().case_insensitive_names(), Eq(false));
+}
+
+TEST(Config, global_service_static_config_format) {
+    auto config = Config();
+
+    config.global().service().set_static_config_format(StaticConfigFormat::Json);
+    ASSERT_THAT(config.global().service().static_config_format(), Eq(StaticConfigFormat::Json));
+    config.global().service().set_static_config_format(StaticConfigFormat::Toml);
+    ASSERT_THAT(config.global().service().static_config_format(), Eq(StaticConfigFormat::Toml));
+}
+
+TEST(Config, global_service_cleanup_dead_ports_on_open) {
+    auto config = Config();
+
+    config.global().service().set_cleanup_dead_ports_on_open(false);
+    ASSERT_THAT(config.global().service().cleanup_dead_ports_on_open(), Eq(false));
+    config.global().service().set_cleanup_dead_ports_on_open(true);
+    ASSERT_THAT(config.global().service().cleanup_dead_ports_on_open(), Eq(true));
+}
+
 TEST(Config, global_node_directory) {
     const auto test_value = iox::Path::create("eat/the/carrototier").expect("");
     auto config = Config();
@@ -286,4 +337,21 @@ TEST(Config, global_node_cleanup_dead_nodes_on_destruction) {
     config.global().node().set_cleanup_dead_nodes_on_destruction(false);
     ASSERT_THAT(config.global().node().cleanup_dead_nodes_on_destruction(), Eq(false));
 }
+
+TEST(Config, global_node_reap_dead_ports_on_wait) {
+    auto config = Config();
+
+    config.global().node().set_reap_dead_ports_on_wait(true);
+    ASSERT_THAT(config.global().node().reap_dead_ports_on_wait(), Eq(true));
+    config.global().node().set_reap_dead_ports_on_wait(false);
+    ASSERT_THAT(config.global().node().reap_dead_ports_on_wait(), Eq(false));
+}
+
+TEST(Config, global_node_reap_dead_ports_interval) {
+    const auto test_value = iox::units::Duration::fromMilliseconds(73);
+    auto config = Config();
+
+    config.global().node().set_reap_dead_ports_interval(test_value);
+    ASSERT_THAT(config.global().node().reap_dead_ports_interval(), Eq(test_value));
+}
 } // namespace
@@ -34,6 +34,8 @@ TEST(EnumConversionTest, config_creation_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ConfigFileDoesNotExist)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToOpenConfigFile)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::MalformedEnvironmentVariable)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InvalidConfig)), 1U);
 }
 
 TEST(EnumConversionTest, connection_failure_into_c_str) {
@@ -66,12 +68,14 @@ TEST(EnumConversionTest, node_creation_failure_into_c_str) {
     using Sut = iox2::NodeCreationFailure;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalError)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InvalidConfig)), 1U);
 }
 
 TEST(EnumConversionTest, node_wait_failure_into_c_str) {
     using Sut = iox2::NodeWaitFailure;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::TerminationRequest)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::Interrupt)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalError)), 1U);
 }
 
 TEST(EnumConversionTest, notifier_create_into_c_str) {
@@ -88,6 +92,8 @@ TEST(EnumConversionTest, publisher_create_into_c_str) {
     using Sut = iox2::PublisherCreateError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSupportedPublishers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToCreateDataSegment)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OutdatedPayloadSchema)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::SendTimestampNotSupported)), 1U);
 }
 
 TEST(EnumConversionTest, publisher_loan_into_c_str) {
@@ -107,6 +113,7 @@ TEST(EnumConversionTest, publisher_send_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorExceedsMaxLoanSize)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorInternalFailure)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ConnectionError)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxMessageSize)), 1U);
 }
 
 TEST(EnumConversionTest, event_open_into_c_str) {
@@ -175,17 +182,30 @@ TEST(EnumConversionTest, publish_subscribe_open_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedAmountOfSubscribers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleOverflowBehavior)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleLastValueMode)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatiblePoolAllocationStrategy)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleCoalescingWindow)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleSlowSubscriberEviction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleSampleMemoryPinning)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedMaxMessageSize)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedPoolCapacity)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::HangsInCreation)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::IsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::StaleServiceHandle)), 1U);
 }
 
 TEST(EnumConversionTest, publish_subscribe_create_into_c_str) {
     using Sut = iox2::PublishSubscribeCreateError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::SubscriberBufferMustBeLargerThanHistorySize)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::PayloadTypeHasZeroSize)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSampleSlots)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::PoolCapacityBelowRequiredMinimum)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::SubscriberMaxBufferSizeIsZero)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::SubscriberMaxBorrowedSamplesIsZero)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::AlreadyExists)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalFailure)), 1U);
@@ -207,13 +227,26 @@ TEST(EnumConversionTest, publish_subscribe_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfSubscribers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleOverflowBehavior)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleLastValueMode)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatiblePoolAllocationStrategy)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleCoalescingWindow)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleSlowSubscriberEviction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleSampleMemoryPinning)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedMaxMessageSize)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedPoolCapacity)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenInsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenHangsInCreation)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenStaleServiceHandle)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateSubscriberBufferMustBeLargerThanHistorySize)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::CreatePayloadTypeHasZeroSize)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateExceedsMaxSampleSlots)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::CreatePoolCapacityBelowRequiredMinimum)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateSubscriberMaxBufferSizeIsZero)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateSubscriberMaxBorrowedSamplesIsZero)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateAlreadyExists)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateInsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateInternalFailure)), 1U);
@@ -244,12 +277,16 @@ TEST(EnumConversionTest, subscriber_receive_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxBorrowedSamples)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::FailedToEstablishConnection)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToMapPublishersDataSegment)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::Disconnected)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToPinSampleMemory)), 1U);
 }
 
 TEST(EnumConversionTest, subscriber_create_into_c_str) {
     using Sut = iox2::SubscriberCreateError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSupportedSubscribers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::BufferSizeExceedsMaxSupportedBufferSizeOfService)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::BufferTooSmallForHistory)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::MaxBorrowedSamplesExceedsMaxSupportedBorrowedSamplesOfService)), 1U);
 }
 
 TEST(EnumConversionTest, waitset_create_into_c_str) {
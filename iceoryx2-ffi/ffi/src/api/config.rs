@@ -17,6 +17,7 @@ use core::ffi::{c_char, c_int};
 use core::mem::ManuallyDrop;
 use core::time::Duration;
 use iceoryx2::config::{Config, ConfigCreationError};
+use iceoryx2::service::static_config::StaticConfigFormat;
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_elementary::static_assert::*;
 use iceoryx2_bb_elementary::AsCStr;
@@ -48,6 +49,10 @@ pub enum iox2_config_creation_error_e {
     UNABLE_TO_OPEN_CONFIG_FILE,
     /// The provided string is not a valid file path
     INVALID_FILE_PATH,
+    /// An environment variable that overrides a setting contains a value that cannot be parsed.
+    MALFORMED_ENVIRONMENT_VARIABLE,
+    /// The loaded settings are invalid.
+    INVALID_CONFIG,
 }
 
 impl IntoCInt for ConfigCreationError {
@@ -68,6 +73,38 @@ impl IntoCInt for ConfigCreationError {
             ConfigCreationError::UnableToOpenConfigFile => {
                 iox2_config_creation_error_e::UNABLE_TO_OPEN_CONFIG_FILE
             }
+            ConfigCreationError::MalformedEnvironmentVariable => {
+                iox2_config_creation_error_e::MALFORMED_ENVIRONMENT_VARIABLE
+            }
+            ConfigCreationError::InvalidConfig(_) => iox2_config_creation_error_e::INVALID_CONFIG,
+        }) as c_int
+    }
+}
+
+/// Defines the format in which the static configuration of a service is stored.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum iox2_static_config_format_e {
+    /// Human readable TOML
+    TOML,
+    /// Compact JSON
+    JSON,
+}
+
+impl From<iox2_static_config_format_e> for StaticConfigFormat {
+    fn from(value: iox2_static_config_format_e) -> Self {
+        match value {
+            iox2_static_config_format_e::TOML => StaticConfigFormat::Toml,
+            iox2_static_config_format_e::JSON => StaticConfigFormat::Json,
+        }
+    }
+}
+
+impl IntoCInt for StaticConfigFormat {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            StaticConfigFormat::Toml => iox2_static_config_format_e::TOML,
+            StaticConfigFormat::Json => iox2_static_config_format_e::JSON,
         }) as c_int
     }
 }
@@ -86,7 +123,7 @@ pub(super) struct ConfigOwner {
 #[repr(C)]
 #[repr(align(8))] // align_of<ConfigOwner>()
 pub struct iox2_config_storage_t {
-    internal: [u8; 3704], // size_of<ConfigOwner>()
+    internal: [u8; 3784], // size_of<ConfigOwner>()
 }
 
 /// Contains the iceoryx2 config
@@ -711,6 +748,104 @@ pub unsafe extern "C" fn iox2_config_global_node_set_cleanup_dead_nodes_on_destr
         .cleanup_dead_nodes_on_destruction = value;
 }
 
+/// When true, [`iox2_node_wait()`](crate::api::iox2_node_wait) removes all ports of the
+/// services the node is registered at whose owning process is no longer alive.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_node_reap_dead_ports_on_wait(
+    handle: iox2_config_h_ref,
+) -> bool {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .node
+        .reap_dead_ports_on_wait
+}
+
+/// Enable/disable the reaping of dead ports on wait
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_node_set_reap_dead_ports_on_wait(
+    handle: iox2_config_h_ref,
+    value: bool,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .global
+        .node
+        .reap_dead_ports_on_wait = value;
+}
+
+/// Returns the minimum time between two reapings of dead ports in
+/// [`iox2_node_wait()`](crate::api::iox2_node_wait)
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+/// * `secs` - A valid pointer pointing to a [`u64`].
+/// * `nsecs` - A valid pointer pointing to a [`u32`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_node_reap_dead_ports_interval(
+    handle: iox2_config_h_ref,
+    secs: *mut u64,
+    nsecs: *mut u32,
+) {
+    handle.assert_non_null();
+    debug_assert!(!secs.is_null());
+    debug_assert!(!nsecs.is_null());
+
+    let config = &*handle.as_type();
+    let interval = config
+        .value
+        .as_ref()
+        .value
+        .global
+        .node
+        .reap_dead_ports_interval;
+    *secs = interval.as_secs();
+    *nsecs = interval.subsec_nanos();
+}
+
+/// Sets the minimum time between two reapings of dead ports in
+/// [`iox2_node_wait()`](crate::api::iox2_node_wait)
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_node_set_reap_dead_ports_interval(
+    handle: iox2_config_h_ref,
+    sec: u64,
+    nsec: u32,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .global
+        .node
+        .reap_dead_ports_interval = Duration::from_secs(sec) + Duration::from_nanos(nsec as u64);
+}
+
 /////////////////
 // END: node
 /////////////////
@@ -975,6 +1110,103 @@ pub unsafe extern "C" fn iox2_config_global_service_set_creation_timeout(
         Duration::from_secs(sec) + Duration::from_nanos(nsec as u64);
 }
 
+/// Returns how often it is tried again to open the dynamic config of a service whose
+/// creator has not yet finished the initialization
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_dynamic_config_open_retries(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .service
+        .dynamic_config_open_retries
+}
+
+/// Sets how often it is tried again to open the dynamic config of a service
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_set_dynamic_config_open_retries(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .global
+        .service
+        .dynamic_config_open_retries = value;
+}
+
+/// Returns the time between two attempts to open the dynamic config of a service
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+/// * `secs` - A valid pointer pointing to a [`u64`].
+/// * `nsecs` - A valid pointer pointing to a [`u32`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_dynamic_config_open_retry_delay(
+    handle: iox2_config_h_ref,
+    secs: *mut u64,
+    nsecs: *mut u32,
+) {
+    handle.assert_non_null();
+    debug_assert!(!secs.is_null());
+    debug_assert!(!nsecs.is_null());
+
+    let config = &*handle.as_type();
+    let delay = config
+        .value
+        .as_ref()
+        .value
+        .global
+        .service
+        .dynamic_config_open_retry_delay;
+    *secs = delay.as_secs();
+    *nsecs = delay.subsec_nanos();
+}
+
+/// Sets the time between two attempts to open the dynamic config of a service
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_set_dynamic_config_open_retry_delay(
+    handle: iox2_config_h_ref,
+    sec: u64,
+    nsec: u32,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .global
+        .service
+        .dynamic_config_open_retry_delay =
+        Duration::from_secs(sec) + Duration::from_nanos(nsec as u64);
+}
+
 /// The suffix of a one-to-one connection
 ///
 /// # Safety
@@ -1076,6 +1308,140 @@ pub unsafe extern "C" fn iox2_config_global_service_set_event_connection_suffix(
         Err(e) => e as c_int,
     }
 }
+
+/// When true, service names are converted to lowercase before they are mapped to the
+/// resources of a service.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_case_insensitive_names(
+    handle: iox2_config_h_ref,
+) -> bool {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .service
+        .case_insensitive_names
+}
+
+/// Enable/disable the case insensitive mapping of service names
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_set_case_insensitive_names(
+    handle: iox2_config_h_ref,
+    value: bool,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .global
+        .service
+        .case_insensitive_names = value;
+}
+
+/// The format in which the static configuration of a newly created service is stored.
+///
+/// Returns [`iox2_static_config_format_e`]
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_static_config_format(
+    handle: iox2_config_h_ref,
+) -> c_int {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .service
+        .static_config_format
+        .into_c_int()
+}
+
+/// Define the format in which the static configuration of a newly created service is stored
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_set_static_config_format(
+    handle: iox2_config_h_ref,
+    value: iox2_static_config_format_e,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .global
+        .service
+        .static_config_format = value.into();
+}
+
+/// When true, opening an existing service removes all ports whose owning process is no
+/// longer alive.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_cleanup_dead_ports_on_open(
+    handle: iox2_config_h_ref,
+) -> bool {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .service
+        .cleanup_dead_ports_on_open
+}
+
+/// Enable/disable the cleanup of dead ports when a service is opened
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_set_cleanup_dead_ports_on_open(
+    handle: iox2_config_h_ref,
+    value: bool,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .global
+        .service
+        .cleanup_dead_ports_on_open = value;
+}
 /////////////////
 // END: service
 /////////////////
@@ -1530,6 +1896,50 @@ pub unsafe extern "C" fn iox2_config_defaults_publish_subscribe_set_subscriber_e
         .publish_subscribe
         .subscriber_expired_connection_buffer = value;
 }
+
+/// The maximum number of sample slots a service may require in the worst case, summed up over
+/// the data segments of all publishers.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_publish_subscribe_max_sample_slots(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .publish_subscribe
+        .max_sample_slots
+}
+
+/// Set the maximum number of sample slots
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_publish_subscribe_set_max_sample_slots(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .publish_subscribe
+        .max_sample_slots = value;
+}
 //////////////////////////
 // END: publish subscribe
 //////////////////////////
@@ -68,6 +68,7 @@ impl IntoCInt for NodeListFailure {
 pub enum iox2_node_wait_failure_e {
     INTERRUPT = IOX2_OK as isize + 1,
     TERMINATION_REQUEST,
+    INTERNAL_ERROR,
 }
 
 impl IntoCInt for NodeWaitFailure {
@@ -75,6 +76,7 @@ impl IntoCInt for NodeWaitFailure {
         (match self {
             NodeWaitFailure::TerminationRequest => iox2_node_wait_failure_e::TERMINATION_REQUEST,
             NodeWaitFailure::Interrupt => iox2_node_wait_failure_e::INTERRUPT,
+            NodeWaitFailure::InternalError => iox2_node_wait_failure_e::INTERNAL_ERROR,
         }) as c_int
     }
 }
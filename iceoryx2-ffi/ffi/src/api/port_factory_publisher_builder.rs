@@ -60,6 +60,8 @@ impl From<iox2_allocation_strategy_e> for AllocationStrategy {
 pub enum iox2_publisher_create_error_e {
     EXCEEDS_MAX_SUPPORTED_PUBLISHERS = IOX2_OK as isize + 1,
     UNABLE_TO_CREATE_DATA_SEGMENT,
+    OUTDATED_PAYLOAD_SCHEMA,
+    SEND_TIMESTAMP_NOT_SUPPORTED,
 }
 
 impl IntoCInt for PublisherCreateError {
@@ -71,6 +73,12 @@ impl IntoCInt for PublisherCreateError {
             PublisherCreateError::UnableToCreateDataSegment => {
                 iox2_publisher_create_error_e::UNABLE_TO_CREATE_DATA_SEGMENT
             }
+            PublisherCreateError::OutdatedPayloadSchema => {
+                iox2_publisher_create_error_e::OUTDATED_PAYLOAD_SCHEMA
+            }
+            PublisherCreateError::SendTimestampNotSupported => {
+                iox2_publisher_create_error_e::SEND_TIMESTAMP_NOT_SUPPORTED
+            }
         }) as c_int
     }
 }
@@ -135,7 +143,7 @@ impl IntoCInt for UnableToDeliverStrategy {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactoryPublisherBuilderUnion>
 pub struct iox2_port_factory_publisher_builder_storage_t {
-    internal: [u8; 128], // magic number obtained with size_of::<Option<PortFactoryPublisherBuilderUnion>>()
+    internal: [u8; 208], // magic number obtained with size_of::<Option<PortFactoryPublisherBuilderUnion>>()
 }
 
 #[repr(C)]
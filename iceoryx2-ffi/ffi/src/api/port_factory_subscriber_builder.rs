@@ -35,6 +35,8 @@ use core::mem::ManuallyDrop;
 pub enum iox2_subscriber_create_error_e {
     EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS = IOX2_OK as isize + 1,
     BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE,
+    BUFFER_TOO_SMALL_FOR_HISTORY,
+    MAX_BORROWED_SAMPLES_EXCEEDS_MAX_SUPPORTED_BORROWED_SAMPLES_OF_SERVICE,
 }
 
 impl IntoCInt for SubscriberCreateError {
@@ -46,6 +48,12 @@ impl IntoCInt for SubscriberCreateError {
             SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService => {
                 iox2_subscriber_create_error_e::BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE
             }
+            SubscriberCreateError::BufferTooSmallForHistory { .. } => {
+                iox2_subscriber_create_error_e::BUFFER_TOO_SMALL_FOR_HISTORY
+            }
+            SubscriberCreateError::MaxBorrowedSamplesExceedsMaxSupportedBorrowedSamplesOfService => {
+                iox2_subscriber_create_error_e::MAX_BORROWED_SAMPLES_EXCEEDS_MAX_SUPPORTED_BORROWED_SAMPLES_OF_SERVICE
+            }
         }) as c_int
     }
 }
@@ -75,7 +83,7 @@ impl PortFactorySubscriberBuilderUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactorySubscriberBuilderUnion>
 pub struct iox2_port_factory_subscriber_builder_storage_t {
-    internal: [u8; 112], // magic number obtained with size_of::<Option<PortFactorySubscriberBuilderUnion>>()
+    internal: [u8; 352], // magic number obtained with size_of::<Option<PortFactorySubscriberBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -198,6 +206,43 @@ pub unsafe extern "C" fn iox2_port_factory_subscriber_builder_set_buffer_size(
     }
 }
 
+/// Sets the max borrowed samples for the subscriber
+///
+/// # Arguments
+///
+/// * `port_factory_handle` - Must be a valid [`iox2_port_factory_subscriber_builder_h_ref`]
+///   obtained by [`iox2_port_factory_pub_sub_subscriber_builder`](crate::iox2_port_factory_pub_sub_subscriber_builder).
+/// * `value` - The value to set max borrowed samples to
+///
+/// # Safety
+///
+/// * `port_factory_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_port_factory_subscriber_builder_set_max_borrowed_samples(
+    port_factory_handle: iox2_port_factory_subscriber_builder_h_ref,
+    value: c_size_t,
+) {
+    port_factory_handle.assert_non_null();
+
+    let port_factory_struct = unsafe { &mut *port_factory_handle.as_type() };
+    match port_factory_struct.service_type {
+        iox2_service_type_e::IPC => {
+            let port_factory = ManuallyDrop::take(&mut port_factory_struct.value.as_mut().ipc);
+
+            port_factory_struct.set(PortFactorySubscriberBuilderUnion::new_ipc(
+                port_factory.max_borrowed_samples(value),
+            ));
+        }
+        iox2_service_type_e::LOCAL => {
+            let port_factory = ManuallyDrop::take(&mut port_factory_struct.value.as_mut().local);
+
+            port_factory_struct.set(PortFactorySubscriberBuilderUnion::new_local(
+                port_factory.max_borrowed_samples(value),
+            ));
+        }
+    }
+}
+
 // TODO [#210] add all the other setter methods
 
 /// Creates a subscriber and consumes the builder
@@ -43,6 +43,7 @@ pub enum iox2_publisher_send_error_e {
     LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE,
     LOAN_ERROR_INTERNAL_FAILURE,
     CONNECTION_ERROR,
+    EXCEEDS_MAX_MESSAGE_SIZE,
 }
 
 impl IntoCInt for PublisherSendError {
@@ -51,7 +52,7 @@ impl IntoCInt for PublisherSendError {
             PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists => {
                 iox2_publisher_send_error_e::CONNECTION_BROKEN_SINCE_PUBLISHER_NO_LONGER_EXISTS
             }
-            PublisherSendError::ConnectionCorrupted => {
+            PublisherSendError::ConnectionCorrupted(_) => {
                 iox2_publisher_send_error_e::CONNECTION_CORRUPTED
             }
             PublisherSendError::LoanError(PublisherLoanError::OutOfMemory) => {
@@ -67,6 +68,9 @@ impl IntoCInt for PublisherSendError {
                 iox2_publisher_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
             }
             PublisherSendError::ConnectionError(_) => iox2_publisher_send_error_e::CONNECTION_ERROR,
+            PublisherSendError::ExceedsMaxMessageSize => {
+                iox2_publisher_send_error_e::EXCEEDS_MAX_MESSAGE_SIZE
+            }
         }) as c_int
     }
 }
@@ -54,7 +54,7 @@ impl SampleMutUninitUnion {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<SampleMutUninitUnion>
 pub struct iox2_sample_mut_storage_t {
-    internal: [u8; 64], // magic number obtained with size_of::<Option<SampleMutUninitUnion>>()
+    internal: [u8; 136], // magic number obtained with size_of::<Option<SampleMutUninitUnion>>()
 }
 
 #[repr(C)]
@@ -96,7 +96,8 @@ impl IntoCInt for EventOpenError {
             EventOpenError::InsufficientPermissions => {
                 iox2_event_open_or_create_error_e::O_INSUFFICIENT_PERMISSIONS
             }
-            EventOpenError::ServiceInCorruptedState => {
+            EventOpenError::ServiceInCorruptedState
+            | EventOpenError::DynamicStorageOpenFailure(_) => {
                 iox2_event_open_or_create_error_e::O_SERVICE_IN_CORRUPTED_STATE
             }
             EventOpenError::IncompatibleMessagingPattern => {
@@ -152,7 +153,9 @@ impl IntoCInt for EventCreateError {
                 iox2_event_open_or_create_error_e::C_SERVICE_IN_CORRUPTED_STATE
             }
 
-            EventCreateError::InternalFailure => {
+            EventCreateError::InternalFailure
+            | EventCreateError::StaticStorageCreationFailure(_)
+            | EventCreateError::DynamicStorageCreationFailure(_) => {
                 iox2_event_open_or_create_error_e::C_INTERNAL_FAILURE
             }
             EventCreateError::IsBeingCreatedByAnotherInstance => {
@@ -66,6 +66,20 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
     #[CStr = "incompatible overflow behavior"]
     O_INCOMPATIBLE_OVERFLOW_BEHAVIOR,
+    #[CStr = "incompatible last value mode"]
+    O_INCOMPATIBLE_LAST_VALUE_MODE,
+    #[CStr = "incompatible pool allocation strategy"]
+    O_INCOMPATIBLE_POOL_ALLOCATION_STRATEGY,
+    #[CStr = "incompatible coalescing window"]
+    O_INCOMPATIBLE_COALESCING_WINDOW,
+    #[CStr = "incompatible slow subscriber eviction"]
+    O_INCOMPATIBLE_SLOW_SUBSCRIBER_EVICTION,
+    #[CStr = "incompatible sample memory pinning"]
+    O_INCOMPATIBLE_SAMPLE_MEMORY_PINNING,
+    #[CStr = "does not support requested max message size"]
+    O_DOES_NOT_SUPPORT_REQUESTED_MAX_MESSAGE_SIZE,
+    #[CStr = "does not support requested pool capacity"]
+    O_DOES_NOT_SUPPORT_REQUESTED_POOL_CAPACITY,
     #[CStr = "insufficient permissions"]
     O_INSUFFICIENT_PERMISSIONS,
     #[CStr = "service in corrupted state"]
@@ -76,10 +90,22 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     O_EXCEEDS_MAX_NUMBER_OF_NODES,
     #[CStr = "is marked for destruction"]
     O_IS_MARKED_FOR_DESTRUCTION,
+    #[CStr = "stale service handle"]
+    O_STALE_SERVICE_HANDLE,
     #[CStr = "service in corrupted state"]
     C_SERVICE_IN_CORRUPTED_STATE,
     #[CStr = "subscriber buffer must be larger than history size"]
     C_SUBSCRIBER_BUFFER_MUST_BE_LARGER_THAN_HISTORY_SIZE,
+    #[CStr = "payload type has zero size"]
+    C_PAYLOAD_TYPE_HAS_ZERO_SIZE,
+    #[CStr = "exceeds max sample slots"]
+    C_EXCEEDS_MAX_SAMPLE_SLOTS,
+    #[CStr = "pool capacity below required minimum"]
+    C_POOL_CAPACITY_BELOW_REQUIRED_MINIMUM,
+    #[CStr = "subscriber max buffer size is zero"]
+    C_SUBSCRIBER_MAX_BUFFER_SIZE_IS_ZERO,
+    #[CStr = "subscriber max borrowed samples is zero"]
+    C_SUBSCRIBER_MAX_BORROWED_SAMPLES_IS_ZERO,
     #[CStr = "already exists"]
     C_ALREADY_EXISTS,
     #[CStr = "insufficient permissions"]
@@ -133,10 +159,32 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IncompatibleOverflowBehavior => {
              iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR
          }
+         PublishSubscribeOpenError::IncompatibleLastValueMode => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_LAST_VALUE_MODE
+         }
+         PublishSubscribeOpenError::IncompatiblePoolAllocationStrategy => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_POOL_ALLOCATION_STRATEGY
+         }
+         PublishSubscribeOpenError::IncompatibleCoalescingWindow => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_COALESCING_WINDOW
+         }
+         PublishSubscribeOpenError::IncompatibleSlowSubscriberEviction => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_SLOW_SUBSCRIBER_EVICTION
+         }
+         PublishSubscribeOpenError::IncompatibleSampleMemoryPinning => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_SAMPLE_MEMORY_PINNING
+         }
+         PublishSubscribeOpenError::DoesNotSupportRequestedMaxMessageSize => {
+             iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_MAX_MESSAGE_SIZE
+         }
+         PublishSubscribeOpenError::DoesNotSupportRequestedPoolCapacity => {
+             iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_POOL_CAPACITY
+         }
          PublishSubscribeOpenError::InsufficientPermissions => {
              iox2_pub_sub_open_or_create_error_e::O_INSUFFICIENT_PERMISSIONS
          }
-         PublishSubscribeOpenError::ServiceInCorruptedState => {
+         PublishSubscribeOpenError::ServiceInCorruptedState
+         | PublishSubscribeOpenError::DynamicStorageOpenFailure(_) => {
              iox2_pub_sub_open_or_create_error_e::O_SERVICE_IN_CORRUPTED_STATE
          }
          PublishSubscribeOpenError::HangsInCreation => {
@@ -148,6 +196,9 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IsMarkedForDestruction => {
              iox2_pub_sub_open_or_create_error_e::O_IS_MARKED_FOR_DESTRUCTION
          }
+         PublishSubscribeOpenError::StaleServiceHandle => {
+             iox2_pub_sub_open_or_create_error_e::O_STALE_SERVICE_HANDLE
+         }
         }) as c_int
     }
 }
@@ -161,11 +212,28 @@ impl IntoCInt for PublishSubscribeCreateError {
             PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize => {
                 iox2_pub_sub_open_or_create_error_e::C_SUBSCRIBER_BUFFER_MUST_BE_LARGER_THAN_HISTORY_SIZE
             }
+            PublishSubscribeCreateError::PayloadTypeHasZeroSize => {
+                iox2_pub_sub_open_or_create_error_e::C_PAYLOAD_TYPE_HAS_ZERO_SIZE
+            }
+            PublishSubscribeCreateError::ExceedsMaxSampleSlots => {
+                iox2_pub_sub_open_or_create_error_e::C_EXCEEDS_MAX_SAMPLE_SLOTS
+            }
+            PublishSubscribeCreateError::PoolCapacityBelowRequiredMinimum => {
+                iox2_pub_sub_open_or_create_error_e::C_POOL_CAPACITY_BELOW_REQUIRED_MINIMUM
+            }
+            PublishSubscribeCreateError::SubscriberMaxBufferSizeIsZero => {
+                iox2_pub_sub_open_or_create_error_e::C_SUBSCRIBER_MAX_BUFFER_SIZE_IS_ZERO
+            }
+            PublishSubscribeCreateError::SubscriberMaxBorrowedSamplesIsZero => {
+                iox2_pub_sub_open_or_create_error_e::C_SUBSCRIBER_MAX_BORROWED_SAMPLES_IS_ZERO
+            }
             PublishSubscribeCreateError::AlreadyExists => iox2_pub_sub_open_or_create_error_e::C_ALREADY_EXISTS,
           PublishSubscribeCreateError::InsufficientPermissions => {
              iox2_pub_sub_open_or_create_error_e::C_INSUFFICIENT_PERMISSIONS
          }
-            PublishSubscribeCreateError::InternalFailure => {
+            PublishSubscribeCreateError::InternalFailure
+            | PublishSubscribeCreateError::StaticStorageCreationFailure(_)
+            | PublishSubscribeCreateError::DynamicStorageCreationFailure(_) => {
                 iox2_pub_sub_open_or_create_error_e::C_INTERNAL_FAILURE
             }
             PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance => {
@@ -302,6 +370,7 @@ pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_user_header_type_detai
         type_name,
         size,
         alignment,
+        schema: None,
     };
 
     let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
@@ -379,6 +448,7 @@ pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_payload_type_details(
         type_name,
         size,
         alignment,
+        schema: None,
     };
 
     let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
@@ -37,6 +37,8 @@ pub enum iox2_subscriber_receive_error_e {
     EXCEEDS_MAX_BORROWED_SAMPLES = IOX2_OK as isize + 1,
     FAILED_TO_ESTABLISH_CONNECTION,
     UNABLE_TO_MAP_PUBLISHERS_DATA_SEGMENT,
+    DISCONNECTED,
+    UNABLE_TO_PIN_SAMPLE_MEMORY,
 }
 
 impl IntoCInt for SubscriberReceiveError {
@@ -51,6 +53,10 @@ impl IntoCInt for SubscriberReceiveError {
             SubscriberReceiveError::ConnectionFailure(
                 ConnectionFailure::UnableToMapPublishersDataSegment(_),
             ) => iox2_subscriber_receive_error_e::UNABLE_TO_MAP_PUBLISHERS_DATA_SEGMENT,
+            SubscriberReceiveError::Disconnected => iox2_subscriber_receive_error_e::DISCONNECTED,
+            SubscriberReceiveError::UnableToPinSampleMemory(_) => {
+                iox2_subscriber_receive_error_e::UNABLE_TO_PIN_SAMPLE_MEMORY
+            }
         }) as c_int
     }
 }
@@ -98,7 +104,7 @@ impl SubscriberUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<SubscriberUnion>
 pub struct iox2_subscriber_storage_t {
-    internal: [u8; 976], // magic number obtained with size_of::<Option<SubscriberUnion>>()
+    internal: [u8; 1216], // magic number obtained with size_of::<Option<SubscriberUnion>>()
 }
 
 #[repr(C)]
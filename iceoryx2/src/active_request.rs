@@ -0,0 +1,282 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .request_response::<u64, u64>()
+//! #   .open_or_create()?;
+//! # let server = service.server_builder().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     println!("received request {:?} from {:?}",
+//!         *active_request, active_request.header().client_port_id());
+//!
+//!     if active_request.is_connected() {
+//!         active_request.send_copy(*active_request * 2)?;
+//!     }
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ops::Deref;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_cal::shm_allocator::PointerOffset;
+use iceoryx2_cal::zero_copy_connection::{ZeroCopyReceiver, ZeroCopyReleaseError};
+
+use crate::port::server::{ClientConnection, ResponseLoanError, ResponseSendError, ServerBackend};
+use crate::raw_sample::{RawSample, RawSampleMut};
+use crate::response_mut::ResponseMut;
+use crate::service;
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+
+/// A request of a [`Client`](crate::port::client::Client) that was received by a
+/// [`Server`](crate::port::server::Server) via
+/// [`Server::receive()`](crate::port::server::Server::receive()). It is used to send the
+/// corresponding responses back to the [`Client`](crate::port::client::Client). When it goes
+/// out of scope, the request is returned to the [`Client`](crate::port::client::Client).
+///
+/// When the [`Client`](crate::port::client::Client) disconnects, for instance since its
+/// process died and its resources were cleaned up, the request can still be read but no
+/// more responses can be loaned or sent.
+pub struct ActiveRequest<
+    Service: service::Service,
+    RequestPayload: Debug,
+    RequestUserHeader: Debug,
+    ResponsePayload: Debug,
+    ResponseUserHeader: Debug,
+> {
+    ptr: RawSample<RequestHeader, RequestUserHeader, RequestPayload>,
+    backend: Arc<ServerBackend<Service>>,
+    connection: Arc<ClientConnection<Service>>,
+    offset: PointerOffset,
+    _response_payload: PhantomData<ResponsePayload>,
+    _response_user_header: PhantomData<ResponseUserHeader>,
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Debug
+    for ActiveRequest<
+        Service,
+        RequestPayload,
+        RequestUserHeader,
+        ResponsePayload,
+        ResponseUserHeader,
+    >
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ActiveRequest<{}, {}, {}, {}, {}> {{ offset: {:?}, header: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<RequestPayload>(),
+            core::any::type_name::<RequestUserHeader>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<ResponseUserHeader>(),
+            self.offset,
+            self.header()
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Deref
+    for ActiveRequest<
+        Service,
+        RequestPayload,
+        RequestUserHeader,
+        ResponsePayload,
+        ResponseUserHeader,
+    >
+{
+    type Target = RequestPayload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_payload_ref()
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Drop
+    for ActiveRequest<
+        Service,
+        RequestPayload,
+        RequestUserHeader,
+        ResponsePayload,
+        ResponseUserHeader,
+    >
+{
+    fn drop(&mut self) {
+        unsafe { self.connection.data_segment.unregister_offset(self.offset) };
+
+        match self.connection.request_receiver.release(self.offset) {
+            Ok(()) => (),
+            Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                fatal_panic!(from self, "This should never happen! The clients retrieve channel is full and the request cannot be returned.");
+            }
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    >
+    ActiveRequest<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    pub(crate) fn new(
+        backend: Arc<ServerBackend<Service>>,
+        connection: Arc<ClientConnection<Service>>,
+        offset: PointerOffset,
+        ptr: RawSample<RequestHeader, RequestUserHeader, RequestPayload>,
+    ) -> Self {
+        Self {
+            ptr,
+            backend,
+            connection,
+            offset,
+            _response_payload: PhantomData,
+            _response_user_header: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the payload of the request
+    pub fn payload(&self) -> &RequestPayload {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a reference to the user_header of the request
+    pub fn user_header(&self) -> &RequestUserHeader {
+        self.ptr.as_user_header_ref()
+    }
+
+    /// Returns a reference to the [`RequestHeader`] of the request.
+    pub fn header(&self) -> &RequestHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns true while the [`Client`](crate::port::client::Client) that sent the request
+    /// is still connected and can receive responses, otherwise false.
+    pub fn is_connected(&self) -> bool {
+        self.backend.is_client_connected(&self.connection)
+    }
+
+    /// Loans uninitialized memory for a response to this request. The response header is
+    /// already initialized and the payload must be written with
+    /// [`ResponseMut::write_payload()`] before it can be sent.
+    ///
+    /// On failure it returns [`ResponseLoanError`] describing the failure. When the
+    /// [`Client`](crate::port::client::Client) is no longer connected
+    /// [`ResponseLoanError::ClientDisconnected`] is returned.
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<
+        ResponseMut<Service, MaybeUninit<ResponsePayload>, ResponseUserHeader>,
+        ResponseLoanError,
+    > {
+        if !self.is_connected() {
+            fail!(from self, with ResponseLoanError::ClientDisconnected,
+                "Unable to loan response since the client {:?} is no longer connected.",
+                self.connection.client_id);
+        }
+
+        let message_type_details = &self.backend.static_config.response_message_type_details;
+        let (offset, header) = self
+            .backend
+            .allocate(message_type_details.sample_layout(1))?;
+
+        let header = header as *mut ResponseHeader;
+        unsafe {
+            header.write(ResponseHeader::new(
+                self.backend.server_id(),
+                self.header().request_id(),
+            ))
+        };
+        let user_header = message_type_details.user_header_ptr_from_header(header.cast());
+        let payload = message_type_details.payload_ptr_from_header(header.cast());
+
+        Ok(ResponseMut::new(
+            self.backend.clone(),
+            self.connection.clone(),
+            offset,
+            unsafe {
+                RawSampleMut::new_unchecked(
+                    header,
+                    user_header as *mut ResponseUserHeader,
+                    payload as *mut MaybeUninit<ResponsePayload>,
+                )
+            },
+        ))
+    }
+
+    /// Copies the value into a new response and sends it to the
+    /// [`Client`](crate::port::client::Client) that sent the request.
+    ///
+    /// On failure it returns [`ResponseSendError`] describing the failure.
+    pub fn send_copy(&self, value: ResponsePayload) -> Result<(), ResponseSendError> {
+        let response = self.loan_uninit()?;
+        response.write_payload(value).send()
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug + Default,
+        ResponseUserHeader: Debug,
+    >
+    ActiveRequest<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    /// Loans memory for a response to this request and initializes the payload with
+    /// [`Default::default()`].
+    ///
+    /// On failure it returns [`ResponseLoanError`] describing the failure.
+    pub fn loan(
+        &self,
+    ) -> Result<ResponseMut<Service, ResponsePayload, ResponseUserHeader>, ResponseLoanError> {
+        Ok(self
+            .loan_uninit()?
+            .write_payload(ResponsePayload::default()))
+    }
+}
@@ -0,0 +1,624 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Forwards the [`Sample`](crate::sample::Sample)s of a local publish-subscribe
+//! [`Service`](crate::service::Service) to another process, on the same or on another host,
+//! that republishes them in its own [`Service`](crate::service::Service) with the same name.
+//!
+//! The [`BridgeSender`](crate::bridge::BridgeSender) subscribes to the local
+//! [`Service`](crate::service::Service) and serializes every received payload with its
+//! [`Serialize`] implementation. The [`BridgeReceiver`](crate::bridge::BridgeReceiver) owns the
+//! [`BridgeEndpoint`](crate::bridge::BridgeEndpoint), verifies that the received payload
+//! matches its own payload type and publishes it locally. In contrast to the rest of iceoryx2,
+//! the bridge is not zero-copy.
+//!
+//! Every message contains the payload
+//! [`TypeDetail`](crate::service::static_config::message_type_details::TypeDetail) of the
+//! sender followed by the payload, both serialized as JSON. Since the payload is serialized,
+//! the sender and the receiver do not need to share the memory layout of the payload type.
+//! The endpoint is either
+//!
+//!  * a unix datagram socket, [`BridgeEndpoint::UnixDatagram`](crate::bridge::BridgeEndpoint::UnixDatagram),
+//!    which transfers every message in one datagram and is restricted to one host, or
+//!  * a TCP socket, [`BridgeEndpoint::Tcp`](crate::bridge::BridgeEndpoint::Tcp), which
+//!    prefixes every message with its length and connects processes on different hosts.
+//!
+//! A message must not exceed [`BridgeBuilder::max_message_size()`](crate::bridge::BridgeBuilder::max_message_size()).
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::bridge::{BridgeBuilder, BridgeEndpoint};
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = "My/Funk/ServiceName".try_into()?;
+//! let endpoint = BridgeEndpoint::UnixDatagram(FilePath::new(b"/tmp/my_funk_bridge.sock")?);
+//!
+//! // the remote side republishes the samples it receives on the endpoint
+//! let remote_node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let bridge_receiver = BridgeBuilder::new(&remote_node, &service_name, &endpoint)
+//!     .create_receiver::<u64>()?;
+//!
+//! // the local side forwards the samples of its service to the endpoint
+//! let local_node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let bridge_sender = BridgeBuilder::new(&local_node, &service_name, &endpoint)
+//!     .create_sender::<u64>()?;
+//!
+//! bridge_sender.forward()?;
+//! bridge_receiver.forward()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::cell::RefCell;
+use core::fmt::Debug;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::creation_mode::CreationMode;
+use iceoryx2_bb_posix::unix_datagram_socket::{
+    UnixDatagramReceiver, UnixDatagramReceiverBuilder, UnixDatagramSender,
+    UnixDatagramSenderBuilder,
+};
+use iceoryx2_bb_system_types::file_path::FilePath;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::node::Node;
+use crate::port::publisher::Publisher;
+use crate::port::subscriber::Subscriber;
+use crate::service;
+use crate::service::port_factory::publish_subscribe::PortFactory;
+use crate::service::port_factory::PortFactory as _;
+use crate::service::service_name::ServiceName;
+use crate::service::static_config::message_type_details::TypeDetail;
+
+const MESSAGE_LENGTH_SIZE: usize = core::mem::size_of::<u32>();
+
+/// The default value of [`BridgeBuilder::max_message_size()`].
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 65536;
+
+/// The endpoint over which a [`BridgeSender`] and a [`BridgeReceiver`] communicate.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeEndpoint {
+    /// A unix datagram socket at the provided path. It can only connect processes on the same
+    /// host.
+    UnixDatagram(FilePath),
+    /// A TCP socket at the provided address. The [`BridgeReceiver`] listens on the address
+    /// and the [`BridgeSender`] connects to it.
+    Tcp(SocketAddr),
+}
+
+impl core::fmt::Display for BridgeEndpoint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BridgeEndpoint::UnixDatagram(path) => std::write!(f, "unix://{}", path),
+            BridgeEndpoint::Tcp(address) => std::write!(f, "tcp://{}", address),
+        }
+    }
+}
+/// Failures that can occur when a [`BridgeSender`] or [`BridgeReceiver`] is created with the
+/// [`BridgeBuilder`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum BridgeCreateError {
+    /// The local [`Service`](crate::service::Service) could not be opened or created.
+    UnableToOpenOrCreateService,
+    /// The [`Subscriber`] of the [`BridgeSender`] could not be created.
+    UnableToCreateSubscriber,
+    /// The [`Publisher`] of the [`BridgeReceiver`] could not be created.
+    UnableToCreatePublisher,
+    /// The socket endpoint of the [`BridgeReceiver`] could not be created.
+    UnableToCreateSocket,
+    /// The [`BridgeSender`] could not connect to the socket endpoint. The [`BridgeReceiver`]
+    /// must be created first.
+    UnableToConnectToSocket,
+}
+
+impl core::fmt::Display for BridgeCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "BridgeCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for BridgeCreateError {}
+
+/// Failures that can occur in [`BridgeSender::forward()`] or [`BridgeReceiver::forward()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum BridgeForwardError {
+    /// A [`Sample`](crate::sample::Sample) could not be received from the local
+    /// [`Service`](crate::service::Service).
+    ReceiveFailure,
+    /// A received payload could not be published in the local
+    /// [`Service`](crate::service::Service).
+    PublishFailure,
+    /// The socket failed to send or receive a message.
+    SocketFailure,
+    /// A payload could not be serialized or the serialized message exceeds
+    /// [`BridgeBuilder::max_message_size()`].
+    SerializationFailure,
+    /// A received message could not be deserialized.
+    DeserializationFailure,
+    /// A message was received whose payload type does not match the payload type of the
+    /// local [`Service`](crate::service::Service).
+    PayloadTypeMismatch,
+}
+
+impl core::fmt::Display for BridgeForwardError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "BridgeForwardError::{:?}", self)
+    }
+}
+
+impl std::error::Error for BridgeForwardError {}
+
+#[derive(Serialize)]
+struct OutgoingMessage<'a, Payload> {
+    type_detail: &'a TypeDetail,
+    payload: &'a Payload,
+}
+
+#[derive(Deserialize)]
+struct IncomingMessage<Payload> {
+    type_detail: TypeDetail,
+    payload: Payload,
+}
+
+/// Creates either the sending side, a [`BridgeSender`], or the receiving side, a
+/// [`BridgeReceiver`], of a bridge for the publish-subscribe
+/// [`Service`](crate::service::Service) with the provided [`ServiceName`].
+#[derive(Debug)]
+pub struct BridgeBuilder<'node, Service: service::Service> {
+    node: &'node Node<Service>,
+    service_name: ServiceName,
+    endpoint: BridgeEndpoint,
+    max_message_size: usize,
+}
+
+impl<'node, Service: service::Service> BridgeBuilder<'node, Service> {
+    /// Creates a new [`BridgeBuilder`] that bridges the [`Service`](crate::service::Service)
+    /// with the name `service_name` over the provided [`BridgeEndpoint`].
+    pub fn new(
+        node: &'node Node<Service>,
+        service_name: &ServiceName,
+        endpoint: &BridgeEndpoint,
+    ) -> Self {
+        Self {
+            node,
+            service_name: service_name.clone(),
+            endpoint: endpoint.clone(),
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+        }
+    }
+
+    /// Defines the maximum size in bytes of a serialized message, consisting of the payload
+    /// type details and the payload. The [`BridgeSender`] refuses to forward larger messages
+    /// and the [`BridgeReceiver`] discards them. With
+    /// [`BridgeEndpoint::UnixDatagram`] it is additionally limited by the maximum datagram
+    /// size of the platform. Default is [`DEFAULT_MAX_MESSAGE_SIZE`].
+    pub fn max_message_size(mut self, value: usize) -> Self {
+        self.max_message_size = value;
+        self
+    }
+
+    fn open_or_create_service<Payload: Debug + 'static>(
+        &self,
+    ) -> Result<PortFactory<Service, Payload, ()>, BridgeCreateError> {
+        Ok(fail!(from self,
+                when self.node.service_builder(&self.service_name).publish_subscribe::<Payload>().open_or_create(),
+                with BridgeCreateError::UnableToOpenOrCreateService,
+                "Unable to open or create the service to bridge."))
+    }
+
+    /// Creates the [`BridgeSender`] that forwards all [`Sample`](crate::sample::Sample)s of the
+    /// local [`Service`](crate::service::Service) to the socket endpoint. The
+    /// [`BridgeReceiver`] of the endpoint must already exist.
+    pub fn create_sender<Payload: Debug + Serialize + 'static>(
+        self,
+    ) -> Result<BridgeSender<Service, Payload>, BridgeCreateError> {
+        let msg = "Unable to create bridge sender";
+        let service = self.open_or_create_service::<Payload>()?;
+        let type_detail = service
+            .static_config()
+            .message_type_details()
+            .payload
+            .clone();
+
+        let subscriber = fail!(from self, when service.subscriber_builder().create(),
+                with BridgeCreateError::UnableToCreateSubscriber,
+                "{} since the subscriber could not be created.", msg);
+
+        let socket = match &self.endpoint {
+            BridgeEndpoint::UnixDatagram(path) => SenderSocket::UnixDatagram(
+                fail!(from self, when UnixDatagramSenderBuilder::new(path).create(),
+                    with BridgeCreateError::UnableToConnectToSocket,
+                    "{} since the socket endpoint {} could not be connected.", msg, self.endpoint),
+            ),
+            BridgeEndpoint::Tcp(address) => SenderSocket::Tcp(RefCell::new(
+                fail!(from self, when TcpStream::connect(address),
+                    with BridgeCreateError::UnableToConnectToSocket,
+                    "{} since the socket endpoint {} could not be connected.", msg, self.endpoint),
+            )),
+        };
+
+        Ok(BridgeSender {
+            subscriber,
+            socket,
+            endpoint: self.endpoint,
+            type_detail,
+            max_message_size: self.max_message_size,
+        })
+    }
+
+    /// Creates the [`BridgeReceiver`] that owns the socket endpoint and publishes all received
+    /// payloads in the local [`Service`](crate::service::Service). An already existing unix
+    /// datagram socket endpoint is replaced.
+    pub fn create_receiver<Payload: Debug + DeserializeOwned + 'static>(
+        self,
+    ) -> Result<BridgeReceiver<Service, Payload>, BridgeCreateError> {
+        let msg = "Unable to create bridge receiver";
+        let service = self.open_or_create_service::<Payload>()?;
+        let type_detail = service
+            .static_config()
+            .message_type_details()
+            .payload
+            .clone();
+
+        let publisher = fail!(from self, when service.publisher_builder().create(),
+                with BridgeCreateError::UnableToCreatePublisher,
+                "{} since the publisher could not be created.", msg);
+
+        let mut endpoint = self.endpoint.clone();
+        let socket = match &self.endpoint {
+            BridgeEndpoint::UnixDatagram(path) => ReceiverSocket::UnixDatagram(fail!(from self,
+                    when UnixDatagramReceiverBuilder::new(path)
+                            .creation_mode(CreationMode::PurgeAndCreate)
+                            .create(),
+                    with BridgeCreateError::UnableToCreateSocket,
+                    "{} since the socket endpoint {} could not be created.", msg, self.endpoint)),
+            BridgeEndpoint::Tcp(address) => {
+                let listener = fail!(from self, when TcpListener::bind(address),
+                    with BridgeCreateError::UnableToCreateSocket,
+                    "{} since the socket endpoint {} could not be created.", msg, self.endpoint);
+                fail!(from self, when listener.set_nonblocking(true),
+                    with BridgeCreateError::UnableToCreateSocket,
+                    "{} since the socket endpoint {} could not be set to non-blocking mode.", msg, self.endpoint);
+                // contains the actual port when the port 0 was requested
+                endpoint = BridgeEndpoint::Tcp(fail!(from self, when listener.local_addr(),
+                    with BridgeCreateError::UnableToCreateSocket,
+                    "{} since the address of the socket endpoint {} could not be acquired.", msg, self.endpoint));
+
+                ReceiverSocket::Tcp {
+                    listener,
+                    connections: RefCell::new(vec![]),
+                }
+            }
+        };
+
+        Ok(BridgeReceiver {
+            publisher,
+            socket,
+            endpoint,
+            type_detail,
+            max_message_size: self.max_message_size,
+        })
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+enum SenderSocket {
+    UnixDatagram(UnixDatagramSender),
+    Tcp(RefCell<TcpStream>),
+}
+
+/// The sending side of a bridge. It forwards every [`Sample`](crate::sample::Sample) of the
+/// local [`Service`](crate::service::Service) to the [`BridgeReceiver`] of the socket endpoint.
+/// Created with [`BridgeBuilder::create_sender()`].
+#[derive(Debug)]
+pub struct BridgeSender<Service: service::Service, Payload: Debug + Serialize + 'static> {
+    subscriber: Subscriber<Service, Payload, ()>,
+    socket: SenderSocket,
+    endpoint: BridgeEndpoint,
+    type_detail: TypeDetail,
+    max_message_size: usize,
+}
+
+impl<Service: service::Service, Payload: Debug + Serialize + 'static>
+    BridgeSender<Service, Payload>
+{
+    /// Forwards all [`Sample`](crate::sample::Sample)s that were received since the last call
+    /// and returns how many were forwarded. When the socket cannot take any more messages, the
+    /// forwarding blocks until the [`BridgeReceiver`] made space again.
+    pub fn forward(&self) -> Result<usize, BridgeForwardError> {
+        let msg = "Unable to forward samples";
+        let mut number_of_forwarded_samples = 0;
+        let mut message = vec![];
+
+        while let Some(sample) = fail!(from self, when self.subscriber.receive(),
+                with BridgeForwardError::ReceiveFailure,
+                "{} since a sample could not be received.", msg)
+        {
+            message.clear();
+            if let SenderSocket::Tcp(_) = self.socket {
+                message.extend_from_slice(&[0; MESSAGE_LENGTH_SIZE]);
+            }
+
+            let outgoing = OutgoingMessage {
+                type_detail: &self.type_detail,
+                payload: &*sample,
+            };
+            fail!(from self, when serde_json::to_writer(&mut message, &outgoing),
+                with BridgeForwardError::SerializationFailure,
+                "{} since the payload could not be serialized.", msg);
+
+            match &self.socket {
+                SenderSocket::UnixDatagram(socket) => {
+                    self.verify_message_size(message.len())?;
+                    fail!(from self, when socket.blocking_send(&message),
+                        with BridgeForwardError::SocketFailure,
+                        "{} since the message could not be sent to {}.", msg, self.endpoint);
+                }
+                SenderSocket::Tcp(stream) => {
+                    let message_len = message.len() - MESSAGE_LENGTH_SIZE;
+                    self.verify_message_size(message_len)?;
+                    message[..MESSAGE_LENGTH_SIZE]
+                        .copy_from_slice(&(message_len as u32).to_le_bytes());
+                    fail!(from self, when stream.borrow_mut().write_all(&message),
+                        with BridgeForwardError::SocketFailure,
+                        "{} since the message could not be sent to {}.", msg, self.endpoint);
+                }
+            }
+
+            number_of_forwarded_samples += 1;
+        }
+
+        Ok(number_of_forwarded_samples)
+    }
+
+    fn verify_message_size(&self, message_len: usize) -> Result<(), BridgeForwardError> {
+        if message_len > self.max_message_size {
+            fail!(from self, with BridgeForwardError::SerializationFailure,
+                "Unable to forward sample since the serialized message has a size of {} bytes and exceeds the maximum message size of {} bytes.",
+                message_len, self.max_message_size);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the underlying [`Subscriber`] of the local [`Service`](crate::service::Service).
+    pub fn subscriber(&self) -> &Subscriber<Service, Payload, ()> {
+        &self.subscriber
+    }
+}
+
+#[derive(Debug)]
+struct TcpConnection {
+    stream: TcpStream,
+    buffer: Vec<u8>,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+enum ReceiverSocket {
+    UnixDatagram(UnixDatagramReceiver),
+    Tcp {
+        listener: TcpListener,
+        connections: RefCell<Vec<TcpConnection>>,
+    },
+}
+
+/// The receiving side of a bridge. It republishes every payload that was received on the
+/// socket endpoint in the local [`Service`](crate::service::Service). Created with
+/// [`BridgeBuilder::create_receiver()`].
+#[derive(Debug)]
+pub struct BridgeReceiver<Service: service::Service, Payload: Debug + DeserializeOwned + 'static> {
+    publisher: Publisher<Service, Payload, ()>,
+    socket: ReceiverSocket,
+    endpoint: BridgeEndpoint,
+    type_detail: TypeDetail,
+    max_message_size: usize,
+}
+
+impl<Service: service::Service, Payload: Debug + DeserializeOwned + 'static>
+    BridgeReceiver<Service, Payload>
+{
+    /// Republishes all payloads that were received on the socket endpoint since the last call
+    /// and returns how many were republished. If a received payload does not match the payload
+    /// type of the local [`Service`](crate::service::Service) it is discarded and
+    /// [`BridgeForwardError::PayloadTypeMismatch`] is returned. With [`BridgeEndpoint::Tcp`]
+    /// it also accepts the connections of new [`BridgeSender`]s.
+    pub fn forward(&self) -> Result<usize, BridgeForwardError> {
+        match &self.socket {
+            ReceiverSocket::UnixDatagram(socket) => self.forward_datagrams(socket),
+            ReceiverSocket::Tcp {
+                listener,
+                connections,
+            } => {
+                self.accept_connections(listener, &mut connections.borrow_mut())?;
+                self.forward_streams(&mut connections.borrow_mut())
+            }
+        }
+    }
+
+    fn forward_datagrams(
+        &self,
+        socket: &UnixDatagramReceiver,
+    ) -> Result<usize, BridgeForwardError> {
+        let msg = "Unable to republish received payloads";
+        // one additional byte to detect messages that are larger than allowed
+        let mut message = vec![0u8; self.max_message_size + 1];
+        let mut number_of_republished_samples = 0;
+
+        loop {
+            let received_bytes = fail!(from self, when socket.try_receive(&mut message),
+                with BridgeForwardError::SocketFailure,
+                "{} since the message could not be received from {}.", msg, self.endpoint);
+
+            if received_bytes == 0 {
+                return Ok(number_of_republished_samples);
+            }
+
+            if received_bytes as usize > self.max_message_size {
+                fail!(from self, with BridgeForwardError::DeserializationFailure,
+                    "{} since a message exceeds the maximum message size of {} bytes.",
+                    msg, self.max_message_size);
+            }
+
+            self.republish(&message[..received_bytes as usize])?;
+            number_of_republished_samples += 1;
+        }
+    }
+
+    fn accept_connections(
+        &self,
+        listener: &TcpListener,
+        connections: &mut Vec<TcpConnection>,
+    ) -> Result<(), BridgeForwardError> {
+        let msg = "Unable to accept bridge senders";
+        loop {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    fail!(from self, when stream.set_nonblocking(true),
+                        with BridgeForwardError::SocketFailure,
+                        "{} since the connection could not be set to non-blocking mode.", msg);
+                    connections.push(TcpConnection {
+                        stream,
+                        buffer: vec![],
+                    });
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return Ok(()),
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    fail!(from self, with BridgeForwardError::SocketFailure,
+                        "{} since the connection could not be accepted on {} ({:?}).", msg, self.endpoint, e);
+                }
+            }
+        }
+    }
+
+    fn forward_streams(
+        &self,
+        connections: &mut Vec<TcpConnection>,
+    ) -> Result<usize, BridgeForwardError> {
+        let msg = "Unable to republish received payloads";
+        let mut number_of_republished_samples = 0;
+        let mut chunk = [0u8; 4096];
+
+        let mut n = 0;
+        while n < connections.len() {
+            let connection = &mut connections[n];
+            let mut is_closed = false;
+            loop {
+                match connection.stream.read(&mut chunk) {
+                    Ok(0) => {
+                        is_closed = true;
+                        break;
+                    }
+                    Ok(received_bytes) => connection
+                        .buffer
+                        .extend_from_slice(&chunk[..received_bytes]),
+                    Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                    Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                    Err(e) => {
+                        fail!(from self, with BridgeForwardError::SocketFailure,
+                            "{} since the message could not be received from {} ({:?}).", msg, self.endpoint, e);
+                    }
+                }
+            }
+
+            let mut consumed_bytes = 0;
+            let mut result = Ok(());
+            while connection.buffer.len() - consumed_bytes >= MESSAGE_LENGTH_SIZE {
+                let mut length = [0u8; MESSAGE_LENGTH_SIZE];
+                length.copy_from_slice(
+                    &connection.buffer[consumed_bytes..consumed_bytes + MESSAGE_LENGTH_SIZE],
+                );
+                let message_len = u32::from_le_bytes(length) as usize;
+
+                if message_len > self.max_message_size {
+                    // the stream cannot be resynchronized, therefore the connection is dropped
+                    connections.remove(n);
+                    fail!(from self, with BridgeForwardError::DeserializationFailure,
+                        "{} since a message exceeds the maximum message size of {} bytes.",
+                        msg, self.max_message_size);
+                }
+
+                let message_start = consumed_bytes + MESSAGE_LENGTH_SIZE;
+                if connection.buffer.len() - message_start < message_len {
+                    break;
+                }
+
+                consumed_bytes = message_start + message_len;
+                result = self.republish(&connection.buffer[message_start..consumed_bytes]);
+                if result.is_err() {
+                    break;
+                }
+                number_of_republished_samples += 1;
+            }
+            connection.buffer.drain(..consumed_bytes);
+            result?;
+
+            if is_closed {
+                connections.remove(n);
+            } else {
+                n += 1;
+            }
+        }
+
+        Ok(number_of_republished_samples)
+    }
+
+    fn republish(&self, message: &[u8]) -> Result<(), BridgeForwardError> {
+        let msg = "Unable to republish received payload";
+        let message: IncomingMessage<Payload> = fail!(from self,
+            when serde_json::from_slice(message),
+            with BridgeForwardError::DeserializationFailure,
+            "{} since the message could not be deserialized.", msg);
+
+        // the size and alignment can differ between hosts, the serialized representation
+        // only depends on the type itself
+        if message.type_detail.variant != self.type_detail.variant
+            || message.type_detail.type_name != self.type_detail.type_name
+        {
+            fail!(from self, with BridgeForwardError::PayloadTypeMismatch,
+                "{} since a message with a payload of type {} was received but {} was expected.",
+                msg, message.type_detail.type_name, self.type_detail.type_name);
+        }
+
+        let sample = fail!(from self, when self.publisher.loan_uninit(),
+            with BridgeForwardError::PublishFailure,
+            "{} since a sample could not be loaned.", msg);
+
+        fail!(from self, when sample.write_payload(message.payload).send(),
+            with BridgeForwardError::PublishFailure,
+            "{} since the sample could not be sent.", msg);
+
+        Ok(())
+    }
+
+    /// Returns the [`BridgeEndpoint`] the [`BridgeReceiver`] receives from. When it was
+    /// created with a [`BridgeEndpoint::Tcp`] port of 0, it contains the port that was
+    /// assigned by the operating system.
+    pub fn endpoint(&self) -> &BridgeEndpoint {
+        &self.endpoint
+    }
+
+    /// Returns the underlying [`Publisher`] of the local [`Service`](crate::service::Service).
+    pub fn publisher(&self) -> &Publisher<Service, Payload, ()> {
+        &self.publisher
+    }
+}
@@ -53,3 +53,20 @@ fn sending_uninitialized_sample_fails_to_compile() {}
 /// ```
 #[cfg(doctest)]
 fn loan_with_type_not_implementing_default_fails_to_compile() {}
+
+/// ```compile_fail,E0599
+/// use iceoryx2::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let node = NodeBuilder::new().create::<ipc::Service>()?;
+/// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+///     .publish_subscribe::<u64>()
+///     .open_subscriber_only()?;
+///
+/// let publisher = service.publisher_builder().create()?; // should fail to compile since a subscriber-only service cannot create publishers
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn creating_publisher_from_subscriber_only_service_fails_to_compile() {}
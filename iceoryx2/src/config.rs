@@ -55,6 +55,44 @@
 //! # }
 //! ```
 //!
+//! ## Override Settings With Environment Variables
+//!
+//! Single settings can be overridden with environment variables via
+//! [`crate::config::Config::with_env_overrides()`]. Every variable is named
+//! `IOX2_<SECTION>_<SETTING>` where the setting is the field name in upper case and the section
+//! is one of
+//!
+//!  * `PUBSUB` for [`crate::config::Defaults::publish_subscribe`], e.g.
+//!    `IOX2_PUBSUB_MAX_SUBSCRIBERS`
+//!  * `EVENT` for [`crate::config::Defaults::event`], e.g. `IOX2_EVENT_MAX_LISTENERS`
+//!  * `REQRES` for [`crate::config::Defaults::request_response`], e.g. `IOX2_REQRES_MAX_CLIENTS`
+//!  * `NODE` for [`crate::config::Global::node`], e.g. `IOX2_NODE_REAP_DEAD_PORTS_ON_WAIT`
+//!  * `SERVICE` for [`crate::config::Global::service`], e.g. `IOX2_SERVICE_CREATION_TIMEOUT`
+//!
+//! Numbers are given in decimal, booleans as `true` or `false`, durations in milliseconds and
+//! optional settings can be unset with `none`. The
+//! [`UnableToDeliverStrategy`](crate::service::port_factory::publisher::UnableToDeliverStrategy) is
+//! either `Block` or `DiscardSample` and the
+//! [`StaticConfigFormat`](crate::service::static_config::StaticConfigFormat) is either `Toml` or
+//! `Json`.
+//!
+//! An environment variable takes precedence over the setting in the config file, which takes
+//! precedence over the built-in default.
+//!
+//! ```no_run
+//! use iceoryx2::config::Config;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // IOX2_PUBSUB_MAX_SUBSCRIBERS=16 overrides the value of the config file
+//! let custom_config = Config::from_file(
+//!     &FilePath::new(b"my/custom/config/file.toml")?)?
+//!     .with_env_overrides()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Generate Config From Custom File
 //!
 //! ```no_run
@@ -69,6 +107,8 @@
 //! # }
 //! ```
 
+extern crate alloc;
+use alloc::collections::BTreeMap;
 use core::time::Duration;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::{lazy_singleton::*, CallbackProgression};
@@ -85,6 +125,7 @@ use serde::{Deserialize, Serialize};
 use iceoryx2_bb_log::{fail, fatal_panic, trace, warn};
 
 use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::service::static_config::StaticConfigFormat;
 
 const DEFAULT_CONFIG_FILE_NAME: &[u8] = b"iceoryx2.toml";
 const RELATIVE_LOCAL_CONFIG_PATH: &[u8] = b"config";
@@ -111,6 +152,11 @@ pub enum ConfigCreationError {
     ConfigFileDoesNotExist,
     /// Since the config file could not be opened
     UnableToOpenConfigFile,
+    /// An environment variable that overrides a setting contains a value that cannot be parsed.
+    MalformedEnvironmentVariable,
+    /// The loaded settings were rejected by [`Config::validate()`]. The underlying cause is
+    /// available via [`std::error::Error::source()`].
+    InvalidConfig(ConfigValidationError),
 }
 
 impl core::fmt::Display for ConfigCreationError {
@@ -119,7 +165,45 @@ impl core::fmt::Display for ConfigCreationError {
     }
 }
 
-impl std::error::Error for ConfigCreationError {}
+impl std::error::Error for ConfigCreationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigCreationError::InvalidConfig(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Failures reported by [`Config::validate()`] when the settings of a [`Config`] cannot be
+/// used to create [`crate::service::Service`]s.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConfigValidationError {
+    /// The worst-case number of sample slots of a publish-subscribe
+    /// [`crate::service::Service`] exceeds [`PublishSubscribe::max_sample_slots`].
+    ExceedsMaxSampleSlots,
+    /// [`PublishSubscribe::subscriber_max_buffer_size`] is zero. A
+    /// [`crate::port::subscriber::Subscriber`] without a buffer cannot receive anything.
+    SubscriberMaxBufferSizeIsZero,
+    /// [`PublishSubscribe::subscriber_max_borrowed_samples`] is zero. A
+    /// [`crate::port::subscriber::Subscriber`] that cannot borrow a sample cannot receive
+    /// anything.
+    SubscriberMaxBorrowedSamplesIsZero,
+    /// [`Event::max_listeners`], [`Event::max_notifiers`] or [`Event::max_nodes`] is zero. An
+    /// event [`crate::service::Service`] could not be opened or used.
+    EventMaxPortsOrNodesIsZero,
+    /// [`Event::notifier_created_event`], [`Event::notifier_dropped_event`] or
+    /// [`Event::notifier_dead_event`] lies outside of the event id range that ends at
+    /// [`Event::event_id_max_value`].
+    EventIdOutOfRange,
+}
+
+impl core::fmt::Display for ConfigValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ConfigValidationError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ConfigValidationError {}
 
 /// All configurable settings of a [`crate::service::Service`].
 #[non_exhaustive]
@@ -137,10 +221,32 @@ pub struct Service {
     /// Defines the time of how long another process will wait until the service creation is
     /// finalized
     pub creation_timeout: Duration,
+    /// Defines how often it is tried again to open the dynamic config of a service that does
+    /// not yet exist or is not yet initialized although its static config is already
+    /// available. This happens when the creator is still initializing the service.
+    pub dynamic_config_open_retries: usize,
+    /// The time between two attempts to open the dynamic config of a service.
+    pub dynamic_config_open_retry_delay: Duration,
     /// The suffix of a one-to-one connection
     pub connection_suffix: FileName,
     /// The suffix of a one-to-one connection
     pub event_connection_suffix: FileName,
+    /// When true, [`crate::service::service_name::ServiceName`]s are converted to lowercase
+    /// before they are mapped to the resources of a [`crate::service::Service`], so that
+    /// `"My/Funk"` and `"my/funk"` refer to the same [`crate::service::Service`].
+    /// Changing the setting is not retroactive, [`crate::service::Service`]s that were created
+    /// before with a name that is not lowercase cannot be opened anymore.
+    pub case_insensitive_names: bool,
+    /// Defines the format in which the static configuration of a newly created
+    /// [`crate::service::Service`] is stored. The format of an existing
+    /// [`crate::service::Service`] is detected when it is opened, therefore it can be changed
+    /// at any time.
+    pub static_config_format: StaticConfigFormat,
+    /// When true, opening an existing [`crate::service::Service`] removes all ports whose
+    /// owning process is no longer alive from it and releases their resources. The liveness
+    /// is determined via the process id, therefore it must be disabled when processes in
+    /// different pid namespaces share the same services.
+    pub cleanup_dead_ports_on_open: bool,
 }
 
 /// All configurable settings of a [`crate::node::Node`].
@@ -164,6 +270,21 @@ pub struct Node {
     /// cleans up all their stale resources whenever an existing [`Node`](crate::node::Node) is
     /// going out of scope.
     pub cleanup_dead_nodes_on_destruction: bool,
+    /// When true, [`Node::wait()`](crate::node::Node::wait()) removes all ports of the
+    /// services the [`Node`](crate::node::Node) is registered at whose owning process is no
+    /// longer alive. The liveness is determined via the process id, therefore it must not be
+    /// enabled when processes in different pid namespaces share the same services.
+    ///
+    /// The reaping checks the liveness of the owner of every port of every registered service,
+    /// which costs at least one syscall per port. It is therefore performed at most once per
+    /// [`Node::reap_dead_ports_interval`] and not in every call of
+    /// [`Node::wait()`](crate::node::Node::wait()).
+    pub reap_dead_ports_on_wait: bool,
+    /// The minimum time between two reapings of dead ports in
+    /// [`Node::wait()`](crate::node::Node::wait()), see [`Node::reap_dead_ports_on_wait`].
+    /// A short interval removes dead ports earlier but adds the cost of the reaping to more
+    /// wait cycles.
+    pub reap_dead_ports_interval: Duration,
 }
 
 /// The global settings
@@ -273,6 +394,14 @@ pub struct PublishSubscribe {
     /// disconnected from a service and the connection
     /// still contains unconsumed [`Sample`](crate::sample::Sample)s.
     pub subscriber_expired_connection_buffer: usize,
+    /// The maximum number of sample slots a publish-subscribe [`crate::service::Service`] may
+    /// require in the worst case, summed up over the data segments of all
+    /// [`crate::port::publisher::Publisher`]s. Every
+    /// [`crate::port::publisher::Publisher`] requires a slot for every sample it can loan,
+    /// that is part of its history or that can be stored in the buffer or be borrowed by
+    /// every [`crate::port::subscriber::Subscriber`]. The creation of a
+    /// [`crate::service::Service`] that exceeds the limit fails.
+    pub max_sample_slots: usize,
 }
 
 /// Default settings for the event messaging pattern. These settings are used unless
@@ -334,6 +463,46 @@ pub struct RequestResonse {
     pub max_nodes: usize,
 }
 
+/// Describes where the value of a setting originates from. See
+/// [`StaticConfigProvenance`](crate::service::static_config::publish_subscribe::StaticConfigProvenance).
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConfigSource {
+    /// The value was not configured and the built-in default of iceoryx2 is used.
+    #[default]
+    BuiltInDefault,
+    /// The value was loaded from a config file with [`Config::from_file()`].
+    ConfigFile,
+    /// The value was overridden by an environment variable with
+    /// [`Config::with_env_overrides()`].
+    EnvironmentVariable,
+    /// The value was set explicitly with the corresponding method of the service builder.
+    Builder,
+    /// The value was defined by the creator of an already existing
+    /// [`crate::service::Service`] that was opened.
+    ExistingService,
+}
+
+/// Tracks the [`ConfigSource`] of the settings of a [`Config`]. Settings that are modified
+/// directly keep the source of the value they replaced.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub(crate) struct ConfigProvenance {
+    // settings that are not contained are built-in defaults
+    sources: BTreeMap<&'static str, ConfigSource>,
+}
+
+/// Wraps a value that describes how a [`Config`] was created but is not part of its content.
+/// Two configs with the same settings are equal regardless of the wrapped value.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct NotCompared<T>(T);
+
+impl<T> PartialEq for NotCompared<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for NotCompared<T> {}
+
 /// Represents the configuration that iceoryx2 will utilize. It is divided into two sections:
 /// the [Global] settings, which must align with the iceoryx2 instance the application intends to
 /// join, and the [Defaults] for communication within that iceoryx2 instance. The user has the
@@ -346,10 +515,228 @@ pub struct Config {
     pub global: Global,
     /// Default settings
     pub defaults: Defaults,
+    #[serde(skip)]
+    pub(crate) provenance: NotCompared<ConfigProvenance>,
 }
 
 static ICEORYX2_CONFIG: LazySingleton<Config> = LazySingleton::<Config>::new();
 
+trait EnvValue: Sized {
+    fn from_env_value(value: &str) -> Option<Self>;
+}
+
+impl EnvValue for usize {
+    fn from_env_value(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+}
+
+impl EnvValue for bool {
+    fn from_env_value(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+}
+
+impl EnvValue for Duration {
+    fn from_env_value(value: &str) -> Option<Self> {
+        Some(Duration::from_millis(value.parse().ok()?))
+    }
+}
+
+impl EnvValue for UnableToDeliverStrategy {
+    fn from_env_value(value: &str) -> Option<Self> {
+        match value {
+            "Block" => Some(UnableToDeliverStrategy::Block),
+            "DiscardSample" => Some(UnableToDeliverStrategy::DiscardSample),
+            _ => None,
+        }
+    }
+}
+
+impl EnvValue for StaticConfigFormat {
+    fn from_env_value(value: &str) -> Option<Self> {
+        match value {
+            "Toml" => Some(StaticConfigFormat::Toml),
+            "Json" => Some(StaticConfigFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+impl<T: EnvValue> EnvValue for Option<T> {
+    fn from_env_value(value: &str) -> Option<Self> {
+        match value {
+            "none" => Some(None),
+            v => Some(Some(T::from_env_value(v)?)),
+        }
+    }
+}
+
+type EnvOverride = (
+    &'static str,
+    &'static [&'static str],
+    fn(&mut Config, &str) -> Option<()>,
+);
+
+macro_rules! env_override {
+    ($name:literal, $($field:ident).+) => {
+        ($name, &[$(stringify!($field)),+], |config: &mut Config, value: &str| {
+            config.$($field).+ = EnvValue::from_env_value(value)?;
+            Some(())
+        })
+    };
+}
+
+const ENV_OVERRIDES: &[EnvOverride] = &[
+    env_override!(
+        "IOX2_SERVICE_CREATION_TIMEOUT",
+        global.service.creation_timeout
+    ),
+    env_override!(
+        "IOX2_SERVICE_DYNAMIC_CONFIG_OPEN_RETRIES",
+        global.service.dynamic_config_open_retries
+    ),
+    env_override!(
+        "IOX2_SERVICE_DYNAMIC_CONFIG_OPEN_RETRY_DELAY",
+        global.service.dynamic_config_open_retry_delay
+    ),
+    env_override!(
+        "IOX2_SERVICE_CASE_INSENSITIVE_NAMES",
+        global.service.case_insensitive_names
+    ),
+    env_override!(
+        "IOX2_SERVICE_STATIC_CONFIG_FORMAT",
+        global.service.static_config_format
+    ),
+    env_override!(
+        "IOX2_SERVICE_CLEANUP_DEAD_PORTS_ON_OPEN",
+        global.service.cleanup_dead_ports_on_open
+    ),
+    env_override!(
+        "IOX2_NODE_CLEANUP_DEAD_NODES_ON_CREATION",
+        global.node.cleanup_dead_nodes_on_creation
+    ),
+    env_override!(
+        "IOX2_NODE_CLEANUP_DEAD_NODES_ON_DESTRUCTION",
+        global.node.cleanup_dead_nodes_on_destruction
+    ),
+    env_override!(
+        "IOX2_NODE_REAP_DEAD_PORTS_ON_WAIT",
+        global.node.reap_dead_ports_on_wait
+    ),
+    env_override!(
+        "IOX2_NODE_REAP_DEAD_PORTS_INTERVAL",
+        global.node.reap_dead_ports_interval
+    ),
+    env_override!(
+        "IOX2_PUBSUB_MAX_SUBSCRIBERS",
+        defaults.publish_subscribe.max_subscribers
+    ),
+    env_override!(
+        "IOX2_PUBSUB_MAX_PUBLISHERS",
+        defaults.publish_subscribe.max_publishers
+    ),
+    env_override!(
+        "IOX2_PUBSUB_MAX_NODES",
+        defaults.publish_subscribe.max_nodes
+    ),
+    env_override!(
+        "IOX2_PUBSUB_SUBSCRIBER_MAX_BUFFER_SIZE",
+        defaults.publish_subscribe.subscriber_max_buffer_size
+    ),
+    env_override!(
+        "IOX2_PUBSUB_SUBSCRIBER_MAX_BORROWED_SAMPLES",
+        defaults.publish_subscribe.subscriber_max_borrowed_samples
+    ),
+    env_override!(
+        "IOX2_PUBSUB_PUBLISHER_MAX_LOANED_SAMPLES",
+        defaults.publish_subscribe.publisher_max_loaned_samples
+    ),
+    env_override!(
+        "IOX2_PUBSUB_PUBLISHER_HISTORY_SIZE",
+        defaults.publish_subscribe.publisher_history_size
+    ),
+    env_override!(
+        "IOX2_PUBSUB_ENABLE_SAFE_OVERFLOW",
+        defaults.publish_subscribe.enable_safe_overflow
+    ),
+    env_override!(
+        "IOX2_PUBSUB_UNABLE_TO_DELIVER_STRATEGY",
+        defaults.publish_subscribe.unable_to_deliver_strategy
+    ),
+    env_override!(
+        "IOX2_PUBSUB_SUBSCRIBER_EXPIRED_CONNECTION_BUFFER",
+        defaults
+            .publish_subscribe
+            .subscriber_expired_connection_buffer
+    ),
+    env_override!(
+        "IOX2_PUBSUB_MAX_SAMPLE_SLOTS",
+        defaults.publish_subscribe.max_sample_slots
+    ),
+    env_override!("IOX2_EVENT_MAX_LISTENERS", defaults.event.max_listeners),
+    env_override!("IOX2_EVENT_MAX_NOTIFIERS", defaults.event.max_notifiers),
+    env_override!("IOX2_EVENT_MAX_NODES", defaults.event.max_nodes),
+    env_override!(
+        "IOX2_EVENT_EVENT_ID_MAX_VALUE",
+        defaults.event.event_id_max_value
+    ),
+    env_override!("IOX2_EVENT_DEADLINE", defaults.event.deadline),
+    env_override!(
+        "IOX2_EVENT_NOTIFIER_CREATED_EVENT",
+        defaults.event.notifier_created_event
+    ),
+    env_override!(
+        "IOX2_EVENT_NOTIFIER_DROPPED_EVENT",
+        defaults.event.notifier_dropped_event
+    ),
+    env_override!(
+        "IOX2_EVENT_NOTIFIER_DEAD_EVENT",
+        defaults.event.notifier_dead_event
+    ),
+    env_override!(
+        "IOX2_REQRES_ENABLE_SAFE_OVERFLOW_FOR_REQUESTS",
+        defaults.request_response.enable_safe_overflow_for_requests
+    ),
+    env_override!(
+        "IOX2_REQRES_ENABLE_SAFE_OVERFLOW_FOR_RESPONSES",
+        defaults.request_response.enable_safe_overflow_for_responses
+    ),
+    env_override!(
+        "IOX2_REQRES_MAX_ACTIVE_RESPONSES",
+        defaults.request_response.max_active_responses
+    ),
+    env_override!(
+        "IOX2_REQRES_MAX_ACTIVE_REQUESTS",
+        defaults.request_response.max_active_requests
+    ),
+    env_override!(
+        "IOX2_REQRES_MAX_BORROWED_RESPONSES",
+        defaults.request_response.max_borrowed_responses
+    ),
+    env_override!(
+        "IOX2_REQRES_MAX_BORROWED_REQUESTS",
+        defaults.request_response.max_borrowed_requests
+    ),
+    env_override!(
+        "IOX2_REQRES_MAX_RESPONSE_BUFFER_SIZE",
+        defaults.request_response.max_response_buffer_size
+    ),
+    env_override!(
+        "IOX2_REQRES_MAX_REQUEST_BUFFER_SIZE",
+        defaults.request_response.max_request_buffer_size
+    ),
+    env_override!(
+        "IOX2_REQRES_MAX_SERVERS",
+        defaults.request_response.max_servers
+    ),
+    env_override!(
+        "IOX2_REQRES_MAX_CLIENTS",
+        defaults.request_response.max_clients
+    ),
+    env_override!("IOX2_REQRES_MAX_NODES", defaults.request_response.max_nodes),
+];
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -363,8 +750,13 @@ impl Default for Config {
                     static_config_storage_suffix: FileName::new(b".service").unwrap(),
                     dynamic_config_storage_suffix: FileName::new(b".dynamic").unwrap(),
                     creation_timeout: Duration::from_millis(500),
+                    dynamic_config_open_retries: 5,
+                    dynamic_config_open_retry_delay: Duration::from_millis(10),
                     connection_suffix: FileName::new(b".connection").unwrap(),
                     event_connection_suffix: FileName::new(b".event").unwrap(),
+                    case_insensitive_names: false,
+                    static_config_format: StaticConfigFormat::Toml,
+                    cleanup_dead_ports_on_open: true,
                 },
                 node: Node {
                     directory: Path::new(b"nodes").unwrap(),
@@ -373,6 +765,8 @@ impl Default for Config {
                     service_tag_suffix: FileName::new(b".service_tag").unwrap(),
                     cleanup_dead_nodes_on_creation: true,
                     cleanup_dead_nodes_on_destruction: true,
+                    reap_dead_ports_on_wait: false,
+                    reap_dead_ports_interval: Duration::from_secs(1),
                 },
             },
             defaults: Defaults {
@@ -400,6 +794,7 @@ impl Default for Config {
                     enable_safe_overflow: true,
                     unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
                     subscriber_expired_connection_buffer: 128,
+                    max_sample_slots: 1048576,
                 },
                 event: Event {
                     max_listeners: 16,
@@ -412,6 +807,7 @@ impl Default for Config {
                     notifier_dead_event: None,
                 },
             },
+            provenance: NotCompared::default(),
         }
     }
 }
@@ -493,8 +889,8 @@ impl Config {
         Ok(())
     }
 
-    /// Loads a configuration from a file. On success it returns a [`Config`] object otherwise a
-    /// [`ConfigCreationError`] describing the failure.
+    /// Loads a configuration from a file and verifies it with [`Config::validate()`]. On success
+    /// it returns a [`Config`] object otherwise a [`ConfigCreationError`] describing the failure.
     pub fn from_file(config_file: &FilePath) -> Result<Config, ConfigCreationError> {
         let msg = "Failed to create config";
         let mut new_config = Self::default();
@@ -526,18 +922,160 @@ impl Config {
                 with ConfigCreationError::FailedToReadConfigFileContents,
                 "{} since the config file contents could not be read.", msg);
 
-        match toml::from_str(&contents) {
-            Ok(v) => new_config = v,
+        match toml::from_str::<Config>(&contents) {
+            Ok(v) => {
+                new_config = v;
+                if let Ok(table) = contents.parse::<toml::Table>() {
+                    new_config.provenance.0.sources = ENV_OVERRIDES
+                        .iter()
+                        .filter(|(_, path, _)| Self::contains_setting(&table, path))
+                        .map(|(name, _, _)| (*name, ConfigSource::ConfigFile))
+                        .collect();
+                }
+            }
             Err(e) => {
                 fail!(from new_config, with ConfigCreationError::UnableToDeserializeContents,
                                 "{} since the contents could not be deserialized ({}).", msg, e);
             }
         }
 
+        if let Err(e) = new_config.validate() {
+            fail!(from new_config, with ConfigCreationError::InvalidConfig(e),
+                "{} since the config file \"{}\" contains invalid settings.", msg, config_file);
+        }
+
         trace!(from new_config, "Loaded.");
         Ok(new_config)
     }
 
+    /// Overrides the settings of the [`Config`] with the values of the corresponding
+    /// environment variables. Settings without a corresponding environment variable keep
+    /// their value. See the [module documentation](crate::config) for the naming scheme of the
+    /// environment variables. When an environment variable contains a value that cannot be
+    /// parsed it returns [`ConfigCreationError::MalformedEnvironmentVariable`].
+    pub fn with_env_overrides(mut self) -> Result<Config, ConfigCreationError> {
+        let msg = "Unable to override config with environment variables";
+        for (name, _, apply_override) in ENV_OVERRIDES {
+            let value = match std::env::var(name) {
+                Ok(value) => value,
+                Err(std::env::VarError::NotPresent) => continue,
+                Err(std::env::VarError::NotUnicode(_)) => {
+                    fail!(from self, with ConfigCreationError::MalformedEnvironmentVariable,
+                        "{} since the environment variable \"{}\" does not contain valid unicode.",
+                        msg, name);
+                }
+            };
+
+            if apply_override(&mut self, &value).is_none() {
+                fail!(from self, with ConfigCreationError::MalformedEnvironmentVariable,
+                    "{} since the environment variable \"{}\" contains the malformed value \"{}\".",
+                    msg, name, value);
+            }
+
+            self.provenance
+                .0
+                .sources
+                .insert(name, ConfigSource::EnvironmentVariable);
+            trace!(from self, "Override setting with \"{}={}\".", name, value);
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the [`ConfigSource`] of the setting that can be overridden with the environment
+    /// variable `env_name`.
+    pub(crate) fn source_of(&self, env_name: &str) -> ConfigSource {
+        self.provenance
+            .0
+            .sources
+            .get(env_name)
+            .copied()
+            .unwrap_or(ConfigSource::BuiltInDefault)
+    }
+
+    /// Returns true when the config file `table` contains the setting with the field `path`.
+    fn contains_setting(table: &toml::Table, path: &[&str]) -> bool {
+        let mut table = table;
+        for (n, field) in path.iter().enumerate() {
+            match table.get(&field.replace('_', "-")) {
+                Some(toml::Value::Table(t)) => table = t,
+                Some(_) => return n + 1 == path.len(),
+                None => return false,
+            }
+        }
+
+        false
+    }
+
+    /// Verifies that publish-subscribe [`crate::service::Service`]s can be created with the
+    /// [`Defaults`] of the [`Config`]. It returns
+    /// [`ConfigValidationError::ExceedsMaxSampleSlots`] when the worst-case number of sample
+    /// slots,
+    /// `max_publishers * (max_subscribers * (subscriber_max_buffer_size + subscriber_max_borrowed_samples) + publisher_history_size + publisher_max_loaned_samples)`,
+    /// exceeds [`PublishSubscribe::max_sample_slots`]. The same limit is verified with the
+    /// actual settings whenever a publish-subscribe [`crate::service::Service`] is created.
+    /// It returns [`ConfigValidationError::SubscriberMaxBufferSizeIsZero`] or
+    /// [`ConfigValidationError::SubscriberMaxBorrowedSamplesIsZero`] when the corresponding
+    /// subscriber limit is zero.
+    ///
+    /// For event [`crate::service::Service`]s it returns
+    /// [`ConfigValidationError::EventMaxPortsOrNodesIsZero`] when one of the maxima of the
+    /// [`Event`] defaults is zero and [`ConfigValidationError::EventIdOutOfRange`] when one of
+    /// the configured notifier event ids exceeds [`Event::event_id_max_value`].
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        let msg = "The config is invalid";
+        if self.defaults.publish_subscribe.subscriber_max_buffer_size == 0 {
+            fail!(from self, with ConfigValidationError::SubscriberMaxBufferSizeIsZero,
+                "{} since the publish-subscribe defaults define a subscriber max buffer size of 0.", msg);
+        }
+
+        if self
+            .defaults
+            .publish_subscribe
+            .subscriber_max_borrowed_samples
+            == 0
+        {
+            fail!(from self, with ConfigValidationError::SubscriberMaxBorrowedSamplesIsZero,
+                "{} since the publish-subscribe defaults define subscriber max borrowed samples of 0.", msg);
+        }
+
+        let required_sample_slots =
+            crate::service::static_config::publish_subscribe::StaticConfig::new(self)
+                .required_sample_slots(
+                    self.defaults.publish_subscribe.publisher_max_loaned_samples,
+                );
+
+        if self.defaults.publish_subscribe.max_sample_slots < required_sample_slots {
+            fail!(from self, with ConfigValidationError::ExceedsMaxSampleSlots,
+                "{} since the publish-subscribe defaults require up to {} sample slots but at most {} are allowed.",
+                msg, required_sample_slots, self.defaults.publish_subscribe.max_sample_slots);
+        }
+
+        let event = &self.defaults.event;
+        if event.max_listeners == 0 || event.max_notifiers == 0 || event.max_nodes == 0 {
+            fail!(from self, with ConfigValidationError::EventMaxPortsOrNodesIsZero,
+                "{} since the event defaults define {} max listeners, {} max notifiers and {} max nodes but all must be at least 1.",
+                msg, event.max_listeners, event.max_notifiers, event.max_nodes);
+        }
+
+        for event_id in [
+            event.notifier_created_event,
+            event.notifier_dropped_event,
+            event.notifier_dead_event,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if event.event_id_max_value < event_id {
+                fail!(from self, with ConfigValidationError::EventIdOutOfRange,
+                    "{} since the event defaults define the notifier event id {} which exceeds the event id max value of {}.",
+                    msg, event_id, event.event_id_max_value);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Sets up the global configuration from a file. If the global configuration was already setup
     /// it will print a warning and does not load the file. It returns the [`Config`] when the file
     /// could be successfully loaded otherwise a [`ConfigCreationError`] describing the error.
@@ -270,12 +270,18 @@
 //!
 //! # Feature Flags
 //!
+//!  * `bridge` - Adds the `bridge` module that serializes the samples of a publish-subscribe
+//!    service and forwards them over a unix datagram or TCP socket to another process. It is
+//!    not zero-copy.
 //!  * `dev_permissions` - The permissions of all resources will be set to read, write, execute
 //!     for everyone. This shall not be used in production and is meant to be enabled in a docker
 //!     environment with inconsistent user configuration.
 //!  * `logger_log` - Uses the [log crate](https://crates.io/crates/log) as default log backend
 //!  * `logger_tracing` - Uses the [tracing crate](https://crates.io/crates/tracing) as default log
 //!     backend
+//!  * `tracing` - Uses the [tracing crate](https://crates.io/crates/tracing) as default log
+//!    backend and wraps the creation and opening of services as well as sending and receiving
+//!    of samples into spans that carry the service name and the port id
 //!
 //! # Custom Configuration
 //!
@@ -288,17 +294,42 @@
 #[cfg(doctest)]
 mod compiletests;
 
+/// Forwards the samples of a publish-subscribe service over a unix datagram socket to another
+/// process
+#[cfg(feature = "bridge")]
+pub mod bridge;
+
 /// Handles iceoryx2s global configuration
 pub mod config;
 
+/// A request of a [`Client`](crate::port::client::Client) that was received by a
+/// [`Server`](crate::port::server::Server) and is used to send the responses.
+pub mod active_request;
+
 /// Central instance that owns all service entities and can handle incoming event in an event loop
 pub mod node;
 
+/// Receives the responses to a request that was sent by a
+/// [`Client`](crate::port::client::Client).
+pub mod pending_response;
+
 /// The ports or communication endpoints of iceoryx2
 pub mod port;
 
 pub(crate) mod raw_sample;
 
+/// Offset based pointers into the payload of the same sample that remain valid in every process.
+pub mod relative_pointer;
+
+/// The request that is sent by a [`Client`](crate::port::client::Client).
+pub mod request_mut;
+
+/// The response that is received by a [`Client`](crate::port::client::Client).
+pub mod response;
+
+/// The response that is sent by a [`Server`](crate::port::server::Server).
+pub mod response_mut;
+
 /// The payload that is received by a [`Subscriber`](crate::port::subscriber::Subscriber).
 pub mod sample;
 
@@ -315,6 +346,8 @@ pub mod service;
 /// [`WaitSet`](crate::waitset::WaitSet) shall handle system signals.
 pub mod signal_handling_mode;
 
+pub(crate) mod span;
+
 /// Loads a meaninful subset to cover 90% of the iceoryx2 communication use cases.
 pub mod prelude;
 
@@ -133,6 +133,10 @@
 //!         Err(NodeWaitFailure::Interrupt) => {
 //!             println!("Someone send an interrupt signal ...");
 //!         }
+//!         Err(NodeWaitFailure::InternalError) => {
+//!             println!("Unable to wait on the node");
+//!             break;
+//!         }
 //!     }
 //! }
 //! # Ok(())
@@ -150,14 +154,19 @@ use crate::service::builder::{Builder, OpenDynamicStorageFailure};
 use crate::service::config_scheme::{
     node_details_path, node_monitoring_config, service_tag_config,
 };
+use crate::service::internal::reap_dead_ports_of_service;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
 use crate::service::{
-    self, remove_service_tag, remove_static_service_config, ServiceRemoveNodeError,
+    self, remove_service_tag, remove_static_service_config, service_directory_from_tag,
+    ServiceRemoveNodeError,
 };
 use crate::signal_handling_mode::SignalHandlingMode;
-use crate::{config::Config, service::config_scheme::node_details_config};
-use core::cell::UnsafeCell;
+use crate::{
+    config::{Config, ConfigValidationError},
+    service::config_scheme::node_details_config,
+};
+use core::cell::{RefCell, UnsafeCell};
 use core::marker::PhantomData;
 use core::sync::atomic::Ordering;
 use core::time::Duration;
@@ -165,9 +174,9 @@ use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_log::{debug, fail, fatal_panic, trace, warn};
-use iceoryx2_bb_posix::clock::{nanosleep, NanosleepError, Time};
+use iceoryx2_bb_posix::clock::{nanosleep, ClockType, NanosleepError, Time};
 use iceoryx2_bb_posix::process::{Process, ProcessId};
-use iceoryx2_bb_posix::signal::SignalHandler;
+use iceoryx2_bb_posix::signal::{NonFatalFetchableSignal, SignalHandler};
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::named_concept::{NamedConceptPathHintRemoveError, NamedConceptRemoveError};
@@ -217,6 +226,9 @@ pub enum NodeCreationFailure {
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     InternalError,
+    /// The [`Config`] of the [`Node`] was rejected by [`Config::validate()`]. The underlying
+    /// cause is available via [`std::error::Error::source()`].
+    InvalidConfig(ConfigValidationError),
 }
 
 impl core::fmt::Display for NodeCreationFailure {
@@ -225,7 +237,14 @@ impl core::fmt::Display for NodeCreationFailure {
     }
 }
 
-impl std::error::Error for NodeCreationFailure {}
+impl std::error::Error for NodeCreationFailure {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NodeCreationFailure::InvalidConfig(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 /// The failures that can occur when a list of [`NodeState`]s is created with [`Node::list()`].
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -234,6 +253,8 @@ pub enum NodeWaitFailure {
     Interrupt,
     /// A termination signal `SIGTERM` was received.
     TerminationRequest,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
 }
 
 impl core::fmt::Display for NodeWaitFailure {
@@ -529,7 +550,13 @@ impl<Service: service::Service> DeadNodeView<Service> {
 
         let mut cleanup_failure = Ok(());
         let remove_node_from_service = |service_id: &ServiceId| {
-            match Service::__internal_remove_node_from_service(self.id(), service_id, config) {
+            let directory = service_directory_from_tag::<Service>(self.id(), service_id, config);
+            match Service::__internal_remove_node_from_service(
+                self.id(),
+                service_id,
+                config,
+                directory.as_ref(),
+            ) {
                 Ok(()) => {
                     if let Err(e) = remove_service_tag::<Service>(self.id(), service_id, config) {
                         debug!(from self,
@@ -546,7 +573,11 @@ impl<Service: service::Service> DeadNodeView<Service> {
                     debug!(from self,
                         "{msg} since the service itself is corrupted. Trying to remove the corrupted remainders of the service.");
                     match unsafe {
-                        remove_static_service_config::<Service>(config, &service_id.0.into())
+                        remove_static_service_config::<Service>(
+                            config,
+                            directory.as_ref(),
+                            &service_id.0.into(),
+                        )
                     } {
                         Ok(v) => {
                             if let Err(e) =
@@ -760,6 +791,10 @@ impl RegisteredServices {
         Ok(())
     }
 
+    pub(crate) fn service_ids(&self) -> Vec<ServiceId> {
+        self.data.lock().unwrap().keys().cloned().collect()
+    }
+
     pub(crate) fn remove<F: FnMut(ContainerHandle)>(
         &self,
         service_id: &ServiceId,
@@ -779,6 +814,106 @@ impl RegisteredServices {
     }
 }
 
+pub(crate) type SampleCallback = Box<dyn FnMut()>;
+
+/// The callbacks that were registered with
+/// [`Subscriber::on_sample()`](crate::port::subscriber::Subscriber::on_sample()) on the
+/// current thread. Every callback owns its subscriber, which is not [`Send`], therefore the
+/// callbacks are stored per thread and never leave the thread that registered them. While the
+/// callbacks of a [`Node`] are dispatched, they are taken out of the list so that a callback
+/// can register or remove callbacks.
+#[derive(Default)]
+struct SampleCallbacks {
+    next_id: u64,
+    entries: Vec<(NodeId, u64, SampleCallback)>,
+    dispatching_nodes: Vec<NodeId>,
+    removed_while_dispatching: Vec<u64>,
+}
+
+thread_local! {
+    static SAMPLE_CALLBACKS: RefCell<SampleCallbacks> = RefCell::new(SampleCallbacks::default());
+}
+
+pub(crate) fn register_sample_callback(node_id: NodeId, callback: SampleCallback) -> u64 {
+    SAMPLE_CALLBACKS.with(|callbacks| {
+        let mut callbacks = callbacks.borrow_mut();
+        let id = callbacks.next_id;
+        callbacks.next_id += 1;
+        callbacks.entries.push((node_id, id, callback));
+        id
+    })
+}
+
+pub(crate) fn remove_sample_callback(id: u64) {
+    // the callbacks are already gone when the thread is exiting
+    let _ = SAMPLE_CALLBACKS.try_with(|callbacks| {
+        let mut callbacks = callbacks.borrow_mut();
+        match callbacks
+            .entries
+            .iter()
+            .position(|(_, entry_id, _)| *entry_id == id)
+        {
+            Some(index) => {
+                let removed = callbacks.entries.remove(index);
+                // the callback owns a subscriber which must not be dropped while borrowed
+                drop(callbacks);
+                drop(removed);
+            }
+            None => {
+                if !callbacks.dispatching_nodes.is_empty() {
+                    callbacks.removed_while_dispatching.push(id);
+                }
+            }
+        }
+    });
+}
+
+fn dispatch_sample_callbacks(node_id: NodeId) {
+    let mut dispatched = SAMPLE_CALLBACKS.with(|callbacks| {
+        let mut callbacks = callbacks.borrow_mut();
+        if callbacks.dispatching_nodes.contains(&node_id) {
+            return Vec::new();
+        }
+
+        let (dispatched, kept): (Vec<_>, Vec<_>) = core::mem::take(&mut callbacks.entries)
+            .into_iter()
+            .partition(|(id, _, _)| *id == node_id);
+        callbacks.entries = kept;
+        if !dispatched.is_empty() {
+            callbacks.dispatching_nodes.push(node_id);
+        }
+        dispatched
+    });
+
+    if dispatched.is_empty() {
+        return;
+    }
+
+    for (_, _, callback) in dispatched.iter_mut() {
+        callback();
+    }
+
+    let removed = SAMPLE_CALLBACKS.with(|callbacks| {
+        let mut callbacks = callbacks.borrow_mut();
+        let mut removed = core::mem::take(&mut callbacks.removed_while_dispatching);
+        let (removed_entries, kept): (Vec<_>, Vec<_>) = dispatched
+            .into_iter()
+            .partition(|(_, id, _)| removed.contains(id));
+        // removals of callbacks that are dispatched by an outer dispatch are kept
+        removed.retain(|id| {
+            !removed_entries
+                .iter()
+                .any(|(_, entry_id, _)| entry_id == id)
+        });
+        callbacks.removed_while_dispatching = removed;
+        let registered_while_dispatching = core::mem::replace(&mut callbacks.entries, kept);
+        callbacks.entries.extend(registered_while_dispatching);
+        callbacks.dispatching_nodes.retain(|id| *id != node_id);
+        removed_entries
+    });
+    drop(removed);
+}
+
 #[derive(Debug)]
 pub(crate) struct SharedNode<Service: service::Service> {
     id: NodeId,
@@ -786,6 +921,8 @@ pub(crate) struct SharedNode<Service: service::Service> {
     monitoring_token: UnsafeCell<Option<<Service::Monitoring as Monitoring>::Token>>,
     registered_services: RegisteredServices,
     signal_handling_mode: SignalHandlingMode,
+    termination_signals: Vec<NonFatalFetchableSignal>,
+    last_dead_port_reaping: Mutex<Option<Time>>,
     _details_storage: Service::StaticStorage,
 }
 
@@ -849,6 +986,10 @@ impl<Service: service::Service> Node<Service> {
         &self.shared.id
     }
 
+    pub(crate) fn shared_node(&self) -> &Arc<SharedNode<Service>> {
+        &self.shared
+    }
+
     /// Instantiates a [`ServiceBuilder`](Builder) for a service with the provided name.
     pub fn service_builder(&self, name: &ServiceName) -> Builder<Service> {
         Builder::new(name, self.shared.clone())
@@ -908,7 +1049,10 @@ impl<Service: service::Service> Node<Service> {
 
     fn handle_termination_request(&self, error_msg: &str) -> Result<(), NodeWaitFailure> {
         if self.shared.signal_handling_mode == SignalHandlingMode::HandleTerminationRequests
-            && SignalHandler::termination_requested()
+            // the signal is not reset, so that a termination request is reported until the
+            // event loop is left
+            && SignalHandler::peek_last_signal()
+                .is_some_and(|signal| self.shared.termination_signals.contains(&signal))
         {
             fail!(from self, with NodeWaitFailure::TerminationRequest,
                 "{error_msg} since a termination request was received.");
@@ -917,16 +1061,49 @@ impl<Service: service::Service> Node<Service> {
         Ok(())
     }
 
+    fn reap_dead_ports(&self) {
+        let interval = self.config().global.node.reap_dead_ports_interval;
+        let mut last_reaping = self.shared.last_dead_port_reaping.lock().unwrap();
+        if let Some(Ok(elapsed)) = last_reaping.as_ref().map(|t| t.elapsed()) {
+            if elapsed < interval {
+                return;
+            }
+        }
+        *last_reaping = Time::now_with_clock(ClockType::Monotonic).ok();
+
+        for service_id in self.shared.registered_services.service_ids() {
+            reap_dead_ports_of_service::<Service>(self.id(), &service_id, self.config());
+        }
+    }
+
     /// Waits until the cycle time has passed. It returns [`NodeWaitFailure::TerminationRequest`]
-    /// when a `SIGTERM` signal was received or [`NodeWaitFailure::Interrupt`] when a `SIGINT`
-    /// signal was received.
+    /// when one of the termination signals was received, see
+    /// [`NodeBuilder::termination_signals()`], or [`NodeWaitFailure::Interrupt`] when the wait
+    /// was interrupted by a signal. A termination signal that interrupts the wait is reported
+    /// with [`NodeWaitFailure::Interrupt`] and with [`NodeWaitFailure::TerminationRequest`]
+    /// by all following calls, since the termination request is never reset.
+    ///
+    /// When [`crate::config::Node::reap_dead_ports_on_wait`] is set, all ports of dead
+    /// processes are removed from the [`Service`](crate::service::Service)s the [`Node`] is
+    /// registered at before waiting, at most once per
+    /// [`crate::config::Node::reap_dead_ports_interval`].
+    ///
+    /// After the cycle time has passed, the callbacks that were registered with
+    /// [`Subscriber::on_sample()`](crate::port::subscriber::Subscriber::on_sample()) are
+    /// called for every [`Sample`](crate::sample::Sample) that arrived in the meantime. Only
+    /// the callbacks that were registered on the calling thread are called.
     pub fn wait(&self, cycle_time: Duration) -> Result<(), NodeWaitFailure> {
         let msg = "Unable to wait on node";
         self.handle_termination_request(msg)?;
 
+        if self.config().global.node.reap_dead_ports_on_wait {
+            self.reap_dead_ports();
+        }
+
         match nanosleep(cycle_time) {
             Ok(()) => {
                 self.handle_termination_request(msg)?;
+                dispatch_sample_callbacks(self.shared.id);
                 Ok(())
             }
             Err(NanosleepError::InterruptedBySignal(_)) => {
@@ -941,11 +1118,68 @@ impl<Service: service::Service> Node<Service> {
         }
     }
 
+    /// Waits until the absolute `deadline` has been reached. The remaining time is measured
+    /// with the [`ClockType`](iceoryx2_bb_posix::clock::ClockType) of the `deadline`, which is
+    /// the monotonic clock when it was acquired with [`Time::now()`]. When the `deadline` has
+    /// already passed, it returns immediately. Otherwise, it behaves like [`Node::wait()`].
+    /// When the current time cannot be acquired with the clock of the `deadline`, it returns
+    /// [`NodeWaitFailure::InternalError`].
+    ///
+    /// In contrast to [`Node::wait()`], a fixed-rate loop that advances its `deadline` by the
+    /// cycle time does not accumulate the scheduling jitter of every cycle.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_bb_posix::clock::{Time, TimeBuilder};
+    /// # use core::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// const CYCLE_TIME: Duration = Duration::from_millis(100);
+    ///
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let start = Time::now().unwrap();
+    /// let mut deadline = start.as_duration();
+    ///
+    /// loop {
+    ///     deadline += CYCLE_TIME;
+    ///     let deadline_time = TimeBuilder::new()
+    ///         .clock_type(start.clock_type())
+    ///         .seconds(deadline.as_secs())
+    ///         .nanoseconds(deadline.subsec_nanos())
+    ///         .create();
+    ///
+    ///     if node.wait_until(deadline_time).is_err() {
+    ///         break;
+    ///     }
+    ///
+    ///     // periodic work
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_until(&self, deadline: Time) -> Result<(), NodeWaitFailure> {
+        let now = fail!(from self, when Time::now_with_clock(deadline.clock_type()),
+            with NodeWaitFailure::InternalError,
+            "Unable to wait on node until {:?} since the current time could not be acquired with the clock {:?}.",
+            deadline, deadline.clock_type());
+
+        self.wait(deadline.as_duration().saturating_sub(now.as_duration()))
+    }
+
     /// Returns the [`SignalHandlingMode`] with which the [`Node`] was created.
     pub fn signal_handling_mode(&self) -> SignalHandlingMode {
         self.shared.signal_handling_mode
     }
 
+    /// Returns the signals that are handled as termination request in [`Node::wait()`], see
+    /// [`NodeBuilder::termination_signals()`].
+    pub fn termination_signals(&self) -> &[NonFatalFetchableSignal] {
+        &self.shared.termination_signals
+    }
+
     /// Removes the stale system resources of all dead [`Node`]s. The dead [`Node`]s are also
     /// removed from all registered [`Service`](crate::service::Service)s.
     ///
@@ -1194,6 +1428,7 @@ impl<Service: service::Service> Node<Service> {
 pub struct NodeBuilder {
     name: Option<NodeName>,
     signal_handling_mode: SignalHandlingMode,
+    termination_signals: Option<Vec<NonFatalFetchableSignal>>,
     config: Option<Config>,
 }
 
@@ -1217,6 +1452,22 @@ impl NodeBuilder {
         self
     }
 
+    /// Defines the signals that are handled as termination request. When one of them is
+    /// received, [`Node::wait()`] returns [`NodeWaitFailure::TerminationRequest`] so that the
+    /// event loop can be left and all ports can be dropped. By default, these are
+    /// [`NonFatalFetchableSignal::Interrupt`] and [`NonFatalFetchableSignal::Terminate`].
+    /// A signal that interrupts the [`Node::wait()`] is always reported with
+    /// [`NodeWaitFailure::Interrupt`] first.
+    /// It has no effect when the [`SignalHandlingMode`] is [`SignalHandlingMode::Disabled`].
+    ///
+    /// **Important:** The signal handlers are installed process-wide for all
+    /// [`NonFatalFetchableSignal`]s with the first [`Node::wait()`] call and replace any
+    /// previously installed signal handler.
+    pub fn termination_signals(mut self, value: &[NonFatalFetchableSignal]) -> Self {
+        self.termination_signals = Some(value.to_vec());
+        self
+    }
+
     /// Sets the config of the [`Node`] that will be used to create all entities owned by the
     /// [`Node`].
     pub fn config(mut self, value: &Config) -> Self {
@@ -1245,11 +1496,17 @@ impl NodeBuilder {
             Config::global_config().clone()
         };
 
+        let msg = "Unable to create node";
+        // the services of the node are created and opened with this config
+        if let Err(e) = config.validate() {
+            fail!(from self, with NodeCreationFailure::InvalidConfig(e),
+                "{msg} since the config contains invalid settings.");
+        }
+
         if config.global.node.cleanup_dead_nodes_on_creation {
             Node::<Service>::cleanup_dead_nodes(&config);
         }
 
-        let msg = "Unable to create node";
         let monitor_name = fatal_panic!(from self, when FileName::new(node_id.value().to_string().as_bytes()),
                                 "This should never happen! {msg} since the UniqueSystemId is not a valid file name.");
         let (details_storage, details) =
@@ -1263,8 +1520,15 @@ impl NodeBuilder {
                 registered_services: RegisteredServices {
                     data: Mutex::new(HashMap::new()),
                 },
+                last_dead_port_reaping: Mutex::new(None),
                 _details_storage: details_storage,
                 signal_handling_mode: self.signal_handling_mode,
+                termination_signals: self.termination_signals.clone().unwrap_or_else(|| {
+                    vec![
+                        NonFatalFetchableSignal::Interrupt,
+                        NonFatalFetchableSignal::Terminate,
+                    ]
+                }),
                 details,
             }),
         })
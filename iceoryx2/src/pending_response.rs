@@ -0,0 +1,190 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client_builder().create()?;
+//! let pending_response = client.send_copy(1234)?;
+//! println!("request sent to {} servers", pending_response.number_of_server_connections());
+//!
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("received response {:?} from {:?}",
+//!         *response, response.header().server_port_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use crate::port::client::{ClientBackend, ResponseReceiveError};
+use crate::port::update_connections::ConnectionFailure;
+use crate::raw_sample::RawSample;
+use crate::response::Response;
+use crate::service;
+
+/// Represents an active request of a [`Client`](crate::port::client::Client) that was sent
+/// with [`RequestMut::send()`](crate::request_mut::RequestMut::send()). It receives all
+/// responses the [`Server`](crate::port::server::Server)s send for this specific request.
+/// As long as the [`PendingResponse`] exists, the request counts as active, see
+/// [`StaticConfig::max_active_requests()`](crate::service::static_config::request_response::StaticConfig::max_active_requests()).
+/// When it goes out of scope, all received but not yet consumed responses are released.
+pub struct PendingResponse<
+    Service: service::Service,
+    RequestPayload: Debug,
+    RequestUserHeader: Debug,
+    ResponsePayload: Debug,
+    ResponseUserHeader: Debug,
+> {
+    backend: Arc<ClientBackend<Service>>,
+    request_id: u64,
+    number_of_server_connections: usize,
+    _request_payload: PhantomData<RequestPayload>,
+    _request_user_header: PhantomData<RequestUserHeader>,
+    _response_payload: PhantomData<ResponsePayload>,
+    _response_user_header: PhantomData<ResponseUserHeader>,
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Debug
+    for PendingResponse<
+        Service,
+        RequestPayload,
+        RequestUserHeader,
+        ResponsePayload,
+        ResponseUserHeader,
+    >
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "PendingResponse<{}, {}, {}, {}, {}> {{ request_id: {}, number_of_server_connections: {} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<RequestPayload>(),
+            core::any::type_name::<RequestUserHeader>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<ResponseUserHeader>(),
+            self.request_id,
+            self.number_of_server_connections
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Drop
+    for PendingResponse<
+        Service,
+        RequestPayload,
+        RequestUserHeader,
+        ResponsePayload,
+        ResponseUserHeader,
+    >
+{
+    fn drop(&mut self) {
+        self.backend.release_pending_request(self.request_id);
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    >
+    PendingResponse<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    pub(crate) fn new(
+        backend: Arc<ClientBackend<Service>>,
+        request_id: u64,
+        number_of_server_connections: usize,
+    ) -> Self {
+        Self {
+            backend,
+            request_id,
+            number_of_server_connections,
+            _request_payload: PhantomData,
+            _request_user_header: PhantomData,
+            _response_payload: PhantomData,
+            _response_user_header: PhantomData,
+        }
+    }
+
+    /// Returns the id of the request, see
+    /// [`RequestHeader::request_id()`](crate::service::header::request_response::RequestHeader::request_id()).
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+
+    /// Returns the number of [`Server`](crate::port::server::Server)s the request was
+    /// delivered to.
+    pub fn number_of_server_connections(&self) -> usize {
+        self.number_of_server_connections
+    }
+
+    /// Returns true if responses are available, otherwise false.
+    pub fn has_response(&self) -> Result<bool, ConnectionFailure> {
+        self.backend.has_response(self.request_id)
+    }
+
+    /// Receives the next [`Response`] to the request. If no response was received [`None`]
+    /// is returned. Responses that belong to other [`PendingResponse`]s of the same
+    /// [`Client`](crate::port::client::Client) are kept until they are received there.
+    ///
+    /// On failure it returns [`ResponseReceiveError`] describing the failure.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<Response<Service, ResponsePayload, ResponseUserHeader>>, ResponseReceiveError>
+    {
+        let details = match self.backend.receive_response(self.request_id)? {
+            Some(details) => details,
+            None => return Ok(None),
+        };
+
+        let message_type_details = &self.backend.static_config.response_message_type_details;
+        let header = details.header;
+        let user_header = message_type_details.user_header_ptr_from_header(header.cast());
+        let payload = message_type_details.payload_ptr_from_header(header.cast());
+
+        Ok(Some(Response::new(details, unsafe {
+            RawSample::new_unchecked(
+                header,
+                user_header as *const ResponseUserHeader,
+                payload as *const ResponsePayload,
+            )
+        })))
+    }
+}
@@ -10,5 +10,813 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-/// TODO
-pub struct Client {}
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client_builder().create()?;
+//!
+//! // loan some uninitialized memory, write the request into it and send it
+//! let request = client.loan_uninit()?;
+//! let request = request.write_payload(1234);
+//! let pending_response = request.send()?;
+//!
+//! // send a copy of the value
+//! let other_pending_response = client.send_copy(5678)?;
+//!
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("received response: {:?}", *response);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::alloc::Layout;
+use core::cell::{Cell, RefCell, UnsafeCell};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::sync::atomic::Ordering;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::process::Process;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shm_allocator::{AllocationError, PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyPortDetails, ZeroCopyReceiveError,
+    ZeroCopyReceiver, ZeroCopyReleaseError, ZeroCopySendError, ZeroCopySender,
+};
+
+use crate::pending_response::PendingResponse;
+use crate::raw_sample::RawSampleMut;
+use crate::request_mut::RequestMut;
+use crate::service::config_scheme::connection_config;
+use crate::service::dynamic_config::request_response::{ClientDetails, ServerDetails};
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+use crate::service::naming_scheme::{
+    port_data_segment_name, request_connection_name, response_connection_name,
+};
+use crate::service::static_config::request_response::StaticConfig;
+use crate::service::{self, ServiceState};
+
+use super::details::data_segment::{DataSegmentType, DataSegmentView};
+use super::details::reference_counted_data_segment::ReferenceCountedDataSegment;
+use super::port_identifiers::{UniqueClientId, UniqueServerId};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+
+/// Describes the failures when a new [`Client`] is created via the
+/// [`crate::service::port_factory::client::PortFactoryClient`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ClientCreateError {
+    /// The maximum amount of [`Client`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Client`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedClients,
+    /// The datasegment in which the requests of the [`Client`] are stored, could not be created.
+    UnableToCreateDataSegment,
+}
+
+impl core::fmt::Display for ClientCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ClientCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ClientCreateError {}
+
+/// Defines a failure that can occur in [`Client::loan()`] and [`Client::loan_uninit()`]
+/// or is part of [`RequestSendError`] emitted in [`Client::send_copy()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum RequestLoanError {
+    /// The [`Client`]s data segment does not have any more memory left.
+    OutOfMemory,
+    /// The [`Client`] holds already
+    /// [`StaticConfig::max_active_requests()`](crate::service::static_config::request_response::StaticConfig::max_active_requests())
+    /// requests that are either loaned or wait for responses as [`PendingResponse`]. Send or
+    /// drop a [`RequestMut`] or drop a [`PendingResponse`] to loan another request.
+    ExceedsMaxActiveRequests,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl core::fmt::Display for RequestLoanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "RequestLoanError::{:?}", self)
+    }
+}
+
+impl std::error::Error for RequestLoanError {}
+
+/// Failure that can be emitted when a [`RequestMut`] is sent via [`RequestMut::send()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum RequestSendError {
+    /// A connection between the [`Client`] and a [`Server`](crate::port::server::Server) is
+    /// corrupted.
+    ConnectionCorrupted,
+    /// A failure occurred while acquiring memory for the request
+    LoanError(RequestLoanError),
+    /// A failure occurred while establishing a connection to a
+    /// [`Server`](crate::port::server::Server)
+    ConnectionError(ConnectionFailure),
+}
+
+impl From<RequestLoanError> for RequestSendError {
+    fn from(value: RequestLoanError) -> Self {
+        RequestSendError::LoanError(value)
+    }
+}
+
+impl From<ConnectionFailure> for RequestSendError {
+    fn from(value: ConnectionFailure) -> Self {
+        RequestSendError::ConnectionError(value)
+    }
+}
+
+impl core::fmt::Display for RequestSendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "RequestSendError::{:?}", self)
+    }
+}
+
+impl std::error::Error for RequestSendError {}
+
+/// Defines the failure that can occur when a response is received with
+/// [`PendingResponse::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ResponseReceiveError {
+    /// The [`Client`] holds already the maximum number of responses it can borrow from a
+    /// [`Server`](crate::port::server::Server), see
+    /// [`StaticConfig::max_borrowed_responses()`](crate::service::static_config::request_response::StaticConfig::max_borrowed_responses()).
+    ExceedsMaxBorrowedResponses,
+    /// Occurs when a [`Client`] is unable to connect to a corresponding
+    /// [`Server`](crate::port::server::Server).
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl core::fmt::Display for ResponseReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ResponseReceiveError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ResponseReceiveError {}
+
+/// The connection of a [`Client`] to one [`Server`](crate::port::server::Server). The requests
+/// are sent from the data segment of the [`Client`] and the responses are received from the
+/// data segment of the [`Server`](crate::port::server::Server).
+#[derive(Debug)]
+pub(crate) struct ServerConnection<Service: service::Service> {
+    pub(crate) server_id: UniqueServerId,
+    pub(crate) request_sender: <Service::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) response_receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
+    pub(crate) data_segment: DataSegmentView<Service>,
+}
+
+impl<Service: service::Service> ServerConnection<Service> {
+    fn new(
+        backend: &ClientBackend<Service>,
+        details: &ServerDetails,
+    ) -> Result<Self, ConnectionFailure> {
+        let msg = format!(
+            "Unable to establish connection to server {:?} from client {:?}.",
+            details.server_id, backend.client_id
+        );
+        let global_config = backend.service_state.shared_node.config();
+        let directory = backend.service_state.directory.as_ref();
+        let static_config = &backend.static_config;
+
+        let request_sender = fail!(from backend,
+            when <Service::Connection as ZeroCopyConnection>::
+                Builder::new(&request_connection_name(backend.client_id, details.server_id))
+                    .config(&connection_config::<Service>(global_config, directory))
+                    .buffer_size(details.request_buffer_size)
+                    .receiver_max_borrowed_samples(static_config.max_borrowed_requests)
+                    .enable_safe_overflow(static_config.enable_safe_overflow_for_requests)
+                    .number_of_samples_per_segment(backend.data_segment.number_of_samples())
+                    .max_supported_shared_memory_segments(1)
+                    .timeout(global_config.global.service.creation_timeout)
+                    .create_sender(),
+            "{} since the request connection could not be established.", msg);
+
+        let response_receiver = fail!(from backend,
+            when <Service::Connection as ZeroCopyConnection>::
+                Builder::new(&response_connection_name(details.server_id, backend.client_id))
+                    .config(&connection_config::<Service>(global_config, directory))
+                    .buffer_size(backend.response_buffer_size)
+                    .receiver_max_borrowed_samples(static_config.max_borrowed_responses_per_client())
+                    .enable_safe_overflow(static_config.enable_safe_overflow_for_responses)
+                    .number_of_samples_per_segment(details.number_of_responses)
+                    .max_supported_shared_memory_segments(1)
+                    .timeout(global_config.global.service.creation_timeout)
+                    .create_receiver(),
+            "{} since the response connection could not be established.", msg);
+
+        let data_segment = fail!(from backend,
+            when DataSegmentView::open(
+                &port_data_segment_name(details.server_id.value()),
+                DataSegmentType::Static,
+                &backend.service_state),
+            "{} since the servers data segment could not be opened.", msg);
+
+        Ok(Self {
+            server_id: details.server_id,
+            request_sender,
+            response_receiver,
+            data_segment,
+        })
+    }
+}
+
+/// A response that was received by the [`Client`] but belongs to another [`PendingResponse`]
+/// than the one that received it.
+#[derive(Debug)]
+pub(crate) struct ResponseDetails<Service: service::Service> {
+    pub(crate) connection: Arc<ServerConnection<Service>>,
+    pub(crate) offset: PointerOffset,
+    pub(crate) header: *const ResponseHeader,
+}
+
+impl<Service: service::Service> ResponseDetails<Service> {
+    /// Returns the response to the [`Server`](crate::port::server::Server).
+    pub(crate) fn release(&self) {
+        unsafe { self.connection.data_segment.unregister_offset(self.offset) };
+
+        match self.connection.response_receiver.release(self.offset) {
+            Ok(()) => (),
+            Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                fatal_panic!(from self, "This should never happen! The servers retrieve channel is full and the response cannot be returned.");
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ClientBackend<Service: service::Service> {
+    client_id: UniqueClientId,
+    pub(crate) service_state: Arc<ServiceState<Service>>,
+    pub(crate) static_config: StaticConfig,
+    pub(crate) data_segment: ReferenceCountedDataSegment<Service>,
+    response_buffer_size: usize,
+
+    server_connections: Vec<RefCell<Option<Arc<ServerConnection<Service>>>>>,
+    server_list_state: UnsafeCell<ContainerState<ServerDetails>>,
+    request_id_counter: Cell<u64>,
+    active_requests: Cell<usize>,
+    pending_request_ids: RefCell<Vec<u64>>,
+    buffered_responses: RefCell<Vec<(u64, ResponseDetails<Service>)>>,
+}
+
+impl<Service: service::Service> ClientBackend<Service> {
+    fn populate_server_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.server_connections.len(), None);
+
+        unsafe {
+            (*self.server_list_state.get()).for_each(|h, details| {
+                visited_indices[h.index() as usize] = Some(*details);
+                CallbackProgression::Continue
+            })
+        };
+
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(details) => {
+                    let create_connection = match &*self.server_connections[i].borrow() {
+                        None => true,
+                        Some(connection) => connection.server_id != details.server_id,
+                    };
+
+                    if create_connection {
+                        self.remove_connection(i);
+                        match ServerConnection::new(self, details) {
+                            Ok(connection) => {
+                                *self.server_connections[i].borrow_mut() =
+                                    Some(Arc::new(connection))
+                            }
+                            Err(e) => {
+                                warn!(from self, "Unable to establish connection to new server {:?}.", details.server_id);
+                                result = Err(e);
+                            }
+                        }
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        result
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.server_connections[i].borrow_mut().take() {
+            // # SAFETY: the server no longer exist, therefore we can
+            //           reacquire all delivered requests
+            unsafe {
+                connection
+                    .request_sender
+                    .acquire_used_offsets(|offset| self.data_segment.release_sample(offset))
+            };
+        }
+    }
+
+    pub(crate) fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .servers
+                .update_state(&mut *self.server_list_state.get())
+        } {
+            fail!(from self, when self.populate_server_channels(),
+                "Connections were updated only partially since at least one connection to a Server port failed.");
+        }
+
+        Ok(())
+    }
+
+    fn retrieve_returned_requests(&self) {
+        for connection in &self.server_connections {
+            if let Some(connection) = &*connection.borrow() {
+                loop {
+                    match connection.request_sender.reclaim() {
+                        Ok(Some(offset)) => self.data_segment.release_sample(offset),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim requests from server {:?} due to {:?}. This may lead to a situation where no more requests will be delivered to this server.", connection.server_id, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn number_of_server_connections(&self) -> usize {
+        self.server_connections
+            .iter()
+            .filter(|connection| connection.borrow().is_some())
+            .count()
+    }
+
+    fn allocate(&self, layout: Layout) -> Result<(PointerOffset, *mut u8), RequestLoanError> {
+        let msg = "Unable to loan request";
+        if self.static_config.max_active_requests <= self.active_requests.get() {
+            fail!(from self, with RequestLoanError::ExceedsMaxActiveRequests,
+                "{} since already {} requests are active and it would exceed the maximum of {} active requests. Send or release a loaned request or release a pending response to loan another request.",
+                msg, self.active_requests.get(), self.static_config.max_active_requests);
+        }
+
+        self.retrieve_returned_requests();
+        match self.data_segment.allocate(layout) {
+            Ok(shm_pointer) => {
+                self.active_requests.set(self.active_requests.get() + 1);
+                Ok((shm_pointer.offset, shm_pointer.data_ptr))
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with RequestLoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with RequestLoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, e);
+            }
+        }
+    }
+
+    /// Releases a loaned request that was not sent.
+    pub(crate) fn return_loaned_request(&self, offset: PointerOffset) {
+        self.data_segment.release_sample(offset);
+        self.active_requests.set(self.active_requests.get() - 1);
+    }
+
+    /// Delivers the loaned request to all connected [`Server`](crate::port::server::Server)s
+    /// and turns the loan into a pending request.
+    pub(crate) fn send_request(
+        &self,
+        offset: PointerOffset,
+        request_id: u64,
+    ) -> Result<usize, RequestSendError> {
+        if let Err(e) = self.update_connections() {
+            self.return_loaned_request(offset);
+            fail!(from self, with RequestSendError::ConnectionError(e),
+                "Unable to send request since the connections could not be updated.");
+        }
+
+        self.retrieve_returned_requests();
+        self.pending_request_ids.borrow_mut().push(request_id);
+
+        let mut number_of_recipients = 0;
+        let mut result = Ok(());
+        for connection in &self.server_connections {
+            if let Some(connection) = &*connection.borrow() {
+                match connection
+                    .request_sender
+                    .try_send(offset, self.data_segment.sample_size())
+                {
+                    Ok(overflow) => {
+                        self.data_segment.borrow_sample(offset);
+                        if let Some(old) = overflow {
+                            self.data_segment.release_sample(old);
+                        }
+                        number_of_recipients += 1;
+                    }
+                    Err(ZeroCopySendError::ReceiveBufferFull)
+                    | Err(ZeroCopySendError::UsedChunkListFull) => (),
+                    Err(ZeroCopySendError::ConnectionCorrupted) => {
+                        warn!(from self,
+                            "While delivering the request {} a corrupted connection was detected with server {:?}.",
+                            request_id, connection.server_id);
+                        result = Err(RequestSendError::ConnectionCorrupted);
+                    }
+                }
+            }
+        }
+
+        // the loan is converted into a pending request that keeps the active request count
+        self.data_segment.release_sample(offset);
+        core::sync::atomic::fence(Ordering::Release);
+
+        match result {
+            Ok(()) => Ok(number_of_recipients),
+            Err(e) => {
+                self.release_pending_request(request_id);
+                Err(e)
+            }
+        }
+    }
+
+    /// Is called when a [`PendingResponse`] goes out of scope. All responses that were already
+    /// received for the request are released.
+    pub(crate) fn release_pending_request(&self, request_id: u64) {
+        self.pending_request_ids
+            .borrow_mut()
+            .retain(|id| *id != request_id);
+        self.buffered_responses
+            .borrow_mut()
+            .retain(|(id, details)| {
+                if *id == request_id {
+                    details.release();
+                }
+                *id != request_id
+            });
+        self.active_requests.set(self.active_requests.get() - 1);
+    }
+
+    /// Receives the next response that belongs to the request with the provided id. Responses
+    /// of other pending requests are kept until the corresponding [`PendingResponse`] receives
+    /// them, responses of no longer pending requests are discarded.
+    pub(crate) fn receive_response(
+        &self,
+        request_id: u64,
+    ) -> Result<Option<ResponseDetails<Service>>, ResponseReceiveError> {
+        if let Err(e) = self.update_connections() {
+            fail!(from self, with ResponseReceiveError::ConnectionFailure(e),
+                "Some responses are not being received since not all connections to servers could be established.");
+        }
+
+        {
+            let mut buffered_responses = self.buffered_responses.borrow_mut();
+            if let Some(index) = buffered_responses
+                .iter()
+                .position(|(id, _)| *id == request_id)
+            {
+                return Ok(Some(buffered_responses.remove(index).1));
+            }
+        }
+
+        let msg = "Unable to receive another response";
+        for connection in &self.server_connections {
+            let connection = match &*connection.borrow() {
+                Some(connection) => connection.clone(),
+                None => continue,
+            };
+
+            loop {
+                let offset = match connection.response_receiver.receive() {
+                    Ok(Some(offset)) => offset,
+                    Ok(None) => break,
+                    Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                        fail!(from self, with ResponseReceiveError::ExceedsMaxBorrowedResponses,
+                            "{} since it would exceed the maximum {} of borrowed responses.",
+                            msg, connection.response_receiver.max_borrowed_samples());
+                    }
+                };
+
+                let address = match connection
+                    .data_segment
+                    .register_and_translate_offset(offset)
+                {
+                    Ok(address) => address,
+                    Err(e) => {
+                        fail!(from self, with ResponseReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapPublishersDataSegment(e)),
+                            "{} since the received offset {:?} from server {:?} could not be registered and translated.",
+                            msg, offset, connection.server_id);
+                    }
+                };
+
+                let details = ResponseDetails {
+                    connection: connection.clone(),
+                    offset,
+                    header: address as *const ResponseHeader,
+                };
+                let response_request_id = unsafe { (*details.header).request_id() };
+
+                if response_request_id == request_id {
+                    return Ok(Some(details));
+                } else if self
+                    .pending_request_ids
+                    .borrow()
+                    .contains(&response_request_id)
+                {
+                    self.buffered_responses
+                        .borrow_mut()
+                        .push((response_request_id, details));
+                } else {
+                    details.release();
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns true if responses for the request with the provided id can be received.
+    pub(crate) fn has_response(&self, request_id: u64) -> Result<bool, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+            "Some responses are not being received since not all connections to servers could be established.");
+
+        if self
+            .buffered_responses
+            .borrow()
+            .iter()
+            .any(|(id, _)| *id == request_id)
+        {
+            return Ok(true);
+        }
+
+        Ok(self.server_connections.iter().any(|connection| {
+            connection
+                .borrow()
+                .as_ref()
+                .is_some_and(|connection| connection.response_receiver.has_data())
+        }))
+    }
+}
+
+/// Sends requests to the [`Server`](crate::port::server::Server)s of a
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based [`Service`](crate::service::Service) and receives the corresponding responses via the
+/// [`PendingResponse`] it gets for every sent request.
+#[derive(Debug)]
+pub struct Client<
+    Service: service::Service,
+    RequestPayload: Debug,
+    RequestUserHeader: Debug,
+    ResponsePayload: Debug,
+    ResponseUserHeader: Debug,
+> {
+    pub(crate) backend: Arc<ClientBackend<Service>>,
+    dynamic_client_handle: Option<ContainerHandle>,
+    _request_payload: PhantomData<RequestPayload>,
+    _request_user_header: PhantomData<RequestUserHeader>,
+    _response_payload: PhantomData<ResponsePayload>,
+    _response_user_header: PhantomData<ResponseUserHeader>,
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Drop
+    for Client<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_client_handle {
+            self.backend
+                .service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .release_client_handle(handle)
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Client<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    pub(crate) fn new(service: &Service) -> Result<Self, ClientCreateError> {
+        let msg = "Unable to create Client port";
+        let origin = "Client::new()";
+        let client_id = UniqueClientId::new();
+        let static_config = service
+            .__internal_state()
+            .static_config
+            .request_response()
+            .clone();
+        let server_list = &service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .servers;
+
+        let number_of_requests = static_config.required_amount_of_requests_per_client();
+        let data_segment = fail!(from origin,
+            when ReferenceCountedDataSegment::create(
+                &port_data_segment_name(client_id.value()),
+                number_of_requests,
+                service.__internal_state(),
+                static_config.request_message_type_details.sample_layout(1)),
+            with ClientCreateError::UnableToCreateDataSegment,
+            "{} since the data segment could not be acquired.", msg);
+
+        let backend = Arc::new(ClientBackend {
+            client_id,
+            service_state: service.__internal_state().clone(),
+            response_buffer_size: static_config.response_buffer_size_per_client(),
+            static_config,
+            data_segment,
+            server_connections: (0..server_list.capacity())
+                .map(|_| RefCell::new(None))
+                .collect(),
+            server_list_state: UnsafeCell::new(unsafe { server_list.get_state() }),
+            request_id_counter: Cell::new(0),
+            active_requests: Cell::new(0),
+            pending_request_ids: RefCell::new(Vec::new()),
+            buffered_responses: RefCell::new(Vec::new()),
+        });
+
+        let mut new_self = Self {
+            backend,
+            dynamic_client_handle: None,
+            _request_payload: PhantomData,
+            _request_user_header: PhantomData,
+            _response_payload: PhantomData,
+            _response_user_header: PhantomData,
+        };
+
+        if let Err(e) = new_self.backend.populate_server_channels() {
+            warn!(from new_self, "The new Client port is unable to connect to every Server port, caused by {:?}.", e);
+        }
+
+        core::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a client is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_client_handle = match service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_client_id(ClientDetails {
+                client_id,
+                node_id: *service.__internal_state().shared_node.id(),
+                owner_start_time: Process::from_self().start_time().ok(),
+                number_of_requests,
+                response_buffer_size: new_self.backend.response_buffer_size,
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ClientCreateError::ExceedsMaxSupportedClients,
+                    "{} since it would exceed the maximum supported amount of clients of {}.",
+                    msg, new_self.backend.static_config.max_clients);
+            }
+        };
+
+        new_self.dynamic_client_handle = Some(dynamic_client_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`Client`]
+    pub fn id(&self) -> UniqueClientId {
+        self.backend.client_id
+    }
+
+    /// Returns the number of [`Server`](crate::port::server::Server)s the [`Client`] is
+    /// currently connected to.
+    pub fn number_of_server_connections(&self) -> usize {
+        self.backend.number_of_server_connections()
+    }
+
+    /// Loans uninitialized memory for a request. The request header is already initialized
+    /// and the payload must be written with [`RequestMut::write_payload()`] before it can be
+    /// sent.
+    ///
+    /// On failure it returns [`RequestLoanError`] describing the failure.
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<
+        RequestMut<
+            Service,
+            MaybeUninit<RequestPayload>,
+            RequestUserHeader,
+            ResponsePayload,
+            ResponseUserHeader,
+        >,
+        RequestLoanError,
+    > {
+        let message_type_details = &self.backend.static_config.request_message_type_details;
+        let (offset, header) = self
+            .backend
+            .allocate(message_type_details.sample_layout(1))?;
+
+        let request_id = self.backend.request_id_counter.get() + 1;
+        self.backend.request_id_counter.set(request_id);
+
+        let header = header as *mut RequestHeader;
+        unsafe { header.write(RequestHeader::new(self.backend.client_id, request_id)) };
+        let user_header = message_type_details.user_header_ptr_from_header(header.cast());
+        let payload = message_type_details.payload_ptr_from_header(header.cast());
+
+        Ok(RequestMut::new(self.backend.clone(), offset, unsafe {
+            RawSampleMut::new_unchecked(
+                header,
+                user_header as *mut RequestUserHeader,
+                payload as *mut MaybeUninit<RequestPayload>,
+            )
+        }))
+    }
+
+    /// Copies the value into a new request and sends it to all connected
+    /// [`Server`](crate::port::server::Server)s. Returns the [`PendingResponse`] that receives
+    /// the responses to the request.
+    ///
+    /// On failure it returns [`RequestSendError`] describing the failure.
+    pub fn send_copy(
+        &self,
+        value: RequestPayload,
+    ) -> Result<
+        PendingResponse<
+            Service,
+            RequestPayload,
+            RequestUserHeader,
+            ResponsePayload,
+            ResponseUserHeader,
+        >,
+        RequestSendError,
+    > {
+        let request = self.loan_uninit()?;
+        request.write_payload(value).send()
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + Default,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Client<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    /// Loans memory for a request and initializes the payload with [`Default::default()`].
+    ///
+    /// On failure it returns [`RequestLoanError`] describing the failure.
+    pub fn loan(
+        &self,
+    ) -> Result<
+        RequestMut<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>,
+        RequestLoanError,
+    > {
+        Ok(self.loan_uninit()?.write_payload(RequestPayload::default()))
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > UpdateConnections
+    for Client<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.backend.update_connections()
+    }
+}
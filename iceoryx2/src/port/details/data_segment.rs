@@ -12,7 +12,7 @@
 
 use core::alloc::Layout;
 
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::{fail, warn};
 use iceoryx2_cal::{
     event::NamedConceptBuilder,
     resizable_shared_memory::*,
@@ -22,19 +22,16 @@ use iceoryx2_cal::{
     },
     shm_allocator::{
         self, pool_allocator::PoolAllocator, AllocationError, AllocationStrategy, PointerOffset,
-        SegmentId, ShmAllocationError,
+        PoolAllocationStrategy, SegmentId, ShmAllocationError,
     },
 };
 
-use crate::{
-    config,
-    service::{
-        self,
-        config_scheme::{data_segment_config, resizable_data_segment_config},
-        dynamic_config::publish_subscribe::PublisherDetails,
-        naming_scheme::data_segment_name,
-    },
+use crate::service::{
+    self,
+    config_scheme::{data_segment_config, resizable_data_segment_config},
+    ServiceState,
 };
+use iceoryx2_bb_system_types::file_name::FileName;
 
 #[doc(hidden)]
 #[repr(C)]
@@ -53,6 +50,12 @@ impl DataSegmentType {
     }
 }
 
+/// Returns the size of the data segment that is created initially for the given number of
+/// samples.
+pub(crate) fn initial_data_segment_size(sample_layout: Layout, number_of_samples: usize) -> usize {
+    sample_layout.size() * number_of_samples + sample_layout.align() - 1
+}
+
 #[derive(Debug)]
 enum MemoryType<Service: service::Service> {
     Static(Service::SharedMemory),
@@ -66,42 +69,57 @@ pub(crate) struct DataSegment<Service: service::Service> {
 
 impl<Service: service::Service> DataSegment<Service> {
     pub(crate) fn create(
-        details: &PublisherDetails,
-        global_config: &config::Config,
+        segment_name: &FileName,
+        data_segment_type: DataSegmentType,
+        number_of_samples: usize,
+        service_state: &ServiceState<Service>,
         sample_layout: Layout,
         allocation_strategy: AllocationStrategy,
+        pool_allocation_strategy: PoolAllocationStrategy,
     ) -> Result<Self, SharedMemoryCreateError> {
         let allocator_config = shm_allocator::pool_allocator::Config {
             bucket_layout: sample_layout,
+            strategy: pool_allocation_strategy,
         };
         let msg = "Unable to create the data segment since the underlying shared memory could not be created.";
         let origin = "DataSegment::create()";
 
-        let segment_name = data_segment_name(&details.publisher_id);
-        let memory = match details.data_segment_type {
+        let memory = match data_segment_type {
             DataSegmentType::Static => {
-                let segment_config = data_segment_config::<Service>(global_config);
+                let segment_config = data_segment_config::<Service>(
+                    service_state.shared_node.config(),
+                    service_state.directory.as_ref(),
+                );
                 let memory = fail!(from origin,
                                 when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
                                 Service::SharedMemory,
-                                    >>::new(&segment_name)
+                                    >>::new(segment_name)
                                     .config(&segment_config)
-                                    .size(sample_layout.size() * details.number_of_samples + sample_layout.align() - 1)
+                                    .size(initial_data_segment_size(sample_layout, number_of_samples))
                                     .create(&allocator_config),
                                 "{msg}");
                 MemoryType::Static(memory)
             }
             DataSegmentType::Dynamic => {
-                let segment_config = resizable_data_segment_config::<Service>(global_config);
+                if pool_allocation_strategy != PoolAllocationStrategy::FreeList {
+                    warn!(from origin,
+                        "The pool allocation strategy {:?} is not supported by resizable data segments, the free-list is used instead.",
+                        pool_allocation_strategy);
+                }
+
+                let segment_config = resizable_data_segment_config::<Service>(
+                    service_state.shared_node.config(),
+                    service_state.directory.as_ref(),
+                );
                 let memory = fail!(from origin,
                     when <<Service::ResizableSharedMemory as ResizableSharedMemory<
                         PoolAllocator,
                         Service::SharedMemory,
                     >>::MemoryBuilder as NamedConceptBuilder<Service::ResizableSharedMemory>>::new(
-                        &segment_name,
+                        segment_name,
                     )
                     .config(&segment_config)
-                    .max_number_of_chunks_hint(details.number_of_samples)
+                    .max_number_of_chunks_hint(number_of_samples)
                     .max_chunk_layout_hint(sample_layout)
                     .allocation_strategy(allocation_strategy)
                     .create(),
@@ -152,6 +170,13 @@ impl<Service: service::Service> DataSegment<Service> {
         }
     }
 
+    pub(crate) fn size(&self) -> usize {
+        match &self.memory {
+            MemoryType::Static(memory) => memory.size(),
+            MemoryType::Dynamic(memory) => memory.size(),
+        }
+    }
+
     pub(crate) fn max_number_of_segments(data_segment_type: DataSegmentType) -> u8 {
         match data_segment_type {
             DataSegmentType::Static => 1,
@@ -180,34 +205,40 @@ pub(crate) struct DataSegmentView<Service: service::Service> {
 
 impl<Service: service::Service> DataSegmentView<Service> {
     pub(crate) fn open(
-        details: &PublisherDetails,
-        global_config: &config::Config,
+        segment_name: &FileName,
+        data_segment_type: DataSegmentType,
+        service_state: &ServiceState<Service>,
     ) -> Result<Self, SharedMemoryOpenError> {
-        let segment_name = data_segment_name(&details.publisher_id);
         let origin = "DataSegment::open()";
         let msg =
             "Unable to open data segment since the underlying shared memory could not be opened.";
 
-        let memory = match details.data_segment_type {
+        let memory = match data_segment_type {
             DataSegmentType::Static => {
-                let segment_config = data_segment_config::<Service>(global_config);
+                let segment_config = data_segment_config::<Service>(
+                    service_state.shared_node.config(),
+                    service_state.directory.as_ref(),
+                );
                 let memory = fail!(from origin,
                             when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
-                                Builder::new(&segment_name)
+                                Builder::new(segment_name)
                                 .config(&segment_config)
-                                .timeout(global_config.global.service.creation_timeout)
+                                .timeout(service_state.shared_node.config().global.service.creation_timeout)
                                 .open(),
                             "{msg}");
                 MemoryViewType::Static(memory)
             }
             DataSegmentType::Dynamic => {
-                let segment_config = resizable_data_segment_config::<Service>(global_config);
+                let segment_config = resizable_data_segment_config::<Service>(
+                    service_state.shared_node.config(),
+                    service_state.directory.as_ref(),
+                );
                 let memory = fail!(from origin,
                     when <<Service::ResizableSharedMemory as ResizableSharedMemory<
                         PoolAllocator,
                         Service::SharedMemory,
                     >>::ViewBuilder as NamedConceptBuilder<Service::ResizableSharedMemory>>::new(
-                        &segment_name,
+                        segment_name,
                     )
                     .config(&segment_config)
                     .open(),
@@ -243,4 +274,13 @@ impl<Service: service::Service> DataSegmentView<Service> {
             memory.unregister_offset(offset);
         }
     }
+
+    /// Returns the payload start address and the payload size of the mapped segment with the
+    /// provided [`SegmentId`].
+    pub(crate) fn segment_region(&self, segment_id: SegmentId) -> Option<(usize, usize)> {
+        match &self.memory {
+            MemoryViewType::Static(memory) => Some((memory.payload_start_address(), memory.size())),
+            MemoryViewType::Dynamic(memory) => memory.segment_region(segment_id),
+        }
+    }
 }
@@ -0,0 +1,71 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Counters for the process-local bookkeeping of a port. By default they are atomics. With the
+//! `single_threaded_publisher` feature they are plain [`Cell`](core::cell::Cell)s with the same
+//! interface, which is only sound since the port that owns them is neither [`Send`] nor [`Sync`].
+
+#[cfg(not(feature = "single_threaded_publisher"))]
+pub(crate) type LocalCounterU64 = iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+#[cfg(not(feature = "single_threaded_publisher"))]
+pub(crate) type LocalCounterUsize = iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+
+#[cfg(feature = "single_threaded_publisher")]
+pub(crate) type LocalCounterU64 = single_threaded::Counter<u64>;
+#[cfg(feature = "single_threaded_publisher")]
+pub(crate) type LocalCounterUsize = single_threaded::Counter<usize>;
+
+#[cfg(feature = "single_threaded_publisher")]
+mod single_threaded {
+    use core::cell::Cell;
+    use core::ops::{Add, Sub};
+    use core::sync::atomic::Ordering;
+
+    /// Non-atomic counter that mirrors the subset of the atomic interface the ports use. The
+    /// [`Ordering`] arguments are ignored.
+    #[derive(Debug)]
+    pub(crate) struct Counter<T: Copy> {
+        value: Cell<T>,
+        // keeps the counter on the thread that created it, even when the owning port becomes
+        // Send or Sync one day
+        _single_threaded: core::marker::PhantomData<*const ()>,
+    }
+
+    impl<T: Copy + Add<Output = T> + Sub<Output = T>> Counter<T> {
+        pub(crate) const fn new(value: T) -> Self {
+            Self {
+                value: Cell::new(value),
+                _single_threaded: core::marker::PhantomData,
+            }
+        }
+
+        pub(crate) fn load(&self, _order: Ordering) -> T {
+            self.value.get()
+        }
+
+        pub(crate) fn store(&self, value: T, _order: Ordering) {
+            self.value.set(value);
+        }
+
+        pub(crate) fn fetch_add(&self, value: T, _order: Ordering) -> T {
+            let old = self.value.get();
+            self.value.set(old + value);
+            old
+        }
+
+        pub(crate) fn fetch_sub(&self, value: T, _order: Ordering) -> T {
+            let old = self.value.get();
+            self.value.set(old - value);
+            old
+        }
+    }
+}
@@ -11,5 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 pub(crate) mod data_segment;
+pub(crate) mod local_counter;
 pub(crate) mod publisher_connections;
+pub(crate) mod reference_counted_data_segment;
 pub(crate) mod subscriber_connections;
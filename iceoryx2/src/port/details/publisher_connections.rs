@@ -10,7 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use core::cell::UnsafeCell;
+use core::cell::{Cell, UnsafeCell};
 
 extern crate alloc;
 use alloc::sync::Arc;
@@ -18,15 +18,21 @@ use alloc::sync::Arc;
 use crate::{
     port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
     service::{
-        self, config_scheme::connection_config,
-        dynamic_config::publish_subscribe::PublisherDetails, naming_scheme::connection_name,
-        static_config::publish_subscribe::StaticConfig, ServiceState,
+        self,
+        config_scheme::connection_config,
+        dynamic_config::publish_subscribe::PublisherDetails,
+        naming_scheme::{connection_name, data_segment_name},
+        static_config::publish_subscribe::StaticConfig,
+        ServiceState,
     },
 };
 
 use crate::port::update_connections::ConnectionFailure;
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::memory_lock::{MemoryLock, MemoryLockCreationError};
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shared_memory::SegmentId;
 use iceoryx2_cal::zero_copy_connection::*;
 
 use super::data_segment::DataSegmentView;
@@ -34,8 +40,11 @@ use super::data_segment::DataSegmentView;
 #[derive(Debug)]
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
+    // payload start address of every pinned segment, a remapped segment has to be pinned again
+    pinned_segments: Vec<Cell<usize>>,
     pub(crate) data_segment: DataSegmentView<Service>,
     pub(crate) publisher_id: UniquePublisherId,
+    pub(crate) static_config: Arc<StaticConfig>,
 }
 
 impl<Service: service::Service> Connection<Service> {
@@ -49,12 +58,13 @@ impl<Service: service::Service> Connection<Service> {
         );
 
         let global_config = this.service_state.shared_node.config();
+        let directory = this.service_state.directory.as_ref();
         let receiver = fail!(from this,
                         when <Service::Connection as ZeroCopyConnection>::
                             Builder::new( &connection_name(details.publisher_id, this.subscriber_id))
-                                    .config(&connection_config::<Service>(global_config))
+                                    .config(&connection_config::<Service>(global_config, directory))
                                     .buffer_size(this.buffer_size)
-                                    .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
+                                    .receiver_max_borrowed_samples(this.max_borrowed_samples)
                                     .enable_safe_overflow(this.static_config.enable_safe_overflow)
                                     .number_of_samples_per_segment(details.number_of_samples)
                                     .max_supported_shared_memory_segments(details.max_number_of_segments)
@@ -63,23 +73,71 @@ impl<Service: service::Service> Connection<Service> {
                         "{} since the zero copy connection could not be established.", msg);
 
         let data_segment = fail!(from this,
-                            when DataSegmentView::open(details, global_config),
+                            when DataSegmentView::open(&data_segment_name(&details.publisher_id), details.data_segment_type, &this.service_state),
                             "{} since the publishers data segment could not be opened.", msg);
 
         Ok(Self {
             receiver,
+            pinned_segments: (0..details.max_number_of_segments)
+                .map(|_| Cell::new(0))
+                .collect(),
             data_segment,
             publisher_id: details.publisher_id,
+            static_config: this.static_config.clone(),
         })
     }
+
+    /// Locks all pages of the data segment that contains the samples of `segment_id` with
+    /// `mlock`, so that they are not paged out. Every mapped segment is locked only once, its
+    /// pages stay locked until the segment is unmapped.
+    pub(crate) fn pin_segment(&self, segment_id: SegmentId) -> Result<(), MemoryLockCreationError> {
+        let pinned_segment = &self.pinned_segments[segment_id.value() as usize];
+        let (address, len) = match self.data_segment.segment_region(segment_id) {
+            Some(region) => region,
+            None => {
+                fail!(from self, with MemoryLockCreationError::InvalidAddressRange,
+                    "Unable to pin the data segment {:?} since it is not mapped.", segment_id);
+            }
+        };
+
+        if pinned_segment.get() == address {
+            return Ok(());
+        }
+
+        let page_size = SystemInfo::PageSize.value();
+        let start = address - address % page_size;
+        let lock = fail!(from self,
+            when unsafe { MemoryLock::new(start as *const _, len + address - start) },
+            "Unable to pin the data segment {:?} at {:#x} with a size of {} bytes.",
+            segment_id, address, len);
+
+        // munmap releases the lock, an explicit munlock would be called on memory that is
+        // already unmapped when the segment is released before the connection
+        core::mem::forget(lock);
+        pinned_segment.set(address);
+
+        Ok(())
+    }
+
+    /// Returns true when the currently mapped data segment of `segment_id` was pinned with
+    /// [`Connection::pin_segment()`].
+    pub(crate) fn is_segment_pinned(&self, segment_id: SegmentId) -> bool {
+        match self.data_segment.segment_region(segment_id) {
+            Some((address, _)) => {
+                self.pinned_segments[segment_id.value() as usize].get() == address
+            }
+            None => false,
+        }
+    }
 }
 #[derive(Debug)]
 pub(crate) struct PublisherConnections<Service: service::Service> {
     connections: Vec<UnsafeCell<Option<Arc<Connection<Service>>>>>,
     subscriber_id: UniqueSubscriberId,
     pub(crate) service_state: Arc<ServiceState<Service>>,
-    pub(crate) static_config: StaticConfig,
+    pub(crate) static_config: Arc<StaticConfig>,
     pub(crate) buffer_size: usize,
+    pub(crate) max_borrowed_samples: usize,
 }
 
 impl<Service: service::Service> PublisherConnections<Service> {
@@ -89,13 +147,15 @@ impl<Service: service::Service> PublisherConnections<Service> {
         service_state: Arc<ServiceState<Service>>,
         static_config: &StaticConfig,
         buffer_size: usize,
+        max_borrowed_samples: usize,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
             subscriber_id,
             service_state,
-            static_config: static_config.clone(),
+            static_config: Arc::new(static_config.clone()),
             buffer_size,
+            max_borrowed_samples,
         }
     }
 
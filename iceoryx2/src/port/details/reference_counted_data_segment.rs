@@ -0,0 +1,116 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::{alloc::Layout, cell::Cell};
+
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::{
+    shared_memory::{SharedMemoryCreateError, ShmPointer},
+    shm_allocator::{AllocationStrategy, PointerOffset, SegmentId, ShmAllocationError},
+};
+
+use crate::service::{self, ServiceState};
+
+use super::data_segment::{DataSegment, DataSegmentType};
+
+/// A static [`DataSegment`] with a fixed number of equally sized samples where every sample
+/// has a reference counter. A sample is returned to the [`DataSegment`] as soon as it is
+/// neither loaned nor delivered to any receiver.
+///
+/// The owning port is neither Send nor Sync, therefore the counters are only accessed by one
+/// thread and do not require atomics.
+#[derive(Debug)]
+pub(crate) struct ReferenceCountedDataSegment<Service: service::Service> {
+    data_segment: DataSegment<Service>,
+    reference_counter: Vec<Cell<u64>>,
+    sample_size: usize,
+}
+
+impl<Service: service::Service> ReferenceCountedDataSegment<Service> {
+    pub(crate) fn create(
+        segment_name: &FileName,
+        number_of_samples: usize,
+        service_state: &ServiceState<Service>,
+        sample_layout: Layout,
+    ) -> Result<Self, SharedMemoryCreateError> {
+        let data_segment = DataSegment::create(
+            segment_name,
+            DataSegmentType::Static,
+            number_of_samples,
+            service_state,
+            sample_layout,
+            AllocationStrategy::Static,
+            Default::default(),
+        )?;
+        let sample_size = data_segment.bucket_size(SegmentId::new(0));
+
+        Ok(Self {
+            data_segment,
+            reference_counter: (0..number_of_samples).map(|_| Cell::new(0)).collect(),
+            sample_size,
+        })
+    }
+
+    /// Returns the number of samples the [`DataSegment`] provides.
+    pub(crate) fn number_of_samples(&self) -> usize {
+        self.reference_counter.len()
+    }
+
+    /// Returns the size of every sample in bytes.
+    pub(crate) fn sample_size(&self) -> usize {
+        self.sample_size
+    }
+
+    fn counter(&self, offset: PointerOffset) -> &Cell<u64> {
+        &self.reference_counter[offset.offset() / self.sample_size]
+    }
+
+    /// Allocates a new sample which is borrowed once by the caller.
+    pub(crate) fn allocate(&self, layout: Layout) -> Result<ShmPointer, ShmAllocationError> {
+        let shm_pointer = self.data_segment.allocate(layout)?;
+        let counter = self.counter(shm_pointer.offset);
+        if counter.get() != 0 {
+            fatal_panic!(from self,
+                "This should never happen! The allocated sample {:?} is already in use.",
+                shm_pointer.offset);
+        }
+        counter.set(1);
+
+        Ok(shm_pointer)
+    }
+
+    /// Increments the reference counter of the sample, required whenever it is delivered to
+    /// a receiver.
+    pub(crate) fn borrow_sample(&self, offset: PointerOffset) {
+        let counter = self.counter(offset);
+        counter.set(counter.get() + 1);
+    }
+
+    /// Decrements the reference counter of the sample and returns it to the [`DataSegment`]
+    /// when it was the last reference.
+    pub(crate) fn release_sample(&self, offset: PointerOffset) {
+        let counter = self.counter(offset);
+        match counter.get() {
+            0 => {
+                fatal_panic!(from self,
+                    "This should never happen! The sample {:?} is released more often than it was borrowed.",
+                    offset);
+            }
+            1 => {
+                counter.set(0);
+                unsafe { self.data_segment.deallocate_bucket(offset) };
+            }
+            n => counter.set(n - 1),
+        }
+    }
+}
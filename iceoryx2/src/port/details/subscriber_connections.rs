@@ -10,23 +10,26 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use core::cell::UnsafeCell;
+use core::cell::{Cell, UnsafeCell};
+use core::time::Duration;
 
 extern crate alloc;
 use alloc::sync::Arc;
 
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_system_types::path::Path;
+use iceoryx2_cal::event::{Event, Notifier, NotifierBuilder, NotifierNotifyError, TriggerId};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::zero_copy_connection::{
     ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
 };
 
-use crate::node::SharedNode;
-use crate::service::config_scheme::connection_config;
+use crate::service::config_scheme::{connection_config, event_config};
 use crate::service::dynamic_config::publish_subscribe::SubscriberDetails;
+use crate::service::naming_scheme::sample_signal_name;
 use crate::{
     port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
-    service,
+    service::{self, ServiceState},
     service::{naming_scheme::connection_name, static_config::publish_subscribe::StaticConfig},
 };
 
@@ -34,11 +37,17 @@ use crate::{
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
     pub(crate) subscriber_id: UniqueSubscriberId,
+    pub(crate) history_size: usize,
+    pub(crate) history_max_age: Option<Duration>,
+    pub(crate) missed_deadlines: Cell<usize>,
+    pub(crate) subscriber_index: u32,
+    sample_signal: UnsafeCell<Option<<Service::Event as Event>::Notifier>>,
 }
 
 impl<Service: service::Service> Connection<Service> {
     fn new(
         this: &SubscriberConnections<Service>,
+        subscriber_index: usize,
         subscriber_details: SubscriberDetails,
         number_of_samples: usize,
     ) -> Result<Self, ZeroCopyCreationError> {
@@ -52,30 +61,68 @@ impl<Service: service::Service> Connection<Service> {
                 msg, subscriber_details.buffer_size, this.static_config.subscriber_max_buffer_size);
         }
 
+        if this.static_config.subscriber_max_borrowed_samples
+            < subscriber_details.max_borrowed_samples
+        {
+            fail!(from this, with ZeroCopyCreationError::IncompatibleMaxBorrowedSampleSetting,
+                "{} since the subscribers max borrowed samples {} exceed the services max subscriber borrowed samples of {}.",
+                msg, subscriber_details.max_borrowed_samples, this.static_config.subscriber_max_borrowed_samples);
+        }
+
+        let global_config = this.service_state.shared_node.config();
         let sender = fail!(from this, when <Service::Connection as ZeroCopyConnection>::
                         Builder::new( &connection_name(this.port_id, subscriber_details.subscriber_id))
-                                .config(&connection_config::<Service>(this.shared_node.config()))
+                                .config(&connection_config::<Service>(global_config, this.service_state.directory.as_ref()))
                                 .buffer_size(subscriber_details.buffer_size)
-                                .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
+                                .receiver_max_borrowed_samples(subscriber_details.max_borrowed_samples)
                                 .enable_safe_overflow(this.static_config.enable_safe_overflow)
                                 .number_of_samples_per_segment(number_of_samples)
                                 .max_supported_shared_memory_segments(this.max_number_of_segments)
-                                .timeout(this.shared_node.config().global.service.creation_timeout)
+                                .timeout(global_config.global.service.creation_timeout)
                                 .create_sender(),
                         "{}.", msg);
 
         Ok(Self {
             sender,
             subscriber_id: subscriber_details.subscriber_id,
+            history_size: subscriber_details.history_size,
+            history_max_age: subscriber_details.history_max_age,
+            missed_deadlines: Cell::new(0),
+            subscriber_index: subscriber_index as u32,
+            sample_signal: UnsafeCell::new(None),
         })
     }
+
+    /// Notifies the sample signal of the subscriber. It is opened with the first
+    /// notification since the subscriber creates it only when it waits for samples.
+    pub(crate) fn notify_sample_signal(
+        &self,
+        config: &crate::config::Config,
+        directory: Option<&Path>,
+    ) {
+        let sample_signal = unsafe { &mut *self.sample_signal.get() };
+        if sample_signal.is_none() {
+            *sample_signal = <Service::Event as Event>::NotifierBuilder::new(&sample_signal_name(
+                &self.subscriber_id,
+            ))
+            .config(&event_config::<Service>(config, directory))
+            .open()
+            .ok();
+        }
+
+        if let Some(notifier) = sample_signal {
+            if let Err(NotifierNotifyError::Disconnected) = notifier.notify(TriggerId::new(0)) {
+                *sample_signal = None;
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
 pub(crate) struct SubscriberConnections<Service: service::Service> {
     connections: Vec<UnsafeCell<Option<Connection<Service>>>>,
     port_id: UniquePublisherId,
-    shared_node: Arc<SharedNode<Service>>,
+    service_state: Arc<ServiceState<Service>>,
     pub(crate) static_config: StaticConfig,
     number_of_samples: usize,
     max_number_of_segments: u8,
@@ -84,7 +131,7 @@ pub(crate) struct SubscriberConnections<Service: service::Service> {
 impl<Service: service::Service> SubscriberConnections<Service> {
     pub(crate) fn new(
         capacity: usize,
-        shared_node: Arc<SharedNode<Service>>,
+        service_state: Arc<ServiceState<Service>>,
         port_id: UniquePublisherId,
         static_config: &StaticConfig,
         number_of_samples: usize,
@@ -92,7 +139,7 @@ impl<Service: service::Service> SubscriberConnections<Service> {
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
-            shared_node,
+            service_state,
             port_id,
             static_config: static_config.clone(),
             number_of_samples,
@@ -117,6 +164,10 @@ impl<Service: service::Service> SubscriberConnections<Service> {
         *self.get_mut(index) = None
     }
 
+    pub(crate) fn take(&self, index: usize) -> Option<Connection<Service>> {
+        self.get_mut(index).take()
+    }
+
     pub(crate) fn create(
         &self,
         index: usize,
@@ -124,6 +175,7 @@ impl<Service: service::Service> SubscriberConnections<Service> {
     ) -> Result<(), ZeroCopyCreationError> {
         *self.get_mut(index) = Some(Connection::new(
             self,
+            index,
             subscriber_details,
             self.number_of_samples,
         )?);
@@ -62,6 +62,8 @@ use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
 use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_bb_posix::process::Process;
+use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::{ListenerBuilder, ListenerWaitError, NamedConceptMgmt, TriggerId};
 use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptRemoveError};
@@ -143,7 +145,10 @@ impl<Service: service::Service> Listener<Service> {
         let listener_id = UniqueListenerId::new();
 
         let event_name = event_concept_name(&listener_id);
-        let event_config = event_config::<Service>(service.__internal_state().shared_node.config());
+        let event_config = event_config::<Service>(
+            service.__internal_state().shared_node.config(),
+            service.__internal_state().directory.as_ref(),
+        );
 
         let listener = fail!(from origin,
                              when <Service::Event as iceoryx2_cal::event::Event>::ListenerBuilder::new(&event_name).config(&event_config)
@@ -171,6 +176,7 @@ impl<Service: service::Service> Listener<Service> {
             .add_listener_id(ListenerDetails {
                 listener_id,
                 node_id: *service.__internal_state().shared_node.id(),
+                owner_start_time: Process::from_self().start_time().ok(),
             }) {
             Some(unique_index) => unique_index,
             None => {
@@ -268,6 +274,7 @@ impl<Service: service::Service> Listener<Service> {
 pub(crate) unsafe fn remove_connection_of_listener<Service: service::Service>(
     listener_id: &UniqueListenerId,
     config: &Config,
+    directory: Option<&Path>,
 ) -> Result<(), NamedConceptRemoveError> {
     let origin = format!(
         "remove_connection_of_listener::<{}>({:?})",
@@ -276,7 +283,7 @@ pub(crate) unsafe fn remove_connection_of_listener<Service: service::Service>(
     );
     let msg = "Unable to remove the listener connection";
     let event_name = event_concept_name(listener_id);
-    let event_config = event_config::<Service>(config);
+    let event_config = event_config::<Service>(config, directory);
 
     fail!(from origin,
             when <Service::Event as NamedConceptMgmt>::remove_cfg(&event_name, &event_config),
@@ -0,0 +1,161 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::merged_subscriber::MergedSubscriber;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service_a = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//! let service_b = node.service_builder(&"My/Other/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = MergedSubscriber::new(vec![
+//!     service_a.subscriber_builder().create()?,
+//!     service_b.subscriber_builder().create()?,
+//! ]);
+//!
+//! while let Some(sample) = subscriber.receive()? {
+//!     println!("received: {:?}", *sample);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::cell::Cell;
+use core::fmt::Debug;
+
+use crate::sample::Sample;
+use crate::service;
+
+use super::subscriber::{Subscriber, SubscriberReceiveError};
+use super::update_connections::ConnectionFailure;
+
+/// Combines multiple [`Subscriber`]s with the same payload and user header type into one
+/// stream of [`Sample`]s. Every call to [`MergedSubscriber::receive()`] starts with the
+/// [`Subscriber`] that follows the one that delivered the last [`Sample`], so that no
+/// [`Subscriber`] is starved by another one that always has data.
+///
+/// Every [`Subscriber`] keeps its own borrow accounting. When one [`Subscriber`] has already
+/// borrowed its maximum amount of [`Sample`]s, the others are still received from.
+#[derive(Debug)]
+pub struct MergedSubscriber<
+    Service: service::Service,
+    Payload: Debug + ?Sized + 'static,
+    UserHeader: Debug,
+> {
+    subscribers: Vec<Subscriber<Service, Payload, UserHeader>>,
+    next_subscriber: Cell<usize>,
+}
+
+impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
+    MergedSubscriber<Service, Payload, UserHeader>
+{
+    /// Creates a new [`MergedSubscriber`] that receives from all provided [`Subscriber`]s.
+    pub fn new(subscribers: Vec<Subscriber<Service, Payload, UserHeader>>) -> Self {
+        Self {
+            subscribers,
+            next_subscriber: Cell::new(0),
+        }
+    }
+
+    /// Returns the underlying [`Subscriber`]s.
+    pub fn subscribers(&self) -> &[Subscriber<Service, Payload, UserHeader>] {
+        &self.subscribers
+    }
+
+    /// Releases the underlying [`Subscriber`]s.
+    pub fn into_subscribers(self) -> Vec<Subscriber<Service, Payload, UserHeader>> {
+        self.subscribers
+    }
+
+    /// Returns true if any of the underlying [`Subscriber`]s has samples in its buffer that can
+    /// be received with [`MergedSubscriber::receive`].
+    pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        for subscriber in &self.subscribers {
+            if subscriber.has_samples()? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn receive_impl<
+        T,
+        F: FnMut(
+            &Subscriber<Service, Payload, UserHeader>,
+        ) -> Result<Option<T>, SubscriberReceiveError>,
+    >(
+        &self,
+        mut receive_call: F,
+    ) -> Result<Option<T>, SubscriberReceiveError> {
+        let number_of_subscribers = self.subscribers.len();
+        let start = self.next_subscriber.get();
+        let mut first_error = None;
+
+        for n in 0..number_of_subscribers {
+            let index = (start + n) % number_of_subscribers;
+            match receive_call(&self.subscribers[index]) {
+                Ok(Some(sample)) => {
+                    self.next_subscriber
+                        .set((index + 1) % number_of_subscribers);
+                    return Ok(Some(sample));
+                }
+                Ok(None) => (),
+                Err(e) => {
+                    if first_error.is_none() {
+                        first_error = Some(e);
+                    }
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
+    MergedSubscriber<Service, Payload, UserHeader>
+{
+    /// Receives a [`Sample`] from one of the underlying [`Subscriber`]s. If no sample could be
+    /// received [`None`] is returned. A [`SubscriberReceiveError`] is only returned when none of
+    /// the [`Subscriber`]s delivered a [`Sample`] and at least one of them failed.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        self.receive_impl(|subscriber| subscriber.receive())
+    }
+}
+
+impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
+    MergedSubscriber<Service, [Payload], UserHeader>
+{
+    /// Receives a [`Sample`] from one of the underlying [`Subscriber`]s. If no sample could be
+    /// received [`None`] is returned. A [`SubscriberReceiveError`] is only returned when none of
+    /// the [`Subscriber`]s delivered a [`Sample`] and at least one of them failed.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
+        self.receive_impl(|subscriber| subscriber.receive())
+    }
+}
@@ -22,12 +22,22 @@ pub mod client;
 pub mod event_id;
 /// Receiving endpoint (port) for event based communication
 pub mod listener;
+/// Receives from multiple publish-subscribe [`Subscriber`](crate::port::subscriber::Subscriber)s
+/// as one stream
+pub mod merged_subscriber;
+/// Sending endpoint (port) for publish-subscribe based communication with multiple payload types
+pub mod multi_publisher;
+/// Receiving endpoint (port) for publish-subscribe based communication with multiple payload types
+pub mod multi_subscriber;
 /// Sending endpoint (port) for event based communication
 pub mod notifier;
 /// Defines port specific unique ids. Used to identify source/destination while communicating.
 pub mod port_identifiers;
 /// Sending endpoint (port) for publish-subscribe based communication
 pub mod publisher;
+/// Sliding windows over the values of consecutive slice samples received by a
+/// [`Subscriber`](crate::port::subscriber::Subscriber)
+pub mod sample_window;
 /// Receives requests from a [`Client`](crate::port::client::Client) port and sends back responses.
 pub mod server;
 /// Receiving endpoint (port) for publish-subscribe based communication
@@ -35,6 +45,9 @@ pub mod subscriber;
 /// Interface to perform cyclic updates to the ports. Required to deliver history to new
 /// participants or to perform other management tasks.
 pub mod update_connections;
+/// Receives from all publish-subscribe [`Service`](crate::service::Service)s whose name
+/// matches a pattern and connects to new ones as they appear
+pub mod wildcard_subscriber;
 
 use crate::port::port_identifiers::*;
 use crate::service;
@@ -0,0 +1,131 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/Bus".try_into()?)
+//!     .publish_subscribe_multi()
+//!     .register::<u64>()
+//!     .register::<[u8; 16]>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher()?;
+//!
+//! publisher.send(1234u64)?;
+//! publisher.send([0u8; 16])?;
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_log::fail;
+
+use crate::service;
+use crate::service::builder::publish_subscribe::CustomPayloadMarker;
+use crate::service::builder::publish_subscribe_multi::TypeTag;
+use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+
+use super::port_identifiers::UniquePublisherId;
+use super::publisher::{Publisher, PublisherLoanError, PublisherSendError};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+
+/// Defines a failure that can occur in [`MultiPublisher::send()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MultiPublisherSendError {
+    /// The type of the value was not registered with
+    /// [`register()`](crate::service::builder::publish_subscribe_multi::Builder::register())
+    UnregisteredType,
+    /// The underlying [`Publisher`] was unable to loan or send the sample.
+    SendError(PublisherSendError),
+}
+
+impl From<PublisherSendError> for MultiPublisherSendError {
+    fn from(value: PublisherSendError) -> Self {
+        MultiPublisherSendError::SendError(value)
+    }
+}
+
+impl From<PublisherLoanError> for MultiPublisherSendError {
+    fn from(value: PublisherLoanError) -> Self {
+        MultiPublisherSendError::SendError(PublisherSendError::LoanError(value))
+    }
+}
+
+impl core::fmt::Display for MultiPublisherSendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "MultiPublisherSendError::{:?}", self)
+    }
+}
+
+impl std::error::Error for MultiPublisherSendError {}
+
+/// Sends values of every type that was registered at the multi type
+/// [`Service`](crate::service::Service). Every sample carries the type tag of the value so that
+/// a [`MultiSubscriber`](crate::port::multi_subscriber::MultiSubscriber) can restore its type.
+#[derive(Debug)]
+pub struct MultiPublisher<Service: service::Service> {
+    publisher: Publisher<Service, [CustomPayloadMarker], TypeTag>,
+    types: Arc<Vec<TypeDetail>>,
+}
+
+impl<Service: service::Service> MultiPublisher<Service> {
+    pub(crate) fn new(
+        publisher: Publisher<Service, [CustomPayloadMarker], TypeTag>,
+        types: Arc<Vec<TypeDetail>>,
+    ) -> Self {
+        Self { publisher, types }
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`MultiPublisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.publisher.id()
+    }
+
+    /// Copies the value into a new sample and delivers it to all connected
+    /// [`MultiSubscriber`](crate::port::multi_subscriber::MultiSubscriber)s. On success it
+    /// returns the number of recipients.
+    pub fn send<T: Debug>(&self, value: T) -> Result<usize, MultiPublisherSendError> {
+        let type_detail = TypeDetail::__internal_new::<T>(TypeVariant::FixedSize);
+        let type_tag = match self.types.iter().position(|t| *t == type_detail) {
+            Some(index) => index as u64,
+            None => {
+                fail!(from self, with MultiPublisherSendError::UnregisteredType,
+                    "Unable to send sample since the type \"{}\" is not registered at the service.",
+                    type_detail.type_name);
+            }
+        };
+
+        // the service payload is sized and aligned for the largest registered type
+        let mut sample = unsafe { self.publisher.loan_custom_payload(1)? };
+        sample.user_header_mut().value = type_tag;
+        unsafe { (sample.payload_mut().as_mut_ptr() as *mut T).write(value) };
+
+        let mut sample = unsafe { sample.assume_init() };
+        sample.set_used_len(core::mem::size_of::<T>());
+        Ok(sample.send()?)
+    }
+}
+
+impl<Service: service::Service> UpdateConnections for MultiPublisher<Service> {
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.publisher.update_connections()
+    }
+}
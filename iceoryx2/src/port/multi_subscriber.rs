@@ -0,0 +1,188 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/Bus".try_into()?)
+//!     .publish_subscribe_multi()
+//!     .register::<u64>()
+//!     .register::<[u8; 16]>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = service.subscriber()?;
+//!
+//! while let Some(sample) = subscriber.receive()? {
+//!     match sample.type_tag() {
+//!         0 => println!("counter: {:?}", sample.match_type::<u64>()),
+//!         _ => println!("bytes: {:?}", sample.match_type::<[u8; 16]>()),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_log::fail;
+
+use crate::sample::Sample;
+use crate::service;
+use crate::service::builder::publish_subscribe::CustomPayloadMarker;
+use crate::service::builder::publish_subscribe_multi::TypeTag;
+use crate::service::header::publish_subscribe::Header;
+use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use super::subscriber::{Subscriber, SubscriberReceiveError};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+
+/// Defines a failure that can occur in [`MultiSubscriber::receive()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MultiSubscriberReceiveError {
+    /// The received sample carries a type tag that does not belong to any registered type.
+    UnknownTypeTag(u64),
+    /// The underlying [`Subscriber`] was unable to receive the sample.
+    ReceiveError(SubscriberReceiveError),
+}
+
+impl From<SubscriberReceiveError> for MultiSubscriberReceiveError {
+    fn from(value: SubscriberReceiveError) -> Self {
+        MultiSubscriberReceiveError::ReceiveError(value)
+    }
+}
+
+impl core::fmt::Display for MultiSubscriberReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "MultiSubscriberReceiveError::{:?}", self)
+    }
+}
+
+impl std::error::Error for MultiSubscriberReceiveError {}
+
+/// A sample of a multi type [`Service`](crate::service::Service). The payload can be accessed
+/// with [`MultiSample::match_type()`] that verifies the type tag of the sample.
+pub struct MultiSample<Service: service::Service> {
+    sample: Sample<Service, [CustomPayloadMarker], TypeTag>,
+    types: Arc<Vec<TypeDetail>>,
+}
+
+impl<Service: service::Service> Debug for MultiSample<Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "MultiSample<{}> {{ type_name: {}, sample: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.type_name(),
+            self.sample
+        )
+    }
+}
+
+impl<Service: service::Service> MultiSample<Service> {
+    /// Returns the type tag of the payload, the position of its type in
+    /// [`registered_types()`](crate::service::port_factory::publish_subscribe_multi::PortFactory::registered_types()).
+    pub fn type_tag(&self) -> u64 {
+        self.sample.user_header().value
+    }
+
+    /// Returns the name of the payload type as provided by [`core::any::type_name()`].
+    pub fn type_name(&self) -> &str {
+        &self.types[self.type_tag() as usize].type_name
+    }
+
+    /// Returns a reference to the payload when it is of type `T`, otherwise [`None`].
+    pub fn match_type<T: Debug>(&self) -> Option<&T> {
+        if self.types[self.type_tag() as usize]
+            != TypeDetail::__internal_new::<T>(TypeVariant::FixedSize)
+        {
+            return None;
+        }
+
+        Some(unsafe { &*(self.sample.payload().as_ptr() as *const T) })
+    }
+
+    /// Returns a reference to the [`Header`] of the [`MultiSample`].
+    pub fn header(&self) -> &Header {
+        self.sample.header()
+    }
+
+    /// Returns the [`UniquePublisherId`] of the
+    /// [`MultiPublisher`](crate::port::multi_publisher::MultiPublisher)
+    pub fn origin(&self) -> UniquePublisherId {
+        self.sample.origin()
+    }
+}
+
+/// Receives the samples of a multi type [`Service`](crate::service::Service) and verifies that
+/// their type tag belongs to a registered type.
+#[derive(Debug)]
+pub struct MultiSubscriber<Service: service::Service> {
+    subscriber: Subscriber<Service, [CustomPayloadMarker], TypeTag>,
+    types: Arc<Vec<TypeDetail>>,
+}
+
+impl<Service: service::Service> MultiSubscriber<Service> {
+    pub(crate) fn new(
+        subscriber: Subscriber<Service, [CustomPayloadMarker], TypeTag>,
+        types: Arc<Vec<TypeDetail>>,
+    ) -> Self {
+        Self { subscriber, types }
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the [`MultiSubscriber`]
+    pub fn id(&self) -> UniqueSubscriberId {
+        self.subscriber.id()
+    }
+
+    /// Returns true if the [`MultiSubscriber`] has samples in the buffer that can be received
+    /// with [`MultiSubscriber::receive()`].
+    pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        self.subscriber.has_samples()
+    }
+
+    /// Receives a [`MultiSample`] from a
+    /// [`MultiPublisher`](crate::port::multi_publisher::MultiPublisher). If no sample could be
+    /// received [`None`] is returned. A sample with an unknown type tag is released and
+    /// [`MultiSubscriberReceiveError::UnknownTypeTag`] is returned.
+    pub fn receive(&self) -> Result<Option<MultiSample<Service>>, MultiSubscriberReceiveError> {
+        let sample = match unsafe { self.subscriber.receive_custom_payload()? } {
+            Some(sample) => sample,
+            None => return Ok(None),
+        };
+
+        let type_tag = sample.user_header().value;
+        if self.types.len() as u64 <= type_tag {
+            fail!(from self, with MultiSubscriberReceiveError::UnknownTypeTag(type_tag),
+                "Unable to receive sample since its type tag {} does not belong to one of the {} registered types.",
+                type_tag, self.types.len());
+        }
+
+        Ok(Some(MultiSample {
+            sample,
+            types: self.types.clone(),
+        }))
+    }
+}
+
+impl<Service: service::Service> UpdateConnections for MultiSubscriber<Service> {
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.subscriber.update_connections()
+    }
+}
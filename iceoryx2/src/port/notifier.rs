@@ -49,6 +49,7 @@ use crate::{
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{debug, fail, warn};
+use iceoryx2_bb_posix::process::Process;
 use iceoryx2_cal::{dynamic_storage::DynamicStorage, event::NotifierBuilder};
 use iceoryx2_cal::{event::Event, named_concept::NamedConceptBuilder};
 
@@ -131,7 +132,10 @@ impl<Service: service::Service> ListenerConnections<Service> {
     fn create(&self, index: usize, listener_id: UniqueListenerId) {
         let msg = "Unable to establish connection to listener";
         let event_name = event_concept_name(&listener_id);
-        let event_config = event_config::<Service>(self.service_state.shared_node.config());
+        let event_config = event_config::<Service>(
+            self.service_state.shared_node.config(),
+            self.service_state.directory.as_ref(),
+        );
         if self.get(index).is_none() {
             match <Service::Event as iceoryx2_cal::event::Event>::NotifierBuilder::new(&event_name)
                 .config(&event_config)
@@ -287,6 +291,7 @@ impl<Service: service::Service> Notifier<Service> {
             .add_notifier_id(NotifierDetails {
                 notifier_id,
                 node_id: *service.__internal_state().shared_node.id(),
+                owner_start_time: Process::from_self().start_time().ok(),
             }) {
             Some(handle) => handle,
             None => {
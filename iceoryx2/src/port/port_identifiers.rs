@@ -11,6 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::process::ProcessId;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 
 macro_rules! generate_id {
@@ -40,6 +41,10 @@ macro_rules! generate_id {
             pub fn value(&self) -> u128 {
                 self.0.value()
             }
+
+            pub(crate) fn process_id(&self) -> ProcessId {
+                self.0.pid()
+            }
         }
     };
 }
@@ -60,6 +65,14 @@ generate_id! {
     /// The system-wide unique id of a [`Listener`](crate::port::listener::Listener).
     UniqueListenerId
 }
+generate_id! {
+    /// The system-wide unique id of a [`Client`](crate::port::client::Client).
+    UniqueClientId
+}
+generate_id! {
+    /// The system-wide unique id of a [`Server`](crate::port::server::Server).
+    UniqueServerId
+}
 
 /// Enum that contains the unique port id
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -72,4 +85,22 @@ pub enum UniquePortId {
     Notifier(UniqueNotifierId),
     /// The system-wide unique id of a [`Listener`](crate::port::listener::Listener).
     Listener(UniqueListenerId),
+    /// The system-wide unique id of a [`Client`](crate::port::client::Client).
+    Client(UniqueClientId),
+    /// The system-wide unique id of a [`Server`](crate::port::server::Server).
+    Server(UniqueServerId),
+}
+
+impl UniquePortId {
+    /// Returns the [`ProcessId`] of the process that created the port.
+    pub(crate) fn process_id(&self) -> ProcessId {
+        match self {
+            UniquePortId::Publisher(id) => id.process_id(),
+            UniquePortId::Subscriber(id) => id.process_id(),
+            UniquePortId::Notifier(id) => id.process_id(),
+            UniquePortId::Listener(id) => id.process_id(),
+            UniquePortId::Client(id) => id.process_id(),
+            UniquePortId::Server(id) => id.process_id(),
+        }
+    }
 }
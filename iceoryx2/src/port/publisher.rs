@@ -100,8 +100,25 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Memory Ordering
+//!
+//! Delivering a [`SampleMut`](crate::sample_mut::SampleMut) to a
+//! [`Subscriber`](crate::port::subscriber::Subscriber) has release semantics and receiving it with
+//! [`Subscriber::receive()`](crate::port::subscriber::Subscriber::receive()) has acquire semantics.
+//! When a [`Subscriber`](crate::port::subscriber::Subscriber) receives sample N, every write the
+//! [`Publisher`](crate::port::publisher::Publisher) performed before sending sample N is visible to
+//! it. This includes writes to the payload of the sample as well as writes to memory outside of the
+//! sample, for instance a separate shared memory region that is referenced by an index stored in
+//! the sample. Writes that are performed after the sample was sent are not covered.
+//!
+//! When the availability of data outside of the sample is signaled by other means than the sample
+//! itself, e.g. by a relaxed atomic flag in a user managed shared memory region,
+//! [`Publisher::publish_fence()`](crate::port::publisher::Publisher::publish_fence()) establishes
+//! the release fence that must precede the signal.
 
 use super::details::data_segment::{DataSegment, DataSegmentType};
+use super::details::local_counter::{LocalCounterU64, LocalCounterUsize};
 use super::port_identifiers::UniquePublisherId;
 use super::UniqueSubscriberId;
 use crate::port::details::subscriber_connections::*;
@@ -114,24 +131,33 @@ use crate::service::config_scheme::{connection_config, data_segment_config};
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::{
-    data_segment_name, extract_publisher_id_from_connection, extract_subscriber_id_from_connection,
+    data_segment_name, extract_port_values_from_connection, extract_publisher_id_from_connection,
+    extract_subscriber_id_from_connection, port_data_segment_name,
+};
+use crate::service::port_factory::publisher::{
+    LoanInitPolicy, LocalPublisherConfig, UnableToDeliverStrategy,
 };
-use crate::service::port_factory::publisher::{LocalPublisherConfig, UnableToDeliverStrategy};
 use crate::service::static_config::message_type_details::TypeVariant;
-use crate::service::static_config::publish_subscribe::{self};
+use crate::service::static_config::publish_subscribe::{self, SlowSubscriberEviction};
 use crate::service::{self, ServiceState};
+use crate::span::enter_span;
 use crate::{config, sample_mut::SampleMut};
 use core::any::TypeId;
-use core::cell::UnsafeCell;
+use core::cell::{Cell, UnsafeCell};
 use core::fmt::Debug;
 use core::sync::atomic::Ordering;
+use core::time::Duration;
 use core::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::allocator::AllocationError;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{debug, error, fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::{AdaptiveTimedWaitWhileError, AdaptiveWaitBuilder};
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_bb_posix::process::Process;
 use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::NamedConceptMgmt;
 use iceoryx2_cal::named_concept::{NamedConceptListError, NamedConceptRemoveError};
@@ -141,7 +167,7 @@ use iceoryx2_cal::zero_copy_connection::{
     ZeroCopyConnection, ZeroCopyCreationError, ZeroCopyPortDetails, ZeroCopyPortRemoveError,
     ZeroCopySendError, ZeroCopySender,
 };
-use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 
 extern crate alloc;
 use alloc::sync::Arc;
@@ -157,6 +183,14 @@ pub enum PublisherCreateError {
     ExceedsMaxSupportedPublishers,
     /// The datasegment in which the payload of the [`Publisher`] is stored, could not be created.
     UnableToCreateDataSegment,
+    /// The [`Service`](crate::service::Service) was created with a newer schema of the payload,
+    /// see [`SchemaEvolution`](crate::service::static_config::message_type_details::SchemaEvolution).
+    /// A [`Publisher`] with an older schema would not initialize the appended fields.
+    OutdatedPayloadSchema,
+    /// The [`Publisher`] was created with
+    /// [`PortFactoryPublisher::enable_send_timestamp()`](crate::service::port_factory::publisher::PortFactoryPublisher::enable_send_timestamp())
+    /// but the sample header of the [`Service`](crate::service::Service) has no room for it.
+    SendTimestampNotSupported,
 }
 
 impl core::fmt::Display for PublisherCreateError {
@@ -168,10 +202,13 @@ impl core::fmt::Display for PublisherCreateError {
 impl std::error::Error for PublisherCreateError {}
 
 /// Defines a failure that can occur in [`Publisher::loan()`] and [`Publisher::loan_uninit()`]
-/// or is part of [`PublisherSendError`] emitted in [`Publisher::send_copy()`].
+/// or is part of [`PublisherSendError`] emitted in [`Publisher::send_copy()`] and
+/// [`Publisher::send_slice_copy()`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 pub enum PublisherLoanError {
-    /// The [`Publisher`]s data segment does not have any more memory left
+    /// The [`Publisher`]s data segment does not have any more memory left. The non-blocking
+    /// loan calls, like [`Publisher::try_loan()`], return it immediately when every sample of the
+    /// data segment is in use.
     OutOfMemory,
     /// The maximum amount of [`SampleMut`]s a user can borrow with [`Publisher::loan()`] or
     /// [`Publisher::loan_uninit()`] is
@@ -200,14 +237,18 @@ pub enum PublisherSendError {
     /// [`SampleMut::send()`] was called but the corresponding [`Publisher`] went already out of
     /// scope.
     ConnectionBrokenSincePublisherNoLongerExists,
-    /// A connection between a [`Subscriber`](crate::port::subscriber::Subscriber) and a
-    /// [`Publisher`] is corrupted.
-    ConnectionCorrupted,
+    /// The connection to the [`Subscriber`](crate::port::subscriber::Subscriber) with the
+    /// contained id is corrupted. It is only returned when the
+    /// [`DegrationCallback`](crate::port::DegrationCallback) returns [`DegrationAction::Fail`].
+    ConnectionCorrupted(UniqueSubscriberId),
     /// A failure occurred while acquiring memory for the payload
     LoanError(PublisherLoanError),
     /// A failure occurred while establishing a connection to a
     /// [`Subscriber`](crate::port::subscriber::Subscriber)
     ConnectionError(ConnectionFailure),
+    /// The used length of the [`SampleMut`] exceeds the maximum message size of the
+    /// [`Service`](crate::service::Service) or the size of its payload.
+    ExceedsMaxMessageSize,
 }
 
 impl From<PublisherLoanError> for PublisherSendError {
@@ -230,6 +271,94 @@ impl core::fmt::Display for PublisherSendError {
 
 impl std::error::Error for PublisherSendError {}
 
+/// Failure that can be emitted by [`Publisher::flush()`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum PublisherFlushError {
+    /// Not all sent samples were received by the connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s before the timeout passed.
+    Timeout,
+    /// A failure occurred while establishing a connection to a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)
+    ConnectionError(ConnectionFailure),
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl From<ConnectionFailure> for PublisherFlushError {
+    fn from(value: ConnectionFailure) -> Self {
+        PublisherFlushError::ConnectionError(value)
+    }
+}
+
+impl core::fmt::Display for PublisherFlushError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "PublisherFlushError::{:?}", self)
+    }
+}
+
+impl std::error::Error for PublisherFlushError {}
+
+/// The result of [`SampleMut::poll_send()`].
+#[derive(Debug)]
+pub enum SendStatus<Sample> {
+    /// The sample was delivered to the contained number of
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s.
+    Delivered(usize),
+    /// The sample was not delivered since the buffer of at least one
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) is full and delivering it would
+    /// require to wait. The sample is handed back so that it can be sent again later or
+    /// discarded.
+    Pending {
+        /// The sample that was not delivered.
+        sample: Sample,
+        /// The number of [`Subscriber`](crate::port::subscriber::Subscriber)s with a full
+        /// buffer.
+        blocked_subscribers: usize,
+    },
+}
+
+/// Defines how [`Publisher::loan_batch()`] and [`Publisher::loan_batch_uninit()`] behave when
+/// fewer samples are available than requested.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum BatchLoanPolicy {
+    /// Loans all requested samples or none of them. When the [`Publisher`] was created with
+    /// [`UnableToDeliverStrategy::Block`], the call blocks until enough samples were returned,
+    /// otherwise it fails with [`PublisherLoanError::OutOfMemory`].
+    WaitForAll,
+    /// Loans as many samples as are available, up to the requested number, and never blocks.
+    AsManyAsAvailable,
+}
+
+/// Describes a [`Subscriber`](crate::port::subscriber::Subscriber) that is connected to a
+/// [`Publisher`] and the state of its receive buffer. Acquired with
+/// [`Publisher::connected_subscribers()`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct ConnectedSubscriber {
+    id: UniqueSubscriberId,
+    buffer_size: usize,
+    free_buffer_slots: usize,
+}
+
+impl ConnectedSubscriber {
+    /// Returns the [`UniqueSubscriberId`] of the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)
+    pub fn id(&self) -> UniqueSubscriberId {
+        self.id
+    }
+
+    /// Returns the size of the receive buffer of the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Returns the number of samples that can be sent until the receive buffer of the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) is full
+    pub fn free_buffer_slots(&self) -> usize {
+        self.free_buffer_slots
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub(crate) enum RemovePubSubPortFromAllConnectionsError {
     CleanupRaceDetected,
@@ -240,20 +369,20 @@ pub(crate) enum RemovePubSubPortFromAllConnectionsError {
 
 #[derive(Debug)]
 struct SegmentState {
-    sample_reference_counter: Vec<IoxAtomicU64>,
-    payload_size: IoxAtomicUsize,
+    sample_reference_counter: Vec<LocalCounterU64>,
+    payload_size: LocalCounterUsize,
 }
 
 impl SegmentState {
     fn new(number_of_samples: usize) -> Self {
         let mut sample_reference_counter = Vec::with_capacity(number_of_samples);
         for _ in 0..number_of_samples {
-            sample_reference_counter.push(IoxAtomicU64::new(0));
+            sample_reference_counter.push(LocalCounterU64::new(0));
         }
 
         Self {
             sample_reference_counter,
-            payload_size: IoxAtomicUsize::new(0),
+            payload_size: LocalCounterUsize::new(0),
         }
     }
 
@@ -285,6 +414,11 @@ impl SegmentState {
 struct OffsetAndSize {
     offset: u64,
     size: usize,
+    // acquired with Time::now() when the sample was sent, so that the age of the sample is
+    // not affected when the system time is adjusted
+    send_time: Time,
+    // all samples that were sent up to this one have a sequence number that is smaller or equal
+    sequence_number: u64,
 }
 
 #[derive(Debug)]
@@ -302,10 +436,19 @@ pub(crate) struct PublisherBackend<Service: service::Service> {
     service_state: Arc<ServiceState<Service>>,
 
     subscriber_connections: SubscriberConnections<Service>,
+    // connections of evicted subscribers that may still hold delivered samples
+    evicted_connections: UnsafeCell<Vec<Connection<Service>>>,
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
+    single_subscriber_connection_index: UnsafeCell<Option<usize>>,
     history: Option<UnsafeCell<Queue<OffsetAndSize>>>,
+    publisher_index: UnsafeCell<Option<u32>>,
+    deferred_history_releases: UnsafeCell<Vec<PointerOffset>>,
     static_config: crate::service::static_config::StaticConfig,
-    loan_counter: IoxAtomicUsize,
+    loan_counter: LocalCounterUsize,
+    active_sample_counter: Cell<usize>,
+    // the segment of the last allocation, it changes when a dynamic data segment is resized
+    current_segment_id: Cell<u8>,
+    sequence_counter: LocalCounterU64,
     is_active: IoxAtomicBool,
 }
 
@@ -315,12 +458,33 @@ impl<Service: service::Service> PublisherBackend<Service> {
 
         let msg = "Unable to allocate Sample";
         let shm_pointer = self.data_segment.allocate(layout)?;
+        let segment_id = shm_pointer.offset.segment_id().value();
+        if segment_id != self.current_segment_id.get() {
+            // the dynamic data segment was resized
+            self.current_segment_id.set(segment_id);
+            self.update_data_segment_size();
+        }
         let (ref_count, sample_size) = self.borrow_sample(shm_pointer.offset);
         if ref_count != 0 {
             fatal_panic!(from self,
                 "{} since the allocated sample is already in use! This should never happen!", msg);
         }
 
+        let active_samples = self.active_sample_counter.get() + 1;
+        self.active_sample_counter.set(active_samples);
+        self.service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .update_peak_active_samples(active_samples);
+        if let Some(index) = unsafe { *self.publisher_index.get() } {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .add_allocated_sample_bytes(index, sample_size);
+        }
+
         Ok(AllocationPair {
             shm_pointer,
             sample_size,
@@ -339,31 +503,88 @@ impl<Service: service::Service> PublisherBackend<Service> {
     }
 
     fn release_sample(&self, offset: PointerOffset) {
-        if self.segment_states[offset.segment_id().value() as usize].release_sample(offset.offset())
-            == 1
-        {
+        let segment_state = &self.segment_states[offset.segment_id().value() as usize];
+        if segment_state.release_sample(offset.offset()) == 1 {
             unsafe {
                 self.data_segment.deallocate_bucket(offset);
             }
+            self.active_sample_counter
+                .set(self.active_sample_counter.get() - 1);
+            self.release_allocated_sample_bytes(segment_state.payload_size());
+            // a resized data segment releases an old segment with its last sample
+            if offset.segment_id().value() != self.current_segment_id.get() {
+                self.update_data_segment_size();
+            }
+        }
+    }
+
+    fn update_data_segment_size(&self) {
+        if let Some(index) = unsafe { *self.publisher_index.get() } {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .set_publisher_data_segment_size(index, self.data_segment.size());
+        }
+    }
+
+    fn release_allocated_sample_bytes(&self, value: usize) {
+        // the remaining bytes of a removed publisher are released with its registration
+        if let Some(index) = unsafe { *self.publisher_index.get() } {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .remove_allocated_sample_bytes(index, value);
         }
     }
 
     fn retrieve_returned_samples(&self) {
-        for i in 0..self.subscriber_connections.len() {
-            if let Some(ref connection) = self.subscriber_connections.get(i) {
-                loop {
-                    match connection.sender.reclaim() {
-                        Ok(Some(ptr_dist)) => {
-                            self.release_sample(ptr_dist);
-                        }
-                        Ok(None) => break,
-                        Err(e) => {
-                            warn!(from self, "Unable to reclaim samples from connection {:?} due to {:?}. This may lead to a situation where no more samples will be delivered to this connection.", connection, e)
-                        }
+        self.release_deferred_history_samples();
+        match self.single_subscriber_connection() {
+            Some(connection) => self.retrieve_returned_samples_from(connection),
+            None => {
+                for i in 0..self.subscriber_connections.len() {
+                    if let Some(ref connection) = self.subscriber_connections.get(i) {
+                        self.retrieve_returned_samples_from(connection);
                     }
                 }
             }
         }
+        self.retrieve_returned_samples_from_evicted_subscribers();
+    }
+
+    fn retrieve_returned_samples_from_evicted_subscribers(&self) {
+        let evicted_connections = unsafe { &mut *self.evicted_connections.get() };
+        evicted_connections.retain(|connection| {
+            self.retrieve_returned_samples_from(connection);
+            if connection.sender.is_connected() {
+                return true;
+            }
+
+            // # SAFETY: the receiver no longer exist, therefore we can
+            //           reacquire all delivered samples
+            unsafe {
+                connection
+                    .sender
+                    .acquire_used_offsets(|offset| self.release_sample(offset))
+            };
+            false
+        });
+    }
+
+    fn retrieve_returned_samples_from(&self, connection: &Connection<Service>) {
+        loop {
+            match connection.sender.reclaim() {
+                Ok(Some(ptr_dist)) => {
+                    self.release_sample(ptr_dist);
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    warn!(from self, "Unable to reclaim samples from connection {:?} due to {:?}. This may lead to a situation where no more samples will be delivered to this connection.", connection, e)
+                }
+            }
+        }
     }
 
     fn remove_connection(&self, i: usize) {
@@ -385,19 +606,143 @@ impl<Service: service::Service> PublisherBackend<Service> {
         self.loan_counter.fetch_sub(1, Ordering::Relaxed);
     }
 
-    fn add_sample_to_history(&self, offset: PointerOffset, sample_size: usize) {
+    fn add_sample_to_history(&self, offset: PointerOffset, sample_size: usize, send_time: Time) {
         match &self.history {
             None => (),
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
                 self.borrow_sample(offset);
-                match history.push_with_overflow(OffsetAndSize {
+                let evicted_sample = history.push_with_overflow(OffsetAndSize {
                     offset: offset.as_value(),
                     size: sample_size,
-                }) {
-                    None => (),
-                    Some(old) => self.release_sample(PointerOffset::from_value(old.offset)),
+                    send_time,
+                    sequence_number: self.sequence_counter.load(Ordering::Relaxed),
+                });
+
+                // the mirror must no longer refer to the evicted sample before it is released
+                if let Some(mirror) = self.history_mirror() {
+                    mirror.push(offset.as_value(), sample_size);
+                }
+
+                if let Some(old) = evicted_sample {
+                    let old = PointerOffset::from_value(old.offset);
+                    match self.history_mirror() {
+                        // a snapshot may still copy the sample
+                        Some(mirror) if mirror.has_readers() => unsafe {
+                            (*self.deferred_history_releases.get()).push(old)
+                        },
+                        _ => self.release_sample(old),
+                    }
+                }
+            }
+        }
+    }
+
+    fn release_deferred_history_samples(&self) {
+        let deferred = unsafe { &mut *self.deferred_history_releases.get() };
+        if deferred.is_empty() || self.history_mirror().is_some_and(|m| m.has_readers()) {
+            return;
+        }
+
+        for offset in deferred.drain(..) {
+            self.release_sample(offset);
+        }
+    }
+
+    fn history_mirror(
+        &self,
+    ) -> Option<crate::service::dynamic_config::publish_subscribe::PublisherHistory<'_>> {
+        let index = unsafe { *self.publisher_index.get() }?;
+        self.service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .publisher_history(index)
+    }
+
+    /// Returns the connection to the only connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber). When exactly one
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) is connected, the sample delivery
+    /// uses this connection directly and does not iterate over all connection slots of the
+    /// [`Service`](crate::service::Service).
+    fn single_subscriber_connection(&self) -> Option<&Connection<Service>> {
+        let index = unsafe { *self.single_subscriber_connection_index.get() }?;
+        self.subscriber_connections.get(index).as_ref()
+    }
+
+    fn update_single_subscriber_connection_index(&self) {
+        let mut connected = (0..self.subscriber_connections.len())
+            .filter(|i| self.subscriber_connections.get(*i).is_some());
+        let index = match (connected.next(), connected.next()) {
+            (Some(index), None) => Some(index),
+            _ => None,
+        };
+
+        unsafe { *self.single_subscriber_connection_index.get() = index };
+    }
+
+    fn deliver_sample_to<
+        F: Fn(
+            &<Service::Connection as ZeroCopyConnection>::Sender,
+            PointerOffset,
+            usize,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError>,
+    >(
+        &self,
+        connection: &Connection<Service>,
+        deliver_call: F,
+        offset: PointerOffset,
+        sample_size: usize,
+    ) -> Result<bool, PublisherSendError> {
+        match deliver_call(&connection.sender, offset, sample_size) {
+            Err(ZeroCopySendError::ReceiveBufferFull)
+            | Err(ZeroCopySendError::UsedChunkListFull) => {
+                /* causes no problem
+                 *   blocking_send => can never happen
+                 *   try_send => we tried and expect that the buffer is full
+                 * */
+                Ok(false)
+            }
+            Err(ZeroCopySendError::ConnectionCorrupted) => {
+                match &self.config.degration_callback {
+                    Some(c) => match c.call(
+                        self.static_config.clone(),
+                        self.port_id,
+                        connection.subscriber_id,
+                    ) {
+                        DegrationAction::Ignore => (),
+                        DegrationAction::Warn => {
+                            error!(from self,
+                                "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
+                                offset, connection.subscriber_id);
+                        }
+                        DegrationAction::Fail => {
+                            fail!(from self, with PublisherSendError::ConnectionCorrupted(connection.subscriber_id),
+                                "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
+                                offset, connection.subscriber_id);
+                        }
+                    },
+                    None => {
+                        error!(from self,
+                            "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
+                            offset, connection.subscriber_id);
+                    }
+                }
+                Ok(false)
+            }
+            Ok(overflow) => {
+                self.borrow_sample(offset);
+                self.signal_sample_delivery(connection);
+
+                if let Some(old) = overflow {
+                    self.release_sample(old);
+                    self.service_state
+                        .dynamic_storage
+                        .get()
+                        .publish_subscribe()
+                        .increment_number_of_overflows();
                 }
+                Ok(true)
             }
         }
     }
@@ -408,6 +753,14 @@ impl<Service: service::Service> PublisherBackend<Service> {
         sample_size: usize,
     ) -> Result<usize, PublisherSendError> {
         self.retrieve_returned_samples();
+        if let Some(eviction) = self.slow_subscriber_eviction() {
+            let number_of_recipients = self.deliver_samples_within_deadline(
+                eviction,
+                core::iter::once((offset, sample_size)),
+            )?;
+            return Ok(number_of_recipients);
+        }
+
         let deliver_call = match self.config.unable_to_deliver_strategy {
             UnableToDeliverStrategy::Block => {
                 <Service::Connection as ZeroCopyConnection>::Sender::blocking_send
@@ -418,57 +771,195 @@ impl<Service: service::Service> PublisherBackend<Service> {
         };
 
         let mut number_of_recipients = 0;
+        match self.single_subscriber_connection() {
+            Some(connection) => {
+                if self.deliver_sample_to(connection, deliver_call, offset, sample_size)? {
+                    number_of_recipients += 1;
+                }
+            }
+            None => {
+                for i in 0..self.subscriber_connections.len() {
+                    if let Some(ref connection) = self.subscriber_connections.get(i) {
+                        if self.deliver_sample_to(connection, deliver_call, offset, sample_size)? {
+                            number_of_recipients += 1;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(number_of_recipients)
+    }
+
+    /// Notifies the sample signal of the [`Subscriber`](crate::port::subscriber::Subscriber)
+    /// of the connection when it waits, for instance in
+    /// [`Subscriber::receive_timeout()`](crate::port::subscriber::Subscriber::receive_timeout()).
+    /// Must be called after the sample was delivered.
+    fn signal_sample_delivery(&self, connection: &Connection<Service>) {
+        if self
+            .service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .take_sample_signal_request(connection.subscriber_index)
+        {
+            connection.notify_sample_signal(
+                self.service_state.shared_node.config(),
+                self.service_state.directory.as_ref(),
+            );
+        }
+    }
+
+    /// Returns the [`SlowSubscriberEviction`] policy of the
+    /// [`Service`](crate::service::Service) when it applies to this publisher. It applies only
+    /// to the [`UnableToDeliverStrategy::Block`].
+    fn slow_subscriber_eviction(&self) -> Option<SlowSubscriberEviction> {
+        match self.config.unable_to_deliver_strategy {
+            UnableToDeliverStrategy::Block => {
+                self.subscriber_connections
+                    .static_config
+                    .slow_subscriber_eviction
+            }
+            UnableToDeliverStrategy::DiscardSample => None,
+        }
+    }
+
+    /// Waits at most `deadline` until the connection has free buffer space. Returns false when
+    /// the deadline was missed.
+    fn wait_for_buffer_space(&self, connection: &Connection<Service>, deadline: Duration) -> bool {
+        if !connection.sender.would_block() {
+            return true;
+        }
+
+        let mut adaptive_wait = match AdaptiveWaitBuilder::new().create() {
+            Ok(adaptive_wait) => adaptive_wait,
+            Err(e) => {
+                warn!(from self, "Unable to wait for free buffer space of subscriber {:?} since the adaptive wait could not be created ({:?}).",
+                    connection.subscriber_id, e);
+                return false;
+            }
+        };
+
+        match adaptive_wait.timed_wait_while(
+            || -> Result<bool, ()> { Ok(connection.sender.would_block()) },
+            deadline,
+        ) {
+            Ok(has_buffer_space) => has_buffer_space,
+            Err(e) => {
+                warn!(from self, "Unable to wait for free buffer space of subscriber {:?} since the adaptive wait failed ({:?}).",
+                    connection.subscriber_id, e);
+                false
+            }
+        }
+    }
+
+    /// Delivers the samples with a single pass over the connections to all subscribers that
+    /// free buffer space before the deadline has passed. The deadline starts with the call and
+    /// bounds the wait for all subscribers and samples together. A subscriber that misses the
+    /// deadline receives only the samples that were delivered before and the send counts as
+    /// one missed deadline. Returns the number of subscribers that received all samples.
+    fn deliver_samples_within_deadline<I: Iterator<Item = (PointerOffset, usize)> + Clone>(
+        &self,
+        eviction: SlowSubscriberEviction,
+        samples: I,
+    ) -> Result<usize, PublisherSendError> {
+        let deliver_call = <Service::Connection as ZeroCopyConnection>::Sender::try_send;
+        let send_start = Time::now().ok();
+        let mut number_of_recipients = 0;
+        let mut evicted_connections = vec![];
+        let remaining_time = || match &send_start {
+            Some(start) => start.elapsed().map_or(Duration::ZERO, |elapsed| {
+                eviction.deadline.saturating_sub(elapsed)
+            }),
+            // without a start time, every subscriber gets the whole deadline
+            None => eviction.deadline,
+        };
+
         for i in 0..self.subscriber_connections.len() {
             if let Some(ref connection) = self.subscriber_connections.get(i) {
-                match deliver_call(&connection.sender, offset, sample_size) {
-                    Err(ZeroCopySendError::ReceiveBufferFull)
-                    | Err(ZeroCopySendError::UsedChunkListFull) => {
-                        /* causes no problem
-                         *   blocking_send => can never happen
-                         *   try_send => we tried and expect that the buffer is full
-                         * */
-                    }
-                    Err(ZeroCopySendError::ConnectionCorrupted) => {
-                        match &self.config.degration_callback {
-                            Some(c) => match c.call(
-                                self.static_config.clone(),
-                                self.port_id,
-                                connection.subscriber_id,
-                            ) {
-                                DegrationAction::Ignore => (),
-                                DegrationAction::Warn => {
-                                    error!(from self,
-                                        "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                        offset, connection.subscriber_id);
-                                }
-                                DegrationAction::Fail => {
-                                    fail!(from self, with PublisherSendError::ConnectionCorrupted,
-                                        "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                        offset, connection.subscriber_id);
-                                }
-                            },
-                            None => {
-                                error!(from self,
-                                    "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                    offset, connection.subscriber_id);
-                            }
-                        }
+                let mut received_all = true;
+                let mut missed_deadline = false;
+                for (offset, sample_size) in samples.clone() {
+                    if !self.wait_for_buffer_space(connection, remaining_time()) {
+                        missed_deadline = true;
+                        break;
                     }
-                    Ok(overflow) => {
-                        self.borrow_sample(offset);
-                        number_of_recipients += 1;
 
-                        if let Some(old) = overflow {
-                            self.release_sample(old)
-                        }
+                    received_all &=
+                        self.deliver_sample_to(connection, deliver_call, offset, sample_size)?;
+                }
+
+                if missed_deadline {
+                    let missed_deadlines = connection.missed_deadlines.get() + 1;
+                    connection.missed_deadlines.set(missed_deadlines);
+                    if eviction.consecutive_misses <= missed_deadlines {
+                        evicted_connections.push(i);
                     }
+                    continue;
+                }
+
+                connection.missed_deadlines.set(0);
+                if received_all {
+                    number_of_recipients += 1;
                 }
             }
         }
+
+        for i in evicted_connections {
+            self.evict_subscriber(i, eviction);
+        }
+
         Ok(number_of_recipients)
     }
 
-    fn populate_subscriber_channels(&self) -> Result<(), ZeroCopyCreationError> {
+    /// Removes the subscriber of the connection from the [`Service`](crate::service::Service)
+    /// and stops delivering samples to it. The samples that were delivered to it are reclaimed
+    /// when the subscriber returns them or when it is gone.
+    fn evict_subscriber(&self, index: usize, eviction: SlowSubscriberEviction) {
+        let subscriber_id = match self.subscriber_connections.get(index) {
+            Some(connection) => connection.subscriber_id,
+            None => return,
+        };
+
+        let mut subscriber_handle = None;
+        unsafe {
+            (*self.subscriber_list_state.get()).for_each(|handle, details| {
+                if handle.index() as usize == index && details.subscriber_id == subscriber_id {
+                    subscriber_handle = Some(handle);
+                    return CallbackProgression::Stop;
+                }
+                CallbackProgression::Continue
+            })
+        };
+
+        warn!(from self,
+            "Disconnecting subscriber {:?} since it did not free buffer space within {:?} on {} consecutive sends.",
+            subscriber_id, eviction.deadline, eviction.consecutive_misses);
+
+        if let Some(handle) = subscriber_handle {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .release_subscriber_handle(handle);
+        }
+
+        if let Some(connection) = self.subscriber_connections.take(index) {
+            unsafe { (*self.evicted_connections.get()).push(connection) };
+        }
+        self.update_single_subscriber_connection_index();
+    }
+
+    fn populate_subscriber_channels(
+        &self,
+        deliver_history: bool,
+    ) -> Result<(), ZeroCopyCreationError> {
+        unsafe { *self.single_subscriber_connection_index.get() = None };
+        let result = self.connect_to_subscribers(deliver_history);
+        self.update_single_subscriber_connection_index();
+        result
+    }
+
+    fn connect_to_subscribers(&self, deliver_history: bool) -> Result<(), ZeroCopyCreationError> {
         let mut visited_indices = vec![];
         visited_indices.resize(self.subscriber_connections.capacity(), None);
 
@@ -497,7 +988,11 @@ impl<Service: service::Service> PublisherBackend<Service> {
                     if create_connection {
                         match self.subscriber_connections.create(i, *subscriber_details) {
                             Ok(()) => match &self.subscriber_connections.get(i) {
-                                Some(connection) => self.deliver_sample_history(connection),
+                                Some(connection) => {
+                                    if deliver_history {
+                                        self.deliver_sample_history(connection)
+                                    }
+                                }
                                 None => {
                                     fatal_panic!(from self, "This should never happen! Unable to acquire previously created subscriber connection.")
                                 }
@@ -537,6 +1032,16 @@ impl<Service: service::Service> PublisherBackend<Service> {
     }
 
     fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.update_connections_with_history(true)
+    }
+
+    /// Connects new [`Subscriber`](crate::port::subscriber::Subscriber)s and removes the
+    /// connections of gone ones. When `deliver_history` is false, the new
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s do not receive the history.
+    fn update_connections_with_history(
+        &self,
+        deliver_history: bool,
+    ) -> Result<(), ConnectionFailure> {
         if unsafe {
             self.service_state
                 .dynamic_storage
@@ -545,7 +1050,7 @@ impl<Service: service::Service> PublisherBackend<Service> {
                 .subscribers
                 .update_state(&mut *self.subscriber_list_state.get())
         } {
-            fail!(from self, when self.populate_subscriber_channels(),
+            fail!(from self, when self.populate_subscriber_channels(deliver_history),
                 "Connections were updated only partially since at least one connection to a Subscriber port failed.");
         }
 
@@ -558,16 +1063,32 @@ impl<Service: service::Service> PublisherBackend<Service> {
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
                 let buffer_size = connection.sender.buffer_size();
-                let history_start = history.len().saturating_sub(buffer_size);
+                let history_start = history
+                    .len()
+                    .saturating_sub(buffer_size.min(connection.history_size));
+
+                // samples sent after the history have a larger sequence number, so that the
+                // subscriber can tell them apart from the replayed ones
+                if !history.is_empty() {
+                    let last = unsafe { history.get_unchecked(history.len() - 1) };
+                    connection.sender.mark_history_end(last.sequence_number);
+                }
 
+                let mut is_delivered = false;
                 for i in history_start..history.len() {
                     let old_sample = unsafe { history.get_unchecked(i) };
+                    if let Some(max_age) = connection.history_max_age {
+                        if max_age < sample_age(&old_sample.send_time) {
+                            continue;
+                        }
+                    }
                     self.retrieve_returned_samples();
 
                     let offset = PointerOffset::from_value(old_sample.offset);
                     match connection.sender.try_send(offset, old_sample.size) {
                         Ok(overflow) => {
                             self.borrow_sample(offset);
+                            is_delivered = true;
 
                             if let Some(old) = overflow {
                                 self.release_sample(old);
@@ -578,15 +1099,55 @@ impl<Service: service::Service> PublisherBackend<Service> {
                         }
                     }
                 }
+                if is_delivered {
+                    self.signal_sample_delivery(connection);
+                }
             }
         }
     }
 
+    /// Returns how many bytes of a sample payload with the given number of elements can be
+    /// marked as used at most.
+    pub(crate) fn max_used_len(&self, number_of_elements: usize) -> usize {
+        let static_config = &self.subscriber_connections.static_config;
+        let payload_size = static_config.message_type_details.payload.size * number_of_elements;
+        static_config
+            .max_message_size
+            .map_or(payload_size, |max| max.min(payload_size))
+    }
+
+    pub(crate) fn has_send_timestamp(&self) -> bool {
+        self.subscriber_connections
+            .static_config
+            .has_send_timestamp()
+    }
+
+    /// The send timestamp is recorded on request and whenever the
+    /// [`crate::port::subscriber::Subscriber`]s require it to measure the coalescing window.
+    pub(crate) fn records_send_timestamp(&self) -> bool {
+        self.config.enable_send_timestamp
+            || self
+                .subscriber_connections
+                .static_config
+                .coalescing_window()
+                .is_some()
+    }
+
+    /// Returns the sequence number of the next sample. The first sample has the sequence number
+    /// `1` so that `0` can represent that nothing was acknowledged so far.
+    pub(crate) fn next_sequence_number(&self) -> u64 {
+        self.sequence_counter
+            .fetch_add(1, Ordering::Relaxed)
+            .wrapping_add(1)
+    }
+
     pub(crate) fn send_sample(
         &self,
         offset: PointerOffset,
         sample_size: usize,
+        send_time: Time,
     ) -> Result<usize, PublisherSendError> {
+        enter_span!(TRACE, "send", service = %self.static_config.name(), port_id = self.port_id.value());
         let msg = "Unable to send sample";
         if !self.is_active.load(Ordering::Relaxed) {
             fail!(from self, with PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists,
@@ -596,51 +1157,205 @@ impl<Service: service::Service> PublisherBackend<Service> {
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
-        self.add_sample_to_history(offset, sample_size);
+        self.add_sample_to_history(offset, sample_size, send_time);
         self.deliver_sample(offset, sample_size)
     }
-}
-
-/// Sending endpoint of a publish-subscriber based communication.
-#[derive(Debug)]
-pub struct Publisher<
-    Service: service::Service,
-    Payload: Debug + ?Sized + 'static,
-    UserHeader: Debug,
-> {
-    pub(crate) backend: Arc<PublisherBackend<Service>>,
-    dynamic_publisher_handle: Option<ContainerHandle>,
-    payload_size: usize,
-    _payload: PhantomData<Payload>,
-    _user_header: PhantomData<UserHeader>,
-}
 
-impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Drop
-    for Publisher<Service, Payload, UserHeader>
-{
-    fn drop(&mut self) {
-        if let Some(handle) = self.dynamic_publisher_handle {
-            self.backend
-                .service_state
-                .dynamic_storage
-                .get()
-                .publish_subscribe()
-                .release_publisher_handle(handle)
+    /// Replaces the retained value of the last value mode. The
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s that joined since the last update
+    /// are connected without the history, so that they receive the new value and never the
+    /// replaced one.
+    pub(crate) fn update_sample(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        send_time: Time,
+    ) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to update the value";
+        if !self.is_active.load(Ordering::Relaxed) {
+            fail!(from self, with PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists,
+                "{} since the connections could not be updated.", msg);
         }
-    }
-}
 
-impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
-    Publisher<Service, Payload, UserHeader>
+        fail!(from self, when self.update_connections_with_history(false),
+            "{} since the connections could not be updated.", msg);
+
+        self.add_sample_to_history(offset, sample_size, send_time);
+        self.deliver_sample(offset, sample_size)
+    }
+
+    /// Delivers all samples with a single pass over the connections. Every
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) receives the samples in the order of
+    /// the batch. Returns the number of [`Subscriber`](crate::port::subscriber::Subscriber)s
+    /// that received all samples.
+    pub(crate) fn send_samples(
+        &self,
+        samples: &[(PointerOffset, usize, Time)],
+    ) -> Result<usize, PublisherSendError> {
+        enter_span!(TRACE, "send_batch", service = %self.static_config.name(), port_id = self.port_id.value());
+        let msg = "Unable to send batch of samples";
+        if !self.is_active.load(Ordering::Relaxed) {
+            fail!(from self, with PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists,
+                "{} since the connections could not be updated.", msg);
+        }
+
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        for (offset, sample_size, send_time) in samples {
+            self.add_sample_to_history(*offset, *sample_size, *send_time);
+        }
+
+        self.retrieve_returned_samples();
+        // the deadline of the slow subscriber eviction bounds the whole batch
+        if let Some(eviction) = self.slow_subscriber_eviction() {
+            return self.deliver_samples_within_deadline(
+                eviction,
+                samples
+                    .iter()
+                    .map(|(offset, sample_size, _)| (*offset, *sample_size)),
+            );
+        }
+
+        let deliver_call = match self.config.unable_to_deliver_strategy {
+            UnableToDeliverStrategy::Block => {
+                <Service::Connection as ZeroCopyConnection>::Sender::blocking_send
+            }
+            UnableToDeliverStrategy::DiscardSample => {
+                <Service::Connection as ZeroCopyConnection>::Sender::try_send
+            }
+        };
+
+        let deliver_batch_to =
+            |connection: &Connection<Service>| -> Result<bool, PublisherSendError> {
+                let mut received_all = true;
+                for (offset, sample_size, _) in samples {
+                    received_all &=
+                        self.deliver_sample_to(connection, deliver_call, *offset, *sample_size)?;
+                }
+                Ok(received_all)
+            };
+
+        let mut number_of_recipients = 0;
+        match self.single_subscriber_connection() {
+            Some(connection) => {
+                if deliver_batch_to(connection)? {
+                    number_of_recipients += 1;
+                }
+            }
+            None => {
+                for i in 0..self.subscriber_connections.len() {
+                    if let Some(ref connection) = self.subscriber_connections.get(i) {
+                        if deliver_batch_to(connection)? {
+                            number_of_recipients += 1;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(number_of_recipients)
+    }
+
+    /// Delivers the sample only when no connected [`Subscriber`](crate::port::subscriber::Subscriber)
+    /// has a full buffer, otherwise it returns [`SendStatus::Pending`] without delivering it
+    /// anywhere.
+    pub(crate) fn poll_send_sample(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        send_time: Time,
+    ) -> Result<SendStatus<()>, PublisherSendError> {
+        enter_span!(TRACE, "poll_send", service = %self.static_config.name(), port_id = self.port_id.value());
+        let msg = "Unable to poll send sample";
+        if !self.is_active.load(Ordering::Relaxed) {
+            fail!(from self, with PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists,
+                "{} since the connections could not be updated.", msg);
+        }
+
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        self.retrieve_returned_samples();
+        let mut blocked_subscribers = 0;
+        for i in 0..self.subscriber_connections.len() {
+            if let Some(ref connection) = self.subscriber_connections.get(i) {
+                if connection.sender.would_block() {
+                    blocked_subscribers += 1;
+                }
+            }
+        }
+
+        if blocked_subscribers != 0 {
+            return Ok(SendStatus::Pending {
+                sample: (),
+                blocked_subscribers,
+            });
+        }
+
+        self.add_sample_to_history(offset, sample_size, send_time);
+        Ok(SendStatus::Delivered(
+            self.deliver_sample(offset, sample_size)?,
+        ))
+    }
+}
+
+/// Sending endpoint of a publish-subscriber based communication.
+///
+/// Dropping the [`Publisher`] does not wait until the connected
+/// [`Subscriber`](crate::port::subscriber::Subscriber)s received the samples that were sent.
+/// A [`Subscriber`](crate::port::subscriber::Subscriber) that did not receive them before it
+/// detects that the [`Publisher`] is gone, discards them. Use [`Publisher::flush()`] before
+/// dropping the [`Publisher`] when the delivery must be ensured.
+#[derive(Debug)]
+pub struct Publisher<
+    Service: service::Service,
+    Payload: Debug + ?Sized + 'static,
+    UserHeader: Debug,
+> {
+    pub(crate) backend: Arc<PublisherBackend<Service>>,
+    dynamic_publisher_handle: Option<ContainerHandle>,
+    payload_size: usize,
+    _payload: PhantomData<Payload>,
+    _user_header: PhantomData<UserHeader>,
+}
+
+impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Drop
+    for Publisher<Service, Payload, UserHeader>
+{
+    fn drop(&mut self) {
+        if let Some(mirror) = self.backend.history_mirror() {
+            mirror.clear();
+        }
+        unsafe { *self.backend.publisher_index.get() = None };
+
+        if let Some(handle) = self.dynamic_publisher_handle {
+            self.backend
+                .service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .release_publisher_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
+    Publisher<Service, Payload, UserHeader>
 {
     pub(crate) fn new(
+        port_id: UniquePublisherId,
         service: &Service,
         static_config: &publish_subscribe::StaticConfig,
         config: LocalPublisherConfig,
     ) -> Result<Self, PublisherCreateError> {
         let msg = "Unable to create Publisher port";
         let origin = "Publisher::new()";
-        let port_id = UniquePublisherId::new();
+
+        if static_config.max_publishers == 0 {
+            fail!(from origin, with PublisherCreateError::ExceedsMaxSupportedPublishers,
+                "{} since the service is subscriber-only and supports no publishers.", msg);
+        }
+
         let subscriber_list = &service
             .__internal_state()
             .dynamic_storage
@@ -657,9 +1372,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         let data_segment_type =
             DataSegmentType::new_from_allocation_strategy(config.allocation_strategy);
 
-        let sample_layout = static_config
-            .message_type_details
-            .sample_layout(config.initial_max_slice_len);
+        let sample_layout = static_config.sample_layout(config.initial_max_slice_len);
 
         let max_slice_len = config.initial_max_slice_len;
         let max_number_of_segments =
@@ -670,12 +1383,12 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             number_of_samples,
             max_slice_len,
             node_id: *service.__internal_state().shared_node.id(),
+            owner_start_time: Process::from_self().start_time().ok(),
+            node_name: config.node_name,
             max_number_of_segments,
         };
-        let global_config = service.__internal_state().shared_node.config();
-
         let data_segment = fail!(from origin,
-                when DataSegment::create(&publisher_details, global_config, sample_layout, config.allocation_strategy),
+                when DataSegment::create(&data_segment_name(&port_id), data_segment_type, number_of_samples, service.__internal_state(), sample_layout, config.allocation_strategy, static_config.pool_allocation_strategy()),
                 with PublisherCreateError::UnableToCreateDataSegment,
                 "{} since the data segment could not be acquired.", msg);
 
@@ -691,9 +1404,10 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             },
             service_state: service.__internal_state().clone(),
             port_id,
+            evicted_connections: UnsafeCell::new(Vec::new()),
             subscriber_connections: SubscriberConnections::new(
                 subscriber_list.capacity(),
-                service.__internal_state().shared_node.clone(),
+                service.__internal_state().clone(),
                 port_id,
                 static_config,
                 number_of_samples,
@@ -701,12 +1415,18 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             ),
             config,
             subscriber_list_state: unsafe { UnsafeCell::new(subscriber_list.get_state()) },
+            single_subscriber_connection_index: UnsafeCell::new(None),
             history: match static_config.history_size == 0 {
                 true => None,
                 false => Some(UnsafeCell::new(Queue::new(static_config.history_size))),
             },
+            publisher_index: UnsafeCell::new(None),
+            deferred_history_releases: UnsafeCell::new(Vec::new()),
             static_config: service.__internal_state().static_config.clone(),
-            loan_counter: IoxAtomicUsize::new(0),
+            loan_counter: LocalCounterUsize::new(0),
+            active_sample_counter: Cell::new(0),
+            current_segment_id: Cell::new(0),
+            sequence_counter: LocalCounterU64::new(0),
         });
 
         let payload_size = backend
@@ -724,7 +1444,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             _user_header: PhantomData,
         };
 
-        if let Err(e) = new_self.backend.populate_subscriber_channels() {
+        if let Err(e) = new_self.backend.populate_subscriber_channels(true) {
             warn!(from new_self, "The new Publisher port is unable to connect to every Subscriber port, caused by {:?}.", e);
         }
 
@@ -748,11 +1468,19 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         };
 
         new_self.dynamic_publisher_handle = Some(dynamic_publisher_handle);
+        unsafe { *new_self.backend.publisher_index.get() = Some(dynamic_publisher_handle.index()) };
+        new_self.backend.update_data_segment_size();
+        if let Some(mirror) = new_self.backend.history_mirror() {
+            mirror.reset(port_id);
+        }
 
         Ok(new_self)
     }
 
-    /// Returns the [`UniquePublisherId`] of the [`Publisher`]
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`]. It is acquired on creation and
+    /// stays the same for the whole lifetime of the [`Publisher`], so that it identifies the
+    /// entry of the [`Publisher`] in the dynamic config of the
+    /// [`Service`](crate::service::Service).
     pub fn id(&self) -> UniquePublisherId {
         self.backend.port_id
     }
@@ -763,12 +1491,372 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         self.backend.config.unable_to_deliver_strategy
     }
 
+    /// Returns the [`LoanInitPolicy`] the [`Publisher`] uses to initialize the payload of a
+    /// loaned sample.
+    pub fn loan_init_policy(&self) -> LoanInitPolicy {
+        self.backend.config.loan_init_policy
+    }
+
+    fn zero_uninit_loans(&self) -> bool {
+        self.backend.config.loan_init_policy != LoanInitPolicy::Uninit
+    }
+
+    fn zero_default_loans(&self) -> bool {
+        self.backend.config.loan_init_policy == LoanInitPolicy::Zeroed
+    }
+
+    /// Returns all [`Subscriber`](crate::port::subscriber::Subscriber)s the [`Publisher`] is
+    /// connected to together with the number of free slots in their receive buffers. New
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s are connected when a [`SampleMut`] is
+    /// sent or when [`UpdateConnections::update_connections()`] is called.
+    ///
+    /// The buffer state is acquired lock-free and the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s receive concurrently, therefore the
+    /// number of free slots is a snapshot that may already be out-of-date when it is returned.
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s that disconnected but whose
+    /// connection was not yet removed by the [`Publisher`] are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    /// let slowest_subscriber_headroom = publisher
+    ///     .connected_subscribers()
+    ///     .iter()
+    ///     .map(|subscriber| subscriber.free_buffer_slots())
+    ///     .min();
+    ///
+    /// if slowest_subscriber_headroom == Some(0) {
+    ///     // slow down
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn connected_subscribers(&self) -> Vec<ConnectedSubscriber> {
+        let connections = &self.backend.subscriber_connections;
+        let mut registered_subscribers = Vec::with_capacity(connections.len());
+        self.backend
+            .service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .__internal_list_subscribers(|details| {
+                registered_subscribers.push(details.subscriber_id)
+            });
+
+        let mut subscribers = Vec::with_capacity(connections.len());
+        for i in 0..connections.len() {
+            if let Some(ref connection) = connections.get(i) {
+                if !registered_subscribers.contains(&connection.subscriber_id) {
+                    continue;
+                }
+
+                let buffer_size = connection.sender.buffer_size();
+                subscribers.push(ConnectedSubscriber {
+                    id: connection.subscriber_id,
+                    buffer_size,
+                    free_buffer_slots: buffer_size
+                        .saturating_sub(connection.sender.number_of_queued_samples()),
+                });
+            }
+        }
+
+        subscribers
+    }
+
+    /// Returns the sequence number of the latest [`SampleMut`] that was consumed by at least
+    /// one connected [`Subscriber`](crate::port::subscriber::Subscriber), see
+    /// [`Header::sequence_number()`]. A [`Sample`](crate::sample::Sample) counts as consumed
+    /// as soon as the [`Subscriber`](crate::port::subscriber::Subscriber) drops it.
+    ///
+    /// It returns [`None`] when no [`SampleMut`] was consumed so far or when the
+    /// [`Service`](crate::service::Service) was not created with
+    /// [`enable_acknowledgment()`](crate::service::builder::publish_subscribe::Builder::enable_acknowledgment()).
+    /// Acknowledgments are opt-in since they add an atomic write to the release of every
+    /// [`Sample`](crate::sample::Sample).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .enable_acknowledgment(true)
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().create()?;
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// publisher.send_copy(1234)?;
+    /// drop(subscriber.receive()?);
+    ///
+    /// println!("last acknowledged sample: {:?}", publisher.last_acked_sequence());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn last_acked_sequence(&self) -> Option<u64> {
+        if !self
+            .backend
+            .static_config
+            .publish_subscribe()
+            .has_acknowledgment()
+        {
+            return None;
+        }
+
+        let connections = &self.backend.subscriber_connections;
+        let mut last_acked_sequence = 0;
+        for i in 0..connections.len() {
+            if let Some(ref connection) = connections.get(i) {
+                last_acked_sequence =
+                    last_acked_sequence.max(connection.sender.last_acknowledgment());
+            }
+        }
+
+        match last_acked_sequence {
+            0 => None,
+            v => Some(v),
+        }
+    }
+
+    /// Blocks until every connected [`Subscriber`](crate::port::subscriber::Subscriber) has
+    /// received all samples that are stored in its receive buffer or until the `timeout` has
+    /// passed. A [`Subscriber`](crate::port::subscriber::Subscriber) that disconnects while
+    /// waiting is no longer considered. It does not wait until the received samples are
+    /// released.
+    ///
+    /// Returns [`PublisherFlushError::Timeout`] when at least one
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) still has samples in its receive
+    /// buffer after the `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder().create()?;
+    /// publisher.send_copy(1234)?;
+    ///
+    /// // ensure that the command was received before the process exits
+    /// if publisher.flush(Duration::from_millis(100)).is_err() {
+    ///     println!("the command was not received by all subscribers");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn flush(&self, timeout: Duration) -> Result<(), PublisherFlushError> {
+        let msg = "Unable to flush the publisher";
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+            with PublisherFlushError::InternalFailure,
+            "{} since the adaptive wait could not be created.", msg);
+
+        let has_queued_samples = || -> Result<bool, ConnectionFailure> {
+            self.backend.update_connections()?;
+
+            let connections = &self.backend.subscriber_connections;
+            for i in 0..connections.len() {
+                if let Some(ref connection) = connections.get(i) {
+                    if connection.sender.number_of_queued_samples() != 0 {
+                        return Ok(true);
+                    }
+                }
+            }
+
+            Ok(false)
+        };
+
+        match adaptive_wait.timed_wait_while(has_queued_samples, timeout) {
+            Ok(true) => Ok(()),
+            Ok(false) => {
+                fail!(from self, with PublisherFlushError::Timeout,
+                    "{} since not all samples were received within the timeout of {:?}.", msg, timeout);
+            }
+            Err(AdaptiveTimedWaitWhileError::PredicateFailure(e)) => {
+                fail!(from self, with PublisherFlushError::ConnectionError(e),
+                    "{} since the connections to the subscribers could not be updated ({:?}).", msg, e);
+            }
+            Err(AdaptiveTimedWaitWhileError::AdaptiveWaitError(e)) => {
+                fail!(from self, with PublisherFlushError::InternalFailure,
+                    "{} since the adaptive wait failed ({:?}).", msg, e);
+            }
+        }
+    }
+
+    /// Returns true when exactly one [`Subscriber`](crate::port::subscriber::Subscriber) was
+    /// connected at the last connection update. In this case, the [`Publisher`] delivers and
+    /// reclaims samples via this single connection directly instead of iterating over all
+    /// connection slots of the [`Service`](crate::service::Service). The connections are
+    /// updated on every send and with
+    /// [`UpdateConnections::update_connections()`], therefore the [`Publisher`] falls back to
+    /// the regular delivery as soon as a second
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) is connected.
+    pub fn fast_path_active(&self) -> bool {
+        self.backend.single_subscriber_connection().is_some()
+    }
+
+    /// Establishes a release fence. All writes that were performed before the fence are
+    /// visible to every process that observes a write performed after the fence and that
+    /// synchronizes with an acquire operation.
+    ///
+    /// Sending a [`SampleMut`] already has release semantics, therefore the fence is not
+    /// required to publish data that is referenced by the sample. It is required when the
+    /// availability of data is signaled with relaxed writes outside of iceoryx2, see
+    /// [Memory Ordering](crate::port::publisher#memory-ordering).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    /// // write data into a shared region that is not managed by iceoryx2
+    /// // ...
+    /// publisher.publish_fence();
+    /// // signal the availability of the data with a relaxed write
+    /// // ...
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn publish_fence(&self) {
+        core::sync::atomic::fence(Ordering::Release);
+    }
+
     /// Returns the maximum slice length configured for this [`Publisher`].
     pub fn initial_max_slice_len(&self) -> usize {
         self.backend.config.initial_max_slice_len
     }
 
-    fn allocate(&self, layout: Layout) -> Result<AllocationPair, PublisherLoanError> {
+    /// Sends all [`crate::sample_mut::SampleMut`]s of the batch with a single pass over the
+    /// connected [`crate::port::subscriber::Subscriber`]s. On success it returns the number of
+    /// [`crate::port::subscriber::Subscriber`]s that received every sample of the batch.
+    ///
+    /// The batch is not delivered atomically. The [`crate::port::subscriber::Subscriber`]s
+    /// are served one after another and every one of them receives the samples in the order
+    /// of the batch. A [`crate::port::subscriber::Subscriber`] may therefore receive the first
+    /// samples before the last ones are delivered, and another
+    /// [`crate::port::subscriber::Subscriber`] may not have received any of them at that
+    /// time. Samples that were loaned from another [`Publisher`] are sent by it after the
+    /// batch was delivered.
+    ///
+    /// With the slow subscriber eviction of the [`crate::service::Service`], the deadline
+    /// bounds the whole batch. A [`crate::port::subscriber::Subscriber`] that misses it receives
+    /// only the samples of the batch that were delivered before and the batch counts as one
+    /// missed deadline.
+    ///
+    /// When the used length of one sample exceeds the maximum message size, no sample is sent
+    /// and [`PublisherSendError::ExceedsMaxMessageSize`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::publisher::BatchLoanPolicy;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .max_loaned_samples(8)
+    ///                          .create()?;
+    ///
+    /// let mut samples = publisher.loan_batch(8, BatchLoanPolicy::WaitForAll)?;
+    /// for (i, sample) in samples.iter_mut().enumerate() {
+    ///     *sample.payload_mut() = i as u64;
+    /// }
+    ///
+    /// publisher.send_batch(samples)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_batch(
+        &self,
+        samples: Vec<SampleMut<Service, Payload, UserHeader>>,
+    ) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to send batch of samples";
+        for sample in &samples {
+            fail!(from self, when sample.verify_used_len(msg),
+                "{} since one sample exceeds the max message size.", msg);
+        }
+
+        let (mut samples, foreign_samples): (Vec<_>, Vec<_>) = samples
+            .into_iter()
+            .partition(|sample| Arc::ptr_eq(&sample.publisher_backend, &self.backend));
+
+        let mut details = Vec::with_capacity(samples.len());
+        for sample in &mut samples {
+            let send_time = sample.set_send_metadata();
+            details.push((sample.offset_to_chunk, sample.sample_size, send_time));
+        }
+
+        let number_of_recipients = match details.is_empty() {
+            true => 0,
+            false => self.backend.send_samples(&details)?,
+        };
+        // the loans are returned when the samples are dropped, the subscribers keep their own
+        // reference to every delivered sample
+        drop(samples);
+
+        for sample in foreign_samples {
+            sample.send()?;
+        }
+
+        Ok(number_of_recipients)
+    }
+
+    fn allocate(
+        &self,
+        layout: Layout,
+        wait_for_free_sample: bool,
+    ) -> Result<AllocationPair, PublisherLoanError> {
+        let result = self.allocate_impl(layout, wait_for_free_sample);
+        if result.is_err() {
+            self.backend
+                .service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .increment_number_of_failed_loans();
+        }
+
+        result
+    }
+
+    fn allocate_impl(
+        &self,
+        layout: Layout,
+        wait_for_free_sample: bool,
+    ) -> Result<AllocationPair, PublisherLoanError> {
         let msg = "Unable to allocate Sample with";
 
         if self.backend.loan_counter.load(Ordering::Relaxed)
@@ -779,7 +1867,44 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 msg, layout, self.backend.loan_counter.load(Ordering::Relaxed), self.backend.config.max_loaned_samples);
         }
 
-        match self.backend.allocate(layout) {
+        let mut result = self.backend.allocate(layout);
+
+        // Only a static data segment can run out of samples that are returned later, a dynamic
+        // data segment reports out of memory when no further reallocation is possible.
+        if wait_for_free_sample
+            && self.backend.config.unable_to_deliver_strategy == UnableToDeliverStrategy::Block
+            && self.backend.config.allocation_strategy == AllocationStrategy::Static
+            && matches!(
+                result,
+                Err(ShmAllocationError::AllocationError(
+                    AllocationError::OutOfMemory
+                ))
+            )
+        {
+            let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                with PublisherLoanError::InternalFailure,
+                "{} {:?} since the adaptive wait for a returned sample could not be created.", msg, layout);
+
+            while matches!(
+                result,
+                Err(ShmAllocationError::AllocationError(
+                    AllocationError::OutOfMemory
+                ))
+            ) {
+                fail!(from self, when adaptive_wait.wait(),
+                    with PublisherLoanError::InternalFailure,
+                    "{} {:?} since the wait for a returned sample failed.", msg, layout);
+
+                // a removed subscriber, for instance one of a dead process that was cleaned
+                // up, never returns its samples, they are reacquired with its connection
+                fail!(from self, when self.backend.update_connections(),
+                    with PublisherLoanError::InternalFailure,
+                    "{} {:?} since the connections could not be updated while waiting for a returned sample.", msg, layout);
+                result = self.backend.allocate(layout);
+            }
+        }
+
+        match result {
             Ok(chunk) => {
                 self.backend.loan_counter.fetch_add(1, Ordering::Relaxed);
                 Ok(chunk)
@@ -803,10 +1928,17 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         self.backend
             .subscriber_connections
             .static_config
-            .message_type_details
             .sample_layout(number_of_elements)
     }
 
+    fn initial_header(&self, number_of_elements: usize) -> Header {
+        Header::new(
+            self.backend.port_id,
+            number_of_elements as _,
+            self.backend.max_used_len(number_of_elements) as _,
+        )
+    }
+
     fn user_header_ptr(&self, header: *const Header) -> *const u8 {
         self.backend
             .subscriber_connections
@@ -833,22 +1965,210 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .payload
             .variant
     }
-}
+}
+
+////////////////////////
+// BEGIN: typed API
+////////////////////////
+impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
+    Publisher<Service, Payload, UserHeader>
+{
+    /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] and delivers it.
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data, otherwise a [`PublisherSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .create()?;
+    ///
+    /// publisher.send_copy(1234)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy(&self, value: Payload) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to send copy of payload";
+        let sample = fail!(from self, when self.loan_uninit_impl(true, false),
+                                    "{} since the loan of a sample failed.", msg);
+
+        sample.write_payload(value).send()
+    }
+
+    /// Copies every value of `values` into its own [`crate::sample_mut::SampleMut`] and
+    /// delivers it, one after another. The loans follow the configured
+    /// [`UnableToDeliverStrategy`], with [`UnableToDeliverStrategy::Block`] the call waits for
+    /// returned samples. When no further sample can be loaned, since the data segment is out
+    /// of memory or the maximum number of loaned samples is reached, it stops and drops the value
+    /// that could not be sent. When the iterator is passed with [`Iterator::by_ref()`], the
+    /// remaining values stay in it.
+    ///
+    /// On success it returns the number of values that were sent. Any other failure is returned
+    /// as [`PublisherSendError`], all values before the failing one were already sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .create()?;
+    ///
+    /// let number_of_sent_values = publisher.send_all([1, 2, 3])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_all<I: IntoIterator<Item = Payload>>(
+        &self,
+        values: I,
+    ) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to send all values";
+        let mut number_of_sent_values = 0;
+
+        for value in values {
+            let sample = match self.loan_uninit_impl(true, false) {
+                Ok(sample) => sample,
+                Err(PublisherLoanError::OutOfMemory)
+                | Err(PublisherLoanError::ExceedsMaxLoanedSamples) => {
+                    debug!(from self,
+                        "{} since no further sample could be loaned, only {} values were sent.",
+                        msg, number_of_sent_values);
+                    break;
+                }
+                Err(e) => {
+                    fail!(from self, with PublisherSendError::LoanError(e),
+                        "{} since the loan of a sample failed after {} values were sent ({:?}).",
+                        msg, number_of_sent_values, e);
+                }
+            };
+
+            sample.write_payload(value).send()?;
+            number_of_sent_values += 1;
+        }
+
+        Ok(number_of_sent_values)
+    }
+
+    /// Replaces the value of a [`Service`](crate::service::Service) that was created with
+    /// [`enable_last_value()`](crate::service::builder::publish_subscribe::Builder::enable_last_value()).
+    /// The new value replaces the value in the buffer of every connected
+    /// [`crate::port::subscriber::Subscriber`] that was not yet received and is the only value
+    /// a late joining [`crate::port::subscriber::Subscriber`] receives. On success it returns
+    /// the number of [`crate::port::subscriber::Subscriber`]s that received the value.
+    ///
+    /// [`crate::port::subscriber::Subscriber`]s that joined since the last call are connected
+    /// without the history, so that they receive exactly the new value and never the replaced
+    /// one. The connected [`crate::port::subscriber::Subscriber`]s receive the value one after
+    /// another. Every one of them holds either the previous or the new value, since its buffer
+    /// slot is replaced in a single step.
+    ///
+    /// In a [`Service`](crate::service::Service) without the last value mode it is identical to
+    /// [`Publisher::send_copy()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// let service = node.service_builder(&"My/Funk/Setpoint".try_into()?)
+    ///     .publish_subscribe::<f64>()
+    ///     .enable_last_value(true)
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().create()?;
+    ///
+    /// publisher.update(21.5)?;
+    /// publisher.update(22.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, value: Payload) -> Result<usize, PublisherSendError> {
+        if !self
+            .backend
+            .subscriber_connections
+            .static_config
+            .enable_last_value
+        {
+            return self.send_copy(value);
+        }
+
+        let msg = "Unable to update the value";
+        let sample = fail!(from self, when self.loan_uninit_impl(true, false),
+                                    "{} since the loan of a sample failed.", msg);
+
+        sample.write_payload(value).update()
+    }
+
+    /// Loans/allocates a [`SampleMutUninit`] from the underlying data segment of the [`Publisher`].
+    /// The user has to initialize the payload before it can be sent. Depending on the
+    /// [`LoanInitPolicy`] the payload is filled with zeros or handed out uninitialized.
+    /// With [`LoanInitPolicy::Uninit`] the payload still contains the data of a previously
+    /// sent sample, reading it before it was written is undefined behavior.
+    ///
+    /// When every sample of the data segment is in use and the [`Publisher`] was created with
+    /// [`UnableToDeliverStrategy::Block`], the call blocks until a sample is returned or until
+    /// the [`Subscriber`](crate::port::subscriber::Subscriber) holding it was removed. Use
+    /// [`Publisher::try_loan_uninit()`] when the call shall never block.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .create()?;
+    ///
+    /// let sample = publisher.loan_uninit()?;
+    /// let sample = sample.write_payload(42); // alternatively `sample.payload_mut()` can be use to access the `MaybeUninit<Payload>`
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>, PublisherLoanError>
+    {
+        self.loan_uninit_impl(true, self.zero_uninit_loans())
+    }
 
-////////////////////////
-// BEGIN: typed API
-////////////////////////
-impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
-    Publisher<Service, Payload, UserHeader>
-{
-    /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] and delivers it.
-    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
-    /// the data, otherwise a [`PublisherSendError`] describing the failure.
+    /// Loans/allocates a [`SampleMutUninit`] from the underlying data segment of the [`Publisher`]
+    /// like [`Publisher::loan_uninit()`] but never blocks. When every sample of the data segment
+    /// is in use it returns [`PublisherLoanError::OutOfMemory`] immediately, independent of the
+    /// [`UnableToDeliverStrategy`].
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
     ///
     /// # Example
     ///
     /// ```
     /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::publisher::PublisherLoanError;
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
     /// #
@@ -859,27 +2179,41 @@ impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
     /// # let publisher = service.publisher_builder()
     ///                          .create()?;
     ///
-    /// publisher.send_copy(1234)?;
+    /// match publisher.try_loan_uninit() {
+    ///     Ok(sample) => {
+    ///         sample.write_payload(42).send()?;
+    ///     }
+    ///     Err(PublisherLoanError::OutOfMemory) => println!("all samples are in use"),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    ///
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send_copy(&self, value: Payload) -> Result<usize, PublisherSendError> {
-        let msg = "Unable to send copy of payload";
-        let sample = fail!(from self, when self.loan_uninit(),
-                                    "{} since the loan of a sample failed.", msg);
-
-        sample.write_payload(value).send()
+    pub fn try_loan_uninit(
+        &self,
+    ) -> Result<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>, PublisherLoanError>
+    {
+        self.loan_uninit_impl(false, self.zero_uninit_loans())
     }
 
-    /// Loans/allocates a [`SampleMutUninit`] from the underlying data segment of the [`Publisher`].
-    /// The user has to initialize the payload before it can be sent.
+    /// Loans/allocates `number_of_samples` [`SampleMutUninit`]s with a single call. The
+    /// [`BatchLoanPolicy`] defines what happens when fewer samples are available than
+    /// requested. With [`BatchLoanPolicy::AsManyAsAvailable`] the returned [`Vec`] contains
+    /// at most `number_of_samples` samples and the number of acquired samples is its length.
+    /// The samples can be sent with [`Publisher::send_batch()`].
     ///
-    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    /// On failure it returns [`PublisherLoanError`] describing the failure. With
+    /// [`BatchLoanPolicy::WaitForAll`] it returns [`PublisherLoanError::ExceedsMaxLoanedSamples`]
+    /// when `number_of_samples` exceeds the number of samples that can be loaned in parallel,
+    /// see
+    /// [`PortFactoryPublisher::max_loaned_samples()`](crate::service::port_factory::publisher::PortFactoryPublisher::max_loaned_samples()).
     ///
     /// # Example
     ///
     /// ```
     /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::publisher::BatchLoanPolicy;
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
     /// #
@@ -888,25 +2222,92 @@ impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
     /// #     .open_or_create()?;
     /// #
     /// # let publisher = service.publisher_builder()
+    ///                          .max_loaned_samples(16)
     ///                          .create()?;
     ///
-    /// let sample = publisher.loan_uninit()?;
-    /// let sample = sample.write_payload(42); // alternatively `sample.payload_mut()` can be use to access the `MaybeUninit<Payload>`
+    /// let samples = publisher.loan_batch_uninit(16, BatchLoanPolicy::AsManyAsAvailable)?;
+    /// println!("loaned {} samples", samples.len());
     ///
-    /// sample.send()?;
+    /// let samples = samples
+    ///     .into_iter()
+    ///     .enumerate()
+    ///     .map(|(i, sample)| sample.write_payload(i as u64))
+    ///     .collect();
+    /// publisher.send_batch(samples)?;
     ///
     /// # Ok(())
     /// # }
     /// ```
-    pub fn loan_uninit(
+    #[allow(clippy::type_complexity)]
+    pub fn loan_batch_uninit(
+        &self,
+        number_of_samples: usize,
+        policy: BatchLoanPolicy,
+    ) -> Result<Vec<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>>, PublisherLoanError>
+    {
+        self.loan_batch_impl(number_of_samples, policy, |wait_for_free_sample| {
+            self.loan_uninit_impl(wait_for_free_sample, self.zero_uninit_loans())
+        })
+    }
+
+    fn loan_batch_impl<T, F: FnMut(bool) -> Result<T, PublisherLoanError>>(
+        &self,
+        number_of_samples: usize,
+        policy: BatchLoanPolicy,
+        mut loan: F,
+    ) -> Result<Vec<T>, PublisherLoanError> {
+        let msg = "Unable to loan batch of samples";
+        let mut samples = Vec::with_capacity(number_of_samples);
+
+        match policy {
+            BatchLoanPolicy::WaitForAll => {
+                let max_loaned_samples = self.backend.config.max_loaned_samples;
+                if max_loaned_samples
+                    < self.backend.loan_counter.load(Ordering::Relaxed) + number_of_samples
+                {
+                    fail!(from self, with PublisherLoanError::ExceedsMaxLoanedSamples,
+                        "{} since {} samples are already loaned and {} further samples would exceed the maximum of parallel loans of {}.",
+                        msg, self.backend.loan_counter.load(Ordering::Relaxed), number_of_samples, max_loaned_samples);
+                }
+
+                for _ in 0..number_of_samples {
+                    samples.push(fail!(from self, when loan(true),
+                        "{} since only {} of {} samples could be loaned.",
+                        msg, samples.len(), number_of_samples));
+                }
+            }
+            BatchLoanPolicy::AsManyAsAvailable => {
+                while samples.len() < number_of_samples {
+                    match loan(false) {
+                        Ok(sample) => samples.push(sample),
+                        Err(PublisherLoanError::OutOfMemory)
+                        | Err(PublisherLoanError::ExceedsMaxLoanedSamples) => break,
+                        Err(e) => {
+                            fail!(from self, with e,
+                                "{} since the loan of a sample failed after {} samples were loaned ({:?}).",
+                                msg, samples.len(), e);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(samples)
+    }
+
+    fn loan_uninit_impl(
         &self,
+        wait_for_free_sample: bool,
+        zero_payload: bool,
     ) -> Result<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>, PublisherLoanError>
     {
-        let chunk = self.allocate(self.sample_layout(1))?;
+        let chunk = self.allocate(self.sample_layout(1), wait_for_free_sample)?;
         let header_ptr = chunk.shm_pointer.data_ptr as *mut Header;
         let user_header_ptr = self.user_header_ptr(header_ptr) as *mut UserHeader;
         let payload_ptr = self.payload_ptr(header_ptr) as *mut MaybeUninit<Payload>;
-        unsafe { header_ptr.write(Header::new(self.backend.port_id, 1)) };
+        if zero_payload {
+            unsafe { payload_ptr.write_bytes(0, 1) };
+        }
 
         let sample =
             unsafe { RawSampleMut::new_unchecked(header_ptr, user_header_ptr, payload_ptr) };
@@ -914,6 +2315,7 @@ impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
             SampleMutUninit::<Service, MaybeUninit<Payload>, UserHeader>::new(
                 &self.backend,
                 sample,
+                self.initial_header(1),
                 chunk.shm_pointer.offset,
                 chunk.sample_size,
             ),
@@ -928,6 +2330,11 @@ impl<Service: service::Service, Payload: Default + Debug + Sized, UserHeader: De
     /// and initialize it with the default value. This can be a performance hit and [`Publisher::loan_uninit`]
     /// can be used to loan a [`core::mem::MaybeUninit<Payload>`].
     ///
+    /// When every sample of the data segment is in use and the [`Publisher`] was created with
+    /// [`UnableToDeliverStrategy::Block`], the call blocks until a sample is returned or until
+    /// the [`Subscriber`](crate::port::subscriber::Subscriber) holding it was removed. Use
+    /// [`Publisher::try_loan()`] when the call shall never block.
+    ///
     /// On failure it returns [`PublisherLoanError`] describing the failure.
     ///
     /// # Example
@@ -952,7 +2359,86 @@ impl<Service: service::Service, Payload: Default + Debug + Sized, UserHeader: De
     /// # }
     /// ```
     pub fn loan(&self) -> Result<SampleMut<Service, Payload, UserHeader>, PublisherLoanError> {
-        Ok(self.loan_uninit()?.write_payload(Payload::default()))
+        Ok(self
+            .loan_uninit_impl(true, self.zero_default_loans())?
+            .write_payload(Payload::default()))
+    }
+
+    /// Loans/allocates a [`crate::sample_mut::SampleMut`] like [`Publisher::loan()`] but never
+    /// blocks. When every sample of the data segment is in use it returns
+    /// [`PublisherLoanError::OutOfMemory`] immediately, independent of the
+    /// [`UnableToDeliverStrategy`].
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.try_loan()?;
+    /// *sample.payload_mut() = 42;
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_loan(&self) -> Result<SampleMut<Service, Payload, UserHeader>, PublisherLoanError> {
+        Ok(self
+            .loan_uninit_impl(false, self.zero_default_loans())?
+            .write_payload(Payload::default()))
+    }
+
+    /// Loans/allocates `number_of_samples` [`crate::sample_mut::SampleMut`]s like
+    /// [`Publisher::loan_batch_uninit()`] and initializes them with the default value.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::publisher::BatchLoanPolicy;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .max_loaned_samples(4)
+    ///                          .create()?;
+    ///
+    /// let mut samples = publisher.loan_batch(4, BatchLoanPolicy::WaitForAll)?;
+    /// for sample in samples.iter_mut() {
+    ///     *sample.payload_mut() = 42;
+    /// }
+    ///
+    /// publisher.send_batch(samples)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_batch(
+        &self,
+        number_of_samples: usize,
+        policy: BatchLoanPolicy,
+    ) -> Result<Vec<SampleMut<Service, Payload, UserHeader>>, PublisherLoanError> {
+        self.loan_batch_impl(number_of_samples, policy, |wait_for_free_sample| {
+            Ok(self
+                .loan_uninit_impl(wait_for_free_sample, self.zero_default_loans())?
+                .write_payload(Payload::default()))
+        })
     }
 }
 ////////////////////////
@@ -970,6 +2456,11 @@ impl<Service: service::Service, Payload: Default + Debug, UserHeader: Debug>
     /// and [`Publisher::loan_slice_uninit()`] can be used to loan a slice of
     /// [`core::mem::MaybeUninit<Payload>`].
     ///
+    /// When every sample of the data segment is in use and the [`Publisher`] was created with
+    /// [`UnableToDeliverStrategy::Block`], the call blocks until a sample is returned or until
+    /// the [`Subscriber`](crate::port::subscriber::Subscriber) holding it was removed. Use
+    /// [`Publisher::try_loan_slice()`] when the call shall never block.
+    ///
     /// On failure it returns [`PublisherLoanError`] describing the failure.
     ///
     /// # Example
@@ -1000,7 +2491,60 @@ impl<Service: service::Service, Payload: Default + Debug, UserHeader: Debug>
         &self,
         number_of_elements: usize,
     ) -> Result<SampleMut<Service, [Payload], UserHeader>, PublisherLoanError> {
-        let sample = self.loan_slice_uninit(number_of_elements)?;
+        let sample = unsafe {
+            self.loan_slice_uninit_impl(
+                number_of_elements,
+                number_of_elements,
+                true,
+                self.zero_default_loans(),
+            )?
+        };
+        Ok(sample.write_from_fn(|_| Payload::default()))
+    }
+
+    /// Loans/allocates a [`crate::sample_mut::SampleMut`] like [`Publisher::loan_slice()`] but
+    /// never blocks. When every sample of the data segment is in use it returns
+    /// [`PublisherLoanError::OutOfMemory`] immediately, independent of the
+    /// [`UnableToDeliverStrategy`].
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .initial_max_slice_len(120)
+    ///                          .create()?;
+    ///
+    /// let slice_length = 5;
+    /// let mut sample = publisher.try_loan_slice(slice_length)?;
+    /// sample.payload_mut()[2] = 42;
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_loan_slice(
+        &self,
+        number_of_elements: usize,
+    ) -> Result<SampleMut<Service, [Payload], UserHeader>, PublisherLoanError> {
+        let sample = unsafe {
+            self.loan_slice_uninit_impl(
+                number_of_elements,
+                number_of_elements,
+                false,
+                self.zero_default_loans(),
+            )?
+        };
         Ok(sample.write_from_fn(|_| Payload::default()))
     }
 }
@@ -1009,7 +2553,15 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     Publisher<Service, [Payload], UserHeader>
 {
     /// Loans/allocates a [`SampleMutUninit`] from the underlying data segment of the [`Publisher`].
-    /// The user has to initialize the payload before it can be sent.
+    /// The user has to initialize the payload before it can be sent. Depending on the
+    /// [`LoanInitPolicy`] the payload is filled with zeros or handed out uninitialized.
+    /// With [`LoanInitPolicy::Uninit`] the payload still contains the data of a previously
+    /// sent sample, reading it before it was written is undefined behavior.
+    ///
+    /// When every sample of the data segment is in use and the [`Publisher`] was created with
+    /// [`UnableToDeliverStrategy::Block`], the call blocks until a sample is returned or until
+    /// the [`Subscriber`](crate::port::subscriber::Subscriber) holding it was removed. Use
+    /// [`Publisher::try_loan_slice_uninit()`] when the call shall never block.
     ///
     /// On failure it returns [`PublisherLoanError`] describing the failure.
     ///
@@ -1043,13 +2595,57 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
         // required since Rust does not support generic specializations or negative traits
         debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
 
-        unsafe { self.loan_slice_uninit_impl(slice_len, slice_len) }
+        unsafe { self.loan_slice_uninit_impl(slice_len, slice_len, true, self.zero_uninit_loans()) }
+    }
+
+    /// Loans/allocates a [`SampleMutUninit`] like [`Publisher::loan_slice_uninit()`] but never
+    /// blocks. When every sample of the data segment is in use it returns
+    /// [`PublisherLoanError::OutOfMemory`] immediately, independent of the
+    /// [`UnableToDeliverStrategy`].
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[usize]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .initial_max_slice_len(120)
+    ///                          .create()?;
+    ///
+    /// let slice_length = 5;
+    /// let sample = publisher.try_loan_slice_uninit(slice_length)?;
+    /// let sample = sample.write_from_fn(|n| n * 2);
+    ///
+    /// sample.send()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_loan_slice_uninit(
+        &self,
+        slice_len: usize,
+    ) -> Result<SampleMutUninit<Service, [MaybeUninit<Payload>], UserHeader>, PublisherLoanError>
+    {
+        // required since Rust does not support generic specializations or negative traits
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        unsafe {
+            self.loan_slice_uninit_impl(slice_len, slice_len, false, self.zero_uninit_loans())
+        }
     }
 
     unsafe fn loan_slice_uninit_impl(
         &self,
         slice_len: usize,
         underlying_number_of_slice_elements: usize,
+        wait_for_free_sample: bool,
+        zero_payload: bool,
     ) -> Result<SampleMutUninit<Service, [MaybeUninit<Payload>], UserHeader>, PublisherLoanError>
     {
         let max_slice_len = self.backend.config.initial_max_slice_len;
@@ -1062,11 +2658,13 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
         }
 
         let sample_layout = self.sample_layout(slice_len);
-        let chunk = self.allocate(sample_layout)?;
+        let chunk = self.allocate(sample_layout, wait_for_free_sample)?;
         let header_ptr = chunk.shm_pointer.data_ptr as *mut Header;
         let user_header_ptr = self.user_header_ptr(header_ptr) as *mut UserHeader;
         let payload_ptr = self.payload_ptr(header_ptr) as *mut MaybeUninit<Payload>;
-        unsafe { header_ptr.write(Header::new(self.backend.port_id, slice_len as _)) };
+        if zero_payload {
+            unsafe { payload_ptr.write_bytes(0, underlying_number_of_slice_elements) };
+        }
 
         let sample = unsafe {
             RawSampleMut::new_unchecked(
@@ -1080,6 +2678,7 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
             SampleMutUninit::<Service, [MaybeUninit<Payload>], UserHeader>::new(
                 &self.backend,
                 sample,
+                self.initial_header(slice_len),
                 chunk.shm_pointer.offset,
                 chunk.sample_size,
             ),
@@ -1087,6 +2686,43 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     }
 }
 
+impl<Service: service::Service, Payload: Debug + Copy, UserHeader: Debug>
+    Publisher<Service, [Payload], UserHeader>
+{
+    /// Copies the input slice into a [`crate::sample_mut::SampleMut`] and delivers it.
+    /// The number of elements of the delivered [`Sample`](crate::sample::Sample) is the
+    /// length of the input slice.
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data, otherwise a [`PublisherSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .initial_max_slice_len(16)
+    ///                          .create()?;
+    ///
+    /// publisher.send_slice_copy(&[1, 2, 3])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_slice_copy(&self, value: &[Payload]) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to send copy of slice";
+        let sample = fail!(from self, when unsafe { self.loan_slice_uninit_impl(value.len(), value.len(), true, false) },
+                                    "{} since the loan of a sample failed.", msg);
+
+        sample.write_from_slice(value).send()
+    }
+}
+
 impl<Service: service::Service, UserHeader: Debug>
     Publisher<Service, [CustomPayloadMarker], UserHeader>
 {
@@ -1108,7 +2744,7 @@ impl<Service: service::Service, UserHeader: Debug>
         // TypeVariant::Dynamic == slice and only here it makes sense to loan more than one element
         debug_assert!(slice_len == 1 || self.payload_type_variant() == TypeVariant::Dynamic);
 
-        self.loan_slice_uninit_impl(slice_len, self.payload_size * slice_len)
+        self.loan_slice_uninit_impl(slice_len, self.payload_size * slice_len, true, false)
     }
 }
 ////////////////////////
@@ -1126,6 +2762,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Upda
 pub(crate) unsafe fn remove_data_segment_of_publisher<Service: service::Service>(
     port_id: &UniquePublisherId,
     config: &config::Config,
+    directory: Option<&Path>,
 ) -> Result<(), NamedConceptRemoveError> {
     let origin = format!(
         "remove_data_segment_of_publisher::<{}>::({:?})",
@@ -1135,13 +2772,21 @@ pub(crate) unsafe fn remove_data_segment_of_publisher<Service: service::Service>
 
     fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
             &data_segment_name(port_id),
-            &data_segment_config::<Service>(config),
+            &data_segment_config::<Service>(config, directory),
         ), "Unable to remove the publishers data segment."
     );
 
     Ok(())
 }
 
+/// Returns how long ago the sample was sent. When the current time cannot be acquired or the
+/// clock went backwards, the sample is considered to be fresh.
+fn sample_age(send_time: &Time) -> Duration {
+    Time::now_with_clock(send_time.clock_type()).map_or(Duration::ZERO, |now| {
+        now.as_duration().saturating_sub(send_time.as_duration())
+    })
+}
+
 fn connections<Service: service::Service>(
     origin: &str,
     msg: &str,
@@ -1190,6 +2835,7 @@ fn handle_port_remove_error(
 pub(crate) unsafe fn remove_publisher_from_all_connections<Service: service::Service>(
     port_id: &UniquePublisherId,
     config: &config::Config,
+    directory: Option<&Path>,
 ) -> Result<(), RemovePubSubPortFromAllConnectionsError> {
     let origin = format!(
         "remove_publisher_from_all_connections::<{}>::({:?})",
@@ -1198,7 +2844,7 @@ pub(crate) unsafe fn remove_publisher_from_all_connections<Service: service::Ser
     );
     let msg = "Unable to remove the publisher from all connections";
 
-    let connection_config = connection_config::<Service>(config);
+    let connection_config = connection_config::<Service>(config, directory);
     let connection_list = connections::<Service>(&origin, msg, &connection_config)?;
 
     let mut ret_val = Ok(());
@@ -1224,6 +2870,7 @@ pub(crate) unsafe fn remove_publisher_from_all_connections<Service: service::Ser
 pub(crate) unsafe fn remove_subscriber_from_all_connections<Service: service::Service>(
     port_id: &UniqueSubscriberId,
     config: &config::Config,
+    directory: Option<&Path>,
 ) -> Result<(), RemovePubSubPortFromAllConnectionsError> {
     let origin = format!(
         "remove_subscriber_from_all_connections::<{}>::({:?})",
@@ -1232,7 +2879,7 @@ pub(crate) unsafe fn remove_subscriber_from_all_connections<Service: service::Se
     );
     let msg = "Unable to remove the subscriber from all connections";
 
-    let connection_config = connection_config::<Service>(config);
+    let connection_config = connection_config::<Service>(config, directory);
     let connection_list = connections::<Service>(&origin, msg, &connection_config)?;
 
     let mut ret_val = Ok(());
@@ -1254,3 +2901,71 @@ pub(crate) unsafe fn remove_subscriber_from_all_connections<Service: service::Se
 
     ret_val
 }
+
+/// Removes the port from all connections of the request-response messaging pattern. A
+/// [`Client`](crate::port::client::Client) or [`Server`](crate::port::server::Server) is
+/// the sender of the connections whose name starts with its id and the receiver of the
+/// connections whose name ends with its id.
+pub(crate) unsafe fn remove_port_from_all_connections<Service: service::Service>(
+    port_id_value: u128,
+    config: &config::Config,
+    directory: Option<&Path>,
+) -> Result<(), RemovePubSubPortFromAllConnectionsError> {
+    let origin = format!(
+        "remove_port_from_all_connections::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id_value
+    );
+    let msg = "Unable to remove the port from all connections";
+
+    let connection_config = connection_config::<Service>(config, directory);
+    let connection_list = connections::<Service>(&origin, msg, &connection_config)?;
+
+    let mut ret_val = Ok(());
+    for connection in connection_list {
+        let (sender, receiver) = extract_port_values_from_connection(&connection);
+        let result = if sender == port_id_value {
+            handle_port_remove_error(
+                Service::Connection::remove_sender(&connection, &connection_config),
+                &origin,
+                msg,
+                &connection,
+            )
+        } else if receiver == port_id_value {
+            handle_port_remove_error(
+                Service::Connection::remove_receiver(&connection, &connection_config),
+                &origin,
+                msg,
+                &connection,
+            )
+        } else {
+            Ok(())
+        };
+
+        if ret_val.is_ok() {
+            ret_val = result;
+        }
+    }
+
+    ret_val
+}
+
+pub(crate) unsafe fn remove_data_segment_of_port<Service: service::Service>(
+    port_id_value: u128,
+    config: &config::Config,
+    directory: Option<&Path>,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_data_segment_of_port::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id_value
+    );
+
+    fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+            &port_data_segment_name(port_id_value),
+            &data_segment_config::<Service>(config, directory),
+        ), "Unable to remove the ports data segment."
+    );
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::sample_window::SampleWindow;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<[f32]>()
+//!     .open_or_create()?;
+//!
+//! // windows of 256 values that overlap by one half
+//! let mut window = SampleWindow::new(service.subscriber_builder().create()?, 256, 128);
+//!
+//! while let Some(values) = window.next_window()? {
+//!     println!("window energy: {}", values.iter().map(|v| v * v).sum::<f32>());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate alloc;
+use alloc::collections::VecDeque;
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_log::warn;
+
+use crate::sample::Sample;
+use crate::service;
+
+use super::subscriber::{Subscriber, SubscriberReceiveError};
+
+/// Stitches the slice payloads of consecutive [`Sample`]s of a [`Subscriber`] into one stream
+/// and provides sliding windows of a fixed size over it. After every window, the start of the
+/// next window is moved forward by the stride. A stride that is smaller than the window size
+/// leads to overlapping windows, a larger stride skips the values in between.
+///
+/// A window that lies completely inside one [`Sample`] is provided as a view into the
+/// [`Sample`] itself, only windows that span over multiple [`Sample`]s are copied into an
+/// internal buffer. The [`SampleWindow`] holds only the [`Sample`]s that contribute to the
+/// current window, therefore a window must not span more [`Sample`]s than
+/// [`StaticConfig::subscriber_max_borrowed_samples()`](crate::service::static_config::publish_subscribe::StaticConfig::subscriber_max_borrowed_samples())
+/// allows, otherwise [`SubscriberReceiveError::ExceedsMaxBorrowedSamples`] is returned.
+#[derive(Debug)]
+pub struct SampleWindow<
+    Service: service::Service,
+    Payload: Debug + Copy + 'static,
+    UserHeader: Debug,
+> {
+    subscriber: Subscriber<Service, [Payload], UserHeader>,
+    samples: VecDeque<Sample<Service, [Payload], UserHeader>>,
+    window_size: usize,
+    stride: usize,
+    offset: usize,
+    is_advance_pending: bool,
+    buffer: Vec<Payload>,
+}
+
+impl<Service: service::Service, Payload: Debug + Copy, UserHeader: Debug>
+    SampleWindow<Service, Payload, UserHeader>
+{
+    /// Creates a new [`SampleWindow`] that provides windows of `window_size` elements which
+    /// start `stride` elements apart. A `window_size` or `stride` of zero is set to one.
+    pub fn new(
+        subscriber: Subscriber<Service, [Payload], UserHeader>,
+        window_size: usize,
+        stride: usize,
+    ) -> Self {
+        let origin = "SampleWindow::new()";
+        if window_size == 0 {
+            warn!(from origin, "A window size of 0 is not supported, it is set to 1.");
+        }
+        if stride == 0 {
+            warn!(from origin, "A stride of 0 is not supported, it is set to 1.");
+        }
+
+        Self {
+            subscriber,
+            samples: VecDeque::new(),
+            window_size: window_size.max(1),
+            stride: stride.max(1),
+            offset: 0,
+            is_advance_pending: false,
+            buffer: Vec::with_capacity(window_size.max(1)),
+        }
+    }
+
+    /// Returns the number of elements of every window.
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Returns the number of elements the start of the window is moved forward after every
+    /// window.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Returns the number of [`Sample`]s that are currently held to provide the current
+    /// window.
+    pub fn number_of_held_samples(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns the underlying [`Subscriber`].
+    pub fn subscriber(&self) -> &Subscriber<Service, [Payload], UserHeader> {
+        &self.subscriber
+    }
+
+    /// Releases all held [`Sample`]s and returns the underlying [`Subscriber`]. The elements
+    /// of the held [`Sample`]s that were not yet part of a window are discarded.
+    pub fn into_subscriber(self) -> Subscriber<Service, [Payload], UserHeader> {
+        self.subscriber
+    }
+
+    /// Returns the next window. Receives as many [`Sample`]s as required to complete the
+    /// window. If the received data is not sufficient, [`None`] is returned and the already
+    /// received [`Sample`]s are kept until the window can be completed by a later call.
+    pub fn next_window(&mut self) -> Result<Option<&[Payload]>, SubscriberReceiveError> {
+        if self.is_advance_pending {
+            self.offset += self.stride;
+            self.is_advance_pending = false;
+        }
+        self.release_consumed_samples();
+
+        while self.number_of_available_elements() < self.window_size {
+            match self.subscriber.receive()? {
+                Some(sample) => self.samples.push_back(sample),
+                None => return Ok(None),
+            }
+            self.release_consumed_samples();
+        }
+
+        self.is_advance_pending = true;
+        let first = self.samples[0].payload();
+        if self.window_size <= first.len() - self.offset {
+            return Ok(Some(&first[self.offset..self.offset + self.window_size]));
+        }
+
+        self.buffer.clear();
+        let mut start = self.offset;
+        for sample in &self.samples {
+            let missing = self.window_size - self.buffer.len();
+            let payload = &sample.payload()[start..];
+            self.buffer
+                .extend_from_slice(&payload[..missing.min(payload.len())]);
+            start = 0;
+        }
+
+        Ok(Some(&self.buffer))
+    }
+
+    fn number_of_available_elements(&self) -> usize {
+        self.samples
+            .iter()
+            .map(|sample| sample.payload().len())
+            .sum::<usize>()
+            .saturating_sub(self.offset)
+    }
+
+    fn release_consumed_samples(&mut self) {
+        while let Some(sample) = self.samples.front() {
+            let len = sample.payload().len();
+            if self.offset < len {
+                break;
+            }
+
+            self.offset -= len;
+            self.samples.pop_front();
+        }
+    }
+}
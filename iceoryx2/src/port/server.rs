@@ -10,5 +10,721 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-/// TODO
-pub struct Server {}
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server_builder().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     println!("received request: {:?}", *active_request);
+//!
+//!     // loan some uninitialized memory, write the response into it and send it
+//!     let response = active_request.loan_uninit()?;
+//!     let response = response.write_payload(*active_request + 1);
+//!     response.send()?;
+//!
+//!     // send a copy of the value
+//!     active_request.send_copy(*active_request + 2)?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::alloc::Layout;
+use core::cell::{Cell, RefCell, UnsafeCell};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::sync::atomic::Ordering;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::process::Process;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shm_allocator::{AllocationError, PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyPortDetails, ZeroCopyReceiveError,
+    ZeroCopyReceiver, ZeroCopySendError, ZeroCopySender,
+};
+
+use crate::active_request::ActiveRequest;
+use crate::raw_sample::RawSample;
+use crate::service::config_scheme::connection_config;
+use crate::service::dynamic_config::request_response::{ClientDetails, ServerDetails};
+use crate::service::header::request_response::RequestHeader;
+use crate::service::naming_scheme::{
+    port_data_segment_name, request_connection_name, response_connection_name,
+};
+use crate::service::static_config::request_response::StaticConfig;
+use crate::service::{self, ServiceState};
+
+use super::details::data_segment::{DataSegmentType, DataSegmentView};
+use super::details::reference_counted_data_segment::ReferenceCountedDataSegment;
+use super::port_identifiers::{UniqueClientId, UniqueServerId};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+
+/// Describes the failures when a new [`Server`] is created via the
+/// [`crate::service::port_factory::server::PortFactoryServer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerCreateError {
+    /// The maximum amount of [`Server`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Server`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedServers,
+    /// The datasegment in which the responses of the [`Server`] are stored, could not be created.
+    UnableToCreateDataSegment,
+}
+
+impl core::fmt::Display for ServerCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ServerCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ServerCreateError {}
+
+/// Defines the failure that can occur when a request is received with [`Server::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerReceiveError {
+    /// The [`Server`] holds already the maximum number of requests it can borrow from a
+    /// [`Client`](crate::port::client::Client), see
+    /// [`StaticConfig::max_borrowed_requests()`](crate::service::static_config::request_response::StaticConfig::max_borrowed_requests()).
+    ExceedsMaxBorrowedRequests,
+    /// Occurs when a [`Server`] is unable to connect to a corresponding
+    /// [`Client`](crate::port::client::Client).
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl core::fmt::Display for ServerReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ServerReceiveError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ServerReceiveError {}
+
+/// Defines a failure that can occur in
+/// [`ActiveRequest::loan()`](crate::active_request::ActiveRequest::loan()) and
+/// [`ActiveRequest::loan_uninit()`](crate::active_request::ActiveRequest::loan_uninit())
+/// or is part of [`ResponseSendError`] emitted in
+/// [`ActiveRequest::send_copy()`](crate::active_request::ActiveRequest::send_copy()).
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum ResponseLoanError {
+    /// The [`Client`](crate::port::client::Client) that sent the request is no longer
+    /// connected, therefore no response can be delivered.
+    ClientDisconnected,
+    /// The [`Server`]s data segment does not have any more memory left.
+    OutOfMemory,
+    /// The [`Server`] holds already
+    /// [`StaticConfig::max_active_responses()`](crate::service::static_config::request_response::StaticConfig::max_active_responses())
+    /// loaned responses. Send or drop a
+    /// [`ResponseMut`](crate::response_mut::ResponseMut) to loan another response.
+    ExceedsMaxActiveResponses,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl core::fmt::Display for ResponseLoanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ResponseLoanError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ResponseLoanError {}
+
+/// Failure that can be emitted when a [`ResponseMut`](crate::response_mut::ResponseMut) is
+/// sent via [`ResponseMut::send()`](crate::response_mut::ResponseMut::send()).
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ResponseSendError {
+    /// The [`Client`](crate::port::client::Client) that sent the request is no longer
+    /// connected, therefore the response was discarded.
+    ClientDisconnected,
+    /// The connection between the [`Server`] and the [`Client`](crate::port::client::Client)
+    /// is corrupted.
+    ConnectionCorrupted,
+    /// The response buffer of the [`Client`](crate::port::client::Client) is full and the
+    /// [`Service`](crate::service::Service) does not allow safe overflow for responses.
+    ReceiveBufferFull,
+    /// A failure occurred while acquiring memory for the response
+    LoanError(ResponseLoanError),
+    /// A failure occurred while establishing a connection to the
+    /// [`Client`](crate::port::client::Client)
+    ConnectionError(ConnectionFailure),
+}
+
+impl From<ResponseLoanError> for ResponseSendError {
+    fn from(value: ResponseLoanError) -> Self {
+        ResponseSendError::LoanError(value)
+    }
+}
+
+impl From<ConnectionFailure> for ResponseSendError {
+    fn from(value: ConnectionFailure) -> Self {
+        ResponseSendError::ConnectionError(value)
+    }
+}
+
+impl core::fmt::Display for ResponseSendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ResponseSendError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ResponseSendError {}
+
+/// The connection of a [`Server`] to one [`Client`](crate::port::client::Client). The
+/// requests are received from the data segment of the
+/// [`Client`](crate::port::client::Client) and the responses are sent from the data segment
+/// of the [`Server`].
+#[derive(Debug)]
+pub(crate) struct ClientConnection<Service: service::Service> {
+    pub(crate) client_id: UniqueClientId,
+    pub(crate) request_receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
+    pub(crate) data_segment: DataSegmentView<Service>,
+    pub(crate) response_sender: <Service::Connection as ZeroCopyConnection>::Sender,
+}
+
+impl<Service: service::Service> ClientConnection<Service> {
+    fn new(
+        backend: &ServerBackend<Service>,
+        details: &ClientDetails,
+    ) -> Result<Self, ConnectionFailure> {
+        let msg = format!(
+            "Unable to establish connection to client {:?} from server {:?}.",
+            details.client_id, backend.server_id
+        );
+        let global_config = backend.service_state.shared_node.config();
+        let directory = backend.service_state.directory.as_ref();
+        let static_config = &backend.static_config;
+
+        let request_receiver = fail!(from backend,
+            when <Service::Connection as ZeroCopyConnection>::
+                Builder::new(&request_connection_name(details.client_id, backend.server_id))
+                    .config(&connection_config::<Service>(global_config, directory))
+                    .buffer_size(static_config.max_request_buffer_size)
+                    .receiver_max_borrowed_samples(static_config.max_borrowed_requests)
+                    .enable_safe_overflow(static_config.enable_safe_overflow_for_requests)
+                    .number_of_samples_per_segment(details.number_of_requests)
+                    .max_supported_shared_memory_segments(1)
+                    .timeout(global_config.global.service.creation_timeout)
+                    .create_receiver(),
+            "{} since the request connection could not be established.", msg);
+
+        let data_segment = fail!(from backend,
+            when DataSegmentView::open(
+                &port_data_segment_name(details.client_id.value()),
+                DataSegmentType::Static,
+                &backend.service_state),
+            "{} since the clients data segment could not be opened.", msg);
+
+        let response_sender = fail!(from backend,
+            when <Service::Connection as ZeroCopyConnection>::
+                Builder::new(&response_connection_name(backend.server_id, details.client_id))
+                    .config(&connection_config::<Service>(global_config, directory))
+                    .buffer_size(details.response_buffer_size)
+                    .receiver_max_borrowed_samples(static_config.max_borrowed_responses_per_client())
+                    .enable_safe_overflow(static_config.enable_safe_overflow_for_responses)
+                    .number_of_samples_per_segment(backend.data_segment.number_of_samples())
+                    .max_supported_shared_memory_segments(1)
+                    .timeout(global_config.global.service.creation_timeout)
+                    .create_sender(),
+            "{} since the response connection could not be established.", msg);
+
+        Ok(Self {
+            client_id: details.client_id,
+            request_receiver,
+            data_segment,
+            response_sender,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ServerBackend<Service: service::Service> {
+    server_id: UniqueServerId,
+    pub(crate) service_state: Arc<ServiceState<Service>>,
+    pub(crate) static_config: StaticConfig,
+    pub(crate) data_segment: ReferenceCountedDataSegment<Service>,
+
+    client_connections: Vec<RefCell<Option<Arc<ClientConnection<Service>>>>>,
+    client_list_state: UnsafeCell<ContainerState<ClientDetails>>,
+    active_responses: Cell<usize>,
+    next_connection: Cell<usize>,
+}
+
+impl<Service: service::Service> ServerBackend<Service> {
+    pub(crate) fn server_id(&self) -> UniqueServerId {
+        self.server_id
+    }
+
+    fn populate_client_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.client_connections.len(), None);
+
+        unsafe {
+            (*self.client_list_state.get()).for_each(|h, details| {
+                visited_indices[h.index() as usize] = Some(*details);
+                CallbackProgression::Continue
+            })
+        };
+
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(details) => {
+                    let create_connection = match &*self.client_connections[i].borrow() {
+                        None => true,
+                        Some(connection) => connection.client_id != details.client_id,
+                    };
+
+                    if create_connection {
+                        self.remove_connection(i);
+                        match ClientConnection::new(self, details) {
+                            Ok(connection) => {
+                                *self.client_connections[i].borrow_mut() =
+                                    Some(Arc::new(connection))
+                            }
+                            Err(e) => {
+                                warn!(from self, "Unable to establish connection to new client {:?}.", details.client_id);
+                                result = Err(e);
+                            }
+                        }
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        result
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.client_connections[i].borrow_mut().take() {
+            // # SAFETY: the client no longer exist, therefore we can
+            //           reacquire all delivered responses
+            unsafe {
+                connection
+                    .response_sender
+                    .acquire_used_offsets(|offset| self.data_segment.release_sample(offset))
+            };
+        }
+    }
+
+    pub(crate) fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .clients
+                .update_state(&mut *self.client_list_state.get())
+        } {
+            fail!(from self, when self.populate_client_channels(),
+                "Connections were updated only partially since at least one connection to a Client port failed.");
+        }
+
+        Ok(())
+    }
+
+    /// Returns true when the [`Client`](crate::port::client::Client) of the connection is
+    /// still connected to the [`Server`]. When the [`Client`](crate::port::client::Client)
+    /// was removed from the [`Service`](crate::service::Service), e.g. since its process
+    /// died and its resources were cleaned up, the connection is no longer part of the
+    /// [`Server`] even when an [`ActiveRequest`] still holds it.
+    pub(crate) fn is_client_connected(&self, connection: &Arc<ClientConnection<Service>>) -> bool {
+        if let Err(e) = self.update_connections() {
+            warn!(from self, "Unable to update all connections ({:?}), the connection states may be outdated.", e);
+        }
+
+        let is_part_of_server = self.client_connections.iter().any(|c| {
+            c.borrow()
+                .as_ref()
+                .is_some_and(|c| Arc::ptr_eq(c, connection))
+        });
+
+        is_part_of_server && connection.response_sender.is_connected()
+    }
+
+    fn retrieve_returned_responses(&self) {
+        for connection in &self.client_connections {
+            if let Some(connection) = &*connection.borrow() {
+                loop {
+                    match connection.response_sender.reclaim() {
+                        Ok(Some(offset)) => self.data_segment.release_sample(offset),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim responses from client {:?} due to {:?}. This may lead to a situation where no more responses will be delivered to this client.", connection.client_id, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn number_of_client_connections(&self) -> usize {
+        self.client_connections
+            .iter()
+            .filter(|connection| connection.borrow().is_some())
+            .count()
+    }
+
+    pub(crate) fn allocate(
+        &self,
+        layout: Layout,
+    ) -> Result<(PointerOffset, *mut u8), ResponseLoanError> {
+        let msg = "Unable to loan response";
+        if self.static_config.max_active_responses <= self.active_responses.get() {
+            fail!(from self, with ResponseLoanError::ExceedsMaxActiveResponses,
+                "{} since already {} responses are loaned and it would exceed the maximum of {} active responses. Send or release a loaned response to loan another response.",
+                msg, self.active_responses.get(), self.static_config.max_active_responses);
+        }
+
+        self.retrieve_returned_responses();
+        match self.data_segment.allocate(layout) {
+            Ok(shm_pointer) => {
+                self.active_responses.set(self.active_responses.get() + 1);
+                Ok((shm_pointer.offset, shm_pointer.data_ptr))
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with ResponseLoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with ResponseLoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, e);
+            }
+        }
+    }
+
+    /// Releases a loaned response that was not sent.
+    pub(crate) fn return_loaned_response(&self, offset: PointerOffset) {
+        self.data_segment.release_sample(offset);
+        self.active_responses.set(self.active_responses.get() - 1);
+    }
+
+    /// Delivers the loaned response to the [`Client`](crate::port::client::Client) of the
+    /// connection. The response is always consumed, also on failure.
+    pub(crate) fn send_response(
+        &self,
+        connection: &Arc<ClientConnection<Service>>,
+        offset: PointerOffset,
+    ) -> Result<(), ResponseSendError> {
+        let msg = "Unable to send response";
+        if !self.is_client_connected(connection) {
+            self.return_loaned_response(offset);
+            fail!(from self, with ResponseSendError::ClientDisconnected,
+                "{} since the client {:?} is no longer connected.", msg, connection.client_id);
+        }
+
+        self.retrieve_returned_responses();
+        core::sync::atomic::fence(Ordering::Release);
+        match connection
+            .response_sender
+            .try_send(offset, self.data_segment.sample_size())
+        {
+            Ok(overflow) => {
+                self.active_responses.set(self.active_responses.get() - 1);
+                if let Some(old) = overflow {
+                    self.data_segment.release_sample(old);
+                }
+                Ok(())
+            }
+            Err(ZeroCopySendError::ReceiveBufferFull)
+            | Err(ZeroCopySendError::UsedChunkListFull) => {
+                self.return_loaned_response(offset);
+                fail!(from self, with ResponseSendError::ReceiveBufferFull,
+                    "{} since the response buffer of the client {:?} is full.", msg, connection.client_id);
+            }
+            Err(ZeroCopySendError::ConnectionCorrupted) => {
+                self.return_loaned_response(offset);
+                fail!(from self, with ResponseSendError::ConnectionCorrupted,
+                    "{} since the connection to the client {:?} is corrupted.", msg, connection.client_id);
+            }
+        }
+    }
+
+    pub(crate) fn has_requests(&self) -> Result<bool, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+            "Some requests are not being received since not all connections to clients could be established.");
+
+        Ok(self.client_connections.iter().any(|connection| {
+            connection
+                .borrow()
+                .as_ref()
+                .is_some_and(|connection| connection.request_receiver.has_data())
+        }))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn receive(
+        &self,
+    ) -> Result<Option<(Arc<ClientConnection<Service>>, PointerOffset, usize)>, ServerReceiveError>
+    {
+        if let Err(e) = self.update_connections() {
+            fail!(from self, with ServerReceiveError::ConnectionFailure(e),
+                "Some requests are not being received since not all connections to clients could be established.");
+        }
+
+        let msg = "Unable to receive another request";
+        let number_of_connections = self.client_connections.len();
+        let start = self.next_connection.get();
+        for n in 0..number_of_connections {
+            let index = (start + n) % number_of_connections;
+            let connection = match &*self.client_connections[index].borrow() {
+                Some(connection) => connection.clone(),
+                None => continue,
+            };
+
+            let offset = match connection.request_receiver.receive() {
+                Ok(Some(offset)) => offset,
+                Ok(None) => continue,
+                Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                    fail!(from self, with ServerReceiveError::ExceedsMaxBorrowedRequests,
+                        "{} since it would exceed the maximum {} of borrowed requests.",
+                        msg, connection.request_receiver.max_borrowed_samples());
+                }
+            };
+
+            let address = match connection
+                .data_segment
+                .register_and_translate_offset(offset)
+            {
+                Ok(address) => address,
+                Err(e) => {
+                    fail!(from self, with ServerReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapPublishersDataSegment(e)),
+                        "{} since the received offset {:?} from client {:?} could not be registered and translated.",
+                        msg, offset, connection.client_id);
+                }
+            };
+
+            self.next_connection
+                .set((index + 1) % number_of_connections);
+            return Ok(Some((connection, offset, address)));
+        }
+
+        Ok(None)
+    }
+}
+
+/// Receives the requests of the [`Client`](crate::port::client::Client)s of a
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based [`Service`](crate::service::Service) as [`ActiveRequest`]s and sends the
+/// corresponding responses with them.
+#[derive(Debug)]
+pub struct Server<
+    Service: service::Service,
+    RequestPayload: Debug,
+    RequestUserHeader: Debug,
+    ResponsePayload: Debug,
+    ResponseUserHeader: Debug,
+> {
+    backend: Arc<ServerBackend<Service>>,
+    dynamic_server_handle: Option<ContainerHandle>,
+    _request_payload: PhantomData<RequestPayload>,
+    _request_user_header: PhantomData<RequestUserHeader>,
+    _response_payload: PhantomData<ResponsePayload>,
+    _response_user_header: PhantomData<ResponseUserHeader>,
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Drop
+    for Server<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_server_handle {
+            self.backend
+                .service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .release_server_handle(handle)
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Server<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    pub(crate) fn new(service: &Service) -> Result<Self, ServerCreateError> {
+        let msg = "Unable to create Server port";
+        let origin = "Server::new()";
+        let server_id = UniqueServerId::new();
+        let static_config = service
+            .__internal_state()
+            .static_config
+            .request_response()
+            .clone();
+        let client_list = &service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .clients;
+
+        let number_of_responses = static_config.required_amount_of_responses_per_server();
+        let data_segment = fail!(from origin,
+            when ReferenceCountedDataSegment::create(
+                &port_data_segment_name(server_id.value()),
+                number_of_responses,
+                service.__internal_state(),
+                static_config.response_message_type_details.sample_layout(1)),
+            with ServerCreateError::UnableToCreateDataSegment,
+            "{} since the data segment could not be acquired.", msg);
+
+        let request_buffer_size = static_config.max_request_buffer_size;
+        let backend = Arc::new(ServerBackend {
+            server_id,
+            service_state: service.__internal_state().clone(),
+            static_config,
+            data_segment,
+            client_connections: (0..client_list.capacity())
+                .map(|_| RefCell::new(None))
+                .collect(),
+            client_list_state: UnsafeCell::new(unsafe { client_list.get_state() }),
+            active_responses: Cell::new(0),
+            next_connection: Cell::new(0),
+        });
+
+        let mut new_self = Self {
+            backend,
+            dynamic_server_handle: None,
+            _request_payload: PhantomData,
+            _request_user_header: PhantomData,
+            _response_payload: PhantomData,
+            _response_user_header: PhantomData,
+        };
+
+        if let Err(e) = new_self.backend.populate_client_channels() {
+            warn!(from new_self, "The new Server port is unable to connect to every Client port, caused by {:?}.", e);
+        }
+
+        core::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a server is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_server_handle = match service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_server_id(ServerDetails {
+                server_id,
+                node_id: *service.__internal_state().shared_node.id(),
+                owner_start_time: Process::from_self().start_time().ok(),
+                number_of_responses,
+                request_buffer_size,
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ServerCreateError::ExceedsMaxSupportedServers,
+                    "{} since it would exceed the maximum supported amount of servers of {}.",
+                    msg, new_self.backend.static_config.max_servers);
+            }
+        };
+
+        new_self.dynamic_server_handle = Some(dynamic_server_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`Server`]
+    pub fn id(&self) -> UniqueServerId {
+        self.backend.server_id
+    }
+
+    /// Returns the number of [`Client`](crate::port::client::Client)s the [`Server`] is
+    /// currently connected to.
+    pub fn number_of_client_connections(&self) -> usize {
+        self.backend.number_of_client_connections()
+    }
+
+    /// Returns true if the [`Server`] has requests in its buffer that can be received,
+    /// otherwise false.
+    pub fn has_requests(&self) -> Result<bool, ConnectionFailure> {
+        self.backend.has_requests()
+    }
+
+    /// Receives the next request of one of the connected
+    /// [`Client`](crate::port::client::Client)s as [`ActiveRequest`]. The responses to the
+    /// request are sent via the [`ActiveRequest`]. If no request could be received [`None`]
+    /// is returned.
+    ///
+    /// On failure it returns [`ServerReceiveError`] describing the failure.
+    #[allow(clippy::type_complexity)]
+    pub fn receive(
+        &self,
+    ) -> Result<
+        Option<
+            ActiveRequest<
+                Service,
+                RequestPayload,
+                RequestUserHeader,
+                ResponsePayload,
+                ResponseUserHeader,
+            >,
+        >,
+        ServerReceiveError,
+    > {
+        let (connection, offset, address) = match self.backend.receive()? {
+            Some(request) => request,
+            None => return Ok(None),
+        };
+
+        let message_type_details = &self.backend.static_config.request_message_type_details;
+        let header = address as *const RequestHeader;
+        let user_header = message_type_details.user_header_ptr_from_header(header.cast());
+        let payload = message_type_details.payload_ptr_from_header(header.cast());
+
+        Ok(Some(ActiveRequest::new(
+            self.backend.clone(),
+            connection,
+            offset,
+            unsafe {
+                RawSample::new_unchecked(
+                    header,
+                    user_header as *const RequestUserHeader,
+                    payload as *const RequestPayload,
+                )
+            },
+        )))
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > UpdateConnections
+    for Server<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.backend.update_connections()
+    }
+}
@@ -30,34 +30,60 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! A received [`Sample`](crate::sample::Sample) synchronizes with the send operation of the
+//! [`Publisher`](crate::port::publisher::Publisher), see
+//! [Memory Ordering](crate::port::publisher#memory-ordering).
 
 use core::any::TypeId;
-use core::cell::UnsafeCell;
+use core::cell::{Cell, OnceCell, UnsafeCell};
 use core::fmt::Debug;
 use core::marker::PhantomData;
 use core::sync::atomic::Ordering;
+use core::time::Duration;
 
 extern crate alloc;
 use alloc::sync::Arc;
 
 use iceoryx2_bb_container::queue::Queue;
+use iceoryx2_bb_elementary::scope_guard::ScopeGuardBuilder;
 use iceoryx2_bb_elementary::CallbackProgression;
-use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
 use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::memory_lock::MemoryLockCreationError;
+use iceoryx2_bb_posix::process::Process;
+use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::{
+    Event, Listener as _, ListenerBuilder as _, ListenerWaitError, NamedConceptBuilder as _,
+    NamedConceptMgmt, TriggerId,
+};
+use iceoryx2_cal::named_concept::NamedConceptRemoveError;
 use iceoryx2_cal::zero_copy_connection::*;
+use tiny_fn::tiny_fn;
+#[cfg(all(unix, feature = "tokio"))]
+use tokio::io::{unix::AsyncFd, Interest};
 
+use crate::config;
+use crate::node::{register_sample_callback, remove_sample_callback};
 use crate::port::DegrationAction;
 use crate::sample::SampleDetails;
 use crate::service::builder::publish_subscribe::CustomPayloadMarker;
-use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
+use crate::service::config_scheme::event_config;
+use crate::service::dynamic_config::publish_subscribe::{
+    PublisherDetails, SubscriberDetails, SubscriberRegistration,
+};
 use crate::service::header::publish_subscribe::Header;
+use crate::service::naming_scheme::sample_signal_name;
 use crate::service::port_factory::subscriber::SubscriberConfig;
 use crate::service::static_config::publish_subscribe::StaticConfig;
+use crate::span::enter_span;
 use crate::{raw_sample::RawSample, sample::Sample, service};
 
 use super::details::publisher_connections::{Connection, PublisherConnections};
-use super::port_identifiers::UniqueSubscriberId;
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 use super::update_connections::{ConnectionFailure, UpdateConnections};
 use super::DegrationCallback;
 
@@ -72,6 +98,17 @@ pub enum SubscriberReceiveError {
     /// Occurs when a [`Subscriber`] is unable to connect to a corresponding
     /// [`Publisher`](crate::port::publisher::Publisher).
     ConnectionFailure(ConnectionFailure),
+
+    /// The [`Subscriber`] was disconnected by a [`Publisher`](crate::port::publisher::Publisher)
+    /// since it did not free buffer space in time, see
+    /// [`Builder::slow_subscriber_eviction()`](crate::service::builder::publish_subscribe::Builder::slow_subscriber_eviction()).
+    /// It no longer receives any samples and must be recreated.
+    Disconnected,
+
+    /// The memory of the received [`Sample`] could not be locked, see
+    /// [`Builder::pin_sample_memory()`](crate::service::builder::publish_subscribe::Builder::pin_sample_memory()).
+    /// The [`Sample`] is released again.
+    UnableToPinSampleMemory(MemoryLockCreationError),
 }
 
 impl core::fmt::Display for SubscriberReceiveError {
@@ -82,6 +119,98 @@ impl core::fmt::Display for SubscriberReceiveError {
 
 impl std::error::Error for SubscriberReceiveError {}
 
+/// Defines the failure that can occur when an iterator is created with
+/// [`Subscriber::samples_since()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SamplesSinceError {
+    /// The point in time was not acquired with the
+    /// [`ClockType::Realtime`](iceoryx2_bb_posix::clock::ClockType::Realtime) clock and cannot
+    /// be compared with the [`Header::publish_timestamp()`].
+    IncompatibleClockType,
+}
+
+impl core::fmt::Display for SamplesSinceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "SamplesSinceError::{:?}", self)
+    }
+}
+
+impl std::error::Error for SamplesSinceError {}
+
+/// Defines the failure that can occur when receiving data with [`Subscriber::receive_timeout()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SubscriberReceiveTimeoutError {
+    /// A failure occurred while receiving the [`Sample`], see [`SubscriberReceiveError`].
+    ReceiveError(SubscriberReceiveError),
+    /// An interrupt signal was received while waiting for a [`Sample`].
+    InterruptSignal,
+    /// The sample signal that is notified by the [`crate::port::publisher::Publisher`]s
+    /// could not be created.
+    UnableToCreateSampleSignal,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl From<SubscriberReceiveError> for SubscriberReceiveTimeoutError {
+    fn from(value: SubscriberReceiveError) -> Self {
+        SubscriberReceiveTimeoutError::ReceiveError(value)
+    }
+}
+
+impl From<SubscriberWaitError> for SubscriberReceiveTimeoutError {
+    fn from(value: SubscriberWaitError) -> Self {
+        match value {
+            SubscriberWaitError::ReceiveError(e) => SubscriberReceiveTimeoutError::ReceiveError(e),
+            SubscriberWaitError::UnableToCreateSampleSignal => {
+                SubscriberReceiveTimeoutError::UnableToCreateSampleSignal
+            }
+            SubscriberWaitError::InternalFailure => SubscriberReceiveTimeoutError::InternalFailure,
+        }
+    }
+}
+
+impl core::fmt::Display for SubscriberReceiveTimeoutError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "SubscriberReceiveTimeoutError::{:?}", self)
+    }
+}
+
+impl std::error::Error for SubscriberReceiveTimeoutError {}
+
+/// Defines the failures that can occur when a [`Subscriber`] waits for new samples on its
+/// sample signal, see [`Subscriber::sample_signal_file_descriptor()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SubscriberWaitError {
+    /// A failure occurred while receiving the [`Sample`], see [`SubscriberReceiveError`].
+    ReceiveError(SubscriberReceiveError),
+    /// The sample signal that is notified by the [`crate::port::publisher::Publisher`]s
+    /// could not be created.
+    UnableToCreateSampleSignal,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl From<SubscriberReceiveError> for SubscriberWaitError {
+    fn from(value: SubscriberReceiveError) -> Self {
+        SubscriberWaitError::ReceiveError(value)
+    }
+}
+
+impl core::fmt::Display for SubscriberWaitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "SubscriberWaitError::{:?}", self)
+    }
+}
+
+impl std::error::Error for SubscriberWaitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SubscriberWaitError::ReceiveError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
 /// Describes the failures when a new [`Subscriber`] is created via the
 /// [`crate::service::port_factory::subscriber::PortFactorySubscriber`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -94,6 +223,20 @@ pub enum SubscriberCreateError {
     /// When the [`Subscriber`] requires a larger buffer size than the
     /// [`Service`](crate::service::Service) offers the creation will fail.
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
+    /// When the [`Subscriber`] requires more borrowed samples than the
+    /// [`Service`](crate::service::Service) supports the creation will fail.
+    MaxBorrowedSamplesExceedsMaxSupportedBorrowedSamplesOfService,
+    /// The buffer of the [`Subscriber`] is smaller than the history of the
+    /// [`Service`](crate::service::Service) and a late-joining [`Subscriber`] would only receive
+    /// a truncated history. Only returned when
+    /// [`PortFactorySubscriber::require_buffer_for_history()`](crate::service::port_factory::subscriber::PortFactorySubscriber::require_buffer_for_history())
+    /// is enabled.
+    BufferTooSmallForHistory {
+        /// The buffer size of the [`Subscriber`]
+        buffer: usize,
+        /// The history size that was granted to the [`Subscriber`]
+        history: usize,
+    },
 }
 
 impl core::fmt::Display for SubscriberCreateError {
@@ -104,6 +247,39 @@ impl core::fmt::Display for SubscriberCreateError {
 
 impl std::error::Error for SubscriberCreateError {}
 
+/// Describes why a [`Subscriber`] discarded a sample without delivering it, see
+/// [`PortFactorySubscriber::dead_letter()`](crate::service::port_factory::subscriber::PortFactorySubscriber::dead_letter()).
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum DeadLetterReason {
+    /// The sample was suppressed by a filter of the [`Subscriber`], either by
+    /// [`PortFactorySubscriber::resume_from()`](crate::service::port_factory::subscriber::PortFactorySubscriber::resume_from())
+    /// or since it was published before the point in time of
+    /// [`Subscriber::samples_since()`].
+    Filtered,
+    /// The sample was superseded by a newer sample of the same coalescence group, see
+    /// [`Builder::coalescing_window()`](crate::service::builder::publish_subscribe::Builder::coalescing_window()).
+    Coalesced,
+    /// The sample was older than the one returned by [`Subscriber::receive_latest()`].
+    Superseded,
+    /// The sample was still buffered when its [`Publisher`](crate::port::publisher::Publisher)
+    /// disconnected and the expired connection buffer, see
+    /// [`PublishSubscribe::subscriber_expired_connection_buffer`](crate::config::PublishSubscribe::subscriber_expired_connection_buffer),
+    /// was exceeded.
+    Overflow,
+}
+
+tiny_fn! {
+    /// Receives the [`Header`] of every sample a [`Subscriber`] discards together with the
+    /// [`DeadLetterReason`].
+    pub struct DeadLetterSink = Fn(header: Header, reason: DeadLetterReason);
+}
+
+impl Debug for DeadLetterSink<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "")
+    }
+}
+
 /// The receiving endpoint of a publish-subscribe communication.
 #[derive(Debug)]
 pub struct Subscriber<
@@ -111,28 +287,115 @@ pub struct Subscriber<
     Payload: Debug + ?Sized + 'static,
     UserHeader: Debug,
 > {
-    dynamic_subscriber_handle: Option<ContainerHandle>,
+    dynamic_subscriber_registration: Option<SubscriberRegistration>,
     publisher_connections: PublisherConnections<Service>,
+    // registered with the reactor of the tokio runtime on the first asynchronous wait, is
+    // declared before the sample signal so that it is deregistered first
+    #[cfg(all(unix, feature = "tokio"))]
+    sample_signal_reactor: OnceCell<AsyncFd<i32>>,
+    // created on the first wait, publishers notify it when they deliver a sample
+    sample_signal: OnceCell<Box<<Service::Event as Event>::Listener>>,
     to_be_removed_connections: UnsafeCell<Queue<Arc<Connection<Service>>>>,
     static_config: crate::service::static_config::StaticConfig,
     degration_callback: Option<DegrationCallback<'static>>,
+    dead_letter_sink: Option<DeadLetterSink<'static>>,
 
     publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    last_sequence_numbers: UnsafeCell<Vec<(UniquePublisherId, u64)>>,
+    coalescing_lookahead: UnsafeCell<Option<(SampleDetails<Service>, usize)>>,
+    peeked_sample: UnsafeCell<Option<Sample<Service, Payload, UserHeader>>>,
+    resume_from: Vec<(UniquePublisherId, u64)>,
+    granted_history: usize,
+    deadline: Option<Duration>,
+    // acquired with the monotonic clock when a sample is received
+    last_arrival: Cell<Option<Time>>,
+    is_deadline_missed: Cell<bool>,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
 
+type ReceiveCall<Service, Payload, UserHeader> =
+    fn(
+        &Subscriber<Service, Payload, UserHeader>,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError>;
+
+type SampleWithGapInfo<Service, Payload, UserHeader> =
+    Option<(Sample<Service, Payload, UserHeader>, u64)>;
+
+/// Iterator over all buffered [`Sample`]s of a [`Subscriber`] that were published at or after
+/// a given point in time. Older [`Sample`]s are received and discarded. Created with
+/// [`Subscriber::samples_since()`].
+#[derive(Debug)]
+pub struct SamplesSince<
+    'subscriber,
+    Service: service::Service,
+    Payload: Debug + ?Sized + 'static,
+    UserHeader: Debug,
+> {
+    subscriber: &'subscriber Subscriber<Service, Payload, UserHeader>,
+    since: Time,
+    receive_call: ReceiveCall<Service, Payload, UserHeader>,
+}
+
+impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Iterator
+    for SamplesSince<'_, Service, Payload, UserHeader>
+{
+    type Item = Result<Sample<Service, Payload, UserHeader>, SubscriberReceiveError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match (self.receive_call)(self.subscriber) {
+                Ok(Some(sample)) => {
+                    if self.since.as_duration() <= sample.header().publish_timestamp().as_duration()
+                    {
+                        return Some(Ok(sample));
+                    }
+
+                    self.subscriber
+                        .report_dead_letter(sample.header(), DeadLetterReason::Filtered);
+                }
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// Keeps the callback that was registered with [`Subscriber::on_sample()`] registered. When it
+/// goes out of scope, the callback is removed and the [`Subscriber`] that was moved into it is
+/// dropped. Since the callback is bound to the thread that registered it, the guard cannot be
+/// sent to another thread.
+#[derive(Debug)]
+pub struct SampleCallbackGuard<Service: service::Service> {
+    id: u64,
+    _data: PhantomData<(Service, *const ())>,
+}
+
+impl<Service: service::Service> Drop for SampleCallbackGuard<Service> {
+    fn drop(&mut self) {
+        remove_sample_callback(self.id);
+    }
+}
+
 impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Drop
     for Subscriber<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
-        if let Some(handle) = self.dynamic_subscriber_handle {
-            self.publisher_connections
+        drop(self.peeked_sample.get_mut().take());
+
+        if let Some((details, offset)) = self.coalescing_lookahead.get_mut().take() {
+            details.release(self.header(offset as *const Header).sequence_number());
+        }
+
+        if let Some(registration) = self.dynamic_subscriber_registration {
+            let dynamic_config = self
+                .publisher_connections
                 .service_state
                 .dynamic_storage
                 .get()
-                .publish_subscribe()
-                .release_subscriber_handle(handle)
+                .publish_subscribe();
+            dynamic_config.cancel_sample_signal_request(registration.handle.index());
+            dynamic_config.release_subscriber(registration);
         }
     }
 }
@@ -168,12 +431,42 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             None => static_config.subscriber_max_buffer_size,
         };
 
+        let max_borrowed_samples = match config.max_borrowed_samples {
+            Some(max_borrowed_samples) => {
+                if static_config.subscriber_max_borrowed_samples < max_borrowed_samples {
+                    fail!(from origin, with SubscriberCreateError::MaxBorrowedSamplesExceedsMaxSupportedBorrowedSamplesOfService,
+                        "{} since the requested max borrowed samples {} exceed the maximum supported borrowed samples {} of the service.",
+                        msg, max_borrowed_samples, static_config.subscriber_max_borrowed_samples);
+                }
+                max_borrowed_samples
+            }
+            None => static_config.subscriber_max_borrowed_samples,
+        };
+
+        let granted_history = match config.history_request {
+            Some(history_request) => history_request.min(static_config.history_size),
+            None => static_config.history_size,
+        };
+
+        if buffer_size < granted_history {
+            if config.require_buffer_for_history {
+                fail!(from origin, with SubscriberCreateError::BufferTooSmallForHistory { buffer: buffer_size, history: granted_history },
+                    "{} since the buffer size {} is smaller than the granted history size {}.",
+                    msg, buffer_size, granted_history);
+            }
+
+            warn!(from origin,
+                "The subscriber buffer size {} is smaller than the granted history size {}. Only the last {} samples of the history will be received.",
+                buffer_size, granted_history, buffer_size);
+        }
+
         let publisher_connections = PublisherConnections::new(
             publisher_list.capacity(),
             subscriber_id,
             service.__internal_state().clone(),
             static_config,
             buffer_size,
+            max_borrowed_samples,
         );
 
         let mut new_self = Self {
@@ -187,9 +480,21 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                     .subscriber_expired_connection_buffer,
             )),
             degration_callback: config.degration_callback,
+            dead_letter_sink: config.dead_letter_sink,
             publisher_connections,
+            #[cfg(all(unix, feature = "tokio"))]
+            sample_signal_reactor: OnceCell::new(),
+            sample_signal: OnceCell::new(),
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
-            dynamic_subscriber_handle: None,
+            last_sequence_numbers: UnsafeCell::new(Vec::with_capacity(publisher_list.capacity())),
+            coalescing_lookahead: UnsafeCell::new(None),
+            peeked_sample: UnsafeCell::new(None),
+            dynamic_subscriber_registration: None,
+            resume_from: config.resume_from,
+            granted_history,
+            deadline: config.deadline,
+            last_arrival: Cell::new(None),
+            is_deadline_missed: Cell::new(false),
             static_config: service.__internal_state().static_config.clone(),
             _payload: PhantomData,
             _user_header: PhantomData,
@@ -203,7 +508,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
 
         // !MUST! be the last task otherwise a subscriber is added to the dynamic config without
         // the creation of all required channels
-        let dynamic_subscriber_handle = match service
+        let dynamic_subscriber_registration = match service
             .__internal_state()
             .dynamic_storage
             .get()
@@ -211,9 +516,14 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .add_subscriber_id(SubscriberDetails {
                 subscriber_id,
                 buffer_size,
+                max_borrowed_samples,
+                history_size: granted_history,
                 node_id: *service.__internal_state().shared_node.id(),
+                owner_start_time: Process::from_self().start_time().ok(),
+                node_name: config.node_name,
+                history_max_age: config.history_max_age,
             }) {
-            Some(unique_index) => unique_index,
+            Some(registration) => registration,
             None => {
                 fail!(from new_self, with SubscriberCreateError::ExceedsMaxSupportedSubscribers,
                                 "{} since it would exceed the maximum supported amount of subscribers of {}.",
@@ -221,7 +531,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             }
         };
 
-        new_self.dynamic_subscriber_handle = Some(dynamic_subscriber_handle);
+        new_self.dynamic_subscriber_registration = Some(dynamic_subscriber_registration);
 
         Ok(new_self)
     }
@@ -244,6 +554,9 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                         .push(connection.clone())
                 {
                     warn!(from self, "Expired connection buffer exceeded. A publisher disconnected with undelivered samples that will be discarded. Increase the config entry `defaults.publish-subscribe.subscriber-expired-connection-buffer` to mitigate the problem.");
+                    if self.dead_letter_sink.is_some() {
+                        self.discard_remaining_samples(connection);
+                    }
                 }
             }
         };
@@ -298,45 +611,178 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         Ok(())
     }
 
+    fn report_dead_letter(&self, header: &Header, reason: DeadLetterReason) {
+        if let Some(sink) = &self.dead_letter_sink {
+            sink.call(*header, reason);
+        }
+    }
+
+    /// Releases all samples that are still buffered in the connection of a disconnected
+    /// publisher and reports them as [`DeadLetterReason::Overflow`].
+    fn discard_remaining_samples(&self, connection: &Arc<Connection<Service>>) {
+        while let Ok(Some(offset)) = connection.receiver.receive() {
+            let details = SampleDetails {
+                publisher_connection: connection.clone(),
+                offset,
+                origin: connection.publisher_id,
+                acknowledge: self.static_config.publish_subscribe().has_acknowledgment(),
+            };
+
+            let header = match connection
+                .data_segment
+                .register_and_translate_offset(offset)
+            {
+                Ok(address) => self.header(address as *const Header),
+                Err(e) => {
+                    warn!(from self, "Unable to report the discarded samples of publisher {:?} since the received offset {:?} could not be registered and translated ({:?}).",
+                        connection.publisher_id, offset, e);
+                    return;
+                }
+            };
+
+            self.report_dead_letter(&header, DeadLetterReason::Overflow);
+            details.release(header.sequence_number());
+        }
+    }
+
     fn receive_from_connection(
         &self,
         connection: &Arc<Connection<Service>>,
+    ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        let candidate = self.receive_next_from_connection(connection)?;
+        match (
+            candidate,
+            self.static_config.publish_subscribe().coalescing_window(),
+        ) {
+            (Some(candidate), Some(window)) => self.coalesce(connection, candidate, window),
+            (candidate, _) => Ok(candidate),
+        }
+    }
+
+    /// Looks ahead in the buffer of the connection and releases the candidate as long as the
+    /// next sample belongs to the same coalescence group and was sent within the window that
+    /// starts with the first candidate. The window is measured with the monotonic send
+    /// timestamps, samples without one are not coalesced. A looked ahead sample that does not supersede the
+    /// candidate is kept and returned first by the next [`Subscriber::receive()`].
+    fn coalesce(
+        &self,
+        connection: &Arc<Connection<Service>>,
+        mut candidate: (SampleDetails<Service>, usize),
+        window: Duration,
+    ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        let header = |offset: usize| self.header(offset as *const Header);
+        let first = header(candidate.1);
+
+        while connection.receiver.has_data() {
+            let next = match self.receive_next_from_connection(connection) {
+                Ok(Some(next)) => next,
+                // without a spare borrow there is no lookahead, deliver without coalescing
+                Ok(None) | Err(SubscriberReceiveError::ExceedsMaxBorrowedSamples) => break,
+                Err(e) => {
+                    unsafe { *self.coalescing_lookahead.get() = Some(candidate) };
+                    return Err(e);
+                }
+            };
+
+            let next_header = header(next.1);
+            let is_superseded = next_header.coalescence_group() == first.coalescence_group()
+                && match (first.send_timestamp(), next_header.send_timestamp()) {
+                    (Some(first_sent), Some(next_sent)) => {
+                        next_sent
+                            .as_duration()
+                            .saturating_sub(first_sent.as_duration())
+                            <= window
+                    }
+                    _ => false,
+                };
+
+            if !is_superseded {
+                unsafe { *self.coalescing_lookahead.get() = Some(next) };
+                break;
+            }
+
+            let superseded = header(candidate.1);
+            self.report_dead_letter(&superseded, DeadLetterReason::Coalesced);
+            candidate.0.release(superseded.sequence_number());
+            candidate = next;
+        }
+
+        Ok(Some(candidate))
+    }
+
+    fn receive_next_from_connection(
+        &self,
+        connection: &Arc<Connection<Service>>,
     ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
         let msg = "Unable to receive another sample";
-        match connection.receiver.receive() {
-            Ok(data) => match data {
-                None => Ok(None),
-                Some(offset) => {
-                    let details = SampleDetails {
-                        publisher_connection: connection.clone(),
-                        offset,
-                        origin: connection.publisher_id,
-                    };
+        loop {
+            match connection.receiver.receive() {
+                Ok(data) => match data {
+                    None => return Ok(None),
+                    Some(offset) => {
+                        let details = SampleDetails {
+                            publisher_connection: connection.clone(),
+                            offset,
+                            origin: connection.publisher_id,
+                            acknowledge: self
+                                .static_config
+                                .publish_subscribe()
+                                .has_acknowledgment(),
+                        };
 
-                    let offset = match connection
-                        .data_segment
-                        .register_and_translate_offset(offset)
-                    {
-                        Ok(offset) => offset,
-                        Err(e) => {
-                            fail!(from self, with SubscriberReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapPublishersDataSegment(e)),
-                                "Unable to register and translate offset from publisher {:?} since the received offset {:?} could not be registered and translated.",
-                                connection.publisher_id, offset);
+                        let offset = match connection
+                            .data_segment
+                            .register_and_translate_offset(offset)
+                        {
+                            Ok(offset) => offset,
+                            Err(e) => {
+                                fail!(from self, with SubscriberReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapPublishersDataSegment(e)),
+                                    "Unable to register and translate offset from publisher {:?} since the received offset {:?} could not be registered and translated.",
+                                    connection.publisher_id, offset);
+                            }
+                        };
+
+                        if let Some((_, resume_from)) = self
+                            .resume_from
+                            .iter()
+                            .find(|(id, _)| *id == connection.publisher_id)
+                        {
+                            let header = self.header(offset as *const Header);
+                            if header.sequence_number() <= *resume_from {
+                                self.report_dead_letter(&header, DeadLetterReason::Filtered);
+                                details.release(header.sequence_number());
+                                continue;
+                            }
+                        }
+
+                        if self.publisher_connections.static_config.pin_sample_memory {
+                            if let Err(e) = details.pin() {
+                                details.release(
+                                    self.header(offset as *const Header).sequence_number(),
+                                );
+                                fail!(from self, with SubscriberReceiveError::UnableToPinSampleMemory(e),
+                                    "{} since the memory of the sample from publisher {:?} could not be pinned.",
+                                    msg, connection.publisher_id);
+                            }
                         }
-                    };
 
-                    Ok(Some((details, offset)))
+                        return Ok(Some((details, offset)));
+                    }
+                },
+                Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                    fail!(from self, with SubscriberReceiveError::ExceedsMaxBorrowedSamples,
+                        "{} since it would exceed the maximum {} of borrowed samples.",
+                        msg, connection.receiver.max_borrowed_samples());
                 }
-            },
-            Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
-                fail!(from self, with SubscriberReceiveError::ExceedsMaxBorrowedSamples,
-                    "{} since it would exceed the maximum {} of borrowed samples.",
-                    msg, connection.receiver.max_borrowed_samples());
             }
         }
     }
 
-    /// Returns the [`UniqueSubscriberId`] of the [`Subscriber`]
+    /// Returns the [`UniqueSubscriberId`] of the [`Subscriber`]. It is acquired on creation
+    /// and stays the same for the whole lifetime of the [`Subscriber`], so that it identifies
+    /// the entry of the [`Subscriber`] in the dynamic config of the
+    /// [`Service`](crate::service::Service) and in
+    /// [`Publisher::connected_subscribers()`](crate::port::publisher::Publisher::connected_subscribers()).
     pub fn id(&self) -> UniqueSubscriberId {
         self.publisher_connections.subscriber_id()
     }
@@ -346,11 +792,104 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         self.publisher_connections.buffer_size
     }
 
+    /// Returns how many [`Sample`]s the [`Subscriber`] can borrow at most from every connected
+    /// [`Publisher`](crate::port::publisher::Publisher).
+    pub fn max_borrowed_samples(&self) -> usize {
+        self.publisher_connections.max_borrowed_samples
+    }
+
+    /// Returns how many samples of the history the [`Subscriber`] receives at most from every
+    /// [`Publisher`](crate::port::publisher::Publisher) when it connects. It is the amount
+    /// requested with
+    /// [`PortFactorySubscriber::request_history()`](crate::service::port_factory::subscriber::PortFactorySubscriber::request_history())
+    /// capped to the history size of the [`Service`](crate::service::Service).
+    pub fn granted_history(&self) -> usize {
+        self.granted_history
+    }
+
+    /// Returns the deadline that was set with
+    /// [`PortFactorySubscriber::deadline()`](crate::service::port_factory::subscriber::PortFactorySubscriber::deadline()).
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// Returns true when the deadline of the [`Subscriber`] was missed since the last call,
+    /// otherwise false. The deadline is missed when two consecutive [`Sample`]s were received
+    /// further apart than the deadline or when no [`Sample`] was received within the deadline
+    /// since the last received [`Sample`]. The arrival of a [`Sample`] is measured with the
+    /// [`ClockType::Monotonic`] clock when it is received, independent of the clocks of the
+    /// [`Publisher`](crate::port::publisher::Publisher)s. The time before the first [`Sample`]
+    /// is received never counts as a missed deadline. It always returns false when no
+    /// deadline was set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let subscriber = service.subscriber_builder()
+    ///     .deadline(Duration::from_millis(100))
+    ///     .create()?;
+    ///
+    /// while let Some(sample) = subscriber.receive()? {
+    ///     println!("received: {:?}", *sample);
+    /// }
+    ///
+    /// if subscriber.deadline_missed() {
+    ///     println!("the publisher did not send within the deadline");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn deadline_missed(&self) -> bool {
+        let deadline = match self.deadline {
+            Some(deadline) => deadline,
+            None => return false,
+        };
+
+        if let Some(last_arrival) = self.last_arrival.get() {
+            if let Ok(now) = Time::now_with_clock(ClockType::Monotonic) {
+                if now.as_duration().saturating_sub(last_arrival.as_duration()) > deadline {
+                    self.is_deadline_missed.set(true);
+                    // the next deadline period starts now, so that an ongoing silence is
+                    // reported once per period
+                    self.last_arrival.set(Some(now));
+                }
+            }
+        }
+
+        self.is_deadline_missed.replace(false)
+    }
+
     /// Returns true if the [`Subscriber`] has samples in the buffer that can be received with [`Subscriber::receive`].
     pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
 
+        if unsafe { &*self.coalescing_lookahead.get() }.is_some()
+            || unsafe { &*self.peeked_sample.get() }.is_some()
+        {
+            return Ok(true);
+        }
+
+        // the connections of disconnected publishers are only queued when they contain samples
+        // and only the first one is drained by receive
+        let to_be_removed_connections = unsafe { &*self.to_be_removed_connections.get() };
+        if to_be_removed_connections.len() > 1
+            || to_be_removed_connections
+                .peek()
+                .is_some_and(|connection| connection.receiver.has_data())
+        {
+            return Ok(true);
+        }
+
         for id in 0..self.publisher_connections.len() {
             if let Some(ref connection) = &self.publisher_connections.get(id) {
                 if connection.receiver.has_data() {
@@ -362,15 +901,281 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         Ok(false)
     }
 
+    /// Returns the number of [`Sample`]s of the same publisher that were lost between the
+    /// previously tracked [`Sample`] and the [`Sample`] with the provided [`Header`]. The
+    /// first tracked [`Sample`] of a publisher and [`Sample`]s that are older than the
+    /// previously tracked one, like a redelivered history, report no gap.
+    fn track_sequence_gap(&self, header: &Header) -> u64 {
+        let last_sequence_numbers = unsafe { &mut *self.last_sequence_numbers.get() };
+        let publisher_id = header.publisher_id();
+        let sequence_number = header.sequence_number();
+
+        match last_sequence_numbers
+            .iter_mut()
+            .find(|(id, _)| *id == publisher_id)
+        {
+            Some((_, last)) => {
+                // the difference is computed modulo 2^64 so that a wrapped around sequence number
+                // is still recognized as newer
+                let distance = sequence_number.wrapping_sub(*last);
+                if distance == 0 || distance > u64::MAX / 2 {
+                    return 0;
+                }
+
+                *last = sequence_number;
+                distance - 1
+            }
+            None => {
+                if last_sequence_numbers.len() >= self.publisher_connections.capacity() {
+                    let connections = &self.publisher_connections;
+                    last_sequence_numbers.retain(|(id, _)| {
+                        (0..connections.capacity()).any(|i| {
+                            connections
+                                .get(i)
+                                .as_ref()
+                                .is_some_and(|c| c.publisher_id == *id)
+                        })
+                    });
+                }
+
+                last_sequence_numbers.push((publisher_id, sequence_number));
+                0
+            }
+        }
+    }
+
+    /// Tracks the arrival of a sample. The arrival is measured on the receiving side with the
+    /// monotonic clock, the timestamps of the publishers are not comparable when their clocks
+    /// are skewed or adjusted.
+    fn track_deadline(&self) {
+        let deadline = match self.deadline {
+            Some(deadline) => deadline,
+            None => return,
+        };
+
+        let arrival = match Time::now_with_clock(ClockType::Monotonic) {
+            Ok(arrival) => arrival,
+            Err(_) => return,
+        };
+
+        if let Some(last_arrival) = self.last_arrival.get() {
+            if arrival
+                .as_duration()
+                .saturating_sub(last_arrival.as_duration())
+                > deadline
+            {
+                self.is_deadline_missed.set(true);
+            }
+        }
+
+        self.last_arrival.set(Some(arrival));
+    }
+
+    fn take_peeked_sample(&self) -> Option<Sample<Service, Payload, UserHeader>> {
+        unsafe { &mut *self.peeked_sample.get() }.take()
+    }
+
+    fn sample_signal(&self) -> Result<&<Service::Event as Event>::Listener, SubscriberWaitError> {
+        if let Some(sample_signal) = self.sample_signal.get() {
+            return Ok(sample_signal);
+        }
+
+        let service_state = &self.publisher_connections.service_state;
+        let name = sample_signal_name(&self.id());
+        let sample_signal = fail!(from self,
+            when <Service::Event as Event>::ListenerBuilder::new(&name)
+                .config(&event_config::<Service>(service_state.shared_node.config(), service_state.directory.as_ref()))
+                .trigger_id_max(TriggerId::new(0))
+                .create(),
+            with SubscriberWaitError::UnableToCreateSampleSignal,
+            "Unable to create the sample signal \"{}\" of the subscriber.", name);
+
+        Ok(self.sample_signal.get_or_init(|| Box::new(sample_signal)))
+    }
+
+    /// Requests that the [`crate::port::publisher::Publisher`]s notify the sample signal
+    /// when they deliver the next sample. The buffer has to be checked afterwards.
+    fn request_sample_signal(&self) -> Result<(), SubscriberWaitError> {
+        let sample_signal = self.sample_signal()?;
+        // notifications of earlier requests are outdated
+        fail!(from self, when sample_signal.try_wait_all(|_| {}),
+            with SubscriberWaitError::InternalFailure,
+            "Unable to reset the sample signal of the subscriber.");
+
+        if let Some(registration) = self.dynamic_subscriber_registration {
+            self.publisher_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .request_sample_signal(registration.handle.index());
+        }
+
+        Ok(())
+    }
+
+    fn cancel_sample_signal_request(&self) {
+        if let Some(registration) = self.dynamic_subscriber_registration {
+            self.publisher_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .cancel_sample_signal_request(registration.handle.index());
+        }
+    }
+
+    fn sample_signal_file_descriptor_impl(&self) -> Result<&FileDescriptor, SubscriberWaitError>
+    where
+        <Service::Event as Event>::Listener: FileDescriptorBased,
+    {
+        Ok(self.sample_signal()?.file_descriptor())
+    }
+
+    #[cfg(all(unix, feature = "tokio"))]
+    fn sample_signal_reactor(&self) -> Result<&AsyncFd<i32>, SubscriberWaitError>
+    where
+        <Service::Event as Event>::Listener: FileDescriptorBased,
+    {
+        if let Some(sample_signal_reactor) = self.sample_signal_reactor.get() {
+            return Ok(sample_signal_reactor);
+        }
+
+        // the sample signal is owned by the subscriber and outlives the registration
+        let native_handle = unsafe { self.sample_signal_file_descriptor_impl()?.native_handle() };
+        let sample_signal_reactor = fail!(from self,
+            when AsyncFd::with_interest(native_handle, Interest::READABLE),
+            with SubscriberWaitError::InternalFailure,
+            "Unable to register the sample signal of the subscriber with the reactor of the tokio runtime.");
+
+        Ok(self
+            .sample_signal_reactor
+            .get_or_init(|| sample_signal_reactor))
+    }
+
+    #[cfg(all(unix, feature = "tokio"))]
+    async fn receive_async_impl<T>(
+        &self,
+        receive_call: fn(&Self) -> Result<Option<T>, SubscriberReceiveError>,
+    ) -> Result<T, SubscriberWaitError>
+    where
+        <Service::Event as Event>::Listener: FileDescriptorBased,
+    {
+        if let Some(sample) = receive_call(self)? {
+            return Ok(sample);
+        }
+
+        let sample_signal = self.sample_signal_reactor()?;
+        loop {
+            let _sample_signal_request = ScopeGuardBuilder::new(self)
+                .on_init(|subscriber| subscriber.request_sample_signal())
+                .on_drop(|subscriber| subscriber.cancel_sample_signal_request())
+                .create()?;
+
+            if let Some(sample) = receive_call(self)? {
+                return Ok(sample);
+            }
+
+            // the readiness is cleared before the buffer is checked again, a notification
+            // that arrives afterwards wakes the task once more
+            let mut is_readable = fail!(from self, when sample_signal.readable().await,
+                with SubscriberWaitError::InternalFailure,
+                "Unable to wait on the sample signal of the subscriber.");
+            is_readable.clear_ready();
+        }
+    }
+
+    fn receive_timeout_impl<T>(
+        &self,
+        timeout: Duration,
+        receive_call: fn(&Self) -> Result<Option<T>, SubscriberReceiveError>,
+    ) -> Result<Option<T>, SubscriberReceiveTimeoutError> {
+        let msg = "Unable to receive a sample with a timeout";
+        if let Some(sample) = receive_call(self)? {
+            return Ok(Some(sample));
+        }
+
+        // Time::now() falls back to the realtime clock when no monotonic clock is available
+        let start = fail!(from self,
+            when Time::now(),
+            with SubscriberReceiveTimeoutError::InternalFailure,
+            "{} since the current time could not be acquired.", msg);
+
+        loop {
+            let _sample_signal_request = ScopeGuardBuilder::new(self)
+                .on_init(|subscriber| subscriber.request_sample_signal())
+                .on_drop(|subscriber| subscriber.cancel_sample_signal_request())
+                .create()?;
+
+            if let Some(sample) = receive_call(self)? {
+                return Ok(Some(sample));
+            }
+
+            let elapsed = start.elapsed().unwrap_or(timeout);
+            if timeout <= elapsed {
+                return Ok(None);
+            }
+
+            match self.sample_signal()?.timed_wait_one(timeout - elapsed) {
+                Ok(_) => (),
+                Err(ListenerWaitError::InterruptSignal) => {
+                    fail!(from self, with SubscriberReceiveTimeoutError::InterruptSignal,
+                        "{} since an interrupt signal was received.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with SubscriberReceiveTimeoutError::InternalFailure,
+                        "{} due to an internal failure while waiting for new samples ({:?}).", msg, e);
+                }
+            }
+        }
+    }
+
+    fn peek_impl(
+        &mut self,
+        receive_call: ReceiveCall<Service, Payload, UserHeader>,
+    ) -> Result<Option<&Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        if self.peeked_sample.get_mut().is_none() {
+            let sample = receive_call(self)?;
+            *self.peeked_sample.get_mut() = sample;
+        }
+
+        Ok(self.peeked_sample.get_mut().as_ref())
+    }
+
     fn receive_impl(
         &self,
     ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        let sample = self.receive_next_sample()?;
+        if sample.is_some() {
+            self.track_deadline();
+        }
+
+        Ok(sample)
+    }
+
+    fn receive_next_sample(
+        &self,
+    ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        enter_span!(TRACE, "receive", service = %self.static_config.name(), port_id = self.id().value());
+        if self.is_disconnected() {
+            fail!(from self, with SubscriberReceiveError::Disconnected,
+                "Unable to receive samples since the subscriber was disconnected by a publisher.");
+        }
+
         if let Err(e) = self.update_connections() {
             fail!(from self,
                 with SubscriberReceiveError::ConnectionFailure(e),
                 "Some samples are not being received since not all connections to publishers could be established.");
         }
 
+        if let Some(lookahead) = unsafe { &mut *self.coalescing_lookahead.get() }.take() {
+            let connection = lookahead.0.publisher_connection.clone();
+            return match self.static_config.publish_subscribe().coalescing_window() {
+                Some(window) => self.coalesce(&connection, lookahead, window),
+                None => Ok(Some(lookahead)),
+            };
+        }
+
         let to_be_removed_connections = unsafe { &mut *self.to_be_removed_connections.get() };
 
         if let Some(connection) = to_be_removed_connections.peek() {
@@ -394,6 +1199,129 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         Ok(None)
     }
 
+    fn is_disconnected(&self) -> bool {
+        self.dynamic_subscriber_registration
+            .is_some_and(|registration| {
+                !self
+                    .publisher_connections
+                    .service_state
+                    .dynamic_storage
+                    .get()
+                    .publish_subscribe()
+                    .is_subscriber_registered(registration)
+            })
+    }
+
+    fn on_sample_impl<F: FnMut(&Sample<Service, Payload, UserHeader>) + 'static>(
+        self,
+        receive_call: ReceiveCall<Service, Payload, UserHeader>,
+        mut callback: F,
+    ) -> SampleCallbackGuard<Service>
+    where
+        Service: 'static,
+        UserHeader: 'static,
+    {
+        let node_id = *self.publisher_connections.service_state.shared_node.id();
+
+        let id = register_sample_callback(
+            node_id,
+            Box::new(move || {
+                // samples that arrive while dispatching are handled in the next dispatch
+                let max_number_of_samples = self.publisher_connections.len() * self.buffer_size();
+                for _ in 0..max_number_of_samples {
+                    match receive_call(&self) {
+                        Ok(Some(sample)) => callback(&sample),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to call the sample callback since a sample could not be received ({:?}).", e);
+                            break;
+                        }
+                    }
+                }
+            }),
+        );
+
+        SampleCallbackGuard {
+            id,
+            _data: PhantomData,
+        }
+    }
+
+    fn samples_since_impl(
+        &self,
+        since: Time,
+        receive_call: ReceiveCall<Service, Payload, UserHeader>,
+    ) -> Result<SamplesSince<'_, Service, Payload, UserHeader>, SamplesSinceError> {
+        if since.clock_type() != ClockType::Realtime {
+            fail!(from self, with SamplesSinceError::IncompatibleClockType,
+                "Unable to receive the samples since {:?} since the publish timestamps are acquired with the realtime clock.",
+                since);
+        }
+
+        Ok(SamplesSince {
+            subscriber: self,
+            since,
+            receive_call,
+        })
+    }
+
+    fn receive_latest_impl(
+        &self,
+        receive_call: ReceiveCall<Service, Payload, UserHeader>,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        // samples that arrive while draining must not prolong the call indefinitely
+        let max_number_of_samples = self.publisher_connections.len() * self.buffer_size();
+        let mut latest: Option<Sample<Service, Payload, UserHeader>> = None;
+
+        for _ in 0..max_number_of_samples {
+            // the next sample of the same publisher is always newer, releasing the held one
+            // first frees its borrow slot
+            if latest
+                .as_ref()
+                .is_some_and(|s| s.details.publisher_connection.receiver.has_data())
+            {
+                if let Some(held) = latest.take() {
+                    self.report_dead_letter(held.header(), DeadLetterReason::Superseded);
+                }
+            }
+
+            let sample = match receive_call(self) {
+                Ok(Some(sample)) => sample,
+                Ok(None) => break,
+                // the held sample occupies the last borrow slot of its publisher
+                Err(SubscriberReceiveError::ExceedsMaxBorrowedSamples) if latest.is_some() => break,
+                Err(e) => return Err(e),
+            };
+
+            let is_newer = match &latest {
+                Some(current) => is_newer_sample(current.header(), sample.header()),
+                None => true,
+            };
+
+            if is_newer {
+                if let Some(held) = latest.replace(sample) {
+                    self.report_dead_letter(held.header(), DeadLetterReason::Superseded);
+                }
+            } else {
+                self.report_dead_letter(sample.header(), DeadLetterReason::Superseded);
+            }
+        }
+
+        Ok(latest)
+    }
+
+    fn header(&self, header: *const Header) -> Header {
+        // SAFETY: every received offset points to the header of a sample of the service
+        unsafe {
+            Header::read_from_sample(
+                header,
+                self.publisher_connections
+                    .static_config
+                    .has_send_timestamp(),
+            )
+        }
+    }
+
     fn payload_ptr(&self, header: *const Header) -> *const u8 {
         self.publisher_connections
             .static_config
@@ -411,6 +1339,22 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
     }
 }
 
+/// Returns true when the `candidate` was sent after the `current` sample. The samples of one
+/// [`crate::port::publisher::Publisher`] are ordered by their sequence number, the samples of
+/// different [`crate::port::publisher::Publisher`]s by their monotonic send timestamp. When one
+/// of them was sent without send timestamp they cannot be ordered and the `candidate`, which was
+/// received later, is considered to be newer.
+fn is_newer_sample(current: &Header, candidate: &Header) -> bool {
+    if current.publisher_id() == candidate.publisher_id() {
+        return current.sequence_number() <= candidate.sequence_number();
+    }
+
+    match (current.send_timestamp(), candidate.send_timestamp()) {
+        (Some(current), Some(candidate)) => current.as_duration() <= candidate.as_duration(),
+        _ => true,
+    }
+}
+
 impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> UpdateConnections
     for Subscriber<Service, Payload, UserHeader>
 {
@@ -440,6 +1384,10 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     pub fn receive(
         &self,
     ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        if let Some(sample) = self.take_peeked_sample() {
+            return Ok(Some(sample));
+        }
+
         Ok(self.receive_impl()?.map(|(details, absolute_address)| {
             let header_ptr = absolute_address as *const Header;
             let user_header_ptr = self.user_header_ptr(header_ptr).cast();
@@ -447,9 +1395,380 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
             Sample {
                 details,
                 ptr: unsafe { RawSample::new_unchecked(header_ptr, user_header_ptr, payload_ptr) },
+                header: self.header(header_ptr),
             }
         }))
     }
+
+    /// Returns a reference to the next [`crate::sample::Sample`] without consuming it. The
+    /// next call of [`Subscriber::receive()`], or of any other receive method, returns the
+    /// same [`crate::sample::Sample`]. If no sample could be received [`None`] is returned.
+    /// If a failure occurs [`SubscriberReceiveError`] is returned.
+    ///
+    /// The peeked [`crate::sample::Sample`] is already taken from the buffer of the
+    /// [`Subscriber`]. Therefore, it counts against
+    /// [`Subscriber::max_borrowed_samples()`] and stays valid when the
+    /// [`crate::port::publisher::Publisher`] overflows the buffer in the meantime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// let mut subscriber = service.subscriber_builder().create()?;
+    ///
+    /// let is_relevant = subscriber.peek()?.is_some_and(|sample| *sample.payload() > 100);
+    /// if is_relevant {
+    ///     let sample = subscriber.receive()?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn peek(
+        &mut self,
+    ) -> Result<Option<&Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        self.peek_impl(Self::receive)
+    }
+
+    /// Returns the [`FileDescriptor`] of the sample signal of the [`Subscriber`]. It becomes
+    /// readable when a [`crate::port::publisher::Publisher`] delivers a sample while the
+    /// [`Subscriber`] waits for it, for instance in [`Subscriber::receive_timeout()`] or, with
+    /// the `tokio` feature, in `Subscriber::receive_async()`.
+    ///
+    /// The sample signal is created with the first call, on failure it returns
+    /// [`SubscriberWaitError`].
+    pub fn sample_signal_file_descriptor(&self) -> Result<&FileDescriptor, SubscriberWaitError>
+    where
+        <Service::Event as Event>::Listener: FileDescriptorBased,
+    {
+        self.sample_signal_file_descriptor_impl()
+    }
+
+    /// Asynchronously receives the next [`crate::sample::Sample`] from a
+    /// [`crate::port::publisher::Publisher`]. The returned future completes as soon as a
+    /// sample is available or when a failure occurs, in which case [`SubscriberWaitError`]
+    /// is returned.
+    ///
+    /// When no sample is available, it requests a notification of the sample signal from the
+    /// [`crate::port::publisher::Publisher`]s and the task sleeps in the reactor of the tokio
+    /// runtime until the [`Subscriber::sample_signal_file_descriptor()`] becomes readable.
+    /// Therefore, the future must be polled by a tokio runtime with enabled I/O. Requires
+    /// the `tokio` feature and a unix platform.
+    ///
+    /// The future is cancellation-safe. A sample is only taken from the underlying connection
+    /// in the poll that completes the future, therefore dropping the future before completion
+    /// never loses a sample.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::port::subscriber::Subscriber;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// async fn process(
+    ///     subscriber: &Subscriber<ipc::Service, u64, ()>,
+    /// ) -> Result<(), Box<dyn std::error::Error>> {
+    ///     // the task sleeps until a publisher delivers a sample
+    ///     let sample = subscriber.receive_async().await?;
+    ///     println!("received: {:?}", sample.payload());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(all(unix, feature = "tokio"))]
+    pub async fn receive_async(
+        &self,
+    ) -> Result<Sample<Service, Payload, UserHeader>, SubscriberWaitError>
+    where
+        <Service::Event as Event>::Listener: FileDescriptorBased,
+    {
+        self.receive_async_impl(Self::receive).await
+    }
+
+    /// Receives a [`crate::sample::Sample`] from a [`crate::port::publisher::Publisher`] and
+    /// waits at most `timeout` until one arrives. When a [`crate::sample::Sample`] is already
+    /// available, it returns immediately. Otherwise it blocks, without polling, on the sample
+    /// signal of the [`Subscriber`], see [`Subscriber::sample_signal_file_descriptor()`], until
+    /// a [`crate::port::publisher::Publisher`] delivers a sample to it. [`None`] is returned
+    /// when the `timeout`, measured with the monotonic clock or the realtime clock when no
+    /// monotonic clock is available, has passed.
+    ///
+    /// On failure it returns [`SubscriberReceiveTimeoutError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// match subscriber.receive_timeout(Duration::from_millis(10))? {
+    ///     Some(sample) => println!("received: {:?}", *sample),
+    ///     None => println!("no sample arrived within the timeout"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveTimeoutError> {
+        self.receive_timeout_impl(timeout, Self::receive)
+    }
+
+    /// Moves the [`Subscriber`] into a callback that is called for every
+    /// [`crate::sample::Sample`] that arrived, instead of receiving them with
+    /// [`Subscriber::receive()`]. The callback is called synchronously in
+    /// [`Node::wait()`](crate::node::Node::wait()) of its [`Node`](crate::node::Node) and must
+    /// therefore not block. It is bound to the thread that registered it and is only called
+    /// when [`Node::wait()`](crate::node::Node::wait()) is called on this thread. The [`crate::sample::Sample`] is borrowed only for the duration of
+    /// the callback and released when it returns.
+    ///
+    /// The callback stays registered as long as the returned [`SampleCallbackGuard`] lives.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// let _guard = subscriber.on_sample(|sample| println!("received: {:?}", **sample));
+    ///
+    /// # let mut n = 0;
+    /// while node.wait(Duration::from_millis(100)).is_ok() {
+    ///     // received samples were already handled by the callback
+    /// #   n += 1; if n == 2 { break; }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_sample<F: FnMut(&Sample<Service, Payload, UserHeader>) + 'static>(
+        self,
+        callback: F,
+    ) -> SampleCallbackGuard<Service>
+    where
+        Service: 'static,
+        UserHeader: 'static,
+    {
+        self.on_sample_impl(Self::receive, callback)
+    }
+
+    /// Returns an iterator that receives all buffered [`crate::sample::Sample`]s that were
+    /// published at or after `since`. Older [`crate::sample::Sample`]s are received and
+    /// discarded. `since` must be acquired with [`Time::now_realtime()`], the same clock that
+    /// is used for the [`Header::publish_timestamp()`], otherwise
+    /// [`SamplesSinceError::IncompatibleClockType`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_bb_posix::clock::Time;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// let since = Time::now_realtime().unwrap();
+    ///
+    /// for sample in subscriber.samples_since(since)? {
+    ///     println!("received: {:?}", *sample?);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn samples_since(
+        &self,
+        since: Time,
+    ) -> Result<SamplesSince<'_, Service, Payload, UserHeader>, SamplesSinceError> {
+        self.samples_since_impl(since, Self::receive)
+    }
+
+    /// Returns a copy of the [`Header`] of the next [`crate::sample::Sample`] without
+    /// consuming it. The [`crate::sample::Sample`] is peeked like with [`Subscriber::peek()`],
+    /// so the next call of [`Subscriber::receive()`], or of any other receive method, returns
+    /// it with its payload. If no sample could be received [`None`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let mut subscriber = service.subscriber_builder().create()?;
+    ///
+    /// if let Some(header) = subscriber.sample_metadata()? {
+    ///     println!("sent by {:?} at {:?}", header.publisher_id(), header.publish_timestamp());
+    ///     let sample = subscriber.receive()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sample_metadata(&mut self) -> Result<Option<Header>, SubscriberReceiveError> {
+        Ok(self
+            .peek_impl(Self::receive)?
+            .map(|sample| *sample.header()))
+    }
+
+    /// Receives all currently buffered [`crate::sample::Sample`]s in a single call and returns
+    /// only the newest one. All older [`crate::sample::Sample`]s are released to their
+    /// [`crate::port::publisher::Publisher`]s, so that a consumer that is slower than the
+    /// [`crate::port::publisher::Publisher`]s never processes outdated data. If no sample could
+    /// be received [`None`] is returned.
+    ///
+    /// The [`crate::sample::Sample`]s of one [`crate::port::publisher::Publisher`] are ordered
+    /// by their [`Header::sequence_number()`]. The [`crate::sample::Sample`]s of different
+    /// [`crate::port::publisher::Publisher`]s are ordered by their
+    /// [`Header::send_timestamp()`], when one of them has none the
+    /// [`crate::sample::Sample`] that was received last is considered to be newer.
+    ///
+    /// The newest [`crate::sample::Sample`] is held while the remaining buffer is drained. When
+    /// it occupies the last borrow slot of its [`crate::port::publisher::Publisher`], see
+    /// [`Subscriber::max_borrowed_samples()`], the draining stops early and the
+    /// [`crate::sample::Sample`]s of other [`crate::port::publisher::Publisher`]s stay buffered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// // every control cycle processes only the most recent value
+    /// if let Some(sample) = subscriber.receive_latest()? {
+    ///     println!("latest value: {:?}", *sample);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive_latest(
+        &self,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        self.receive_latest_impl(Self::receive)
+    }
+
+    /// Receives a [`crate::sample::Sample`] like [`Subscriber::receive()`] and additionally
+    /// returns the number of [`crate::sample::Sample`]s of the same
+    /// [`crate::port::publisher::Publisher`] that were lost since the last
+    /// [`crate::sample::Sample`] of this [`crate::port::publisher::Publisher`] that was received
+    /// with this method. Gaps are detected with the [`Header::sequence_number()`] and are
+    /// tracked for every [`crate::port::publisher::Publisher`] separately. Samples received with
+    /// any other receive method are not tracked and are therefore reported as gap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// while let Some((sample, gap)) = subscriber.receive_with_gap_info()? {
+    ///     if gap != 0 {
+    ///         println!("lost {} samples of {:?}", gap, sample.header().publisher_id());
+    ///     }
+    ///     println!("received: {:?}", *sample);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive_with_gap_info(
+        &self,
+    ) -> Result<SampleWithGapInfo<Service, Payload, UserHeader>, SubscriberReceiveError> {
+        Ok(self.receive()?.map(|sample| {
+            let gap = self.track_sequence_gap(sample.header());
+            (sample, gap)
+        }))
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + Clone, UserHeader: Debug>
+    Subscriber<Service, Payload, UserHeader>
+{
+    /// Receives all currently buffered [`crate::sample::Sample`]s, copies their payload into a
+    /// [`Vec`] and releases every [`crate::sample::Sample`] immediately. Therefore, the number
+    /// of drained payloads is not limited by
+    /// [`StaticConfig::subscriber_max_borrowed_samples()`] but by the buffers of all connected
+    /// [`crate::port::publisher::Publisher`]s.
+    ///
+    /// Every call allocates the [`Vec`] on the heap and clones every payload, so it trades the
+    /// zero-copy property for the convenience of batch processing. Use
+    /// [`Subscriber::receive()`] when the payload shall be processed in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// let batch = subscriber.drain_to_vec()?;
+    /// println!("received a batch of {} samples", batch.len());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn drain_to_vec(&self) -> Result<Vec<Payload>, SubscriberReceiveError> {
+        // samples that arrive while draining must not prolong the call indefinitely
+        let max_number_of_samples = self.publisher_connections.len() * self.buffer_size();
+        let mut payloads = Vec::new();
+
+        while payloads.len() < max_number_of_samples {
+            match self.receive()? {
+                Some(sample) => payloads.push(sample.payload().clone()),
+                None => break,
+            }
+        }
+
+        Ok(payloads)
+    }
 }
 
 impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
@@ -462,11 +1781,16 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
         debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
 
+        if let Some(sample) = self.take_peeked_sample() {
+            return Ok(Some(sample));
+        }
+
         Ok(self.receive_impl()?.map(|(details, absolute_address)| {
             let header_ptr = absolute_address as *const Header;
             let user_header_ptr = self.user_header_ptr(header_ptr).cast();
             let payload_ptr = self.payload_ptr(header_ptr).cast();
-            let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+            let header = self.header(header_ptr);
+            let number_of_elements = header.number_of_elements();
 
             Sample {
                 details,
@@ -477,9 +1801,100 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
                         core::slice::from_raw_parts(payload_ptr, number_of_elements as _),
                     )
                 },
+                header,
             }
         }))
     }
+
+    /// Returns a reference to the next [`crate::sample::Sample`] without consuming it. The
+    /// next call of [`Subscriber::receive()`], or of any other receive method, returns the
+    /// same [`crate::sample::Sample`], see
+    /// [`Subscriber::peek()`](Subscriber#method.peek) for the details.
+    #[allow(clippy::type_complexity)]
+    pub fn peek(
+        &mut self,
+    ) -> Result<Option<&Sample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
+        self.peek_impl(Self::receive)
+    }
+
+    /// See [`Subscriber::sample_signal_file_descriptor()`].
+    pub fn sample_signal_file_descriptor(&self) -> Result<&FileDescriptor, SubscriberWaitError>
+    where
+        <Service::Event as Event>::Listener: FileDescriptorBased,
+    {
+        self.sample_signal_file_descriptor_impl()
+    }
+
+    /// See [`Subscriber::receive_async()`].
+    #[cfg(all(unix, feature = "tokio"))]
+    pub async fn receive_async(
+        &self,
+    ) -> Result<Sample<Service, [Payload], UserHeader>, SubscriberWaitError>
+    where
+        <Service::Event as Event>::Listener: FileDescriptorBased,
+    {
+        self.receive_async_impl(Self::receive).await
+    }
+
+    /// See [`Subscriber::receive_timeout()`].
+    pub fn receive_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, SubscriberReceiveTimeoutError> {
+        self.receive_timeout_impl(timeout, Self::receive)
+    }
+
+    /// See [`Subscriber::on_sample()`].
+    pub fn on_sample<F: FnMut(&Sample<Service, [Payload], UserHeader>) + 'static>(
+        self,
+        callback: F,
+    ) -> SampleCallbackGuard<Service>
+    where
+        Service: 'static,
+        UserHeader: 'static,
+    {
+        self.on_sample_impl(Self::receive, callback)
+    }
+
+    /// See [`Subscriber::samples_since()`](Subscriber#method.samples_since).
+    pub fn samples_since(
+        &self,
+        since: Time,
+    ) -> Result<SamplesSince<'_, Service, [Payload], UserHeader>, SamplesSinceError> {
+        self.samples_since_impl(since, Self::receive)
+    }
+
+    /// Returns a copy of the [`Header`] of the next [`crate::sample::Sample`] without
+    /// consuming it, see
+    /// [`Subscriber::sample_metadata()`](Subscriber#method.sample_metadata) for the details.
+    pub fn sample_metadata(&mut self) -> Result<Option<Header>, SubscriberReceiveError> {
+        Ok(self
+            .peek_impl(Self::receive)?
+            .map(|sample| *sample.header()))
+    }
+
+    /// Receives all currently buffered [`crate::sample::Sample`]s in a single call and returns
+    /// only the newest one, see
+    /// [`Subscriber::receive_latest()`](Subscriber#method.receive_latest) for the details.
+    pub fn receive_latest(
+        &self,
+    ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
+        self.receive_latest_impl(Self::receive)
+    }
+
+    /// Receives a [`crate::sample::Sample`] like [`Subscriber::receive()`] and additionally
+    /// returns the number of [`crate::sample::Sample`]s of the same
+    /// [`crate::port::publisher::Publisher`] that were lost since the last
+    /// [`crate::sample::Sample`] of this [`crate::port::publisher::Publisher`] that was received
+    /// with this method.
+    pub fn receive_with_gap_info(
+        &self,
+    ) -> Result<SampleWithGapInfo<Service, [Payload], UserHeader>, SubscriberReceiveError> {
+        Ok(self.receive()?.map(|sample| {
+            let gap = self.track_sequence_gap(sample.header());
+            (sample, gap)
+        }))
+    }
 }
 
 impl<Service: service::Service, UserHeader: Debug>
@@ -503,7 +1918,8 @@ impl<Service: service::Service, UserHeader: Debug>
             let header_ptr = absolute_address as *const Header;
             let user_header_ptr = self.user_header_ptr(header_ptr).cast();
             let payload_ptr = self.payload_ptr(header_ptr).cast();
-            let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+            let header = self.header(header_ptr);
+            let number_of_elements = header.number_of_elements();
             let number_of_bytes = number_of_elements as usize
                 * self
                     .static_config
@@ -521,7 +1937,27 @@ impl<Service: service::Service, UserHeader: Debug>
                         core::slice::from_raw_parts(payload_ptr, number_of_bytes),
                     )
                 },
+                header,
             }
         }))
     }
 }
+
+pub(crate) unsafe fn remove_sample_signal_of_subscriber<Service: service::Service>(
+    subscriber_id: &UniqueSubscriberId,
+    config: &config::Config,
+    directory: Option<&Path>,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_sample_signal_of_subscriber::<{}>({:?})",
+        core::any::type_name::<Service>(),
+        subscriber_id
+    );
+    let msg = "Unable to remove the sample signal of the subscriber";
+    let name = sample_signal_name(subscriber_id);
+
+    fail!(from origin,
+            when <Service::Event as NamedConceptMgmt>::remove_cfg(&name, &event_config::<Service>(config, directory)),
+            "{} since the underlying concept could not be removed.", msg);
+    Ok(())
+}
@@ -0,0 +1,339 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::wildcard_subscriber::WildcardSubscriber;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! // connects to "sensor/camera/0", "sensor/camera/1", ... as soon as they appear
+//! let mut subscriber =
+//!     WildcardSubscriber::<_, u64, ()>::new(&node, "sensor/camera/*", Duration::from_millis(500));
+//!
+//! while let Some((service_name, sample)) = subscriber.receive()? {
+//!     println!("received {:?} from {}", *sample, service_name);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use core::fmt::Debug;
+use core::time::Duration;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::{debug, warn};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+
+use crate::node::{Node, SharedNode};
+use crate::sample::Sample;
+use crate::service::builder::publish_subscribe::PublishSubscribeOpenError;
+use crate::service::builder::Builder;
+use crate::service::port_factory::publish_subscribe::PortFactory;
+use crate::service::port_factory::PortFactory as _;
+use crate::service::service_name::ServiceName;
+use crate::service::static_config::messaging_pattern::MessagingPattern;
+use crate::service::{self, ServiceListError};
+
+use super::subscriber::{Subscriber, SubscriberReceiveError};
+
+type OpenCall<Service, Payload, UserHeader> =
+    fn(
+        &Arc<SharedNode<Service>>,
+        &ServiceName,
+    ) -> Result<PortFactory<Service, Payload, UserHeader>, PublishSubscribeOpenError>;
+
+#[derive(Debug)]
+struct DiscoveredService<
+    Service: service::Service,
+    Payload: Debug + ?Sized + 'static,
+    UserHeader: Debug,
+> {
+    name: ServiceName,
+    service: PortFactory<Service, Payload, UserHeader>,
+    subscriber: Subscriber<Service, Payload, UserHeader>,
+}
+
+/// Receives from all publish-subscribe [`Service`](crate::service::Service)s whose
+/// [`ServiceName`] matches a pattern. The pattern may contain `*` which matches any sequence of
+/// characters, including `/`, therefore `"sensor/*"` matches `"sensor/camera/0"` as well as
+/// `"sensor/lidar"`.
+///
+/// The [`Service`](crate::service::Service)s are discovered with
+/// [`Service::list()`](crate::service::Service::list()) on every call to
+/// [`WildcardSubscriber::update_services()`] and on [`WildcardSubscriber::receive()`] when
+/// the scan interval has passed since the last scan. A matching
+/// [`Service`](crate::service::Service) with the same payload and user header type is connected
+/// as soon as it has at least one [`crate::port::publisher::Publisher`]. Its [`Subscriber`] is
+/// closed again when the [`Service`](crate::service::Service) is no longer listed or when all
+/// of its [`crate::port::publisher::Publisher`]s are gone, but not before all samples it has
+/// already received were consumed. As long as the [`Subscriber`] is
+/// connected, it keeps the [`Service`](crate::service::Service) alive.
+#[derive(Debug)]
+pub struct WildcardSubscriber<
+    Service: service::Service,
+    Payload: Debug + ?Sized + 'static,
+    UserHeader: Debug,
+> {
+    node: Arc<SharedNode<Service>>,
+    pattern: String,
+    scan_interval: Duration,
+    last_scan: Option<Time>,
+    services: Vec<DiscoveredService<Service, Payload, UserHeader>>,
+    next_service: usize,
+    open_call: OpenCall<Service, Payload, UserHeader>,
+}
+
+impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
+    WildcardSubscriber<Service, Payload, UserHeader>
+{
+    fn new_impl(
+        node: &Node<Service>,
+        pattern: &str,
+        scan_interval: Duration,
+        open_call: OpenCall<Service, Payload, UserHeader>,
+    ) -> Self {
+        Self {
+            node: node.shared_node().clone(),
+            pattern: pattern.to_string(),
+            scan_interval,
+            last_scan: None,
+            services: Vec::new(),
+            next_service: 0,
+            open_call,
+        }
+    }
+
+    /// Returns the pattern the [`ServiceName`]s are matched against.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the interval in which [`WildcardSubscriber::receive()`] scans for new
+    /// [`Service`](crate::service::Service)s.
+    pub fn scan_interval(&self) -> Duration {
+        self.scan_interval
+    }
+
+    /// Returns the [`ServiceName`]s of all currently connected
+    /// [`Service`](crate::service::Service)s.
+    pub fn connected_services(&self) -> Vec<ServiceName> {
+        self.services
+            .iter()
+            .map(|service| service.name.clone())
+            .collect()
+    }
+
+    /// Scans for matching [`Service`](crate::service::Service)s immediately, connects to the
+    /// new ones and closes the [`Subscriber`]s of the
+    /// [`Service`](crate::service::Service)s that disappeared and have no more samples to
+    /// receive.
+    pub fn update_services(&mut self) -> Result<(), ServiceListError> {
+        self.last_scan = Time::now_with_clock(ClockType::Monotonic).ok();
+
+        let mut listed_names = Vec::new();
+        Service::list(self.node.config(), |details| {
+            if let MessagingPattern::PublishSubscribe(_) =
+                details.static_details.messaging_pattern()
+            {
+                let name = details.static_details.name();
+                if matches_pattern(&self.pattern, name.as_str()) {
+                    listed_names.push(name.clone());
+                }
+            }
+            CallbackProgression::Continue
+        })?;
+
+        self.services.retain(|service| {
+            let is_active = listed_names.contains(&service.name)
+                && service.service.dynamic_config().number_of_publishers() != 0;
+            // samples that were delivered before the publishers disappeared must stay receivable
+            is_active || service.subscriber.has_samples().unwrap_or(false)
+        });
+
+        for name in listed_names {
+            if self.services.iter().any(|service| service.name == name) {
+                continue;
+            }
+
+            let service = match (self.open_call)(&self.node, &name) {
+                Ok(service) => service,
+                Err(e) => {
+                    debug!(from self, "Skipping the matching service \"{}\" since it could not be opened ({:?}).", name, e);
+                    continue;
+                }
+            };
+
+            if service.dynamic_config().number_of_publishers() == 0 {
+                continue;
+            }
+
+            match service.subscriber_builder().create() {
+                Ok(subscriber) => self.services.push(DiscoveredService {
+                    name,
+                    service,
+                    subscriber,
+                }),
+                Err(e) => {
+                    warn!(from self, "Unable to connect to the matching service \"{}\" since the subscriber could not be created ({:?}).", name, e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn update_services_when_due(&mut self) {
+        let is_due = match self.last_scan {
+            None => true,
+            Some(last_scan) => last_scan
+                .elapsed()
+                .map_or(true, |elapsed| self.scan_interval <= elapsed),
+        };
+
+        if is_due {
+            if let Err(e) = self.update_services() {
+                warn!(from self, "Unable to scan for matching services ({:?}), the scan is repeated after the scan interval.", e);
+            }
+        }
+    }
+
+    fn receive_impl<
+        T,
+        F: FnMut(
+            &Subscriber<Service, Payload, UserHeader>,
+        ) -> Result<Option<T>, SubscriberReceiveError>,
+    >(
+        &mut self,
+        mut receive_call: F,
+    ) -> Result<Option<(ServiceName, T)>, SubscriberReceiveError> {
+        self.update_services_when_due();
+
+        let number_of_services = self.services.len();
+        let start = self.next_service;
+        let mut first_error = None;
+
+        for n in 0..number_of_services {
+            let index = (start + n) % number_of_services;
+            let service = &self.services[index];
+            match receive_call(&service.subscriber) {
+                Ok(Some(sample)) => {
+                    self.next_service = (index + 1) % number_of_services;
+                    return Ok(Some((service.name.clone(), sample)));
+                }
+                Ok(None) => (),
+                Err(e) => {
+                    if first_error.is_none() {
+                        first_error = Some(e);
+                    }
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
+    WildcardSubscriber<Service, Payload, UserHeader>
+{
+    /// Creates a new [`WildcardSubscriber`] that receives from all
+    /// [`Service`](crate::service::Service)s whose [`ServiceName`] matches the `pattern` and
+    /// scans for new [`Service`](crate::service::Service)s every `scan_interval`.
+    pub fn new(node: &Node<Service>, pattern: &str, scan_interval: Duration) -> Self {
+        Self::new_impl(node, pattern, scan_interval, |node, name| {
+            Builder::new(name, node.clone())
+                .publish_subscribe::<Payload>()
+                .user_header::<UserHeader>()
+                .open()
+        })
+    }
+
+    /// Receives a [`Sample`] from one of the connected [`Service`](crate::service::Service)s
+    /// together with the [`ServiceName`] of its source. If no sample could be received
+    /// [`None`] is returned. A [`SubscriberReceiveError`] is only returned when none of the
+    /// [`Subscriber`]s delivered a [`Sample`] and at least one of them failed.
+    #[allow(clippy::type_complexity)]
+    pub fn receive(
+        &mut self,
+    ) -> Result<Option<(ServiceName, Sample<Service, Payload, UserHeader>)>, SubscriberReceiveError>
+    {
+        self.receive_impl(|subscriber| subscriber.receive())
+    }
+}
+
+impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
+    WildcardSubscriber<Service, [Payload], UserHeader>
+{
+    /// Creates a new [`WildcardSubscriber`] that receives from all
+    /// [`Service`](crate::service::Service)s whose [`ServiceName`] matches the `pattern` and
+    /// scans for new [`Service`](crate::service::Service)s every `scan_interval`.
+    pub fn new(node: &Node<Service>, pattern: &str, scan_interval: Duration) -> Self {
+        Self::new_impl(node, pattern, scan_interval, |node, name| {
+            Builder::new(name, node.clone())
+                .publish_subscribe::<[Payload]>()
+                .user_header::<UserHeader>()
+                .open()
+        })
+    }
+
+    /// Receives a [`Sample`] from one of the connected [`Service`](crate::service::Service)s
+    /// together with the [`ServiceName`] of its source. If no sample could be received
+    /// [`None`] is returned. A [`SubscriberReceiveError`] is only returned when none of the
+    /// [`Subscriber`]s delivered a [`Sample`] and at least one of them failed.
+    #[allow(clippy::type_complexity)]
+    pub fn receive(
+        &mut self,
+    ) -> Result<Option<(ServiceName, Sample<Service, [Payload], UserHeader>)>, SubscriberReceiveError>
+    {
+        self.receive_impl(|subscriber| subscriber.receive())
+    }
+}
+
+/// Returns true when `name` matches `pattern`, where `*` matches any sequence of characters.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let name = name.as_bytes();
+    let (mut p, mut n) = (0, 0);
+    // position of the last `*` in the pattern and the position in the name it was matched at
+    let mut backtrack = None;
+
+    while n < name.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == name[n] {
+            p += 1;
+            n += 1;
+        } else if let Some((star, matched)) = backtrack {
+            // let the last `*` consume one more character
+            p = star + 1;
+            n = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == b'*')
+}
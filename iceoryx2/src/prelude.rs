@@ -16,7 +16,8 @@ pub use crate::port::event_id::EventId;
 pub use crate::service::messaging_pattern::MessagingPattern;
 pub use crate::service::{
     attribute::AttributeSet, attribute::AttributeSpecifier, attribute::AttributeVerifier, ipc,
-    local, port_factory::publisher::UnableToDeliverStrategy, port_factory::PortFactory,
+    local, port_factory::publisher::LoanInitPolicy,
+    port_factory::publisher::UnableToDeliverStrategy, port_factory::PortFactory,
     service_name::ServiceName, Service, ServiceDetails,
 };
 pub use crate::signal_handling_mode::SignalHandlingMode;
@@ -29,4 +30,4 @@ pub use iceoryx2_bb_log::set_log_level;
 pub use iceoryx2_bb_log::LogLevel;
 pub use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
 pub use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
-pub use iceoryx2_cal::shm_allocator::AllocationStrategy;
+pub use iceoryx2_cal::shm_allocator::{AllocationStrategy, PoolAllocationStrategy};
@@ -164,6 +164,13 @@ impl<Header, UserHeader, Payload: ?Sized> RawSampleMut<Header, UserHeader, Paylo
         unsafe { &*self.header }
     }
 
+    /// Returns the pointer to the underlying header.
+    #[must_use]
+    #[inline(always)]
+    pub(crate) fn header_ptr(&self) -> *mut Header {
+        self.header
+    }
+
     /// Acquires the underlying payload as reference.
     #[must_use]
     #[inline(always)]
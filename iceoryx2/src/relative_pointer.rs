@@ -0,0 +1,169 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::relative_pointer::RelativePointer;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! #[derive(Debug, Clone, Copy)]
+//! #[repr(C)]
+//! struct Frame {
+//!     id: u64,
+//!     body: RelativePointer<u32>,
+//! }
+//!
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<[u8]>()
+//!     .payload_alignment(Alignment::new(8).unwrap())
+//!     .open_or_create()?;
+//! let publisher = service.publisher_builder().initial_max_slice_len(64).create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! let mut sample = publisher.loan_slice(64)?;
+//! // the variable-length tail is stored directly behind the frame
+//! // SAFETY: Frame and u32 do not contain padding bytes
+//! let body = unsafe { sample.write_relative(core::mem::size_of::<Frame>(), &[1u32, 2, 3])? };
+//! unsafe { sample.write_relative(0, &[Frame { id: 42, body }])? };
+//! sample.send()?;
+//!
+//! let sample = subscriber.receive()?.unwrap();
+//! // SAFETY: the payload was written as Frame and u32 by the publisher
+//! let frame = unsafe { sample.resolve(&RelativePointer::<Frame>::new(0, 1))? }[0];
+//! println!("frame {} with body {:?}", frame.id, unsafe { sample.resolve(&frame.body)? });
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_log::fail;
+
+/// Failures that can occur when a [`RelativePointer`] is resolved against the payload of a
+/// sample.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum RelativePointerError {
+    /// The referenced elements do not lie completely inside the payload of the sample.
+    OutOfBounds,
+    /// The referenced elements are not correctly aligned for their type. The payload of a
+    /// sample starts at the payload alignment of the
+    /// [`Service`](crate::service::Service), see
+    /// [`payload_alignment()`](crate::service::builder::publish_subscribe::Builder::payload_alignment()).
+    Misaligned,
+}
+
+impl core::fmt::Display for RelativePointerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "RelativePointerError::{:?}", self)
+    }
+}
+
+impl std::error::Error for RelativePointerError {}
+
+/// Refers to `len` consecutive elements of type `T` that are stored inside the payload of the
+/// same sample. It stores the offset relative to the start of the payload instead of an
+/// absolute address and remains therefore valid in every process that maps the sample.
+///
+/// It can be stored as part of the payload itself to build messages with variable-length
+/// tails. It is written with
+/// [`SampleMut::write_relative()`](crate::sample_mut::SampleMut::write_relative()) and
+/// resolved with [`Sample::resolve()`](crate::sample::Sample::resolve()).
+#[repr(C)]
+pub struct RelativePointer<T> {
+    offset: u64,
+    len: u64,
+    _data: PhantomData<T>,
+}
+
+impl<T> Debug for RelativePointer<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "RelativePointer<{}> {{ offset: {}, len: {} }}",
+            core::any::type_name::<T>(),
+            self.offset,
+            self.len
+        )
+    }
+}
+
+impl<T> Clone for RelativePointer<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RelativePointer<T> {}
+
+impl<T> PartialEq for RelativePointer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset && self.len == other.len
+    }
+}
+
+impl<T> Eq for RelativePointer<T> {}
+
+impl<T> RelativePointer<T> {
+    /// Creates a new [`RelativePointer`] that refers to `len` elements starting `offset` bytes
+    /// after the start of the payload.
+    pub fn new(offset: usize, len: usize) -> Self {
+        Self {
+            offset: offset as u64,
+            len: len as u64,
+            _data: PhantomData,
+        }
+    }
+
+    /// Returns the offset in bytes relative to the start of the payload.
+    pub fn offset(&self) -> usize {
+        self.offset as usize
+    }
+
+    /// Returns the number of referenced elements.
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns true if no element is referenced.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Translates the [`RelativePointer`] into an absolute pointer into the payload that starts
+    /// at `payload` and has a size of `payload_size` bytes.
+    pub(crate) fn resolve_in(
+        &self,
+        payload: *const u8,
+        payload_size: usize,
+    ) -> Result<*const T, RelativePointerError> {
+        let msg = "Unable to resolve relative pointer";
+        let end = (self.len as u128) * (core::mem::size_of::<T>() as u128) + self.offset as u128;
+        if (payload_size as u128) < end {
+            fail!(from self, with RelativePointerError::OutOfBounds,
+                "{} since it ends at {} which exceeds the payload size of {} bytes.",
+                msg, end, payload_size);
+        }
+
+        let ptr = payload.wrapping_add(self.offset as usize);
+        if ptr as usize % core::mem::align_of::<T>() != 0 {
+            fail!(from self, with RelativePointerError::Misaligned,
+                "{} since the resulting address {:?} is not aligned to {}.",
+                msg, ptr, core::mem::align_of::<T>());
+        }
+
+        Ok(ptr.cast())
+    }
+}
@@ -0,0 +1,267 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client_builder().create()?;
+//!
+//! let mut request = client.loan()?;
+//! *request = 1234;
+//! println!("request id: {}", request.header().request_id());
+//!
+//! let pending_response = request.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::pending_response::PendingResponse;
+use crate::port::client::{ClientBackend, RequestSendError};
+use crate::raw_sample::RawSampleMut;
+use crate::service;
+use crate::service::header::request_response::RequestHeader;
+
+#[derive(Debug)]
+struct LoanedRequest<Service: service::Service> {
+    backend: Arc<ClientBackend<Service>>,
+    offset: PointerOffset,
+    is_sent: bool,
+}
+
+impl<Service: service::Service> Drop for LoanedRequest<Service> {
+    fn drop(&mut self) {
+        if !self.is_sent {
+            self.backend.return_loaned_request(self.offset);
+        }
+    }
+}
+
+/// A loaned request of a [`Client`](crate::port::client::Client) that can be written and
+/// sent to all connected [`Server`](crate::port::server::Server)s with [`RequestMut::send()`].
+/// When it goes out of scope without being sent, the memory is returned to the
+/// [`Client`](crate::port::client::Client).
+pub struct RequestMut<
+    Service: service::Service,
+    RequestPayload: Debug,
+    RequestUserHeader: Debug,
+    ResponsePayload: Debug,
+    ResponseUserHeader: Debug,
+> {
+    loan: LoanedRequest<Service>,
+    ptr: RawSampleMut<RequestHeader, RequestUserHeader, RequestPayload>,
+    _response_payload: PhantomData<ResponsePayload>,
+    _response_user_header: PhantomData<ResponseUserHeader>,
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Debug
+    for RequestMut<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "RequestMut<{}, {}, {}, {}, {}> {{ offset: {:?}, header: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<RequestPayload>(),
+            core::any::type_name::<RequestUserHeader>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<ResponseUserHeader>(),
+            self.loan.offset,
+            self.header()
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Deref
+    for RequestMut<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    type Target = RequestPayload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_payload_ref()
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > DerefMut
+    for RequestMut<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.ptr.as_payload_mut()
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > RequestMut<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+    pub(crate) fn new(
+        backend: Arc<ClientBackend<Service>>,
+        offset: PointerOffset,
+        ptr: RawSampleMut<RequestHeader, RequestUserHeader, RequestPayload>,
+    ) -> Self {
+        Self {
+            loan: LoanedRequest {
+                backend,
+                offset,
+                is_sent: false,
+            },
+            ptr,
+            _response_payload: PhantomData,
+            _response_user_header: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the [`RequestHeader`].
+    pub fn header(&self) -> &RequestHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the user header of the request.
+    pub fn user_header(&self) -> &RequestUserHeader {
+        self.ptr.as_user_header_ref()
+    }
+
+    /// Returns a mutable reference to the user header of the request.
+    pub fn user_header_mut(&mut self) -> &mut RequestUserHeader {
+        self.ptr.as_user_header_mut()
+    }
+
+    /// Returns a reference to the payload of the request.
+    pub fn payload(&self) -> &RequestPayload {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the request.
+    pub fn payload_mut(&mut self) -> &mut RequestPayload {
+        self.ptr.as_payload_mut()
+    }
+
+    /// Sends the request to all connected [`Server`](crate::port::server::Server)s and
+    /// returns the [`PendingResponse`] that receives the corresponding responses. The request
+    /// stays active, see
+    /// [`StaticConfig::max_active_requests()`](crate::service::static_config::request_response::StaticConfig::max_active_requests()),
+    /// until the [`PendingResponse`] goes out of scope.
+    ///
+    /// On failure it returns [`RequestSendError`] describing the failure.
+    pub fn send(
+        self,
+    ) -> Result<
+        PendingResponse<
+            Service,
+            RequestPayload,
+            RequestUserHeader,
+            ResponsePayload,
+            ResponseUserHeader,
+        >,
+        RequestSendError,
+    > {
+        let mut loan = self.loan;
+        loan.is_sent = true;
+        let request_id = self.ptr.as_header_ref().request_id();
+
+        let number_of_server_connections = loan.backend.send_request(loan.offset, request_id)?;
+
+        Ok(PendingResponse::new(
+            loan.backend.clone(),
+            request_id,
+            number_of_server_connections,
+        ))
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    >
+    RequestMut<
+        Service,
+        MaybeUninit<RequestPayload>,
+        RequestUserHeader,
+        ResponsePayload,
+        ResponseUserHeader,
+    >
+{
+    /// Writes the payload into the uninitialized request and returns the initialized
+    /// [`RequestMut`].
+    pub fn write_payload(
+        mut self,
+        value: RequestPayload,
+    ) -> RequestMut<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+    {
+        self.payload_mut().write(value);
+        // SAFETY: the payload was initialized in the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Returns the initialized [`RequestMut`].
+    ///
+    /// # Safety
+    ///
+    /// * The caller must ensure that the payload was initialized before.
+    pub unsafe fn assume_init(
+        self,
+    ) -> RequestMut<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+    {
+        RequestMut {
+            loan: self.loan,
+            ptr: RawSampleMut::new_unchecked(
+                self.ptr.as_header_ref() as *const RequestHeader as *mut RequestHeader,
+                self.ptr.as_user_header_ref() as *const RequestUserHeader as *mut RequestUserHeader,
+                self.ptr.as_payload_ref().as_ptr() as *mut RequestPayload,
+            ),
+            _response_payload: PhantomData,
+            _response_user_header: PhantomData,
+        }
+    }
+}
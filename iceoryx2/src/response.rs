@@ -0,0 +1,109 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .request_response::<u64, u64>()
+//! #   .open_or_create()?;
+//! # let client = service.client_builder().create()?;
+//! let pending_response = client.send_copy(1234)?;
+//!
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("received: {:?}", *response);
+//!     println!("header request id {:?}", response.header().request_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::ops::Deref;
+
+use crate::port::client::ResponseDetails;
+use crate::raw_sample::RawSample;
+use crate::service;
+use crate::service::header::request_response::ResponseHeader;
+
+/// A response of a [`Server`](crate::port::server::Server) that was received by a
+/// [`Client`](crate::port::client::Client) via
+/// [`PendingResponse::receive()`](crate::pending_response::PendingResponse::receive()).
+/// When it goes out of scope, the memory is returned to the
+/// [`Server`](crate::port::server::Server).
+pub struct Response<Service: service::Service, ResponsePayload: Debug, ResponseUserHeader> {
+    ptr: RawSample<ResponseHeader, ResponseUserHeader, ResponsePayload>,
+    details: ResponseDetails<Service>,
+}
+
+impl<Service: service::Service, ResponsePayload: Debug, ResponseUserHeader> Debug
+    for Response<Service, ResponsePayload, ResponseUserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Response<{}, {}, {}> {{ details: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<ResponseUserHeader>(),
+            self.details
+        )
+    }
+}
+
+impl<Service: service::Service, ResponsePayload: Debug, ResponseUserHeader> Deref
+    for Response<Service, ResponsePayload, ResponseUserHeader>
+{
+    type Target = ResponsePayload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_payload_ref()
+    }
+}
+
+impl<Service: service::Service, ResponsePayload: Debug, ResponseUserHeader> Drop
+    for Response<Service, ResponsePayload, ResponseUserHeader>
+{
+    fn drop(&mut self) {
+        self.details.release();
+    }
+}
+
+impl<Service: service::Service, ResponsePayload: Debug, ResponseUserHeader>
+    Response<Service, ResponsePayload, ResponseUserHeader>
+{
+    pub(crate) fn new(
+        details: ResponseDetails<Service>,
+        ptr: RawSample<ResponseHeader, ResponseUserHeader, ResponsePayload>,
+    ) -> Self {
+        Self { ptr, details }
+    }
+
+    /// Returns a reference to the payload of the [`Response`]
+    pub fn payload(&self) -> &ResponsePayload {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a reference to the user_header of the [`Response`]
+    pub fn user_header(&self) -> &ResponseUserHeader {
+        self.ptr.as_user_header_ref()
+    }
+
+    /// Returns a reference to the [`ResponseHeader`] of the [`Response`].
+    pub fn header(&self) -> &ResponseHeader {
+        self.ptr.as_header_ref()
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .request_response::<u64, u64>()
+//! #   .open_or_create()?;
+//! # let server = service.server_builder().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     let mut response = active_request.loan()?;
+//!     *response = *active_request + 1;
+//!     println!("response for request id: {}", response.header().request_id());
+//!     response.send()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::port::server::{ClientConnection, ResponseSendError, ServerBackend};
+use crate::raw_sample::RawSampleMut;
+use crate::service;
+use crate::service::header::request_response::ResponseHeader;
+
+#[derive(Debug)]
+struct LoanedResponse<Service: service::Service> {
+    backend: Arc<ServerBackend<Service>>,
+    connection: Arc<ClientConnection<Service>>,
+    offset: PointerOffset,
+    is_sent: bool,
+}
+
+impl<Service: service::Service> Drop for LoanedResponse<Service> {
+    fn drop(&mut self) {
+        if !self.is_sent {
+            self.backend.return_loaned_response(self.offset);
+        }
+    }
+}
+
+/// A loaned response of a [`Server`](crate::port::server::Server) that was acquired via an
+/// [`ActiveRequest`](crate::active_request::ActiveRequest) and is sent to the
+/// [`Client`](crate::port::client::Client) of the request with [`ResponseMut::send()`].
+/// When it goes out of scope without being sent, the memory is returned to the
+/// [`Server`](crate::port::server::Server).
+pub struct ResponseMut<Service: service::Service, ResponsePayload: Debug, ResponseUserHeader: Debug>
+{
+    loan: LoanedResponse<Service>,
+    ptr: RawSampleMut<ResponseHeader, ResponseUserHeader, ResponsePayload>,
+}
+
+impl<Service: service::Service, ResponsePayload: Debug, ResponseUserHeader: Debug> Debug
+    for ResponseMut<Service, ResponsePayload, ResponseUserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ResponseMut<{}, {}, {}> {{ offset: {:?}, header: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<ResponseUserHeader>(),
+            self.loan.offset,
+            self.header()
+        )
+    }
+}
+
+impl<Service: service::Service, ResponsePayload: Debug, ResponseUserHeader: Debug> Deref
+    for ResponseMut<Service, ResponsePayload, ResponseUserHeader>
+{
+    type Target = ResponsePayload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_payload_ref()
+    }
+}
+
+impl<Service: service::Service, ResponsePayload: Debug, ResponseUserHeader: Debug> DerefMut
+    for ResponseMut<Service, ResponsePayload, ResponseUserHeader>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.ptr.as_payload_mut()
+    }
+}
+
+impl<Service: service::Service, ResponsePayload: Debug, ResponseUserHeader: Debug>
+    ResponseMut<Service, ResponsePayload, ResponseUserHeader>
+{
+    pub(crate) fn new(
+        backend: Arc<ServerBackend<Service>>,
+        connection: Arc<ClientConnection<Service>>,
+        offset: PointerOffset,
+        ptr: RawSampleMut<ResponseHeader, ResponseUserHeader, ResponsePayload>,
+    ) -> Self {
+        Self {
+            loan: LoanedResponse {
+                backend,
+                connection,
+                offset,
+                is_sent: false,
+            },
+            ptr,
+        }
+    }
+
+    /// Returns a reference to the [`ResponseHeader`].
+    pub fn header(&self) -> &ResponseHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the user header of the response.
+    pub fn user_header(&self) -> &ResponseUserHeader {
+        self.ptr.as_user_header_ref()
+    }
+
+    /// Returns a mutable reference to the user header of the response.
+    pub fn user_header_mut(&mut self) -> &mut ResponseUserHeader {
+        self.ptr.as_user_header_mut()
+    }
+
+    /// Returns a reference to the payload of the response.
+    pub fn payload(&self) -> &ResponsePayload {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the response.
+    pub fn payload_mut(&mut self) -> &mut ResponsePayload {
+        self.ptr.as_payload_mut()
+    }
+
+    /// Sends the response to the [`Client`](crate::port::client::Client) of the
+    /// corresponding request. When the [`Client`](crate::port::client::Client) disconnected
+    /// in the meantime, the response is discarded and
+    /// [`ResponseSendError::ClientDisconnected`] is returned.
+    ///
+    /// On failure it returns [`ResponseSendError`] describing the failure.
+    pub fn send(self) -> Result<(), ResponseSendError> {
+        let mut loan = self.loan;
+        loan.is_sent = true;
+        loan.backend.send_response(&loan.connection, loan.offset)
+    }
+}
+
+impl<Service: service::Service, ResponsePayload: Debug, ResponseUserHeader: Debug>
+    ResponseMut<Service, MaybeUninit<ResponsePayload>, ResponseUserHeader>
+{
+    /// Writes the payload into the uninitialized response and returns the initialized
+    /// [`ResponseMut`].
+    pub fn write_payload(
+        mut self,
+        value: ResponsePayload,
+    ) -> ResponseMut<Service, ResponsePayload, ResponseUserHeader> {
+        self.payload_mut().write(value);
+        // SAFETY: the payload was initialized in the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Returns the initialized [`ResponseMut`].
+    ///
+    /// # Safety
+    ///
+    /// * The caller must ensure that the payload was initialized before.
+    pub unsafe fn assume_init(self) -> ResponseMut<Service, ResponsePayload, ResponseUserHeader> {
+        ResponseMut {
+            loan: self.loan,
+            ptr: RawSampleMut::new_unchecked(
+                self.ptr.as_header_ref() as *const ResponseHeader as *mut ResponseHeader,
+                self.ptr.as_user_header_ref() as *const ResponseUserHeader
+                    as *mut ResponseUserHeader,
+                self.ptr.as_payload_ref().as_ptr() as *mut ResponsePayload,
+            ),
+        }
+    }
+}
@@ -35,19 +35,82 @@ use core::{fmt::Debug, ops::Deref};
 extern crate alloc;
 use alloc::sync::Arc;
 
-use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_posix::memory_lock::MemoryLockCreationError;
 use iceoryx2_cal::zero_copy_connection::{PointerOffset, ZeroCopyReceiver, ZeroCopyReleaseError};
 
 use crate::port::details::publisher_connections::Connection;
 use crate::port::port_identifiers::UniquePublisherId;
+use crate::port::publisher::PublisherLoanError;
 use crate::raw_sample::RawSample;
+use crate::relative_pointer::{RelativePointer, RelativePointerError};
+use crate::sample_mut::SampleMut;
 use crate::service::header::publish_subscribe::Header;
+use crate::service::static_config::publish_subscribe::StaticConfig;
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+use crate::service::static_config::publish_subscribe::CACHE_LINE_SIZE;
+
+/// Issues a software prefetch for every cache line of the memory region.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn prefetch_read(start: *const u8, len: usize) {
+    let end = start.wrapping_add(len);
+    let mut cache_line = start.wrapping_sub(start as usize % CACHE_LINE_SIZE);
+    while cache_line < end {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            core::arch::x86_64::_mm_prefetch(cache_line.cast(), core::arch::x86_64::_MM_HINT_T0);
+        }
+        #[cfg(target_arch = "aarch64")]
+        unsafe {
+            core::arch::asm!("prfm pldl1keep, [{}]", in(reg) cache_line,
+                options(nostack, readonly, preserves_flags));
+        }
+
+        cache_line = cache_line.wrapping_add(CACHE_LINE_SIZE);
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+fn prefetch_read(_start: *const u8, _len: usize) {}
 
 #[derive(Debug)]
 pub(crate) struct SampleDetails<Service: crate::service::Service> {
     pub(crate) publisher_connection: Arc<Connection<Service>>,
     pub(crate) offset: PointerOffset,
     pub(crate) origin: UniquePublisherId,
+    pub(crate) acknowledge: bool,
+}
+
+impl<Service: crate::service::Service> SampleDetails<Service> {
+    /// Pins the memory of the data segment that contains the sample.
+    pub(crate) fn pin(&self) -> Result<(), MemoryLockCreationError> {
+        fail!(from self, when self.publisher_connection.pin_segment(self.offset.segment_id()),
+            "Unable to pin the memory of the sample.");
+        Ok(())
+    }
+
+    /// Returns the sample to the [`Publisher`](crate::port::publisher::Publisher) and
+    /// acknowledges its sequence number when acknowledgment is enabled.
+    pub(crate) fn release(&self, sequence_number: u64) {
+        if self.acknowledge {
+            self.publisher_connection
+                .receiver
+                .acknowledge(sequence_number);
+        }
+
+        unsafe {
+            self.publisher_connection
+                .data_segment
+                .unregister_offset(self.offset)
+        };
+
+        match self.publisher_connection.receiver.release(self.offset) {
+            Ok(()) => (),
+            Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                fatal_panic!(from self, "This should never happen! The publishers retrieve channel is full and the sample cannot be returned.");
+            }
+        }
+    }
 }
 
 /// It stores the payload and is acquired by the [`Subscriber`](crate::port::subscriber::Subscriber) whenever
@@ -56,6 +119,8 @@ pub(crate) struct SampleDetails<Service: crate::service::Service> {
 pub struct Sample<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader> {
     pub(crate) ptr: RawSample<Header, UserHeader, Payload>,
     pub(crate) details: SampleDetails<Service>,
+    // a copy of the header of the sample since its size in the sample depends on the service
+    pub(crate) header: Header,
 }
 
 impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader> Debug
@@ -86,24 +151,7 @@ impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader> Drop
     for Sample<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
-        unsafe {
-            self.details
-                .publisher_connection
-                .data_segment
-                .unregister_offset(self.details.offset)
-        };
-
-        match self
-            .details
-            .publisher_connection
-            .receiver
-            .release(self.details.offset)
-        {
-            Ok(()) => (),
-            Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
-                fatal_panic!(from self, "This should never happen! The publishers retrieve channel is full and the sample cannot be returned.");
-            }
-        }
+        self.details.release(self.header().sequence_number());
     }
 }
 
@@ -122,11 +170,278 @@ impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader>
 
     /// Returns a reference to the [`Header`] of the [`Sample`].
     pub fn header(&self) -> &Header {
-        self.ptr.as_header_ref()
+        &self.header
+    }
+
+    /// Returns how many bytes of the payload were marked as used by the
+    /// [`Publisher`](crate::port::publisher::Publisher) with
+    /// [`SampleMut::set_used_len()`](crate::sample_mut::SampleMut::set_used_len()).
+    pub fn used_len(&self) -> usize {
+        self.header().used_len() as usize
     }
 
     /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher)
     pub fn origin(&self) -> UniquePublisherId {
         self.details.origin
     }
+
+    /// Returns true when the [`Sample`] was published before the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) was created and was therefore
+    /// replayed from the history of the [`Publisher`](crate::port::publisher::Publisher),
+    /// otherwise false. The [`Header`] is shared by all
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s, so the
+    /// [`Publisher`](crate::port::publisher::Publisher) announces the last
+    /// [`Header::sequence_number()`] of the history on every connection before it is replayed.
+    pub fn is_historical(&self) -> bool {
+        self.header().sequence_number() <= self.details.publisher_connection.receiver.history_end()
+    }
+
+    /// Returns the [`StaticConfig`] of the [`Service`](crate::service::Service) the [`Sample`]
+    /// was received from. It allows consumers that receive from multiple
+    /// [`Service`](crate::service::Service)s, like the
+    /// [`MergedSubscriber`](crate::port::merged_subscriber::MergedSubscriber) or the
+    /// [`WildcardSubscriber`](crate::port::wildcard_subscriber::WildcardSubscriber), to inspect
+    /// for instance the [`StaticConfig::message_type_details()`] of the source.
+    pub fn service_static_config(&self) -> &StaticConfig {
+        &self.details.publisher_connection.static_config
+    }
+
+    /// Returns the payload of the [`Sample`] as bytes, independent of the payload type. The
+    /// slice covers the whole payload region, see
+    /// [`Sample::used_len()`] for the part that was marked as used.
+    ///
+    /// The bytes can only be interpreted as a type again when its
+    /// [`TypeDetail`](crate::service::static_config::message_type_details::TypeDetail)
+    /// matches the payload
+    /// [`MessageTypeDetails`](crate::service::static_config::message_type_details::MessageTypeDetails)
+    /// of the [`Service`](crate::service::Service).
+    ///
+    /// # Safety
+    ///
+    ///  * `Payload` must not contain uninitialized bytes, like padding bytes, since every
+    ///    byte of the returned slice is considered to be initialized.
+    pub unsafe fn as_bytes(&self) -> &[u8] {
+        let (start, len) = self.payload_region();
+        core::slice::from_raw_parts(start, len)
+    }
+
+    fn payload_region(&self) -> (*const u8, usize) {
+        let payload = self.payload();
+        (
+            (payload as *const Payload).cast(),
+            core::mem::size_of_val(payload),
+        )
+    }
+
+    /// Hints the CPU to load the payload of the [`Sample`] into the cache, so that the first
+    /// access of a large payload does not stall on a cold cache, for instance, when it is
+    /// located on another NUMA node. It should be called ahead of processing, e.g. before the
+    /// previous [`Sample`] is processed in a pipeline stage.
+    ///
+    /// The prefetch is only advisory and does not block. On platforms without software
+    /// prefetch support it has no effect.
+    pub fn prefetch(&self) {
+        let (start, len) = self.payload_region();
+        prefetch_read(start, len);
+    }
+
+    /// Returns the start address and the size in bytes of the payload region of the
+    /// [`Sample`] in the address space of the current process, for instance, to hand it over
+    /// to a device that reads it with DMA. Call [`Sample::pin_memory()`] beforehand when the
+    /// pages must not be paged out while the device accesses them.
+    ///
+    /// # Safety
+    ///
+    ///  * the region is only valid as long as the [`Sample`] is borrowed, it must not be
+    ///    accessed after the [`Sample`] was dropped since the memory is returned to the
+    ///    [`Publisher`](crate::port::publisher::Publisher) and reused
+    ///  * the region must not be written to
+    pub unsafe fn as_raw_region(&self) -> (*const u8, usize) {
+        self.payload_region()
+    }
+
+    /// Locks the memory pages of the [`Sample`] with `mlock` so that they are not paged out.
+    /// The whole data segment of the [`Publisher`](crate::port::publisher::Publisher) that
+    /// contains the [`Sample`] is locked once and stays locked until the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) unmaps it, therefore all further
+    /// [`Sample`]s from the same segment are pinned as well. It is required when the region of
+    /// [`Sample::as_raw_region()`] is accessed by a device via DMA. When the
+    /// [`Service`](crate::service::Service) was created with
+    /// [`Builder::pin_sample_memory()`](crate::service::builder::publish_subscribe::Builder::pin_sample_memory())
+    /// every [`Sample`] is already pinned on reception and the call has no effect.
+    ///
+    /// The number of pages that can be locked is limited by the `RLIMIT_MEMLOCK` resource
+    /// limit of the process.
+    pub fn pin_memory(&self) -> Result<(), MemoryLockCreationError> {
+        self.details.pin()
+    }
+
+    /// Returns true when the memory of the [`Sample`] is pinned, see
+    /// [`Sample::pin_memory()`].
+    pub fn is_memory_pinned(&self) -> bool {
+        self.details
+            .publisher_connection
+            .is_segment_pinned(self.details.offset.segment_id())
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        Payload: Debug + Copy + 'static,
+        UserHeader: Debug + Copy,
+    > Sample<Service, Payload, UserHeader>
+{
+    /// Loans a new [`SampleMut`] from the provided
+    /// [`Publisher`](crate::port::publisher::Publisher) and copies the payload, the user header
+    /// and the [`Sample::used_len()`] into it. The returned [`SampleMut`] is ready to be sent.
+    /// It is meant for gateways that republish the received [`Sample`]s to multiple
+    /// [`Service`](crate::service::Service)s.
+    ///
+    /// The payload is always copied since every
+    /// [`Publisher`](crate::port::publisher::Publisher) owns its own data segment. When the
+    /// used length exceeds the max message size of the destination it is reduced to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let source = node.service_builder(&"Source".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    /// let destinations = [
+    ///     node.service_builder(&"Destination/A".try_into()?)
+    ///         .publish_subscribe::<u64>()
+    ///         .open_or_create()?,
+    ///     node.service_builder(&"Destination/B".try_into()?)
+    ///         .publish_subscribe::<u64>()
+    ///         .open_or_create()?,
+    /// ];
+    ///
+    /// let subscriber = source.subscriber_builder().create()?;
+    /// let publishers = destinations
+    ///     .iter()
+    ///     .map(|d| d.publisher_builder().create())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// while let Some(sample) = subscriber.receive()? {
+    ///     for publisher in &publishers {
+    ///         sample.clone_into_loan(publisher)?.send()?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clone_into_loan<PublisherService: crate::service::Service>(
+        &self,
+        publisher: &crate::port::publisher::Publisher<PublisherService, Payload, UserHeader>,
+    ) -> Result<SampleMut<PublisherService, Payload, UserHeader>, PublisherLoanError> {
+        let mut sample = publisher.loan_uninit()?;
+        *sample.user_header_mut() = *self.user_header();
+        let mut sample = sample.write_payload(*self.payload());
+
+        let used_len = self.used_len().min(sample.header().used_len() as usize);
+        sample.set_used_len(used_len);
+        Ok(sample)
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        Payload: Debug + Copy + 'static,
+        UserHeader: Debug + Copy,
+    > Sample<Service, [Payload], UserHeader>
+{
+    /// Loans a new [`SampleMut`] with the same number of elements from the provided
+    /// [`Publisher`](crate::port::publisher::Publisher) and copies the payload, the user header
+    /// and the [`Sample::used_len()`] into it. The returned [`SampleMut`] is ready to be sent.
+    /// When the destination does not support a slice of that length,
+    /// [`PublisherLoanError::ExceedsMaxLoanSize`] is returned.
+    ///
+    /// The payload is always copied since every
+    /// [`Publisher`](crate::port::publisher::Publisher) owns its own data segment. When the
+    /// used length exceeds the max message size of the destination it is reduced to it.
+    pub fn clone_into_loan<PublisherService: crate::service::Service>(
+        &self,
+        publisher: &crate::port::publisher::Publisher<PublisherService, [Payload], UserHeader>,
+    ) -> Result<SampleMut<PublisherService, [Payload], UserHeader>, PublisherLoanError> {
+        let mut sample = publisher.loan_slice_uninit(self.len())?;
+        *sample.user_header_mut() = *self.user_header();
+        let mut sample = sample.write_from_slice(self.payload());
+
+        let used_len = self.used_len().min(sample.header().used_len() as usize);
+        sample.set_used_len(used_len);
+        Ok(sample)
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug, UserHeader>
+    Sample<Service, [Payload], UserHeader>
+{
+    /// Returns the number of elements the [`Publisher`](crate::port::publisher::Publisher)
+    /// has loaned the [`Sample`] with, see
+    /// [`Header::number_of_elements()`](crate::service::header::publish_subscribe::Header::number_of_elements()).
+    pub fn len(&self) -> usize {
+        self.header().number_of_elements() as usize
+    }
+
+    /// Returns true if the [`Sample`] does not contain any element.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the payload of the [`Sample`] as slice.
+    pub fn as_slice(&self) -> &[Payload] {
+        self.payload()
+    }
+
+    /// Returns a reference to the element at `index` or [`None`] when `index` is out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<&Payload> {
+        self.payload().get(index)
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug, const N: usize, UserHeader>
+    Sample<Service, [Payload; N], UserHeader>
+{
+    /// Returns the number of elements of the array payload.
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the array payload does not contain any element.
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns a reference to the element at `index` or [`None`] when `index` is out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<&Payload> {
+        self.payload().get(index)
+    }
+}
+
+impl<Service: crate::service::Service, UserHeader> Sample<Service, [u8], UserHeader> {
+    /// Resolves a [`RelativePointer`] that was stored in the payload with
+    /// [`SampleMut::write_relative()`](crate::sample_mut::SampleMut::write_relative()). Returns
+    /// an error when the referenced elements are not completely inside the payload or
+    /// misaligned, which indicates a corrupted [`RelativePointer`].
+    ///
+    /// # Safety
+    ///
+    ///  * the referenced bytes must be a valid bit pattern of `T`, for instance since they
+    ///    were written with
+    ///    [`SampleMut::write_relative()`](crate::sample_mut::SampleMut::write_relative())
+    ///    as `T`
+    pub unsafe fn resolve<T: Copy>(
+        &self,
+        ptr: &RelativePointer<T>,
+    ) -> Result<&[T], RelativePointerError> {
+        let payload = self.payload();
+        let start = ptr.resolve_in(payload.as_ptr(), payload.len())?;
+        Ok(core::slice::from_raw_parts(start, ptr.len()))
+    }
 }
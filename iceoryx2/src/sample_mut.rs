@@ -64,10 +64,13 @@
 //! ```
 
 use crate::{
-    port::publisher::{PublisherBackend, PublisherSendError},
+    port::publisher::{PublisherBackend, PublisherSendError, SendStatus},
     raw_sample::RawSampleMut,
+    relative_pointer::{RelativePointer, RelativePointerError},
     service::header::publish_subscribe::Header,
 };
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_cal::shared_memory::*;
 
 use core::fmt::{Debug, Formatter};
@@ -75,13 +78,31 @@ use core::fmt::{Debug, Formatter};
 extern crate alloc;
 use alloc::sync::Arc;
 
+/// Failure that can be emitted when the payload of a [`SampleMut`] is written with
+/// [`SampleMut::write_segments()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum WriteSegmentsError {
+    /// The combined length of all segments exceeds the size of the payload.
+    ExceedsPayloadSize,
+}
+
+impl core::fmt::Display for WriteSegmentsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "WriteSegmentsError::{:?}", self)
+    }
+}
+
+impl std::error::Error for WriteSegmentsError {}
+
 /// Acquired by a [`crate::port::publisher::Publisher`] via
 ///  * [`crate::port::publisher::Publisher::loan()`],
 ///  * [`crate::port::publisher::Publisher::loan_slice()`]
 ///
 /// It stores the payload that will be sent
-/// to all connected [`crate::port::subscriber::Subscriber`]s. If the [`SampleMut`] is not sent
-/// it will release the loaned memory when going out of scope.
+/// to all connected [`crate::port::subscriber::Subscriber`]s. The loan ends either with
+/// [`SampleMut::send()`], which commits the sample, or with [`SampleMut::abort()`], which
+/// discards it. Dropping a [`SampleMut`] without sending it is equivalent to
+/// [`SampleMut::abort()`].
 ///
 /// # Notes
 ///
@@ -90,6 +111,8 @@ use alloc::sync::Arc;
 pub struct SampleMut<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader> {
     pub(crate) publisher_backend: Arc<PublisherBackend<Service>>,
     pub(crate) ptr: RawSampleMut<Header, UserHeader, Payload>,
+    // the header is written into the sample when it is sent
+    pub(crate) header: Header,
     pub(crate) offset_to_chunk: PointerOffset,
     pub(crate) sample_size: usize,
 }
@@ -148,7 +171,7 @@ impl<
     /// # }
     /// ```
     pub fn header(&self) -> &Header {
-        self.ptr.as_header_ref()
+        &self.header
     }
 
     /// Returns a reference to the user_header of the sample.
@@ -263,6 +286,82 @@ impl<
         self.ptr.as_payload_mut()
     }
 
+    /// Returns the payload of the [`SampleMut`] as mutable bytes, independent of the payload
+    /// type. It can be used to fill the payload region generically, for instance from a
+    /// recording.
+    ///
+    /// # Safety
+    ///
+    ///  * The written bytes must form a valid payload of the
+    ///    [`TypeDetail`](crate::service::static_config::message_type_details::TypeDetail)
+    ///    stored in the payload
+    ///    [`MessageTypeDetails`](crate::service::static_config::message_type_details::MessageTypeDetails)
+    ///    of the [`Service`](crate::service::Service) since the
+    ///    [`crate::port::subscriber::Subscriber`] interprets them as such.
+    ///  * Uninitialized bytes of `M`, like padding bytes, must not be read through the
+    ///    returned slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// let recorded_payload = 4567u64.to_ne_bytes();
+    /// unsafe { sample.as_bytes_mut() }.copy_from_slice(&recorded_payload);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn as_bytes_mut(&mut self) -> &mut [u8] {
+        let payload = self.payload_mut();
+        let len = core::mem::size_of_val(payload);
+        core::slice::from_raw_parts_mut((payload as *mut M).cast(), len)
+    }
+
+    /// Discards the [`SampleMut`] without sending it and returns its memory immediately to
+    /// the [`crate::port::publisher::Publisher`], so that it can be loaned again. It is
+    /// equivalent to dropping the [`SampleMut`] and makes the intent explicit in error paths
+    /// where a partially written sample shall not be published.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    /// # let is_measurement_valid = false;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// *sample.payload_mut() = 4567;
+    ///
+    /// if is_measurement_valid {
+    ///     sample.send()?;
+    /// } else {
+    ///     sample.abort();
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn abort(self) {
+        drop(self)
+    }
+
     /// Send a previously loaned [`crate::port::publisher::Publisher::loan_uninit()`] or
     /// [`crate::port::publisher::Publisher::loan()`] [`SampleMut`] to all connected
     /// [`crate::port::subscriber::Subscriber`]s of the service.
@@ -291,8 +390,386 @@ impl<
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send(self) -> Result<usize, PublisherSendError> {
+    pub fn send(mut self) -> Result<usize, PublisherSendError> {
+        self.verify_used_len("Unable to send sample")?;
+        let send_time = self.set_send_metadata();
+
         self.publisher_backend
-            .send_sample(self.offset_to_chunk, self.sample_size)
+            .send_sample(self.offset_to_chunk, self.sample_size, send_time)
+    }
+
+    /// Replaces the retained value of a [`Service`](crate::service::Service) in the last value
+    /// mode with the [`SampleMut`], see [`crate::port::publisher::Publisher::update()`].
+    pub(crate) fn update(mut self) -> Result<usize, PublisherSendError> {
+        self.verify_used_len("Unable to update the value")?;
+        let send_time = self.set_send_metadata();
+
+        self.publisher_backend
+            .update_sample(self.offset_to_chunk, self.sample_size, send_time)
+    }
+
+    /// Sends a [`SampleMut`] like [`SampleMut::send()`] but never blocks, independent of the
+    /// [`UnableToDeliverStrategy`](crate::service::port_factory::publisher::UnableToDeliverStrategy).
+    /// When the buffer of at least one connected
+    /// [`crate::port::subscriber::Subscriber`] is full, the [`SampleMut`] is not delivered
+    /// to any [`crate::port::subscriber::Subscriber`] and handed back with
+    /// [`SendStatus::Pending`] so that the caller can decide to retry later or to discard it.
+    /// Otherwise, it returns [`SendStatus::Delivered`] with the number of
+    /// [`crate::port::subscriber::Subscriber`]s that received the [`SampleMut`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::publisher::SendStatus;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let sample = publisher.loan_uninit()?.write_payload(4567);
+    ///
+    /// match sample.poll_send()? {
+    ///     SendStatus::Delivered(n) => println!("delivered to {n} subscribers"),
+    ///     SendStatus::Pending { sample, blocked_subscribers } => {
+    ///         println!("{blocked_subscribers} subscribers are blocked");
+    ///         // retry later or drop the sample to discard it
+    ///         drop(sample);
+    ///     }
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn poll_send(mut self) -> Result<SendStatus<Self>, PublisherSendError> {
+        self.verify_used_len("Unable to poll send sample")?;
+        let send_time = self.set_send_metadata();
+
+        match self.publisher_backend.poll_send_sample(
+            self.offset_to_chunk,
+            self.sample_size,
+            send_time,
+        )? {
+            SendStatus::Delivered(number_of_recipients) => {
+                Ok(SendStatus::Delivered(number_of_recipients))
+            }
+            SendStatus::Pending {
+                blocked_subscribers,
+                ..
+            } => Ok(SendStatus::Pending {
+                sample: self,
+                blocked_subscribers,
+            }),
+        }
+    }
+
+    pub(crate) fn verify_used_len(&self, msg: &str) -> Result<(), PublisherSendError> {
+        let used_len = self.header().used_len() as usize;
+        let max_used_len = self
+            .publisher_backend
+            .max_used_len(self.header().number_of_elements() as usize);
+        if max_used_len < used_len {
+            fail!(from self, with PublisherSendError::ExceedsMaxMessageSize,
+                "{} since the used length of {} exceeds the max message size of {}.",
+                msg, used_len, max_used_len);
+        }
+
+        Ok(())
+    }
+
+    /// Stamps the header of the [`SampleMut`] and returns the time of the send acquired with
+    /// [`Time::now()`], which is used to compute the age of the sample in the history.
+    pub(crate) fn set_send_metadata(&mut self) -> Time {
+        self.header
+            .set_publish_timestamp(Time::now_realtime().unwrap_or_default());
+
+        let send_time = Time::now().unwrap_or_default();
+        if self.publisher_backend.records_send_timestamp()
+            && send_time.clock_type() == ClockType::Monotonic
+        {
+            self.header.set_send_timestamp(send_time);
+        }
+
+        // a sample that is returned by a pending poll send keeps its sequence number
+        if self.header.sequence_number() == 0 {
+            let sequence_number = self.publisher_backend.next_sequence_number();
+            self.header.set_sequence_number(sequence_number);
+        }
+
+        // SAFETY: the header pointer points to the header of the loaned sample
+        unsafe {
+            self.header.write_to_sample(
+                self.ptr.header_ptr(),
+                self.publisher_backend.has_send_timestamp(),
+            )
+        };
+
+        send_time
+    }
+
+    /// Marks the first `value` bytes of the payload as used. A
+    /// [`crate::port::subscriber::Subscriber`] can acquire it with
+    /// [`crate::sample::Sample::used_len()`]. By default the whole payload is used, capped by
+    /// the [`max_message_size`](crate::service::static_config::publish_subscribe::StaticConfig::max_message_size())
+    /// of the [`Service`](crate::service::Service).
+    /// If `value` exceeds it, [`SampleMut::send()`] fails with
+    /// [`PublisherSendError::ExceedsMaxMessageSize`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<[u8; 1024]>()
+    ///     .max_message_size(256)
+    ///     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut payload = [0u8; 1024];
+    /// payload[..5].copy_from_slice(b"hello");
+    ///
+    /// let mut sample = publisher.loan_uninit()?.write_payload(payload);
+    /// sample.set_used_len(5);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_used_len(&mut self, value: usize) {
+        self.header.set_used_len(value as _);
+    }
+
+    /// Tags the sample with a coalescence group. When the
+    /// [`Service`](crate::service::Service) has a
+    /// [`coalescing_window()`](crate::service::static_config::publish_subscribe::StaticConfig::coalescing_window())
+    /// a buffered sample is dropped in favor of a newer sample of the same
+    /// [`crate::port::publisher::Publisher`] and coalescence group. Samples of different groups
+    /// never supersede each other. By default, every sample belongs to group `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<f32>()
+    ///     .coalescing_window(Duration::from_millis(10))
+    ///     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let sensor_id = 3;
+    /// let mut sample = publisher.loan_uninit()?.write_payload(23.5);
+    /// sample.set_coalescence_group(sensor_id);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_coalescence_group(&mut self, value: u64) {
+        self.header.set_coalescence_group(value);
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug, UserHeader>
+    SampleMut<Service, [Payload], UserHeader>
+{
+    /// Returns the number of elements the [`SampleMut`] was loaned with.
+    pub fn len(&self) -> usize {
+        self.payload().len()
+    }
+
+    /// Returns true if the [`SampleMut`] does not contain any element.
+    pub fn is_empty(&self) -> bool {
+        self.payload().is_empty()
+    }
+
+    /// Returns a reference to the element at `index` or [`None`] when `index` is out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<&Payload> {
+        self.payload().get(index)
+    }
+
+    /// Returns a mutable reference to the element at `index` or [`None`] when `index` is out
+    /// of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(4).create()?;
+    ///
+    /// let mut sample = publisher.loan_slice(4)?;
+    /// if let Some(element) = sample.get_mut(3) {
+    ///     *element = 42;
+    /// }
+    /// assert!(sample.get_mut(4).is_none());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Payload> {
+        self.payload_mut().get_mut(index)
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug, const N: usize, UserHeader>
+    SampleMut<Service, [Payload; N], UserHeader>
+{
+    /// Returns the number of elements of the array payload.
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the array payload does not contain any element.
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns a reference to the element at `index` or [`None`] when `index` is out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<&Payload> {
+        self.payload().get(index)
+    }
+
+    /// Returns a mutable reference to the element at `index` or [`None`] when `index` is out
+    /// of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Payload> {
+        self.payload_mut().get_mut(index)
+    }
+}
+
+impl<Service: crate::service::Service, UserHeader> SampleMut<Service, [u8], UserHeader> {
+    /// Copies all segments back to back into the payload of the [`SampleMut`], starting at
+    /// the beginning of the payload. Bytes that are not covered by the segments remain
+    /// untouched. On success, the number of written bytes is returned. When the combined
+    /// length of all segments exceeds the payload size, nothing is written and
+    /// [`WriteSegmentsError::ExceedsPayloadSize`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(16).create()?;
+    ///
+    /// let frame_header = [0xaa, 0xbb];
+    /// let frame_body = [1, 2, 3, 4];
+    ///
+    /// let mut sample = publisher.loan_slice(6)?;
+    /// sample.write_segments(&[&frame_header, &frame_body])?;
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_segments(&mut self, segments: &[&[u8]]) -> Result<usize, WriteSegmentsError> {
+        let total_len: usize = segments.iter().map(|segment| segment.len()).sum();
+        let payload_size = self.payload().len();
+
+        if payload_size < total_len {
+            fail!(from self, with WriteSegmentsError::ExceedsPayloadSize,
+                "Unable to write {} segments with a total length of {} bytes since the payload has a size of {} bytes.",
+                segments.len(), total_len, payload_size);
+        }
+
+        let payload = self.payload_mut();
+        let mut offset = 0;
+        for segment in segments {
+            payload[offset..offset + segment.len()].copy_from_slice(segment);
+            offset += segment.len();
+        }
+
+        Ok(total_len)
+    }
+
+    /// Copies the values into the payload, starting `offset` bytes after the start of the
+    /// payload, and returns a [`RelativePointer`] to them. The [`RelativePointer`] can be stored
+    /// in the payload itself and is resolved by the receiver with
+    /// [`Sample::resolve()`](crate::sample::Sample::resolve()). When the values do not fit into
+    /// the payload or would be misaligned, nothing is written and an error is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * `T` must not contain uninitialized bytes, like padding bytes, since the bytes are
+    ///    copied into the initialized byte payload
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .payload_alignment(Alignment::new(8).unwrap())
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(32).create()?;
+    ///
+    /// let mut sample = publisher.loan_slice(32)?;
+    /// let tail = unsafe { sample.write_relative(8, &[1u64, 2, 3])? };
+    /// unsafe { sample.write_relative(0, &[tail])? };
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn write_relative<T: Copy>(
+        &mut self,
+        offset: usize,
+        values: &[T],
+    ) -> Result<RelativePointer<T>, RelativePointerError> {
+        let ptr = RelativePointer::new(offset, values.len());
+        let payload = self.payload_mut();
+        let start = ptr.resolve_in(payload.as_ptr(), payload.len())? as *mut T;
+        core::ptr::copy_nonoverlapping(values.as_ptr(), start, values.len());
+
+        Ok(ptr)
+    }
+
+    /// Resolves a [`RelativePointer`] into the payload of the [`SampleMut`], see
+    /// [`Sample::resolve()`](crate::sample::Sample::resolve()).
+    ///
+    /// # Safety
+    ///
+    ///  * the referenced bytes must be a valid bit pattern of `T`, see
+    ///    [`Sample::resolve()`](crate::sample::Sample::resolve())
+    pub unsafe fn resolve<T: Copy>(
+        &self,
+        ptr: &RelativePointer<T>,
+    ) -> Result<&[T], RelativePointerError> {
+        let payload = self.payload();
+        let start = ptr.resolve_in(payload.as_ptr(), payload.len())?;
+        Ok(core::slice::from_raw_parts(start, ptr.len()))
     }
 }
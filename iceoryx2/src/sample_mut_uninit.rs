@@ -95,10 +95,13 @@ use core::{fmt::Debug, mem::MaybeUninit};
 extern crate alloc;
 use alloc::sync::Arc;
 
+use iceoryx2_bb_log::fail;
 use iceoryx2_cal::shm_allocator::PointerOffset;
 
 use crate::{
-    port::publisher::PublisherBackend, raw_sample::RawSampleMut, sample_mut::SampleMut,
+    port::publisher::{PublisherBackend, PublisherSendError},
+    raw_sample::RawSampleMut,
+    sample_mut::SampleMut,
     service::header::publish_subscribe::Header,
 };
 
@@ -108,7 +111,8 @@ use crate::{
 ///
 /// It stores the payload that will be sent
 /// to all connected [`crate::port::subscriber::Subscriber`]s. If the [`SampleMut`] is not sent
-/// it will release the loaned memory when going out of scope.
+/// it will release the loaned memory when going out of scope or when it is discarded with
+/// [`SampleMutUninit::abort()`].
 ///
 /// # Notes
 ///
@@ -258,6 +262,11 @@ impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader>
     pub fn payload_mut(&mut self) -> &mut Payload {
         self.sample.payload_mut()
     }
+
+    /// Discards the [`SampleMutUninit`] without sending it, see [`SampleMut::abort()`].
+    pub fn abort(self) {
+        self.sample.abort()
+    }
 }
 
 impl<Service: crate::service::Service, Payload: Debug, UserHeader>
@@ -266,6 +275,7 @@ impl<Service: crate::service::Service, Payload: Debug, UserHeader>
     pub(crate) fn new(
         publisher_backend: &Arc<PublisherBackend<Service>>,
         ptr: RawSampleMut<Header, UserHeader, MaybeUninit<Payload>>,
+        header: Header,
         offset_to_chunk: PointerOffset,
         sample_size: usize,
     ) -> Self {
@@ -273,6 +283,7 @@ impl<Service: crate::service::Service, Payload: Debug, UserHeader>
             sample: SampleMut {
                 publisher_backend: Arc::clone(publisher_backend),
                 ptr,
+                header,
                 offset_to_chunk,
                 sample_size,
             },
@@ -348,6 +359,7 @@ impl<Service: crate::service::Service, Payload: Debug, UserHeader>
     pub(crate) fn new(
         publisher_backend: &Arc<PublisherBackend<Service>>,
         ptr: RawSampleMut<Header, UserHeader, [MaybeUninit<Payload>]>,
+        header: Header,
         offset_to_chunk: PointerOffset,
         sample_size: usize,
     ) -> Self {
@@ -355,6 +367,7 @@ impl<Service: crate::service::Service, Payload: Debug, UserHeader>
             sample: SampleMut {
                 publisher_backend: Arc::clone(publisher_backend),
                 ptr,
+                header,
                 offset_to_chunk,
                 sample_size,
             },
@@ -437,6 +450,143 @@ impl<Service: crate::service::Service, Payload: Debug, UserHeader>
         // SAFETY: this is safe since the payload was initialized on the line above
         unsafe { self.assume_init() }
     }
+
+    /// Writes the elements provided by the iterator into the slice. If the iterator provides at
+    /// least as many elements as the slice has, the sample is labeled as initialized and the
+    /// remaining elements of the iterator are not consumed. Otherwise, the written elements are
+    /// returned as [`SampleMutPartial`] which can only be sent with
+    /// [`SampleMutPartial::send_partial()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[usize]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(16).create()?;
+    ///
+    /// let sample = publisher.loan_slice_uninit(8)?;
+    /// match sample.write_from_iter(0..5) {
+    ///     Ok(sample) => sample.send()?,
+    ///     // the iterator ended after 5 elements, send only them
+    ///     Err(sample) => {
+    ///         let written_len = sample.written_len();
+    ///         sample.send_partial(written_len)?
+    ///     }
+    /// };
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity, clippy::result_large_err)]
+    pub fn write_from_iter<I: IntoIterator<Item = Payload>>(
+        mut self,
+        iter: I,
+    ) -> Result<
+        SampleMut<Service, [Payload], UserHeader>,
+        SampleMutPartial<Service, Payload, UserHeader>,
+    > {
+        let mut written_len = 0;
+        let mut iter = iter.into_iter();
+        for element in self.payload_mut().iter_mut() {
+            match iter.next() {
+                Some(value) => element.write(value),
+                None => break,
+            };
+            written_len += 1;
+        }
+
+        if written_len < self.payload().len() {
+            return Err(SampleMutPartial {
+                sample: self,
+                written_len,
+            });
+        }
+
+        // SAFETY: this is safe since every element was initialized in the loop above
+        Ok(unsafe { self.assume_init() })
+    }
+}
+
+/// Acquired by [`SampleMutUninit::write_from_iter()`] when the iterator ended before every
+/// element of the slice was written. Only the first [`SampleMutPartial::written_len()`]
+/// elements are initialized, therefore it can only be sent with
+/// [`SampleMutPartial::send_partial()`] which explicitly records the shorter length. If it is
+/// not sent it releases the loaned memory when going out of scope.
+pub struct SampleMutPartial<Service: crate::service::Service, Payload: Debug, UserHeader> {
+    sample: SampleMutUninit<Service, [MaybeUninit<Payload>], UserHeader>,
+    written_len: usize,
+}
+
+impl<Service: crate::service::Service, Payload: Debug, UserHeader> Debug
+    for SampleMutPartial<Service, Payload, UserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SampleMutPartial<{}, {}, {}> {{ sample: {:?}, written_len: {} }}",
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<UserHeader>(),
+            core::any::type_name::<Service>(),
+            self.sample.sample,
+            self.written_len
+        )
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug, UserHeader>
+    SampleMutPartial<Service, Payload, UserHeader>
+{
+    /// Returns the number of elements that were written, starting from the beginning of the
+    /// slice.
+    pub fn written_len(&self) -> usize {
+        self.written_len
+    }
+
+    /// Returns the number of elements that were loaned.
+    pub fn loaned_len(&self) -> usize {
+        self.sample.payload().len()
+    }
+
+    /// Returns a reference to the written elements.
+    pub fn payload(&self) -> &[Payload] {
+        // SAFETY: the first written_len elements were initialized by write_from_iter()
+        unsafe {
+            core::slice::from_raw_parts(
+                self.sample.payload().as_ptr().cast::<Payload>(),
+                self.written_len,
+            )
+        }
+    }
+
+    /// Sends only the first `used` elements of the slice to all connected
+    /// [`crate::port::subscriber::Subscriber`]s, they receive a slice with `used` elements.
+    /// If `used` exceeds [`SampleMutPartial::written_len()`]
+    /// [`PublisherSendError::ExceedsMaxMessageSize`] is returned since the remaining elements
+    /// are not initialized. Otherwise, it behaves like [`SampleMut::send()`].
+    pub fn send_partial(self, used: usize) -> Result<usize, PublisherSendError> {
+        if self.written_len < used {
+            fail!(from self, with PublisherSendError::ExceedsMaxMessageSize,
+                "Unable to send partial sample since only {} of the requested {} elements were written.",
+                self.written_len, used);
+        }
+
+        let mut sample = self.sample.sample;
+        let used_len = sample.publisher_backend.max_used_len(used);
+        sample.header.set_number_of_elements(used as _);
+        sample.header.set_used_len(used_len as _);
+
+        // SAFETY: the first `used` elements are initialized and the header restricts the
+        // payload the subscribers receive to them
+        let sample: SampleMut<Service, [Payload], UserHeader> =
+            unsafe { core::mem::transmute(sample) };
+        sample.send()
+    }
 }
 
 impl<Service: crate::service::Service, Payload: Debug + Copy, UserHeader>
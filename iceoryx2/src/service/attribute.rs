@@ -57,6 +57,8 @@
 //!         &AttributeVerifier::new()
 //!             .require("another key", "another value")
 //!             .require_key("some attribute key")
+//!             // at least one of the alternatives must be defined by the service
+//!             .require_any_of(&[("codec", "h264"), ("codec", "h265")])
 //!     )?;
 //!
 //! # Ok(())
@@ -149,12 +151,19 @@ impl AttributeSpecifier {
     }
 }
 
+#[derive(Debug)]
+struct AlternativeGroup {
+    attribute_set: AttributeSet,
+    description: String,
+}
+
 /// Represents the set of [`Attribute`]s that are required when the [`crate::service::Service`]
 /// is opened.
 #[derive(Debug)]
 pub struct AttributeVerifier {
     attribute_set: AttributeSet,
     required_keys: Vec<String>,
+    alternative_groups: Vec<AlternativeGroup>,
 }
 
 impl Default for AttributeVerifier {
@@ -162,6 +171,7 @@ impl Default for AttributeVerifier {
         Self {
             attribute_set: AttributeSet::new(),
             required_keys: Vec::new(),
+            alternative_groups: Vec::new(),
         }
     }
 }
@@ -184,17 +194,51 @@ impl AttributeVerifier {
         self
     }
 
+    /// Requires that at least one of the provided key-value pairs is defined. Every call adds
+    /// a new group that must be satisfied in addition to all other requirements. An empty group
+    /// can never be satisfied.
+    pub fn require_any_of(mut self, alternatives: &[(&str, &str)]) -> Self {
+        let mut attribute_set = AttributeSet::new();
+        for (key, value) in alternatives {
+            attribute_set.add(key, value);
+        }
+
+        let description = format!(
+            "({})",
+            alternatives
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect::<Vec<_>>()
+                .join(" OR ")
+        );
+
+        self.alternative_groups.push(AlternativeGroup {
+            attribute_set,
+            description,
+        });
+        self
+    }
+
     /// Returns the underlying required [`AttributeSet`]
     pub fn attributes(&self) -> &AttributeSet {
         &self.attribute_set
     }
 
+    /// Returns the groups of alternative [`Attribute`]s added with
+    /// [`AttributeVerifier::require_any_of()`].
+    pub fn alternatives(&self) -> impl Iterator<Item = &AttributeSet> {
+        self.alternative_groups.iter().map(|g| &g.attribute_set)
+    }
+
     /// Returns the underlying required keys
     pub fn keys(&self) -> &Vec<String> {
         &self.required_keys
     }
 
-    /// Verifies if the [`AttributeSet`] contains all required keys and key-value pairs.
+    /// Verifies if the [`AttributeSet`] contains all required keys and key-value pairs and
+    /// at least one key-value pair of every alternative group. On failure, it returns the
+    /// incompatible key or the description of the unsatisfied alternative group, e.g.
+    /// `(codec=h264 OR codec=h265)`.
     pub fn verify_requirements(&self, rhs: &AttributeSet) -> Result<(), &str> {
         let is_subset = |lhs: Vec<&str>, rhs: Vec<&str>| lhs.iter().all(|v| rhs.contains(v));
 
@@ -213,6 +257,17 @@ impl AttributeVerifier {
             }
         }
 
+        for group in &self.alternative_groups {
+            let is_satisfied = group.attribute_set.iter().any(|attribute| {
+                rhs.get_vec(&attribute.key)
+                    .contains(&attribute.value.as_str())
+            });
+
+            if !is_satisfied {
+                return Err(&group.description);
+            }
+        }
+
         Ok(())
     }
 }
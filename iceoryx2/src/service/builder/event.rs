@@ -20,6 +20,7 @@ use crate::service::port_factory::event;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
 use crate::service::{self, dynamic_config::event::DynamicConfigSettings};
+use crate::span::enter_span;
 use builder::RETRY_LIMIT;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_posix::clock::Time;
@@ -28,7 +29,7 @@ use static_config::event::Deadline;
 
 use self::attribute::{AttributeSpecifier, AttributeVerifier};
 
-use super::ServiceState;
+use super::{CreationOutcome, ServiceState};
 
 /// Failures that can occur when an existing [`MessagingPattern::Event`] [`Service`] shall be opened.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -74,6 +75,9 @@ pub enum EventOpenError {
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The dynamic [`Service`] segment exists but could not be opened. The underlying cause is
+    /// available via [`std::error::Error::source()`].
+    DynamicStorageOpenFailure(DynamicStorageOpenError),
 }
 
 impl core::fmt::Display for EventOpenError {
@@ -82,7 +86,14 @@ impl core::fmt::Display for EventOpenError {
     }
 }
 
-impl std::error::Error for EventOpenError {}
+impl std::error::Error for EventOpenError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EventOpenError::DynamicStorageOpenFailure(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl From<ServiceState> for EventOpenError {
     fn from(value: ServiceState) -> Self {
@@ -113,6 +124,12 @@ pub enum EventCreateError {
     HangsInCreation,
     /// The process has insufficient permissions to create the [`Service`].
     InsufficientPermissions,
+    /// The static [`Service`] information could not be created. The underlying cause is
+    /// available via [`std::error::Error::source()`].
+    StaticStorageCreationFailure(StaticStorageCreateError),
+    /// The dynamic [`Service`] segment could not be created. The underlying cause is
+    /// available via [`std::error::Error::source()`].
+    DynamicStorageCreationFailure(DynamicStorageCreateError),
 }
 
 impl core::fmt::Display for EventCreateError {
@@ -121,7 +138,15 @@ impl core::fmt::Display for EventCreateError {
     }
 }
 
-impl std::error::Error for EventCreateError {}
+impl std::error::Error for EventCreateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EventCreateError::StaticStorageCreationFailure(e) => Some(e),
+            EventCreateError::DynamicStorageCreationFailure(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl From<ServiceState> for EventCreateError {
     fn from(value: ServiceState) -> Self {
@@ -165,7 +190,15 @@ impl core::fmt::Display for EventOpenOrCreateError {
     }
 }
 
-impl std::error::Error for EventOpenOrCreateError {}
+impl std::error::Error for EventOpenOrCreateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EventOpenOrCreateError::EventOpenError(e) => Some(e),
+            EventOpenOrCreateError::EventCreateError(e) => Some(e),
+            EventOpenOrCreateError::SystemInFlux => None,
+        }
+    }
+}
 
 impl From<ServiceState> for EventOpenOrCreateError {
     fn from(value: ServiceState) -> Self {
@@ -335,9 +368,28 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
     /// requirements must be satisfied otherwise the open process will fail. If the [`Service`]
     /// does not exist the required attributes will be defined in the [`Service`].
     pub fn open_or_create_with_attributes(
-        mut self,
+        self,
         required_attributes: &AttributeVerifier,
     ) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
+        self.open_or_create_impl(required_attributes)
+            .map(|(factory, _)| factory)
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. Additionally to the port factory it returns the [`CreationOutcome`] that
+    /// states whether the [`Service`] was created by this call or opened. When multiple
+    /// instances call it concurrently, exactly one of them observes
+    /// [`CreationOutcome::Created`].
+    pub fn open_or_create_with_outcome(
+        self,
+    ) -> Result<(event::PortFactory<ServiceType>, CreationOutcome), EventOpenOrCreateError> {
+        self.open_or_create_impl(&AttributeVerifier::new())
+    }
+
+    fn open_or_create_impl(
+        mut self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<(event::PortFactory<ServiceType>, CreationOutcome), EventOpenOrCreateError> {
         let msg = "Unable to open or create event service";
 
         let mut retry_count = 0;
@@ -351,12 +403,17 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             retry_count += 1;
 
             match self.base.is_service_available(msg)? {
-                Some(_) => return Ok(self.open_with_attributes(required_attributes)?),
+                Some(_) => {
+                    return Ok((
+                        self.open_with_attributes(required_attributes)?,
+                        CreationOutcome::Opened,
+                    ))
+                }
                 None => {
                     match self.create_impl(&AttributeSpecifier(
                         required_attributes.attributes().clone(),
                     )) {
-                        Ok(factory) => return Ok(factory),
+                        Ok(factory) => return Ok((factory, CreationOutcome::Created)),
                         Err(EventCreateError::AlreadyExists)
                         | Err(EventCreateError::IsBeingCreatedByAnotherInstance) => {
                             continue;
@@ -379,6 +436,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         mut self,
         required_attributes: &AttributeVerifier,
     ) -> Result<event::PortFactory<ServiceType>, EventOpenError> {
+        enter_span!(DEBUG, "open", service = %self.base.service_config.name());
         let msg = "Unable to open event service";
 
         let mut service_open_retry_count = 0;
@@ -412,7 +470,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                             fail!(from self, with EventOpenError::ServiceInCorruptedState,
                                 "{} since the dynamic segment of the service is missing.", msg);
                         }
-                        Err(e) => {
+                        Err(OpenDynamicStorageFailure::DynamicStorageOpenError(e)) => {
                             if self.base.is_service_available(msg)?.is_none() {
                                 fail!(from self, with EventOpenError::DoesNotExist,
                                     "{} since the event does not exist.", msg);
@@ -421,7 +479,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                             service_open_retry_count += 1;
 
                             if RETRY_LIMIT < service_open_retry_count {
-                                fail!(from self, with EventOpenError::ServiceInCorruptedState,
+                                fail!(from self, with EventOpenError::DynamicStorageOpenFailure(e),
                                 "{} since the dynamic service information could not be opened ({:?}). This could indicate a corrupted system or a misconfigured system where services are created/removed with a high frequency.",
                                 msg, e);
                             }
@@ -441,6 +499,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                         service::ServiceState::new(
                             static_config,
                             self.base.shared_node,
+                            self.base.directory,
                             dynamic_config,
                             static_storage,
                         ),
@@ -467,6 +526,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         &mut self,
         attributes: &AttributeSpecifier,
     ) -> Result<event::PortFactory<ServiceType>, EventCreateError> {
+        enter_span!(DEBUG, "create", service = %self.base.service_config.name());
         self.adjust_attributes_to_meaningful_values();
 
         let msg = "Unable to create event service";
@@ -500,7 +560,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                             "{} since the static service information could not be created due to insufficient permissions.", msg);
                     }
                     Err(e) => {
-                        fail!(from self, with EventCreateError::InternalFailure,
+                        fail!(from self, with EventCreateError::StaticStorageCreationFailure(e),
                             "{} since the static service information could not be created ({:?}).", msg, e);
                     }
                 };
@@ -525,14 +585,14 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                             "{} since there exist an old dynamic config from a previous instance of the service.", msg);
                     }
                     Err(e) => {
-                        fail!(from self, with EventCreateError::InternalFailure,
+                        fail!(from self, with EventCreateError::DynamicStorageCreationFailure(e),
                             "{} since the dynamic service segment could not be created ({:?}).", msg, e);
                     }
                 };
 
                 self.base.service_config.attributes = attributes.0.clone();
 
-                let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                let service_config = fail!(from self, when self.base.shared_node.config().global.service.static_config_format.serialize(&self.base.service_config),
                                             with EventCreateError::ServiceInCorruptedState,
                                             "{} since the configuration could not be serialized.", msg);
 
@@ -550,6 +610,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                     service::ServiceState::new(
                         self.base.service_config.clone(),
                         self.base.shared_node.clone(),
+                        self.base.directory,
                         dynamic_config,
                         unlocked_static_details,
                     ),
@@ -593,7 +654,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         if let Err(incompatible_key) = required_attributes.verify_requirements(existing_attributes)
         {
             fail!(from self, with EventOpenError::IncompatibleAttributes,
-                "{} due to incompatible service attribute key {}. The following attributes {:?} are required but the service has the attributes {:?}.",
+                "{} due to incompatible service attribute requirement {}. The following attributes {:?} are required but the service has the attributes {:?}.",
                 msg, incompatible_key, required_attributes, existing_attributes);
         }
 
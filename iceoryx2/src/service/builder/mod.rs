@@ -20,6 +20,10 @@ pub mod event;
 /// Builder for [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 pub mod publish_subscribe;
 
+/// Builder for [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// services that carry multiple payload types
+pub mod publish_subscribe_multi;
+
 /// Builder for [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
 pub mod request_response;
 
@@ -30,17 +34,21 @@ use crate::service::dynamic_config::RegisterNodeResult;
 use crate::service::static_config::*;
 use core::fmt::Debug;
 use core::marker::PhantomData;
+use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::debug;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::warn;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::clock::{nanosleep, ClockType, Time};
+use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::dynamic_storage::DynamicStorageOpenError;
 use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::named_concept::NamedConceptDoesExistError;
 use iceoryx2_cal::named_concept::NamedConceptMgmt;
-use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::*;
 
 extern crate alloc;
@@ -49,6 +57,7 @@ use alloc::sync::Arc;
 use super::config_scheme::dynamic_config_storage_config;
 use super::config_scheme::service_tag_config;
 use super::config_scheme::static_config_storage_config;
+use super::internal::reap_dead_ports;
 use super::service_name::ServiceName;
 use super::Service;
 
@@ -78,7 +87,14 @@ impl core::fmt::Display for OpenDynamicStorageFailure {
     }
 }
 
-impl std::error::Error for OpenDynamicStorageFailure {}
+impl std::error::Error for OpenDynamicStorageFailure {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OpenDynamicStorageFailure::DynamicStorageOpenError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 enum_gen! {
 #[doc(hidden)]
@@ -94,7 +110,26 @@ impl core::fmt::Display for ReadStaticStorageFailure {
     }
 }
 
-impl std::error::Error for ReadStaticStorageFailure {}
+impl std::error::Error for ReadStaticStorageFailure {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadStaticStorageFailure::StaticStorageOpenError(e) => Some(e),
+            ReadStaticStorageFailure::StaticStorageReadError(e) => Some(e),
+        }
+    }
+}
+
+/// Describes whether an `open_or_create_with_outcome()` call, like
+/// [`publish_subscribe::Builder::open_or_create_with_outcome()`], has created the
+/// [`Service`] or opened an already existing one. When multiple instances call it concurrently
+/// for the same [`Service`], exactly one of them observes [`CreationOutcome::Created`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum CreationOutcome {
+    /// The [`Service`] did not exist and was created by this call.
+    Created,
+    /// The [`Service`] already existed and was opened by this call.
+    Opened,
+}
 
 /// Builder to create or open [`Service`]s
 ///
@@ -105,31 +140,64 @@ impl std::error::Error for ReadStaticStorageFailure {}
 pub struct Builder<S: Service> {
     name: ServiceName,
     shared_node: Arc<SharedNode<S>>,
+    directory: Option<Path>,
     _phantom_s: PhantomData<S>,
 }
 
 impl<S: Service> Builder<S> {
     pub(crate) fn new(name: &ServiceName, shared_node: Arc<SharedNode<S>>) -> Self {
         Self {
-            name: name.clone(),
+            name: name.normalize(&shared_node.config().global.service),
             shared_node,
+            directory: None,
             _phantom_s: PhantomData,
         }
     }
 
+    /// Stores all resources of the [`Service`], like the static and dynamic configuration
+    /// and the payload data segments, under the provided directory instead of the root path
+    /// defined in the [`Config`](crate::config::Config) of the [`Node`](crate::node::Node).
+    /// Every participant of the [`Service`] must use the same directory. Services with the
+    /// same name that reside in different directories are independent of each other.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_bb_container::semantic_string::SemanticString;
+    /// use iceoryx2_bb_system_types::path::Path;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .in_directory(&Path::new(b"/tmp/iceoryx2/my_domain/")?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn in_directory(mut self, directory: &Path) -> Self {
+        self.directory = Some(*directory);
+        self
+    }
+
+    fn service_type_builder(self, mut service_config: StaticConfig) -> BuilderWithServiceType<S> {
+        if let Some(directory) = &self.directory {
+            service_config.place_in_directory::<S::ServiceNameHasher>(directory);
+        }
+
+        BuilderWithServiceType::new(service_config, self.shared_node, self.directory)
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`].
     pub fn request_response<RequestPayload: Debug, ResponsePayload: Debug>(
         self,
     ) -> request_response::Builder<RequestPayload, (), ResponsePayload, (), S> {
-        BuilderWithServiceType::new(
-            StaticConfig::new_request_response::<S::ServiceNameHasher>(
-                &self.name,
-                self.shared_node.config(),
-            ),
-            self.shared_node,
-        )
-        .request_response::<RequestPayload, ResponsePayload>()
+        let static_config = StaticConfig::new_request_response::<S::ServiceNameHasher>(
+            &self.name,
+            self.shared_node.config(),
+        );
+        self.service_type_builder(static_config)
+            .request_response::<RequestPayload, ResponsePayload>()
     }
 
     /// Create a new builder to create a
@@ -137,24 +205,30 @@ impl<S: Service> Builder<S> {
     pub fn publish_subscribe<PayloadType: Debug + ?Sized>(
         self,
     ) -> publish_subscribe::Builder<PayloadType, (), S> {
-        BuilderWithServiceType::new(
-            StaticConfig::new_publish_subscribe::<S::ServiceNameHasher>(
-                &self.name,
-                self.shared_node.config(),
-            ),
-            self.shared_node,
+        let static_config = StaticConfig::new_publish_subscribe::<S::ServiceNameHasher>(
+            &self.name,
+            self.shared_node.config(),
+        );
+        self.service_type_builder(static_config).publish_subscribe()
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`]
+    /// that carries samples of every type registered with
+    /// [`publish_subscribe_multi::Builder::register()`].
+    pub fn publish_subscribe_multi(self) -> publish_subscribe_multi::Builder<S> {
+        publish_subscribe_multi::Builder::new(
+            self.publish_subscribe::<[publish_subscribe::CustomPayloadMarker]>()
+                .user_header::<publish_subscribe_multi::TypeTag>(),
         )
-        .publish_subscribe()
     }
 
     /// Create a new builder to create a
     /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
     pub fn event(self) -> event::Builder<S> {
-        BuilderWithServiceType::new(
-            StaticConfig::new_event::<S::ServiceNameHasher>(&self.name, self.shared_node.config()),
-            self.shared_node,
-        )
-        .event()
+        let static_config =
+            StaticConfig::new_event::<S::ServiceNameHasher>(&self.name, self.shared_node.config());
+        self.service_type_builder(static_config).event()
     }
 }
 
@@ -163,14 +237,20 @@ impl<S: Service> Builder<S> {
 pub struct BuilderWithServiceType<ServiceType: service::Service> {
     service_config: StaticConfig,
     shared_node: Arc<SharedNode<ServiceType>>,
+    directory: Option<Path>,
     _phantom_data: PhantomData<ServiceType>,
 }
 
 impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
-    fn new(service_config: StaticConfig, shared_node: Arc<SharedNode<ServiceType>>) -> Self {
+    fn new(
+        service_config: StaticConfig,
+        shared_node: Arc<SharedNode<ServiceType>>,
+        directory: Option<Path>,
+    ) -> Self {
         Self {
             service_config,
             shared_node,
+            directory,
             _phantom_data: PhantomData,
         }
     }
@@ -191,14 +271,48 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         event::Builder::new(self)
     }
 
+    fn open_static_config_storage(
+        &self,
+        msg: &str,
+    ) -> Result<Option<ServiceType::StaticStorage>, ServiceState> {
+        let static_storage_config = static_config_storage_config::<ServiceType>(
+            self.shared_node.config(),
+            self.directory.as_ref(),
+        );
+        let file_name_uuid = self.service_config.service_id().0.into();
+        let creation_timeout = self.shared_node.config().global.service.creation_timeout;
+
+        match <<ServiceType::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+            ServiceType::StaticStorage,
+        >>::new(&file_name_uuid)
+        .has_ownership(false)
+        .config(&static_storage_config)
+        .open(creation_timeout)
+        {
+            Ok(storage) => Ok(Some(storage)),
+            Err(StaticStorageOpenError::DoesNotExist) => Ok(None),
+            Err(StaticStorageOpenError::InitializationNotYetFinalized) => {
+                fail!(from self, with ServiceState::HangsInCreation,
+                    "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded.",
+                    msg, creation_timeout);
+            }
+            Err(e) => {
+                fail!(from self, with ServiceState::InsufficientPermissions,
+                    "{} since it is not possible to open the services underlying static details ({:?}). Is the service accessible?",
+                    msg, e);
+            }
+        }
+    }
+
     fn is_service_available(
         &self,
         msg: &str,
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceState> {
-        let static_storage_config =
-            static_config_storage_config::<ServiceType>(self.shared_node.config());
+        let static_storage_config = static_config_storage_config::<ServiceType>(
+            self.shared_node.config(),
+            self.directory.as_ref(),
+        );
         let file_name_uuid = self.service_config.service_id().0.into();
-        let creation_timeout = self.shared_node.config().global.service.creation_timeout;
 
         match <ServiceType::StaticStorage as NamedConceptMgmt>::does_exist_cfg(
             &file_name_uuid,
@@ -206,39 +320,18 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         ) {
             Ok(false) => Ok(None),
             Ok(true) | Err(NamedConceptDoesExistError::UnderlyingResourcesBeingSetUp) => {
-                let storage = match <<ServiceType::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
-                                       ServiceType::StaticStorage>>
-                                       ::new(&file_name_uuid)
-                                        .has_ownership(false)
-                                        .config(&static_storage_config)
-                                        .open(creation_timeout) {
-                        Ok(storage) => storage,
-                        Err(StaticStorageOpenError::DoesNotExist) => return Ok(None),
-                        Err(StaticStorageOpenError::InitializationNotYetFinalized) => {
-                            fail!(from self, with ServiceState::HangsInCreation,
-                                "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded.",
-                                msg, creation_timeout);
-                        },
-                        Err(e) =>
-                        {
-                            fail!(from self, with ServiceState::InsufficientPermissions,
-                                    "{} since it is not possible to open the services underlying static details ({:?}). Is the service accessible?",
-                                    msg, e);
-                        }
-                    };
-
-                let mut read_content =
-                    String::from_utf8(vec![b' '; storage.len() as usize]).expect("");
-                if storage
-                    .read(unsafe { read_content.as_mut_vec() }.as_mut_slice())
-                    .is_err()
-                {
+                let storage = match self.open_static_config_storage(msg)? {
+                    Some(storage) => storage,
+                    None => return Ok(None),
+                };
+
+                let mut read_content = vec![0u8; storage.len() as usize];
+                if storage.read(read_content.as_mut_slice()).is_err() {
                     fail!(from self, with ServiceState::InsufficientPermissions,
                             "{} since it is not possible to read the services underlying static details. Is the service accessible?", msg);
                 }
 
-                let service_config = fail!(from self, when ServiceType::ConfigSerializer::deserialize::<StaticConfig>(unsafe {
-                                            read_content.as_mut_vec() }),
+                let service_config = fail!(from self, when StaticConfigFormat::deserialize(&read_content),
                                      with ServiceState::Corrupted, "Unable to deserialize the service config. Is the service corrupted?");
 
                 if service_config.service_id() != self.service_config.service_id() {
@@ -275,16 +368,20 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
     ) -> Result<ServiceType::DynamicStorage, DynamicStorageCreateError> {
         let msg = "Failed to create dynamic storage for service";
         let required_memory_size = DynamicConfig::memory_size(max_number_of_nodes);
+        let now = fail!(from self, when Time::now_with_clock(ClockType::Monotonic),
+                        with DynamicStorageCreateError::InternalError,
+                        "{} since the current time could not be acquired to determine the storage generation.", msg);
+        let generation = now.as_duration().as_nanos() as u64;
         match <<ServiceType::DynamicStorage as DynamicStorage<
             DynamicConfig,
         >>::Builder<'_> as NamedConceptBuilder<
             ServiceType::DynamicStorage,
         >>::new(&self.service_config.service_id().0.into())
-            .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config()))
+            .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config(), self.directory.as_ref()))
             .supplementary_size(additional_size + required_memory_size)
             .has_ownership(false)
             .initializer(Self::config_init_call)
-            .create(DynamicConfig::new_uninit(messaging_pattern, max_number_of_nodes) ) {
+            .create(DynamicConfig::new_uninit(messaging_pattern, max_number_of_nodes, generation) ) {
                 Ok(dynamic_storage) => {
                     let node_id = self.shared_node.id();
                     let node_handle = fatal_panic!(from self,
@@ -303,17 +400,39 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         &self,
     ) -> Result<ServiceType::DynamicStorage, OpenDynamicStorageFailure> {
         let msg = "Failed to open dynamic service information";
-        let storage = fail!(from self, when
-            <<ServiceType::DynamicStorage as DynamicStorage<
+        let service_config = &self.shared_node.config().global.service;
+        let mut retry_count = 0;
+        let storage = loop {
+            match <<ServiceType::DynamicStorage as DynamicStorage<
                     DynamicConfig,
                 >>::Builder<'_> as NamedConceptBuilder<
                     ServiceType::DynamicStorage,
                 >>::new(&self.service_config.service_id().0.into())
-                    .timeout(self.shared_node.config().global.service.creation_timeout)
-                    .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config()))
+                    .timeout(service_config.creation_timeout)
+                    .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config(), self.directory.as_ref()))
                 .has_ownership(false)
-                .open(),
-            "{} since the dynamic storage could not be opened.", msg);
+                .open()
+            {
+                Ok(storage) => break storage,
+                Err(
+                    e @ (DynamicStorageOpenError::DoesNotExist
+                    | DynamicStorageOpenError::InitializationNotYetFinalized),
+                ) if retry_count < service_config.dynamic_config_open_retries => {
+                    retry_count += 1;
+                    debug!(from self,
+                        "{} ({:?}), the creator may still initialize the service. Retry {} of {} in {:?}.",
+                        msg, e, retry_count, service_config.dynamic_config_open_retries,
+                        service_config.dynamic_config_open_retry_delay);
+                    if let Err(e) = nanosleep(service_config.dynamic_config_open_retry_delay) {
+                        warn!(from self, "Unable to wait for the next attempt to open the dynamic storage ({:?}).", e);
+                    }
+                }
+                Err(e) => {
+                    fail!(from self, with e.into(),
+                        "{} since the dynamic storage could not be opened.", msg);
+                }
+            }
+        };
 
         self.shared_node
             .registered_services()
@@ -332,6 +451,18 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                 }
             })?;
 
+        if service_config.cleanup_dead_ports_on_open {
+            let number_of_reaped_ports = reap_dead_ports::<ServiceType>(
+                storage.get(),
+                self.shared_node.config(),
+                self.directory.as_ref(),
+            );
+            if number_of_reaped_ports != 0 {
+                debug!(from self, "Removed {} ports of dead processes while opening the service.",
+                    number_of_reaped_ports);
+            }
+        }
+
         Ok(storage)
     }
 
@@ -348,8 +479,10 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
             self.shared_node.id(),
         ))
         .has_ownership(true)
-        .create(&[])
-        {
+        .create(match &self.directory {
+            Some(directory) => directory.as_bytes(),
+            None => &[],
+        }) {
             Ok(static_storage) => Ok(Some(static_storage)),
             Err(StaticStorageCreateError::AlreadyExists) => Ok(None),
             Err(e) => {
@@ -367,9 +500,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
             fail!(from self, when <<ServiceType::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
                         ServiceType::StaticStorage,
                     >>::new(&self.service_config.service_id().0.into())
-                    .config(&static_config_storage_config::<ServiceType>(
-                        self.shared_node.config(),
-                    ))
+                    .config(&static_config_storage_config::<ServiceType>(self.shared_node.config(), self.directory.as_ref()))
                     .has_ownership(true)
                     .create_locked(),
                     "Failed to create static service information since the underlying static storage could not be created."),
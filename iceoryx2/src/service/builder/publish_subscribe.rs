@@ -15,26 +15,30 @@
 //! See [`crate::service`]
 //!
 use core::marker::PhantomData;
+use core::time::Duration;
 
+use crate::config::ConfigSource;
 use crate::service;
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::header::publish_subscribe::Header;
 use crate::service::port_factory::publish_subscribe;
+use crate::service::service_handle::ServiceHandle;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
+use crate::span::enter_span;
 use builder::RETRY_LIMIT;
 use iceoryx2_bb_elementary::alignment::Alignment;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
-use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::shm_allocator::PoolAllocationStrategy;
 use iceoryx2_cal::static_storage::StaticStorageLocked;
 
 use self::{
     attribute::{AttributeSpecifier, AttributeVerifier},
-    message_type_details::{MessageTypeDetails, TypeDetail, TypeVariant},
+    message_type_details::{MessageTypeDetails, SchemaEvolution, TypeDetail, TypeVariant},
 };
 
-use super::{OpenDynamicStorageFailure, ServiceState};
+use super::{CreationOutcome, OpenDynamicStorageFailure, ServiceState};
 
 #[repr(C)]
 #[derive(Debug)]
@@ -73,6 +77,21 @@ pub enum PublishSubscribeOpenError {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`] required last value mode is not compatible.
+    IncompatibleLastValueMode,
+    /// The [`Service`] uses a different [`PoolAllocationStrategy`] than required.
+    IncompatiblePoolAllocationStrategy,
+    /// The [`Service`] uses a different coalescing window than required.
+    IncompatibleCoalescingWindow,
+    /// The [`Service`] uses a different slow subscriber eviction policy than required.
+    IncompatibleSlowSubscriberEviction,
+    /// The [`Service`] has a different sample memory pinning setting than required.
+    IncompatibleSampleMemoryPinning,
+    /// The [`Service`] supports a smaller maximum message size than requested.
+    DoesNotSupportRequestedMaxMessageSize,
+    /// The [`Service`] provides fewer sample slots per
+    /// [`Publisher`](crate::port::publisher::Publisher) than the requested pool capacity.
+    DoesNotSupportRequestedPoolCapacity,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
@@ -86,6 +105,12 @@ pub enum PublishSubscribeOpenError {
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`ServiceHandle`] refers to another [`Service`] or to a [`Service`] that was
+    /// destroyed and possibly recreated in the meantime.
+    StaleServiceHandle,
+    /// The dynamic [`Service`] segment exists but could not be opened. The underlying cause is
+    /// available via [`std::error::Error::source()`].
+    DynamicStorageOpenFailure(DynamicStorageOpenError),
 }
 
 impl core::fmt::Display for PublishSubscribeOpenError {
@@ -94,7 +119,14 @@ impl core::fmt::Display for PublishSubscribeOpenError {
     }
 }
 
-impl std::error::Error for PublishSubscribeOpenError {}
+impl std::error::Error for PublishSubscribeOpenError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PublishSubscribeOpenError::DynamicStorageOpenFailure(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl From<ServiceAvailabilityState> for PublishSubscribeOpenError {
     fn from(value: ServiceAvailabilityState) -> Self {
@@ -127,6 +159,24 @@ pub enum PublishSubscribeCreateError {
     /// [`Subscriber`](crate::port::subscriber::Subscriber)s buffer size must be at least the size
     /// of the history. Otherwise, how could it hold the whole history?
     SubscriberBufferMustBeLargerThanHistorySize,
+    /// The payload type has a size of zero, like `()` or an empty struct. Such a payload
+    /// carries no data, use a [`MessagingPattern::Event`] [`Service`] to signal occurrences
+    /// instead.
+    PayloadTypeHasZeroSize,
+    /// The worst-case number of sample slots of the [`Service`] exceeds the configured
+    /// [`max_sample_slots`](crate::config::PublishSubscribe::max_sample_slots).
+    ExceedsMaxSampleSlots,
+    /// The pool capacity provided with [`Builder::pool_capacity()`] is smaller than the number
+    /// of sample slots a [`Publisher`](crate::port::publisher::Publisher) requires at least.
+    PoolCapacityBelowRequiredMinimum,
+    /// The [`Config`](crate::config::Config) defines a
+    /// [`subscriber_max_buffer_size`](crate::config::PublishSubscribe::subscriber_max_buffer_size)
+    /// of zero and no other value was provided to the [`Builder`].
+    SubscriberMaxBufferSizeIsZero,
+    /// The [`Config`](crate::config::Config) defines
+    /// [`subscriber_max_borrowed_samples`](crate::config::PublishSubscribe::subscriber_max_borrowed_samples)
+    /// of zero and no other value was provided to the [`Builder`].
+    SubscriberMaxBorrowedSamplesIsZero,
     /// The [`Service`] already exists.
     AlreadyExists,
     /// The process has insufficient permissions to create the [`Service`].
@@ -138,6 +188,12 @@ pub enum PublishSubscribeCreateError {
     /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     HangsInCreation,
+    /// The static [`Service`] information could not be created. The underlying cause is
+    /// available via [`std::error::Error::source()`].
+    StaticStorageCreationFailure(StaticStorageCreateError),
+    /// The dynamic [`Service`] segment could not be created. The underlying cause is
+    /// available via [`std::error::Error::source()`].
+    DynamicStorageCreationFailure(DynamicStorageCreateError),
 }
 
 impl core::fmt::Display for PublishSubscribeCreateError {
@@ -146,7 +202,15 @@ impl core::fmt::Display for PublishSubscribeCreateError {
     }
 }
 
-impl std::error::Error for PublishSubscribeCreateError {}
+impl std::error::Error for PublishSubscribeCreateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PublishSubscribeCreateError::StaticStorageCreationFailure(e) => Some(e),
+            PublishSubscribeCreateError::DynamicStorageCreationFailure(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl From<ServiceAvailabilityState> for PublishSubscribeCreateError {
     fn from(value: ServiceAvailabilityState) -> Self {
@@ -211,13 +275,52 @@ impl core::fmt::Display for PublishSubscribeOpenOrCreateError {
     }
 }
 
-impl std::error::Error for PublishSubscribeOpenOrCreateError {}
+impl std::error::Error for PublishSubscribeOpenOrCreateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(e) => Some(e),
+            PublishSubscribeOpenOrCreateError::PublishSubscribeCreateError(e) => Some(e),
+            PublishSubscribeOpenOrCreateError::SystemInFlux => None,
+        }
+    }
+}
 
 /// Builder to create new [`MessagingPattern::PublishSubscribe`] based [`Service`]s
 ///
 /// # Example
 ///
 /// See [`crate::service`]
+///
+/// # Packed Payloads
+///
+/// A [`#[repr(packed)]`](https://doc.rust-lang.org/reference/type-layout.html#the-alignment-modifiers)
+/// payload has an alignment of `1` and its fields can be misaligned inside the sample. This is
+/// sound since the compiler rejects any reference to a field of a packed type, the fields must
+/// be read by value instead.
+///
+/// ```compile_fail,E0793
+/// use iceoryx2::prelude::*;
+///
+/// #[derive(Debug, Clone, Copy)]
+/// #[repr(C, packed)]
+/// struct Packed {
+///     flag: u8,
+///     value: u64,
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+/// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+/// #     .publish_subscribe::<Packed>()
+/// #     .open_or_create()?;
+/// # let subscriber = service.subscriber_builder().create()?;
+/// if let Some(sample) = subscriber.receive()? {
+///     // the field is misaligned, use `{ sample.value }` to read it by value
+///     let value: &u64 = &sample.value;
+/// }
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug)]
 pub struct Builder<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service> {
     base: builder::BuilderWithServiceType<ServiceType>,
@@ -230,7 +333,17 @@ pub struct Builder<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: serv
     verify_subscriber_max_borrowed_samples: bool,
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
+    verify_enable_last_value: bool,
+    verify_coalescing_window: bool,
+    verify_slow_subscriber_eviction: bool,
+    verify_pin_sample_memory: bool,
+    verify_pool_allocation_strategy: bool,
     verify_max_nodes: bool,
+    verify_max_message_size: bool,
+    verify_pool_capacity: bool,
+    verify_enable_send_timestamp: bool,
+    tolerate_trailing_padding: bool,
+    config_provenance: static_config::publish_subscribe::StaticConfigProvenance,
     _data: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -239,6 +352,9 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     Builder<Payload, UserHeader, ServiceType>
 {
     pub(crate) fn new(base: builder::BuilderWithServiceType<ServiceType>) -> Self {
+        let config_provenance = static_config::publish_subscribe::StaticConfigProvenance::new(
+            base.shared_node.config(),
+        );
         let mut new_self = Self {
             base,
             verify_number_of_publishers: false,
@@ -247,7 +363,17 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
             verify_publisher_history_size: false,
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
+            verify_enable_last_value: false,
+            verify_coalescing_window: false,
+            verify_slow_subscriber_eviction: false,
+            verify_pin_sample_memory: false,
+            verify_pool_allocation_strategy: false,
             verify_max_nodes: false,
+            verify_max_message_size: false,
+            verify_pool_capacity: false,
+            verify_enable_send_timestamp: false,
+            tolerate_trailing_padding: false,
+            config_provenance,
             override_alignment: None,
             override_payload_type: None,
             override_user_header_type: None,
@@ -280,6 +406,39 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         }
     }
 
+    fn has_compatible_types(
+        &self,
+        existing_settings: &static_config::publish_subscribe::StaticConfig,
+    ) -> bool {
+        if self.verify_enable_send_timestamp
+            && existing_settings.enable_send_timestamp
+                != self.config_details().enable_send_timestamp
+        {
+            return false;
+        }
+
+        // the header layout depends on the send timestamp of the existing service
+        let existing = &existing_settings.message_type_details;
+        let mut required = self.config_details().message_type_details.clone();
+        required.header = static_config::publish_subscribe::StaticConfig::header_type_detail(
+            existing_settings.has_send_timestamp(),
+        );
+
+        if required.is_compatible_to(existing) {
+            return true;
+        }
+
+        if self.tolerate_trailing_padding && required.is_compatible_up_to_trailing_padding(existing)
+        {
+            warn!(from self,
+                "The service offers the type \"{:?}\" which differs from the requested type \"{:?}\" only in trailing padding. It is treated as compatible since trailing padding differences are tolerated.",
+                existing, required);
+            return true;
+        }
+
+        false
+    }
+
     // triggers the underlying is_service_available method to check whether the service described in base is available.
     fn is_service_available(
         &mut self,
@@ -287,11 +446,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
-                if !self
-                    .config_details()
-                    .message_type_details
-                    .is_compatible_to(&config.publish_subscribe().message_type_details)
-                {
+                if !self.has_compatible_types(config.publish_subscribe()) {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
                         "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
                         error_msg, &config.publish_subscribe().message_type_details , self.config_details().message_type_details);
@@ -323,6 +478,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     pub fn enable_safe_overflow(mut self, value: bool) -> Self {
         self.config_details_mut().enable_safe_overflow = value;
         self.verify_enable_safe_overflow = true;
+        self.config_provenance.enable_safe_overflow = ConfigSource::Builder;
         self
     }
 
@@ -332,6 +488,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     pub fn subscriber_max_borrowed_samples(mut self, value: usize) -> Self {
         self.config_details_mut().subscriber_max_borrowed_samples = value;
         self.verify_subscriber_max_borrowed_samples = true;
+        self.config_provenance.subscriber_max_borrowed_samples = ConfigSource::Builder;
         self
     }
 
@@ -341,6 +498,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     pub fn history_size(mut self, value: usize) -> Self {
         self.config_details_mut().history_size = value;
         self.verify_publisher_history_size = true;
+        self.config_provenance.history_size = ConfigSource::Builder;
         self
     }
 
@@ -350,6 +508,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     pub fn subscriber_max_buffer_size(mut self, value: usize) -> Self {
         self.config_details_mut().subscriber_max_buffer_size = value;
         self.verify_subscriber_max_buffer_size = true;
+        self.config_provenance.subscriber_max_buffer_size = ConfigSource::Builder;
         self
     }
 
@@ -359,15 +518,23 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     pub fn max_subscribers(mut self, value: usize) -> Self {
         self.config_details_mut().max_subscribers = value;
         self.verify_number_of_subscribers = true;
+        self.config_provenance.max_subscribers = ConfigSource::Builder;
         self
     }
 
     /// If the [`Service`] is created it defines how many [`crate::port::publisher::Publisher`] shall
     /// be supported at most. If an existing [`Service`] is opened it defines how many
     /// [`crate::port::publisher::Publisher`] must be at least supported.
+    ///
+    /// A [`Service`] that is created with 0 [`crate::port::publisher::Publisher`]s is a
+    /// subscriber-only [`Service`]. No process can create a
+    /// [`crate::port::publisher::Publisher`] for it, see [`Builder::open_subscriber_only()`].
+    /// Only a 0 that is set explicitly with this method creates a subscriber-only [`Service`],
+    /// a 0 from the config file or the environment is adjusted to 1.
     pub fn max_publishers(mut self, value: usize) -> Self {
         self.config_details_mut().max_publishers = value;
         self.verify_number_of_publishers = true;
+        self.config_provenance.max_publishers = ConfigSource::Builder;
         self
     }
 
@@ -377,35 +544,279 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     pub fn max_nodes(mut self, value: usize) -> Self {
         self.config_details_mut().max_nodes = value;
         self.verify_max_nodes = true;
+        self.config_provenance.max_nodes = ConfigSource::Builder;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many bytes of the payload a
+    /// [`crate::port::publisher::Publisher`] can mark as used with
+    /// [`crate::sample_mut::SampleMut::set_used_len()`]. For fixed size payloads it must not
+    /// exceed the payload size. If an existing [`Service`] is opened it defines the minimum
+    /// required.
+    pub fn max_message_size(mut self, value: usize) -> Self {
+        self.config_details_mut().max_message_size = Some(value);
+        self.verify_max_message_size = true;
+        self.config_provenance.max_message_size = ConfigSource::Builder;
+        self
+    }
+
+    /// If the [`Service`] is created it defines if every sample slot in the data segment of a
+    /// [`crate::port::publisher::Publisher`] is aligned to a cache line boundary. It avoids false
+    /// sharing when [`crate::port::subscriber::Subscriber`]s on different cores read adjacent
+    /// samples but increases the memory required for the data segment. If an existing
+    /// [`Service`] is opened the setting has no effect.
+    pub fn sample_cache_line_aligned(mut self, value: bool) -> Self {
+        self.config_details_mut().sample_cache_line_aligned = value;
+        self.config_provenance.sample_cache_line_aligned = ConfigSource::Builder;
+        self
+    }
+
+    /// If the [`Service`] is created it defines if a [`crate::port::subscriber::Subscriber`]
+    /// acknowledges every [`crate::sample::Sample`] it consumed, so that the
+    /// [`crate::port::publisher::Publisher`] can acquire the sequence number of the latest
+    /// consumed sample with [`crate::port::publisher::Publisher::last_acked_sequence()`].
+    ///
+    /// It is disabled by default since it adds an atomic write to the release of every
+    /// [`crate::sample::Sample`] and a scan over all connections to every query. If an existing
+    /// [`Service`] is opened the setting has no effect.
+    pub fn enable_acknowledgment(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_acknowledgment = value;
+        self.config_provenance.enable_acknowledgment = ConfigSource::Builder;
+        self
+    }
+
+    /// If the [`Service`] is created it defines if the [`Service`] retains only the latest
+    /// value, like a current setpoint. The history size and the
+    /// [`crate::port::subscriber::Subscriber`]s buffer size are set to 1 and the safe overflow
+    /// is enabled, so that every [`crate::port::subscriber::Subscriber`] holds at most the
+    /// latest value and a late joining [`crate::port::subscriber::Subscriber`] receives exactly
+    /// the current value. The value is replaced with
+    /// [`crate::port::publisher::Publisher::update()`].
+    ///
+    /// If an existing [`Service`] is opened it requires the service to have the defined last
+    /// value mode.
+    pub fn enable_last_value(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_last_value = value;
+        self.verify_enable_last_value = true;
+        self.config_provenance.enable_last_value = ConfigSource::Builder;
+        self
+    }
+
+    /// If the [`Service`] is created it defines if the header of every sample has room for a
+    /// send timestamp, so that a [`crate::port::publisher::Publisher`] created with
+    /// [`PortFactoryPublisher::enable_send_timestamp()`](crate::service::port_factory::publisher::PortFactoryPublisher::enable_send_timestamp())
+    /// can record when it sent the sample, see
+    /// [`Header::send_timestamp()`](crate::service::header::publish_subscribe::Header::send_timestamp()).
+    /// It is disabled by default so that the header does not grow when it is not required.
+    /// A [`Service`] with a [`Builder::coalescing_window()`] always has room for it.
+    ///
+    /// If an existing [`Service`] is opened it requires the service to have the same setting,
+    /// otherwise it fails with [`PublishSubscribeOpenError::IncompatibleTypes`]. When it is
+    /// not defined the header layout of the existing [`Service`] is used.
+    pub fn enable_send_timestamp(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_send_timestamp = value;
+        self.verify_enable_send_timestamp = true;
+        self.config_provenance.enable_send_timestamp = ConfigSource::Builder;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the [`PoolAllocationStrategy`] that every
+    /// [`crate::port::publisher::Publisher`] uses to hand out the sample slots of its data
+    /// segment. [`PoolAllocationStrategy::Bump`] is meant for fixed-size payloads with a
+    /// single producer and applies only to data segments that are not resized, see
+    /// [`AllocationStrategy::Static`](crate::prelude::AllocationStrategy::Static). Resizable
+    /// data segments always use the [`PoolAllocationStrategy::FreeList`].
+    ///
+    /// If an existing [`Service`] is opened it requires the service to use the defined
+    /// [`PoolAllocationStrategy`].
+    pub fn pool_allocation_strategy(mut self, value: PoolAllocationStrategy) -> Self {
+        self.config_details_mut().pool_allocation_strategy = value;
+        self.verify_pool_allocation_strategy = true;
+        self.config_provenance.pool_allocation_strategy = ConfigSource::Builder;
+        self
+    }
+
+    /// If the [`Service`] is created it enables the coalescing of samples. A
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) that falls behind collapses all
+    /// samples of the same coalescence group, see
+    /// [`SampleMut::set_coalescence_group()`](crate::sample_mut::SampleMut::set_coalescence_group()),
+    /// that were published within `window` into the most recent one, while a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) that keeps up still receives every
+    /// sample. The [`Subscriber`](crate::port::subscriber::Subscriber) must be able to borrow
+    /// one additional sample to look ahead in its buffer, otherwise the samples are delivered
+    /// without coalescing.
+    ///
+    /// The `window` is measured with the monotonic send timestamps, so that it is not affected
+    /// when the system time is adjusted. Every
+    /// [`Publisher`](crate::port::publisher::Publisher) of the [`Service`] records them, see
+    /// [`Header::send_timestamp()`](crate::service::header::publish_subscribe::Header::send_timestamp()).
+    ///
+    /// If an existing [`Service`] is opened it requires the service to use the same
+    /// coalescing window.
+    pub fn coalescing_window(mut self, window: Duration) -> Self {
+        self.config_details_mut().coalescing_window = Some(window);
+        self.verify_coalescing_window = true;
+        self.config_provenance.coalescing_window = ConfigSource::Builder;
+        self
+    }
+
+    /// If the [`Service`] is created it enables the eviction of slow
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s. It applies only when the safe
+    /// overflow is disabled, see [`Builder::enable_safe_overflow()`]. A
+    /// [`Publisher`](crate::port::publisher::Publisher) with the
+    /// [`UnableToDeliverStrategy::Block`](crate::service::port_factory::publisher::UnableToDeliverStrategy::Block)
+    /// waits at most `deadline` for a full
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) buffer to free space, afterwards the
+    /// sample is not delivered to this [`Subscriber`](crate::port::subscriber::Subscriber). When
+    /// the deadline is missed on `consecutive_misses` consecutive sends, the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) is removed from the [`Service`] and
+    /// [`Subscriber::receive()`](crate::port::subscriber::Subscriber::receive()) fails with
+    /// [`SubscriberReceiveError::Disconnected`](crate::port::subscriber::SubscriberReceiveError::Disconnected).
+    /// The samples the [`Subscriber`](crate::port::subscriber::Subscriber) still holds are
+    /// reclaimed by the [`Publisher`](crate::port::publisher::Publisher) and must no longer be
+    /// accessed.
+    ///
+    /// If an existing [`Service`] is opened it requires the service to use the same policy.
+    pub fn slow_subscriber_eviction(
+        mut self,
+        deadline: Duration,
+        consecutive_misses: usize,
+    ) -> Self {
+        self.config_details_mut().slow_subscriber_eviction =
+            Some(static_config::publish_subscribe::SlowSubscriberEviction {
+                deadline,
+                consecutive_misses,
+            });
+        self.verify_slow_subscriber_eviction = true;
+        self.config_provenance.slow_subscriber_eviction = ConfigSource::Builder;
+        self
+    }
+
+    /// If the [`Service`] is created it defines whether every
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) locks the memory of a received
+    /// [`Sample`](crate::sample::Sample) with `mlock`, see
+    /// [`Sample::pin_memory()`](crate::sample::Sample::pin_memory()). When the memory cannot be
+    /// locked, [`Subscriber::receive()`](crate::port::subscriber::Subscriber::receive()) fails
+    /// with
+    /// [`SubscriberReceiveError::UnableToPinSampleMemory`](crate::port::subscriber::SubscriberReceiveError::UnableToPinSampleMemory).
+    ///
+    /// If an existing [`Service`] is opened it requires the service to have the same setting.
+    pub fn pin_sample_memory(mut self, value: bool) -> Self {
+        self.config_details_mut().pin_sample_memory = value;
+        self.verify_pin_sample_memory = true;
+        self.config_provenance.pin_sample_memory = ConfigSource::Builder;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the number of sample slots in the data segment
+    /// of every [`crate::port::publisher::Publisher`] explicitly, instead of deriving it from
+    /// the maxima of the [`Service`]. It provides headroom for bursty workloads independent of
+    /// the number of [`Subscriber`](crate::port::subscriber::Subscriber)s and their buffer
+    /// sizes. It must not be smaller than the required minimum, otherwise the creation fails
+    /// with [`PublishSubscribeCreateError::PoolCapacityBelowRequiredMinimum`].
+    ///
+    /// If an existing [`Service`] is opened it defines how many sample slots must be at least
+    /// provided.
+    pub fn pool_capacity(mut self, value: usize) -> Self {
+        self.config_details_mut().pool_capacity = Some(value);
+        self.verify_pool_capacity = true;
+        self.config_provenance.pool_capacity = ConfigSource::Builder;
+        self
+    }
+
+    /// If an existing [`Service`] is opened, its payload and user header type are also
+    /// compatible when their size differs from the requested type only in trailing padding,
+    /// for instance since the type was compiled by another compiler. The sizes are compatible
+    /// when they are equal after rounding them up to the alignment of the types and the
+    /// requested size does not exceed the size of the [`Service`]s type. Types with different
+    /// fields remain incompatible.
+    ///
+    /// **Attention:** This relaxes a safety check since a differing size can no longer reveal
+    /// a differing layout. Every open that relies on it is logged as warning.
+    pub fn tolerate_trailing_padding(mut self, value: bool) -> Self {
+        self.tolerate_trailing_padding = value;
         self
     }
 
     /// Validates configuration and overrides the invalid setting with meaningful values.
     fn adjust_configuration_to_meaningful_values(&mut self) {
         let origin = format!("{:?}", self);
+        let is_history_size_set = self.verify_publisher_history_size;
+        let is_buffer_size_set = self.verify_subscriber_max_buffer_size;
+        let is_safe_overflow_set = self.verify_enable_safe_overflow;
+        let is_max_borrowed_samples_set = self.verify_subscriber_max_borrowed_samples;
+        let is_max_publishers_set = self.verify_number_of_publishers;
         let settings = self.base.service_config.publish_subscribe_mut();
 
-        if settings.subscriber_max_borrowed_samples == 0 {
+        if settings.enable_last_value {
+            if is_history_size_set && settings.history_size != 1 {
+                warn!(from origin,
+                    "The history size of {} is not supported in the last value mode. Adjust it to 1.",
+                    settings.history_size);
+            }
+            if is_buffer_size_set && settings.subscriber_max_buffer_size != 1 {
+                warn!(from origin,
+                    "The subscriber buffer size of {} is not supported in the last value mode. Adjust it to 1.",
+                    settings.subscriber_max_buffer_size);
+            }
+            if is_safe_overflow_set && !settings.enable_safe_overflow {
+                warn!(from origin,
+                    "The last value mode requires the safe overflow. Enable it.");
+            }
+
+            settings.history_size = 1;
+            settings.subscriber_max_buffer_size = 1;
+            settings.enable_safe_overflow = true;
+            self.config_provenance.history_size = ConfigSource::Builder;
+            self.config_provenance.subscriber_max_buffer_size = ConfigSource::Builder;
+            self.config_provenance.enable_safe_overflow = ConfigSource::Builder;
+        }
+
+        let payload = &settings.message_type_details.payload;
+        if payload.variant == TypeVariant::FixedSize {
+            match settings.max_message_size {
+                Some(size) if payload.size < size => {
+                    warn!(from origin,
+                        "The max message size of {} exceeds the payload size. Adjust it to the payload size of {}.",
+                        size, payload.size);
+                    settings.max_message_size = Some(payload.size);
+                }
+                Some(_) => (),
+                None => settings.max_message_size = Some(payload.size),
+            }
+        }
+
+        if is_max_borrowed_samples_set && settings.subscriber_max_borrowed_samples == 0 {
             warn!(from origin,
                 "Setting the subscribers max borrowed samples to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.subscriber_max_borrowed_samples = 1;
         }
 
-        if settings.subscriber_max_buffer_size == 0 {
+        if is_buffer_size_set && settings.subscriber_max_buffer_size == 0 {
             warn!(from origin,
                 "Setting the subscribers buffer size to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.subscriber_max_buffer_size = 1;
         }
 
+        if let Some(eviction) = &mut settings.slow_subscriber_eviction {
+            if eviction.consecutive_misses == 0 {
+                warn!(from origin,
+                    "Evicting slow subscribers after 0 missed deadlines is not supported. Adjust it to 1, the smallest supported value.");
+                eviction.consecutive_misses = 1;
+            }
+        }
+
         if settings.max_subscribers == 0 {
             warn!(from origin,
                 "Setting the maximum amount of subscribers to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_subscribers = 1;
         }
 
-        if settings.max_publishers == 0 {
+        // only an explicit max_publishers(0) creates a subscriber-only service, a 0 from the
+        // config file or the environment is adjusted
+        if !is_max_publishers_set && settings.max_publishers == 0 {
             warn!(from origin,
-                "Setting the maximum amount of publishers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+                "Setting the maximum amount of publishers to 0 is only supported with Builder::max_publishers(). Adjust it to 1, the smallest supported value.");
             settings.max_publishers = 1;
         }
 
@@ -427,7 +838,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         if let Err(incompatible_key) = required_attributes.verify_requirements(existing_attributes)
         {
             fail!(from self, with PublishSubscribeOpenError::IncompatibleAttributes,
-                "{} due to incompatible service attribute key \"{}\". The following attributes {:?} are required but the service has the attributes {:?}.",
+                "{} due to incompatible service attribute requirement \"{}\". The following attributes {:?} are required but the service has the attributes {:?}.",
                 msg, incompatible_key, required_attributes, existing_attributes);
         }
 
@@ -490,6 +901,73 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                                 msg);
         }
 
+        if self.verify_enable_last_value
+            && existing_settings.enable_last_value != required_settings.enable_last_value
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleLastValueMode,
+                                "{} since the service has an incompatible last value mode.",
+                                msg);
+        }
+
+        if self.verify_coalescing_window
+            && existing_settings.coalescing_window != required_settings.coalescing_window
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleCoalescingWindow,
+                                "{} since the service uses the coalescing window {:?} but {:?} is required.",
+                                msg, existing_settings.coalescing_window, required_settings.coalescing_window);
+        }
+
+        if self.verify_slow_subscriber_eviction
+            && existing_settings.slow_subscriber_eviction
+                != required_settings.slow_subscriber_eviction
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleSlowSubscriberEviction,
+                                "{} since the service uses the slow subscriber eviction {:?} but {:?} is required.",
+                                msg, existing_settings.slow_subscriber_eviction, required_settings.slow_subscriber_eviction);
+        }
+
+        if self.verify_pin_sample_memory
+            && existing_settings.pin_sample_memory != required_settings.pin_sample_memory
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleSampleMemoryPinning,
+                                "{} since the service has the sample memory pinning set to {} but {} is required.",
+                                msg, existing_settings.pin_sample_memory, required_settings.pin_sample_memory);
+        }
+
+        if self.verify_pool_allocation_strategy
+            && existing_settings.pool_allocation_strategy
+                != required_settings.pool_allocation_strategy
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatiblePoolAllocationStrategy,
+                                "{} since the service uses the pool allocation strategy {:?} but {:?} is required.",
+                                msg, existing_settings.pool_allocation_strategy, required_settings.pool_allocation_strategy);
+        }
+
+        if self.verify_max_message_size
+            && existing_settings.max_message_size.unwrap_or(usize::MAX)
+                < required_settings.max_message_size.unwrap_or(usize::MAX)
+        {
+            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedMaxMessageSize,
+                                "{} since the service supports only a max message size of {:?} but a max message size of {:?} was requested.",
+                                msg, existing_settings.max_message_size, required_settings.max_message_size);
+        }
+
+        if self.verify_pool_capacity {
+            let existing_pool_capacity = existing_settings.effective_pool_capacity(
+                self.base
+                    .shared_node
+                    .config()
+                    .defaults
+                    .publish_subscribe
+                    .publisher_max_loaned_samples,
+            );
+            if existing_pool_capacity < required_settings.pool_capacity.unwrap_or(0) {
+                fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedPoolCapacity,
+                                "{} since the service provides only {} sample slots per publisher but a pool capacity of {:?} was requested.",
+                                msg, existing_pool_capacity, required_settings.pool_capacity);
+            }
+        }
+
         if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
             fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes,
                                 "{} since the service supports only {} nodes but {} are required.",
@@ -506,10 +984,21 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
         PublishSubscribeCreateError,
     > {
+        enter_span!(DEBUG, "create", service = %self.base.service_config.name());
         self.adjust_configuration_to_meaningful_values();
 
         let msg = "Unable to create publish subscribe service";
 
+        if self.config_details().subscriber_max_buffer_size == 0 {
+            fail!(from self, with PublishSubscribeCreateError::SubscriberMaxBufferSizeIsZero,
+                "{} since the config defines a subscriber max buffer size of 0. A subscriber without a buffer cannot receive any sample.", msg);
+        }
+
+        if self.config_details().subscriber_max_borrowed_samples == 0 {
+            fail!(from self, with PublishSubscribeCreateError::SubscriberMaxBorrowedSamplesIsZero,
+                "{} since the config defines subscriber max borrowed samples of 0. A subscriber that cannot borrow a sample cannot receive any sample.", msg);
+        }
+
         if !self.config_details().enable_safe_overflow
             && (self.config_details().subscriber_max_buffer_size
                 < self.config_details().history_size)
@@ -518,6 +1007,33 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                 "{} since the history size is greater than the subscriber buffer size. The subscriber buffer size must be always greater or equal to the history size in the non-overflowing setup.", msg);
         }
 
+        if self.config_details().message_type_details.payload.size == 0 {
+            fail!(from self, with PublishSubscribeCreateError::PayloadTypeHasZeroSize,
+                "{} since the payload type \"{}\" has a size of zero.",
+                msg, self.config_details().message_type_details.payload.type_name);
+        }
+
+        let pubsub_config = &self.base.shared_node.config().defaults.publish_subscribe;
+        if let Some(pool_capacity) = self.config_details().pool_capacity {
+            let min_pool_capacity = self
+                .config_details()
+                .min_pool_capacity(pubsub_config.publisher_max_loaned_samples);
+            if pool_capacity < min_pool_capacity {
+                fail!(from self, with PublishSubscribeCreateError::PoolCapacityBelowRequiredMinimum,
+                    "{} since the pool capacity of {} is smaller than the required minimum of {} sample slots per publisher.",
+                    msg, pool_capacity, min_pool_capacity);
+            }
+        }
+
+        let required_sample_slots = self
+            .config_details()
+            .required_sample_slots(pubsub_config.publisher_max_loaned_samples);
+        if pubsub_config.max_sample_slots < required_sample_slots {
+            fail!(from self, with PublishSubscribeCreateError::ExceedsMaxSampleSlots,
+                "{} since the service requires up to {} sample slots but at most {} are allowed.",
+                msg, required_sample_slots, pubsub_config.max_sample_slots);
+        }
+
         match self.is_service_available(msg)? {
             None => {
                 let service_tag = self
@@ -540,17 +1056,19 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                             "{} since the static service information could not be created due to insufficient permissions.", msg);
                     }
                     Err(e) => {
-                        fail!(from self, with PublishSubscribeCreateError::InternalFailure,
+                        fail!(from self, with PublishSubscribeCreateError::StaticStorageCreationFailure(e),
                             "{} since the static service information could not be created due to an internal failure ({:?}).", msg, e);
                     }
                 };
 
                 let pubsub_config = self.base.service_config.publish_subscribe();
 
-                // create dynamic config
+                // create dynamic config, a subscriber-only service still requires a non-empty
+                // publisher container, the publisher creation is rejected beforehand
                 let dynamic_config_setting = DynamicConfigSettings {
-                    number_of_publishers: pubsub_config.max_publishers,
+                    number_of_publishers: pubsub_config.max_publishers.max(1),
                     number_of_subscribers: pubsub_config.max_subscribers,
+                    history_size: pubsub_config.history_size,
                 };
 
                 let dynamic_config = match self.base.create_dynamic_config_storage(
@@ -570,14 +1088,14 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                             "{} since the dynamic config of a previous instance of the service still exists.", msg);
                     }
                     Err(e) => {
-                        fail!(from self, with PublishSubscribeCreateError::InternalFailure,
+                        fail!(from self, with PublishSubscribeCreateError::DynamicStorageCreationFailure(e),
                             "{} since the dynamic service segment could not be created ({:?}).", msg, e);
                     }
                 };
 
                 self.base.service_config.attributes = attributes.0.clone();
                 let service_config = fail!(from self,
-                            when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                            when self.base.shared_node.config().global.service.static_config_format.serialize(&self.base.service_config),
                             with PublishSubscribeCreateError::ServiceInCorruptedState,
                             "{} since the configuration could not be serialized.", msg);
 
@@ -591,14 +1109,18 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                     service_tag.release_ownership();
                 }
 
-                Ok(publish_subscribe::PortFactory::new(
+                let mut factory = publish_subscribe::PortFactory::new(
                     ServiceType::__internal_from_state(service::ServiceState::new(
                         self.base.service_config.clone(),
                         self.base.shared_node.clone(),
+                        self.base.directory,
                         dynamic_config,
                         unlocked_static_details,
                     )),
-                ))
+                );
+                factory.config_provenance = self.config_provenance;
+
+                Ok(factory)
             }
             Some(_) => {
                 fail!(from self, with PublishSubscribeCreateError::AlreadyExists,
@@ -614,6 +1136,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
         PublishSubscribeOpenError,
     > {
+        enter_span!(DEBUG, "open", service = %self.base.service_config.name());
         let msg = "Unable to open publish subscribe service";
 
         let mut service_open_retry_count = 0;
@@ -647,7 +1170,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                             fail!(from self, with PublishSubscribeOpenError::ServiceInCorruptedState,
                                 "{} since the dynamic segment of the service is missing.", msg);
                         }
-                        Err(e) => {
+                        Err(OpenDynamicStorageFailure::DynamicStorageOpenError(e)) => {
                             if self.is_service_available(msg)?.is_none() {
                                 fail!(from self, with PublishSubscribeOpenError::DoesNotExist,
                                     "{} since the service does not exist.", msg);
@@ -656,7 +1179,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                             service_open_retry_count += 1;
 
                             if RETRY_LIMIT < service_open_retry_count {
-                                fail!(from self, with PublishSubscribeOpenError::ServiceInCorruptedState,
+                                fail!(from self, with PublishSubscribeOpenError::DynamicStorageOpenFailure(e),
                                 "{} since the dynamic service information could not be opened ({:?}). This could indicate a corrupted system or a misconfigured system where services are created/removed with a high frequency.",
                                 msg, e);
                             }
@@ -665,6 +1188,10 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                         }
                     };
 
+                    let has_outdated_payload_schema =
+                        self.config_details().message_type_details.payload.schema
+                            != pub_sub_static_config.message_type_details.payload.schema;
+
                     self.base.service_config.messaging_pattern =
                         MessagingPattern::PublishSubscribe(pub_sub_static_config.clone());
 
@@ -672,24 +1199,151 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                         service_tag.release_ownership();
                     }
 
-                    return Ok(publish_subscribe::PortFactory::new(
+                    let mut factory = publish_subscribe::PortFactory::new(
                         ServiceType::__internal_from_state(service::ServiceState::new(
                             static_config,
                             self.base.shared_node.clone(),
+                            self.base.directory,
                             dynamic_config,
                             static_storage,
                         )),
-                    ));
+                    );
+                    factory.has_outdated_payload_schema = has_outdated_payload_schema;
+
+                    return Ok(factory);
                 }
             }
         }
     }
 
+    fn attach_impl(
+        &mut self,
+        handle: &ServiceHandle,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        enter_span!(DEBUG, "attach", service = %self.base.service_config.name());
+        let msg = "Unable to attach to publish subscribe service";
+
+        if handle.service_id() != self.base.service_config.service_id() {
+            fail!(from self, with PublishSubscribeOpenError::StaleServiceHandle,
+                "{} since the service handle refers to the service \"{}\" ({:?}).",
+                msg, handle.name(), handle.service_id());
+        }
+
+        if !handle
+            .static_config
+            .has_same_messaging_pattern(&self.base.service_config)
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleMessagingPattern,
+                "{} since the messaging pattern \"{:?}\" does not fit the requested pattern \"{:?}\".",
+                msg, handle.static_config.messaging_pattern(), self.base.service_config.messaging_pattern());
+        }
+
+        if !self.has_compatible_types(handle.static_config.publish_subscribe()) {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleTypes,
+                "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
+                msg, &handle.static_config.publish_subscribe().message_type_details, self.config_details().message_type_details);
+        }
+
+        let pub_sub_static_config =
+            self.verify_service_configuration(&handle.static_config, &AttributeVerifier::new())?;
+
+        let static_storage = match self.base.open_static_config_storage(msg) {
+            Ok(Some(static_storage)) => static_storage,
+            Ok(None) => {
+                fail!(from self, with PublishSubscribeOpenError::DoesNotExist,
+                    "{} since the service does not exist anymore.", msg);
+            }
+            Err(e) => return Err(ServiceAvailabilityState::ServiceState(e).into()),
+        };
+
+        // the handle is provided by another process, only the stored static config is trusted
+        let mut stored_content = vec![0u8; static_storage.len() as usize];
+        if static_storage.read(stored_content.as_mut_slice()).is_err() {
+            fail!(from self, with PublishSubscribeOpenError::InsufficientPermissions,
+                "{} since the static service information could not be read.", msg);
+        }
+
+        let stored_static_config = fail!(from self,
+            when StaticConfigFormat::deserialize(&stored_content),
+            with PublishSubscribeOpenError::ServiceInCorruptedState,
+            "{} since the static service information could not be deserialized.", msg);
+
+        if stored_static_config != handle.static_config {
+            fail!(from self, with PublishSubscribeOpenError::StaleServiceHandle,
+                "{} since the static config of the service handle differs from the stored static config of the service.",
+                msg);
+        }
+
+        let service_tag = self
+            .base
+            .create_node_service_tag(msg, PublishSubscribeOpenError::InternalFailure)?;
+
+        let dynamic_config = match self.base.open_dynamic_config_storage() {
+            Ok(v) => v,
+            Err(OpenDynamicStorageFailure::IsMarkedForDestruction) => {
+                fail!(from self, with PublishSubscribeOpenError::IsMarkedForDestruction,
+                    "{} since the service is marked for destruction.", msg);
+            }
+            Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
+                fail!(from self, with PublishSubscribeOpenError::ExceedsMaxNumberOfNodes,
+                    "{} since it would exceed the maximum number of supported nodes.", msg);
+            }
+            Err(OpenDynamicStorageFailure::DynamicStorageOpenError(
+                DynamicStorageOpenError::DoesNotExist,
+            )) => {
+                fail!(from self, with PublishSubscribeOpenError::DoesNotExist,
+                    "{} since the service does not exist anymore.", msg);
+            }
+            Err(OpenDynamicStorageFailure::DynamicStorageOpenError(e)) => {
+                fail!(from self, with PublishSubscribeOpenError::DynamicStorageOpenFailure(e),
+                    "{} since the dynamic service information could not be opened ({:?}).", msg, e);
+            }
+        };
+
+        let storage_generation = dynamic_config.get().generation();
+        let has_outdated_payload_schema = self.config_details().message_type_details.payload.schema
+            != pub_sub_static_config.message_type_details.payload.schema;
+
+        self.base.service_config.messaging_pattern =
+            MessagingPattern::PublishSubscribe(pub_sub_static_config);
+
+        if let Some(mut service_tag) = service_tag {
+            service_tag.release_ownership();
+        }
+
+        // the node is registered at the dynamic config from here on, dropping the factory
+        // deregisters it again when the handle turns out to be stale
+        let mut factory = publish_subscribe::PortFactory::new(ServiceType::__internal_from_state(
+            service::ServiceState::new(
+                handle.static_config.clone(),
+                self.base.shared_node.clone(),
+                self.base.directory,
+                dynamic_config,
+                static_storage,
+            ),
+        ));
+        factory.has_outdated_payload_schema = has_outdated_payload_schema;
+
+        if storage_generation != handle.storage_generation() {
+            fail!(from self, with PublishSubscribeOpenError::StaleServiceHandle,
+                "{} since the service handle refers to the storage generation {} but the service has the generation {}. The service was recreated in the meantime.",
+                msg, handle.storage_generation(), storage_generation);
+        }
+
+        Ok(factory)
+    }
+
     fn open_or_create_impl(
         mut self,
         attributes: &AttributeVerifier,
     ) -> Result<
-        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        (
+            publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+            CreationOutcome,
+        ),
         PublishSubscribeOpenOrCreateError,
     > {
         let msg = "Unable to open or create publish subscribe service";
@@ -706,13 +1360,13 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
 
             match self.is_service_available(msg)? {
                 Some(_) => match self.open_impl(attributes) {
-                    Ok(factory) => return Ok(factory),
+                    Ok(factory) => return Ok((factory, CreationOutcome::Opened)),
                     Err(PublishSubscribeOpenError::DoesNotExist) => continue,
                     Err(e) => return Err(e.into()),
                 },
                 None => {
                     match self.create_impl(&AttributeSpecifier(attributes.attributes().clone())) {
-                        Ok(factory) => return Ok(factory),
+                        Ok(factory) => return Ok((factory, CreationOutcome::Created)),
                         Err(PublishSubscribeCreateError::AlreadyExists)
                         | Err(PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance) => {
                             continue;
@@ -759,12 +1413,31 @@ impl<Payload: Debug + ?Sized, ServiceType: service::Service>
     }
 }
 
+impl<Payload: Debug + SchemaEvolution, UserHeader: Debug, ServiceType: service::Service>
+    Builder<Payload, UserHeader, ServiceType>
+{
+    /// Enables the schema evolution of the payload, see [`SchemaEvolution`]. If the [`Service`]
+    /// is created, it stores the schema of the payload. If an existing [`Service`] is opened,
+    /// it must have been created with the same or a newer version of the payload whose fields
+    /// are prefixed by the fields of the payload. With an older version, only
+    /// [`crate::port::subscriber::Subscriber`]s can be created and they can read the common
+    /// prefix of the payload.
+    pub fn enable_schema_evolution(mut self) -> Self {
+        self.override_payload_type = Some(TypeDetail::from_schema::<Payload>());
+        self
+    }
+}
+
 impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
     Builder<Payload, UserHeader, ServiceType>
 {
     fn prepare_config_details(&mut self) {
         self.config_details_mut().message_type_details =
             MessageTypeDetails::from::<Header, UserHeader, Payload>(TypeVariant::FixedSize);
+        self.config_details_mut().message_type_details.header =
+            static_config::publish_subscribe::StaticConfig::header_type_detail(
+                self.config_details().has_send_timestamp(),
+            );
 
         if let Some(details) = &self.override_payload_type {
             self.config_details_mut().message_type_details.payload = details.clone();
@@ -803,6 +1476,25 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
     > {
         self.prepare_config_details();
         self.open_or_create_impl(required_attributes)
+            .map(|(factory, _)| factory)
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. Additionally to the port factory it returns the [`CreationOutcome`] that
+    /// states whether the [`Service`] was created by this call or opened. When multiple
+    /// instances call it concurrently, exactly one of them observes
+    /// [`CreationOutcome::Created`].
+    pub fn open_or_create_with_outcome(
+        mut self,
+    ) -> Result<
+        (
+            publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+            CreationOutcome,
+        ),
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.prepare_config_details();
+        self.open_or_create_impl(&AttributeVerifier::new())
     }
 
     /// Opens an existing [`Service`].
@@ -828,6 +1520,41 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
         self.open_impl(required_attributes)
     }
 
+    /// Opens an existing [`Service`] like [`Builder::open()`] but returns a
+    /// [`SubscriberOnlyPortFactory`](publish_subscribe::SubscriberOnlyPortFactory) that can
+    /// only create [`crate::port::subscriber::Subscriber`]s. It is intended for monitoring
+    /// processes that must never publish into the [`Service`].
+    ///
+    /// The restriction applies only to the opening process. When the [`Service`] was created
+    /// with [`Builder::max_publishers()`] set to 0, the creation of a
+    /// [`crate::port::publisher::Publisher`] fails in every process with
+    /// [`PublisherCreateError::ExceedsMaxSupportedPublishers`](crate::port::publisher::PublisherCreateError::ExceedsMaxSupportedPublishers).
+    pub fn open_subscriber_only(
+        self,
+    ) -> Result<
+        publish_subscribe::SubscriberOnlyPortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.open_with_attributes(&AttributeVerifier::new())
+            .map(publish_subscribe::SubscriberOnlyPortFactory::new)
+    }
+
+    /// Attaches to the existing [`Service`] the [`ServiceHandle`] refers to. Opposed to
+    /// [`Builder::open()`] the static configuration of the [`Service`] is taken from the
+    /// [`ServiceHandle`] instead of being read from the underlying storage. The
+    /// [`ServiceHandle`] must refer to the [`Service`] with the name of the builder and must
+    /// not be stale, otherwise [`PublishSubscribeOpenError::StaleServiceHandle`] is returned.
+    pub fn attach(
+        mut self,
+        handle: &ServiceHandle,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.prepare_config_details();
+        self.attach_impl(handle)
+    }
+
     /// Creates a new [`Service`].
     pub fn create(
         self,
@@ -857,6 +1584,10 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
     fn prepare_config_details(&mut self) {
         self.config_details_mut().message_type_details =
             MessageTypeDetails::from::<Header, UserHeader, Payload>(TypeVariant::Dynamic);
+        self.config_details_mut().message_type_details.header =
+            static_config::publish_subscribe::StaticConfig::header_type_detail(
+                self.config_details().has_send_timestamp(),
+            );
 
         if let Some(details) = &self.override_payload_type {
             self.config_details_mut().message_type_details.payload = details.clone();
@@ -893,6 +1624,26 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
     > {
         self.prepare_config_details();
         self.open_or_create_impl(attributes)
+            .map(|(factory, _)| factory)
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. Additionally to the port factory it returns the [`CreationOutcome`] that
+    /// states whether the [`Service`] was created by this call or opened. When multiple
+    /// instances call it concurrently, exactly one of them observes
+    /// [`CreationOutcome::Created`].
+    #[allow(clippy::type_complexity)]
+    pub fn open_or_create_with_outcome(
+        mut self,
+    ) -> Result<
+        (
+            publish_subscribe::PortFactory<ServiceType, [Payload], UserHeader>,
+            CreationOutcome,
+        ),
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.prepare_config_details();
+        self.open_or_create_impl(&AttributeVerifier::new())
     }
 
     /// Opens an existing [`Service`].
@@ -918,6 +1669,41 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
         self.open_impl(attributes)
     }
 
+    /// Opens an existing [`Service`] like [`Builder::open()`] but returns a
+    /// [`SubscriberOnlyPortFactory`](publish_subscribe::SubscriberOnlyPortFactory) that can
+    /// only create [`crate::port::subscriber::Subscriber`]s. It is intended for monitoring
+    /// processes that must never publish into the [`Service`].
+    ///
+    /// The restriction applies only to the opening process. When the [`Service`] was created
+    /// with [`Builder::max_publishers()`] set to 0, the creation of a
+    /// [`crate::port::publisher::Publisher`] fails in every process with
+    /// [`PublisherCreateError::ExceedsMaxSupportedPublishers`](crate::port::publisher::PublisherCreateError::ExceedsMaxSupportedPublishers).
+    pub fn open_subscriber_only(
+        self,
+    ) -> Result<
+        publish_subscribe::SubscriberOnlyPortFactory<ServiceType, [Payload], UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.open_with_attributes(&AttributeVerifier::new())
+            .map(publish_subscribe::SubscriberOnlyPortFactory::new)
+    }
+
+    /// Attaches to the existing [`Service`] the [`ServiceHandle`] refers to. Opposed to
+    /// [`Builder::open()`] the static configuration of the [`Service`] is taken from the
+    /// [`ServiceHandle`] instead of being read from the underlying storage. The
+    /// [`ServiceHandle`] must refer to the [`Service`] with the name of the builder and must
+    /// not be stale, otherwise [`PublishSubscribeOpenError::StaleServiceHandle`] is returned.
+    pub fn attach(
+        mut self,
+        handle: &ServiceHandle,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [Payload], UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.prepare_config_details();
+        self.attach_impl(handle)
+    }
+
     /// Creates a new [`Service`].
     pub fn create(
         self,
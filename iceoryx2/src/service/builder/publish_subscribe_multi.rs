@@ -0,0 +1,197 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! #[derive(Debug)]
+//! #[repr(C)]
+//! struct Position {
+//!     x: f32,
+//!     y: f32,
+//! }
+//!
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/Bus".try_into()?)
+//!     .publish_subscribe_multi()
+//!     .register::<u64>()
+//!     .register::<Position>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher()?;
+//! let subscriber = service.subscriber()?;
+//!
+//! publisher.send(1234u64)?;
+//! publisher.send(Position { x: 1.0, y: 2.0 })?;
+//!
+//! while let Some(sample) = subscriber.receive()? {
+//!     if let Some(value) = sample.match_type::<u64>() {
+//!         println!("received counter: {}", value);
+//!     } else if let Some(position) = sample.match_type::<Position>() {
+//!         println!("received position: {:?}", position);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use crate::service;
+use crate::service::port_factory::publish_subscribe_multi::PortFactory;
+use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+
+use super::publish_subscribe::{
+    self, CustomPayloadMarker, PublishSubscribeCreateError, PublishSubscribeOpenError,
+    PublishSubscribeOpenOrCreateError,
+};
+
+/// The user header of every sample of a multi type
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// [`Service`](crate::service::Service). It contains the index of the registered type that is
+/// stored in the payload.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub(crate) struct TypeTag {
+    pub(crate) value: u64,
+}
+
+/// Builder to create new
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based [`Service`](crate::service::Service)s that carry samples of multiple payload types.
+///
+/// Every sample slot is sized and aligned for the largest registered type and every sample
+/// carries a type tag in its user header. So a multi type service trades memory for topology
+/// simplicity, a single service replaces one service per type.
+///
+/// The registered types form the payload type of the [`Service`](crate::service::Service),
+/// therefore every participant must register the same types in the same order. Like every
+/// payload, the registered types must be self-contained, they must not contain pointers or
+/// heap allocated members. At least one registered type must have a non-zero size, otherwise
+/// the creation fails with [`PublishSubscribeCreateError::PayloadTypeHasZeroSize`].
+#[derive(Debug)]
+pub struct Builder<ServiceType: service::Service> {
+    builder: publish_subscribe::Builder<[CustomPayloadMarker], TypeTag, ServiceType>,
+    types: Vec<TypeDetail>,
+}
+
+impl<ServiceType: service::Service> Builder<ServiceType> {
+    pub(crate) fn new(
+        builder: publish_subscribe::Builder<[CustomPayloadMarker], TypeTag, ServiceType>,
+    ) -> Self {
+        Self {
+            builder,
+            types: Vec::new(),
+        }
+    }
+
+    /// Registers a type that can be sent over the [`Service`](crate::service::Service). The
+    /// order of registration defines the type tag, registering a type twice has no effect.
+    pub fn register<T: Debug>(mut self) -> Self {
+        let type_detail = TypeDetail::__internal_new::<T>(TypeVariant::FixedSize);
+        if !self.types.contains(&type_detail) {
+            self.types.push(type_detail);
+        }
+        self
+    }
+
+    /// See [`publish_subscribe::Builder::enable_safe_overflow()`].
+    pub fn enable_safe_overflow(mut self, value: bool) -> Self {
+        self.builder = self.builder.enable_safe_overflow(value);
+        self
+    }
+
+    /// See [`publish_subscribe::Builder::subscriber_max_borrowed_samples()`].
+    pub fn subscriber_max_borrowed_samples(mut self, value: usize) -> Self {
+        self.builder = self.builder.subscriber_max_borrowed_samples(value);
+        self
+    }
+
+    /// See [`publish_subscribe::Builder::history_size()`].
+    pub fn history_size(mut self, value: usize) -> Self {
+        self.builder = self.builder.history_size(value);
+        self
+    }
+
+    /// See [`publish_subscribe::Builder::subscriber_max_buffer_size()`].
+    pub fn subscriber_max_buffer_size(mut self, value: usize) -> Self {
+        self.builder = self.builder.subscriber_max_buffer_size(value);
+        self
+    }
+
+    /// See [`publish_subscribe::Builder::max_subscribers()`].
+    pub fn max_subscribers(mut self, value: usize) -> Self {
+        self.builder = self.builder.max_subscribers(value);
+        self
+    }
+
+    /// See [`publish_subscribe::Builder::max_publishers()`].
+    pub fn max_publishers(mut self, value: usize) -> Self {
+        self.builder = self.builder.max_publishers(value);
+        self
+    }
+
+    /// If the [`Service`](crate::service::Service) exists, it will be opened otherwise a new
+    /// [`Service`](crate::service::Service) will be created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<PortFactory<ServiceType>, PublishSubscribeOpenOrCreateError> {
+        let (builder, types) = self.prepare();
+        Ok(PortFactory::new(builder.open_or_create()?, types))
+    }
+
+    /// Opens an existing [`Service`](crate::service::Service).
+    pub fn open(self) -> Result<PortFactory<ServiceType>, PublishSubscribeOpenError> {
+        let (builder, types) = self.prepare();
+        Ok(PortFactory::new(builder.open()?, types))
+    }
+
+    /// Creates a new [`Service`](crate::service::Service).
+    pub fn create(self) -> Result<PortFactory<ServiceType>, PublishSubscribeCreateError> {
+        let (builder, types) = self.prepare();
+        Ok(PortFactory::new(builder.create()?, types))
+    }
+
+    fn prepare(
+        self,
+    ) -> (
+        publish_subscribe::Builder<[CustomPayloadMarker], TypeTag, ServiceType>,
+        Arc<Vec<TypeDetail>>,
+    ) {
+        let payload_type = TypeDetail {
+            variant: TypeVariant::FixedSize,
+            type_name: format!(
+                "multi<{}>",
+                self.types
+                    .iter()
+                    .map(|t| t.type_name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            size: self.types.iter().map(|t| t.size).max().unwrap_or(0),
+            alignment: self.types.iter().map(|t| t.alignment).max().unwrap_or(1),
+            schema: None,
+        };
+
+        let builder = unsafe {
+            self.builder
+                .__internal_set_payload_type_details(&payload_type)
+        };
+        (builder, Arc::new(self.types))
+    }
+}
@@ -20,14 +20,14 @@ use crate::service::port_factory::request_response;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::{self, header, static_config};
 use crate::service::{builder, dynamic_config, Service};
+use crate::span::enter_span;
 use iceoryx2_bb_elementary::alignment::Alignment;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_cal::dynamic_storage::{DynamicStorageCreateError, DynamicStorageOpenError};
-use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::{StaticStorage, StaticStorageCreateError, StaticStorageLocked};
 
 use super::message_type_details::{MessageTypeDetails, TypeVariant};
-use super::{ServiceState, RETRY_LIMIT};
+use super::{CreationOutcome, ServiceState, RETRY_LIMIT};
 
 /// Errors that can occur when an existing [`MessagingPattern::RequestResponse`] [`Service`] shall
 /// be opened.
@@ -80,6 +80,9 @@ pub enum RequestResponseOpenError {
     IsMarkedForDestruction,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
     ServiceInCorruptedState,
+    /// The dynamic [`Service`] segment exists but could not be opened. The underlying cause is
+    /// available via [`std::error::Error::source()`].
+    DynamicStorageOpenFailure(DynamicStorageOpenError),
 }
 
 impl core::fmt::Display for RequestResponseOpenError {
@@ -88,7 +91,14 @@ impl core::fmt::Display for RequestResponseOpenError {
     }
 }
 
-impl std::error::Error for RequestResponseOpenError {}
+impl std::error::Error for RequestResponseOpenError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RequestResponseOpenError::DynamicStorageOpenFailure(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl From<ServiceAvailabilityState> for RequestResponseOpenError {
     fn from(value: ServiceAvailabilityState) -> Self {
@@ -131,6 +141,12 @@ pub enum RequestResponseCreateError {
     HangsInCreation,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
     ServiceInCorruptedState,
+    /// The static [`Service`] information could not be created. The underlying cause is
+    /// available via [`std::error::Error::source()`].
+    StaticStorageCreationFailure(StaticStorageCreateError),
+    /// The dynamic [`Service`] segment could not be created. The underlying cause is
+    /// available via [`std::error::Error::source()`].
+    DynamicStorageCreationFailure(DynamicStorageCreateError),
 }
 
 impl core::fmt::Display for RequestResponseCreateError {
@@ -139,7 +155,15 @@ impl core::fmt::Display for RequestResponseCreateError {
     }
 }
 
-impl std::error::Error for RequestResponseCreateError {}
+impl std::error::Error for RequestResponseCreateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RequestResponseCreateError::StaticStorageCreationFailure(e) => Some(e),
+            RequestResponseCreateError::DynamicStorageCreationFailure(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl From<ServiceAvailabilityState> for RequestResponseCreateError {
     fn from(value: ServiceAvailabilityState) -> Self {
@@ -199,7 +223,15 @@ impl core::fmt::Display for RequestResponseOpenOrCreateError {
     }
 }
 
-impl std::error::Error for RequestResponseOpenOrCreateError {}
+impl std::error::Error for RequestResponseOpenOrCreateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RequestResponseOpenOrCreateError::RequestResponseOpenError(e) => Some(e),
+            RequestResponseOpenOrCreateError::RequestResponseCreateError(e) => Some(e),
+            RequestResponseOpenOrCreateError::SystemInFlux => None,
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 enum ServiceAvailabilityState {
@@ -502,7 +534,7 @@ impl<
         if let Err(incompatible_key) = required_attributes.verify_requirements(existing_attributes)
         {
             fail!(from self, with RequestResponseOpenError::IncompatibleAttributes,
-                "{} due to incompatible service attribute key \"{}\". The following attributes {:?} are required but the service has the attributes {:?}.",
+                "{} due to incompatible service attribute requirement \"{}\". The following attributes {:?} are required but the service has the attributes {:?}.",
                 msg, incompatible_key, required_attributes, existing_attributes);
         }
 
@@ -656,7 +688,17 @@ impl<
     fn create_impl(
         &mut self,
         attributes: &AttributeSpecifier,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseCreateError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseCreateError,
+    > {
+        enter_span!(DEBUG, "create", service = %self.base.service_config.name());
         let msg = "Unable to create request response service";
         self.adjust_configuration_to_meaningful_values();
 
@@ -687,7 +729,7 @@ impl<
                             msg);
                     }
                     Err(e) => {
-                        fail!(from self, with RequestResponseCreateError::InternalFailure,
+                        fail!(from self, with RequestResponseCreateError::StaticStorageCreationFailure(e),
                             "{} since the static service information could not be created due to an internal failure ({:?}).",
                             msg, e);
                     }
@@ -717,7 +759,7 @@ impl<
                             msg);
                     }
                     Err(e) => {
-                        fail!(from self, with RequestResponseCreateError::InternalFailure,
+                        fail!(from self, with RequestResponseCreateError::DynamicStorageCreationFailure(e),
                             "{} since the dynamic service segment could not be created ({:?}).",
                             msg, e);
                     }
@@ -725,7 +767,7 @@ impl<
 
                 self.base.service_config.attributes = attributes.0.clone();
                 let serialized_service_config = fail!(from self,
-                          when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                          when self.base.shared_node.config().global.service.static_config_format.serialize(&self.base.service_config),
                           with RequestResponseCreateError::ServiceInCorruptedState,
                           "{} since the configuration could not be serialized.",
                           msg);
@@ -745,6 +787,7 @@ impl<
                     ServiceType::__internal_from_state(service::ServiceState::new(
                         self.base.service_config.clone(),
                         self.base.shared_node.clone(),
+                        self.base.directory,
                         dynamic_config,
                         unlocked_static_details,
                     )),
@@ -756,7 +799,17 @@ impl<
     fn open_impl(
         &mut self,
         attributes: &AttributeVerifier,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseOpenError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        enter_span!(DEBUG, "open", service = %self.base.service_config.name());
         const OPEN_RETRY_LIMIT: usize = 5;
         let msg = "Unable to open request response service";
 
@@ -795,7 +848,7 @@ impl<
                                 "{} since the dynamic segment of the service is missing.",
                                 msg);
                         }
-                        Err(e) => {
+                        Err(OpenDynamicStorageFailure::DynamicStorageOpenError(e)) => {
                             if self.is_service_available(msg)?.is_none() {
                                 fail!(from self, with RequestResponseOpenError::DoesNotExist,
                                     "{} since the service does not exist.", msg);
@@ -804,7 +857,7 @@ impl<
                             service_open_retry_count += 1;
 
                             if OPEN_RETRY_LIMIT < service_open_retry_count {
-                                fail!(from self, with RequestResponseOpenError::ServiceInCorruptedState,
+                                fail!(from self, with RequestResponseOpenError::DynamicStorageOpenFailure(e),
                                     "{} since the dynamic service information could not be opened ({:?}).",
                                     msg, e);
                             }
@@ -824,6 +877,7 @@ impl<
                         ServiceType::__internal_from_state(service::ServiceState::new(
                             static_config,
                             self.base.shared_node.clone(),
+                            self.base.directory,
                             dynamic_config,
                             static_storage,
                         )),
@@ -833,10 +887,23 @@ impl<
         }
     }
 
+    #[allow(clippy::type_complexity)]
     fn open_or_create_impl(
         mut self,
         attributes: &AttributeVerifier,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseOpenOrCreateError> {
+    ) -> Result<
+        (
+            request_response::PortFactory<
+                ServiceType,
+                RequestPayload,
+                RequestHeader,
+                ResponsePayload,
+                ResponseHeader,
+            >,
+            CreationOutcome,
+        ),
+        RequestResponseOpenOrCreateError,
+    > {
         let msg = "Unable to open or create request response service";
 
         let mut retry_count = 0;
@@ -851,13 +918,13 @@ impl<
 
             match self.is_service_available(msg)? {
                 Some(_) => match self.open_impl(attributes) {
-                    Ok(factory) => return Ok(factory),
+                    Ok(factory) => return Ok((factory, CreationOutcome::Opened)),
                     Err(RequestResponseOpenError::DoesNotExist) => continue,
                     Err(e) => return Err(e.into()),
                 },
                 None => {
                     match self.create_impl(&AttributeSpecifier(attributes.attributes().clone())) {
-                        Ok(factory) => return Ok(factory),
+                        Ok(factory) => return Ok((factory, CreationOutcome::Created)),
                         Err(RequestResponseCreateError::AlreadyExists)
                         | Err(RequestResponseCreateError::IsBeingCreatedByAnotherInstance) => {
                             continue;
@@ -911,7 +978,16 @@ impl<
     /// created.
     pub fn open_or_create(
         self,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseOpenOrCreateError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
         self.open_or_create_with_attributes(&AttributeVerifier::new())
     }
 
@@ -924,15 +1000,59 @@ impl<
     pub fn open_or_create_with_attributes(
         mut self,
         required_attributes: &AttributeVerifier,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseOpenOrCreateError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
         self.prepare_message_type_details();
         self.open_or_create_impl(required_attributes)
+            .map(|(factory, _)| factory)
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. Additionally to the port factory it returns the [`CreationOutcome`] that
+    /// states whether the [`Service`] was created by this call or opened. When multiple
+    /// instances call it concurrently, exactly one of them observes
+    /// [`CreationOutcome::Created`].
+    #[allow(clippy::type_complexity)]
+    pub fn open_or_create_with_outcome(
+        mut self,
+    ) -> Result<
+        (
+            request_response::PortFactory<
+                ServiceType,
+                RequestPayload,
+                RequestHeader,
+                ResponsePayload,
+                ResponseHeader,
+            >,
+            CreationOutcome,
+        ),
+        RequestResponseOpenOrCreateError,
+    > {
+        self.prepare_message_type_details();
+        self.open_or_create_impl(&AttributeVerifier::new())
     }
 
     /// Opens an existing [`Service`].
     pub fn open(
         self,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseOpenError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
         self.open_with_attributes(&AttributeVerifier::new())
     }
 
@@ -941,7 +1061,16 @@ impl<
     pub fn open_with_attributes(
         mut self,
         required_attributes: &AttributeVerifier,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseOpenError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
         self.prepare_message_type_details();
         self.open_impl(required_attributes)
     }
@@ -949,7 +1078,16 @@ impl<
     /// Creates a new [`Service`].
     pub fn create(
         self,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseCreateError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseCreateError,
+    > {
         self.create_with_attributes(&AttributeSpecifier::new())
     }
 
@@ -957,7 +1095,16 @@ impl<
     pub fn create_with_attributes(
         mut self,
         attributes: &AttributeSpecifier,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseCreateError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseCreateError,
+    > {
         self.prepare_message_type_details();
         self.create_impl(attributes)
     }
@@ -12,23 +12,35 @@
 
 use crate::{config, node::NodeId};
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_cal::named_concept::{NamedConceptConfiguration, NamedConceptMgmt};
 
+/// Returns the directory under which all resources of a service are stored. It is the root
+/// path of the [`config::Config`] unless the service was placed in a custom directory.
+fn service_root_path<'a>(
+    global_config: &'a config::Config,
+    directory: Option<&'a Path>,
+) -> &'a Path {
+    directory.unwrap_or(global_config.global.root_path())
+}
+
 pub(crate) fn dynamic_config_storage_config<Service: crate::service::Service>(
     global_config: &config::Config,
+    directory: Option<&Path>,
 ) -> <Service::DynamicStorage as NamedConceptMgmt>::Configuration {
     <<Service::DynamicStorage as NamedConceptMgmt>::Configuration>::default()
         .prefix(&global_config.global.prefix)
         .suffix(&global_config.global.service.dynamic_config_storage_suffix)
-        .path_hint(global_config.global.root_path())
+        .path_hint(service_root_path(global_config, directory))
 }
 
 pub(crate) fn static_config_storage_config<Service: crate::service::Service>(
     global_config: &config::Config,
+    directory: Option<&Path>,
 ) -> <Service::StaticStorage as NamedConceptMgmt>::Configuration {
     let origin = "static_config_storage_config";
     let msg = "Unable to generate static config storage directory";
-    let mut path_hint = *global_config.global.root_path();
+    let mut path_hint = *service_root_path(global_config, directory);
     fatal_panic!(from origin, when path_hint.add_path_entry(&global_config.global.service.directory),
             "{} since the combination of root directory and service directory entry result in an invalid directory \"{}{}\".",
             msg, path_hint, global_config.global.service.directory);
@@ -41,38 +53,42 @@ pub(crate) fn static_config_storage_config<Service: crate::service::Service>(
 
 pub(crate) fn connection_config<Service: crate::service::Service>(
     global_config: &config::Config,
+    directory: Option<&Path>,
 ) -> <Service::Connection as NamedConceptMgmt>::Configuration {
     <<Service::Connection as NamedConceptMgmt>::Configuration>::default()
         .prefix(&global_config.global.prefix)
         .suffix(&global_config.global.service.connection_suffix)
-        .path_hint(global_config.global.root_path())
+        .path_hint(service_root_path(global_config, directory))
 }
 
 pub(crate) fn event_config<Service: crate::service::Service>(
     global_config: &config::Config,
+    directory: Option<&Path>,
 ) -> <Service::Event as NamedConceptMgmt>::Configuration {
     <<Service::Event as NamedConceptMgmt>::Configuration>::default()
         .prefix(&global_config.global.prefix)
         .suffix(&global_config.global.service.event_connection_suffix)
-        .path_hint(global_config.global.root_path())
+        .path_hint(service_root_path(global_config, directory))
 }
 
 pub(crate) fn data_segment_config<Service: crate::service::Service>(
     global_config: &config::Config,
+    directory: Option<&Path>,
 ) -> <Service::SharedMemory as NamedConceptMgmt>::Configuration {
     <<Service::SharedMemory as NamedConceptMgmt>::Configuration>::default()
         .prefix(&global_config.global.prefix)
         .suffix(&global_config.global.service.publisher_data_segment_suffix)
-        .path_hint(global_config.global.root_path())
+        .path_hint(service_root_path(global_config, directory))
 }
 
 pub(crate) fn resizable_data_segment_config<Service: crate::service::Service>(
     global_config: &config::Config,
+    directory: Option<&Path>,
 ) -> <Service::ResizableSharedMemory as NamedConceptMgmt>::Configuration {
     <<Service::ResizableSharedMemory as NamedConceptMgmt>::Configuration>::default()
         .prefix(&global_config.global.prefix)
         .suffix(&global_config.global.service.publisher_data_segment_suffix)
-        .path_hint(global_config.global.root_path())
+        .path_hint(service_root_path(global_config, directory))
 }
 
 pub(crate) fn node_monitoring_config<Service: crate::service::Service>(
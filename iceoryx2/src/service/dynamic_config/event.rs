@@ -26,6 +26,9 @@
 //! # Ok(())
 //! # }
 //! ```
+use core::sync::atomic::Ordering;
+use core::time::Duration;
+
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
@@ -54,6 +57,7 @@ pub struct DynamicConfig {
     pub(crate) listeners: Container<ListenerDetails>,
     pub(crate) notifiers: Container<NotifierDetails>,
     pub(crate) elapsed_time_since_last_notification: IoxAtomicU64,
+    pub(crate) barrier_arrivals: IoxAtomicU64,
 }
 
 #[doc(hidden)]
@@ -62,6 +66,7 @@ pub struct DynamicConfig {
 pub struct ListenerDetails {
     pub listener_id: UniqueListenerId,
     pub node_id: NodeId,
+    pub owner_start_time: Option<Duration>,
 }
 
 #[doc(hidden)]
@@ -70,6 +75,7 @@ pub struct ListenerDetails {
 pub struct NotifierDetails {
     pub notifier_id: UniqueNotifierId,
     pub node_id: NodeId,
+    pub owner_start_time: Option<Duration>,
 }
 
 impl DynamicConfig {
@@ -78,6 +84,7 @@ impl DynamicConfig {
             listeners: unsafe { Container::new_uninit(config.number_of_listeners) },
             notifiers: unsafe { Container::new_uninit(config.number_of_notifiers) },
             elapsed_time_since_last_notification: IoxAtomicU64::new(0),
+            barrier_arrivals: IoxAtomicU64::new(0),
         }
     }
 
@@ -105,6 +112,13 @@ impl DynamicConfig {
         self.notifiers.len()
     }
 
+    /// Returns how many participants arrived at the barrier of the service in total, without
+    /// the ones that withdrew their arrival after a timeout, see
+    /// [`PortFactory::barrier()`](crate::service::port_factory::event::PortFactory::barrier()).
+    pub fn number_of_barrier_arrivals(&self) -> u64 {
+        self.barrier_arrivals.load(Ordering::Relaxed)
+    }
+
     #[doc(hidden)]
     pub fn __internal_list_listeners<F: FnMut(&ListenerDetails)>(&self, mut callback: F) {
         let state = unsafe { self.listeners.get_state() };
@@ -159,6 +173,39 @@ impl DynamicConfig {
             });
     }
 
+    pub(crate) unsafe fn remove_dead_ports<
+        PortCleanup: FnMut(UniquePortId, Option<Duration>) -> PortCleanupAction,
+    >(
+        &self,
+        mut port_cleanup_callback: PortCleanup,
+    ) {
+        self.listeners
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_listener| {
+                if port_cleanup_callback(
+                    UniquePortId::Listener(registered_listener.listener_id),
+                    registered_listener.owner_start_time,
+                ) == PortCleanupAction::RemovePort
+                {
+                    self.release_listener_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+
+        self.notifiers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_notifier| {
+                if port_cleanup_callback(
+                    UniquePortId::Notifier(registered_notifier.notifier_id),
+                    registered_notifier.owner_start_time,
+                ) == PortCleanupAction::RemovePort
+                {
+                    self.release_notifier_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+    }
+
     pub(crate) fn add_listener_id(&self, id: ListenerDetails) -> Option<ContainerHandle> {
         unsafe { self.listeners.add(id).ok() }
     }
@@ -26,6 +26,7 @@ pub mod publish_subscribe;
 pub mod request_response;
 
 use core::fmt::Display;
+use core::time::Duration;
 use iceoryx2_bb_container::queue::RelocatableContainer;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::{
@@ -60,6 +61,8 @@ pub(crate) enum RemoveDeadNodeResult {
 }
 
 #[derive(Debug)]
+// stored in shared memory, the variants cannot be boxed
+#[allow(clippy::large_enum_variant)]
 pub(crate) enum MessagingPattern {
     RequestResponse(request_response::DynamicConfig),
     PublishSubscribe(publish_subscribe::DynamicConfig),
@@ -71,6 +74,7 @@ pub(crate) enum MessagingPattern {
 pub struct DynamicConfig {
     messaging_pattern: MessagingPattern,
     nodes: Container<NodeId>,
+    generation: u64,
 }
 
 impl Display for DynamicConfig {
@@ -87,10 +91,12 @@ impl DynamicConfig {
     pub(crate) fn new_uninit(
         messaging_pattern: MessagingPattern,
         max_number_of_nodes: usize,
+        generation: u64,
     ) -> Self {
         Self {
             messaging_pattern,
             nodes: unsafe { Container::new_uninit(max_number_of_nodes) },
+            generation,
         }
     }
 
@@ -140,6 +146,19 @@ impl DynamicConfig {
         ret_val
     }
 
+    pub(crate) unsafe fn remove_dead_ports<
+        PortCleanup: FnMut(UniquePortId, Option<Duration>) -> PortCleanupAction,
+    >(
+        &self,
+        port_cleanup_callback: PortCleanup,
+    ) {
+        match self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref v) => v.remove_dead_ports(port_cleanup_callback),
+            MessagingPattern::Event(ref v) => v.remove_dead_ports(port_cleanup_callback),
+            MessagingPattern::RequestResponse(ref v) => v.remove_dead_ports(port_cleanup_callback),
+        }
+    }
+
     pub(crate) fn register_node_id(
         &self,
         node_id: NodeId,
@@ -163,6 +182,13 @@ impl DynamicConfig {
         state.for_each(|_, node_id| callback(node_id));
     }
 
+    /// Returns the generation of the dynamic storage. It is acquired from the monotonic system
+    /// clock when the storage is created and therefore increases whenever a service is
+    /// destroyed and recreated at the same storage location.
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation
+    }
+
     pub(crate) fn is_marked_for_destruction(&self) -> bool {
         self.nodes.is_locked()
     }
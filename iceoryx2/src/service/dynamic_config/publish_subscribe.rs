@@ -23,29 +23,184 @@
 //!
 //! println!("number of active publishers:      {:?}", pubsub.dynamic_config().number_of_publishers());
 //! println!("number of active subscribers:     {:?}", pubsub.dynamic_config().number_of_subscribers());
+//!
+//! println!("number of failed loans:           {:?}", pubsub.dynamic_config().number_of_failed_loans());
+//! println!("number of overflows:              {:?}", pubsub.dynamic_config().number_of_overflows());
+//!
+//! for publisher in pubsub.dynamic_config().publishers() {
+//!     println!("publisher {:?} of node \"{}\"", publisher.publisher_id(), publisher.node_name());
+//! }
+//!
+//! // blocks until a publisher or subscriber connects or disconnects
+//! match pubsub.dynamic_config().wait_for_change(core::time::Duration::from_millis(10)) {
+//!     Ok(change) => println!("participants changed: {:?}", change),
+//!     Err(e) => println!("no change: {}", e),
+//! }
 //! # Ok(())
 //! # }
 //! ```
+use core::sync::atomic::Ordering;
+use core::time::Duration;
+
+use iceoryx2_bb_container::byte_string::FixedSizeByteString;
+use iceoryx2_bb_container::vec::RelocatableVec;
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
-use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_bb_posix::mutex::{Handle, IpcCapable};
+use iceoryx2_bb_posix::semaphore::{
+    SemaphoreInterface, SemaphoreTimedWaitError, SemaphoreWaitError, UnnamedSemaphore,
+    UnnamedSemaphoreBuilder, UnnamedSemaphoreHandle,
+};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
+use serde::Serialize;
 
 use crate::{
     node::NodeId,
     port::{
-        details::data_segment::DataSegmentType,
+        details::data_segment::{initial_data_segment_size, DataSegmentType},
         port_identifiers::{UniquePortId, UniquePublisherId, UniqueSubscriberId},
     },
 };
 
 use super::PortCleanupAction;
+use crate::service::static_config::publish_subscribe::StaticConfig;
+
+/// The maximum length of a [`PortNodeName`]. Longer names are truncated.
+pub const MAX_PORT_NODE_NAME_LENGTH: usize = 64;
+
+/// Human-readable name of the logical node a [`crate::port::publisher::Publisher`] or
+/// [`crate::port::subscriber::Subscriber`] belongs to. It is only used for introspection
+/// and is empty when no name was provided.
+pub type PortNodeName = FixedSizeByteString<MAX_PORT_NODE_NAME_LENGTH>;
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DynamicConfigSettings {
     pub number_of_subscribers: usize,
     pub number_of_publishers: usize,
+    pub history_size: usize,
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct HistoryEntry {
+    offset: IoxAtomicU64,
+    size: IoxAtomicU64,
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct HistoryState {
+    generation: IoxAtomicU64,
+    owner_high: IoxAtomicU64,
+    owner_low: IoxAtomicU64,
+    number_of_pushes: IoxAtomicU64,
+    number_of_readers: IoxAtomicU64,
+}
+
+/// Mirrors the history of a [`crate::port::publisher::Publisher`] into the dynamic config so
+/// that it can be read without a connection. The [`crate::port::publisher::Publisher`] is the
+/// only writer, it increments the generation before and after every modification so that a
+/// reader can detect a concurrent modification like in a sequence lock. While a reader copies
+/// the samples, the [`crate::port::publisher::Publisher`] defers the release of the samples that
+/// are evicted from the history.
+#[derive(Debug)]
+pub(crate) struct PublisherHistory<'a> {
+    state: &'a HistoryState,
+    entries: &'a [HistoryEntry],
+}
+
+impl PublisherHistory<'_> {
+    fn modify<F: FnOnce()>(&self, modification: F) {
+        self.state.generation.fetch_add(1, Ordering::SeqCst);
+        modification();
+        self.state.generation.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Assigns the history to a new owner and removes all entries.
+    pub(crate) fn reset(&self, owner: UniquePublisherId) {
+        self.reset_owner(owner.value());
+    }
+
+    /// Removes the owner and all entries.
+    pub(crate) fn clear(&self) {
+        self.reset_owner(0);
+    }
+
+    fn reset_owner(&self, owner: u128) {
+        self.modify(|| {
+            self.state
+                .owner_high
+                .store((owner >> 64) as u64, Ordering::SeqCst);
+            self.state.owner_low.store(owner as u64, Ordering::SeqCst);
+            self.state.number_of_pushes.store(0, Ordering::SeqCst);
+        });
+    }
+
+    /// Adds an entry and overrides the oldest entry when the history is full.
+    pub(crate) fn push(&self, offset: u64, size: usize) {
+        self.modify(|| {
+            let number_of_pushes = self.state.number_of_pushes.load(Ordering::SeqCst);
+            let entry = &self.entries[(number_of_pushes % self.entries.len() as u64) as usize];
+            entry.offset.store(offset, Ordering::SeqCst);
+            entry.size.store(size as u64, Ordering::SeqCst);
+            self.state
+                .number_of_pushes
+                .store(number_of_pushes + 1, Ordering::SeqCst);
+        });
+    }
+
+    /// Calls `reader` with all entries of `owner` starting with the oldest one. The
+    /// samples of the entries are not released by the [`crate::port::publisher::Publisher`]
+    /// until `reader` returns. Returns [`None`] when the history is currently modified or
+    /// belongs to another owner.
+    pub(crate) fn read<R, F: FnOnce(&[(u64, usize)]) -> R>(
+        &self,
+        owner: UniquePublisherId,
+        reader: F,
+    ) -> Option<R> {
+        // must be registered before the entries are acquired, so that the publisher either
+        // observes the reader or the reader observes the modification of the publisher
+        self.state.number_of_readers.fetch_add(1, Ordering::SeqCst);
+        let result = self.entries_of(owner).map(|entries| reader(&entries));
+        self.state.number_of_readers.fetch_sub(1, Ordering::SeqCst);
+        result
+    }
+
+    /// Returns true when a reader may currently access the samples of the history.
+    pub(crate) fn has_readers(&self) -> bool {
+        self.state.number_of_readers.load(Ordering::SeqCst) != 0
+    }
+
+    fn entries_of(&self, owner: UniquePublisherId) -> Option<Vec<(u64, usize)>> {
+        let generation = self.state.generation.load(Ordering::SeqCst);
+        let is_owner = self.state.owner_high.load(Ordering::SeqCst) == (owner.value() >> 64) as u64
+            && self.state.owner_low.load(Ordering::SeqCst) == owner.value() as u64;
+        if generation % 2 == 1 || !is_owner {
+            return None;
+        }
+
+        let capacity = self.entries.len() as u64;
+        let number_of_pushes = self.state.number_of_pushes.load(Ordering::SeqCst);
+        let entries = (number_of_pushes.saturating_sub(capacity)..number_of_pushes)
+            .map(|n| {
+                let entry = &self.entries[(n % capacity) as usize];
+                (
+                    entry.offset.load(Ordering::SeqCst),
+                    entry.size.load(Ordering::SeqCst) as usize,
+                )
+            })
+            .collect();
+
+        if self.state.generation.load(Ordering::SeqCst) != generation {
+            return None;
+        }
+
+        Some(entries)
+    }
 }
 
 #[doc(hidden)]
@@ -54,10 +209,12 @@ pub(crate) struct DynamicConfigSettings {
 pub struct PublisherDetails {
     pub publisher_id: UniquePublisherId,
     pub node_id: NodeId,
+    pub node_name: PortNodeName,
     pub number_of_samples: usize,
     pub max_slice_len: usize,
     pub data_segment_type: DataSegmentType,
     pub max_number_of_segments: u8,
+    pub owner_start_time: Option<Duration>,
 }
 
 #[doc(hidden)]
@@ -66,7 +223,215 @@ pub struct PublisherDetails {
 pub struct SubscriberDetails {
     pub subscriber_id: UniqueSubscriberId,
     pub node_id: NodeId,
+    pub node_name: PortNodeName,
     pub buffer_size: usize,
+    pub max_borrowed_samples: usize,
+    pub history_size: usize,
+    pub history_max_age: Option<Duration>,
+    pub owner_start_time: Option<Duration>,
+}
+
+/// A snapshot of the dynamic counters of a
+/// [`crate::service::messaging_pattern::MessagingPattern::PublishSubscribe`] based service,
+/// acquired with [`DynamicConfig::statistics()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub struct Statistics {
+    timestamp: Time,
+    number_of_publishers: usize,
+    number_of_subscribers: usize,
+    number_of_failed_loans: u64,
+    number_of_overflows: u64,
+    peak_publishers: usize,
+    peak_subscribers: usize,
+    peak_active_samples: usize,
+}
+
+impl Statistics {
+    /// Returns the point in time of the [`ClockType::Monotonic`] clock when the snapshot was
+    /// taken.
+    pub fn timestamp(&self) -> Time {
+        self.timestamp
+    }
+
+    /// See [`DynamicConfig::number_of_publishers()`].
+    pub fn number_of_publishers(&self) -> usize {
+        self.number_of_publishers
+    }
+
+    /// See [`DynamicConfig::number_of_subscribers()`].
+    pub fn number_of_subscribers(&self) -> usize {
+        self.number_of_subscribers
+    }
+
+    /// See [`DynamicConfig::number_of_failed_loans()`].
+    pub fn number_of_failed_loans(&self) -> u64 {
+        self.number_of_failed_loans
+    }
+
+    /// See [`DynamicConfig::number_of_overflows()`].
+    pub fn number_of_overflows(&self) -> u64 {
+        self.number_of_overflows
+    }
+
+    /// See [`DynamicConfig::peak_publishers()`].
+    pub fn peak_publishers(&self) -> usize {
+        self.peak_publishers
+    }
+
+    /// See [`DynamicConfig::peak_subscribers()`].
+    pub fn peak_subscribers(&self) -> usize {
+        self.peak_subscribers
+    }
+
+    /// See [`DynamicConfig::peak_active_samples()`].
+    pub fn peak_active_samples(&self) -> usize {
+        self.peak_active_samples
+    }
+}
+
+/// The size of the data segment of a [`crate::port::publisher::Publisher`], see
+/// [`MemoryStatistics::data_segments()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DataSegmentUsage {
+    publisher_id: UniquePublisherId,
+    size: usize,
+}
+
+impl DataSegmentUsage {
+    /// Returns the [`UniquePublisherId`] of the [`crate::port::publisher::Publisher`] that
+    /// owns the data segment.
+    pub fn publisher_id(&self) -> UniquePublisherId {
+        self.publisher_id
+    }
+
+    /// Returns the size of the memory that is currently mapped for the data segment in bytes.
+    /// It covers all segments of a data segment with a dynamic
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::allocation_strategy()`]
+    /// and grows when the data segment is resized.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// Introspection information of a [`crate::port::publisher::Publisher`] that is connected
+/// to the service, see [`DynamicConfig::publishers()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PublisherInfo {
+    publisher_id: UniquePublisherId,
+    node_name: PortNodeName,
+}
+
+impl PublisherInfo {
+    /// Returns the [`UniquePublisherId`] of the [`crate::port::publisher::Publisher`].
+    pub fn publisher_id(&self) -> UniquePublisherId {
+        self.publisher_id
+    }
+
+    /// Returns the [`PortNodeName`] that was provided with
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::node_name()`]. It is
+    /// empty when no name was provided.
+    pub fn node_name(&self) -> &PortNodeName {
+        &self.node_name
+    }
+}
+
+/// Introspection information of a [`crate::port::subscriber::Subscriber`] that is connected
+/// to the service, see [`DynamicConfig::subscribers()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SubscriberInfo {
+    subscriber_id: UniqueSubscriberId,
+    node_name: PortNodeName,
+}
+
+impl SubscriberInfo {
+    /// Returns the [`UniqueSubscriberId`] of the [`crate::port::subscriber::Subscriber`].
+    pub fn subscriber_id(&self) -> UniqueSubscriberId {
+        self.subscriber_id
+    }
+
+    /// Returns the [`PortNodeName`] that was provided with
+    /// [`crate::service::port_factory::subscriber::PortFactorySubscriber::node_name()`]. It is
+    /// empty when no name was provided.
+    pub fn node_name(&self) -> &PortNodeName {
+        &self.node_name
+    }
+}
+
+/// A snapshot of the shared memory usage of a
+/// [`crate::service::messaging_pattern::MessagingPattern::PublishSubscribe`] based service,
+/// acquired with [`crate::service::port_factory::publish_subscribe::PortFactory::memory_statistics()`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MemoryStatistics {
+    allocated_sample_bytes: usize,
+    peak_allocated_sample_bytes: usize,
+    data_segments: Vec<DataSegmentUsage>,
+}
+
+impl MemoryStatistics {
+    /// Returns the sum of the sizes of the data segments of all connected
+    /// [`crate::port::publisher::Publisher`]s.
+    pub fn reserved_bytes(&self) -> usize {
+        self.data_segments.iter().map(|s| s.size).sum()
+    }
+
+    /// See [`DynamicConfig::allocated_sample_bytes()`].
+    pub fn allocated_sample_bytes(&self) -> usize {
+        self.allocated_sample_bytes
+    }
+
+    /// See [`DynamicConfig::peak_allocated_sample_bytes()`].
+    pub fn peak_allocated_sample_bytes(&self) -> usize {
+        self.peak_allocated_sample_bytes
+    }
+
+    /// Returns the [`DataSegmentUsage`] of every connected
+    /// [`crate::port::publisher::Publisher`].
+    pub fn data_segments(&self) -> &[DataSegmentUsage] {
+        &self.data_segments
+    }
+}
+
+/// Describes which participants of the service changed, returned by
+/// [`DynamicConfig::wait_for_change()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ChangeKind {
+    /// A [`crate::port::publisher::Publisher`] connected or disconnected.
+    Publishers,
+    /// A [`crate::port::subscriber::Subscriber`] connected or disconnected.
+    Subscribers,
+    /// [`crate::port::publisher::Publisher`]s and [`crate::port::subscriber::Subscriber`]s
+    /// connected or disconnected.
+    PublishersAndSubscribers,
+}
+
+/// Failures that can occur in [`DynamicConfig::wait_for_change()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum WaitForChangeError {
+    /// No participant changed within the provided timeout.
+    Timeout,
+    /// An interrupt signal was received while waiting.
+    InterruptSignal,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl core::fmt::Display for WaitForChangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "WaitForChangeError::{:?}", self)
+    }
+}
+
+impl std::error::Error for WaitForChangeError {}
+
+/// The registration of a [`crate::port::subscriber::Subscriber`] in the [`DynamicConfig`].
+/// Besides the [`ContainerHandle`] it contains a token that is unique for every registration,
+/// so that the registration is released exactly once, even when it is released concurrently
+/// by the [`crate::port::subscriber::Subscriber`] and by a
+/// [`crate::port::publisher::Publisher`] that evicts it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SubscriberRegistration {
+    pub(crate) handle: ContainerHandle,
+    token: u64,
 }
 
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
@@ -76,6 +441,31 @@ pub struct SubscriberDetails {
 pub struct DynamicConfig {
     pub(crate) subscribers: Container<SubscriberDetails>,
     pub(crate) publishers: Container<PublisherDetails>,
+    // the token of the current registration of every subscriber slot, 0 when it is free
+    subscriber_tokens: RelocatableVec<IoxAtomicU64>,
+    subscriber_token_counter: IoxAtomicU64,
+    // true while the subscriber of the slot waits for the signal of a new sample
+    sample_signal_requests: RelocatableVec<IoxAtomicBool>,
+    // the allocated sample bytes of the publisher of every slot, subtracted from
+    // allocated_sample_bytes when the slot is released
+    publisher_sample_bytes: RelocatableVec<IoxAtomicU64>,
+    // the mapped size of the data segment of the publisher of every slot, 0 until the
+    // publisher has stored it
+    publisher_data_segment_sizes: RelocatableVec<IoxAtomicU64>,
+    history_states: RelocatableVec<HistoryState>,
+    history_entries: RelocatableVec<HistoryEntry>,
+    history_size: usize,
+    number_of_failed_loans: IoxAtomicU64,
+    number_of_overflows: IoxAtomicU64,
+    peak_publishers: IoxAtomicU64,
+    peak_subscribers: IoxAtomicU64,
+    peak_active_samples: IoxAtomicU64,
+    allocated_sample_bytes: IoxAtomicU64,
+    peak_allocated_sample_bytes: IoxAtomicU64,
+    publisher_generation: IoxAtomicU64,
+    subscriber_generation: IoxAtomicU64,
+    number_of_change_waiters: IoxAtomicU64,
+    change_signal: UnnamedSemaphoreHandle,
 }
 
 impl DynamicConfig {
@@ -83,6 +473,33 @@ impl DynamicConfig {
         Self {
             subscribers: unsafe { Container::new_uninit(config.number_of_subscribers) },
             publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            subscriber_tokens: unsafe { RelocatableVec::new_uninit(config.number_of_subscribers) },
+            subscriber_token_counter: IoxAtomicU64::new(0),
+            sample_signal_requests: unsafe {
+                RelocatableVec::new_uninit(config.number_of_subscribers)
+            },
+            publisher_sample_bytes: unsafe {
+                RelocatableVec::new_uninit(config.number_of_publishers)
+            },
+            publisher_data_segment_sizes: unsafe {
+                RelocatableVec::new_uninit(config.number_of_publishers)
+            },
+            history_states: unsafe { RelocatableVec::new_uninit(Self::history_capacity(config).0) },
+            history_entries: unsafe {
+                RelocatableVec::new_uninit(Self::history_capacity(config).1)
+            },
+            history_size: config.history_size,
+            number_of_failed_loans: IoxAtomicU64::new(0),
+            number_of_overflows: IoxAtomicU64::new(0),
+            peak_publishers: IoxAtomicU64::new(0),
+            peak_subscribers: IoxAtomicU64::new(0),
+            peak_active_samples: IoxAtomicU64::new(0),
+            allocated_sample_bytes: IoxAtomicU64::new(0),
+            peak_allocated_sample_bytes: IoxAtomicU64::new(0),
+            publisher_generation: IoxAtomicU64::new(0),
+            subscriber_generation: IoxAtomicU64::new(0),
+            number_of_change_waiters: IoxAtomicU64::new(0),
+            change_signal: UnnamedSemaphoreHandle::new(),
         }
     }
 
@@ -93,11 +510,107 @@ impl DynamicConfig {
         fatal_panic!(from self,
             when self.publishers.init(allocator),
             "This should never happen! Unable to initialize publisher port id container.");
+        fatal_panic!(from self,
+            when self.subscriber_tokens.init(allocator),
+            "This should never happen! Unable to initialize the subscriber registration tokens.");
+        self.subscriber_tokens.fill_with(|| IoxAtomicU64::new(0));
+        fatal_panic!(from self,
+            when self.sample_signal_requests.init(allocator),
+            "This should never happen! Unable to initialize the sample signal requests.");
+        self.sample_signal_requests
+            .fill_with(|| IoxAtomicBool::new(false));
+        fatal_panic!(from self,
+            when self.publisher_sample_bytes.init(allocator),
+            "This should never happen! Unable to initialize the allocated sample bytes of the publishers.");
+        self.publisher_sample_bytes
+            .fill_with(|| IoxAtomicU64::new(0));
+        fatal_panic!(from self,
+            when self.publisher_data_segment_sizes.init(allocator),
+            "This should never happen! Unable to initialize the data segment sizes of the publishers.");
+        self.publisher_data_segment_sizes
+            .fill_with(|| IoxAtomicU64::new(0));
+        // an empty vector cannot be initialized since it has no memory to acquire
+        if self.history_size != 0 {
+            fatal_panic!(from self,
+                when self.history_states.init(allocator),
+                "This should never happen! Unable to initialize the publisher history states.");
+            fatal_panic!(from self,
+                when self.history_entries.init(allocator),
+                "This should never happen! Unable to initialize the publisher history entries.");
+            self.history_states.fill_with(|| HistoryState {
+                generation: IoxAtomicU64::new(0),
+                owner_high: IoxAtomicU64::new(0),
+                owner_low: IoxAtomicU64::new(0),
+                number_of_pushes: IoxAtomicU64::new(0),
+                number_of_readers: IoxAtomicU64::new(0),
+            });
+            self.history_entries.fill_with(|| HistoryEntry {
+                offset: IoxAtomicU64::new(0),
+                size: IoxAtomicU64::new(0),
+            });
+        }
+        fatal_panic!(from self,
+            when UnnamedSemaphoreBuilder::new()
+                .is_interprocess_capable(true)
+                .create(&self.change_signal),
+            "This should never happen! Unable to initialize the change signal.");
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        let (number_of_states, number_of_entries) = Self::history_capacity(config);
         Container::<SubscriberDetails>::memory_size(config.number_of_subscribers)
             + Container::<PublisherDetails>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<IoxAtomicU64>::memory_size(config.number_of_subscribers)
+            + RelocatableVec::<IoxAtomicBool>::memory_size(config.number_of_subscribers)
+            + RelocatableVec::<IoxAtomicU64>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<IoxAtomicU64>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<HistoryState>::memory_size(number_of_states)
+            + RelocatableVec::<HistoryEntry>::memory_size(number_of_entries)
+    }
+
+    fn history_capacity(config: &DynamicConfigSettings) -> (usize, usize) {
+        match config.history_size {
+            0 => (0, 0),
+            n => (config.number_of_publishers, config.number_of_publishers * n),
+        }
+    }
+
+    /// Returns the [`PublisherHistory`] of the publisher that is registered with the handle
+    /// `index`. Returns [`None`] when the service has no history.
+    pub(crate) fn publisher_history(&self, index: u32) -> Option<PublisherHistory<'_>> {
+        if self.history_size == 0 {
+            return None;
+        }
+
+        let index = index as usize;
+        // SAFETY: both vectors are initialized in init() and are never modified afterwards
+        let (states, entries) = unsafe {
+            (
+                self.history_states.as_slice(),
+                self.history_entries.as_slice(),
+            )
+        };
+
+        Some(PublisherHistory {
+            state: &states[index],
+            entries: &entries[index * self.history_size..(index + 1) * self.history_size],
+        })
+    }
+
+    /// Calls the callback for every registered publisher together with its
+    /// [`PublisherHistory`]. Publishers are skipped when the service has no history.
+    pub(crate) fn for_each_publisher_history<F: FnMut(&PublisherDetails, PublisherHistory<'_>)>(
+        &self,
+        mut callback: F,
+    ) {
+        let state = unsafe { self.publishers.get_state() };
+
+        state.for_each(|handle: ContainerHandle, details| {
+            if let Some(history) = self.publisher_history(handle.index()) {
+                callback(details, history);
+            }
+            CallbackProgression::Continue
+        });
     }
 
     pub(crate) unsafe fn remove_dead_node_id<
@@ -134,6 +647,39 @@ impl DynamicConfig {
             });
     }
 
+    pub(crate) unsafe fn remove_dead_ports<
+        PortCleanup: FnMut(UniquePortId, Option<Duration>) -> PortCleanupAction,
+    >(
+        &self,
+        mut port_cleanup_callback: PortCleanup,
+    ) {
+        self.publishers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_publisher| {
+                if port_cleanup_callback(
+                    UniquePortId::Publisher(registered_publisher.publisher_id),
+                    registered_publisher.owner_start_time,
+                ) == PortCleanupAction::RemovePort
+                {
+                    self.release_publisher_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+
+        self.subscribers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_subscriber| {
+                if port_cleanup_callback(
+                    UniquePortId::Subscriber(registered_subscriber.subscriber_id),
+                    registered_subscriber.owner_start_time,
+                ) == PortCleanupAction::RemovePort
+                {
+                    self.release_subscriber_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+    }
+
     /// Returns how many [`crate::port::publisher::Publisher`] ports are currently connected.
     pub fn number_of_publishers(&self) -> usize {
         self.publishers.len()
@@ -144,6 +690,247 @@ impl DynamicConfig {
         self.subscribers.len()
     }
 
+    /// Returns how often a [`crate::port::publisher::Publisher`] of the service failed to loan
+    /// a sample since the service was created.
+    pub fn number_of_failed_loans(&self) -> u64 {
+        self.number_of_failed_loans.load(Ordering::Relaxed)
+    }
+
+    /// Returns how often a [`crate::port::publisher::Publisher`] of the service recycled the
+    /// oldest sample of a full [`crate::port::subscriber::Subscriber`] buffer since the service
+    /// was created. It can only be non-zero when the service has safe overflow enabled.
+    pub fn number_of_overflows(&self) -> u64 {
+        self.number_of_overflows.load(Ordering::Relaxed)
+    }
+
+    /// Returns the largest number of [`crate::port::publisher::Publisher`] ports that were
+    /// connected at the same time since the service was created. Together with
+    /// [`DynamicConfig::peak_subscribers()`] and [`DynamicConfig::peak_active_samples()`] it
+    /// helps to right-size over-provisioned limits of the service.
+    pub fn peak_publishers(&self) -> usize {
+        self.peak_publishers.load(Ordering::Relaxed) as usize
+    }
+
+    /// Returns the largest number of [`crate::port::subscriber::Subscriber`] ports that were
+    /// connected at the same time since the service was created.
+    pub fn peak_subscribers(&self) -> usize {
+        self.peak_subscribers.load(Ordering::Relaxed) as usize
+    }
+
+    /// Returns the largest number of samples a single [`crate::port::publisher::Publisher`] of
+    /// the service had in use at the same time since the service was created. A sample is in
+    /// use from the moment it is loaned until it is returned by all
+    /// [`crate::port::subscriber::Subscriber`]s and is no longer part of the history.
+    pub fn peak_active_samples(&self) -> usize {
+        self.peak_active_samples.load(Ordering::Relaxed) as usize
+    }
+
+    /// Returns how many bytes of the data segments of all [`crate::port::publisher::Publisher`]s
+    /// are currently occupied by samples. A sample occupies its memory from the moment it is
+    /// loaned until it is returned by all [`crate::port::subscriber::Subscriber`]s and is no
+    /// longer part of the history or until the [`crate::port::publisher::Publisher`] is
+    /// removed from the service.
+    pub fn allocated_sample_bytes(&self) -> usize {
+        self.allocated_sample_bytes.load(Ordering::Relaxed) as usize
+    }
+
+    /// Returns the largest value of [`DynamicConfig::allocated_sample_bytes()`] since the
+    /// service was created. It is not reset when [`crate::port::publisher::Publisher`]s
+    /// disconnect and reconnect.
+    pub fn peak_allocated_sample_bytes(&self) -> usize {
+        self.peak_allocated_sample_bytes.load(Ordering::Relaxed) as usize
+    }
+
+    /// Returns a [`PublisherInfo`] for every [`crate::port::publisher::Publisher`] that is
+    /// currently connected to the service.
+    pub fn publishers(&self) -> Vec<PublisherInfo> {
+        let mut publishers = vec![];
+        self.__internal_list_publishers(|details| {
+            publishers.push(PublisherInfo {
+                publisher_id: details.publisher_id,
+                node_name: details.node_name,
+            })
+        });
+        publishers
+    }
+
+    /// Returns a [`SubscriberInfo`] for every [`crate::port::subscriber::Subscriber`] that is
+    /// currently connected to the service.
+    pub fn subscribers(&self) -> Vec<SubscriberInfo> {
+        let mut subscribers = vec![];
+        self.__internal_list_subscribers(|details| {
+            subscribers.push(SubscriberInfo {
+                subscriber_id: details.subscriber_id,
+                node_name: details.node_name,
+            })
+        });
+        subscribers
+    }
+
+    pub(crate) fn memory_statistics(&self, static_config: &StaticConfig) -> MemoryStatistics {
+        let mut data_segments = vec![];
+        let state = unsafe { self.publishers.get_state() };
+        state.for_each(|handle, details| {
+            // a dynamic data segment is resized by its publisher, the initial size is only
+            // reported until the publisher has stored the mapped size after its registration
+            let size = match self.publisher_data_segment_size(handle.index()) {
+                0 => initial_data_segment_size(
+                    static_config.sample_layout(details.max_slice_len),
+                    details.number_of_samples,
+                ),
+                size => size,
+            };
+            data_segments.push(DataSegmentUsage {
+                publisher_id: details.publisher_id,
+                size,
+            });
+            CallbackProgression::Continue
+        });
+
+        MemoryStatistics {
+            allocated_sample_bytes: self.allocated_sample_bytes(),
+            peak_allocated_sample_bytes: self.peak_allocated_sample_bytes(),
+            data_segments,
+        }
+    }
+
+    /// Returns a [`Statistics`] snapshot of all dynamic counters of the service. It is
+    /// convenient for periodic reporting since all values are acquired in one call. The
+    /// counters are updated concurrently, therefore the snapshot is not atomic as a whole.
+    pub fn statistics(&self) -> Statistics {
+        Statistics {
+            timestamp: Time::now_with_clock(ClockType::Monotonic).unwrap_or_default(),
+            number_of_publishers: self.number_of_publishers(),
+            number_of_subscribers: self.number_of_subscribers(),
+            number_of_failed_loans: self.number_of_failed_loans(),
+            number_of_overflows: self.number_of_overflows(),
+            peak_publishers: self.peak_publishers(),
+            peak_subscribers: self.peak_subscribers(),
+            peak_active_samples: self.peak_active_samples(),
+        }
+    }
+
+    /// Blocks until a [`crate::port::publisher::Publisher`] or
+    /// [`crate::port::subscriber::Subscriber`] connects to or disconnects from the service, or
+    /// until the `timeout` has passed. Returns which participants changed.
+    ///
+    /// Spurious wakeups are possible, a participant may for instance connect and disconnect
+    /// again before the caller is woken up. The returned [`ChangeKind`] is therefore only a
+    /// hint and the caller shall re-read the actual state, e.g. with
+    /// [`DynamicConfig::statistics()`].
+    pub fn wait_for_change(&self, timeout: Duration) -> Result<ChangeKind, WaitForChangeError> {
+        let msg = "Unable to wait for a change of the participants";
+        self.number_of_change_waiters.fetch_add(1, Ordering::SeqCst);
+        let result = self.wait_for_change_impl(timeout);
+        self.number_of_change_waiters.fetch_sub(1, Ordering::SeqCst);
+
+        match result {
+            Ok(Some(change)) => Ok(change),
+            Ok(None) => {
+                fail!(from self, with WaitForChangeError::Timeout,
+                    "{} since no participant changed within the timeout of {:?}.", msg, timeout);
+            }
+            Err(SemaphoreTimedWaitError::SemaphoreWaitError(SemaphoreWaitError::Interrupt)) => {
+                fail!(from self, with WaitForChangeError::InterruptSignal,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with WaitForChangeError::InternalFailure,
+                    "{} due to an internal failure ({:?}).", msg, e);
+            }
+        }
+    }
+
+    fn wait_for_change_impl(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<ChangeKind>, SemaphoreTimedWaitError> {
+        let publisher_generation = self.publisher_generation.load(Ordering::SeqCst);
+        let subscriber_generation = self.subscriber_generation.load(Ordering::SeqCst);
+        let semaphore = unsafe { UnnamedSemaphore::from_ipc_handle(&self.change_signal) };
+        let start = Time::now_with_clock(ClockType::Monotonic).unwrap_or_default();
+
+        loop {
+            let publishers_changed =
+                self.publisher_generation.load(Ordering::SeqCst) != publisher_generation;
+            let subscribers_changed =
+                self.subscriber_generation.load(Ordering::SeqCst) != subscriber_generation;
+            match (publishers_changed, subscribers_changed) {
+                (true, true) => return Ok(Some(ChangeKind::PublishersAndSubscribers)),
+                (true, false) => return Ok(Some(ChangeKind::Publishers)),
+                (false, true) => return Ok(Some(ChangeKind::Subscribers)),
+                (false, false) => (),
+            }
+
+            let elapsed = start.elapsed().unwrap_or(timeout);
+            if timeout <= elapsed {
+                return Ok(None);
+            }
+
+            // a signal that was meant for an earlier waiter may wake us up, the loop reevaluates
+            // the generations in this case and continues to wait for the remaining time
+            semaphore.timed_wait(timeout - elapsed)?;
+        }
+    }
+
+    fn signal_change(&self, generation: &IoxAtomicU64) {
+        generation.fetch_add(1, Ordering::SeqCst);
+        let semaphore = unsafe { UnnamedSemaphore::from_ipc_handle(&self.change_signal) };
+        for _ in 0..self.number_of_change_waiters.load(Ordering::SeqCst) {
+            if semaphore.post().is_err() {
+                break;
+            }
+        }
+    }
+
+    pub(crate) fn increment_number_of_failed_loans(&self) {
+        self.number_of_failed_loans.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn increment_number_of_overflows(&self) {
+        self.number_of_overflows.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn update_peak_active_samples(&self, value: usize) {
+        self.peak_active_samples
+            .fetch_max(value as u64, Ordering::Relaxed);
+    }
+
+    fn publisher_sample_bytes(&self, publisher_index: u32) -> &IoxAtomicU64 {
+        // SAFETY: the vector is initialized in init() and is never modified afterwards
+        unsafe { &self.publisher_sample_bytes.as_slice()[publisher_index as usize] }
+    }
+
+    pub(crate) fn add_allocated_sample_bytes(&self, publisher_index: u32, value: usize) {
+        self.publisher_sample_bytes(publisher_index)
+            .fetch_add(value as u64, Ordering::Relaxed);
+        let allocated = self
+            .allocated_sample_bytes
+            .fetch_add(value as u64, Ordering::Relaxed)
+            + value as u64;
+        self.peak_allocated_sample_bytes
+            .fetch_max(allocated, Ordering::Relaxed);
+    }
+
+    fn publisher_data_segment_size(&self, publisher_index: u32) -> usize {
+        // SAFETY: the vector is initialized in init() and is never modified afterwards
+        unsafe { &self.publisher_data_segment_sizes.as_slice()[publisher_index as usize] }
+            .load(Ordering::Relaxed) as usize
+    }
+
+    pub(crate) fn set_publisher_data_segment_size(&self, publisher_index: u32, value: usize) {
+        // SAFETY: the vector is initialized in init() and is never modified afterwards
+        unsafe { &self.publisher_data_segment_sizes.as_slice()[publisher_index as usize] }
+            .store(value as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn remove_allocated_sample_bytes(&self, publisher_index: u32, value: usize) {
+        self.publisher_sample_bytes(publisher_index)
+            .fetch_sub(value as u64, Ordering::Relaxed);
+        self.allocated_sample_bytes
+            .fetch_sub(value as u64, Ordering::Relaxed);
+    }
+
     #[doc(hidden)]
     pub fn __internal_list_subscribers<F: FnMut(&SubscriberDetails)>(&self, mut callback: F) {
         let state = unsafe { self.subscribers.get_state() };
@@ -164,19 +951,120 @@ impl DynamicConfig {
         });
     }
 
-    pub(crate) fn add_subscriber_id(&self, details: SubscriberDetails) -> Option<ContainerHandle> {
-        unsafe { self.subscribers.add(details).ok() }
+    fn subscriber_token(&self, handle: ContainerHandle) -> &IoxAtomicU64 {
+        // SAFETY: the vector is initialized in init() and is never modified afterwards
+        unsafe { &self.subscriber_tokens.as_slice()[handle.index() as usize] }
+    }
+
+    fn sample_signal_request(&self, subscriber_index: u32) -> &IoxAtomicBool {
+        // SAFETY: the vector is initialized in init() and is never modified afterwards
+        unsafe { &self.sample_signal_requests.as_slice()[subscriber_index as usize] }
+    }
+
+    /// Requests that the next [`crate::port::publisher::Publisher`] that delivers a sample
+    /// to the [`crate::port::subscriber::Subscriber`] with the handle index
+    /// `subscriber_index` signals its sample signal. The buffer of the
+    /// [`crate::port::subscriber::Subscriber`] must be checked after the request, otherwise a
+    /// sample that was delivered in between is not signaled.
+    pub(crate) fn request_sample_signal(&self, subscriber_index: u32) {
+        self.sample_signal_request(subscriber_index)
+            .store(true, Ordering::SeqCst);
+        core::sync::atomic::fence(Ordering::SeqCst);
+    }
+
+    /// Withdraws a request of [`DynamicConfig::request_sample_signal()`].
+    pub(crate) fn cancel_sample_signal_request(&self, subscriber_index: u32) {
+        self.sample_signal_request(subscriber_index)
+            .store(false, Ordering::Relaxed);
     }
 
-    pub(crate) fn release_subscriber_handle(&self, handle: ContainerHandle) {
-        unsafe { self.subscribers.remove(handle, ReleaseMode::Default) };
+    /// Returns true when the [`crate::port::subscriber::Subscriber`] with the handle index
+    /// `subscriber_index` requested a sample signal and resets the request. Must be called
+    /// after a sample was delivered.
+    pub(crate) fn take_sample_signal_request(&self, subscriber_index: u32) -> bool {
+        core::sync::atomic::fence(Ordering::SeqCst);
+        let request = self.sample_signal_request(subscriber_index);
+        request.load(Ordering::Relaxed) && request.swap(false, Ordering::SeqCst)
+    }
+
+    pub(crate) fn add_subscriber_id(
+        &self,
+        details: SubscriberDetails,
+    ) -> Option<SubscriberRegistration> {
+        let handle = unsafe { self.subscribers.add(details).ok() }?;
+        // a request of a previous subscriber of the slot must not be signaled
+        self.sample_signal_request(handle.index())
+            .store(false, Ordering::Relaxed);
+        let token = self
+            .subscriber_token_counter
+            .fetch_add(1, Ordering::Relaxed)
+            + 1;
+        self.subscriber_token(handle)
+            .store(token, Ordering::Release);
+        self.peak_subscribers
+            .fetch_max(self.subscribers.len() as u64, Ordering::Relaxed);
+        self.signal_change(&self.subscriber_generation);
+        Some(SubscriberRegistration { handle, token })
+    }
+
+    /// Returns true when the registration was not released in the meantime.
+    pub(crate) fn is_subscriber_registered(&self, registration: SubscriberRegistration) -> bool {
+        self.subscriber_token(registration.handle)
+            .load(Ordering::Acquire)
+            == registration.token
+    }
+
+    /// Releases the registration when it was not already released, for instance by a
+    /// [`crate::port::publisher::Publisher`] that evicted the subscriber. Returns false when it
+    /// was already released.
+    pub(crate) fn release_subscriber(&self, registration: SubscriberRegistration) -> bool {
+        if self
+            .subscriber_token(registration.handle)
+            .compare_exchange(registration.token, 0, Ordering::AcqRel, Ordering::Relaxed)
+            .is_err()
+        {
+            return false;
+        }
+
+        unsafe {
+            self.subscribers
+                .remove(registration.handle, ReleaseMode::Default)
+        };
+        self.signal_change(&self.subscriber_generation);
+        true
+    }
+
+    /// Releases the current registration of the subscriber slot of the handle. Returns false
+    /// when it was already released.
+    pub(crate) fn release_subscriber_handle(&self, handle: ContainerHandle) -> bool {
+        let token = self.subscriber_token(handle).load(Ordering::Acquire);
+        if token == 0 {
+            return false;
+        }
+
+        self.release_subscriber(SubscriberRegistration { handle, token })
     }
 
     pub(crate) fn add_publisher_id(&self, details: PublisherDetails) -> Option<ContainerHandle> {
-        unsafe { self.publishers.add(details).ok() }
+        let handle = unsafe { self.publishers.add(details).ok() };
+        if handle.is_some() {
+            self.peak_publishers
+                .fetch_max(self.publishers.len() as u64, Ordering::Relaxed);
+            self.signal_change(&self.publisher_generation);
+        }
+        handle
     }
 
     pub(crate) fn release_publisher_handle(&self, handle: ContainerHandle) {
+        // the samples of a publisher are no longer accounted when it is removed, this covers
+        // also the publishers of crashed processes that are removed by the cleanup
+        let remaining_sample_bytes = self
+            .publisher_sample_bytes(handle.index())
+            .swap(0, Ordering::Relaxed);
+        self.allocated_sample_bytes
+            .fetch_sub(remaining_sample_bytes, Ordering::Relaxed);
+        self.set_publisher_data_segment_size(handle.index(), 0);
         unsafe { self.publishers.remove(handle, ReleaseMode::Default) };
+        self.signal_change(&self.publisher_generation);
     }
 }
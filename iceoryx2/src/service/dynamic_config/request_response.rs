@@ -10,13 +10,18 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::time::Duration;
+
 use iceoryx2_bb_container::queue::RelocatableContainer;
 use iceoryx2_bb_elementary::CallbackProgression;
-use iceoryx2_bb_lock_free::mpmc::container::Container;
+use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
 
-use crate::{node::NodeId, port::port_identifiers::UniquePortId};
+use crate::{
+    node::NodeId,
+    port::port_identifiers::{UniqueClientId, UniquePortId, UniqueServerId},
+};
 
 use super::PortCleanupAction;
 
@@ -24,14 +29,22 @@ use super::PortCleanupAction;
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub struct ServerDetails {
-    _stub: usize,
+    pub server_id: UniqueServerId,
+    pub node_id: NodeId,
+    pub number_of_responses: usize,
+    pub request_buffer_size: usize,
+    pub owner_start_time: Option<Duration>,
 }
 
 #[doc(hidden)]
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub struct ClientDetails {
-    _stub: usize,
+    pub client_id: UniqueClientId,
+    pub node_id: NodeId,
+    pub number_of_requests: usize,
+    pub response_buffer_size: usize,
+    pub owner_start_time: Option<Duration>,
 }
 
 #[repr(C)]
@@ -87,10 +100,81 @@ impl DynamicConfig {
         PortCleanup: FnMut(UniquePortId) -> PortCleanupAction,
     >(
         &self,
-        _node_id: &NodeId,
-        mut _port_cleanup_callback: PortCleanup,
+        node_id: &NodeId,
+        mut port_cleanup_callback: PortCleanup,
     ) {
-        todo!()
+        self.servers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_server| {
+                if registered_server.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Server(registered_server.server_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_server_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+
+        self.clients
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_client| {
+                if registered_client.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Client(registered_client.client_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_client_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+    }
+
+    pub(crate) unsafe fn remove_dead_ports<
+        PortCleanup: FnMut(UniquePortId, Option<Duration>) -> PortCleanupAction,
+    >(
+        &self,
+        mut port_cleanup_callback: PortCleanup,
+    ) {
+        self.servers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_server| {
+                if port_cleanup_callback(
+                    UniquePortId::Server(registered_server.server_id),
+                    registered_server.owner_start_time,
+                ) == PortCleanupAction::RemovePort
+                {
+                    self.release_server_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+
+        self.clients
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_client| {
+                if port_cleanup_callback(
+                    UniquePortId::Client(registered_client.client_id),
+                    registered_client.owner_start_time,
+                ) == PortCleanupAction::RemovePort
+                {
+                    self.release_client_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+    }
+
+    pub(crate) fn add_server_id(&self, details: ServerDetails) -> Option<ContainerHandle> {
+        unsafe { self.servers.add(details).ok() }
+    }
+
+    pub(crate) fn release_server_handle(&self, handle: ContainerHandle) {
+        unsafe { self.servers.remove(handle, ReleaseMode::Default) };
+    }
+
+    pub(crate) fn add_client_id(&self, details: ClientDetails) -> Option<ContainerHandle> {
+        unsafe { self.clients.add(details).ok() }
+    }
+
+    pub(crate) fn release_client_handle(&self, handle: ContainerHandle) {
+        unsafe { self.clients.remove(handle, ReleaseMode::Default) };
     }
 
     #[doc(hidden)]
@@ -104,7 +188,7 @@ impl DynamicConfig {
     }
 
     #[doc(hidden)]
-    pub fn __internal_list_publishers<F: FnMut(&ClientDetails)>(&self, mut callback: F) {
+    pub fn __internal_list_clients<F: FnMut(&ClientDetails)>(&self, mut callback: F) {
         let state = unsafe { self.clients.get_state() };
 
         state.for_each(|_, details| {
@@ -31,25 +31,134 @@
 //! # }
 //! ```
 
+use core::mem::MaybeUninit;
+
+use iceoryx2_bb_posix::clock::{ClockType, Time, TimeBuilder};
+
 use crate::port::port_identifiers::UniquePublisherId;
 
+// the nanoseconds of a send timestamp are always below one second, the maximum marks a sample
+// that was sent without send timestamp
+const NO_SEND_TIMESTAMP: u32 = u32::MAX;
+
 /// Sample header used by
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+///
+/// When the [`Service`](crate::service::Service) does not record send timestamps, see
+/// [`StaticConfig::has_send_timestamp()`](crate::service::static_config::publish_subscribe::StaticConfig::has_send_timestamp()),
+/// the sample stores the [`Header`] without its last field, the seconds of the send timestamp.
+/// Therefore, the [`Header`] is never referenced in the memory of the sample but always copied
+/// from and into it.
 #[derive(Debug, Copy, Clone)]
 #[repr(C)]
 pub struct Header {
     publisher_port_id: UniquePublisherId,
     number_of_elements: u64,
+    used_len: u64,
+    // the publish timestamp is always acquired with the realtime clock and the send timestamp
+    // with the monotonic clock, therefore both are stored without their clock type
+    publish_timestamp_seconds: u64,
+    sequence_number: u64,
+    coalescence_group: u64,
+    publish_timestamp_nanoseconds: u32,
+    send_timestamp_nanoseconds: u32,
+    // must stay the last field, the header has no padding
+    send_timestamp_seconds: u64,
 }
 
 impl Header {
-    pub(crate) fn new(publisher_port_id: UniquePublisherId, number_of_elements: u64) -> Self {
+    pub(crate) fn new(
+        publisher_port_id: UniquePublisherId,
+        number_of_elements: u64,
+        used_len: u64,
+    ) -> Self {
         Self {
             publisher_port_id,
             number_of_elements,
+            used_len,
+            publish_timestamp_seconds: 0,
+            sequence_number: 0,
+            coalescence_group: 0,
+            publish_timestamp_nanoseconds: 0,
+            send_timestamp_nanoseconds: NO_SEND_TIMESTAMP,
+            send_timestamp_seconds: 0,
+        }
+    }
+
+    /// Returns the size of the [`Header`] in the memory of a sample.
+    pub(crate) const fn size_in_sample(has_send_timestamp: bool) -> usize {
+        if has_send_timestamp {
+            core::mem::size_of::<Header>()
+        } else {
+            core::mem::size_of::<Header>() - core::mem::size_of::<u64>()
         }
     }
 
+    /// Reads the [`Header`] from the memory of a sample.
+    ///
+    /// # Safety
+    ///
+    ///  * `ptr` must point to the initialized header of a sample
+    ///  * `has_send_timestamp` must be the
+    ///    [`StaticConfig::has_send_timestamp()`](crate::service::static_config::publish_subscribe::StaticConfig::has_send_timestamp())
+    ///    of the [`Service`](crate::service::Service) the sample belongs to
+    pub(crate) unsafe fn read_from_sample(ptr: *const Header, has_send_timestamp: bool) -> Header {
+        let mut header = MaybeUninit::<Header>::uninit();
+        core::ptr::copy_nonoverlapping(
+            ptr.cast::<u8>(),
+            header.as_mut_ptr().cast::<u8>(),
+            Self::size_in_sample(has_send_timestamp),
+        );
+
+        if !has_send_timestamp {
+            core::ptr::addr_of_mut!((*header.as_mut_ptr()).send_timestamp_seconds).write(0);
+        }
+
+        header.assume_init()
+    }
+
+    /// Writes the [`Header`] into the memory of a sample.
+    ///
+    /// # Safety
+    ///
+    ///  * `ptr` must point to the header of a sample
+    ///  * `has_send_timestamp` must be the
+    ///    [`StaticConfig::has_send_timestamp()`](crate::service::static_config::publish_subscribe::StaticConfig::has_send_timestamp())
+    ///    of the [`Service`](crate::service::Service) the sample belongs to
+    pub(crate) unsafe fn write_to_sample(&self, ptr: *mut Header, has_send_timestamp: bool) {
+        core::ptr::copy_nonoverlapping(
+            (self as *const Header).cast::<u8>(),
+            ptr.cast::<u8>(),
+            Self::size_in_sample(has_send_timestamp),
+        );
+    }
+
+    pub(crate) fn set_publish_timestamp(&mut self, value: Time) {
+        self.publish_timestamp_seconds = value.seconds();
+        self.publish_timestamp_nanoseconds = value.nanoseconds();
+    }
+
+    pub(crate) fn set_send_timestamp(&mut self, value: Time) {
+        self.send_timestamp_seconds = value.seconds();
+        self.send_timestamp_nanoseconds = value.nanoseconds();
+    }
+
+    pub(crate) fn set_sequence_number(&mut self, value: u64) {
+        self.sequence_number = value;
+    }
+
+    pub(crate) fn set_number_of_elements(&mut self, value: u64) {
+        self.number_of_elements = value;
+    }
+
+    pub(crate) fn set_used_len(&mut self, value: u64) {
+        self.used_len = value;
+    }
+
+    pub(crate) fn set_coalescence_group(&mut self, value: u64) {
+        self.coalescence_group = value;
+    }
+
     /// Returns the [`UniquePublisherId`] of the source [`crate::port::publisher::Publisher`].
     pub fn publisher_id(&self) -> UniquePublisherId {
         self.publisher_port_id
@@ -67,4 +176,64 @@ impl Header {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns how many bytes of the sample's payload were marked as used by the
+    /// [`crate::port::publisher::Publisher`], see
+    /// [`SampleMut::set_used_len()`](crate::sample_mut::SampleMut::set_used_len()).
+    pub fn used_len(&self) -> u64 {
+        self.used_len
+    }
+
+    /// Returns the point in time when the sample was sent by the
+    /// [`crate::port::publisher::Publisher`]. It is acquired with [`Time::now_realtime()`], the
+    /// system time since the unix epoch, so that it can be compared with timestamps of other
+    /// hosts and with other timestamps acquired with [`Time::now_realtime()`]. It is affected
+    /// when the system time is adjusted, therefore iceoryx2 does not use it to measure
+    /// durations. The age of the history and the deadline of a
+    /// [`crate::port::subscriber::Subscriber`] are measured with the monotonic clock and
+    /// latencies can be measured with the [`Header::send_timestamp()`].
+    pub fn publish_timestamp(&self) -> Time {
+        TimeBuilder::new()
+            .clock_type(ClockType::Realtime)
+            .seconds(self.publish_timestamp_seconds)
+            .nanoseconds(self.publish_timestamp_nanoseconds)
+            .create()
+    }
+
+    /// Returns the point in time when the sample was sent by a
+    /// [`crate::port::publisher::Publisher`] that was created with
+    /// [`PortFactoryPublisher::enable_send_timestamp()`](crate::service::port_factory::publisher::PortFactoryPublisher::enable_send_timestamp())
+    /// or that belongs to a [`Service`](crate::service::Service) with a
+    /// [`coalescing_window()`](crate::service::static_config::publish_subscribe::StaticConfig::coalescing_window()).
+    /// It is acquired with the [`ClockType::Monotonic`] clock and is therefore not affected when
+    /// the system time is adjusted, which makes it suitable to measure the latency between
+    /// processes on the same host. Returns [`None`] when the sample was sent without a send
+    /// timestamp.
+    pub fn send_timestamp(&self) -> Option<Time> {
+        if self.send_timestamp_nanoseconds == NO_SEND_TIMESTAMP {
+            return None;
+        }
+
+        Some(
+            TimeBuilder::new()
+                .clock_type(ClockType::Monotonic)
+                .seconds(self.send_timestamp_seconds)
+                .nanoseconds(self.send_timestamp_nanoseconds)
+                .create(),
+        )
+    }
+
+    /// Returns the sequence number the [`crate::port::publisher::Publisher`] assigned to the
+    /// sample when it was sent. The first sample of a publisher has the sequence number `1`,
+    /// every following sample increments it by one.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Returns the coalescence group the sample was tagged with, see
+    /// [`SampleMut::set_coalescence_group()`](crate::sample_mut::SampleMut::set_coalescence_group()).
+    /// It is `0` unless it was set explicitly.
+    pub fn coalescence_group(&self) -> u64 {
+        self.coalescence_group
+    }
 }
@@ -10,14 +10,85 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server_builder().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     println!("request header: {:?}", active_request.header());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+
 /// Request header used by
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
 #[derive(Debug, Copy, Clone)]
 #[repr(C)]
-pub struct RequestHeader {}
+pub struct RequestHeader {
+    client_port_id: UniqueClientId,
+    request_id: u64,
+}
+
+impl RequestHeader {
+    pub(crate) fn new(client_port_id: UniqueClientId, request_id: u64) -> Self {
+        Self {
+            client_port_id,
+            request_id,
+        }
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`Client`](crate::port::client::Client) that
+    /// sent the request.
+    pub fn client_port_id(&self) -> UniqueClientId {
+        self.client_port_id
+    }
+
+    /// Returns the id of the request. It is unique for all requests of the same
+    /// [`Client`](crate::port::client::Client) and is used to correlate the responses with
+    /// the request.
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+}
 
 /// Response header used by
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
 #[derive(Debug, Copy, Clone)]
 #[repr(C)]
-pub struct ResponseHeader {}
+pub struct ResponseHeader {
+    server_port_id: UniqueServerId,
+    request_id: u64,
+}
+
+impl ResponseHeader {
+    pub(crate) fn new(server_port_id: UniqueServerId, request_id: u64) -> Self {
+        Self {
+            server_port_id,
+            request_id,
+        }
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`Server`](crate::port::server::Server) that
+    /// sent the response.
+    pub fn server_port_id(&self) -> UniqueServerId {
+        self.server_port_id
+    }
+
+    /// Returns the id of the request the response belongs to, see
+    /// [`RequestHeader::request_id()`].
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+}
@@ -147,6 +147,9 @@ pub mod service_name;
 /// Represents the unique id of a [`Service`]
 pub mod service_id;
 
+/// A serializable reference to an existing [`Service`] that other processes can attach to
+pub mod service_handle;
+
 /// Represents the static configuration of a [`Service`]. These are the settings that never change
 /// during the runtime of a service, like:
 ///
@@ -182,6 +185,7 @@ use config_scheme::service_tag_config;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_log::{debug, fail, trace, warn};
+use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_cal::dynamic_storage::{
     DynamicStorage, DynamicStorageBuilder, DynamicStorageOpenError,
 };
@@ -244,6 +248,36 @@ impl core::fmt::Display for ServiceDetailsError {
 
 impl std::error::Error for ServiceDetailsError {}
 
+/// Failure that can be reported by [`Service::destroy()`] and [`Service::destroy_forced()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceDestroyError {
+    /// The [`Service`] does not exist.
+    DoesNotExist,
+    /// The [`Service`] is still used by the contained number of ports whose owning processes
+    /// are alive.
+    ServiceInUse(usize),
+    /// The [`Service`] has no ports of alive processes but is still opened by an alive
+    /// [`Node`](crate::node::Node).
+    ServiceStillOpened,
+    /// The resources of the contained number of ports could not be removed. The [`Service`]
+    /// and the ports are kept so that the destruction can be retried.
+    PortResourcesNotRemoved(usize),
+    /// The process has insufficient permissions to remove the resources of the [`Service`].
+    InsufficientPermissions,
+    /// The [`Service`] was created with a different iceoryx2 version.
+    VersionMismatch,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl core::fmt::Display for ServiceDestroyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ServiceDestroyError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ServiceDestroyError {}
+
 /// Failure that can be reported by [`Service::list()`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ServiceListError {
@@ -261,6 +295,60 @@ impl core::fmt::Display for ServiceListError {
 
 impl std::error::Error for ServiceListError {}
 
+/// The reason why the stored resources of a [`Service`] could not be migrated with
+/// [`Service::migrate_storage()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageMigrationFailure {
+    /// The stored static config could not be read or does not contain the settings that are
+    /// required to convert it.
+    StaticConfigNotConvertible,
+    /// The [`Service`] was created with a message header layout that differs from the one of
+    /// the current iceoryx2 version.
+    IncompatibleHeaderLayout,
+    /// The dynamic config of the [`Service`] still exists. The [`Service`] is either still in
+    /// use or its resources were not cleaned up.
+    DynamicConfigExists,
+    /// The converted static config could not be written into the static storage. The old
+    /// static config is restored whenever possible.
+    FailedToRewriteStaticConfig,
+}
+
+/// A [`Service`] whose stored resources could not be migrated with
+/// [`Service::migrate_storage()`] and that must be recreated manually.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnmigratableService {
+    /// The name under which the static config of the [`Service`] is stored.
+    pub uuid: FileName,
+    /// The [`ServiceName`] of the [`Service`], if it could be read from the stored static
+    /// config.
+    pub service_name: Option<ServiceName>,
+    /// The reason why the [`Service`] could not be migrated.
+    pub reason: StorageMigrationFailure,
+}
+
+/// Failure that can be reported by [`Service::migrate_storage()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageMigrationError {
+    /// There is no migration between the requested storage format versions. Only migrations
+    /// to the current [`STORAGE_FORMAT_VERSION`] are supported.
+    UnsupportedMigration,
+    /// The process has insufficient permissions to list all [`Service`]s.
+    InsufficientPermissions,
+    /// All [`Service`]s that could be converted were migrated, the contained [`Service`]s
+    /// remained untouched and must be recreated manually.
+    RequiresManualRecreation(Vec<UnmigratableService>),
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl core::fmt::Display for StorageMigrationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "StorageMigrationError::{:?}", self)
+    }
+}
+
+impl std::error::Error for StorageMigrationError {}
+
 /// Represents all the [`Service`] information that one can acquire with [`Service::list()`]
 /// when the [`Service`] is accessible by the current process.
 #[derive(Debug)]
@@ -284,6 +372,7 @@ pub struct ServiceDetails<S: Service> {
 pub struct ServiceState<S: Service> {
     pub(crate) static_config: StaticConfig,
     pub(crate) shared_node: Arc<SharedNode<S>>,
+    pub(crate) directory: Option<Path>,
     pub(crate) dynamic_storage: S::DynamicStorage,
     pub(crate) static_storage: S::StaticStorage,
 }
@@ -292,12 +381,14 @@ impl<S: Service> ServiceState<S> {
     pub(crate) fn new(
         static_config: StaticConfig,
         shared_node: Arc<SharedNode<S>>,
+        directory: Option<Path>,
         dynamic_storage: S::DynamicStorage,
         static_storage: S::StaticStorage,
     ) -> Self {
         let new_self = Self {
             static_config,
             shared_node,
+            directory,
             dynamic_storage,
             static_storage,
         };
@@ -346,19 +437,26 @@ pub(crate) mod internal {
             notifier::Notifier,
             port_identifiers::UniquePortId,
             publisher::{
-                remove_data_segment_of_publisher, remove_publisher_from_all_connections,
+                remove_data_segment_of_port, remove_data_segment_of_publisher,
+                remove_port_from_all_connections, remove_publisher_from_all_connections,
                 remove_subscriber_from_all_connections,
             },
+            subscriber::remove_sample_signal_of_subscriber,
         },
         prelude::EventId,
     };
 
     use super::*;
+    use iceoryx2_bb_posix::process::Process;
 
-    fn send_dead_node_signal<S: Service>(service_id: &ServiceId, config: &config::Config) {
+    fn send_dead_node_signal<S: Service>(
+        service_id: &ServiceId,
+        config: &config::Config,
+        directory: Option<&Path>,
+    ) {
         let origin = "send_dead_node_signal()";
 
-        let service_details = match details::<S>(config, &service_id.0.into()) {
+        let service_details = match details::<S>(config, directory, &service_id.0.into()) {
             Ok(Some(service_details)) => service_details,
             Ok(None) => return,
             Err(e) => {
@@ -385,7 +483,12 @@ pub(crate) mod internal {
             }
         };
 
-        let service = match node.service_builder(service_name).event().open() {
+        let mut service_builder = node.service_builder(service_name);
+        if let Some(directory) = directory {
+            service_builder = service_builder.in_directory(directory);
+        }
+
+        let service = match service_builder.event().open() {
             Ok(service) => service,
             Err(EventOpenError::DoesNotExist) => return,
             Err(e) => {
@@ -427,6 +530,280 @@ pub(crate) mod internal {
         trace!(from origin, "Send dead node signal on service {}.", service_name);
     }
 
+    fn remove_port_resources<S: Service>(
+        origin: &str,
+        port_id: UniquePortId,
+        config: &config::Config,
+        directory: Option<&Path>,
+    ) -> PortCleanupAction {
+        match port_id {
+            UniquePortId::Publisher(ref id) => {
+                if let Err(e) =
+                    unsafe { remove_publisher_from_all_connections::<S>(id, config, directory) }
+                {
+                    debug!(from origin, "Failed to remove the publishers ({:?}) from all of its connections ({:?}).", id, e);
+                    return PortCleanupAction::SkipPort;
+                }
+
+                if let Err(e) =
+                    unsafe { remove_data_segment_of_publisher::<S>(id, config, directory) }
+                {
+                    debug!(from origin, "Failed to remove the publishers ({:?}) data segment ({:?}).", id, e);
+                    return PortCleanupAction::SkipPort;
+                }
+            }
+            UniquePortId::Subscriber(ref id) => {
+                if let Err(e) =
+                    unsafe { remove_subscriber_from_all_connections::<S>(id, config, directory) }
+                {
+                    debug!(from origin, "Failed to remove the subscriber ({:?}) from all of its connections ({:?}).", id, e);
+                    return PortCleanupAction::SkipPort;
+                }
+
+                if let Err(e) =
+                    unsafe { remove_sample_signal_of_subscriber::<S>(id, config, directory) }
+                {
+                    debug!(from origin, "Failed to remove the subscribers ({:?}) sample signal ({:?}).", id, e);
+                    return PortCleanupAction::SkipPort;
+                }
+            }
+            UniquePortId::Client(ref id) => {
+                if let Err(e) =
+                    unsafe { remove_port_from_all_connections::<S>(id.value(), config, directory) }
+                {
+                    debug!(from origin, "Failed to remove the client ({:?}) from all of its connections ({:?}).", id, e);
+                    return PortCleanupAction::SkipPort;
+                }
+
+                if let Err(e) =
+                    unsafe { remove_data_segment_of_port::<S>(id.value(), config, directory) }
+                {
+                    debug!(from origin, "Failed to remove the clients ({:?}) data segment ({:?}).", id, e);
+                    return PortCleanupAction::SkipPort;
+                }
+            }
+            UniquePortId::Server(ref id) => {
+                if let Err(e) =
+                    unsafe { remove_port_from_all_connections::<S>(id.value(), config, directory) }
+                {
+                    debug!(from origin, "Failed to remove the server ({:?}) from all of its connections ({:?}).", id, e);
+                    return PortCleanupAction::SkipPort;
+                }
+
+                if let Err(e) =
+                    unsafe { remove_data_segment_of_port::<S>(id.value(), config, directory) }
+                {
+                    debug!(from origin, "Failed to remove the servers ({:?}) data segment ({:?}).", id, e);
+                    return PortCleanupAction::SkipPort;
+                }
+            }
+            UniquePortId::Notifier(_) => (),
+            UniquePortId::Listener(ref id) => {
+                if let Err(e) = unsafe { remove_connection_of_listener::<S>(id, config, directory) }
+                {
+                    debug!(from origin, "Failed to remove the listeners ({:?}) connection ({:?}).", id, e);
+                    return PortCleanupAction::SkipPort;
+                }
+            }
+        };
+
+        debug!(from origin, "Remove port {:?} from service.", port_id);
+        PortCleanupAction::RemovePort
+    }
+
+    /// Returns true when the process that created the port is still alive. Since process ids
+    /// are reused, an alive process whose start time differs from the start time that was
+    /// stored when the port was created is not its owner. When one of the start times is
+    /// unavailable the port is considered alive.
+    fn is_port_owner_alive(port_id: &UniquePortId, owner_start_time: Option<Duration>) -> bool {
+        let process = Process::from_pid(port_id.process_id());
+        if !process.is_alive() {
+            return false;
+        }
+
+        match (owner_start_time, process.start_time()) {
+            (Some(owner_start_time), Ok(start_time)) => owner_start_time == start_time,
+            _ => true,
+        }
+    }
+
+    /// Removes all ports from the [`DynamicConfig`] whose owning process is no longer alive
+    /// and releases their resources. Returns the number of removed ports.
+    pub(crate) fn reap_dead_ports<S: Service>(
+        dynamic_config: &DynamicConfig,
+        config: &config::Config,
+        directory: Option<&Path>,
+    ) -> usize {
+        let origin = "reap_dead_ports()";
+        let mut number_of_reaped_ports = 0;
+
+        let reap_port = |port_id: UniquePortId, owner_start_time| {
+            if is_port_owner_alive(&port_id, owner_start_time) {
+                return PortCleanupAction::SkipPort;
+            }
+
+            let action = remove_port_resources::<S>(origin, port_id, config, directory);
+            if action == PortCleanupAction::RemovePort {
+                number_of_reaped_ports += 1;
+            }
+            action
+        };
+
+        unsafe { dynamic_config.remove_dead_ports(reap_port) };
+
+        number_of_reaped_ports
+    }
+
+    /// Opens the dynamic config of the [`Service`] the [`Node`](crate::node::Node) is
+    /// registered at and removes all ports of dead processes. Returns the number of removed
+    /// ports.
+    pub(crate) fn reap_dead_ports_of_service<S: Service>(
+        node_id: &NodeId,
+        service_id: &ServiceId,
+        config: &config::Config,
+    ) -> usize {
+        let directory = service_directory_from_tag::<S>(node_id, service_id, config);
+        match open_dynamic_config::<S>(config, directory.as_ref(), service_id) {
+            Ok(Some(storage)) => reap_dead_ports::<S>(storage.get(), config, directory.as_ref()),
+            Ok(None) => 0,
+            Err(e) => {
+                debug!(from "reap_dead_ports_of_service()",
+                    "Unable to open the dynamic config of the service {:?} ({:?}).", service_id, e);
+                0
+            }
+        }
+    }
+
+    /// Removes the static config, the dynamic config and the resources of all ports of the
+    /// [`Service`]. When `force` is false, it fails as long as a port of an alive process is
+    /// registered at the [`Service`] or an alive [`Node`](crate::node::Node) has it opened.
+    /// When the resources of a port cannot be removed, the configs are kept and it fails.
+    pub(crate) fn destroy_service<S: Service>(
+        service_id: &ServiceId,
+        config: &config::Config,
+        directory: Option<&Path>,
+        force: bool,
+    ) -> Result<(), ServiceDestroyError> {
+        let origin = format!("Service::destroy({:?})", service_id);
+        let msg = "Unable to destroy service";
+        let uuid: FileName = service_id.0.into();
+
+        let dynamic_config = match open_dynamic_config::<S>(config, directory, service_id) {
+            Ok(v) => v,
+            Err(ServiceDetailsError::VersionMismatch) => {
+                fail!(from origin, with ServiceDestroyError::VersionMismatch,
+                    "{} since the service version does not match.", msg);
+            }
+            Err(e) => {
+                fail!(from origin, with ServiceDestroyError::InternalError,
+                    "{} since the dynamic config could not be opened ({:?}).", msg, e);
+            }
+        };
+
+        let static_storage_config =
+            config_scheme::static_config_storage_config::<S>(config, directory);
+        let static_config_exists = fail!(from origin,
+            when <S::StaticStorage as NamedConceptMgmt>::does_exist_cfg(&uuid, &static_storage_config),
+            with ServiceDestroyError::InternalError,
+            "{} since the existence of the static config could not be verified.", msg);
+
+        if dynamic_config.is_none() && !static_config_exists {
+            fail!(from origin, with ServiceDestroyError::DoesNotExist,
+                "{} since the service does not exist.", msg);
+        }
+
+        match dynamic_config {
+            Some(dynamic_config) => {
+                let mut number_of_live_ports = 0;
+                unsafe {
+                    dynamic_config
+                        .get()
+                        .remove_dead_ports(|port_id, owner_start_time| {
+                            if is_port_owner_alive(&port_id, owner_start_time) {
+                                number_of_live_ports += 1;
+                            }
+                            PortCleanupAction::SkipPort
+                        })
+                };
+
+                if number_of_live_ports != 0 && !force {
+                    fail!(from origin, with ServiceDestroyError::ServiceInUse(number_of_live_ports),
+                        "{} since it is still used by {} ports of alive processes.", msg, number_of_live_ports);
+                }
+
+                let mut has_alive_nodes = false;
+                dynamic_config.get().list_node_ids(|node_id| {
+                    if let Ok(Some(NodeState::Alive(_))) = NodeState::<S>::new(node_id, config) {
+                        has_alive_nodes = true;
+                        CallbackProgression::Stop
+                    } else {
+                        CallbackProgression::Continue
+                    }
+                });
+
+                if has_alive_nodes && !force {
+                    fail!(from origin, with ServiceDestroyError::ServiceStillOpened,
+                        "{} since it is still opened by an alive node.", msg);
+                }
+
+                let mut number_of_remaining_ports = 0;
+                unsafe {
+                    dynamic_config.get().remove_dead_ports(|port_id, _| {
+                        let action =
+                            remove_port_resources::<S>(&origin, port_id, config, directory);
+                        if action == PortCleanupAction::SkipPort {
+                            number_of_remaining_ports += 1;
+                        }
+                        action
+                    })
+                };
+
+                if number_of_remaining_ports != 0 {
+                    fail!(from origin, with ServiceDestroyError::PortResourcesNotRemoved(number_of_remaining_ports),
+                        "{} since the resources of {} ports could not be removed.", msg, number_of_remaining_ports);
+                }
+
+                dynamic_config.get().list_node_ids(|node_id| {
+                    if let Err(e) = remove_service_tag::<S>(node_id, service_id, config) {
+                        debug!(from origin, "Unable to remove the service tag of the node {:?} ({:?}).", node_id, e);
+                    }
+                    CallbackProgression::Continue
+                });
+
+                dynamic_config.acquire_ownership();
+            }
+            None => {
+                if force {
+                    let dynamic_storage_config =
+                        dynamic_config_storage_config::<S>(config, directory);
+                    if let Err(e) = unsafe {
+                        <S::DynamicStorage as NamedConceptMgmt>::remove_cfg(
+                            &uuid,
+                            &dynamic_storage_config,
+                        )
+                    } {
+                        debug!(from origin, "Unable to remove the uninitialized dynamic config ({:?}).", e);
+                    }
+                }
+            }
+        }
+
+        match unsafe { remove_static_service_config::<S>(config, directory, &uuid) } {
+            Ok(_) => {
+                debug!(from origin, "Service destroyed.");
+                Ok(())
+            }
+            Err(NamedConceptRemoveError::InsufficientPermissions) => {
+                fail!(from origin, with ServiceDestroyError::InsufficientPermissions,
+                    "{} since the static config could not be removed due to insufficient permissions.", msg);
+            }
+            Err(NamedConceptRemoveError::InternalError) => {
+                fail!(from origin, with ServiceDestroyError::InternalError,
+                    "{} since the static config could not be removed due to an internal error.", msg);
+            }
+        }
+    }
+
     pub(crate) trait ServiceInternal<S: Service> {
         fn __internal_from_state(state: ServiceState<S>) -> S;
 
@@ -436,6 +813,7 @@ pub(crate) mod internal {
             node_id: &NodeId,
             service_id: &ServiceId,
             config: &config::Config,
+            directory: Option<&Path>,
         ) -> Result<(), ServiceRemoveNodeError> {
             let origin = format!(
                 "Service::remove_node_from_service({:?}, {:?})",
@@ -443,7 +821,7 @@ pub(crate) mod internal {
             );
             let msg = "Unable to remove node from service";
 
-            let dynamic_config = match open_dynamic_config::<S>(config, service_id) {
+            let dynamic_config = match open_dynamic_config::<S>(config, directory, service_id) {
                 Ok(Some(c)) => c,
                 Ok(None) => {
                     fail!(from origin,
@@ -462,42 +840,11 @@ pub(crate) mod internal {
 
             let mut number_of_dead_node_notifications = 0;
             let cleanup_port_resources = |port_id| {
-                match port_id {
-                    UniquePortId::Publisher(ref id) => {
-                        if let Err(e) =
-                            unsafe { remove_publisher_from_all_connections::<S>(id, config) }
-                        {
-                            debug!(from origin, "Failed to remove the publishers ({:?}) from all of its connections ({:?}).", id, e);
-                            return PortCleanupAction::SkipPort;
-                        }
-
-                        if let Err(e) = unsafe { remove_data_segment_of_publisher::<S>(id, config) }
-                        {
-                            debug!(from origin, "Failed to remove the publishers ({:?}) data segment ({:?}).", id, e);
-                            return PortCleanupAction::SkipPort;
-                        }
-                    }
-                    UniquePortId::Subscriber(ref id) => {
-                        if let Err(e) =
-                            unsafe { remove_subscriber_from_all_connections::<S>(id, config) }
-                        {
-                            debug!(from origin, "Failed to remove the subscriber ({:?}) from all of its connections ({:?}).", id, e);
-                            return PortCleanupAction::SkipPort;
-                        }
-                    }
-                    UniquePortId::Notifier(_) => {
-                        number_of_dead_node_notifications += 1;
-                    }
-                    UniquePortId::Listener(ref id) => {
-                        if let Err(e) = unsafe { remove_connection_of_listener::<S>(id, config) } {
-                            debug!(from origin, "Failed to remove the listeners ({:?}) connection ({:?}).", id, e);
-                            return PortCleanupAction::SkipPort;
-                        }
-                    }
-                };
+                if let UniquePortId::Notifier(_) = port_id {
+                    number_of_dead_node_notifications += 1;
+                }
 
-                debug!(from origin, "Remove port {:?} from service.", port_id);
-                PortCleanupAction::RemovePort
+                remove_port_resources::<S>(&origin, port_id, config, directory)
             };
 
             let remove_service = match unsafe {
@@ -513,7 +860,9 @@ pub(crate) mod internal {
             };
 
             if remove_service {
-                match unsafe { remove_static_service_config::<S>(config, &service_id.0.into()) } {
+                match unsafe {
+                    remove_static_service_config::<S>(config, directory, &service_id.0.into())
+                } {
                     Ok(_) => {
                         debug!(from origin, "Remove unused service.");
                         dynamic_config.acquire_ownership()
@@ -524,7 +873,7 @@ pub(crate) mod internal {
                     }
                 }
             } else if number_of_dead_node_notifications != 0 {
-                send_dead_node_signal::<S>(service_id, config);
+                send_dead_node_signal::<S>(service_id, config, directory);
             }
 
             Ok(())
@@ -545,7 +894,10 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
     /// Defines the construct that is used to store the [`StaticConfig`] of the [`Service`]
     type StaticStorage: StaticStorage;
 
-    /// Sets the serializer that is used to serialize the [`StaticConfig`] into the [`StaticStorage`]
+    /// Sets the serializer that is used to serialize the
+    /// [`NodeDetails`](crate::node::NodeDetails) into the node storage. The format of the
+    /// [`StaticConfig`] is defined by
+    /// [`config::Service::static_config_format`](crate::config::Service::static_config_format).
     type ConfigSerializer: Serialize;
 
     /// Defines the construct used to store the [`Service`]s dynamic configuration. This
@@ -623,8 +975,110 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
         config: &config::Config,
         messaging_pattern: MessagingPattern,
     ) -> Result<Option<ServiceDetails<Self>>, ServiceDetailsError> {
-        let service_id = ServiceId::new::<Self::ServiceNameHasher>(service_name, messaging_pattern);
-        details::<Self>(config, &service_id.0.into())
+        let service_name = service_name.normalize(&config.global.service);
+        let service_id =
+            ServiceId::new::<Self::ServiceNameHasher>(&service_name, messaging_pattern);
+        details::<Self>(config, None, &service_id.0.into())
+    }
+
+    /// Removes all resources of a [`Service`], like the static and dynamic config and the data
+    /// segments of its [`Publisher`](crate::port::publisher::Publisher)s. It can be used to
+    /// recover from a [`Service`] in a corrupted state. It fails with
+    /// [`ServiceDestroyError::ServiceInUse`] as long as a port of an alive process is registered
+    /// at the [`Service`] and with [`ServiceDestroyError::ServiceStillOpened`] as long as an
+    /// alive [`Node`](crate::node::Node) has it opened. When the resources of some ports could
+    /// not be removed, it fails with [`ServiceDestroyError::PortResourcesNotRemoved`] and keeps
+    /// the [`Service`], so that the call can be retried.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    /// use iceoryx2::service::ServiceDestroyError;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let name = ServiceName::new("Some/Name")?;
+    /// match ipc::Service::destroy(&name, Config::global_config(), MessagingPattern::Event) {
+    ///     Ok(()) => println!("service destroyed"),
+    ///     Err(ServiceDestroyError::DoesNotExist) => println!("service does not exist"),
+    ///     Err(ServiceDestroyError::ServiceInUse(n)) => println!("service is used by {n} ports"),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn destroy(
+        service_name: &ServiceName,
+        config: &config::Config,
+        messaging_pattern: MessagingPattern,
+    ) -> Result<(), ServiceDestroyError> {
+        Self::destroy_in_directory(service_name, config, None, messaging_pattern)
+    }
+
+    /// Removes all resources of a [`Service`] like [`Service::destroy()`] when it is stored in
+    /// the provided directory, see [`crate::service::builder::Builder::in_directory()`]. When
+    /// no directory is provided it behaves like [`Service::destroy()`].
+    fn destroy_in_directory(
+        service_name: &ServiceName,
+        config: &config::Config,
+        directory: Option<&Path>,
+        messaging_pattern: MessagingPattern,
+    ) -> Result<(), ServiceDestroyError> {
+        let service_name = service_name.normalize(&config.global.service);
+        let service_id = match directory {
+            Some(directory) => ServiceId::new_in_directory::<Self::ServiceNameHasher>(
+                &service_name,
+                messaging_pattern,
+                directory,
+            ),
+            None => ServiceId::new::<Self::ServiceNameHasher>(&service_name, messaging_pattern),
+        };
+        internal::destroy_service::<Self>(&service_id, config, directory, false)
+    }
+
+    /// Removes all resources of a [`Service`] like [`Service::destroy()`] but also when ports of
+    /// alive processes are still registered at the [`Service`] or alive
+    /// [`Node`](crate::node::Node)s have it opened.
+    ///
+    /// # Safety
+    ///
+    ///  * All processes that still use the [`Service`] must be dead or must never access the
+    ///    [`Service`] or any of its ports again, otherwise they may access removed resources.
+    ///  * Service handles and ports of the [`Service`] that are owned by the current process
+    ///    must never be used or dropped again.
+    unsafe fn destroy_forced(
+        service_name: &ServiceName,
+        config: &config::Config,
+        messaging_pattern: MessagingPattern,
+    ) -> Result<(), ServiceDestroyError> {
+        Self::destroy_forced_in_directory(service_name, config, None, messaging_pattern)
+    }
+
+    /// Removes all resources of a [`Service`] like [`Service::destroy_forced()`] when it is
+    /// stored in the provided directory, see
+    /// [`crate::service::builder::Builder::in_directory()`]. When no directory is provided it
+    /// behaves like [`Service::destroy_forced()`].
+    ///
+    /// # Safety
+    ///
+    ///  * See [`Service::destroy_forced()`].
+    unsafe fn destroy_forced_in_directory(
+        service_name: &ServiceName,
+        config: &config::Config,
+        directory: Option<&Path>,
+        messaging_pattern: MessagingPattern,
+    ) -> Result<(), ServiceDestroyError> {
+        let service_name = service_name.normalize(&config.global.service);
+        let service_id = match directory {
+            Some(directory) => ServiceId::new_in_directory::<Self::ServiceNameHasher>(
+                &service_name,
+                messaging_pattern,
+                directory,
+            ),
+            None => ServiceId::new::<Self::ServiceNameHasher>(&service_name, messaging_pattern),
+        };
+        internal::destroy_service::<Self>(&service_id, config, directory, true)
     }
 
     /// Returns a list of all services created under a given [`config::Config`].
@@ -645,37 +1099,90 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
     /// ```
     fn list<F: FnMut(ServiceDetails<Self>) -> CallbackProgression>(
         config: &config::Config,
-        mut callback: F,
+        callback: F,
     ) -> Result<(), ServiceListError> {
-        let msg = "Unable to list all services";
-        let origin = "Service::list_from_config()";
-        let static_storage_config = config_scheme::static_config_storage_config::<Self>(config);
-
-        let service_uuids = fail!(from origin,
-                when <Self::StaticStorage as NamedConceptMgmt>::list_cfg(&static_storage_config),
-                map NamedConceptListError::InsufficientPermissions => ServiceListError::InsufficientPermissions,
-                unmatched ServiceListError::InternalError,
-                "{} due to a failure while collecting all active services for config: {:?}", msg, config);
-
-        for uuid in &service_uuids {
-            if let Ok(Some(service_details)) = details::<Self>(config, uuid) {
-                if callback(service_details) == CallbackProgression::Stop {
-                    break;
-                }
-            }
-        }
+        list::<Self, F>(config, None, callback)
+    }
 
-        Ok(())
+    /// Returns a list of all services created under a given [`config::Config`] that are
+    /// stored in the provided directory, see
+    /// [`crate::service::builder::Builder::in_directory()`]. When no directory is provided
+    /// it behaves like [`Service::list()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    /// use iceoryx2_bb_container::semantic_string::SemanticString;
+    /// use iceoryx2_bb_system_types::path::Path;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let directory = Path::new(b"/tmp/iceoryx2/my_domain/")?;
+    /// ipc::Service::list_in_directory(Config::global_config(), Some(&directory), |service| {
+    ///     println!("\n{:#?}", &service);
+    ///     CallbackProgression::Continue
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn list_in_directory<F: FnMut(ServiceDetails<Self>) -> CallbackProgression>(
+        config: &config::Config,
+        directory: Option<&Path>,
+        callback: F,
+    ) -> Result<(), ServiceListError> {
+        list::<Self, F>(config, directory, callback)
+    }
+
+    /// Converts the stored static configs of all [`Service`]s under a given [`config::Config`]
+    /// from the storage format version `from_version` into `to_version`, see
+    /// [`STORAGE_FORMAT_VERSION`]. Settings that did not exist in `from_version` are set to
+    /// their defaults from the [`config::Config`]. [`Service`]s that are stored in another
+    /// version remain untouched. It returns the number of migrated [`Service`]s.
+    ///
+    /// A [`Service`] is only converted when it is not in use and when its message layout is
+    /// compatible with the current iceoryx2 version, all others are reported with
+    /// [`StorageMigrationError::RequiresManualRecreation`]. The migration must not run while
+    /// other processes create or open the [`Service`]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    /// use iceoryx2::service::static_config::STORAGE_FORMAT_VERSION;
+    /// use iceoryx2::service::StorageMigrationError;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// match ipc::Service::migrate_storage(Config::global_config(), 0, STORAGE_FORMAT_VERSION) {
+    ///     Ok(n) => println!("migrated {n} services"),
+    ///     Err(StorageMigrationError::RequiresManualRecreation(services)) => {
+    ///         for service in services {
+    ///             println!("recreate {:?} manually ({:?})", service.service_name, service.reason);
+    ///         }
+    ///     }
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn migrate_storage(
+        config: &config::Config,
+        from_version: u32,
+        to_version: u32,
+    ) -> Result<usize, StorageMigrationError> {
+        migrate_storage::<Self>(config, from_version, to_version)
     }
 }
 
 pub(crate) unsafe fn remove_static_service_config<S: Service>(
     config: &config::Config,
+    directory: Option<&Path>,
     uuid: &FileName,
 ) -> Result<bool, NamedConceptRemoveError> {
     let msg = "Unable to remove static service config";
     let origin = "Service::remove_static_service_config()";
-    let static_storage_config = config_scheme::static_config_storage_config::<S>(config);
+    let static_storage_config = config_scheme::static_config_storage_config::<S>(config, directory);
 
     match <S::StaticStorage as NamedConceptMgmt>::remove_cfg(uuid, &static_storage_config) {
         Ok(v) => Ok(v),
@@ -685,13 +1192,222 @@ pub(crate) unsafe fn remove_static_service_config<S: Service>(
     }
 }
 
+fn list<S: Service, F: FnMut(ServiceDetails<S>) -> CallbackProgression>(
+    config: &config::Config,
+    directory: Option<&Path>,
+    mut callback: F,
+) -> Result<(), ServiceListError> {
+    let msg = "Unable to list all services";
+    let origin = "Service::list_from_config()";
+    let static_storage_config = config_scheme::static_config_storage_config::<S>(config, directory);
+
+    let service_uuids = fail!(from origin,
+            when <S::StaticStorage as NamedConceptMgmt>::list_cfg(&static_storage_config),
+            map NamedConceptListError::InsufficientPermissions => ServiceListError::InsufficientPermissions,
+            unmatched ServiceListError::InternalError,
+            "{} due to a failure while collecting all active services for config: {:?}", msg, config);
+
+    for uuid in &service_uuids {
+        if let Ok(Some(service_details)) = details::<S>(config, directory, uuid) {
+            if callback(service_details) == CallbackProgression::Stop {
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn migrate_storage<S: Service>(
+    config: &config::Config,
+    from_version: u32,
+    to_version: u32,
+) -> Result<usize, StorageMigrationError> {
+    let msg = "Unable to migrate the stored services";
+    let origin = "Service::migrate_storage()";
+
+    if to_version != STORAGE_FORMAT_VERSION || to_version < from_version {
+        fail!(from origin, with StorageMigrationError::UnsupportedMigration,
+            "{} since there is no migration from storage format version {} to {}, only migrations to the current version {} are supported.",
+            msg, from_version, to_version, STORAGE_FORMAT_VERSION);
+    }
+
+    let static_storage_config = config_scheme::static_config_storage_config::<S>(config, None);
+    let service_uuids = fail!(from origin,
+            when <S::StaticStorage as NamedConceptMgmt>::list_cfg(&static_storage_config),
+            map NamedConceptListError::InsufficientPermissions => StorageMigrationError::InsufficientPermissions,
+            unmatched StorageMigrationError::InternalError,
+            "{} due to a failure while collecting all services for config: {:?}", msg, config);
+
+    let mut number_of_migrated_services = 0;
+    let mut unmigratable_services = vec![];
+    for uuid in &service_uuids {
+        if migrate_service::<S>(
+            config,
+            uuid,
+            from_version,
+            to_version,
+            &mut unmigratable_services,
+        ) {
+            number_of_migrated_services += 1;
+        }
+    }
+
+    if !unmigratable_services.is_empty() {
+        let number_of_unmigratable_services = unmigratable_services.len();
+        fail!(from origin, with StorageMigrationError::RequiresManualRecreation(unmigratable_services),
+            "{} since {} services cannot be converted and must be recreated manually.",
+            msg, number_of_unmigratable_services);
+    }
+
+    Ok(number_of_migrated_services)
+}
+
+fn migrate_service<S: Service>(
+    config: &config::Config,
+    uuid: &FileName,
+    from_version: u32,
+    to_version: u32,
+    unmigratable_services: &mut Vec<UnmigratableService>,
+) -> bool {
+    let msg = "Unable to migrate service";
+    let origin = "Service::migrate_storage()";
+    let static_storage_config = config_scheme::static_config_storage_config::<S>(config, None);
+    let mut unmigratable = |service_name, reason| {
+        unmigratable_services.push(UnmigratableService {
+            uuid: *uuid,
+            service_name,
+            reason,
+        });
+        false
+    };
+
+    let reader = match <<S::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+        S::StaticStorage,
+    >>::new(uuid)
+    .config(&static_storage_config)
+    .has_ownership(false)
+    .open(Duration::ZERO)
+    {
+        Ok(reader) => reader,
+        Err(StaticStorageOpenError::DoesNotExist)
+        | Err(StaticStorageOpenError::InitializationNotYetFinalized) => return false,
+        Err(e) => {
+            warn!(from origin, "{} \"{}\" since its static config could not be opened ({:?}).", msg, uuid, e);
+            return unmigratable(None, StorageMigrationFailure::StaticConfigNotConvertible);
+        }
+    };
+
+    let mut stored_content = vec![0u8; reader.len() as usize];
+    if let Err(e) = reader.read(stored_content.as_mut_slice()) {
+        warn!(from origin, "{} \"{}\" since its static config could not be read ({:?}).", msg, uuid, e);
+        return unmigratable(None, StorageMigrationFailure::StaticConfigNotConvertible);
+    }
+    drop(reader);
+
+    let stored = match StoredStaticConfig::from_bytes(&stored_content) {
+        Ok(stored) => stored,
+        Err(e) => {
+            warn!(from origin, "{} \"{}\" since its static config could not be deserialized ({:?}).", msg, uuid, e);
+            return unmigratable(None, StorageMigrationFailure::StaticConfigNotConvertible);
+        }
+    };
+
+    if stored.storage_format_version() != from_version || from_version == to_version {
+        return false;
+    }
+
+    let service_name = stored.service_name();
+    let format = stored.format();
+    let service_config = match stored.upgrade(config, to_version) {
+        Ok(service_config) if uuid.as_bytes() == service_config.service_id().0.as_bytes() => {
+            service_config
+        }
+        Ok(_) | Err(_) => {
+            warn!(from origin, "{} \"{}\" since its static config cannot be converted.", msg, uuid);
+            return unmigratable(
+                service_name,
+                StorageMigrationFailure::StaticConfigNotConvertible,
+            );
+        }
+    };
+
+    if !service_config.has_current_header_layout() {
+        warn!(from origin, "{} \"{}\" since it uses a message header layout of another iceoryx2 version.", msg, uuid);
+        return unmigratable(
+            service_name,
+            StorageMigrationFailure::IncompatibleHeaderLayout,
+        );
+    }
+
+    if !matches!(
+        open_dynamic_config::<S>(config, None, service_config.service_id()),
+        Ok(None)
+    ) {
+        warn!(from origin, "{} \"{}\" since its dynamic config still exists.", msg, uuid);
+        return unmigratable(service_name, StorageMigrationFailure::DynamicConfigExists);
+    }
+
+    let content = match format.serialize(&service_config) {
+        Ok(content) => content,
+        Err(e) => {
+            warn!(from origin, "{} \"{}\" since the converted static config could not be serialized ({:?}).", msg, uuid, e);
+            return unmigratable(
+                service_name,
+                StorageMigrationFailure::FailedToRewriteStaticConfig,
+            );
+        }
+    };
+
+    if let Err(e) = unsafe { remove_static_service_config::<S>(config, None, uuid) } {
+        warn!(from origin, "{} \"{}\" since the old static config could not be removed ({:?}).", msg, uuid, e);
+        return unmigratable(
+            service_name,
+            StorageMigrationFailure::FailedToRewriteStaticConfig,
+        );
+    }
+
+    let write = |content: &[u8]| {
+        <<S::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<S::StaticStorage>>::new(
+            uuid,
+        )
+        .config(&static_storage_config)
+        .has_ownership(true)
+        .create(content)
+        .map(|mut storage| storage.release_ownership())
+    };
+
+    match write(&content) {
+        Ok(()) => {
+            debug!(from origin, "Migrated service \"{}\" to storage format version {}.", uuid, to_version);
+            true
+        }
+        Err(e) => {
+            // restore the old static config so that the service is not lost
+            match write(&stored_content) {
+                Ok(()) => {
+                    warn!(from origin, "{} \"{}\" since the converted static config could not be written ({:?}), the old static config was restored.", msg, uuid, e);
+                }
+                Err(restore_error) => {
+                    warn!(from origin, "{} \"{}\" since the converted static config could not be written ({:?}) and the old static config could not be restored ({:?}), the service is lost.", msg, uuid, e, restore_error);
+                }
+            }
+            unmigratable(
+                service_name,
+                StorageMigrationFailure::FailedToRewriteStaticConfig,
+            )
+        }
+    }
+}
+
 fn details<S: Service>(
     config: &config::Config,
+    directory: Option<&Path>,
     uuid: &FileName,
 ) -> Result<Option<ServiceDetails<S>>, ServiceDetailsError> {
     let msg = "Unable to acquire servic details";
     let origin = "Service::details()";
-    let static_storage_config = config_scheme::static_config_storage_config::<S>(config);
+    let static_storage_config = config_scheme::static_config_storage_config::<S>(config, directory);
 
     let reader = match <<S::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
         S::StaticStorage,
@@ -710,22 +1426,21 @@ fn details<S: Service>(
         }
     };
 
-    let mut content = String::from_utf8(vec![b' '; reader.len() as usize]).unwrap();
-    if let Err(e) = reader.read(unsafe { content.as_mut_vec().as_mut_slice() }) {
+    let mut content = vec![0u8; reader.len() as usize];
+    if let Err(e) = reader.read(content.as_mut_slice()) {
         fail!(from origin, with ServiceDetailsError::FailedToReadStaticServiceInfo,
                 "{} since the static service info \"{}\" could not be read ({:?}).",
                 msg, uuid, e );
     }
 
-    let service_config =
-        match S::ConfigSerializer::deserialize::<StaticConfig>(unsafe { content.as_mut_vec() }) {
-            Ok(service_config) => service_config,
-            Err(e) => {
-                fail!(from origin, with ServiceDetailsError::FailedToDeserializeStaticServiceInfo,
+    let service_config = match StaticConfigFormat::deserialize(&content) {
+        Ok(service_config) => service_config,
+        Err(e) => {
+            fail!(from origin, with ServiceDetailsError::FailedToDeserializeStaticServiceInfo,
                     "{} since the static service info \"{}\" could not be deserialized ({:?}).",
                        msg, uuid, e );
-            }
-        };
+        }
+    };
 
     if uuid.as_bytes() != service_config.service_id().0.as_bytes() {
         fail!(from origin, with ServiceDetailsError::ServiceInInconsistentState,
@@ -733,7 +1448,7 @@ fn details<S: Service>(
                 msg, service_config, uuid, config);
     }
 
-    let dynamic_config = open_dynamic_config::<S>(config, service_config.service_id())?;
+    let dynamic_config = open_dynamic_config::<S>(config, directory, service_config.service_id())?;
     let dynamic_details = if let Some(d) = dynamic_config {
         let mut nodes = vec![];
         d.get().list_node_ids(|node_id| {
@@ -761,6 +1476,7 @@ fn details<S: Service>(
 
 fn open_dynamic_config<S: Service>(
     config: &config::Config,
+    directory: Option<&Path>,
     service_id: &ServiceId,
 ) -> Result<Option<S::DynamicStorage>, ServiceDetailsError> {
     let origin = format!(
@@ -775,7 +1491,7 @@ fn open_dynamic_config<S: Service>(
                 >>::Builder<'_> as NamedConceptBuilder<
                     S::DynamicStorage,
                 >>::new(&service_id.0.into())
-                    .config(&dynamic_config_storage_config::<S>(config))
+                    .config(&dynamic_config_storage_config::<S>(config, directory))
                 .has_ownership(false)
                 .open() {
             Ok(storage) => Ok(Some(storage)),
@@ -824,3 +1540,51 @@ pub(crate) fn remove_service_tag<S: Service>(
         }
     }
 }
+
+/// Returns the directory under which the resources of the [`Service`] were created by the
+/// [`Node`](crate::node::Node). When the [`Service`] was created or opened with a custom
+/// directory, the directory is stored in the node's service tag.
+pub(crate) fn service_directory_from_tag<S: Service>(
+    node_id: &NodeId,
+    service_id: &ServiceId,
+    config: &config::Config,
+) -> Option<Path> {
+    let origin = format!(
+        "service_directory_from_tag<{}>({:?}, service_id: {:?})",
+        core::any::type_name::<S>(),
+        node_id,
+        service_id
+    );
+
+    let tag = match <<S::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+        S::StaticStorage,
+    >>::new(&service_id.0.into())
+    .config(&service_tag_config::<S>(config, node_id))
+    .has_ownership(false)
+    .open(Duration::ZERO)
+    {
+        Ok(tag) => tag,
+        Err(e) => {
+            debug!(from origin, "Unable to open the service tag, falling back to the root path ({:?}).", e);
+            return None;
+        }
+    };
+
+    if tag.len() == 0 {
+        return None;
+    }
+
+    let mut content = vec![0u8; tag.len() as usize];
+    if let Err(e) = tag.read(content.as_mut_slice()) {
+        debug!(from origin, "Unable to read the service tag, falling back to the root path ({:?}).", e);
+        return None;
+    }
+
+    match Path::new(&content) {
+        Ok(directory) => Some(directory),
+        Err(e) => {
+            debug!(from origin, "The service tag contains an invalid directory, falling back to the root path ({:?}).", e);
+            None
+        }
+    }
+}
@@ -10,7 +10,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::port::port_identifiers::{UniqueListenerId, UniquePublisherId, UniqueSubscriberId};
+use crate::port::port_identifiers::{
+    UniqueClientId, UniqueListenerId, UniquePublisherId, UniqueServerId, UniqueSubscriberId,
+};
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_system_types::file_name::FileName;
@@ -23,6 +25,14 @@ pub(crate) fn event_concept_name(listener_id: &UniqueListenerId) -> FileName {
                  "{}", msg)
 }
 
+pub(crate) fn sample_signal_name(subscriber_id: &UniqueSubscriberId) -> FileName {
+    let msg = "The system does not support the required file name length for the subscribers sample signal.";
+    let origin = "sample_signal_name()";
+    fatal_panic!(from origin,
+                 when FileName::new(subscriber_id.0.value().to_string().as_bytes()),
+                 "{}", msg)
+}
+
 pub(crate) fn connection_name(
     publisher_id: UniquePublisherId,
     subscriber_id: UniqueSubscriberId,
@@ -58,3 +68,44 @@ pub(crate) fn data_segment_name(publisher_id: &UniquePublisherId) -> FileName {
                  when FileName::new(publisher_id.0.value().to_string().as_bytes()),
                  "{}", msg)
 }
+
+fn connection_name_from_values(sender: u128, receiver: u128) -> FileName {
+    let mut file = FileName::new(sender.to_string().as_bytes()).unwrap();
+    file.push(b'_').unwrap();
+    file.push_bytes(receiver.to_string().as_bytes()).unwrap();
+    file
+}
+
+pub(crate) fn request_connection_name(
+    client_id: UniqueClientId,
+    server_id: UniqueServerId,
+) -> FileName {
+    connection_name_from_values(client_id.value(), server_id.value())
+}
+
+pub(crate) fn response_connection_name(
+    server_id: UniqueServerId,
+    client_id: UniqueClientId,
+) -> FileName {
+    connection_name_from_values(server_id.value(), client_id.value())
+}
+
+/// Returns the raw values of the sending and the receiving port id of a connection.
+pub(crate) fn extract_port_values_from_connection(connection: &FileName) -> (u128, u128) {
+    let name = core::str::from_utf8(connection.as_bytes()).unwrap();
+    let separator = name.find('_').unwrap();
+    (
+        name[..separator].parse::<u128>().unwrap(),
+        name[separator + 1..].parse::<u128>().unwrap(),
+    )
+}
+
+pub(crate) fn port_data_segment_name(port_id_value: u128) -> FileName {
+    let msg =
+        "The system does not support the required file name length for the ports data segment.";
+    let origin = "port_data_segment_name()";
+
+    fatal_panic!(from origin,
+                 when FileName::new(port_id_value.to_string().as_bytes()),
+                 "{}", msg)
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let req_res = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = req_res.client_builder()
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+
+use crate::port::client::{Client, ClientCreateError};
+use crate::service;
+
+use super::request_response::PortFactory;
+
+/// Factory to create a new [`Client`] port/endpoint for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based communication.
+#[derive(Debug)]
+pub struct PortFactoryClient<
+    'factory,
+    Service: service::Service,
+    RequestPayload: Debug,
+    RequestUserHeader: Debug,
+    ResponsePayload: Debug,
+    ResponseUserHeader: Debug,
+> {
+    pub(crate) factory: &'factory PortFactory<
+        Service,
+        RequestPayload,
+        RequestUserHeader,
+        ResponsePayload,
+        ResponseUserHeader,
+    >,
+}
+
+impl<
+        'factory,
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    >
+    PortFactoryClient<
+        'factory,
+        Service,
+        RequestPayload,
+        RequestUserHeader,
+        ResponsePayload,
+        ResponseUserHeader,
+    >
+{
+    pub(crate) fn new(
+        factory: &'factory PortFactory<
+            Service,
+            RequestPayload,
+            RequestUserHeader,
+            ResponsePayload,
+            ResponseUserHeader,
+        >,
+    ) -> Self {
+        Self { factory }
+    }
+
+    /// Creates a new [`Client`] or returns a [`ClientCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<
+        Client<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>,
+        ClientCreateError,
+    > {
+        let origin = format!("{:?}", self);
+        Ok(fail!(from origin, when Client::new(&self.factory.service),
+                "Failed to create new Client port."))
+    }
+}
@@ -34,11 +34,21 @@
 //! # Ok(())
 //! # }
 //! ```
+use core::sync::atomic::Ordering;
+use core::time::Duration;
+
 use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::ListenerWaitError;
 
 use crate::node::NodeListFailure;
+use crate::port::listener::ListenerCreateError;
+use crate::port::notifier::{NotifierCreateError, NotifierNotifyError};
 use crate::service::attribute::AttributeSet;
+use crate::service::builder::event::EventOpenOrCreateError;
+use crate::service::internal::reap_dead_ports;
 use crate::service::service_id::ServiceId;
 use crate::service::{self, static_config};
 use crate::service::{dynamic_config, ServiceName};
@@ -47,6 +57,70 @@ use super::listener::PortFactoryListener;
 use super::nodes;
 use super::notifier::PortFactoryNotifier;
 
+/// Failures that can occur when a participant waits at the barrier of a
+/// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
+/// based [`Service`](crate::service::Service) with [`PortFactory::barrier()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum BarrierError {
+    /// Not all expected participants arrived before the timeout has passed. Contains the
+    /// number of participants that arrived at the barrier, including the caller.
+    Timeout {
+        /// The number of participants that arrived at the barrier.
+        arrived: usize,
+    },
+    /// The [`crate::port::listener::Listener`] that waits for the arrival of the other
+    /// participants could not be created.
+    ListenerCreateError(ListenerCreateError),
+    /// The [`crate::port::notifier::Notifier`] that signals the arrival to the other
+    /// participants could not be created.
+    NotifierCreateError(NotifierCreateError),
+    /// The arrival could not be signaled to the other participants.
+    NotifyError(NotifierNotifyError),
+    /// Waiting for the arrival of the other participants failed.
+    WaitError(ListenerWaitError),
+    /// The internal [`Service`](crate::service::Service) that signals the arrivals could not
+    /// be opened or created.
+    ServiceOpenOrCreateError(EventOpenOrCreateError),
+}
+
+impl core::fmt::Display for BarrierError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "BarrierError::{:?}", self)
+    }
+}
+
+impl std::error::Error for BarrierError {}
+
+impl From<ListenerCreateError> for BarrierError {
+    fn from(value: ListenerCreateError) -> Self {
+        BarrierError::ListenerCreateError(value)
+    }
+}
+
+impl From<NotifierCreateError> for BarrierError {
+    fn from(value: NotifierCreateError) -> Self {
+        BarrierError::NotifierCreateError(value)
+    }
+}
+
+impl From<NotifierNotifyError> for BarrierError {
+    fn from(value: NotifierNotifyError) -> Self {
+        BarrierError::NotifyError(value)
+    }
+}
+
+impl From<ListenerWaitError> for BarrierError {
+    fn from(value: ListenerWaitError) -> Self {
+        BarrierError::WaitError(value)
+    }
+}
+
+impl From<EventOpenOrCreateError> for BarrierError {
+    fn from(value: EventOpenOrCreateError) -> Self {
+        BarrierError::ServiceOpenOrCreateError(value)
+    }
+}
+
 /// The factory for
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event). It can
 /// acquire dynamic and static service informations and create [`crate::port::notifier::Notifier`]
@@ -98,6 +172,22 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
             callback,
         )
     }
+
+    fn reap_dead_ports(&self) -> usize {
+        reap_dead_ports::<Service>(
+            self.service.__internal_state().dynamic_storage.get(),
+            self.service.__internal_state().shared_node.config(),
+            self.service.__internal_state().directory.as_ref(),
+        )
+    }
+
+    fn storage_generation(&self) -> u64 {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .generation()
+    }
 }
 
 impl<Service: service::Service> PortFactory<Service> {
@@ -146,4 +236,125 @@ impl<Service: service::Service> PortFactory<Service> {
     pub fn listener_builder(&self) -> PortFactoryListener<Service> {
         PortFactoryListener { factory: self }
     }
+
+    /// Blocks until `expected` participants, including the caller, arrived at the barrier of
+    /// the [`Service`](crate::service::Service) or the `timeout` has passed. When the timeout
+    /// passes first, [`BarrierError::Timeout`] is returned with the number of participants that
+    /// arrived so far.
+    ///
+    /// The arrivals are counted in the dynamic config of the
+    /// [`Service`](crate::service::Service), see
+    /// [`DynamicConfig::number_of_barrier_arrivals()`](crate::service::dynamic_config::event::DynamicConfig::number_of_barrier_arrivals()).
+    /// Every `expected` consecutive arrivals form one round, therefore the barrier can be
+    /// reused as long as all participants provide the same `expected` value. A participant
+    /// that times out withdraws its arrival from its round, unless the round was completed in
+    /// the meantime, so that it neither counts as partner of a later participant nor shifts
+    /// the later rounds.
+    ///
+    /// The arrivals are signaled via an internal event [`Service`](crate::service::Service)
+    /// that exists only while participants wait at the barrier, the
+    /// [`crate::port::listener::Listener`]s of the [`Service`](crate::service::Service) itself
+    /// are never notified. It is created with the same number of listeners, notifiers and
+    /// nodes as the [`Service`](crate::service::Service), every waiting participant requires
+    /// one [`crate::port::listener::Listener`] and one [`crate::port::notifier::Notifier`] of it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let event = node.service_builder(&"MyStartupBarrier".try_into()?)
+    ///     .event()
+    ///     .open_or_create()?;
+    ///
+    /// // every participant is ready and waits until all others are ready as well
+    /// event.barrier(1, Duration::from_secs(5))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn barrier(&self, expected: usize, timeout: Duration) -> Result<(), BarrierError> {
+        use crate::service::port_factory::PortFactory;
+        let msg = "Unable to wait at the barrier";
+
+        if expected == 0 {
+            return Ok(());
+        }
+
+        let start = Time::now().unwrap_or_default();
+        let barrier_service = fail!(from self,
+            when self.barrier_service(),
+            "{} since the internal barrier service could not be opened or created.", msg);
+        // the listener must exist before the arrival is visible, otherwise the notification of
+        // the last participant could be missed
+        let listener = fail!(from self,
+            when barrier_service.listener_builder().create(),
+            "{} since the listener could not be created.", msg);
+        let notifier = fail!(from self,
+            when barrier_service.notifier_builder().create(),
+            "{} since the notifier could not be created.", msg);
+
+        let arrivals = &self.dynamic_config().barrier_arrivals;
+        let expected = expected as u64;
+        let round_start = arrivals.fetch_add(1, Ordering::AcqRel) / expected * expected;
+        let round_end = round_start + expected;
+
+        fail!(from self,
+            when notifier.notify(),
+            "{} since the arrival could not be signaled.", msg);
+
+        loop {
+            let arrived = arrivals.load(Ordering::Acquire);
+            if round_end <= arrived {
+                return Ok(());
+            }
+
+            let elapsed = start.elapsed().unwrap_or(timeout);
+            if timeout <= elapsed {
+                // the round is still open, otherwise the arrival could not be withdrawn
+                match arrivals.compare_exchange(
+                    arrived,
+                    arrived - 1,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                ) {
+                    Ok(_) => {
+                        fail!(from self, with BarrierError::Timeout { arrived: (arrived - round_start) as usize },
+                            "{} since only {} of {} participants arrived within {:?}.",
+                            msg, arrived - round_start, expected, timeout);
+                    }
+                    Err(_) => continue,
+                }
+            }
+
+            fail!(from self,
+                when listener.timed_wait_all(|_| {}, timeout - elapsed),
+                "{} since waiting for the other participants failed.", msg);
+        }
+    }
+
+    fn barrier_service(&self) -> Result<PortFactory<Service>, EventOpenOrCreateError> {
+        use crate::service::port_factory::PortFactory as _;
+        // the service id is never chosen by a user, therefore the name does not collide
+        // with a user defined service
+        let name = match ServiceName::new(&format!("iox2_barrier/{}", self.service_id().as_str())) {
+            Ok(name) => name,
+            Err(e) => {
+                fatal_panic!(from self,
+                    "This should never happen! The name of the internal barrier service is invalid ({:?}).", e);
+            }
+        };
+
+        crate::service::builder::Builder::new(
+            &name,
+            self.service.__internal_state().shared_node.clone(),
+        )
+        .event()
+        .max_listeners(self.static_config().max_listeners())
+        .max_notifiers(self.static_config().max_notifiers())
+        .max_nodes(self.static_config().max_nodes())
+        .open_or_create()
+    }
 }
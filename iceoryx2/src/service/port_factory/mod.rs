@@ -19,7 +19,8 @@ use super::dynamic_config::DynamicConfig;
 use super::service_id::ServiceId;
 use super::{attribute::AttributeSet, service_name::ServiceName};
 
-pub mod request_response;
+/// Factory to create a [`Client`](crate::port::client::Client)
+pub mod client;
 
 /// Factory to create the endpoints of
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) based
@@ -37,9 +38,22 @@ pub mod notifier;
 /// communication and to acquire static and dynamic service information
 pub mod publish_subscribe;
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
+/// communication that carries multiple payload types
+pub mod publish_subscribe_multi;
+
 /// Factory to create a [`Publisher`](crate::port::publisher::Publisher)
 pub mod publisher;
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) based
+/// communication and to acquire static and dynamic service information
+pub mod request_response;
+
+/// Factory to create a [`Server`](crate::port::server::Server)
+pub mod server;
+
 /// Factory to create a [`Subscriber`](crate::port::subscriber::Subscriber)
 pub mod subscriber;
 
@@ -82,6 +96,17 @@ pub trait PortFactory {
         &self,
         callback: F,
     ) -> Result<(), NodeListFailure>;
+
+    /// Removes all ports of the [`Service`](crate::service::Service) whose owning process is no
+    /// longer alive from the dynamic configuration and releases their resources. Returns the
+    /// number of removed ports.
+    fn reap_dead_ports(&self) -> usize;
+
+    /// Returns the generation of the underlying dynamic storage of the
+    /// [`Service`](crate::service::Service). It changes whenever the
+    /// [`Service`](crate::service::Service) is destroyed and recreated, so that cached references
+    /// can cheaply detect that they refer to a stale [`Service`](crate::service::Service).
+    fn storage_generation(&self) -> u64;
 }
 
 pub(crate) fn nodes<
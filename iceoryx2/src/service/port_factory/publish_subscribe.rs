@@ -40,20 +40,61 @@
 //! # }
 //! ```
 
-use core::{fmt::Debug, marker::PhantomData};
+use core::{fmt::Debug, marker::PhantomData, mem::MaybeUninit};
 
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::shm_allocator::PointerOffset;
 
 use crate::node::NodeListFailure;
+use crate::port::details::data_segment::DataSegmentView;
 use crate::service::attribute::AttributeSet;
+use crate::service::header::publish_subscribe::Header;
+use crate::service::internal::reap_dead_ports;
+use crate::service::naming_scheme::data_segment_name;
+use crate::service::service_handle::ServiceHandle;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
 use crate::service::{self, dynamic_config, static_config};
 
+use dynamic_config::publish_subscribe::{MemoryStatistics, Statistics};
+
 use super::nodes;
 use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 
+// a publisher that modifies its history during every attempt is skipped
+const MAX_SNAPSHOT_ATTEMPTS: usize = 8;
+
+/// A copy of a sample that was retained in the history of a
+/// [`crate::port::publisher::Publisher`], acquired with [`PortFactory::snapshot_history()`].
+/// Since it is a copy, it does not hold any resources of the
+/// [`crate::port::publisher::Publisher`]. The bytes of the user header and the payload are
+/// copied as they are, including the padding bytes of the types, and may therefore be
+/// uninitialized.
+#[derive(Debug, Clone)]
+pub struct HistorySample {
+    header: Header,
+    user_header: Vec<MaybeUninit<u8>>,
+    payload: Vec<MaybeUninit<u8>>,
+}
+
+impl HistorySample {
+    /// Returns the [`Header`] of the sample.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the bytes of the user header of the sample.
+    pub fn user_header_bytes(&self) -> &[MaybeUninit<u8>] {
+        &self.user_header
+    }
+
+    /// Returns the bytes of the payload of the sample.
+    pub fn payload_bytes(&self) -> &[MaybeUninit<u8>] {
+        &self.payload
+    }
+}
+
 /// The factory for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe).
 /// It can acquire dynamic and static service informations and create
@@ -62,6 +103,8 @@ use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 #[derive(Debug)]
 pub struct PortFactory<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> {
     pub(crate) service: Service,
+    pub(crate) has_outdated_payload_schema: bool,
+    pub(crate) config_provenance: static_config::publish_subscribe::StaticConfigProvenance,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -119,6 +162,22 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             callback,
         )
     }
+
+    fn reap_dead_ports(&self) -> usize {
+        reap_dead_ports::<Service>(
+            self.service.__internal_state().dynamic_storage.get(),
+            self.service.__internal_state().shared_node.config(),
+            self.service.__internal_state().directory.as_ref(),
+        )
+    }
+
+    fn storage_generation(&self) -> u64 {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .generation()
+    }
 }
 
 impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
@@ -127,11 +186,42 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
     pub(crate) fn new(service: Service) -> Self {
         Self {
             service,
+            has_outdated_payload_schema: false,
+            config_provenance:
+                static_config::publish_subscribe::StaticConfigProvenance::existing_service(),
             _payload: PhantomData,
             _user_header: PhantomData,
         }
     }
 
+    /// Returns the [`StaticConfigProvenance`](static_config::publish_subscribe::StaticConfigProvenance)
+    /// that records from which [`ConfigSource`](crate::config::ConfigSource) every setting of
+    /// the [`StaticConfig`](static_config::publish_subscribe::StaticConfig) originates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::ConfigSource;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .max_publishers(4)
+    ///     .open_or_create()?;
+    ///
+    /// if pubsub.config_provenance().max_publishers() == ConfigSource::ExistingService {
+    ///     println!("max publishers defined by the creator: {}", pubsub.static_config().max_publishers());
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn config_provenance(&self) -> &static_config::publish_subscribe::StaticConfigProvenance {
+        &self.config_provenance
+    }
+
     /// Returns a [`PortFactorySubscriber`] to create a new
     /// [`crate::port::subscriber::Subscriber`] port.
     ///
@@ -181,4 +271,329 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
     pub fn publisher_builder(&self) -> PortFactoryPublisher<Service, Payload, UserHeader> {
         PortFactoryPublisher::new(self)
     }
+
+    /// Returns a [`Statistics`] snapshot of the dynamic counters of the
+    /// [`Service`](crate::service::Service), see
+    /// [`DynamicConfig::statistics()`](dynamic_config::publish_subscribe::DynamicConfig::statistics()).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let statistics = pubsub.statistics();
+    /// println!("publishers: {}", statistics.number_of_publishers());
+    /// println!("overflows:  {}", statistics.number_of_overflows());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn statistics(&self) -> Statistics {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .statistics()
+    }
+
+    /// Returns a [`MemoryStatistics`] snapshot of the shared memory the
+    /// [`Service`](crate::service::Service) reserved for the data segments of its
+    /// [`crate::port::publisher::Publisher`]s and how much of it is occupied by samples. The
+    /// high-water mark of the occupied memory is stored in the
+    /// [`DynamicConfig`](dynamic_config::publish_subscribe::DynamicConfig) and covers the whole
+    /// lifetime of the [`Service`](crate::service::Service).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let statistics = pubsub.memory_statistics();
+    /// println!("reserved:  {} bytes", statistics.reserved_bytes());
+    /// println!("allocated: {} bytes", statistics.allocated_sample_bytes());
+    /// println!("peak:      {} bytes", statistics.peak_allocated_sample_bytes());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn memory_statistics(&self) -> MemoryStatistics {
+        let state = self.service.__internal_state();
+        state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .memory_statistics(state.static_config.publish_subscribe())
+    }
+
+    /// Returns a [`ServiceHandle`] that refers to the [`Service`](crate::service::Service).
+    /// It can be serialized and passed to another process which attaches to the
+    /// [`Service`](crate::service::Service) with
+    /// [`Builder::attach()`](crate::service::builder::publish_subscribe::Builder::attach()).
+    pub fn service_handle(&self) -> ServiceHandle {
+        let state = self.service.__internal_state();
+        ServiceHandle::new(
+            state.static_config.clone(),
+            state.dynamic_storage.get().generation(),
+        )
+    }
+
+    /// Copies the samples that are currently retained in the history of every
+    /// [`crate::port::publisher::Publisher`] of the
+    /// [`Service`](crate::service::Service), see
+    /// [`StaticConfig::history_size()`](crate::service::static_config::publish_subscribe::StaticConfig::history_size()).
+    /// The samples of a [`crate::port::publisher::Publisher`] are ordered from the oldest to
+    /// the newest one. It does not create a [`crate::port::subscriber::Subscriber`] and
+    /// therefore neither occupies a subscriber slot nor affects the buffer and borrow
+    /// accounting, which makes it suitable for debuggers and one-shot inspectors.
+    ///
+    /// The snapshot is best-effort. The history of every
+    /// [`crate::port::publisher::Publisher`] is read consistently, but the
+    /// [`crate::port::publisher::Publisher`]s may continue to publish and the history may
+    /// already have advanced when the call returns. A
+    /// [`crate::port::publisher::Publisher`] that modifies its history during every read
+    /// attempt is skipped. Samples that are evicted from the history while they are copied
+    /// are released by the [`crate::port::publisher::Publisher`] after the copy is finished.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .history_size(4)
+    ///     .open_or_create()?;
+    ///
+    /// for sample in pubsub.snapshot_history() {
+    ///     println!("sample {} with {} bytes", sample.header().sequence_number(), sample.payload_bytes().len());
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn snapshot_history(&self) -> Vec<HistorySample> {
+        let state = self.service.__internal_state();
+        let mut samples = vec![];
+
+        state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .for_each_publisher_history(|details, history| {
+                let data_segment = match DataSegmentView::<Service>::open(
+                    &data_segment_name(&details.publisher_id),
+                    details.data_segment_type,
+                    state,
+                ) {
+                    Ok(data_segment) => data_segment,
+                    // the publisher is just going out of scope
+                    Err(_) => return,
+                };
+
+                for _ in 0..MAX_SNAPSHOT_ATTEMPTS {
+                    // the publisher does not release the history samples while they are read
+                    let copies = history.read(details.publisher_id, |entries| {
+                        entries
+                            .iter()
+                            .filter_map(|(offset, size)| {
+                                self.copy_sample(&data_segment, *offset, *size)
+                            })
+                            .collect::<Vec<HistorySample>>()
+                    });
+
+                    if let Some(copies) = copies {
+                        samples.extend(copies);
+                        return;
+                    }
+                }
+            });
+
+        samples
+    }
+
+    fn copy_sample(
+        &self,
+        data_segment: &DataSegmentView<Service>,
+        offset: u64,
+        size: usize,
+    ) -> Option<HistorySample> {
+        let offset = PointerOffset::from_value(offset);
+        let static_config = self
+            .service
+            .__internal_state()
+            .static_config
+            .publish_subscribe();
+        let type_details = &static_config.message_type_details;
+
+        let chunk = data_segment.register_and_translate_offset(offset).ok()?;
+        let header_ptr = chunk as *const Header;
+        let header =
+            unsafe { Header::read_from_sample(header_ptr, static_config.has_send_timestamp()) };
+
+        let user_header_ptr = type_details.user_header_ptr_from_header(header_ptr.cast());
+        let user_header = unsafe {
+            core::slice::from_raw_parts(
+                user_header_ptr.cast::<MaybeUninit<u8>>(),
+                type_details.user_header.size,
+            )
+        }
+        .to_vec();
+
+        // the payload must not exceed the chunk, even when the header is inconsistent
+        let payload_ptr = type_details.payload_ptr_from_header(header_ptr.cast());
+        let payload_len = (header.number_of_elements() as usize * type_details.payload.size)
+            .min(size.saturating_sub(payload_ptr as usize - chunk));
+        let payload = unsafe {
+            core::slice::from_raw_parts(payload_ptr.cast::<MaybeUninit<u8>>(), payload_len)
+        }
+        .to_vec();
+
+        unsafe { data_segment.unregister_offset(offset) };
+
+        Some(HistorySample {
+            header,
+            user_header,
+            payload,
+        })
+    }
+}
+
+/// The factory for
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// that is returned by
+/// [`Builder::open_subscriber_only()`](crate::service::builder::publish_subscribe::Builder::open_subscriber_only()).
+/// Opposed to [`PortFactory`] it can only create [`crate::port::subscriber::Subscriber`] ports,
+/// so that a monitoring process cannot accidentally create a
+/// [`crate::port::publisher::Publisher`].
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::prelude::*;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let node = NodeBuilder::new().create::<ipc::Service>()?;
+/// # let _pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+/// #     .publish_subscribe::<u64>()
+/// #     .open_or_create()?;
+/// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+///     .publish_subscribe::<u64>()
+///     .open_subscriber_only()?;
+///
+/// println!("number of active publishers: {:?}", pubsub.dynamic_config().number_of_publishers());
+/// let subscriber = pubsub.subscriber_builder().create()?;
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SubscriberOnlyPortFactory<
+    Service: service::Service,
+    Payload: Debug + ?Sized,
+    UserHeader: Debug,
+> {
+    factory: PortFactory<Service, Payload, UserHeader>,
+}
+
+impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
+    crate::service::port_factory::PortFactory
+    for SubscriberOnlyPortFactory<Service, Payload, UserHeader>
+{
+    type Service = Service;
+    type StaticConfig = static_config::publish_subscribe::StaticConfig;
+    type DynamicConfig = dynamic_config::publish_subscribe::DynamicConfig;
+
+    fn name(&self) -> &ServiceName {
+        self.factory.name()
+    }
+
+    fn service_id(&self) -> &ServiceId {
+        self.factory.service_id()
+    }
+
+    fn attributes(&self) -> &AttributeSet {
+        self.factory.attributes()
+    }
+
+    fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
+        self.factory.static_config()
+    }
+
+    fn dynamic_config(&self) -> &dynamic_config::publish_subscribe::DynamicConfig {
+        self.factory.dynamic_config()
+    }
+
+    fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
+        &self,
+        callback: F,
+    ) -> Result<(), NodeListFailure> {
+        self.factory.nodes(callback)
+    }
+
+    fn reap_dead_ports(&self) -> usize {
+        self.factory.reap_dead_ports()
+    }
+
+    fn storage_generation(&self) -> u64 {
+        self.factory.storage_generation()
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
+    SubscriberOnlyPortFactory<Service, Payload, UserHeader>
+{
+    pub(crate) fn new(factory: PortFactory<Service, Payload, UserHeader>) -> Self {
+        Self { factory }
+    }
+
+    /// Returns the [`StaticConfigProvenance`](static_config::publish_subscribe::StaticConfigProvenance),
+    /// see [`PortFactory::config_provenance()`].
+    pub fn config_provenance(&self) -> &static_config::publish_subscribe::StaticConfigProvenance {
+        self.factory.config_provenance()
+    }
+
+    /// Returns a [`PortFactorySubscriber`] to create a new
+    /// [`crate::port::subscriber::Subscriber`] port.
+    pub fn subscriber_builder(&self) -> PortFactorySubscriber<'_, Service, Payload, UserHeader> {
+        self.factory.subscriber_builder()
+    }
+
+    /// Returns a [`Statistics`] snapshot of the dynamic counters of the
+    /// [`Service`](crate::service::Service), see [`PortFactory::statistics()`].
+    pub fn statistics(&self) -> Statistics {
+        self.factory.statistics()
+    }
+
+    /// Returns a [`MemoryStatistics`] snapshot of the shared memory usage of the
+    /// [`Service`](crate::service::Service), see [`PortFactory::memory_statistics()`].
+    pub fn memory_statistics(&self) -> MemoryStatistics {
+        self.factory.memory_statistics()
+    }
+
+    /// Returns a [`ServiceHandle`] that refers to the [`Service`](crate::service::Service),
+    /// see [`PortFactory::service_handle()`].
+    pub fn service_handle(&self) -> ServiceHandle {
+        self.factory.service_handle()
+    }
+
+    /// Copies the samples that are currently retained in the history of every
+    /// [`crate::port::publisher::Publisher`], see [`PortFactory::snapshot_history()`].
+    pub fn snapshot_history(&self) -> Vec<HistorySample> {
+        self.factory.snapshot_history()
+    }
 }
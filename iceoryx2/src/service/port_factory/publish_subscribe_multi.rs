@@ -0,0 +1,143 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/Bus".try_into()?)
+//!     .publish_subscribe_multi()
+//!     .register::<u64>()
+//!     .register::<f32>()
+//!     .open_or_create()?;
+//!
+//! println!("name:             {:?}", service.name());
+//! println!("registered types: {:?}", service.registered_types());
+//!
+//! let publisher = service.publisher()?;
+//! let subscriber = service.subscriber()?;
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+
+use crate::node::NodeListFailure;
+use crate::port::multi_publisher::MultiPublisher;
+use crate::port::multi_subscriber::MultiSubscriber;
+use crate::port::publisher::PublisherCreateError;
+use crate::port::subscriber::SubscriberCreateError;
+use crate::service::attribute::AttributeSet;
+use crate::service::builder::publish_subscribe::CustomPayloadMarker;
+use crate::service::builder::publish_subscribe_multi::TypeTag;
+use crate::service::dynamic_config::publish_subscribe::Statistics;
+use crate::service::service_id::ServiceId;
+use crate::service::service_name::ServiceName;
+use crate::service::static_config::message_type_details::TypeDetail;
+use crate::service::{self, dynamic_config, static_config};
+
+use super::publish_subscribe;
+
+/// The factory for
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based [`Service`](crate::service::Service)s that carry multiple payload types. It can
+/// create [`MultiPublisher`]s and [`MultiSubscriber`]s.
+#[derive(Debug)]
+pub struct PortFactory<Service: service::Service> {
+    factory: publish_subscribe::PortFactory<Service, [CustomPayloadMarker], TypeTag>,
+    types: Arc<Vec<TypeDetail>>,
+}
+
+impl<Service: service::Service> crate::service::port_factory::PortFactory for PortFactory<Service> {
+    type Service = Service;
+    type StaticConfig = static_config::publish_subscribe::StaticConfig;
+    type DynamicConfig = dynamic_config::publish_subscribe::DynamicConfig;
+
+    fn name(&self) -> &ServiceName {
+        self.factory.name()
+    }
+
+    fn service_id(&self) -> &ServiceId {
+        self.factory.service_id()
+    }
+
+    fn attributes(&self) -> &AttributeSet {
+        self.factory.attributes()
+    }
+
+    fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
+        self.factory.static_config()
+    }
+
+    fn dynamic_config(&self) -> &dynamic_config::publish_subscribe::DynamicConfig {
+        self.factory.dynamic_config()
+    }
+
+    fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
+        &self,
+        callback: F,
+    ) -> Result<(), NodeListFailure> {
+        self.factory.nodes(callback)
+    }
+
+    fn reap_dead_ports(&self) -> usize {
+        self.factory.reap_dead_ports()
+    }
+
+    fn storage_generation(&self) -> u64 {
+        self.factory.storage_generation()
+    }
+}
+
+impl<Service: service::Service> PortFactory<Service> {
+    pub(crate) fn new(
+        factory: publish_subscribe::PortFactory<Service, [CustomPayloadMarker], TypeTag>,
+        types: Arc<Vec<TypeDetail>>,
+    ) -> Self {
+        Self { factory, types }
+    }
+
+    /// Returns the [`TypeDetail`]s of all registered types. The position of a type is its
+    /// type tag.
+    pub fn registered_types(&self) -> &[TypeDetail] {
+        &self.types
+    }
+
+    /// See [`publish_subscribe::PortFactory::statistics()`].
+    pub fn statistics(&self) -> Statistics {
+        self.factory.statistics()
+    }
+
+    /// Creates a new [`MultiPublisher`] with the default settings of the
+    /// [`Service`](crate::service::Service).
+    pub fn publisher(&self) -> Result<MultiPublisher<Service>, PublisherCreateError> {
+        Ok(MultiPublisher::new(
+            self.factory.publisher_builder().create()?,
+            self.types.clone(),
+        ))
+    }
+
+    /// Creates a new [`MultiSubscriber`] with the default settings of the
+    /// [`Service`](crate::service::Service).
+    pub fn subscriber(&self) -> Result<MultiSubscriber<Service>, SubscriberCreateError> {
+        Ok(MultiSubscriber::new(
+            self.factory.subscriber_builder().create()?,
+            self.types.clone(),
+        ))
+    }
+}
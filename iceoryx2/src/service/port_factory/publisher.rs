@@ -57,6 +57,7 @@
 use core::fmt::Debug;
 
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::shm_allocator::AllocationStrategy;
 use serde::{de::Visitor, Deserialize, Serialize};
 
@@ -68,7 +69,7 @@ use crate::{
         publisher::PublisherCreateError,
         DegrationAction, DegrationCallback,
     },
-    service,
+    service::{self, dynamic_config::publish_subscribe::PortNodeName},
 };
 
 /// Defines the strategy the [`Publisher`] shall pursue in
@@ -126,6 +127,28 @@ impl<'de> Deserialize<'de> for UnableToDeliverStrategy {
     }
 }
 
+/// Defines how the [`Publisher`] prepares the payload of a loaned sample before it is handed
+/// out to the user. Samples are reused, without initialization the payload contains the data
+/// of a previously sent sample.
+#[derive(Debug, Default, Eq, Hash, PartialEq, Clone, Copy)]
+pub enum LoanInitPolicy {
+    /// The payload of every loan is filled with zeros. [`Publisher::loan()`] and
+    /// [`Publisher::loan_slice()`] write [`Default::default()`] afterwards, therefore also
+    /// padding bytes are zeroed.
+    Zeroed,
+    /// [`Publisher::loan()`] and [`Publisher::loan_slice()`] write [`Default::default()`],
+    /// the payload of [`Publisher::loan_uninit()`] and [`Publisher::loan_slice_uninit()`] is
+    /// filled with zeros since the payload type does not provide a default value there. This is
+    /// the default.
+    #[default]
+    Default,
+    /// [`Publisher::loan()`] and [`Publisher::loan_slice()`] write [`Default::default()`],
+    /// the payload of [`Publisher::loan_uninit()`] and [`Publisher::loan_slice_uninit()`] is
+    /// handed out uninitialized. It is the fastest option for large payloads that are
+    /// completely overwritten by the user.
+    Uninit,
+}
+
 #[derive(Debug)]
 pub(crate) struct LocalPublisherConfig {
     pub(crate) max_loaned_samples: usize,
@@ -133,6 +156,9 @@ pub(crate) struct LocalPublisherConfig {
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
     pub(crate) initial_max_slice_len: usize,
     pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) node_name: PortNodeName,
+    pub(crate) loan_init_policy: LoanInitPolicy,
+    pub(crate) enable_send_timestamp: bool,
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
@@ -157,6 +183,9 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
             config: LocalPublisherConfig {
                 allocation_strategy: AllocationStrategy::Static,
                 degration_callback: None,
+                node_name: PortNodeName::new(),
+                loan_init_policy: LoanInitPolicy::default(),
+                enable_send_timestamp: false,
                 initial_max_slice_len: 1,
                 max_loaned_samples: factory
                     .service
@@ -187,12 +216,42 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
         self
     }
 
+    /// Tags the [`Publisher`] with a human-readable node name that is stored in the
+    /// [`DynamicConfig`](crate::service::dynamic_config::publish_subscribe::DynamicConfig)
+    /// of the [`Service`](crate::service::Service) alongside its [`UniquePublisherId`].
+    /// It is used for introspection only and does not change the identity of the
+    /// [`Publisher`]. Names longer than
+    /// [`MAX_PORT_NODE_NAME_LENGTH`](crate::service::dynamic_config::publish_subscribe::MAX_PORT_NODE_NAME_LENGTH)
+    /// are truncated.
+    pub fn node_name(mut self, value: &str) -> Self {
+        self.config.node_name = PortNodeName::from_bytes_truncated(value.as_bytes());
+        self
+    }
+
     /// Sets the [`UnableToDeliverStrategy`].
     pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
         self.config.unable_to_deliver_strategy = value;
         self
     }
 
+    /// Sets the [`LoanInitPolicy`] that defines how the payload of a loaned sample is
+    /// initialized. By default, [`LoanInitPolicy::Default`] is used.
+    pub fn loan_init_policy(mut self, value: LoanInitPolicy) -> Self {
+        self.config.loan_init_policy = value;
+        self
+    }
+
+    /// Records the point in time when a sample is sent in its header, see
+    /// [`Header::send_timestamp()`](crate::service::header::publish_subscribe::Header::send_timestamp()).
+    /// The sample header of the [`Service`](crate::service::Service) must have room for it, see
+    /// [`StaticConfig::has_send_timestamp()`](crate::service::static_config::publish_subscribe::StaticConfig::has_send_timestamp()),
+    /// otherwise [`PortFactoryPublisher::create()`] fails with
+    /// [`PublisherCreateError::SendTimestampNotSupported`].
+    pub fn enable_send_timestamp(mut self) -> Self {
+        self.config.enable_send_timestamp = true;
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Publisher`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -215,11 +274,47 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
         self
     }
 
+    fn verify_service_compatibility(&self, origin: &str) -> Result<(), PublisherCreateError> {
+        if self.factory.has_outdated_payload_schema {
+            fail!(from origin, with PublisherCreateError::OutdatedPayloadSchema,
+                "Failed to create new Publisher port since the service was created with a newer schema of the payload.");
+        }
+
+        if self.config.enable_send_timestamp
+            && !self
+                .factory
+                .service
+                .__internal_state()
+                .static_config
+                .publish_subscribe()
+                .has_send_timestamp()
+        {
+            fail!(from origin, with PublisherCreateError::SendTimestampNotSupported,
+                "Failed to create new Publisher port since the service was created without the send timestamp in the sample header.");
+        }
+
+        Ok(())
+    }
+
     /// Creates a new [`Publisher`] or returns a [`PublisherCreateError`] on failure.
     pub fn create(self) -> Result<Publisher<Service, Payload, UserHeader>, PublisherCreateError> {
         let origin = format!("{:?}", self);
+        self.verify_service_compatibility(&origin)?;
+        Ok(
+            fail!(from origin, when Publisher::new(UniquePublisherId::new(), &self.factory.service, self.factory.service.__internal_state().static_config.publish_subscribe(), self.config),
+                "Failed to create new Publisher port."),
+        )
+    }
+
+    #[doc(hidden)]
+    pub unsafe fn __internal_create_with_custom_port_id(
+        self,
+        port_id: UniqueSystemId,
+    ) -> Result<Publisher<Service, Payload, UserHeader>, PublisherCreateError> {
+        let origin = format!("{:?}", self);
+        self.verify_service_compatibility(&origin)?;
         Ok(
-            fail!(from origin, when Publisher::new(&self.factory.service, self.factory.service.__internal_state().static_config.publish_subscribe(), self.config),
+            fail!(from origin, when Publisher::new(UniquePublisherId(port_id), &self.factory.service, self.factory.service.__internal_state().static_config.publish_subscribe(), self.config),
                 "Failed to create new Publisher port."),
         )
     }
@@ -36,11 +36,18 @@
 //! println!("max nodes:                        {:?}", req_res.static_config().max_nodes());
 //! println!("request safe overflow:            {:?}", req_res.static_config().has_safe_overflow_for_requests());
 //! println!("response safe overflow:           {:?}", req_res.static_config().has_safe_overflow_for_responses());
+//! println!("number of active clients:         {:?}", req_res.dynamic_config().number_of_clients());
+//! println!("number of active servers:         {:?}", req_res.dynamic_config().number_of_servers());
+//!
+//! let client = req_res.client_builder().create()?;
+//! let server = req_res.server_builder().create()?;
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+use core::{fmt::Debug, marker::PhantomData};
+
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
@@ -48,11 +55,12 @@ use crate::{
     node::NodeListFailure,
     prelude::AttributeSet,
     service::{
-        self, dynamic_config, service_id::ServiceId, service_name::ServiceName, static_config,
+        self, dynamic_config, internal::reap_dead_ports, service_id::ServiceId,
+        service_name::ServiceName, static_config,
     },
 };
 
-use super::nodes;
+use super::{client::PortFactoryClient, nodes, server::PortFactoryServer};
 
 /// The factory for
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse).
@@ -60,14 +68,51 @@ use super::nodes;
 /// [`crate::port::client::Client`]
 /// or [`crate::port::server::Server`] ports.
 #[derive(Debug)]
-pub struct PortFactory<Service: service::Service> {
+pub struct PortFactory<
+    Service: service::Service,
+    RequestPayload: Debug,
+    RequestUserHeader: Debug,
+    ResponsePayload: Debug,
+    ResponseUserHeader: Debug,
+> {
     pub(crate) service: Service,
+    _request_payload: PhantomData<RequestPayload>,
+    _request_user_header: PhantomData<RequestUserHeader>,
+    _response_payload: PhantomData<ResponsePayload>,
+    _response_user_header: PhantomData<ResponseUserHeader>,
+}
+
+unsafe impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Send
+    for PortFactory<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
 }
 
-unsafe impl<Service: service::Service> Send for PortFactory<Service> {}
-unsafe impl<Service: service::Service> Sync for PortFactory<Service> {}
+unsafe impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > Sync
+    for PortFactory<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
+}
 
-impl<Service: service::Service> crate::service::port_factory::PortFactory for PortFactory<Service> {
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > crate::service::port_factory::PortFactory
+    for PortFactory<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
     type Service = Service;
     type StaticConfig = static_config::request_response::StaticConfig;
     type DynamicConfig = dynamic_config::request_response::DynamicConfig;
@@ -109,10 +154,103 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
             callback,
         )
     }
+
+    fn reap_dead_ports(&self) -> usize {
+        reap_dead_ports::<Service>(
+            self.service.__internal_state().dynamic_storage.get(),
+            self.service.__internal_state().shared_node.config(),
+            self.service.__internal_state().directory.as_ref(),
+        )
+    }
+
+    fn storage_generation(&self) -> u64 {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .generation()
+    }
 }
 
-impl<Service: service::Service> PortFactory<Service> {
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestUserHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseUserHeader: Debug,
+    > PortFactory<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>
+{
     pub(crate) fn new(service: Service) -> Self {
-        Self { service }
+        Self {
+            service,
+            _request_payload: PhantomData,
+            _request_user_header: PhantomData,
+            _response_payload: PhantomData,
+            _response_user_header: PhantomData,
+        }
+    }
+
+    /// Returns a [`PortFactoryClient`] to create a new
+    /// [`crate::port::client::Client`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let req_res = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .request_response::<u64, u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let client = req_res.client_builder().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn client_builder(
+        &self,
+    ) -> PortFactoryClient<
+        '_,
+        Service,
+        RequestPayload,
+        RequestUserHeader,
+        ResponsePayload,
+        ResponseUserHeader,
+    > {
+        PortFactoryClient::new(self)
+    }
+
+    /// Returns a [`PortFactoryServer`] to create a new
+    /// [`crate::port::server::Server`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let req_res = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .request_response::<u64, u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let server = req_res.server_builder().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn server_builder(
+        &self,
+    ) -> PortFactoryServer<
+        '_,
+        Service,
+        RequestPayload,
+        RequestUserHeader,
+        ResponsePayload,
+        ResponseUserHeader,
+    > {
+        PortFactoryServer::new(self)
     }
 }
@@ -30,16 +30,19 @@
 //! ```
 
 use core::fmt::Debug;
+use core::time::Duration;
 
 use iceoryx2_bb_log::fail;
 
 use crate::{
     port::{
         port_identifiers::{UniquePublisherId, UniqueSubscriberId},
-        subscriber::{Subscriber, SubscriberCreateError},
+        subscriber::{DeadLetterReason, DeadLetterSink, Subscriber, SubscriberCreateError},
         DegrationAction, DegrationCallback,
     },
-    service,
+    service::{
+        self, dynamic_config::publish_subscribe::PortNodeName, header::publish_subscribe::Header,
+    },
 };
 
 use super::publish_subscribe::PortFactory;
@@ -47,7 +50,15 @@ use super::publish_subscribe::PortFactory;
 #[derive(Debug)]
 pub(crate) struct SubscriberConfig {
     pub(crate) buffer_size: Option<usize>,
+    pub(crate) max_borrowed_samples: Option<usize>,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
+    pub(crate) dead_letter_sink: Option<DeadLetterSink<'static>>,
+    pub(crate) node_name: PortNodeName,
+    pub(crate) require_buffer_for_history: bool,
+    pub(crate) history_request: Option<usize>,
+    pub(crate) resume_from: Vec<(UniquePublisherId, u64)>,
+    pub(crate) history_max_age: Option<Duration>,
+    pub(crate) deadline: Option<Duration>,
 }
 
 /// Factory to create a new [`Subscriber`] port/endpoint for
@@ -71,7 +82,15 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
         Self {
             config: SubscriberConfig {
                 buffer_size: None,
+                max_borrowed_samples: None,
                 degration_callback: None,
+                dead_letter_sink: None,
+                node_name: PortNodeName::new(),
+                require_buffer_for_history: false,
+                history_request: None,
+                resume_from: Vec::new(),
+                history_max_age: None,
+                deadline: None,
             },
             factory,
         }
@@ -83,6 +102,99 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
         self
     }
 
+    /// Defines how many [`Sample`](crate::sample::Sample)s the [`Subscriber`] can borrow at
+    /// most from every connected [`Publisher`](crate::port::publisher::Publisher). It must not
+    /// exceed the
+    /// [`StaticConfig::subscriber_max_borrowed_samples()`](crate::service::static_config::publish_subscribe::StaticConfig::subscriber_max_borrowed_samples())
+    /// of the [`Service`](crate::service::Service), otherwise
+    /// [`PortFactorySubscriber::create()`] fails with
+    /// [`SubscriberCreateError::MaxBorrowedSamplesExceedsMaxSupportedBorrowedSamplesOfService`].
+    /// Smallest possible value is `1`.
+    pub fn max_borrowed_samples(mut self, value: usize) -> Self {
+        self.config.max_borrowed_samples = Some(value.max(1));
+        self
+    }
+
+    /// Defines what happens when the buffer size of the [`Subscriber`] is smaller than the
+    /// history size of the [`Service`](crate::service::Service). By default, a warning is
+    /// logged and the [`Subscriber`] receives only the most recent part of the history. When
+    /// set to `true`, [`PortFactorySubscriber::create()`] fails with
+    /// [`SubscriberCreateError::BufferTooSmallForHistory`] instead.
+    pub fn require_buffer_for_history(mut self, value: bool) -> Self {
+        self.config.require_buffer_for_history = value;
+        self
+    }
+
+    /// Defines how many samples of the history the [`Subscriber`] wants to receive from every
+    /// [`Publisher`](crate::port::publisher::Publisher) when it connects, so that a
+    /// late-joining [`Subscriber`] can request fewer samples than the
+    /// [`StaticConfig::history_size()`](crate::service::static_config::publish_subscribe::StaticConfig::history_size())
+    /// of the [`Service`](crate::service::Service). A larger value is capped to the history
+    /// size of the [`Service`](crate::service::Service), the granted amount is returned by
+    /// [`Subscriber::granted_history()`]. By default, the whole history is requested.
+    pub fn request_history(mut self, count: usize) -> Self {
+        self.config.history_request = Some(count);
+        self
+    }
+
+    /// Suppresses every sample of the [`Publisher`](crate::port::publisher::Publisher) with the
+    /// given [`UniquePublisherId`], the history included, whose
+    /// [`Header::sequence_number()`](crate::service::header::publish_subscribe::Header::sequence_number())
+    /// is less than or equal to `sequence`. The suppressed samples are released without being
+    /// delivered. It allows a restarted consumer to skip the samples it already processed by
+    /// persisting the [`Header::publisher_id()`] and the sequence number of the last processed
+    /// sample and resuming from them. Can be called once for every
+    /// [`Publisher`](crate::port::publisher::Publisher), a repeated call for the same
+    /// [`UniquePublisherId`] replaces the previous sequence. A recreated
+    /// [`Publisher`](crate::port::publisher::Publisher) has a new [`UniquePublisherId`] and
+    /// starts again with the sequence number `1`, so none of its samples is suppressed.
+    pub fn resume_from(mut self, publisher_id: UniquePublisherId, sequence: u64) -> Self {
+        match self
+            .config
+            .resume_from
+            .iter_mut()
+            .find(|(id, _)| *id == publisher_id)
+        {
+            Some(entry) => entry.1 = sequence,
+            None => self.config.resume_from.push((publisher_id, sequence)),
+        }
+        self
+    }
+
+    /// Restricts the history that is delivered to the [`Subscriber`] when it connects to a
+    /// [`Publisher`](crate::port::publisher::Publisher) to the samples that were sent not
+    /// longer than `value` ago. The age is measured by the
+    /// [`Publisher`](crate::port::publisher::Publisher) with the monotonic clock, see
+    /// [`Time::now()`](iceoryx2_bb_posix::clock::Time::now()), so that it is not affected when
+    /// the system time is adjusted. Older samples of the history are skipped, so that a late-joining [`Subscriber`] does not start with stale data. The
+    /// samples that are sent after the [`Subscriber`] is connected are not affected.
+    pub fn history_max_age(mut self, value: Duration) -> Self {
+        self.config.history_max_age = Some(value);
+        self
+    }
+
+    /// Defines the maximum time between two [`Sample`](crate::sample::Sample)s the
+    /// [`Subscriber`] expects from its [`Publisher`](crate::port::publisher::Publisher)s. The
+    /// time is measured with the monotonic clock between the arrivals of the samples at the
+    /// [`Subscriber`]. The deadline is evaluated lazily, without a background thread, when
+    /// samples are received and when [`Subscriber::deadline_missed()`] is called.
+    pub fn deadline(mut self, value: Duration) -> Self {
+        self.config.deadline = Some(value);
+        self
+    }
+
+    /// Tags the [`Subscriber`] with a human-readable node name that is stored in the
+    /// [`DynamicConfig`](crate::service::dynamic_config::publish_subscribe::DynamicConfig)
+    /// of the [`Service`](crate::service::Service) alongside its [`UniqueSubscriberId`].
+    /// It is used for introspection only and does not change the identity of the
+    /// [`Subscriber`]. Names longer than
+    /// [`MAX_PORT_NODE_NAME_LENGTH`](crate::service::dynamic_config::publish_subscribe::MAX_PORT_NODE_NAME_LENGTH)
+    /// are truncated.
+    pub fn node_name(mut self, value: &str) -> Self {
+        self.config.node_name = PortNodeName::from_bytes_truncated(value.as_bytes());
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Subscriber`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -105,6 +217,39 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
         self
     }
 
+    /// Sets a sink that is called with the [`Header`] and the [`DeadLetterReason`] of every
+    /// sample the [`Subscriber`] discards without delivering it, so that data loss can be
+    /// audited without instrumenting every drop site. The sink is called synchronously in the
+    /// receive call that discards the sample. Without a sink, the discarded samples are
+    /// released silently.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// # let publisher = pubsub.publisher_builder().create()?;
+    /// let subscriber = pubsub.subscriber_builder()
+    ///     .resume_from(publisher.id(), 10)
+    ///     .dead_letter(|header, reason| {
+    ///         println!("discarded sample {} due to {:?}", header.sequence_number(), reason)
+    ///     })
+    ///     .create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dead_letter<F: Fn(Header, DeadLetterReason) + 'static>(mut self, sink: F) -> Self {
+        self.config.dead_letter_sink = Some(DeadLetterSink::new(sink));
+        self
+    }
+
     /// Creates a new [`Subscriber`] or returns a [`SubscriberCreateError`] on failure.
     pub fn create(
         self,
@@ -0,0 +1,84 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! // the handle can be serialized and passed to a child process
+//! let handle = service.service_handle();
+//!
+//! // the child process attaches to the service without looking it up by name
+//! let child_node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = child_node.service_builder(handle.name())
+//!     .publish_subscribe::<u64>()
+//!     .attach(&handle)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use super::service_id::ServiceId;
+use super::service_name::ServiceName;
+use super::static_config::StaticConfig;
+
+/// A serializable reference to an existing [`Service`](crate::service::Service). It contains
+/// the [`StaticConfig`] and the storage generation of the [`Service`](crate::service::Service) and
+/// can be passed to other processes, for instance to forked children, that attach directly to the
+/// [`Service`](crate::service::Service) with
+/// [`publish_subscribe::Builder::attach()`](crate::service::builder::publish_subscribe::Builder::attach())
+/// without looking it up by name. The [`StaticConfig`] of the [`ServiceHandle`] must be equal
+/// to the stored static configuration of the [`Service`](crate::service::Service), otherwise
+/// the attach fails.
+///
+/// The [`ServiceHandle`] becomes stale when the [`Service`](crate::service::Service) is
+/// destroyed. Attaching via a stale [`ServiceHandle`] fails, also when the
+/// [`Service`](crate::service::Service) was recreated under the same name in the meantime.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ServiceHandle {
+    pub(crate) static_config: StaticConfig,
+    pub(crate) storage_generation: u64,
+}
+
+impl ServiceHandle {
+    pub(crate) fn new(static_config: StaticConfig, storage_generation: u64) -> Self {
+        Self {
+            static_config,
+            storage_generation,
+        }
+    }
+
+    /// Returns the [`ServiceName`] of the referenced [`Service`](crate::service::Service).
+    pub fn name(&self) -> &ServiceName {
+        self.static_config.name()
+    }
+
+    /// Returns the [`ServiceId`] of the referenced [`Service`](crate::service::Service).
+    pub fn service_id(&self) -> &ServiceId {
+        self.static_config.service_id()
+    }
+
+    /// Returns the storage generation of the referenced
+    /// [`Service`](crate::service::Service), see
+    /// [`PortFactory::storage_generation()`](crate::service::port_factory::PortFactory::storage_generation()).
+    pub fn storage_generation(&self) -> u64 {
+        self.storage_generation
+    }
+}
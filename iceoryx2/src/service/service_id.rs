@@ -13,6 +13,7 @@
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_system_types::file_name::RestrictedFileName;
+use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_cal::hash::Hash;
 use serde::{Deserialize, Serialize};
 
@@ -30,9 +31,23 @@ impl ServiceId {
         messaging_pattern: MessagingPattern,
     ) -> Self {
         let pattern_and_service = (messaging_pattern as u32).to_string() + service_name.as_str();
-        let value = *Hasher::new(pattern_and_service.as_bytes())
-            .value()
-            .as_base64url();
+        Self::from_hash::<Hasher>(pattern_and_service.as_bytes())
+    }
+
+    pub(crate) fn new_in_directory<Hasher: Hash>(
+        service_name: &ServiceName,
+        messaging_pattern: MessagingPattern,
+        directory: &Path,
+    ) -> Self {
+        let mut pattern_directory_and_service = (messaging_pattern as u32).to_string().into_bytes();
+        pattern_directory_and_service.extend_from_slice(directory.as_bytes());
+        pattern_directory_and_service.push(b'/');
+        pattern_directory_and_service.extend_from_slice(service_name.as_str().as_bytes());
+        Self::from_hash::<Hasher>(&pattern_directory_and_service)
+    }
+
+    fn from_hash<Hasher: Hash>(bytes: &[u8]) -> Self {
+        let value = *Hasher::new(bytes).value().as_base64url();
 
         Self(fatal_panic!(from "ServiceId::new()",
                    when RestrictedFileName::new(&value),
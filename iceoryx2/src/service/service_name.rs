@@ -21,14 +21,78 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Normalization
+//!
+//! When [`crate::config::Service::case_insensitive_names`] is set, the
+//! [`ServiceName`] is converted to lowercase before it is mapped to the resources of the
+//! [`Service`](crate::service::Service). The policy is applied when a service is created,
+//! opened, inspected or destroyed and the [`Service`](crate::service::Service) reports the
+//! canonical name. [`ServiceName::new()`] always keeps the provided value.
+//!
+//! # Interning
+//!
+//! [`ServiceName::intern()`] returns a [`ServiceName`] that shares its storage with every
+//! other [`ServiceName`] interned with the same name.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let name_a = ServiceName::intern("My/Funk/ServiceName")?;
+//! let name_b = ServiceName::intern("My/Funk/ServiceName")?;
+//!
+//! assert_eq!(name_a.as_str().as_ptr(), name_b.as_str().as_ptr());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # iceoryx1 Service Descriptors
+//!
+//! iceoryx1 addresses a topic with the triple of service, instance and event.
+//! [`ServiceName::from_iox1()`] maps it to the [`ServiceName`] `"service/instance/event"` and
+//! [`ServiceName::to_iox1_triple()`] splits such a [`ServiceName`] again into its parts. Every
+//! part must not be empty, must not contain the separator `/` and must not be longer than
+//! [`IOX1_MAX_ID_STRING_LENGTH`](crate::service::service_name::IOX1_MAX_ID_STRING_LENGTH)
+//! bytes, like the `IdString_t` of iceoryx1.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::from_iox1("Radar", "FrontLeft", "Objects")?;
+//!
+//! assert_eq!(service_name.as_str(), "Radar/FrontLeft/Objects");
+//! assert_eq!(service_name.to_iox1_triple(), Some(("Radar", "FrontLeft", "Objects")));
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate alloc;
+use alloc::collections::BTreeSet;
+use alloc::sync::Arc;
+use std::sync::Mutex;
 
+use crate::config;
 use iceoryx2_bb_container::semantic_string::SemanticStringError;
 use serde::{de::Visitor, Deserialize, Serialize};
 
+static INTERNED_NAMES: Mutex<BTreeSet<Arc<str>>> = Mutex::new(BTreeSet::new());
+
+/// The maximum length in bytes of every part of an iceoryx1 service descriptor, see
+/// [`ServiceName::from_iox1()`].
+pub const IOX1_MAX_ID_STRING_LENGTH: usize = 100;
+
+const IOX1_SEPARATOR: char = '/';
+
 /// The name of a [`Service`](crate::service::Service).
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///
+/// [`PartialEq`], [`Eq`], [`Ord`] and [`Hash`] are defined on
+/// [`ServiceName::canonical_bytes()`], therefore two equal [`ServiceName`]s always have the
+/// same hash, independent of how they were created.
+#[derive(Debug, Clone)]
 pub struct ServiceName {
-    value: String,
+    value: Arc<str>,
 }
 
 impl ServiceName {
@@ -41,10 +105,133 @@ impl ServiceName {
         Ok(Self { value: name.into() })
     }
 
+    /// Creates a new [`ServiceName`] that shares its storage with all other [`ServiceName`]s
+    /// that were interned with the same name, so that workloads that create the same names
+    /// repeatedly, like discovery or routing tables, allocate every name only once. Cloning a
+    /// [`ServiceName`] never allocates, independent of how it was created.
+    ///
+    /// The storage of an interned name is never released, therefore it shall only be used for
+    /// a bounded set of names. The name is not allowed to be empty.
+    pub fn intern(name: &str) -> Result<Self, SemanticStringError> {
+        if name.is_empty() {
+            return Err(SemanticStringError::InvalidContent);
+        }
+
+        let mut interned_names = match INTERNED_NAMES.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let value = match interned_names.get(name) {
+            Some(value) => value.clone(),
+            None => {
+                let value: Arc<str> = name.into();
+                interned_names.insert(value.clone());
+                value
+            }
+        };
+
+        Ok(Self { value })
+    }
+
+    /// Creates a new [`ServiceName`] from the service, instance and event of an iceoryx1
+    /// service descriptor by joining them with `/`. Returns
+    /// [`SemanticStringError::InvalidContent`] when a part is empty or contains a `/` and
+    /// [`SemanticStringError::ExceedsMaximumLength`] when a part is longer than
+    /// [`IOX1_MAX_ID_STRING_LENGTH`].
+    pub fn from_iox1(
+        service: &str,
+        instance: &str,
+        event: &str,
+    ) -> Result<Self, SemanticStringError> {
+        for part in [service, instance, event] {
+            Self::verify_iox1_part(part)?;
+        }
+
+        Self::new(&format!(
+            "{service}{IOX1_SEPARATOR}{instance}{IOX1_SEPARATOR}{event}"
+        ))
+    }
+
+    /// Returns the service, instance and event of the iceoryx1 service descriptor the
+    /// [`ServiceName`] corresponds to. Returns [`None`] when the [`ServiceName`] does not
+    /// consist of exactly three valid parts, see [`ServiceName::from_iox1()`].
+    pub fn to_iox1_triple(&self) -> Option<(&str, &str, &str)> {
+        let mut parts = self.value.split(IOX1_SEPARATOR);
+        let triple = (parts.next()?, parts.next()?, parts.next()?);
+        if parts.next().is_some() {
+            return None;
+        }
+
+        for part in [triple.0, triple.1, triple.2] {
+            Self::verify_iox1_part(part).ok()?;
+        }
+
+        Some(triple)
+    }
+
+    fn verify_iox1_part(part: &str) -> Result<(), SemanticStringError> {
+        if part.is_empty() || part.contains(IOX1_SEPARATOR) {
+            return Err(SemanticStringError::InvalidContent);
+        }
+
+        if IOX1_MAX_ID_STRING_LENGTH < part.len() {
+            return Err(SemanticStringError::ExceedsMaximumLength);
+        }
+
+        Ok(())
+    }
+
     /// Returns a str reference to the [`ServiceName`]
     pub fn as_str(&self) -> &str {
         &self.value
     }
+
+    /// Returns the canonical byte representation of the [`ServiceName`], the UTF-8 encoding of
+    /// the name exactly as it was provided. It does not depend on whether the name was created
+    /// with [`ServiceName::new()`] or [`ServiceName::intern()`] and can be used as a stable key
+    /// for persisted or hashed state. The normalization of
+    /// [`crate::config::Service::case_insensitive_names`] is not applied.
+    pub fn canonical_bytes(&self) -> &[u8] {
+        self.value.as_bytes()
+    }
+
+    /// Returns the [`ServiceName`] in the canonical form that is defined by the normalization
+    /// policy of the provided [`config::Service`] settings.
+    pub(crate) fn normalize(&self, config: &config::Service) -> Self {
+        match config.case_insensitive_names {
+            true => Self {
+                value: self.value.to_lowercase().into(),
+            },
+            false => self.clone(),
+        }
+    }
+}
+
+impl PartialEq for ServiceName {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical_bytes() == other.canonical_bytes()
+    }
+}
+
+impl Eq for ServiceName {}
+
+impl PartialOrd for ServiceName {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ServiceName {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.canonical_bytes().cmp(other.canonical_bytes())
+    }
+}
+
+impl core::hash::Hash for ServiceName {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.canonical_bytes().hash(state)
+    }
 }
 
 impl core::fmt::Display for ServiceName {
@@ -53,6 +53,9 @@ pub struct TypeDetail {
     /// The ABI-required minimum alignment of the underlying type calculated by [`core::mem::align_of`].
     /// It may be set by users with a larger alignment, e.g. the memory provided by allocator used by SIMD.
     pub alignment: usize,
+    /// The [`TypeSchema`] of the type when schema evolution is enabled, see [`SchemaEvolution`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Box<TypeSchema>>,
 }
 
 impl TypeDetail {
@@ -63,10 +66,127 @@ impl TypeDetail {
             type_name: core::any::type_name::<T>().to_string(),
             size: core::mem::size_of::<T>(),
             alignment: core::mem::align_of::<T>(),
+            schema: None,
+        }
+    }
+
+    pub(crate) fn from_schema<T: SchemaEvolution>() -> Self {
+        Self {
+            type_name: T::TYPE_NAME.to_string(),
+            schema: Some(Box::new(TypeSchema::from::<T>())),
+            ..Self::__internal_new::<T>(TypeVariant::FixedSize)
         }
     }
 }
 
+/// Describes the location of a single field inside a type that supports [`SchemaEvolution`].
+#[derive(Default, Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct SchemaField {
+    /// The offset of the field in bytes from the start of the type, e.g. the distance between
+    /// the field address acquired with [`core::ptr::addr_of!`] and the address of the type.
+    pub offset: usize,
+    /// The size of the field calculated by [`core::mem::size_of`].
+    pub size: usize,
+}
+
+impl SchemaField {
+    /// Creates a new [`SchemaField`].
+    pub fn new(offset: usize, size: usize) -> Self {
+        Self { offset, size }
+    }
+}
+
+/// The versioned layout of a type that supports [`SchemaEvolution`].
+#[derive(Default, Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct TypeSchema {
+    /// The version of the schema, see [`SchemaEvolution::VERSION`].
+    pub version: u32,
+    /// The [`SchemaField`]s of the type in the order of their declaration.
+    pub fields: Vec<SchemaField>,
+}
+
+impl TypeSchema {
+    fn from<T: SchemaEvolution>() -> Self {
+        Self {
+            version: T::VERSION,
+            fields: T::schema_fields(),
+        }
+    }
+
+    /// Returns true when the schema is the same or an older version of `rhs` and all of its
+    /// fields are a prefix of the fields of `rhs`.
+    pub(crate) fn is_prefix_of(&self, rhs: &Self) -> bool {
+        self.version <= rhs.version
+            && self.fields.len() <= rhs.fields.len()
+            && self
+                .fields
+                .iter()
+                .zip(rhs.fields.iter())
+                .all(|(l, r)| l == r)
+    }
+}
+
+/// Marks a payload type whose layout evolves over time by appending new fields at the end.
+/// When schema evolution is enabled with
+/// [`Builder::enable_schema_evolution()`](crate::service::builder::publish_subscribe::Builder::enable_schema_evolution()),
+/// a [`crate::service::Service`] created with a newer version of the type can be opened with
+/// any older version whose fields are a prefix of the newer fields. The older version can
+/// then read the common prefix of the payload. Therefore, the
+/// [`crate::service::Service`] must always be created with the newest version.
+///
+/// # Safety
+///
+///  * the type must be [`#[repr(C)]`](https://doc.rust-lang.org/reference/type-layout.html#reprc)
+///  * new versions must only append fields at the end and must increase [`SchemaEvolution::VERSION`]
+///  * [`SchemaEvolution::schema_fields()`] must describe every field of the type in
+///    declaration order
+///  * [`SchemaEvolution::TYPE_NAME`] must be the same for all versions of the type and
+///    unique among all other types
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::service::static_config::message_type_details::{SchemaEvolution, SchemaField};
+///
+/// #[derive(Debug)]
+/// #[repr(C)]
+/// struct Pose {
+///     x: f64,
+///     y: f64,
+///     // added in version 2
+///     heading: f32,
+/// }
+///
+/// unsafe impl SchemaEvolution for Pose {
+///     const TYPE_NAME: &'static str = "Pose";
+///     const VERSION: u32 = 2;
+///
+///     fn schema_fields() -> Vec<SchemaField> {
+///         let pose = core::mem::MaybeUninit::<Pose>::uninit();
+///         let base = pose.as_ptr();
+///         // SAFETY: only the addresses of the fields are computed, nothing is read
+///         let offset_of = |field: *const u8| field as usize - base as usize;
+///         unsafe {
+///             vec![
+///                 SchemaField::new(offset_of(core::ptr::addr_of!((*base).x).cast()), core::mem::size_of::<f64>()),
+///                 SchemaField::new(offset_of(core::ptr::addr_of!((*base).y).cast()), core::mem::size_of::<f64>()),
+///                 SchemaField::new(offset_of(core::ptr::addr_of!((*base).heading).cast()), core::mem::size_of::<f32>()),
+///             ]
+///         }
+///     }
+/// }
+/// ```
+pub unsafe trait SchemaEvolution: Sized {
+    /// The name of the type that is used instead of [`core::any::type_name()`] so that
+    /// different versions of the type can be identified as the same type.
+    const TYPE_NAME: &'static str;
+    /// The version of the schema.
+    const VERSION: u32;
+
+    /// Returns the [`SchemaField`]s of the type in declaration order.
+    fn schema_fields() -> Vec<SchemaField>;
+}
+
 /// Contains all type information to the header and payload type.
 #[derive(Default, Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct MessageTypeDetails {
@@ -117,15 +237,41 @@ impl MessageTypeDetails {
     }
 
     pub(crate) fn is_compatible_to(&self, rhs: &Self) -> bool {
+        self.is_compatible_to_impl(rhs, false)
+    }
+
+    /// Like [`MessageTypeDetails::is_compatible_to()`] but the sizes of the user header and
+    /// the payload are also compatible when they differ only in trailing padding, meaning that
+    /// they are equal when rounded up to the larger alignment. A size that exceeds the size of
+    /// `rhs` is never compatible since it would exceed the sample slots of `rhs`.
+    pub(crate) fn is_compatible_up_to_trailing_padding(&self, rhs: &Self) -> bool {
+        self.is_compatible_to_impl(rhs, true)
+    }
+
+    fn is_compatible_to_impl(&self, rhs: &Self, tolerate_trailing_padding: bool) -> bool {
+        let has_compatible_size = |lhs: &TypeDetail, rhs: &TypeDetail| {
+            let alignment = lhs.alignment.max(rhs.alignment);
+            lhs.size == rhs.size
+                || (tolerate_trailing_padding
+                    && lhs.size <= rhs.size
+                    && align(lhs.size, alignment) == align(rhs.size, alignment))
+        };
+
         self.header == rhs.header
             && self.user_header.type_name == rhs.user_header.type_name
             && self.user_header.variant == rhs.user_header.variant
-            && self.user_header.size == rhs.user_header.size
+            && has_compatible_size(&self.user_header, &rhs.user_header)
             && self.user_header.alignment <= rhs.user_header.alignment
             && self.payload.type_name == rhs.payload.type_name
             && self.payload.variant == rhs.payload.variant
-            && self.payload.size == rhs.payload.size
             && self.payload.alignment <= rhs.payload.alignment
+            && match (&self.payload.schema, &rhs.payload.schema) {
+                (None, None) => has_compatible_size(&self.payload, &rhs.payload),
+                (Some(lhs_schema), Some(rhs_schema)) => {
+                    lhs_schema.is_prefix_of(rhs_schema) && self.payload.size <= rhs.payload.size
+                }
+                _ => false,
+            }
     }
 }
 
@@ -155,18 +301,21 @@ mod tests {
                 type_name: "i32".to_string(),
                 size: 4,
                 alignment: 4, // i32 uses 4 bytes, so its aliment is always 4 no matter x32 or x64.
+                schema: None,
             },
             user_header: TypeDetail{
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                schema: None,
             },
             payload: TypeDetail{
                 variant: TypeVariant::FixedSize,
                 type_name: "iceoryx2::service::static_config::message_type_details::tests::test_from::MyPayload".to_string(),
                 size: 16,
                 alignment: ALIGNMENT,
+                schema: None,
             },
         };
         assert_that!(sut, eq expected);
@@ -178,18 +327,21 @@ mod tests {
                 type_name: "i32".to_string(),
                 size: 4,
                 alignment: 4,
+                schema: None,
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "bool".to_string(),
                 size: 1,
                 alignment: 1,
+                schema: None,
             },
             payload: TypeDetail {
                 variant: TypeVariant::Dynamic,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                schema: None,
             },
         };
         assert_that!(sut, eq expected);
@@ -335,18 +487,21 @@ mod tests {
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                schema: None,
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                schema: None,
             },
             payload: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                schema: None,
             },
         };
         // smaller to bigger is allowed.
@@ -370,22 +525,112 @@ mod tests {
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                schema: None,
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                schema: None,
             },
             payload: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                schema: None,
             },
         };
         // bigger to smaller is invalid.
         let sut = right.is_compatible_to(&left);
         assert_that!(sut, eq false);
     }
+
+    #[test]
+    fn test_is_compatible_to_with_schema_requires_prefix_of_newer_schema() {
+        let with_schema = |version: u32, fields: Vec<SchemaField>| {
+            let mut details =
+                MessageTypeDetails::from::<i64, i64, [u32; 4]>(TypeVariant::FixedSize);
+            details.payload.size = fields.iter().map(|f| f.size).sum();
+            details.payload.schema = Some(Box::new(TypeSchema { version, fields }));
+            details
+        };
+
+        let v1 = with_schema(1, vec![SchemaField::new(0, 4), SchemaField::new(4, 4)]);
+        let v2 = with_schema(
+            2,
+            vec![
+                SchemaField::new(0, 4),
+                SchemaField::new(4, 4),
+                SchemaField::new(8, 4),
+            ],
+        );
+        let other = with_schema(2, vec![SchemaField::new(0, 4), SchemaField::new(4, 8)]);
+
+        assert_that!(v1.is_compatible_to(&v1), eq true);
+        assert_that!(v1.is_compatible_to(&v2), eq true);
+        assert_that!(v2.is_compatible_to(&v1), eq false);
+        assert_that!(v1.is_compatible_to(&other), eq false);
+
+        let mut without_schema = v2.clone();
+        without_schema.payload.schema = None;
+        assert_that!(v2.is_compatible_to(&without_schema), eq false);
+        assert_that!(without_schema.is_compatible_to(&v2), eq false);
+    }
+
+    #[test]
+    fn test_is_compatible_up_to_trailing_padding_accepts_only_padding_differences() {
+        #[repr(C)]
+        struct Padded {
+            _a: u64,
+            _b: u32,
+        }
+
+        #[repr(C, packed(4))]
+        struct Packed {
+            _a: u64,
+            _b: u32,
+        }
+
+        #[repr(C)]
+        struct Extended {
+            _a: u64,
+            _b: u32,
+            _c: u64,
+        }
+
+        #[repr(C)]
+        struct Truncated {
+            _a: u32,
+        }
+
+        // the same type compiled with different padding rules has the same type name
+        let details_of = |mut details: MessageTypeDetails| {
+            details.payload.type_name = "Frame".to_string();
+            details
+        };
+        let padded = details_of(MessageTypeDetails::from::<i64, i64, Padded>(
+            TypeVariant::FixedSize,
+        ));
+        let packed = details_of(MessageTypeDetails::from::<i64, i64, Packed>(
+            TypeVariant::FixedSize,
+        ));
+        let extended = details_of(MessageTypeDetails::from::<i64, i64, Extended>(
+            TypeVariant::FixedSize,
+        ));
+        let truncated = details_of(MessageTypeDetails::from::<i64, i64, Truncated>(
+            TypeVariant::FixedSize,
+        ));
+        assert_that!(packed.payload.size, lt padded.payload.size);
+
+        assert_that!(packed.is_compatible_to(&padded), eq false);
+        assert_that!(packed.is_compatible_up_to_trailing_padding(&padded), eq true);
+        assert_that!(padded.is_compatible_up_to_trailing_padding(&padded), eq true);
+        // exceeds the sample slots of the packed type and requires a stricter alignment
+        assert_that!(padded.is_compatible_up_to_trailing_padding(&packed), eq false);
+        assert_that!(extended.is_compatible_up_to_trailing_padding(&padded), eq false);
+        assert_that!(padded.is_compatible_up_to_trailing_padding(&extended), eq false);
+        assert_that!(truncated.is_compatible_up_to_trailing_padding(&padded), eq false);
+    }
 }
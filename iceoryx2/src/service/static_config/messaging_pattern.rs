@@ -60,10 +60,7 @@ impl MessagingPattern {
     ) -> usize {
         match self {
             MessagingPattern::PublishSubscribe(v) => {
-                v.max_subscribers
-                    * (v.subscriber_max_buffer_size + v.subscriber_max_borrowed_samples)
-                    + v.history_size
-                    + publisher_max_loaned_samples
+                v.effective_pool_capacity(publisher_max_loaned_samples)
             }
             _ => 0,
         }
@@ -98,6 +95,7 @@ mod tests {
         let cfg2 = config::Config {
             defaults: new_defaults,
             global: cfg.global.clone(),
+            ..cfg.clone()
         };
 
         // ensure the cfg and cfg2 are not equal
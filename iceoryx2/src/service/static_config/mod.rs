@@ -28,19 +28,166 @@ pub mod request_response;
 
 pub mod messaging_pattern;
 
-use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_cal::hash::Hash;
+use iceoryx2_cal::serialize::json::Json;
+use iceoryx2_cal::serialize::toml::Toml;
+use iceoryx2_cal::serialize::{DeserializeError, Serialize as _, SerializeError};
 use serde::{Deserialize, Serialize};
 
 use crate::config;
+use crate::service::header;
 
+use self::message_type_details::{TypeDetail, TypeVariant};
 use self::messaging_pattern::MessagingPattern;
 
 use super::{attribute::AttributeSet, service_id::ServiceId, service_name::ServiceName};
 
+/// Defines the format in which the [`StaticConfig`] of a [`Service`](crate::service::Service)
+/// is written into its static storage, see
+/// [`config::Service::static_config_format`](crate::config::Service::static_config_format).
+/// The format is detected from the stored content when the [`StaticConfig`] is read, therefore
+/// [`Service`](crate::service::Service)s stored in different formats can be used side by side.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum StaticConfigFormat {
+    /// Human readable TOML. All [`StaticConfig`]s that were stored before the format became
+    /// configurable are stored in this format.
+    #[default]
+    Toml,
+    /// Compact JSON that can be inspected with standard tools like `jq`.
+    Json,
+}
+
+impl StaticConfigFormat {
+    /// Detects the format of a stored [`StaticConfig`]. A JSON encoded config starts with an
+    /// object, a TOML document cannot start with an inline table.
+    pub(crate) fn detect(bytes: &[u8]) -> Self {
+        match bytes.iter().find(|b| !b.is_ascii_whitespace()) {
+            Some(b'{') => StaticConfigFormat::Json,
+            _ => StaticConfigFormat::Toml,
+        }
+    }
+
+    pub(crate) fn serialize(&self, config: &StaticConfig) -> Result<Vec<u8>, SerializeError> {
+        match self {
+            StaticConfigFormat::Toml => Toml::serialize(config),
+            StaticConfigFormat::Json => Json::serialize(config),
+        }
+    }
+
+    pub(crate) fn deserialize(bytes: &[u8]) -> Result<StaticConfig, DeserializeError> {
+        match Self::detect(bytes) {
+            StaticConfigFormat::Toml => Toml::deserialize(bytes),
+            StaticConfigFormat::Json => Json::deserialize(bytes),
+        }
+    }
+}
+
+/// The version of the layout in which the [`StaticConfig`] is written into the static storage.
+/// It is increased whenever the stored layout changes, older static configs can be converted
+/// with [`Service::migrate_storage()`](crate::service::Service::migrate_storage()). All
+/// [`StaticConfig`]s that were stored before the version was recorded have the version `0`.
+pub const STORAGE_FORMAT_VERSION: u32 = 1;
+
+/// A [`StaticConfig`] as it was read from the static storage without assuming the layout of a
+/// specific [`STORAGE_FORMAT_VERSION`].
+#[derive(Debug)]
+pub(crate) struct StoredStaticConfig {
+    value: serde_json::Value,
+    format: StaticConfigFormat,
+}
+
+impl StoredStaticConfig {
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializeError> {
+        let format = StaticConfigFormat::detect(bytes);
+        let value = match format {
+            StaticConfigFormat::Toml => Toml::deserialize(bytes)?,
+            StaticConfigFormat::Json => Json::deserialize(bytes)?,
+        };
+
+        Ok(Self { value, format })
+    }
+
+    pub(crate) fn format(&self) -> StaticConfigFormat {
+        self.format
+    }
+
+    pub(crate) fn storage_format_version(&self) -> u32 {
+        self.value
+            .get("storage_format_version")
+            .and_then(|v| v.as_u64())
+            .map_or(0, |v| v as u32)
+    }
+
+    pub(crate) fn service_name(&self) -> Option<ServiceName> {
+        self.value
+            .get("service_name")
+            .and_then(|v| v.as_str())
+            .and_then(|v| ServiceName::new(v).ok())
+    }
+
+    /// Converts the stored layout into the layout of `to_version`. All settings that did not
+    /// exist when the [`StaticConfig`] was stored are set to their defaults from `config`.
+    pub(crate) fn upgrade(
+        mut self,
+        config: &config::Config,
+        to_version: u32,
+    ) -> Result<StaticConfig, DeserializeError> {
+        let origin = "StoredStaticConfig::upgrade()";
+        let defaults = match self
+            .value
+            .get("messaging_pattern")
+            .and_then(|v| v.get("messaging_pattern"))
+            .and_then(|v| v.as_str())
+        {
+            Some("RequestResponse") => {
+                MessagingPattern::RequestResponse(request_response::StaticConfig::new(config))
+            }
+            Some("PublishSubscribe") => {
+                MessagingPattern::PublishSubscribe(publish_subscribe::StaticConfig::new(config))
+            }
+            Some("Event") => MessagingPattern::Event(event::StaticConfig::new(config)),
+            m => {
+                fail!(from origin, with DeserializeError::InternalError,
+                    "Unable to upgrade the stored static config since it contains the unknown messaging pattern {:?}.", m);
+            }
+        };
+        let defaults = fail!(from origin, when serde_json::to_value(&defaults),
+            with DeserializeError::InternalError,
+            "Unable to upgrade the stored static config since the default settings could not be serialized.");
+
+        if let Some(messaging_pattern) = self.value.get_mut("messaging_pattern") {
+            add_missing_entries(messaging_pattern, &defaults);
+        }
+        if let Some(value) = self.value.as_object_mut() {
+            value.insert("storage_format_version".into(), to_version.into());
+        }
+
+        Ok(fail!(from origin, when serde_json::from_value(self.value),
+            with DeserializeError::InternalError,
+            "Unable to upgrade the stored static config since it does not contain all required settings."))
+    }
+}
+
+fn add_missing_entries(value: &mut serde_json::Value, defaults: &serde_json::Value) {
+    if let (Some(value), Some(defaults)) = (value.as_object_mut(), defaults.as_object()) {
+        for (key, default) in defaults {
+            match value.get_mut(key) {
+                Some(entry) => add_missing_entries(entry, default),
+                None => {
+                    value.insert(key.clone(), default.clone());
+                }
+            }
+        }
+    }
+}
+
 /// Defines a common set of static service configuration details every service shares.
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct StaticConfig {
+    #[serde(default)]
+    storage_format_version: u32,
     service_id: ServiceId,
     service_name: ServiceName,
     pub(crate) attributes: AttributeSet,
@@ -55,6 +202,7 @@ impl StaticConfig {
         let messaging_pattern =
             MessagingPattern::RequestResponse(request_response::StaticConfig::new(config));
         Self {
+            storage_format_version: STORAGE_FORMAT_VERSION,
             service_id: ServiceId::new::<Hasher>(
                 service_name,
                 crate::service::messaging_pattern::MessagingPattern::RequestResponse,
@@ -71,6 +219,7 @@ impl StaticConfig {
     ) -> Self {
         let messaging_pattern = MessagingPattern::Event(event::StaticConfig::new(config));
         Self {
+            storage_format_version: STORAGE_FORMAT_VERSION,
             service_id: ServiceId::new::<Hasher>(
                 service_name,
                 crate::service::messaging_pattern::MessagingPattern::Event,
@@ -88,6 +237,7 @@ impl StaticConfig {
         let messaging_pattern =
             MessagingPattern::PublishSubscribe(publish_subscribe::StaticConfig::new(config));
         Self {
+            storage_format_version: STORAGE_FORMAT_VERSION,
             service_id: ServiceId::new::<Hasher>(
                 service_name,
                 crate::service::messaging_pattern::MessagingPattern::PublishSubscribe,
@@ -98,6 +248,28 @@ impl StaticConfig {
         }
     }
 
+    pub(crate) fn place_in_directory<Hasher: Hash>(&mut self, directory: &Path) {
+        let messaging_pattern = match self.messaging_pattern {
+            MessagingPattern::RequestResponse(_) => {
+                crate::service::messaging_pattern::MessagingPattern::RequestResponse
+            }
+            MessagingPattern::PublishSubscribe(_) => {
+                crate::service::messaging_pattern::MessagingPattern::PublishSubscribe
+            }
+            MessagingPattern::Event(_) => {
+                crate::service::messaging_pattern::MessagingPattern::Event
+            }
+        };
+
+        self.service_id =
+            ServiceId::new_in_directory::<Hasher>(&self.service_name, messaging_pattern, directory);
+    }
+
+    /// Returns the [`STORAGE_FORMAT_VERSION`] in which the [`StaticConfig`] was stored.
+    pub fn storage_format_version(&self) -> u32 {
+        self.storage_format_version
+    }
+
     /// Returns the attributes of the [`crate::service::Service`]
     pub fn attributes(&self) -> &AttributeSet {
         &self.attributes
@@ -118,6 +290,28 @@ impl StaticConfig {
         &self.messaging_pattern
     }
 
+    /// Returns true when the iceoryx2 internal headers of all messages have the same layout as
+    /// in the current iceoryx2 version.
+    pub(crate) fn has_current_header_layout(&self) -> bool {
+        match &self.messaging_pattern {
+            MessagingPattern::RequestResponse(v) => {
+                v.request_message_type_details.header
+                    == TypeDetail::__internal_new::<header::request_response::RequestHeader>(
+                        TypeVariant::FixedSize,
+                    )
+                    && v.response_message_type_details.header
+                        == TypeDetail::__internal_new::<header::request_response::ResponseHeader>(
+                            TypeVariant::FixedSize,
+                        )
+            }
+            MessagingPattern::PublishSubscribe(v) => {
+                v.message_type_details.header
+                    == publish_subscribe::StaticConfig::header_type_detail(v.has_send_timestamp())
+            }
+            MessagingPattern::Event(_) => true,
+        }
+    }
+
     pub(crate) fn has_same_messaging_pattern(&self, rhs: &StaticConfig) -> bool {
         self.messaging_pattern
             .is_same_pattern(&rhs.messaging_pattern)
@@ -28,15 +28,47 @@
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("max message size:                 {:?}", pubsub.static_config().max_message_size());
+//! println!("sample alignment:                 {:?}", pubsub.static_config().sample_alignment());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
-use super::message_type_details::MessageTypeDetails;
-use crate::config;
+use core::alloc::Layout;
+use core::time::Duration;
+
+use super::message_type_details::{MessageTypeDetails, TypeDetail, TypeVariant};
+use crate::config::{self, ConfigSource};
+use crate::service::header::publish_subscribe::Header;
+use iceoryx2_cal::shm_allocator::PoolAllocationStrategy;
 use serde::{Deserialize, Serialize};
 
+/// Defines when a [`crate::port::subscriber::Subscriber`] that does not free buffer space in
+/// time is disconnected by a [`crate::port::publisher::Publisher`] with the
+/// [`UnableToDeliverStrategy::Block`](crate::service::port_factory::publisher::UnableToDeliverStrategy::Block).
+/// See
+/// [`Builder::slow_subscriber_eviction()`](crate::service::builder::publish_subscribe::Builder::slow_subscriber_eviction()).
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct SlowSubscriberEviction {
+    pub(crate) deadline: Duration,
+    pub(crate) consecutive_misses: usize,
+}
+
+impl SlowSubscriberEviction {
+    /// Returns how long a [`crate::port::publisher::Publisher`] waits for free buffer space
+    /// of a [`crate::port::subscriber::Subscriber`] on every send.
+    pub fn deadline(&self) -> Duration {
+        self.deadline
+    }
+
+    /// Returns after how many consecutive sends with a missed deadline the
+    /// [`crate::port::subscriber::Subscriber`] is disconnected.
+    pub fn consecutive_misses(&self) -> usize {
+        self.consecutive_misses
+    }
+}
+
 /// The static configuration of an
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 /// based service. Contains all parameters that do not change during the lifetime of a
@@ -50,9 +82,23 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
+    pub(crate) max_message_size: Option<usize>,
+    pub(crate) sample_cache_line_aligned: bool,
+    pub(crate) enable_acknowledgment: bool,
+    pub(crate) enable_last_value: bool,
+    pub(crate) pool_allocation_strategy: PoolAllocationStrategy,
+    pub(crate) coalescing_window: Option<Duration>,
+    pub(crate) slow_subscriber_eviction: Option<SlowSubscriberEviction>,
+    pub(crate) pin_sample_memory: bool,
+    pub(crate) pool_capacity: Option<usize>,
+    pub(crate) enable_send_timestamp: bool,
     pub(crate) message_type_details: MessageTypeDetails,
 }
 
+/// The cache line size to which every sample slot is aligned when
+/// [`StaticConfig::sample_cache_line_aligned()`] is set.
+pub const CACHE_LINE_SIZE: usize = 64;
+
 impl StaticConfig {
     pub(crate) fn new(config: &config::Config) -> Self {
         Self {
@@ -69,10 +115,49 @@ impl StaticConfig {
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            max_message_size: None,
+            sample_cache_line_aligned: false,
+            enable_acknowledgment: false,
+            enable_last_value: false,
+            pool_allocation_strategy: PoolAllocationStrategy::default(),
+            coalescing_window: None,
+            slow_subscriber_eviction: None,
+            pin_sample_memory: false,
+            pool_capacity: None,
+            enable_send_timestamp: false,
             message_type_details: MessageTypeDetails::default(),
         }
     }
 
+    /// Returns the minimum number of sample slots the data segment of a
+    /// [`Publisher`](crate::port::publisher::Publisher) requires so that it never runs out of
+    /// samples. Saturates instead of overflowing for absurdly large settings.
+    pub(crate) fn min_pool_capacity(&self, publisher_max_loaned_samples: usize) -> usize {
+        self.max_subscribers
+            .saturating_mul(
+                self.subscriber_max_buffer_size
+                    .saturating_add(self.subscriber_max_borrowed_samples),
+            )
+            .saturating_add(self.history_size)
+            .saturating_add(publisher_max_loaned_samples)
+    }
+
+    /// Returns the number of sample slots of the data segment of a
+    /// [`Publisher`](crate::port::publisher::Publisher), the explicit
+    /// [`StaticConfig::pool_capacity()`] or the minimum when it is larger.
+    pub(crate) fn effective_pool_capacity(&self, publisher_max_loaned_samples: usize) -> usize {
+        self.min_pool_capacity(publisher_max_loaned_samples)
+            .max(self.pool_capacity.unwrap_or(0))
+    }
+
+    /// Returns the worst-case number of sample slots of all
+    /// [`Publisher`](crate::port::publisher::Publisher) data segments combined. Saturates
+    /// instead of overflowing for absurdly large settings.
+    pub(crate) fn required_sample_slots(&self, publisher_max_loaned_samples: usize) -> usize {
+        self.effective_pool_capacity(publisher_max_loaned_samples)
+            .saturating_mul(self.max_publishers)
+    }
+
     /// Returns the maximum supported amount of [`Node`](crate::node::Node)s that can open the
     /// [`Service`](crate::service::Service) in parallel.
     pub fn max_nodes(&self) -> usize {
@@ -113,8 +198,277 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns the maximum number of payload bytes a [`crate::port::publisher::Publisher`] can
+    /// mark as used with [`crate::sample_mut::SampleMut::set_used_len()`]. For fixed size
+    /// payloads it is at most the size of the payload, for slices it is [`None`] when it was
+    /// not restricted explicitly.
+    pub fn max_message_size(&self) -> Option<usize> {
+        self.max_message_size
+    }
+
+    /// Returns true if every sample slot in the data segment of a
+    /// [`crate::port::publisher::Publisher`] starts at a cache line boundary, so that
+    /// [`crate::port::subscriber::Subscriber`]s reading adjacent samples do not share a cache
+    /// line.
+    pub fn sample_cache_line_aligned(&self) -> bool {
+        self.sample_cache_line_aligned
+    }
+
+    /// Returns true if a [`crate::port::subscriber::Subscriber`] acknowledges every consumed
+    /// [`crate::sample::Sample`] so that the [`crate::port::publisher::Publisher`] can acquire
+    /// it with [`crate::port::publisher::Publisher::last_acked_sequence()`].
+    pub fn has_acknowledgment(&self) -> bool {
+        self.enable_acknowledgment
+    }
+
+    /// Returns true if the [`crate::service::Service`] retains only the latest value that is
+    /// replaced with [`crate::port::publisher::Publisher::update()`].
+    pub fn has_last_value(&self) -> bool {
+        self.enable_last_value
+    }
+
+    /// Returns the [`PoolAllocationStrategy`] that every
+    /// [`crate::port::publisher::Publisher`] uses to hand out the sample slots of its data
+    /// segment.
+    pub fn pool_allocation_strategy(&self) -> PoolAllocationStrategy {
+        self.pool_allocation_strategy
+    }
+
+    /// Returns the coalescing window when coalescing is enabled, otherwise [`None`]. Buffered
+    /// samples of the same coalescence group that were published within the window are
+    /// collapsed into the most recent one when a
+    /// [`crate::port::subscriber::Subscriber`] receives them.
+    pub fn coalescing_window(&self) -> Option<Duration> {
+        self.coalescing_window
+    }
+
+    /// Returns the [`SlowSubscriberEviction`] policy when slow
+    /// [`crate::port::subscriber::Subscriber`]s are disconnected, otherwise [`None`].
+    pub fn slow_subscriber_eviction(&self) -> Option<SlowSubscriberEviction> {
+        self.slow_subscriber_eviction
+    }
+
+    /// Returns true if the memory of every received [`crate::sample::Sample`] is locked
+    /// with `mlock` until it is released, see
+    /// [`Sample::pin_memory()`](crate::sample::Sample::pin_memory()).
+    pub fn pins_sample_memory(&self) -> bool {
+        self.pin_sample_memory
+    }
+
+    /// Returns the number of sample slots every [`crate::port::publisher::Publisher`]
+    /// provides in its data segment when it was set explicitly, otherwise [`None`]. A
+    /// [`crate::port::publisher::Publisher`] that requires more slots than defined, since it
+    /// loans more samples in parallel, uses the required number instead.
+    pub fn pool_capacity(&self) -> Option<usize> {
+        self.pool_capacity
+    }
+
+    /// Returns true if the header of every sample has room for a send timestamp, so that
+    /// [`crate::port::publisher::Publisher`]s can record it, see
+    /// [`Header::send_timestamp()`](crate::service::header::publish_subscribe::Header::send_timestamp()).
+    /// It is the case when the [`crate::service::Service`] was created with
+    /// [`Builder::enable_send_timestamp()`](crate::service::builder::publish_subscribe::Builder::enable_send_timestamp())
+    /// or with a [`StaticConfig::coalescing_window()`], which is measured with the send
+    /// timestamps.
+    pub fn has_send_timestamp(&self) -> bool {
+        self.enable_send_timestamp || self.coalescing_window.is_some()
+    }
+
+    /// Returns the effective alignment of a sample slot in the data segment of a
+    /// [`crate::port::publisher::Publisher`]. The stride between two sample slots is always a
+    /// multiple of it.
+    pub fn sample_alignment(&self) -> usize {
+        let alignment = self.message_type_details.sample_layout(0).align();
+        if self.sample_cache_line_aligned {
+            alignment.max(CACHE_LINE_SIZE)
+        } else {
+            alignment
+        }
+    }
+
+    pub(crate) fn sample_layout(&self, number_of_elements: usize) -> Layout {
+        let layout = self.message_type_details.sample_layout(number_of_elements);
+
+        if self.sample_cache_line_aligned {
+            unsafe {
+                Layout::from_size_align_unchecked(layout.size(), self.sample_alignment())
+                    .pad_to_align()
+            }
+        } else {
+            layout
+        }
+    }
+
+    /// Returns the [`TypeDetail`] of the sample header, its size depends on
+    /// [`StaticConfig::has_send_timestamp()`].
+    pub(crate) fn header_type_detail(has_send_timestamp: bool) -> TypeDetail {
+        TypeDetail {
+            size: Header::size_in_sample(has_send_timestamp),
+            ..TypeDetail::__internal_new::<Header>(TypeVariant::FixedSize)
+        }
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn message_type_details(&self) -> &MessageTypeDetails {
         &self.message_type_details
     }
 }
+
+/// Records the [`ConfigSource`] of every setting of a [`StaticConfig`]. It is acquired with
+/// [`PortFactory::config_provenance()`](crate::service::port_factory::publish_subscribe::PortFactory::config_provenance())
+/// and reflects how the [`StaticConfig`] was resolved in the current process. When an
+/// existing [`Service`](crate::service::Service) was opened, every setting was defined by its
+/// creator and is therefore reported as [`ConfigSource::ExistingService`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct StaticConfigProvenance {
+    pub(crate) max_subscribers: ConfigSource,
+    pub(crate) max_publishers: ConfigSource,
+    pub(crate) max_nodes: ConfigSource,
+    pub(crate) history_size: ConfigSource,
+    pub(crate) subscriber_max_buffer_size: ConfigSource,
+    pub(crate) subscriber_max_borrowed_samples: ConfigSource,
+    pub(crate) enable_safe_overflow: ConfigSource,
+    pub(crate) max_message_size: ConfigSource,
+    pub(crate) sample_cache_line_aligned: ConfigSource,
+    pub(crate) enable_acknowledgment: ConfigSource,
+    pub(crate) enable_last_value: ConfigSource,
+    pub(crate) pool_allocation_strategy: ConfigSource,
+    pub(crate) coalescing_window: ConfigSource,
+    pub(crate) slow_subscriber_eviction: ConfigSource,
+    pub(crate) pin_sample_memory: ConfigSource,
+    pub(crate) pool_capacity: ConfigSource,
+    pub(crate) enable_send_timestamp: ConfigSource,
+}
+
+impl StaticConfigProvenance {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            max_subscribers: config.source_of("IOX2_PUBSUB_MAX_SUBSCRIBERS"),
+            max_publishers: config.source_of("IOX2_PUBSUB_MAX_PUBLISHERS"),
+            max_nodes: config.source_of("IOX2_PUBSUB_MAX_NODES"),
+            history_size: config.source_of("IOX2_PUBSUB_PUBLISHER_HISTORY_SIZE"),
+            subscriber_max_buffer_size: config.source_of("IOX2_PUBSUB_SUBSCRIBER_MAX_BUFFER_SIZE"),
+            subscriber_max_borrowed_samples: config
+                .source_of("IOX2_PUBSUB_SUBSCRIBER_MAX_BORROWED_SAMPLES"),
+            enable_safe_overflow: config.source_of("IOX2_PUBSUB_ENABLE_SAFE_OVERFLOW"),
+            max_message_size: ConfigSource::BuiltInDefault,
+            sample_cache_line_aligned: ConfigSource::BuiltInDefault,
+            enable_acknowledgment: ConfigSource::BuiltInDefault,
+            enable_last_value: ConfigSource::BuiltInDefault,
+            pool_allocation_strategy: ConfigSource::BuiltInDefault,
+            coalescing_window: ConfigSource::BuiltInDefault,
+            slow_subscriber_eviction: ConfigSource::BuiltInDefault,
+            pin_sample_memory: ConfigSource::BuiltInDefault,
+            pool_capacity: ConfigSource::BuiltInDefault,
+            enable_send_timestamp: ConfigSource::BuiltInDefault,
+        }
+    }
+
+    pub(crate) fn existing_service() -> Self {
+        let source = ConfigSource::ExistingService;
+        Self {
+            max_subscribers: source,
+            max_publishers: source,
+            max_nodes: source,
+            history_size: source,
+            subscriber_max_buffer_size: source,
+            subscriber_max_borrowed_samples: source,
+            enable_safe_overflow: source,
+            max_message_size: source,
+            sample_cache_line_aligned: source,
+            enable_acknowledgment: source,
+            enable_last_value: source,
+            pool_allocation_strategy: source,
+            coalescing_window: source,
+            slow_subscriber_eviction: source,
+            pin_sample_memory: source,
+            pool_capacity: source,
+            enable_send_timestamp: source,
+        }
+    }
+
+    /// Returns the source of [`StaticConfig::max_subscribers()`].
+    pub fn max_subscribers(&self) -> ConfigSource {
+        self.max_subscribers
+    }
+
+    /// Returns the source of [`StaticConfig::max_publishers()`].
+    pub fn max_publishers(&self) -> ConfigSource {
+        self.max_publishers
+    }
+
+    /// Returns the source of [`StaticConfig::max_nodes()`].
+    pub fn max_nodes(&self) -> ConfigSource {
+        self.max_nodes
+    }
+
+    /// Returns the source of [`StaticConfig::history_size()`].
+    pub fn history_size(&self) -> ConfigSource {
+        self.history_size
+    }
+
+    /// Returns the source of [`StaticConfig::subscriber_max_buffer_size()`].
+    pub fn subscriber_max_buffer_size(&self) -> ConfigSource {
+        self.subscriber_max_buffer_size
+    }
+
+    /// Returns the source of [`StaticConfig::subscriber_max_borrowed_samples()`].
+    pub fn subscriber_max_borrowed_samples(&self) -> ConfigSource {
+        self.subscriber_max_borrowed_samples
+    }
+
+    /// Returns the source of [`StaticConfig::has_safe_overflow()`].
+    pub fn has_safe_overflow(&self) -> ConfigSource {
+        self.enable_safe_overflow
+    }
+
+    /// Returns the source of [`StaticConfig::max_message_size()`].
+    pub fn max_message_size(&self) -> ConfigSource {
+        self.max_message_size
+    }
+
+    /// Returns the source of [`StaticConfig::sample_cache_line_aligned()`].
+    pub fn sample_cache_line_aligned(&self) -> ConfigSource {
+        self.sample_cache_line_aligned
+    }
+
+    /// Returns the source of [`StaticConfig::has_acknowledgment()`].
+    pub fn has_acknowledgment(&self) -> ConfigSource {
+        self.enable_acknowledgment
+    }
+
+    /// Returns the source of [`StaticConfig::has_last_value()`].
+    pub fn has_last_value(&self) -> ConfigSource {
+        self.enable_last_value
+    }
+
+    /// Returns the source of [`StaticConfig::pool_allocation_strategy()`].
+    pub fn pool_allocation_strategy(&self) -> ConfigSource {
+        self.pool_allocation_strategy
+    }
+
+    /// Returns the source of [`StaticConfig::coalescing_window()`].
+    pub fn coalescing_window(&self) -> ConfigSource {
+        self.coalescing_window
+    }
+
+    /// Returns the source of [`StaticConfig::slow_subscriber_eviction()`].
+    pub fn slow_subscriber_eviction(&self) -> ConfigSource {
+        self.slow_subscriber_eviction
+    }
+
+    /// Returns the source of [`StaticConfig::pins_sample_memory()`].
+    pub fn pins_sample_memory(&self) -> ConfigSource {
+        self.pin_sample_memory
+    }
+
+    /// Returns the source of [`StaticConfig::pool_capacity()`].
+    pub fn pool_capacity(&self) -> ConfigSource {
+        self.pool_capacity
+    }
+
+    /// Returns the source of [`StaticConfig::has_send_timestamp()`].
+    pub fn has_send_timestamp(&self) -> ConfigSource {
+        self.enable_send_timestamp
+    }
+}
@@ -167,4 +167,31 @@ impl StaticConfig {
     pub fn max_nodes(&self) -> usize {
         self.max_nodes
     }
+
+    /// Returns the size of the buffer in which a [`crate::port::client::Client`] receives the
+    /// responses of all its active requests from one [`crate::port::server::Server`].
+    pub(crate) fn response_buffer_size_per_client(&self) -> usize {
+        self.max_active_requests * self.max_response_buffer_size
+    }
+
+    /// Returns the maximum number of responses a [`crate::port::client::Client`] can borrow
+    /// from one [`crate::port::server::Server`] over all its active requests.
+    pub(crate) fn max_borrowed_responses_per_client(&self) -> usize {
+        self.max_active_requests * self.max_borrowed_responses
+    }
+
+    /// Returns the number of requests the data segment of a [`crate::port::client::Client`]
+    /// must provide so that it never runs out of memory while it respects all limits.
+    pub(crate) fn required_amount_of_requests_per_client(&self) -> usize {
+        self.max_servers * (self.max_request_buffer_size + self.max_borrowed_requests)
+            + self.max_active_requests
+    }
+
+    /// Returns the number of responses the data segment of a [`crate::port::server::Server`]
+    /// must provide so that it never runs out of memory while it respects all limits.
+    pub(crate) fn required_amount_of_responses_per_server(&self) -> usize {
+        self.max_clients
+            * (self.response_buffer_size_per_client() + self.max_borrowed_responses_per_client())
+            + self.max_active_responses
+    }
 }
@@ -0,0 +1,27 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// Enters a [tracing](https://crates.io/crates/tracing) span that lasts until the end of the
+/// current scope. When the `tracing` feature is disabled the macro expands to nothing and the
+/// fields are not evaluated.
+///
+/// ```ignore
+/// enter_span!(DEBUG, "create", service = %service_name);
+/// ```
+macro_rules! enter_span {
+    ($level:ident, $name:literal $(, $($fields:tt)*)?) => {
+        #[cfg(feature = "tracing")]
+        let _span = ::tracing::span!(::tracing::Level::$level, $name $(, $($fields)*)?).entered();
+    };
+}
+
+pub(crate) use enter_span;
@@ -12,7 +12,7 @@
 
 #[cfg(test)]
 mod attribute {
-    use iceoryx2::service::attribute::AttributeVerifier;
+    use iceoryx2::service::attribute::{AttributeSpecifier, AttributeVerifier};
     use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_testing::assert_that;
 
@@ -95,4 +95,37 @@ mod attribute {
 
         assert_that!(counter, eq 0);
     }
+
+    #[test]
+    fn attribute_verifier_alternatives_are_satisfied_by_any_match() {
+        let sut = AttributeVerifier::new()
+            .require_any_of(&[("codec", "h264"), ("codec", "h265")])
+            .require_any_of(&[("resolution", "4k"), ("fps", "60")]);
+        let attributes = AttributeSpecifier::new()
+            .define("codec", "h264")
+            .define("fps", "60");
+
+        assert_that!(sut.alternatives().count(), eq 2);
+        assert_that!(sut.verify_requirements(attributes.attributes()), eq Ok(()));
+    }
+
+    #[test]
+    fn attribute_verifier_reports_unsatisfied_alternative_group() {
+        let sut = AttributeVerifier::new()
+            .require_any_of(&[("codec", "h264"), ("codec", "h265")])
+            .require_any_of(&[("resolution", "4k"), ("fps", "60")]);
+        let attributes = AttributeSpecifier::new()
+            .define("codec", "h265")
+            .define("fps", "30");
+
+        assert_that!(sut.verify_requirements(attributes.attributes()), eq Err("(resolution=4k OR fps=60)"));
+    }
+
+    #[test]
+    fn attribute_verifier_empty_alternative_group_is_never_satisfied() {
+        let sut = AttributeVerifier::new().require_any_of(&[]);
+        let attributes = AttributeSpecifier::new().define("codec", "h264");
+
+        assert_that!(sut.verify_requirements(attributes.attributes()), eq Err("()"));
+    }
 }
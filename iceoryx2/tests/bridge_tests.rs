@@ -0,0 +1,324 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(feature = "bridge")]
+mod bridge_error {
+    use iceoryx2::bridge::{BridgeCreateError, BridgeForwardError};
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn create_error_display_works() {
+        assert_that!(
+            format!("{}", BridgeCreateError::UnableToConnectToSocket), eq "BridgeCreateError::UnableToConnectToSocket");
+    }
+
+    #[test]
+    fn forward_error_display_works() {
+        assert_that!(
+            format!("{}", BridgeForwardError::PayloadTypeMismatch), eq "BridgeForwardError::PayloadTypeMismatch");
+    }
+}
+
+#[cfg(feature = "bridge")]
+#[generic_tests::define]
+mod bridge {
+    use std::net::{Ipv4Addr, SocketAddr, TcpListener};
+
+    use core::fmt::Debug;
+    use iceoryx2::bridge::{
+        BridgeBuilder, BridgeCreateError, BridgeEndpoint, BridgeForwardError, BridgeReceiver,
+    };
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::config::test_directory;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+    use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+    fn generate_endpoint() -> BridgeEndpoint {
+        let mut file = FileName::new(b"bridge_tests_").unwrap();
+        file.push_bytes(
+            UniqueSystemId::new()
+                .unwrap()
+                .value()
+                .to_string()
+                .as_bytes(),
+        )
+        .unwrap();
+
+        BridgeEndpoint::UnixDatagram(
+            FilePath::from_path_and_file(&test_directory(), &file).unwrap(),
+        )
+    }
+
+    fn generate_tcp_endpoint() -> BridgeEndpoint {
+        // the operating system assigns a free port
+        BridgeEndpoint::Tcp(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)))
+    }
+
+    fn forward_until<Sut: Service, Payload: Debug + DeserializeOwned + 'static>(
+        bridge_receiver: &BridgeReceiver<Sut, Payload>,
+        number_of_samples: usize,
+    ) {
+        let _watchdog = Watchdog::new();
+        let mut number_of_republished_samples = 0;
+        while number_of_republished_samples < number_of_samples {
+            number_of_republished_samples += bridge_receiver.forward().unwrap();
+        }
+        assert_that!(number_of_republished_samples, eq number_of_samples);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    #[repr(C)]
+    struct PaddedPayload {
+        flag: u8,
+        value: u64,
+    }
+
+    fn bridge_republishes_samples_in_remote_service<Sut: Service>(endpoint: BridgeEndpoint) {
+        const NUMBER_OF_SAMPLES: u64 = 4;
+        let service_name = generate_service_name();
+        // isolated configs separate the local and the remote services like two hosts would
+        let local_config = generate_isolated_config();
+        let remote_config = generate_isolated_config();
+        let local_node = NodeBuilder::new()
+            .config(&local_config)
+            .create::<Sut>()
+            .unwrap();
+        let remote_node = NodeBuilder::new()
+            .config(&remote_config)
+            .create::<Sut>()
+            .unwrap();
+
+        let local_service = local_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+        let remote_service = remote_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        let bridge_receiver = BridgeBuilder::new(&remote_node, &service_name, &endpoint)
+            .create_receiver::<u64>()
+            .unwrap();
+        let bridge_sender =
+            BridgeBuilder::new(&local_node, &service_name, bridge_receiver.endpoint())
+                .create_sender::<u64>()
+                .unwrap();
+
+        let publisher = local_service.publisher_builder().create().unwrap();
+        let subscriber = remote_service.subscriber_builder().create().unwrap();
+
+        for value in 1..=NUMBER_OF_SAMPLES {
+            publisher.send_copy(value).unwrap();
+        }
+
+        assert_that!(bridge_sender.forward(), eq Ok(NUMBER_OF_SAMPLES as usize));
+        forward_until(&bridge_receiver, NUMBER_OF_SAMPLES as usize);
+        assert_that!(bridge_receiver.forward(), eq Ok(0));
+
+        for value in 1..=NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq value);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn bridge_republishes_samples_in_remote_service_over_unix_datagram_socket<Sut: Service>() {
+        bridge_republishes_samples_in_remote_service::<Sut>(generate_endpoint());
+    }
+
+    #[test]
+    fn bridge_republishes_samples_in_remote_service_over_tcp<Sut: Service>() {
+        bridge_republishes_samples_in_remote_service::<Sut>(generate_tcp_endpoint());
+    }
+
+    #[test]
+    fn bridge_serializes_payload_with_padding<Sut: Service>() {
+        let service_name = generate_service_name();
+        let local_config = generate_isolated_config();
+        let remote_config = generate_isolated_config();
+        let local_node = NodeBuilder::new()
+            .config(&local_config)
+            .create::<Sut>()
+            .unwrap();
+        let remote_node = NodeBuilder::new()
+            .config(&remote_config)
+            .create::<Sut>()
+            .unwrap();
+
+        let bridge_receiver =
+            BridgeBuilder::new(&remote_node, &service_name, &generate_tcp_endpoint())
+                .create_receiver::<PaddedPayload>()
+                .unwrap();
+        let bridge_sender =
+            BridgeBuilder::new(&local_node, &service_name, bridge_receiver.endpoint())
+                .create_sender::<PaddedPayload>()
+                .unwrap();
+
+        let publisher = local_node
+            .service_builder(&service_name)
+            .publish_subscribe::<PaddedPayload>()
+            .open()
+            .unwrap()
+            .publisher_builder()
+            .create()
+            .unwrap();
+        let subscriber = remote_node
+            .service_builder(&service_name)
+            .publish_subscribe::<PaddedPayload>()
+            .open()
+            .unwrap()
+            .subscriber_builder()
+            .create()
+            .unwrap();
+
+        let payload = PaddedPayload {
+            flag: 3,
+            value: 0x1234_5678_9abc,
+        };
+        publisher.send_copy(payload).unwrap();
+
+        assert_that!(bridge_sender.forward(), eq Ok(1));
+        forward_until(&bridge_receiver, 1);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq payload);
+    }
+
+    #[test]
+    fn bridge_sender_cannot_be_created_without_receiver<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = BridgeBuilder::new(&node, &generate_service_name(), &generate_endpoint())
+            .create_sender::<u64>();
+
+        assert_that!(sut.err(), eq Some(BridgeCreateError::UnableToConnectToSocket));
+    }
+
+    #[test]
+    fn bridge_tcp_sender_cannot_be_created_without_receiver<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        // acquire a free port that nobody listens on
+        let address = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .unwrap()
+            .local_addr()
+            .unwrap();
+
+        let sut = BridgeBuilder::new(
+            &node,
+            &generate_service_name(),
+            &BridgeEndpoint::Tcp(address),
+        )
+        .create_sender::<u64>();
+
+        assert_that!(sut.err(), eq Some(BridgeCreateError::UnableToConnectToSocket));
+    }
+
+    fn bridge_receiver_rejects_payload_of_different_type<Sut: Service>(endpoint: BridgeEndpoint) {
+        let service_name = generate_service_name();
+        let local_config = generate_isolated_config();
+        let remote_config = generate_isolated_config();
+        let local_node = NodeBuilder::new()
+            .config(&local_config)
+            .create::<Sut>()
+            .unwrap();
+        let remote_node = NodeBuilder::new()
+            .config(&remote_config)
+            .create::<Sut>()
+            .unwrap();
+
+        let bridge_receiver = BridgeBuilder::new(&remote_node, &service_name, &endpoint)
+            .create_receiver::<u64>()
+            .unwrap();
+        let bridge_sender =
+            BridgeBuilder::new(&local_node, &service_name, bridge_receiver.endpoint())
+                .create_sender::<u32>()
+                .unwrap();
+
+        let publisher = local_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u32>()
+            .open()
+            .unwrap()
+            .publisher_builder()
+            .create()
+            .unwrap();
+
+        publisher.send_copy(42).unwrap();
+
+        assert_that!(bridge_sender.forward(), eq Ok(1));
+        let _watchdog = Watchdog::new();
+        let result = loop {
+            match bridge_receiver.forward() {
+                Ok(0) => continue,
+                result => break result,
+            }
+        };
+        assert_that!(result, eq Err(BridgeForwardError::PayloadTypeMismatch));
+    }
+
+    #[test]
+    fn bridge_receiver_rejects_payload_of_different_type_over_unix_datagram_socket<Sut: Service>() {
+        bridge_receiver_rejects_payload_of_different_type::<Sut>(generate_endpoint());
+    }
+
+    #[test]
+    fn bridge_receiver_rejects_payload_of_different_type_over_tcp<Sut: Service>() {
+        bridge_receiver_rejects_payload_of_different_type::<Sut>(generate_tcp_endpoint());
+    }
+
+    #[test]
+    fn bridge_sender_rejects_message_exceeding_max_message_size<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let bridge_receiver = BridgeBuilder::new(&node, &service_name, &generate_tcp_endpoint())
+            .create_receiver::<u64>()
+            .unwrap();
+        let bridge_sender = BridgeBuilder::new(&node, &service_name, bridge_receiver.endpoint())
+            .max_message_size(8)
+            .create_sender::<u64>()
+            .unwrap();
+
+        let publisher = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap()
+            .publisher_builder()
+            .create()
+            .unwrap();
+        publisher.send_copy(42).unwrap();
+
+        assert_that!(bridge_sender.forward(), eq Err(BridgeForwardError::SerializationFailure));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -42,3 +42,417 @@ mod node_name {
         assert_that!(default_config, eq file_config);
     }
 }
+
+mod validation {
+    use iceoryx2::config::{ConfigCreationError, ConfigValidationError};
+    use iceoryx2::node::NodeCreationFailure;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn config_with_max_sample_slots(max_sample_slots: usize) -> Config {
+        let mut config = Config::default();
+        config.defaults.publish_subscribe.max_publishers = 3;
+        config.defaults.publish_subscribe.max_subscribers = 4;
+        config.defaults.publish_subscribe.subscriber_max_buffer_size = 5;
+        config
+            .defaults
+            .publish_subscribe
+            .subscriber_max_borrowed_samples = 2;
+        config.defaults.publish_subscribe.publisher_history_size = 3;
+        config
+            .defaults
+            .publish_subscribe
+            .publisher_max_loaned_samples = 1;
+        config.defaults.publish_subscribe.max_sample_slots = max_sample_slots;
+        config
+    }
+
+    // 3 publishers * (4 subscribers * (5 buffer + 2 borrowed) + 3 history + 1 loaned)
+    const REQUIRED_SAMPLE_SLOTS: usize = 96;
+
+    #[test]
+    fn default_config_is_valid() {
+        assert_that!(Config::default().validate(), is_ok);
+    }
+
+    #[test]
+    fn config_with_required_sample_slots_at_limit_is_valid() {
+        assert_that!(
+            config_with_max_sample_slots(REQUIRED_SAMPLE_SLOTS).validate(),
+            is_ok
+        );
+    }
+
+    #[test]
+    fn config_with_required_sample_slots_above_limit_is_invalid() {
+        assert_that!(config_with_max_sample_slots(REQUIRED_SAMPLE_SLOTS - 1).validate(),
+            eq Err(ConfigValidationError::ExceedsMaxSampleSlots));
+    }
+
+    #[test]
+    fn config_with_overflowing_sample_slots_is_invalid() {
+        let mut config = config_with_max_sample_slots(usize::MAX - 1);
+        config.defaults.publish_subscribe.max_subscribers = usize::MAX;
+
+        assert_that!(config.validate(), eq Err(ConfigValidationError::ExceedsMaxSampleSlots));
+    }
+
+    #[test]
+    fn config_with_zero_subscriber_max_buffer_size_is_invalid() {
+        let mut config = config_with_max_sample_slots(REQUIRED_SAMPLE_SLOTS);
+        config.defaults.publish_subscribe.subscriber_max_buffer_size = 0;
+
+        assert_that!(config.validate(), eq Err(ConfigValidationError::SubscriberMaxBufferSizeIsZero));
+    }
+
+    #[test]
+    fn config_with_zero_subscriber_max_borrowed_samples_is_invalid() {
+        let mut config = config_with_max_sample_slots(REQUIRED_SAMPLE_SLOTS);
+        config
+            .defaults
+            .publish_subscribe
+            .subscriber_max_borrowed_samples = 0;
+
+        assert_that!(config.validate(), eq Err(ConfigValidationError::SubscriberMaxBorrowedSamplesIsZero));
+    }
+
+    #[test]
+    fn config_file_with_invalid_settings_is_rejected() {
+        let mut config = Config::default();
+        config.defaults.publish_subscribe.subscriber_max_buffer_size = 0;
+        let file_path = std::env::temp_dir().join(format!(
+            "config_tests_{}.toml",
+            UniqueSystemId::new().unwrap().value()
+        ));
+        std::fs::write(&file_path, toml::to_string(&config).unwrap()).unwrap();
+
+        let sut =
+            Config::from_file(&FilePath::new(file_path.to_str().unwrap().as_bytes()).unwrap());
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_that!(sut.err(), eq Some(ConfigCreationError::InvalidConfig(
+            ConfigValidationError::SubscriberMaxBufferSizeIsZero
+        )));
+    }
+
+    #[test]
+    fn node_with_invalid_config_cannot_be_created() {
+        let mut config = generate_isolated_config();
+        config.defaults.event.max_listeners = 0;
+
+        let sut = NodeBuilder::new().config(&config).create::<ipc::Service>();
+
+        assert_that!(sut.err(), eq Some(NodeCreationFailure::InvalidConfig(
+            ConfigValidationError::EventMaxPortsOrNodesIsZero
+        )));
+    }
+
+    #[test]
+    fn config_with_zero_event_maxima_is_invalid() {
+        for n in 0..3 {
+            let mut config = Config::default();
+            match n {
+                0 => config.defaults.event.max_listeners = 0,
+                1 => config.defaults.event.max_notifiers = 0,
+                _ => config.defaults.event.max_nodes = 0,
+            }
+
+            assert_that!(config.validate(), eq Err(ConfigValidationError::EventMaxPortsOrNodesIsZero));
+        }
+    }
+
+    #[test]
+    fn config_with_notifier_event_ids_in_event_id_range_is_valid() {
+        let mut config = Config::default();
+        config.defaults.event.event_id_max_value = 3;
+        config.defaults.event.notifier_created_event = Some(0);
+        config.defaults.event.notifier_dropped_event = Some(2);
+        config.defaults.event.notifier_dead_event = Some(3);
+
+        assert_that!(config.validate(), is_ok);
+    }
+
+    #[test]
+    fn config_with_notifier_event_id_out_of_event_id_range_is_invalid() {
+        for n in 0..3 {
+            let mut config = Config::default();
+            config.defaults.event.event_id_max_value = 3;
+            match n {
+                0 => config.defaults.event.notifier_created_event = Some(4),
+                1 => config.defaults.event.notifier_dropped_event = Some(4),
+                _ => config.defaults.event.notifier_dead_event = Some(4),
+            }
+
+            assert_that!(config.validate(), eq Err(ConfigValidationError::EventIdOutOfRange));
+        }
+    }
+
+    #[test]
+    fn validation_error_display_works() {
+        assert_that!(format!("{}", ConfigValidationError::ExceedsMaxSampleSlots),
+            eq "ConfigValidationError::ExceedsMaxSampleSlots");
+        assert_that!(format!("{}", ConfigValidationError::SubscriberMaxBufferSizeIsZero),
+            eq "ConfigValidationError::SubscriberMaxBufferSizeIsZero");
+        assert_that!(format!("{}", ConfigValidationError::SubscriberMaxBorrowedSamplesIsZero),
+            eq "ConfigValidationError::SubscriberMaxBorrowedSamplesIsZero");
+        assert_that!(format!("{}", ConfigValidationError::EventMaxPortsOrNodesIsZero),
+            eq "ConfigValidationError::EventMaxPortsOrNodesIsZero");
+        assert_that!(format!("{}", ConfigValidationError::EventIdOutOfRange),
+            eq "ConfigValidationError::EventIdOutOfRange");
+    }
+}
+
+mod serialization {
+    use core::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn event_defaults_round_trip() {
+        let mut config = Config::default();
+        config.defaults.event.max_listeners = 7;
+        config.defaults.event.max_notifiers = 11;
+        config.defaults.event.max_nodes = 13;
+        config.defaults.event.event_id_max_value = 255;
+        config.defaults.event.deadline = Some(Duration::from_millis(1500));
+        config.defaults.event.notifier_created_event = Some(1);
+        config.defaults.event.notifier_dropped_event = Some(2);
+        config.defaults.event.notifier_dead_event = Some(3);
+
+        let serialized = toml::to_string(&config).unwrap();
+        assert_that!(serialized.contains("[defaults.event]"), eq true);
+
+        let deserialized: Config = toml::from_str(&serialized).unwrap();
+        assert_that!(deserialized.defaults.event, eq config.defaults.event);
+        assert_that!(deserialized, eq config);
+    }
+}
+
+mod env_overrides {
+    use core::time::Duration;
+    use std::sync::Mutex;
+
+    use iceoryx2::config::{ConfigCreationError, ConfigSource};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2::service::static_config::StaticConfigFormat;
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+    use iceoryx2_bb_testing::assert_that;
+
+    // environment variables are shared by all tests of the process
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn with_env_vars<F: FnOnce()>(vars: &[(&str, &str)], test: F) {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        for (name, value) in vars {
+            std::env::set_var(name, value);
+        }
+
+        test();
+
+        for (name, _) in vars {
+            std::env::remove_var(name);
+        }
+    }
+
+    #[test]
+    fn config_without_env_vars_is_unchanged() {
+        with_env_vars(&[], || {
+            let sut = Config::default().with_env_overrides().unwrap();
+            assert_that!(sut, eq Config::default());
+        });
+    }
+
+    #[test]
+    fn env_vars_override_config_settings() {
+        with_env_vars(
+            &[
+                ("IOX2_PUBSUB_MAX_SUBSCRIBERS", "91"),
+                ("IOX2_PUBSUB_ENABLE_SAFE_OVERFLOW", "false"),
+                ("IOX2_PUBSUB_UNABLE_TO_DELIVER_STRATEGY", "DiscardSample"),
+                ("IOX2_EVENT_DEADLINE", "250"),
+                ("IOX2_EVENT_NOTIFIER_DEAD_EVENT", "12"),
+                ("IOX2_SERVICE_CREATION_TIMEOUT", "1500"),
+                ("IOX2_SERVICE_DYNAMIC_CONFIG_OPEN_RETRIES", "9"),
+                ("IOX2_PUBSUB_MAX_SAMPLE_SLOTS", "512"),
+                ("IOX2_NODE_REAP_DEAD_PORTS_ON_WAIT", "true"),
+                ("IOX2_NODE_REAP_DEAD_PORTS_INTERVAL", "200"),
+                ("IOX2_SERVICE_CLEANUP_DEAD_PORTS_ON_OPEN", "false"),
+            ],
+            || {
+                let mut config = Config::default();
+                config.defaults.publish_subscribe.max_publishers = 7;
+
+                let sut = config.with_env_overrides().unwrap();
+
+                assert_that!(sut.defaults.publish_subscribe.max_subscribers, eq 91);
+                assert_that!(sut.defaults.publish_subscribe.max_publishers, eq 7);
+                assert_that!(sut.defaults.publish_subscribe.enable_safe_overflow, eq false);
+                assert_that!(sut.defaults.publish_subscribe.unable_to_deliver_strategy, eq UnableToDeliverStrategy::DiscardSample);
+                assert_that!(sut.defaults.event.deadline, eq Some(Duration::from_millis(250)));
+                assert_that!(sut.defaults.event.notifier_dead_event, eq Some(12));
+                assert_that!(sut.global.service.creation_timeout, eq Duration::from_millis(1500));
+                assert_that!(sut.global.service.dynamic_config_open_retries, eq 9);
+                assert_that!(sut.defaults.publish_subscribe.max_sample_slots, eq 512);
+                assert_that!(sut.global.node.reap_dead_ports_on_wait, eq true);
+                assert_that!(sut.global.node.reap_dead_ports_interval, eq Duration::from_millis(200));
+                assert_that!(sut.global.service.cleanup_dead_ports_on_open, eq false);
+            },
+        );
+    }
+
+    #[test]
+    fn env_var_overrides_are_reported_in_config_provenance() {
+        with_env_vars(&[("IOX2_PUBSUB_MAX_SUBSCRIBERS", "5")], || {
+            let config = generate_isolated_config().with_env_overrides().unwrap();
+            let node = NodeBuilder::new()
+                .config(&config)
+                .create::<ipc::Service>()
+                .unwrap();
+            let service_name = ServiceName::new(&format!(
+                "config_tests_{}",
+                UniqueSystemId::new().unwrap().value()
+            ))
+            .unwrap();
+
+            let sut = node
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .max_publishers(2)
+                .create()
+                .unwrap();
+
+            assert_that!(sut.static_config().max_subscribers(), eq 5);
+            assert_that!(sut.config_provenance().max_subscribers(), eq ConfigSource::EnvironmentVariable);
+            assert_that!(sut.config_provenance().max_publishers(), eq ConfigSource::Builder);
+            assert_that!(sut.config_provenance().max_nodes(), eq ConfigSource::BuiltInDefault);
+        });
+    }
+
+    #[test]
+    fn config_file_settings_are_reported_in_config_provenance() {
+        with_env_vars(&[("IOX2_PUBSUB_MAX_NODES", "4")], || {
+            let file_path = std::env::temp_dir().join(format!(
+                "config_tests_{}.toml",
+                UniqueSystemId::new().unwrap().value()
+            ));
+            std::fs::write(
+                &file_path,
+                toml::to_string(&generate_isolated_config()).unwrap(),
+            )
+            .unwrap();
+            let config =
+                Config::from_file(&FilePath::new(file_path.to_str().unwrap().as_bytes()).unwrap());
+            std::fs::remove_file(&file_path).unwrap();
+            let config = config.unwrap().with_env_overrides().unwrap();
+
+            let node = NodeBuilder::new()
+                .config(&config)
+                .create::<ipc::Service>()
+                .unwrap();
+            let service_name = ServiceName::new(&format!(
+                "config_tests_{}",
+                UniqueSystemId::new().unwrap().value()
+            ))
+            .unwrap();
+
+            let sut = node
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .max_publishers(2)
+                .create()
+                .unwrap();
+
+            assert_that!(sut.config_provenance().max_subscribers(), eq ConfigSource::ConfigFile);
+            assert_that!(sut.config_provenance().history_size(), eq ConfigSource::ConfigFile);
+            assert_that!(sut.config_provenance().max_publishers(), eq ConfigSource::Builder);
+            assert_that!(sut.config_provenance().max_nodes(), eq ConfigSource::EnvironmentVariable);
+        });
+    }
+
+    #[test]
+    fn env_var_can_unset_optional_setting() {
+        with_env_vars(&[("IOX2_EVENT_NOTIFIER_CREATED_EVENT", "none")], || {
+            let mut config = Config::default();
+            config.defaults.event.notifier_created_event = Some(3);
+
+            let sut = config.with_env_overrides().unwrap();
+
+            assert_that!(sut.defaults.event.notifier_created_event, eq None);
+        });
+    }
+
+    #[test]
+    fn env_var_overrides_case_insensitive_names() {
+        with_env_vars(&[("IOX2_SERVICE_CASE_INSENSITIVE_NAMES", "true")], || {
+            let sut = Config::default().with_env_overrides().unwrap();
+
+            assert_that!(sut.global.service.case_insensitive_names, eq true);
+        });
+
+        with_env_vars(&[("IOX2_SERVICE_CASE_INSENSITIVE_NAMES", "yes")], || {
+            let sut = Config::default().with_env_overrides();
+
+            assert_that!(sut.err(), eq Some(ConfigCreationError::MalformedEnvironmentVariable));
+        });
+    }
+
+    #[test]
+    fn env_var_overrides_static_config_format() {
+        with_env_vars(&[("IOX2_SERVICE_STATIC_CONFIG_FORMAT", "Json")], || {
+            let sut = Config::default().with_env_overrides().unwrap();
+
+            assert_that!(sut.global.service.static_config_format, eq StaticConfigFormat::Json);
+        });
+
+        with_env_vars(&[("IOX2_SERVICE_STATIC_CONFIG_FORMAT", "Yaml")], || {
+            let sut = Config::default().with_env_overrides();
+
+            assert_that!(sut.err(), eq Some(ConfigCreationError::MalformedEnvironmentVariable));
+        });
+    }
+
+    #[test]
+    fn env_var_overrides_cleanup_dead_ports_on_open() {
+        with_env_vars(
+            &[("IOX2_SERVICE_CLEANUP_DEAD_PORTS_ON_OPEN", "false")],
+            || {
+                let sut = Config::default().with_env_overrides().unwrap();
+
+                assert_that!(sut.global.service.cleanup_dead_ports_on_open, eq false);
+            },
+        );
+
+        with_env_vars(&[("IOX2_SERVICE_CLEANUP_DEAD_PORTS_ON_OPEN", "0")], || {
+            let sut = Config::default().with_env_overrides();
+
+            assert_that!(sut.err(), eq Some(ConfigCreationError::MalformedEnvironmentVariable));
+        });
+    }
+
+    #[test]
+    fn env_var_with_malformed_value_fails() {
+        for (name, value) in [
+            ("IOX2_PUBSUB_MAX_SUBSCRIBERS", "many"),
+            ("IOX2_PUBSUB_MAX_SUBSCRIBERS", "-1"),
+            ("IOX2_REQRES_ENABLE_SAFE_OVERFLOW_FOR_REQUESTS", "yes"),
+            ("IOX2_PUBSUB_UNABLE_TO_DELIVER_STRATEGY", "Drop"),
+            ("IOX2_EVENT_DEADLINE", "1s"),
+        ] {
+            with_env_vars(&[(name, value)], || {
+                let sut = Config::default().with_env_overrides();
+
+                assert_that!(sut, is_err);
+                assert_that!(sut.err().unwrap(), eq ConfigCreationError::MalformedEnvironmentVariable);
+            });
+        }
+    }
+}
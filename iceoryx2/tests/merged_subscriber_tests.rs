@@ -0,0 +1,196 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod merged_subscriber {
+    use iceoryx2::port::merged_subscriber::MergedSubscriber;
+    use iceoryx2::port::subscriber::SubscriberReceiveError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "merged_subscriber_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn create_service<Sut: Service>(
+        node: &Node<Sut>,
+        max_borrowed_samples: usize,
+    ) -> PortFactory<Sut, u64, ()> {
+        node.service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(8)
+            .subscriber_max_borrowed_samples(max_borrowed_samples)
+            .create()
+            .unwrap()
+    }
+
+    #[test]
+    fn merged_subscriber_without_subscribers_receives_nothing<Sut: Service>() {
+        let sut = MergedSubscriber::<Sut, u64, ()>::new(vec![]);
+
+        assert_that!(sut.has_samples().unwrap(), eq false);
+        assert_that!(sut.receive().unwrap(), is_none);
+        assert_that!(sut.subscribers(), len 0);
+    }
+
+    #[test]
+    fn merged_subscriber_receives_from_all_subscribers<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_a = create_service(&node, 8);
+        let service_b = create_service(&node, 8);
+
+        let sut = MergedSubscriber::new(vec![
+            service_a.subscriber_builder().create().unwrap(),
+            service_b.subscriber_builder().create().unwrap(),
+        ]);
+        let publisher_a = service_a.publisher_builder().create().unwrap();
+        let publisher_b = service_b.publisher_builder().create().unwrap();
+
+        assert_that!(sut.has_samples().unwrap(), eq false);
+
+        publisher_a.send_copy(1).unwrap();
+        publisher_b.send_copy(2).unwrap();
+
+        assert_that!(sut.has_samples().unwrap(), eq true);
+
+        let mut received = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            received.push(*sample);
+        }
+        received.sort();
+
+        assert_that!(received, eq vec![1, 2]);
+        assert_that!(sut.has_samples().unwrap(), eq false);
+    }
+
+    #[test]
+    fn merged_subscriber_does_not_starve_any_subscriber<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 4;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_a = create_service(&node, 8);
+        let service_b = create_service(&node, 8);
+
+        let sut = MergedSubscriber::new(vec![
+            service_a.subscriber_builder().create().unwrap(),
+            service_b.subscriber_builder().create().unwrap(),
+        ]);
+        let publisher_a = service_a.publisher_builder().create().unwrap();
+        let publisher_b = service_b.publisher_builder().create().unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            publisher_a.send_copy(n).unwrap();
+            publisher_b.send_copy(100 + n).unwrap();
+        }
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            let sample_a = sut.receive().unwrap().unwrap();
+            let sample_b = sut.receive().unwrap().unwrap();
+            assert_that!(*sample_a, eq n);
+            assert_that!(*sample_b, eq 100 + n);
+        }
+
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn merged_subscriber_respects_borrow_limit_of_each_subscriber<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_a = create_service(&node, 1);
+        let service_b = create_service(&node, 8);
+
+        let sut = MergedSubscriber::new(vec![
+            service_a.subscriber_builder().create().unwrap(),
+            service_b.subscriber_builder().create().unwrap(),
+        ]);
+        let publisher_a = service_a.publisher_builder().create().unwrap();
+        let publisher_b = service_b.publisher_builder().create().unwrap();
+
+        publisher_a.send_copy(1).unwrap();
+        publisher_a.send_copy(2).unwrap();
+        publisher_b.send_copy(3).unwrap();
+        publisher_b.send_copy(4).unwrap();
+
+        let sample_1 = sut.receive().unwrap().unwrap();
+        assert_that!(*sample_1, eq 1);
+
+        // the first subscriber has reached its borrow limit, the second one still delivers
+        let sample_3 = sut.receive().unwrap().unwrap();
+        assert_that!(*sample_3, eq 3);
+        let sample_4 = sut.receive().unwrap().unwrap();
+        assert_that!(*sample_4, eq 4);
+
+        let result = sut.receive();
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq SubscriberReceiveError::ExceedsMaxBorrowedSamples);
+
+        drop(sample_1);
+
+        let sample_2 = sut.receive().unwrap().unwrap();
+        assert_that!(*sample_2, eq 2);
+    }
+
+    #[test]
+    fn merged_subscriber_works_with_slices<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_a = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+        let service_b = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let sut = MergedSubscriber::new(vec![
+            service_a.subscriber_builder().create().unwrap(),
+            service_b.subscriber_builder().create().unwrap(),
+        ]);
+        let publisher_b = service_b
+            .publisher_builder()
+            .initial_max_slice_len(3)
+            .create()
+            .unwrap();
+
+        publisher_b
+            .loan_slice_uninit(3)
+            .unwrap()
+            .write_from_fn(|n| n as u64)
+            .send()
+            .unwrap();
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq [0, 1, 2]);
+        assert_that!(sut.receive().unwrap(), is_none);
+
+        let subscribers = sut.into_subscribers();
+        assert_that!(subscribers, len 2);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -17,6 +17,7 @@ mod node_death_tests {
     use iceoryx2::config::Config;
     use iceoryx2::node::testing::__internal_node_staged_death;
     use iceoryx2::node::{CleanupState, NodeState};
+    use iceoryx2::port::server::ResponseLoanError;
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
@@ -270,6 +271,50 @@ mod node_death_tests {
         }
     }
 
+    #[test]
+    fn dead_node_is_removed_from_request_response_service<S: Test>() {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_service_name();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let good_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let mut bad_node = S::create_test_node(&config).node;
+
+        let service = good_node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let bad_service = bad_node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open()
+            .unwrap();
+        let bad_client = bad_service.client_builder().create().unwrap();
+        let bad_pending_response = bad_client.send_copy(123).unwrap();
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.is_connected(), eq true);
+
+        S::staged_death(&mut bad_node);
+        core::mem::forget(bad_pending_response);
+        core::mem::forget(bad_client);
+        core::mem::forget(bad_service);
+
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(&config), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+        assert_that!(service.dynamic_config().number_of_clients(), eq 0);
+
+        assert_that!(*active_request, eq 123);
+        assert_that!(active_request.is_connected(), eq false);
+        assert_that!(active_request.loan().err(), eq Some(ResponseLoanError::ClientDisconnected));
+    }
+
     #[test]
     fn notifier_of_dead_node_emits_death_event_when_configured<S: Test>() {
         let _watchdog = Watchdog::new();
@@ -379,6 +424,39 @@ mod node_death_tests {
         );
     }
 
+    #[test]
+    fn service_of_dead_node_can_be_destroyed<S: Test>() {
+        let service_name = generate_service_name();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let mut sut = S::create_test_node(&config).node;
+        core::mem::forget(
+            sut.service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .open_or_create()
+                .unwrap(),
+        );
+        S::staged_death(&mut sut);
+
+        assert_that!(
+            S::Service::destroy(&service_name, &config, MessagingPattern::PublishSubscribe),
+            is_ok
+        );
+        assert_that!(S::Service::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        assert_that!(
+            node.service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .create(),
+            is_ok
+        );
+    }
+
     #[test]
     fn node_cleanup_option_works_on_node_creation<S: Test>() {
         let mut config = generate_isolated_config();
@@ -0,0 +1,54 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// The signal handler is a process-wide resource, therefore this file contains only a single
+// test so that no other test can consume the raised signals.
+mod node_termination_signal {
+    use core::time::Duration;
+
+    use iceoryx2::node::NodeWaitFailure;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::process::Process;
+    use iceoryx2_bb_posix::signal::{NonFatalFetchableSignal, Signal};
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_millis(10);
+
+    #[test]
+    fn wait_returns_termination_request_only_for_configured_signals() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .termination_signals(&[NonFatalFetchableSignal::UserDefined1])
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+
+        // installs the signal handler
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+
+        Process::from_self().send_signal(Signal::Interrupt).unwrap();
+        let result = node.wait(TIMEOUT);
+        assert_that!(result.err(), ne Some(NodeWaitFailure::TerminationRequest));
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+
+        Process::from_self()
+            .send_signal(Signal::UserDefined1)
+            .unwrap();
+        let result = node.wait(TIMEOUT);
+        assert_that!(result.err(), eq Some(NodeWaitFailure::TerminationRequest));
+
+        // the termination request is not consumed by the wait
+        let result = node.wait(TIMEOUT);
+        assert_that!(result.err(), eq Some(NodeWaitFailure::TerminationRequest));
+    }
+}
@@ -23,6 +23,8 @@ mod node {
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::clock::{Time, TimeBuilder};
+    use iceoryx2_bb_posix::signal::NonFatalFetchableSignal;
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
     use iceoryx2_bb_system_types::path::*;
     use iceoryx2_bb_testing::watchdog::Watchdog;
@@ -395,6 +397,61 @@ mod node {
         assert_that!(node.signal_handling_mode(), eq SignalHandlingMode::HandleTerminationRequests);
     }
 
+    #[test]
+    fn termination_signals_can_be_configured<S: Service>() {
+        let config = generate_isolated_config();
+        let node_1 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let node_2 = NodeBuilder::new()
+            .termination_signals(&[NonFatalFetchableSignal::UserDefined1])
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(
+            node_1.termination_signals(),
+            eq & [
+                NonFatalFetchableSignal::Interrupt,
+                NonFatalFetchableSignal::Terminate
+            ]
+        );
+        assert_that!(
+            node_2.termination_signals(),
+            eq & [NonFatalFetchableSignal::UserDefined1]
+        );
+    }
+
+    #[test]
+    fn wait_until_returns_immediately_when_deadline_has_passed<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let start = std::time::Instant::now();
+        assert_that!(node.wait_until(Time::default()), is_ok);
+        let past_deadline = Time::now().unwrap();
+        assert_that!(node.wait_until(past_deadline), is_ok);
+        assert_that!(start.elapsed(), lt Duration::from_secs(1));
+    }
+
+    #[test]
+    fn wait_until_waits_until_deadline_is_reached<S: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(50);
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let now = Time::now().unwrap();
+        let deadline = now.as_duration() + TIMEOUT;
+        let deadline = TimeBuilder::new()
+            .clock_type(now.clock_type())
+            .seconds(deadline.as_secs())
+            .nanoseconds(deadline.subsec_nanos())
+            .create();
+
+        assert_that!(node.wait_until(deadline), is_ok);
+        assert_that!(Time::now().unwrap().as_duration(), ge deadline.as_duration());
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
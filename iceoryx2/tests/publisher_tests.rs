@@ -12,22 +12,30 @@
 
 #[generic_tests::define]
 mod publisher {
+    use core::mem::ManuallyDrop;
+    use core::sync::atomic::{fence, Ordering};
     use core::time::Duration;
     use std::collections::HashSet;
     use std::sync::Mutex;
     use std::time::Instant;
 
-    use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError};
+    use iceoryx2::port::publisher::{
+        BatchLoanPolicy, Publisher, PublisherCreateError, PublisherFlushError, PublisherLoanError,
+        SendStatus,
+    };
+    use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::publish_subscribe::CustomPayloadMarker;
+    use iceoryx2::service::dynamic_config::publish_subscribe::MAX_PORT_NODE_NAME_LENGTH;
     use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::service::{service_name::ServiceName, Service};
     use iceoryx2::testing::*;
     use iceoryx2_bb_posix::barrier::*;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
-    use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_bb_testing::{assert_that, test_fail};
+    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
 
     type TestResult<T> = core::result::Result<T, Box<dyn std::error::Error>>;
 
@@ -289,7 +297,243 @@ mod publisher {
     }
 
     #[test]
-    fn publisher_block_when_unable_to_deliver_blocks<Sut: Service>() -> TestResult<()> {
+    fn publisher_try_loan_does_not_block_when_no_sample_can_be_loaned<Sut: Service>(
+    ) -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(2)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+
+        let _sample1 = sut.try_loan()?;
+        let _sample2 = sut.try_loan_uninit()?;
+
+        let sample3 = sut.try_loan();
+        assert_that!(sample3, is_err);
+        assert_that!(sample3.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+        let sample3 = sut.try_loan_uninit();
+        assert_that!(sample3, is_err);
+        assert_that!(sample3.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_try_loan_slice_does_not_block_when_no_sample_can_be_loaned<Sut: Service>(
+    ) -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .max_loaned_samples(1)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+
+        let _sample1 = sut.try_loan_slice(4)?;
+
+        let sample2 = sut.try_loan_slice(4);
+        assert_that!(sample2, is_err);
+        assert_that!(sample2.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+        let sample2 = sut.try_loan_slice_uninit(4);
+        assert_that!(sample2, is_err);
+        assert_that!(sample2.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_try_loan_does_not_block_when_every_sample_is_in_use<Sut: Service>(
+    ) -> TestResult<()> {
+        const BUFFER_SIZE: usize = 2;
+        const MAX_LOANED_SAMPLES: usize = 3;
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(1)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(1)
+            .history_size(0)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        // occupy every sample of the data segment: the borrowed one, the ones in the
+        // subscriber buffer and the loaned ones. The data segment is sized for exactly this
+        // worst case, therefore the loan limit is reached together with the last free sample.
+        sut.try_loan()?.send()?;
+        let borrowed_sample = subscriber.receive()?.unwrap();
+        for _ in 0..BUFFER_SIZE {
+            sut.try_loan()?.send()?;
+        }
+
+        let mut loaned_samples = vec![];
+        for _ in 0..MAX_LOANED_SAMPLES {
+            loaned_samples.push(sut.try_loan()?);
+        }
+
+        let sample = sut.try_loan();
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+        let sample = sut.try_loan_uninit();
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+        drop(borrowed_sample);
+        drop(loaned_samples);
+
+        let sample = sut.try_loan();
+        assert_that!(sample, is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_try_loan_slice_does_not_block_when_every_sample_is_in_use<Sut: Service>(
+    ) -> TestResult<()> {
+        const BUFFER_SIZE: usize = 2;
+        const MAX_LOANED_SAMPLES: usize = 3;
+        const SLICE_LEN: usize = 4;
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .max_subscribers(1)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(1)
+            .history_size(0)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_LEN)
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.try_loan_slice(SLICE_LEN)?.send()?;
+        let borrowed_sample = subscriber.receive()?.unwrap();
+        for _ in 0..BUFFER_SIZE {
+            sut.try_loan_slice(SLICE_LEN)?.send()?;
+        }
+
+        let mut loaned_samples = vec![];
+        for _ in 0..MAX_LOANED_SAMPLES {
+            loaned_samples.push(sut.try_loan_slice(SLICE_LEN)?);
+        }
+
+        let sample = sut.try_loan_slice(SLICE_LEN);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+        let sample = sut.try_loan_slice_uninit(SLICE_LEN);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+        drop(borrowed_sample);
+        drop(loaned_samples);
+
+        let sample = sut.try_loan_slice(SLICE_LEN);
+        assert_that!(sample, is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_try_loan_fails_with_out_of_memory_when_the_data_segment_runs_dry<Sut: Service>(
+    ) -> TestResult<()> {
+        const DEADLINE: Duration = Duration::from_millis(1);
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(1)
+            .subscriber_max_buffer_size(1)
+            .subscriber_max_borrowed_samples(1)
+            .history_size(0)
+            .enable_safe_overflow(false)
+            .slow_subscriber_eviction(DEADLINE, 1)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(1)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+
+        // the evicted subscriber keeps its borrowed and its buffered sample while a new
+        // subscriber takes its place, so more samples are in use than the data segment was
+        // sized for
+        let evicted_subscriber = service.subscriber_builder().create()?;
+        assert_that!(sut.send_copy(1), eq Ok(1));
+        let borrowed_sample = evicted_subscriber.receive()?.unwrap();
+        assert_that!(sut.send_copy(2), eq Ok(1));
+        assert_that!(sut.send_copy(3), eq Ok(0));
+
+        let subscriber = service.subscriber_builder().create()?;
+        assert_that!(sut.send_copy(4), eq Ok(1));
+
+        let start = Instant::now();
+        let sample = sut.try_loan();
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq PublisherLoanError::OutOfMemory);
+
+        let sample = sut.try_loan_uninit();
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq PublisherLoanError::OutOfMemory);
+        assert_that!(start.elapsed(), lt TIMEOUT);
+
+        drop(borrowed_sample);
+        drop(evicted_subscriber);
+
+        let sample = sut.try_loan();
+        assert_that!(sample, is_ok);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_blocks_until_the_subscriber_holding_the_samples_is_removed<Sut: Service>(
+    ) -> TestResult<()> {
+        const DEADLINE: Duration = Duration::from_millis(1);
         let _watchdog = Watchdog::new();
         let service_name = generate_name()?;
         let config = generate_isolated_config();
@@ -299,15 +543,25 @@ mod publisher {
             .unwrap()
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
+            .max_subscribers(1)
             .subscriber_max_buffer_size(1)
+            .subscriber_max_borrowed_samples(1)
+            .history_size(0)
             .enable_safe_overflow(false)
+            .slow_subscriber_eviction(DEADLINE, 1)
             .create()?;
 
         let sut = service
             .publisher_builder()
+            .max_loaned_samples(1)
             .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
             .create()?;
-        assert_that!(sut.unable_to_deliver_strategy(), eq UnableToDeliverStrategy::Block);
+
+        let evicted_subscriber = service.subscriber_builder().create()?;
+        assert_that!(sut.send_copy(1), eq Ok(1));
+        let _borrowed_sample = evicted_subscriber.receive()?.unwrap();
+        assert_that!(sut.send_copy(2), eq Ok(1));
+        assert_that!(sut.send_copy(3), eq Ok(0));
 
         let handle = BarrierHandle::new();
         let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
@@ -319,152 +573,1390 @@ mod publisher {
                     .unwrap()
                     .service_builder(&service_name)
                     .publish_subscribe::<u64>()
-                    .subscriber_max_buffer_size(1)
                     .open()
                     .unwrap();
-
                 let subscriber = service.subscriber_builder().create().unwrap();
-                let receive_sample = || loop {
-                    if let Some(sample) = subscriber.receive().unwrap() {
-                        return sample;
-                    }
-                };
 
+                barrier.wait();
                 barrier.wait();
                 std::thread::sleep(TIMEOUT);
-                let sample_1 = receive_sample();
-                std::thread::sleep(TIMEOUT);
-                let sample_2 = receive_sample();
-
-                assert_that!(*sample_1, eq 8192);
-                assert_that!(*sample_2, eq 2);
+                // the sample in the buffer is never returned, the publisher reacquires it
+                // when it removes the connection to the gone subscriber
+                drop(subscriber);
             });
 
             barrier.wait();
-            let now = Instant::now();
-            sut.send_copy(8192).unwrap();
-            sut.send_copy(2).unwrap();
-            assert_that!(now.elapsed(), time_at_least TIMEOUT);
+            assert_that!(sut.send_copy(4), eq Ok(1));
+            assert_that!(sut.try_loan().err(), eq Some(PublisherLoanError::OutOfMemory));
+            barrier.wait();
+
+            let start = Instant::now();
+            let sample = sut.loan();
+            assert_that!(sample, is_ok);
+            assert_that!(start.elapsed(), time_at_least TIMEOUT);
         });
 
         Ok(())
     }
 
     #[test]
-    fn create_error_display_works<S: Service>() {
-        assert_that!(
-            format!("{}", PublisherCreateError::ExceedsMaxSupportedPublishers), eq "PublisherCreateError::ExceedsMaxSupportedPublishers");
-        assert_that!(
-            format!("{}", PublisherCreateError::UnableToCreateDataSegment), eq "PublisherCreateError::UnableToCreateDataSegment");
-    }
-
-    #[test]
-    fn loan_error_display_works<S: Service>() {
-        assert_that!(
-            format!("{}", PublisherLoanError::OutOfMemory), eq "PublisherLoanError::OutOfMemory");
-        assert_that!(
-            format!("{}", PublisherLoanError::ExceedsMaxLoanedSamples), eq "PublisherLoanError::ExceedsMaxLoanedSamples");
-        assert_that!(
-            format!("{}", PublisherLoanError::ExceedsMaxLoanSize), eq "PublisherLoanError::ExceedsMaxLoanSize");
-        assert_that!(
-            format!("{}", PublisherLoanError::InternalFailure), eq "PublisherLoanError::InternalFailure");
-    }
-
-    #[test]
-    fn id_is_unique<Sut: Service>() {
-        let service_name = generate_name().unwrap();
+    fn publisher_send_all_sends_batch_larger_than_the_data_segment<Sut: Service>() -> TestResult<()>
+    {
+        const BATCH_SIZE: u64 = 64;
+        let service_name = generate_name()?;
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const MAX_PUBLISHERS: usize = 8;
-
-        let sut = node
+        let service = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_publishers(MAX_PUBLISHERS)
-            .create()
-            .unwrap();
+            .subscriber_max_buffer_size(2)
+            .create()?;
 
-        let mut publishers = vec![];
-        let mut publisher_id_set = HashSet::new();
+        let sut = service.publisher_builder().max_loaned_samples(1).create()?;
+        let subscriber = service.subscriber_builder().create()?;
 
-        for _ in 0..MAX_PUBLISHERS {
-            let publisher = sut.publisher_builder().create().unwrap();
-            assert_that!(publisher_id_set.insert(publisher.id()), eq true);
-            publishers.push(publisher);
-        }
+        assert_that!(sut.send_all(0..BATCH_SIZE)?, eq BATCH_SIZE as usize);
+
+        // the safe overflow keeps only the latest samples
+        assert_that!(*subscriber.receive()?.unwrap(), eq BATCH_SIZE - 2);
+        assert_that!(*subscriber.receive()?.unwrap(), eq BATCH_SIZE - 1);
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
     }
 
     #[test]
-    fn publisher_with_custom_payload_details_adjusts_slice_len<Sut: Service>() -> TestResult<()> {
-        const TYPE_SIZE_OVERRIDE: usize = 128;
+    fn publisher_send_all_stops_when_no_further_sample_can_be_loaned<Sut: Service>(
+    ) -> TestResult<()> {
+        const MAX_LOANED_SAMPLES: usize = 3;
         let service_name = generate_name()?;
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut type_detail = TypeDetail::__internal_new::<u8>(TypeVariant::FixedSize);
-        type_detail.size = TYPE_SIZE_OVERRIDE;
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(16)
+            .create()?;
 
-        let service = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&type_detail)
-                .create()?
-        };
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
 
-        let sut = service.publisher_builder().create()?;
+        // another part of the producer loans every sample while the batch is sent
+        let mut loaned_samples = vec![];
+        let batch = (0..10u64).inspect(|value| {
+            if *value == 2 {
+                while loaned_samples.len() < MAX_LOANED_SAMPLES {
+                    loaned_samples.push(sut.try_loan_uninit().unwrap());
+                }
+            }
+        });
 
-        let sample = unsafe { sut.loan_custom_payload(1)? };
+        assert_that!(sut.send_all(batch)?, eq 2);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 0);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1);
+        assert_that!(subscriber.receive()?, is_none);
 
-        assert_that!(sample.payload(), len TYPE_SIZE_OVERRIDE);
+        drop(loaned_samples);
+        assert_that!(sut.send_all([5, 6, 7])?, eq 3);
 
         Ok(())
     }
 
     #[test]
-    #[should_panic]
-    #[cfg(debug_assertions)]
-    fn publisher_with_custom_payload_details_panics_when_calling_loan_slice_uninit<Sut: Service>() {
-        const TYPE_SIZE_OVERRIDE: usize = 128;
-        let service_name = generate_name().unwrap();
+    fn publisher_send_batch_delivers_all_samples_in_order<Sut: Service>() -> TestResult<()> {
+        const BATCH_SIZE: usize = 8;
+        let service_name = generate_name()?;
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut type_detail = TypeDetail::__internal_new::<u8>(TypeVariant::FixedSize);
-        type_detail.size = TYPE_SIZE_OVERRIDE;
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BATCH_SIZE)
+            .create()?;
 
-        let service = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&type_detail)
-                .create()
-                .unwrap()
-        };
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(BATCH_SIZE)
+            .create()?;
+        let subscriber_1 = service.subscriber_builder().create()?;
+        let subscriber_2 = service.subscriber_builder().create()?;
 
-        let sut = service.publisher_builder().create().unwrap();
+        let mut samples = sut.loan_batch(BATCH_SIZE, BatchLoanPolicy::WaitForAll)?;
+        assert_that!(samples, len BATCH_SIZE);
+        for (i, sample) in samples.iter_mut().enumerate() {
+            *sample.payload_mut() = i as u64 * 3;
+        }
 
-        // panics here
-        let _sample = sut.loan_slice_uninit(1);
+        assert_that!(sut.send_batch(samples)?, eq 2);
+
+        for subscriber in [&subscriber_1, &subscriber_2] {
+            for i in 0..BATCH_SIZE {
+                assert_that!(*subscriber.receive()?.unwrap(), eq i as u64 * 3);
+            }
+            assert_that!(subscriber.receive()?, is_none);
+        }
+
+        // all loans were returned
+        let samples = sut.loan_batch_uninit(BATCH_SIZE, BatchLoanPolicy::WaitForAll)?;
+        assert_that!(samples, len BATCH_SIZE);
+
+        Ok(())
     }
 
     #[test]
-    #[should_panic]
-    #[cfg(debug_assertions)]
-    fn custom_fixed_size_payload_panics_when_loaning_more_than_one_element<Sut: Service>() {
-        set_log_level(LogLevel::Error);
-        let service_name = generate_name().unwrap();
+    fn publisher_send_batch_with_slow_subscriber_eviction_counts_only_complete_deliveries<
+        Sut: Service,
+    >() -> TestResult<()> {
+        const BATCH_SIZE: usize = 3;
+        const DEADLINE: Duration = Duration::from_millis(1);
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let type_details = TypeDetail::__internal_new::<u8>(TypeVariant::FixedSize);
-
-        let service = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&type_details)
-                .create()
-                .unwrap()
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BATCH_SIZE)
+            .history_size(0)
+            .enable_safe_overflow(false)
+            .slow_subscriber_eviction(DEADLINE, 2)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(BATCH_SIZE)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+        let slow_subscriber = service
+            .subscriber_builder()
+            .buffer_size(BATCH_SIZE - 1)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let mut samples = sut.loan_batch(BATCH_SIZE, BatchLoanPolicy::WaitForAll)?;
+        for (i, sample) in samples.iter_mut().enumerate() {
+            *sample.payload_mut() = i as u64;
+        }
+
+        // the slow subscriber misses the deadline for the last sample of the batch
+        assert_that!(sut.send_batch(samples)?, eq 1);
+
+        for i in 0..BATCH_SIZE as u64 {
+            assert_that!(*subscriber.receive()?.unwrap(), eq i);
+        }
+        assert_that!(subscriber.receive()?, is_none);
+
+        for i in 0..BATCH_SIZE as u64 - 1 {
+            assert_that!(*slow_subscriber.receive()?.unwrap(), eq i);
+        }
+        assert_that!(slow_subscriber.receive()?, is_none);
+        // one missed deadline per batch, the slow subscriber is not evicted yet
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_batch_respects_batch_loan_policy<Sut: Service>() -> TestResult<()> {
+        const MAX_LOANED_SAMPLES: usize = 4;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .create()?;
+
+        let _sample = sut.loan()?;
+
+        let samples = sut.loan_batch(MAX_LOANED_SAMPLES, BatchLoanPolicy::WaitForAll);
+        assert_that!(samples.err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+
+        let samples = sut.loan_batch(MAX_LOANED_SAMPLES, BatchLoanPolicy::AsManyAsAvailable)?;
+        assert_that!(samples, len MAX_LOANED_SAMPLES - 1);
+        drop(samples);
+
+        let samples = sut.loan_batch_uninit(MAX_LOANED_SAMPLES - 1, BatchLoanPolicy::WaitForAll)?;
+        assert_that!(samples, len MAX_LOANED_SAMPLES - 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_send_batch_sends_samples_of_other_publishers_with_them<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let other_publisher = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let samples = vec![
+            sut.loan_uninit()?.write_payload(1),
+            other_publisher.loan_uninit()?.write_payload(2),
+            sut.loan_uninit()?.write_payload(3),
+        ];
+        assert_that!(sut.send_batch(samples)?, eq 1);
+
+        let mut received = vec![];
+        while let Some(sample) = subscriber.receive()? {
+            received.push((*sample, sample.header().publisher_id()));
+        }
+        received.sort_by_key(|(value, _)| *value);
+        assert_that!(received, eq vec![(1, sut.id()), (2, other_publisher.id()), (3, sut.id())]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_poll_send_without_subscribers_delivers_to_nobody<Sut: Service>() -> TestResult<()>
+    {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+
+        let status = sut.loan_uninit()?.write_payload(1).poll_send()?;
+        assert_that!(matches!(status, SendStatus::Delivered(0)), eq true);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_poll_send_returns_sample_when_subscriber_buffer_is_full<Sut: Service>(
+    ) -> TestResult<()> {
+        const BUFFER_SIZE: usize = 2;
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for n in 0..BUFFER_SIZE {
+            let status = sut.loan_uninit()?.write_payload(n as u64).poll_send()?;
+            assert_that!(matches!(status, SendStatus::Delivered(1)), eq true);
+        }
+
+        let status = sut.loan_uninit()?.write_payload(1234).poll_send()?;
+        let sample = match status {
+            SendStatus::Pending {
+                sample,
+                blocked_subscribers,
+            } => {
+                assert_that!(blocked_subscribers, eq 1);
+                sample
+            }
+            SendStatus::Delivered(_) => {
+                test_fail!("the sample shall not be delivered to a full subscriber buffer")
+            }
         };
+        assert_that!(*sample.payload(), eq 1234);
 
-        let sut = service.publisher_builder().create().unwrap();
+        assert_that!(*subscriber.receive()?.unwrap(), eq 0);
 
-        let _sample = unsafe { sut.loan_custom_payload(2) };
+        let status = sample.poll_send()?;
+        assert_that!(matches!(status, SendStatus::Delivered(1)), eq true);
+
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1234);
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_poll_send_delivers_when_safe_overflow_is_enabled<Sut: Service>() -> TestResult<()>
+    {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for n in 0..3 * BUFFER_SIZE {
+            let status = sut.loan_uninit()?.write_payload(n as u64).poll_send()?;
+            assert_that!(matches!(status, SendStatus::Delivered(1)), eq true);
+        }
+
+        for n in 2 * BUFFER_SIZE..3 * BUFFER_SIZE {
+            assert_that!(*subscriber.receive()?.unwrap(), eq n as u64);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publish_fence_makes_writes_visible_before_a_relaxed_signal<Sut: Service>() -> TestResult<()>
+    {
+        const NUMBER_OF_VALUES: usize = 1024;
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+        let sut = service.publisher_builder().create()?;
+
+        // the secondary buffer and the signal are not managed by iceoryx2, the signal is
+        // written and read with relaxed ordering only
+        let shared_buffer: Vec<IoxAtomicU64> = (0..NUMBER_OF_VALUES)
+            .map(|_| IoxAtomicU64::new(0))
+            .collect();
+        let number_of_written_values = IoxAtomicUsize::new(0);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let mut number_of_read_values = 0;
+                while number_of_read_values < NUMBER_OF_VALUES {
+                    let number_of_values = number_of_written_values.load(Ordering::Relaxed);
+                    fence(Ordering::Acquire);
+                    for (index, value) in shared_buffer
+                        .iter()
+                        .enumerate()
+                        .take(number_of_values)
+                        .skip(number_of_read_values)
+                    {
+                        assert_that!(value.load(Ordering::Relaxed), eq 7 * index as u64 + 1);
+                    }
+                    number_of_read_values = number_of_values;
+                }
+            });
+
+            for (index, value) in shared_buffer.iter().enumerate() {
+                value.store(7 * index as u64 + 1, Ordering::Relaxed);
+                sut.publish_fence();
+                number_of_written_values.store(index + 1, Ordering::Relaxed);
+            }
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_block_when_unable_to_deliver_blocks<Sut: Service>() -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+        let service = node
+            .lock()
+            .unwrap()
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+        assert_that!(sut.unable_to_deliver_strategy(), eq UnableToDeliverStrategy::Block);
+
+        let handle = BarrierHandle::new();
+        let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .subscriber_max_buffer_size(1)
+                    .open()
+                    .unwrap();
+
+                let subscriber = service.subscriber_builder().create().unwrap();
+                let receive_sample = || loop {
+                    if let Some(sample) = subscriber.receive().unwrap() {
+                        return sample;
+                    }
+                };
+
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                let sample_1 = receive_sample();
+                std::thread::sleep(TIMEOUT);
+                let sample_2 = receive_sample();
+
+                assert_that!(*sample_1, eq 8192);
+                assert_that!(*sample_2, eq 2);
+            });
+
+            barrier.wait();
+            let now = Instant::now();
+            sut.send_copy(8192).unwrap();
+            sut.send_copy(2).unwrap();
+            assert_that!(now.elapsed(), time_at_least TIMEOUT);
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_flush_without_subscribers_returns_immediately<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        sut.send_copy(1)?;
+
+        assert_that!(sut.flush(Duration::ZERO), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_flush_fails_when_samples_are_not_received<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+        sut.send_copy(1)?;
+        sut.send_copy(2)?;
+
+        let now = Instant::now();
+        assert_that!(sut.flush(TIMEOUT).err(), eq Some(PublisherFlushError::Timeout));
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+
+        assert_that!(subscriber.receive()?, is_some);
+        assert_that!(sut.flush(Duration::ZERO).err(), eq Some(PublisherFlushError::Timeout));
+        assert_that!(subscriber.receive()?, is_some);
+        assert_that!(sut.flush(Duration::ZERO), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_flush_waits_until_slow_subscriber_received_all_samples<Sut: Service>(
+    ) -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+        let service = node
+            .lock()
+            .unwrap()
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let received_samples = Mutex::new(vec![]);
+
+        let handle = BarrierHandle::new();
+        let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open()
+                    .unwrap();
+                let subscriber = service.subscriber_builder().create().unwrap();
+
+                barrier.wait();
+                barrier.wait();
+                for _ in 0..3 {
+                    std::thread::sleep(TIMEOUT);
+                    if let Some(sample) = subscriber.receive().unwrap() {
+                        received_samples.lock().unwrap().push(*sample);
+                    }
+                }
+            });
+
+            barrier.wait();
+            sut.send_copy(1).unwrap();
+            sut.send_copy(2).unwrap();
+            sut.send_copy(3).unwrap();
+            barrier.wait();
+
+            let now = Instant::now();
+            assert_that!(sut.flush(TIMEOUT * 100), is_ok);
+            assert_that!(now.elapsed(), time_at_least TIMEOUT * 3);
+        });
+
+        assert_that!(*received_samples.lock().unwrap(), eq vec![1, 2, 3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_connected_subscribers_is_empty_without_subscribers<Sut: Service>() -> TestResult<()>
+    {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+
+        assert_that!(sut.connected_subscribers(), len 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_connected_subscribers_skips_disconnected_subscribers<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+        let disconnecting_subscriber = service.subscriber_builder().create()?;
+        sut.update_connections()?;
+        assert_that!(sut.connected_subscribers(), len 2);
+
+        drop(disconnecting_subscriber);
+
+        // the connection is not yet removed since the connections were not updated
+        let connected_subscribers = sut.connected_subscribers();
+        assert_that!(connected_subscribers, len 1);
+        assert_that!(connected_subscribers[0].id(), eq subscriber.id());
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_connected_subscribers_reports_free_buffer_slots<Sut: Service>() -> TestResult<()> {
+        const BUFFER_SIZE: usize = 4;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let fast_subscriber = service.subscriber_builder().create()?;
+        let slow_subscriber = service.subscriber_builder().create()?;
+        sut.update_connections()?;
+
+        let free_buffer_slots = |id| {
+            sut.connected_subscribers()
+                .iter()
+                .find(|s| s.id() == id)
+                .map(|s| s.free_buffer_slots())
+        };
+
+        assert_that!(sut.connected_subscribers(), len 2);
+        for subscriber in sut.connected_subscribers() {
+            assert_that!(subscriber.buffer_size(), eq BUFFER_SIZE);
+            assert_that!(subscriber.free_buffer_slots(), eq BUFFER_SIZE);
+        }
+
+        for n in 0..3 {
+            sut.send_copy(n)?;
+        }
+        assert_that!(free_buffer_slots(fast_subscriber.id()), eq Some(BUFFER_SIZE - 3));
+        assert_that!(free_buffer_slots(slow_subscriber.id()), eq Some(BUFFER_SIZE - 3));
+
+        while fast_subscriber.receive()?.is_some() {}
+        assert_that!(free_buffer_slots(fast_subscriber.id()), eq Some(BUFFER_SIZE));
+        assert_that!(free_buffer_slots(slow_subscriber.id()), eq Some(BUFFER_SIZE - 3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_last_acked_sequence_is_none_without_acknowledgment<Sut: Service>() -> TestResult<()>
+    {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(1)?;
+        drop(subscriber.receive()?);
+
+        assert_that!(sut.last_acked_sequence(), is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_last_acked_sequence_tracks_consumed_samples<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_acknowledgment(true)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.last_acked_sequence(), is_none);
+
+        sut.send_copy(1)?;
+        sut.send_copy(2)?;
+        assert_that!(sut.last_acked_sequence(), is_none);
+
+        let first_sample = subscriber.receive()?.unwrap();
+        assert_that!(first_sample.header().sequence_number(), eq 1);
+        assert_that!(sut.last_acked_sequence(), is_none);
+        drop(first_sample);
+        assert_that!(sut.last_acked_sequence(), eq Some(1));
+
+        let second_sample = subscriber.receive()?.unwrap();
+        assert_that!(second_sample.header().sequence_number(), eq 2);
+        drop(second_sample);
+        assert_that!(sut.last_acked_sequence(), eq Some(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_update_retains_only_the_latest_value<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(5)
+            .enable_last_value(true)
+            .create()?;
+
+        assert_that!(service.static_config().has_last_value(), eq true);
+        assert_that!(service.static_config().history_size(), eq 1);
+        assert_that!(service.static_config().subscriber_max_buffer_size(), eq 1);
+        assert_that!(service.static_config().has_safe_overflow(), eq true);
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.update(1)?, eq 1);
+        assert_that!(sut.update(2)?, eq 1);
+        assert_that!(sut.update(3)?, eq 1);
+
+        assert_that!(*subscriber.receive()?.unwrap(), eq 3);
+        assert_that!(subscriber.receive()?, is_none);
+
+        let late_subscriber = service.subscriber_builder().create()?;
+        sut.update_connections()?;
+
+        assert_that!(*late_subscriber.receive()?.unwrap(), eq 3);
+        assert_that!(late_subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_update_delivers_only_the_new_value_to_late_joiners<Sut: Service>() -> TestResult<()>
+    {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_last_value(true)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        assert_that!(sut.update(1)?, eq 0);
+
+        // both join before the update, the replaced value is never delivered to them
+        let late_subscriber = service.subscriber_builder().create()?;
+        let late_subscriber_without_history =
+            service.subscriber_builder().request_history(0).create()?;
+        assert_that!(sut.update(2)?, eq 2);
+        assert_that!(service.dynamic_config().number_of_overflows(), eq 0);
+
+        assert_that!(*late_subscriber.receive()?.unwrap(), eq 2);
+        assert_that!(late_subscriber.receive()?, is_none);
+        assert_that!(*late_subscriber_without_history.receive()?.unwrap(), eq 2);
+        assert_that!(late_subscriber_without_history.receive()?, is_none);
+
+        // a subscriber that joins after the update receives the value with the history
+        let subscriber = service.subscriber_builder().create()?;
+        sut.update_connections()?;
+        assert_that!(*subscriber.receive()?.unwrap(), eq 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_error_display_works<S: Service>() {
+        assert_that!(
+            format!("{}", PublisherCreateError::ExceedsMaxSupportedPublishers), eq "PublisherCreateError::ExceedsMaxSupportedPublishers");
+        assert_that!(
+            format!("{}", PublisherCreateError::UnableToCreateDataSegment), eq "PublisherCreateError::UnableToCreateDataSegment");
+        assert_that!(
+            format!("{}", PublisherCreateError::OutdatedPayloadSchema), eq "PublisherCreateError::OutdatedPayloadSchema");
+        assert_that!(
+            format!("{}", PublisherCreateError::SendTimestampNotSupported), eq "PublisherCreateError::SendTimestampNotSupported");
+    }
+
+    #[test]
+    fn loan_error_display_works<S: Service>() {
+        assert_that!(
+            format!("{}", PublisherLoanError::OutOfMemory), eq "PublisherLoanError::OutOfMemory");
+        assert_that!(
+            format!("{}", PublisherLoanError::ExceedsMaxLoanedSamples), eq "PublisherLoanError::ExceedsMaxLoanedSamples");
+        assert_that!(
+            format!("{}", PublisherLoanError::ExceedsMaxLoanSize), eq "PublisherLoanError::ExceedsMaxLoanSize");
+        assert_that!(
+            format!("{}", PublisherLoanError::InternalFailure), eq "PublisherLoanError::InternalFailure");
+    }
+
+    #[test]
+    fn id_is_unique<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const MAX_PUBLISHERS: usize = 8;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(MAX_PUBLISHERS)
+            .create()
+            .unwrap();
+
+        let mut publishers = vec![];
+        let mut publisher_id_set = HashSet::new();
+
+        for _ in 0..MAX_PUBLISHERS {
+            let publisher = sut.publisher_builder().create().unwrap();
+            assert_that!(publisher_id_set.insert(publisher.id()), eq true);
+            publishers.push(publisher);
+        }
+    }
+
+    #[test]
+    fn publisher_with_custom_payload_details_adjusts_slice_len<Sut: Service>() -> TestResult<()> {
+        const TYPE_SIZE_OVERRIDE: usize = 128;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let mut type_detail = TypeDetail::__internal_new::<u8>(TypeVariant::FixedSize);
+        type_detail.size = TYPE_SIZE_OVERRIDE;
+
+        let service = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&type_detail)
+                .create()?
+        };
+
+        let sut = service.publisher_builder().create()?;
+
+        let sample = unsafe { sut.loan_custom_payload(1)? };
+
+        assert_that!(sample.payload(), len TYPE_SIZE_OVERRIDE);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn publisher_with_custom_payload_details_panics_when_calling_loan_slice_uninit<Sut: Service>() {
+        const TYPE_SIZE_OVERRIDE: usize = 128;
+        let service_name = generate_name().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let mut type_detail = TypeDetail::__internal_new::<u8>(TypeVariant::FixedSize);
+        type_detail.size = TYPE_SIZE_OVERRIDE;
+
+        let service = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&type_detail)
+                .create()
+                .unwrap()
+        };
+
+        let sut = service.publisher_builder().create().unwrap();
+
+        // panics here
+        let _sample = sut.loan_slice_uninit(1);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn custom_fixed_size_payload_panics_when_loaning_more_than_one_element<Sut: Service>() {
+        set_log_level(LogLevel::Error);
+        let service_name = generate_name().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let type_details = TypeDetail::__internal_new::<u8>(TypeVariant::FixedSize);
+
+        let service = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&type_details)
+                .create()
+                .unwrap()
+        };
+
+        let sut = service.publisher_builder().create().unwrap();
+
+        let _sample = unsafe { sut.loan_custom_payload(2) };
+    }
+
+    // the publisher of a dead process is never dropped, its resources are reaped instead
+    fn create_publisher_of_dead_process<S: Service>(
+        service: &iceoryx2::service::port_factory::publish_subscribe::PortFactory<S, u64, ()>,
+    ) -> ManuallyDrop<Publisher<S, u64, ()>> {
+        // a pid beyond the maximum pid of the system, the process can never be alive
+        let fake_port_id =
+            (i32::MAX as u128) | ((UniqueSystemId::new().unwrap().value() >> 32) << 32);
+
+        ManuallyDrop::new(unsafe {
+            service
+                .publisher_builder()
+                .__internal_create_with_custom_port_id(UniqueSystemId::from(fake_port_id))
+                .unwrap()
+        })
+    }
+
+    #[test]
+    fn publisher_of_dead_process_is_reaped<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        create_publisher_of_dead_process(&sut);
+        let _living_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 2);
+
+        assert_that!(sut.reap_dead_ports(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 1);
+
+        assert_that!(sut.reap_dead_ports(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 1);
+        assert_that!(sut.publisher_builder().create(), is_ok);
+    }
+
+    #[test]
+    fn allocated_sample_bytes_of_reaped_publisher_are_released<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = create_publisher_of_dead_process(&sut);
+        core::mem::forget(publisher.loan().unwrap());
+        assert_that!(sut.dynamic_config().allocated_sample_bytes(), gt 0);
+
+        assert_that!(sut.reap_dead_ports(), eq 1);
+        assert_that!(sut.dynamic_config().allocated_sample_bytes(), eq 0);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn publisher_of_process_with_reused_process_id_is_reaped<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        // the pid of the init process which is alive but was started before the own process,
+        // like the port of a crashed process whose pid was reused
+        let fake_port_id = 1 | ((UniqueSystemId::new().unwrap().value() >> 32) << 32);
+        let publisher = unsafe {
+            sut.publisher_builder()
+                .__internal_create_with_custom_port_id(UniqueSystemId::from(fake_port_id))
+                .unwrap()
+        };
+        core::mem::forget(publisher);
+        let _living_publisher = sut.publisher_builder().create().unwrap();
+
+        assert_that!(sut.reap_dead_ports(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 1);
+    }
+
+    #[test]
+    fn publisher_of_dead_process_is_reaped_when_service_is_opened<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        create_publisher_of_dead_process(&sut);
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 1);
+
+        let _opened_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 0);
+    }
+
+    #[test]
+    fn publisher_of_dead_process_is_not_reaped_on_open_when_disabled<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let mut config = generate_isolated_config();
+        config.global.service.cleanup_dead_ports_on_open = false;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        create_publisher_of_dead_process(&sut);
+
+        let _opened_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 1);
+
+        assert_that!(sut.reap_dead_ports(), eq 1);
+    }
+
+    #[test]
+    fn publisher_of_dead_process_is_reaped_on_wait_when_enabled<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let mut config = generate_isolated_config();
+        config.global.node.reap_dead_ports_on_wait = true;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        create_publisher_of_dead_process(&sut);
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 1);
+
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 0);
+    }
+
+    #[test]
+    fn publisher_of_dead_process_is_reaped_at_most_once_per_interval_on_wait<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let mut config = generate_isolated_config();
+        config.global.node.reap_dead_ports_on_wait = true;
+        config.global.node.reap_dead_ports_interval = Duration::from_secs(3600);
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        create_publisher_of_dead_process(&sut);
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 0);
+
+        create_publisher_of_dead_process(&sut);
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 1);
+
+        assert_that!(sut.reap_dead_ports(), eq 1);
+    }
+
+    #[test]
+    fn publisher_of_dead_process_is_not_reaped_on_wait_by_default<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        create_publisher_of_dead_process(&sut);
+
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 1);
+
+        assert_that!(sut.reap_dead_ports(), eq 1);
+    }
+
+    #[test]
+    fn publisher_node_name_is_stored_in_dynamic_config<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher_1 = sut
+            .publisher_builder()
+            .node_name("camera-node")
+            .create()
+            .unwrap();
+        let publisher_2 = sut.publisher_builder().create().unwrap();
+
+        let publishers = sut.dynamic_config().publishers();
+
+        assert_that!(publishers, len 2);
+        for info in publishers {
+            if info.publisher_id() == publisher_1.id() {
+                assert_that!(*info.node_name(), eq b"camera-node");
+            } else {
+                assert_that!(info.publisher_id(), eq publisher_2.id());
+                assert_that!(info.node_name().is_empty(), eq true);
+            }
+        }
+    }
+
+    #[test]
+    fn publisher_node_name_is_truncated_when_too_long<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let long_name = "x".repeat(MAX_PORT_NODE_NAME_LENGTH + 10);
+        let _publisher = sut
+            .publisher_builder()
+            .node_name(&long_name)
+            .create()
+            .unwrap();
+
+        let node_names: Vec<_> = sut
+            .dynamic_config()
+            .publishers()
+            .iter()
+            .map(|info| *info.node_name())
+            .collect();
+
+        assert_that!(node_names, len 1);
+        assert_that!(
+            node_names[0].as_bytes(),
+            eq & long_name.as_bytes()[..MAX_PORT_NODE_NAME_LENGTH]
+        );
+    }
+
+    #[test]
+    fn publisher_fast_path_is_only_active_with_a_single_subscriber<Sut: Service>() -> TestResult<()>
+    {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(4)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        assert_that!(sut.fast_path_active(), eq false);
+
+        let subscriber_1 = service.subscriber_builder().create()?;
+        sut.update_connections()?;
+        assert_that!(sut.fast_path_active(), eq true);
+
+        assert_that!(sut.send_copy(1)?, eq 1);
+        assert_that!(*subscriber_1.receive()?.unwrap(), eq 1);
+
+        let subscriber_2 = service.subscriber_builder().create()?;
+        assert_that!(sut.send_copy(2)?, eq 2);
+        assert_that!(sut.fast_path_active(), eq false);
+        assert_that!(*subscriber_1.receive()?.unwrap(), eq 2);
+        assert_that!(*subscriber_2.receive()?.unwrap(), eq 2);
+
+        drop(subscriber_1);
+        assert_that!(sut.send_copy(3)?, eq 1);
+        assert_that!(sut.fast_path_active(), eq true);
+        assert_that!(*subscriber_2.receive()?.unwrap(), eq 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_fast_path_delivers_to_subscriber_joining_after_loan<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(4)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber_1 = service.subscriber_builder().create()?;
+        sut.update_connections()?;
+        assert_that!(sut.fast_path_active(), eq true);
+
+        let sample = sut.loan_uninit()?;
+        let subscriber_2 = service.subscriber_builder().create()?;
+        assert_that!(sample.write_payload(4).send()?, eq 2);
+
+        assert_that!(*subscriber_1.receive()?.unwrap(), eq 4);
+        assert_that!(*subscriber_2.receive()?.unwrap(), eq 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_fast_path_reclaims_released_samples<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(4)
+            .subscriber_max_buffer_size(1)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for n in 0..64 {
+            assert_that!(sut.send_copy(n)?, eq 1);
+            assert_that!(sut.fast_path_active(), eq true);
+            assert_that!(*subscriber.receive()?.unwrap(), eq n);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_init_policy_can_be_configured<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        assert_that!(sut.loan_init_policy(), eq LoanInitPolicy::Default);
+
+        let sut = service
+            .publisher_builder()
+            .loan_init_policy(LoanInitPolicy::Uninit)
+            .create()?;
+        assert_that!(sut.loan_init_policy(), eq LoanInitPolicy::Uninit);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_default_loan_init_policy_zeroes_uninit_loans<Sut: Service>() -> TestResult<()>
+    {
+        const SLICE_LEN: usize = 8;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_LEN)
+            .max_loaned_samples(1)
+            .create()?;
+
+        for _ in 0..4 {
+            let sample = sut.loan_slice_uninit(SLICE_LEN)?;
+            drop(sample.write_from_fn(|_| u64::MAX));
+            let sample = unsafe { sut.loan_slice_uninit(SLICE_LEN)?.assume_init() };
+            assert_that!(sample.payload().iter().all(|v| *v == 0), eq true);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_zeroed_loan_init_policy_zeroes_all_loans<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .loan_init_policy(LoanInitPolicy::Zeroed)
+            .max_loaned_samples(1)
+            .create()?;
+
+        for _ in 0..4 {
+            drop(sut.loan_uninit()?.write_payload(u64::MAX));
+            let sample = unsafe { sut.loan_uninit()?.assume_init() };
+            assert_that!(*sample.payload(), eq 0);
+            drop(sample);
+
+            let sample = sut.loan()?;
+            assert_that!(*sample.payload(), eq 0);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_returns_zeroed_payload_for_reused_samples<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64; 32]>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(1).create()?;
+
+        for _ in 0..4 {
+            drop(sut.loan_uninit()?.write_payload([u64::MAX; 32]));
+            let sample = sut.loan()?;
+            assert_that!(sample.payload().iter().all(|v| *v == 0), eq true);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_discarding_samples_returns_number_of_recipients_when_buffer_is_full<
+        Sut: Service,
+    >() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(false)
+            .subscriber_max_buffer_size(1)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()?;
+        let full_subscriber = service.subscriber_builder().create()?;
+        assert_that!(sut.send_copy(1), eq Ok(1));
+
+        let subscriber = service.subscriber_builder().create()?;
+        assert_that!(sut.send_copy(2), eq Ok(1));
+        assert_that!(sut.send_copy(3), eq Ok(0));
+
+        assert_that!(*full_subscriber.receive()?.unwrap(), eq 1);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 2);
+
+        Ok(())
     }
 
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
@@ -12,9 +12,10 @@
 
 #[generic_tests::define]
 mod sample_mut {
-    use iceoryx2::port::publisher::{Publisher, PublisherLoanError};
+    use iceoryx2::port::publisher::{Publisher, PublisherLoanError, PublisherSendError};
     use iceoryx2::port::subscriber::Subscriber;
     use iceoryx2::prelude::*;
+    use iceoryx2::sample_mut::WriteSegmentsError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
     use iceoryx2::service::Service;
@@ -93,6 +94,37 @@ mod sample_mut {
         }
     }
 
+    #[test]
+    fn aborted_sample_is_released_and_not_delivered<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+
+        let mut sample_vec = vec![];
+        while let Ok(sample) = test_context.publisher.loan() {
+            sample_vec.push(sample);
+        }
+        assert_that!(sample_vec, len MAX_LOANED_SAMPLES);
+
+        let mut sample = sample_vec.pop().unwrap();
+        *sample.payload_mut() = 981;
+        sample.abort();
+        test_context.publisher.loan_uninit().unwrap().abort();
+
+        let sample = test_context.publisher.loan();
+        assert_that!(sample, is_ok);
+        let loan_result = test_context.publisher.loan();
+        assert_that!(loan_result.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+        drop(sample);
+        for sample in sample_vec.drain(..) {
+            sample.abort();
+        }
+        for _ in 0..MAX_LOANED_SAMPLES {
+            sample_vec.push(test_context.publisher.loan().unwrap());
+        }
+        assert_that!(test_context.subscriber.receive().unwrap(), is_none);
+    }
+
     #[test]
     fn header_tracks_correct_origin<Sut: Service>() {
         let config = generate_isolated_config();
@@ -178,6 +210,359 @@ mod sample_mut {
         assert_that!(service.publisher_builder().create(), is_ok);
     }
 
+    #[test]
+    fn write_segments_concatenates_all_segments<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan_slice(8).unwrap();
+        let result = sample.write_segments(&[&[1, 2], &[], &[3, 4, 5]]);
+        assert_that!(result, eq Ok(5));
+        assert_that!(sample.send(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq [1, 2, 3, 4, 5, 0, 0, 0]);
+    }
+
+    #[test]
+    fn write_segments_fails_when_segments_exceed_payload_size<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .create()
+            .unwrap();
+
+        let mut sample = publisher.loan_slice(4).unwrap();
+        let result = sample.write_segments(&[&[1, 2, 3], &[4, 5]]);
+        assert_that!(result, eq Err(WriteSegmentsError::ExceedsPayloadSize));
+        assert_that!(sample.payload(), eq [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn used_len_is_capped_by_max_message_size_by_default<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8; 32]>()
+            .max_message_size(8)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.loan().unwrap().send(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.used_len(), eq 8);
+    }
+
+    #[test]
+    fn used_len_is_the_payload_size_by_default<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8; 32]>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.loan().unwrap().send(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.used_len(), eq 32);
+    }
+
+    #[test]
+    fn set_used_len_is_received_by_subscriber<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8; 32]>()
+            .max_message_size(8)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan().unwrap();
+        sample.set_used_len(5);
+        assert_that!(sample.header().used_len(), eq 5);
+        assert_that!(sample.send(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.used_len(), eq 5);
+    }
+
+    #[test]
+    fn send_fails_when_used_len_exceeds_max_message_size<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8; 32]>()
+            .max_message_size(8)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan().unwrap();
+        sample.set_used_len(9);
+        assert_that!(sample.send().err(), eq Some(PublisherSendError::ExceedsMaxMessageSize));
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn max_message_size_greater_than_payload_size_is_adjusted<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8; 16]>()
+            .max_message_size(32)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let mut sample = publisher.loan().unwrap();
+        sample.set_used_len(17);
+        assert_that!(sample.send().err(), eq Some(PublisherSendError::ExceedsMaxMessageSize));
+    }
+
+    #[test]
+    fn used_len_of_slice_is_capped_by_slice_size<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u16]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.loan_slice(4).unwrap().send(), is_ok);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.used_len(), eq 8);
+
+        let mut sample = publisher.loan_slice(4).unwrap();
+        sample.set_used_len(9);
+        assert_that!(sample.send().err(), eq Some(PublisherSendError::ExceedsMaxMessageSize));
+    }
+
+    #[test]
+    fn as_bytes_mut_covers_the_whole_payload<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+        const PAYLOAD: u64 = 0x1122334455667788;
+
+        let mut sample = test_context.publisher.loan().unwrap();
+        let bytes = unsafe { sample.as_bytes_mut() };
+        assert_that!(bytes, len core::mem::size_of::<u64>());
+        bytes.copy_from_slice(&PAYLOAD.to_ne_bytes());
+        assert_that!(sample.send(), is_ok);
+
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq PAYLOAD);
+    }
+
+    #[test]
+    fn as_bytes_mut_of_slice_covers_all_elements<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u16]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan_slice(3).unwrap();
+        let bytes = unsafe { sample.as_bytes_mut() };
+        assert_that!(bytes, len 3 * core::mem::size_of::<u16>());
+        bytes.fill(0xff);
+        assert_that!(sample.send(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq [u16::MAX; 3]);
+    }
+
+    #[test]
+    fn write_from_iter_with_enough_elements_initializes_whole_slice<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u16]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_slice_uninit(4).unwrap();
+        let sample = sample.write_from_iter(10..20).unwrap();
+        assert_that!(sample.send(), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq [10, 11, 12, 13]);
+    }
+
+    #[test]
+    fn partially_written_slice_sends_only_used_elements<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u16]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_slice_uninit(6).unwrap();
+        let sut = sample.write_from_iter([7, 8, 9]).unwrap_err();
+        assert_that!(sut.written_len(), eq 3);
+        assert_that!(sut.loaned_len(), eq 6);
+        assert_that!(sut.payload(), eq [7, 8, 9]);
+        assert_that!(sut.send_partial(2), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq [7, 8]);
+        assert_that!(sample.header().number_of_elements(), eq 2);
+        assert_that!(sample.used_len(), eq 2 * core::mem::size_of::<u16>());
+    }
+
+    #[test]
+    fn send_partial_with_more_than_written_elements_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u16]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_slice_uninit(6).unwrap();
+        let sut = sample.write_from_iter([7, 8, 9]).unwrap_err();
+        assert_that!(sut.send_partial(4).err(), eq Some(PublisherSendError::ExceedsMaxMessageSize));
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        // the loan was released
+        assert_that!(publisher.loan_slice_uninit(6), is_ok);
+    }
+
+    #[test]
+    fn indexed_access_of_slice_payload_is_bounds_checked<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u16]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sut = publisher.loan_slice(3).unwrap();
+        assert_that!(sut.len(), eq 3);
+        assert_that!(sut.is_empty(), eq false);
+
+        *sut.get_mut(0).unwrap() = 5;
+        *sut.get_mut(2).unwrap() = 7;
+        assert_that!(sut.get_mut(3), is_none);
+        assert_that!(sut.get(2), eq Some(&7));
+        assert_that!(sut.get(3), is_none);
+        sut.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.get(0), eq Some(&5));
+        assert_that!(sample.get(1), eq Some(&0));
+        assert_that!(sample.get(2), eq Some(&7));
+        assert_that!(sample.get(3), is_none);
+        assert_that!(sample.get(usize::MAX), is_none);
+    }
+
+    #[test]
+    fn indexed_access_of_array_payload_is_bounds_checked<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u32; 4]>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sut = publisher.loan().unwrap();
+        assert_that!(sut.len(), eq 4);
+        assert_that!(sut.is_empty(), eq false);
+
+        *sut.get_mut(1).unwrap() = 11;
+        *sut.get_mut(3).unwrap() = 33;
+        assert_that!(sut.get_mut(4), is_none);
+        assert_that!(sut.get(3), eq Some(&33));
+        assert_that!(sut.get(4), is_none);
+        sut.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.len(), eq 4);
+        assert_that!(sample.is_empty(), eq false);
+        assert_that!(sample.get(0), eq Some(&0));
+        assert_that!(sample.get(1), eq Some(&11));
+        assert_that!(sample.get(3), eq Some(&33));
+        assert_that!(sample.get(4), is_none);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
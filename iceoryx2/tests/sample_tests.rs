@@ -12,10 +12,12 @@
 
 #[generic_tests::define]
 mod sample {
-    use iceoryx2::port::publisher::Publisher;
+    use iceoryx2::port::publisher::{Publisher, PublisherLoanError};
     use iceoryx2::port::subscriber::Subscriber;
     use iceoryx2::prelude::*;
+    use iceoryx2::relative_pointer::{RelativePointer, RelativePointerError};
     use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
+    use iceoryx2::service::port_factory::PortFactory as _;
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
@@ -81,6 +83,324 @@ mod sample {
         assert_that!(sample.origin(), eq test_context.publisher_2.id());
     }
 
+    #[test]
+    fn service_static_config_is_the_one_of_the_source_service<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+        let expected_config = test_context.service.static_config().clone();
+
+        assert_that!(test_context.publisher_1.send_copy(123), eq Ok(1));
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        drop(test_context.service);
+        drop(test_context.subscriber);
+
+        assert_that!(*sample.service_static_config(), eq expected_config);
+        assert_that!(sample.service_static_config().message_type_details(), eq expected_config.message_type_details());
+        assert_that!(sample.service_static_config().max_publishers(), eq 2);
+    }
+
+    #[test]
+    fn as_bytes_contains_the_payload<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+        const PAYLOAD: u64 = 0x1122334455667788;
+
+        assert_that!(test_context.publisher_1.send_copy(PAYLOAD), eq Ok(1));
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        // SAFETY: u64 has no padding bytes
+        assert_that!(unsafe { sample.as_bytes() }, eq PAYLOAD.to_ne_bytes());
+    }
+
+    #[test]
+    fn as_bytes_of_slice_contains_all_elements<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u16]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_slice_copy(&[1, 2, 3]), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+
+        let mut expected = vec![];
+        for value in [1u16, 2, 3] {
+            expected.extend_from_slice(&value.to_ne_bytes());
+        }
+        // SAFETY: u16 has no padding bytes
+        assert_that!(unsafe { sample.as_bytes() }, eq expected.as_slice());
+    }
+
+    #[test]
+    fn clone_into_loan_copies_payload_and_user_header_to_every_destination<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let create_service = || {
+            node.service_builder(&generate_name())
+                .publish_subscribe::<u64>()
+                .user_header::<u32>()
+                .create()
+                .unwrap()
+        };
+        let source = create_service();
+        let destinations = [create_service(), create_service()];
+
+        let publisher = source.publisher_builder().create().unwrap();
+        let subscriber = source.subscriber_builder().create().unwrap();
+        let forwarders: Vec<_> = destinations
+            .iter()
+            .map(|d| d.publisher_builder().create().unwrap())
+            .collect();
+        let receivers: Vec<_> = destinations
+            .iter()
+            .map(|d| d.subscriber_builder().create().unwrap())
+            .collect();
+
+        let mut sample = publisher.loan_uninit().unwrap();
+        *sample.user_header_mut() = 77;
+        sample.write_payload(4711).send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        for forwarder in &forwarders {
+            let forwarded = sample.clone_into_loan(forwarder).unwrap();
+            assert_that!(forwarded.header().publisher_id(), eq forwarder.id());
+            assert_that!(forwarded.send(), eq Ok(1));
+        }
+
+        for receiver in &receivers {
+            let received = receiver.receive().unwrap().unwrap();
+            assert_that!(*received, eq 4711);
+            assert_that!(*received.user_header(), eq 77);
+        }
+    }
+
+    #[test]
+    fn clone_into_loan_copies_slice_and_used_len<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let create_service = || {
+            node.service_builder(&generate_name())
+                .publish_subscribe::<[u8]>()
+                .create()
+                .unwrap()
+        };
+        let source = create_service();
+        let destination = create_service();
+
+        let publisher = source
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()
+            .unwrap();
+        let subscriber = source.subscriber_builder().create().unwrap();
+        let forwarder = destination
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()
+            .unwrap();
+        let too_small_forwarder = destination
+            .publisher_builder()
+            .initial_max_slice_len(2)
+            .create()
+            .unwrap();
+        let receiver = destination.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher
+            .loan_slice_uninit(4)
+            .unwrap()
+            .write_from_slice(b"abcd");
+        sample.set_used_len(3);
+        sample.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.clone_into_loan(&too_small_forwarder).err(), eq Some(PublisherLoanError::ExceedsMaxLoanSize));
+        assert_that!(sample.clone_into_loan(&forwarder).unwrap().send(), eq Ok(1));
+
+        let received = receiver.receive().unwrap().unwrap();
+        assert_that!(received.payload(), eq b"abcd");
+        assert_that!(received.used_len(), eq 3);
+    }
+
+    #[test]
+    fn prefetch_does_not_modify_the_payload<Sut: Service>() {
+        const NUMBER_OF_ELEMENTS: usize = 16384;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let payload: Vec<u8> = (0..NUMBER_OF_ELEMENTS).map(|n| n as u8).collect();
+        assert_that!(publisher.send_slice_copy(&payload), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+
+        sample.prefetch();
+        assert_that!(sample.payload(), eq payload.as_slice());
+    }
+
+    #[test]
+    fn raw_region_covers_the_payload<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+        const PAYLOAD: u64 = 0x1122334455667788;
+
+        assert_that!(test_context.publisher_1.send_copy(PAYLOAD), eq Ok(1));
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        let (address, len) = unsafe { sample.as_raw_region() };
+
+        assert_that!(address, eq sample.payload() as *const u64 as *const u8);
+        assert_that!(len, eq core::mem::size_of::<u64>());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn pin_memory_pins_the_data_segment_of_the_sample<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+
+        assert_that!(test_context.service.static_config().pins_sample_memory(), eq false);
+        assert_that!(test_context.publisher_1.send_copy(1), eq Ok(1));
+        assert_that!(test_context.publisher_1.send_copy(2), eq Ok(1));
+        let sample_1 = test_context.subscriber.receive().unwrap().unwrap();
+        let sample_2 = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(sample_1.is_memory_pinned(), eq false);
+        assert_that!(sample_2.is_memory_pinned(), eq false);
+
+        assert_that!(sample_1.pin_memory(), is_ok);
+        assert_that!(sample_1.pin_memory(), is_ok);
+        assert_that!(sample_1.is_memory_pinned(), eq true);
+        assert_that!(sample_2.is_memory_pinned(), eq true);
+
+        drop(sample_1);
+        assert_that!(*sample_2, eq 2);
+        drop(sample_2);
+
+        // the segment stays pinned as long as the subscriber has it mapped
+        assert_that!(test_context.publisher_1.send_copy(3), eq Ok(1));
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.is_memory_pinned(), eq true);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn samples_of_service_with_pinned_sample_memory_are_pinned_on_reception<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .pin_sample_memory(true)
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(16384)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        assert_that!(service.static_config().pins_sample_memory(), eq true);
+
+        let payload: Vec<u8> = (0..16384).map(|n| n as u8).collect();
+        for _ in 0..2 {
+            assert_that!(publisher.send_slice_copy(&payload), eq Ok(1));
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.is_memory_pinned(), eq true);
+            assert_that!(sample.payload(), eq payload.as_slice());
+        }
+    }
+
+    #[test]
+    fn relative_pointer_is_resolved_in_received_sample<Sut: Service>() {
+        #[derive(Debug, Clone, Copy)]
+        #[repr(C)]
+        struct Frame {
+            id: u64,
+            body: RelativePointer<u16>,
+        }
+
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .payload_alignment(Alignment::new(8).unwrap())
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(64)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan_slice(64).unwrap();
+        // SAFETY: Frame and u16 do not contain padding bytes and are read back as the
+        // written types
+        unsafe {
+            let body = sample
+                .write_relative(core::mem::size_of::<Frame>(), &[5u16, 6, 7])
+                .unwrap();
+            let frame = sample.write_relative(0, &[Frame { id: 99, body }]).unwrap();
+            assert_that!(sample.resolve(&body).unwrap(), eq [5, 6, 7]);
+            sample.send().unwrap();
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            let received_frame = sample.resolve(&frame).unwrap()[0];
+            assert_that!(received_frame.id, eq 99);
+            assert_that!(received_frame.body, eq body);
+            assert_that!(sample.resolve(&received_frame.body).unwrap(), eq [5, 6, 7]);
+        }
+    }
+
+    #[test]
+    fn corrupted_relative_pointer_is_not_resolved<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .payload_alignment(Alignment::new(8).unwrap())
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(16)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan_slice(16).unwrap();
+        // SAFETY: u32 does not contain padding bytes
+        assert_that!(unsafe { sample.write_relative(12, &[1u32, 2]) }.err(), eq Some(RelativePointerError::OutOfBounds));
+        assert_that!(unsafe { sample.write_relative(2, &[1u32]) }.err(), eq Some(RelativePointerError::Misaligned));
+        sample.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        // SAFETY: every bit pattern is a valid u64
+        assert_that!(unsafe { sample.resolve(&RelativePointer::<u64>::new(8, 2)) }.err(), eq Some(RelativePointerError::OutOfBounds));
+        assert_that!(unsafe { sample.resolve(&RelativePointer::<u64>::new(usize::MAX, usize::MAX)) }.err(), eq Some(RelativePointerError::OutOfBounds));
+        assert_that!(unsafe { sample.resolve(&RelativePointer::<u64>::new(4, 1)) }.err(), eq Some(RelativePointerError::Misaligned));
+        assert_that!(
+            unsafe { sample.resolve(&RelativePointer::<u64>::new(8, 1)) },
+            is_ok
+        );
+    }
+
     #[test]
     fn sample_of_dropped_service_does_not_block_new_service_creation<Sut: Service>() {
         let config = generate_isolated_config();
@@ -0,0 +1,150 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod sample_window {
+    use iceoryx2::port::publisher::Publisher;
+    use iceoryx2::port::sample_window::SampleWindow;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "sample_window_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn create_service<Sut: Service>(node: &Node<Sut>) -> PortFactory<Sut, [u32], ()> {
+        node.service_builder(&generate_name())
+            .publish_subscribe::<[u32]>()
+            .subscriber_max_buffer_size(8)
+            .subscriber_max_borrowed_samples(4)
+            .create()
+            .unwrap()
+    }
+
+    fn create_publisher<Sut: Service>(
+        service: &PortFactory<Sut, [u32], ()>,
+    ) -> Publisher<Sut, [u32], ()> {
+        service
+            .publisher_builder()
+            .initial_max_slice_len(16)
+            .create()
+            .unwrap()
+    }
+
+    fn send<Sut: Service>(publisher: &Publisher<Sut, [u32], ()>, values: &[u32]) {
+        let sample = publisher.loan_slice_uninit(values.len()).unwrap();
+        let sample = sample.write_from_slice(values);
+        sample.send().unwrap();
+    }
+
+    #[test]
+    fn sample_window_without_data_provides_no_window<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let _publisher = create_publisher(&service);
+        let mut sut = SampleWindow::new(service.subscriber_builder().create().unwrap(), 4, 2);
+
+        assert_that!(sut.next_window().unwrap(), is_none);
+        assert_that!(sut.number_of_held_samples(), eq 0);
+    }
+
+    #[test]
+    fn sample_window_provides_overlapping_windows_across_samples<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = create_publisher(&service);
+        let mut sut = SampleWindow::new(service.subscriber_builder().create().unwrap(), 4, 2);
+
+        send(&publisher, &[0, 1, 2]);
+        send(&publisher, &[3, 4, 5]);
+        send(&publisher, &[6, 7]);
+
+        assert_that!(sut.next_window().unwrap(), eq Some(&[0u32, 1, 2, 3][..]));
+        assert_that!(sut.next_window().unwrap(), eq Some(&[2u32, 3, 4, 5][..]));
+        assert_that!(sut.next_window().unwrap(), eq Some(&[4u32, 5, 6, 7][..]));
+        assert_that!(sut.next_window().unwrap(), is_none);
+
+        send(&publisher, &[8, 9]);
+        assert_that!(sut.next_window().unwrap(), eq Some(&[6u32, 7, 8, 9][..]));
+    }
+
+    #[test]
+    fn sample_window_skips_values_when_stride_exceeds_window_size<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = create_publisher(&service);
+        let mut sut = SampleWindow::new(service.subscriber_builder().create().unwrap(), 2, 5);
+
+        send(&publisher, &[0, 1, 2]);
+        send(&publisher, &[3, 4, 5, 6]);
+        send(&publisher, &[7, 8, 9, 10, 11]);
+
+        assert_that!(sut.next_window().unwrap(), eq Some(&[0u32, 1][..]));
+        assert_that!(sut.next_window().unwrap(), eq Some(&[5u32, 6][..]));
+        assert_that!(sut.next_window().unwrap(), eq Some(&[10u32, 11][..]));
+        assert_that!(sut.next_window().unwrap(), is_none);
+    }
+
+    #[test]
+    fn sample_window_holds_only_samples_of_the_current_window<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = create_publisher(&service);
+        let mut sut = SampleWindow::new(service.subscriber_builder().create().unwrap(), 3, 3);
+
+        for n in 0..6 {
+            send(&publisher, &[2 * n, 2 * n + 1]);
+        }
+
+        for n in 0..4 {
+            let expected: Vec<u32> = (3 * n..3 * n + 3).collect();
+            assert_that!(sut.next_window().unwrap(), eq Some(&expected[..]));
+            assert_that!(sut.number_of_held_samples(), eq 2);
+        }
+        assert_that!(sut.next_window().unwrap(), is_none);
+        assert_that!(sut.number_of_held_samples(), eq 0);
+    }
+
+    #[test]
+    fn sample_window_with_zero_size_and_stride_uses_one<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = create_publisher(&service);
+        let mut sut = SampleWindow::new(service.subscriber_builder().create().unwrap(), 0, 0);
+
+        assert_that!(sut.window_size(), eq 1);
+        assert_that!(sut.stride(), eq 1);
+
+        send(&publisher, &[5, 6]);
+        assert_that!(sut.next_window().unwrap(), eq Some(&[5u32][..]));
+        assert_that!(sut.next_window().unwrap(), eq Some(&[6u32][..]));
+        assert_that!(sut.next_window().unwrap(), is_none);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
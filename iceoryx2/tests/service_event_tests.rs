@@ -22,10 +22,12 @@ mod service_event {
     use iceoryx2::port::notifier::{NotifierCreateError, NotifierNotifyError};
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
+    use iceoryx2::service::port_factory::event::BarrierError;
     use iceoryx2::testing::*;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
     const TIMEOUT: Duration = Duration::from_millis(50);
 
@@ -1402,6 +1404,144 @@ mod service_event {
         assert_that!(listener.try_wait_one().unwrap(), is_some);
     }
 
+    #[test]
+    fn barrier_with_single_participant_returns_immediately<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.barrier(0, TIMEOUT), is_ok);
+        assert_that!(sut.dynamic_config().number_of_barrier_arrivals(), eq 0);
+        assert_that!(sut.barrier(1, TIMEOUT), is_ok);
+        assert_that!(sut.barrier(1, TIMEOUT), is_ok);
+        assert_that!(sut.dynamic_config().number_of_barrier_arrivals(), eq 2);
+    }
+
+    #[test]
+    fn barrier_does_not_notify_the_listeners_of_the_service<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_listeners(1)
+            .max_notifiers(1)
+            .create()
+            .unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+        let _notifier = sut.notifier_builder().create().unwrap();
+
+        assert_that!(sut.barrier(1, TIMEOUT), is_ok);
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+    }
+
+    #[test]
+    fn barrier_times_out_with_number_of_arrived_participants<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let start = Instant::now();
+        let result = sut.barrier(3, TIMEOUT);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(result.err(), eq Some(BarrierError::Timeout { arrived: 1 }));
+        assert_that!(format!("{}", result.unwrap_err()), eq "BarrierError::Timeout { arrived: 1 }");
+
+        assert_that!(sut.dynamic_config().number_of_barrier_arrivals(), eq 0);
+    }
+
+    #[test]
+    fn barrier_round_after_timeout_requires_all_participants<S: Service>() {
+        const NUMBER_OF_PARTICIPANTS: usize = 2;
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        // the participant that timed out is no partner of the next one
+        assert_that!(sut.barrier(NUMBER_OF_PARTICIPANTS, TIMEOUT).err(), eq Some(BarrierError::Timeout { arrived: 1 }));
+        assert_that!(sut.barrier(NUMBER_OF_PARTICIPANTS, TIMEOUT).err(), eq Some(BarrierError::Timeout { arrived: 1 }));
+
+        for _ in 0..2 {
+            std::thread::scope(|s| {
+                for _ in 0..NUMBER_OF_PARTICIPANTS {
+                    s.spawn(|| {
+                        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+                        let sut = node.service_builder(&service_name).event().open().unwrap();
+                        assert_that!(
+                            sut.barrier(NUMBER_OF_PARTICIPANTS, Duration::from_secs(10)),
+                            is_ok
+                        );
+                    });
+                }
+            });
+        }
+
+        assert_that!(
+            sut.dynamic_config().number_of_barrier_arrivals(),
+            eq 2 * NUMBER_OF_PARTICIPANTS as u64
+        );
+        assert_that!(sut.barrier(NUMBER_OF_PARTICIPANTS, TIMEOUT).err(), eq Some(BarrierError::Timeout { arrived: 1 }));
+    }
+
+    #[test]
+    fn barrier_blocks_until_all_participants_arrived<S: Service>() {
+        const NUMBER_OF_PARTICIPANTS: usize = 4;
+        const NUMBER_OF_ROUNDS: usize = 3;
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let counter = IoxAtomicU64::new(0);
+
+        std::thread::scope(|s| {
+            for _ in 0..NUMBER_OF_PARTICIPANTS {
+                s.spawn(|| {
+                    let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+                    let sut = node.service_builder(&service_name).event().open().unwrap();
+
+                    for round in 0..NUMBER_OF_ROUNDS as u64 {
+                        counter.fetch_add(1, Ordering::Relaxed);
+                        assert_that!(
+                            sut.barrier(NUMBER_OF_PARTICIPANTS, Duration::from_secs(10)),
+                            is_ok
+                        );
+                        assert_that!(
+                            counter.load(Ordering::Relaxed),
+                            ge(round + 1) * NUMBER_OF_PARTICIPANTS as u64
+                        );
+                    }
+                });
+            }
+        });
+
+        assert_that!(
+            _sut.dynamic_config().number_of_barrier_arrivals(),
+            eq(NUMBER_OF_PARTICIPANTS * NUMBER_OF_ROUNDS) as u64
+        );
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
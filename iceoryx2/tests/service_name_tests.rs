@@ -0,0 +1,160 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod service_name {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashMap;
+    use std::hash::{Hash, Hasher};
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::service_name::IOX1_MAX_ID_STRING_LENGTH;
+    use iceoryx2_bb_container::semantic_string::SemanticStringError;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn creating_works() {
+        let value = "wo ist die bratwurst";
+        let sut = ServiceName::new(value).unwrap();
+
+        assert_that!(sut, eq value);
+        assert_that!(format!("{}", sut), eq value);
+    }
+
+    #[test]
+    fn creating_empty_name_fails() {
+        assert_that!(ServiceName::new("").err(), eq Some(SemanticStringError::InvalidContent));
+        assert_that!(ServiceName::intern("").err(), eq Some(SemanticStringError::InvalidContent));
+    }
+
+    #[test]
+    fn interned_names_share_storage() {
+        let value = "ein starker auftritt des hypnotoads";
+        let sut_1 = ServiceName::intern(value).unwrap();
+        let sut_2 = ServiceName::intern(value).unwrap();
+        let other = ServiceName::intern("ein schwacher auftritt").unwrap();
+
+        assert_that!(sut_1, eq value);
+        assert_that!(sut_1.as_str().as_ptr(), eq sut_2.as_str().as_ptr());
+        assert_that!(sut_1.as_str().as_ptr(), ne other.as_str().as_ptr());
+    }
+
+    #[test]
+    fn interned_name_equals_created_name() {
+        let value = "schnitzel/mit/pommes";
+        let sut = ServiceName::intern(value).unwrap();
+        let created = ServiceName::new(value).unwrap();
+
+        assert_that!(sut, eq created);
+        assert_that!(sut.as_str().as_ptr(), ne created.as_str().as_ptr());
+    }
+
+    #[test]
+    fn clone_shares_storage() {
+        let sut = ServiceName::new("gulasch/suppe").unwrap();
+        let sut_clone = sut.clone();
+
+        assert_that!(sut.as_str().as_ptr(), eq sut_clone.as_str().as_ptr());
+    }
+
+    fn hash_of(name: &ServiceName) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        name.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn hash_eq_and_canonical_bytes_agree_for_all_construction_paths() {
+        let value = "wurst/salat/mit/senf";
+        let created = ServiceName::new(value).unwrap();
+        let variants = [
+            ServiceName::intern(value).unwrap(),
+            created.clone(),
+            value.try_into().unwrap(),
+            ServiceName::new(&String::from(value)).unwrap(),
+        ];
+
+        assert_that!(created.canonical_bytes(), eq value.as_bytes());
+        for sut in &variants {
+            assert_that!(*sut, eq created);
+            assert_that!(sut.cmp(&created), eq core::cmp::Ordering::Equal);
+            assert_that!(sut.canonical_bytes(), eq created.canonical_bytes());
+            assert_that!(hash_of(sut), eq hash_of(&created));
+        }
+
+        let from_iox1 = ServiceName::from_iox1("wurst", "salat", "mit").unwrap();
+        assert_that!(from_iox1, eq ServiceName::new("wurst/salat/mit").unwrap());
+        assert_that!(hash_of(&from_iox1), eq hash_of(&ServiceName::intern("wurst/salat/mit").unwrap()));
+
+        let mut map = HashMap::new();
+        map.insert(ServiceName::intern(value).unwrap(), 1);
+        assert_that!(map.get(&created), eq Some(&1));
+    }
+
+    #[test]
+    fn different_names_have_different_canonical_bytes() {
+        let sut = ServiceName::new("Wurst").unwrap();
+        let other = ServiceName::new("wurst").unwrap();
+
+        assert_that!(sut, ne other);
+        assert_that!(sut.canonical_bytes(), ne other.canonical_bytes());
+        assert_that!(sut.cmp(&other), eq sut.canonical_bytes().cmp(other.canonical_bytes()));
+    }
+
+    #[test]
+    fn iox1_descriptor_round_trip_works() {
+        for (service, instance, event) in [
+            ("Radar", "FrontLeft", "Objects"),
+            ("Camera", "0", "Image"),
+            ("a", "b", "c"),
+            ("Bremse.Vorne", "Links Rechts", "Druck_0815"),
+        ] {
+            let sut = ServiceName::from_iox1(service, instance, event).unwrap();
+
+            let expected_name = format!("{service}/{instance}/{event}");
+            assert_that!(sut, eq expected_name.as_str());
+            assert_that!(sut.to_iox1_triple(), eq Some((service, instance, event)));
+        }
+    }
+
+    #[test]
+    fn iox1_descriptor_with_invalid_part_fails() {
+        assert_that!(ServiceName::from_iox1("", "b", "c").err(), eq Some(SemanticStringError::InvalidContent));
+        assert_that!(ServiceName::from_iox1("a", "", "c").err(), eq Some(SemanticStringError::InvalidContent));
+        assert_that!(ServiceName::from_iox1("a", "b", "").err(), eq Some(SemanticStringError::InvalidContent));
+        assert_that!(ServiceName::from_iox1("a/x", "b", "c").err(), eq Some(SemanticStringError::InvalidContent));
+        assert_that!(ServiceName::from_iox1("a", "b/x", "c").err(), eq Some(SemanticStringError::InvalidContent));
+    }
+
+    #[test]
+    fn iox1_descriptor_with_too_long_part_fails() {
+        let max_part = "x".repeat(IOX1_MAX_ID_STRING_LENGTH);
+        let too_long_part = "x".repeat(IOX1_MAX_ID_STRING_LENGTH + 1);
+
+        assert_that!(ServiceName::from_iox1(&max_part, "b", "c"), is_ok);
+        assert_that!(ServiceName::from_iox1("a", "b", &too_long_part).err(), eq Some(SemanticStringError::ExceedsMaximumLength));
+    }
+
+    #[test]
+    fn service_name_that_is_no_iox1_descriptor_has_no_triple() {
+        for value in ["a", "a/b", "a/b/c/d", "a//c", "/b/c", "a/b/"] {
+            let sut = ServiceName::new(value).unwrap();
+            assert_that!(sut.to_iox1_triple(), is_none);
+        }
+
+        let too_long = ServiceName::new(&format!(
+            "a/b/{}",
+            "x".repeat(IOX1_MAX_ID_STRING_LENGTH + 1)
+        ))
+        .unwrap();
+        assert_that!(too_long.to_iox1_triple(), is_none);
+    }
+}
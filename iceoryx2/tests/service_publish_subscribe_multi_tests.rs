@@ -0,0 +1,171 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod service_publish_subscribe_multi_error {
+    use iceoryx2::port::multi_publisher::MultiPublisherSendError;
+    use iceoryx2::port::multi_subscriber::MultiSubscriberReceiveError;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn send_error_display_works() {
+        assert_that!(
+            format!("{}", MultiPublisherSendError::UnregisteredType), eq "MultiPublisherSendError::UnregisteredType");
+    }
+
+    #[test]
+    fn receive_error_display_works() {
+        assert_that!(
+            format!("{}", MultiSubscriberReceiveError::UnknownTypeTag(3)), eq "MultiSubscriberReceiveError::UnknownTypeTag(3)");
+    }
+}
+
+#[generic_tests::define]
+mod service_publish_subscribe_multi {
+    use iceoryx2::port::multi_publisher::MultiPublisherSendError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[derive(Debug, PartialEq)]
+    #[repr(C)]
+    struct Position {
+        x: u32,
+        y: u32,
+        z: u64,
+    }
+
+    #[test]
+    fn samples_of_all_registered_types_are_delivered<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_multi()
+            .register::<u8>()
+            .register::<Position>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().unwrap();
+        let subscriber = sut.subscriber().unwrap();
+
+        assert_that!(publisher.send(12u8), eq Ok(1));
+        assert_that!(publisher.send(Position { x: 1, y: 2, z: 3 }), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.type_tag(), eq 0);
+        assert_that!(sample.match_type::<u8>(), eq Some(&12));
+        assert_that!(sample.match_type::<Position>(), is_none);
+        drop(sample);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.type_tag(), eq 1);
+        assert_that!(sample.match_type::<u8>(), is_none);
+        assert_that!(sample.match_type::<Position>(), eq Some(&Position { x: 1, y: 2, z: 3 }));
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn sending_unregistered_type_fails<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_multi()
+            .register::<u8>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher().unwrap();
+        let subscriber = sut.subscriber().unwrap();
+
+        assert_that!(publisher.send(12u16), eq Err(MultiPublisherSendError::UnregisteredType));
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn registering_a_type_twice_has_no_effect<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_multi()
+            .register::<u8>()
+            .register::<u64>()
+            .register::<u8>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.registered_types(), len 2);
+    }
+
+    #[test]
+    fn open_with_different_registered_types_fails<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_multi()
+            .register::<u8>()
+            .register::<u64>()
+            .create()
+            .unwrap();
+
+        let reordered = node
+            .service_builder(&service_name)
+            .publish_subscribe_multi()
+            .register::<u64>()
+            .register::<u8>()
+            .open();
+        assert_that!(reordered.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let same = node
+            .service_builder(&service_name)
+            .publish_subscribe_multi()
+            .register::<u8>()
+            .register::<u64>()
+            .open();
+        assert_that!(same, is_ok);
+    }
+
+    #[test]
+    fn single_type_service_cannot_open_multi_type_service<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_multi()
+            .register::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -12,35 +12,139 @@
 
 #[generic_tests::define]
 mod service_publish_subscribe {
+    #[cfg(all(unix, feature = "tokio"))]
+    use core::future::Future;
+    #[cfg(all(unix, feature = "tokio"))]
+    use core::pin::Pin;
     use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    #[cfg(all(unix, feature = "tokio"))]
+    use core::task::{Context, Poll, Waker};
+    use core::time::Duration;
+    #[cfg(all(unix, feature = "tokio"))]
+    use std::sync::Arc;
     use std::sync::{Barrier, Mutex};
+    #[cfg(all(unix, feature = "tokio"))]
+    use std::task::Wake;
     use std::thread;
 
-    use iceoryx2::config::Config;
-    use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError};
-    use iceoryx2::port::subscriber::SubscriberCreateError;
+    use iceoryx2::config::{Config, ConfigSource, ConfigValidationError};
+    use iceoryx2::node::NodeCreationFailure;
+    use iceoryx2::port::publisher::{
+        Publisher, PublisherCreateError, PublisherLoanError, PublisherSendError,
+    };
+    use iceoryx2::port::subscriber::{SubscriberCreateError, SubscriberReceiveError};
     use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::prelude::{AllocationStrategy, *};
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenOrCreateError;
     use iceoryx2::service::builder::publish_subscribe::{CustomHeaderMarker, CustomPayloadMarker};
+    use iceoryx2::service::dynamic_config::publish_subscribe::{ChangeKind, WaitForChangeError};
+    use iceoryx2::service::header::publish_subscribe::Header;
     use iceoryx2::service::messaging_pattern::MessagingPattern;
+    use iceoryx2::service::port_factory::publish_subscribe::HistorySample;
     use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
-    use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
-    use iceoryx2::service::{Service, ServiceDetails};
+    use iceoryx2::service::service_handle::ServiceHandle;
+    use iceoryx2::service::static_config::message_type_details::{
+        SchemaEvolution, SchemaField, TypeDetail, TypeVariant,
+    };
+    use iceoryx2::service::static_config::publish_subscribe::CACHE_LINE_SIZE;
+    use iceoryx2::service::{Service, ServiceDestroyError, ServiceDetails};
     use iceoryx2::testing::*;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
     use iceoryx2_bb_elementary::alignment::Alignment;
     use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
+    use iceoryx2_bb_posix::clock::{ClockType, Time};
+    #[cfg(all(unix, feature = "tokio"))]
+    use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+    #[cfg(all(unix, feature = "tokio"))]
+    use iceoryx2_bb_posix::file_descriptor_set::{
+        FileDescriptorSet, FileEvent, SynchronousMultiplexing,
+    };
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::path::Path;
     use iceoryx2_bb_testing::assert_that;
+    #[cfg(all(unix, feature = "tokio"))]
+    use iceoryx2_bb_testing::test_fail;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    #[cfg(all(unix, feature = "tokio"))]
+    use iceoryx2_cal::event::Event;
 
     #[derive(Debug)]
     struct SomeUserHeader {
         value: [u64; 1024],
     }
 
+    // core::mem::offset_of! requires a newer Rust version than the supported minimum
+    macro_rules! field_offset {
+        ($type:ty, $field:ident) => {{
+            let value = core::mem::MaybeUninit::<$type>::uninit();
+            let base = value.as_ptr();
+            // SAFETY: only the address of the field is computed, nothing is read
+            unsafe { core::ptr::addr_of!((*base).$field) as usize - base as usize }
+        }};
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(C)]
+    struct PoseV1 {
+        x: u64,
+        y: u64,
+    }
+
+    unsafe impl SchemaEvolution for PoseV1 {
+        const TYPE_NAME: &'static str = "Pose";
+        const VERSION: u32 = 1;
+
+        fn schema_fields() -> Vec<SchemaField> {
+            vec![
+                SchemaField::new(field_offset!(PoseV1, x), 8),
+                SchemaField::new(field_offset!(PoseV1, y), 8),
+            ]
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(C)]
+    struct PoseV2 {
+        x: u64,
+        y: u64,
+        heading: u32,
+    }
+
+    unsafe impl SchemaEvolution for PoseV2 {
+        const TYPE_NAME: &'static str = "Pose";
+        const VERSION: u32 = 2;
+
+        fn schema_fields() -> Vec<SchemaField> {
+            vec![
+                SchemaField::new(field_offset!(PoseV2, x), 8),
+                SchemaField::new(field_offset!(PoseV2, y), 8),
+                SchemaField::new(field_offset!(PoseV2, heading), 4),
+            ]
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(C)]
+    struct PoseWithoutY {
+        x: u64,
+        heading: u32,
+    }
+
+    unsafe impl SchemaEvolution for PoseWithoutY {
+        const TYPE_NAME: &'static str = "Pose";
+        const VERSION: u32 = 1;
+
+        fn schema_fields() -> Vec<SchemaField> {
+            vec![
+                SchemaField::new(field_offset!(PoseWithoutY, x), 8),
+                SchemaField::new(field_offset!(PoseWithoutY, heading), 4),
+            ]
+        }
+    }
+
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
             "service_tests_{}",
@@ -49,6 +153,53 @@ mod service_publish_subscribe {
         .unwrap()
     }
 
+    #[cfg(all(unix, feature = "tokio"))]
+    struct SampleSignal<'a>(&'a FileDescriptor);
+
+    #[cfg(all(unix, feature = "tokio"))]
+    impl FileDescriptorBased for SampleSignal<'_> {
+        fn file_descriptor(&self) -> &FileDescriptor {
+            self.0
+        }
+    }
+
+    #[cfg(all(unix, feature = "tokio"))]
+    impl SynchronousMultiplexing for SampleSignal<'_> {}
+
+    #[cfg(all(unix, feature = "tokio"))]
+    fn is_readable(fd: &FileDescriptor) -> bool {
+        let fd_set = FileDescriptorSet::new();
+        let sample_signal = SampleSignal(fd);
+        let _guard = fd_set.add(&sample_signal).unwrap();
+        fd_set
+            .timed_wait(Duration::ZERO, FileEvent::Read, |_| {})
+            .unwrap()
+            == 1
+    }
+
+    #[cfg(all(unix, feature = "tokio"))]
+    struct NoopWaker;
+
+    #[cfg(all(unix, feature = "tokio"))]
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    #[cfg(all(unix, feature = "tokio"))]
+    fn poll_once<F: Future>(future: Pin<&mut F>) -> Poll<F::Output> {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        future.poll(&mut Context::from_waker(&waker))
+    }
+
+    #[cfg(all(unix, feature = "tokio"))]
+    fn tokio_runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .enable_time()
+            .build()
+            .unwrap()
+    }
+
     #[test]
     fn open_or_create_with_attributes_succeeds_when_service_does_exist<Sut: Service>() {
         let service_name = generate_name();
@@ -201,6 +352,87 @@ mod service_publish_subscribe {
         assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
     }
 
+    #[test]
+    fn open_subscriber_only_fails_when_service_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_subscriber_only();
+        assert_that!(sut.err(), eq Some(PublishSubscribeOpenError::DoesNotExist));
+    }
+
+    #[test]
+    fn subscriber_only_port_factory_receives_samples_of_existing_publishers<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(3)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_subscriber_only()
+            .unwrap();
+        assert_that!(sut.name(), eq & service_name);
+        assert_that!(sut.static_config().max_publishers(), eq 3);
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 1);
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        publisher.update_connections().unwrap();
+        publisher.send_copy(1234).unwrap();
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 1);
+    }
+
+    #[test]
+    fn service_with_zero_max_publishers_rejects_publishers_of_every_process<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(0)
+            .create()
+            .unwrap();
+        assert_that!(service.static_config().max_publishers(), eq 0);
+
+        let other_node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = other_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_subscriber_only()
+            .unwrap();
+        let _subscriber = sut.subscriber_builder().create().unwrap();
+
+        let publisher = service.publisher_builder().create();
+        assert_that!(publisher.err(), eq Some(PublisherCreateError::ExceedsMaxSupportedPublishers));
+
+        let opened_service = other_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let publisher = opened_service.publisher_builder().create();
+        assert_that!(publisher.err(), eq Some(PublisherCreateError::ExceedsMaxSupportedPublishers));
+
+        let opened_service = other_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(1)
+            .open();
+        assert_that!(opened_service.err(), eq Some(PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers));
+    }
+
     #[test]
     fn open_succeeds_when_service_does_exist<Sut: Service>() {
         let service_name = generate_name();
@@ -420,807 +652,1565 @@ mod service_publish_subscribe {
     }
 
     #[test]
-    fn open_fails_when_service_does_not_satisfy_history_requirement<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_last_value_requirement<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .history_size(2)
+            .enable_last_value(true)
             .create();
         assert_that!(sut, is_ok);
 
         let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .history_size(3)
+            .enable_last_value(false)
             .open();
 
         assert_that!(sut2, is_err);
         assert_that!(
             sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize
+            PublishSubscribeOpenError::IncompatibleLastValueMode
         );
 
-        let sut2 = node
+        let sut3 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .history_size(1)
             .open();
 
-        assert_that!(sut2, is_ok);
+        assert_that!(sut3, is_ok);
     }
 
     #[test]
-    fn open_fails_when_service_does_not_satisfy_subscriber_max_borrow_requirement<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_pool_allocation_strategy_requirement<
+        Sut: Service,
+    >() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_borrowed_samples(2)
+            .pool_allocation_strategy(PoolAllocationStrategy::Bump)
             .create();
         assert_that!(sut, is_ok);
+        assert_that!(sut.as_ref().unwrap().static_config().pool_allocation_strategy(), eq PoolAllocationStrategy::Bump);
 
         let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_borrowed_samples(3)
+            .pool_allocation_strategy(PoolAllocationStrategy::FreeList)
             .open();
 
         assert_that!(sut2, is_err);
         assert_that!(
             sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples
+            PublishSubscribeOpenError::IncompatiblePoolAllocationStrategy
         );
 
-        let sut2 = node
+        let sut3 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_borrowed_samples(1)
             .open();
 
-        assert_that!(sut2, is_ok);
+        assert_that!(sut3, is_ok);
+        assert_that!(sut3.unwrap().static_config().pool_allocation_strategy(), eq PoolAllocationStrategy::Bump);
     }
 
     #[test]
-    fn open_fails_when_service_does_not_satisfy_subscriber_max_buffer_size_requirement<
-        Sut: Service,
-    >() {
+    fn open_fails_when_service_does_not_satisfy_coalescing_window_requirement<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_buffer_size(2)
+            .coalescing_window(Duration::from_millis(10))
             .create();
         assert_that!(sut, is_ok);
+        assert_that!(sut.as_ref().unwrap().static_config().coalescing_window(), eq Some(Duration::from_millis(10)));
 
         let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_buffer_size(3)
+            .coalescing_window(Duration::from_millis(20))
             .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleCoalescingWindow));
 
-        assert_that!(sut2, is_err);
-        assert_that!(
-            sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize
-        );
-
-        let sut2 = node
+        let sut3 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_buffer_size(1)
             .open();
-
-        assert_that!(sut2, is_ok);
+        assert_that!(sut3, is_ok);
+        assert_that!(sut3.unwrap().static_config().coalescing_window(), eq Some(Duration::from_millis(10)));
     }
 
     #[test]
-    fn open_fails_when_service_does_not_satisfy_alignment_requirement<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_sample_memory_pinning_requirement<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .payload_alignment(Alignment::new(128).unwrap())
+            .pin_sample_memory(true)
             .create();
         assert_that!(sut, is_ok);
 
         let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .payload_alignment(Alignment::new(512).unwrap())
+            .pin_sample_memory(false)
             .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleSampleMemoryPinning));
 
-        assert_that!(sut2, is_err);
-        assert_that!(
-            sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::IncompatibleTypes
-        );
-
-        let sut2 = node
+        let sut3 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .payload_alignment(Alignment::new(16).unwrap())
             .open();
-
-        assert_that!(sut2, is_ok);
+        assert_that!(sut3, is_ok);
+        assert_that!(sut3.unwrap().static_config().pins_sample_memory(), eq true);
     }
 
     #[test]
-    fn open_does_not_fail_when_service_owner_is_dropped<Sut: Service>() {
+    fn config_provenance_of_created_service_distinguishes_builder_and_defaults<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .create();
-        assert_that!(sut, is_ok);
+            .max_publishers(3)
+            .enable_acknowledgment(true)
+            .create()
+            .unwrap();
 
-        let sut2 = node
+        let provenance = sut.config_provenance();
+        assert_that!(provenance.max_publishers(), eq ConfigSource::Builder);
+        assert_that!(provenance.has_acknowledgment(), eq ConfigSource::Builder);
+        assert_that!(provenance.max_subscribers(), eq ConfigSource::BuiltInDefault);
+        assert_that!(provenance.history_size(), eq ConfigSource::BuiltInDefault);
+        assert_that!(provenance.pool_allocation_strategy(), eq ConfigSource::BuiltInDefault);
+    }
+
+    #[test]
+    fn config_provenance_of_last_value_service_reports_adjusted_settings_as_builder<
+        Sut: Service,
+    >() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .open();
-        assert_that!(sut2, is_ok);
+            .enable_last_value(true)
+            .create()
+            .unwrap();
 
-        drop(sut);
+        let provenance = sut.config_provenance();
+        assert_that!(provenance.has_last_value(), eq ConfigSource::Builder);
+        assert_that!(provenance.history_size(), eq ConfigSource::Builder);
+        assert_that!(provenance.subscriber_max_buffer_size(), eq ConfigSource::Builder);
+        assert_that!(provenance.has_safe_overflow(), eq ConfigSource::Builder);
+        assert_that!(provenance.max_nodes(), eq ConfigSource::BuiltInDefault);
+    }
 
-        let sut3 = node
+    #[test]
+    fn config_provenance_of_opened_service_is_existing_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .open();
-        assert_that!(sut3, is_ok);
+            .max_publishers(3)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .open()
+            .unwrap();
+
+        let provenance = sut2.config_provenance();
+        assert_that!(provenance.max_publishers(), eq ConfigSource::ExistingService);
+        assert_that!(provenance.max_subscribers(), eq ConfigSource::ExistingService);
+        assert_that!(provenance.pins_sample_memory(), eq ConfigSource::ExistingService);
     }
 
     #[test]
-    fn open_fails_when_all_previous_owners_have_been_dropped<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_slow_subscriber_eviction_requirement<
+        Sut: Service,
+    >() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
+            .slow_subscriber_eviction(Duration::from_millis(10), 0)
             .create();
         assert_that!(sut, is_ok);
+        let eviction = sut
+            .as_ref()
+            .unwrap()
+            .static_config()
+            .slow_subscriber_eviction();
+        assert_that!(eviction.map(|e| e.deadline()), eq Some(Duration::from_millis(10)));
+        assert_that!(eviction.map(|e| e.consecutive_misses()), eq Some(1));
 
         let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
+            .slow_subscriber_eviction(Duration::from_millis(10), 2)
             .open();
-        assert_that!(sut2, is_ok);
-
-        drop(sut);
-        drop(sut2);
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleSlowSubscriberEviction));
 
         let sut3 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
             .open();
-        assert_that!(sut3, is_err);
-        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
+        assert_that!(sut3, is_ok);
+        assert_that!(sut3.unwrap().static_config().slow_subscriber_eviction(), eq eviction);
     }
 
     #[test]
-    fn open_or_create_creates_service_if_it_does_not_exist<Sut: Service>() {
-        let service_name = generate_name();
+    fn stalled_subscriber_is_evicted_after_consecutive_missed_deadlines<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(10);
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<[u64]>()
-            .open_or_create();
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .slow_subscriber_eviction(DEADLINE, 2)
+            .create()
+            .unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()
+            .unwrap();
+        let stalled_subscriber = sut.subscriber_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        assert_that!(sut, is_ok);
+        assert_that!(publisher.send_copy(1), eq Ok(2));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1);
+
+        let start = std::time::Instant::now();
+        assert_that!(publisher.send_copy(2), eq Ok(1));
+        assert_that!(start.elapsed(), ge DEADLINE);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 2);
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 2);
+
+        assert_that!(publisher.send_copy(3), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 3);
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 1);
+        assert_that!(stalled_subscriber.receive().err(), eq Some(SubscriberReceiveError::Disconnected));
+
+        // the publisher no longer waits for the evicted subscriber
+        let start = std::time::Instant::now();
+        assert_that!(publisher.send_copy(4), eq Ok(1));
+        assert_that!(start.elapsed(), lt DEADLINE);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 4);
     }
 
     #[test]
-    fn open_or_create_opens_service_if_it_does_exist<Sut: Service>() {
-        let service_name = generate_name();
+    fn samples_borrowed_by_evicted_subscriber_stay_valid<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(1);
+        const MAX_LOANED_SAMPLES: usize = 8;
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let _sut = node
-            .service_builder(&service_name)
+        let sut = node
+            .service_builder(&generate_name())
             .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .slow_subscriber_eviction(DEADLINE, 1)
             .create()
             .unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()
+            .unwrap();
+        let stalled_subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+        let borrowed_sample = stalled_subscriber.receive().unwrap().unwrap();
+        assert_that!(publisher.send_copy(2), eq Ok(1));
+        assert_that!(publisher.send_copy(3), eq Ok(0));
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 0);
+
+        // the memory of the borrowed sample must not be reused for new samples
+        let samples: Vec<_> = (0..MAX_LOANED_SAMPLES)
+            .map(|_| publisher.loan_uninit().unwrap().write_payload(99))
+            .collect();
+        assert_that!(*borrowed_sample, eq 1);
+        for sample in samples {
+            assert_that!(sample.send(), eq Ok(0));
+        }
 
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open_or_create();
-
-        assert_that!(sut, is_ok);
+        drop(borrowed_sample);
+        drop(stalled_subscriber);
+        for value in 4..64 {
+            assert_that!(publisher.send_copy(value), eq Ok(0));
+        }
     }
 
     #[test]
-    fn max_publishers_and_subscribers_is_set_to_config_default<Sut: Service>() {
-        let service_name = generate_name();
-        let node = NodeBuilder::new().create::<Sut>().unwrap();
+    fn deadline_of_slow_subscriber_eviction_bounds_the_whole_send<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(100);
+        const NUMBER_OF_STALLED_SUBSCRIBERS: usize = 4;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
-            .service_builder(&service_name)
+            .service_builder(&generate_name())
             .publish_subscribe::<u64>()
+            .max_subscribers(NUMBER_OF_STALLED_SUBSCRIBERS)
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .slow_subscriber_eviction(DEADLINE, 2)
+            .create()
+            .unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
             .create()
             .unwrap();
+        let _stalled_subscribers: Vec<_> = (0..NUMBER_OF_STALLED_SUBSCRIBERS)
+            .map(|_| sut.subscriber_builder().create().unwrap())
+            .collect();
 
-        let defaults = &Config::global_config().defaults;
+        assert_that!(publisher.send_copy(1), eq Ok(NUMBER_OF_STALLED_SUBSCRIBERS));
 
-        assert_that!(
-            sut.static_config().max_publishers(), eq
-            defaults.publish_subscribe.max_publishers
-        );
-        assert_that!(
-            sut.static_config().max_subscribers(), eq
-            defaults.publish_subscribe.max_subscribers
-        );
+        let start = std::time::Instant::now();
+        assert_that!(publisher.send_copy(2), eq Ok(0));
+        assert_that!(start.elapsed(), ge DEADLINE);
+        assert_that!(start.elapsed(), lt DEADLINE * NUMBER_OF_STALLED_SUBSCRIBERS as u32);
     }
 
     #[test]
-    fn open_uses_predefined_settings_when_nothing_is_specified<Sut: Service>() {
-        let service_name = generate_name();
+    fn subscriber_that_frees_buffer_space_in_time_is_not_evicted<Sut: Service>() {
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
-            .service_builder(&service_name)
+            .service_builder(&generate_name())
             .publish_subscribe::<u64>()
-            .max_nodes(89)
-            .max_publishers(4)
-            .max_subscribers(5)
+            .subscriber_max_buffer_size(1)
             .enable_safe_overflow(false)
-            .history_size(6)
-            .subscriber_max_borrowed_samples(7)
-            .subscriber_max_buffer_size(8)
+            .slow_subscriber_eviction(Duration::from_millis(1), 2)
             .create()
             .unwrap();
-
-        assert_that!(sut.static_config().max_nodes(), eq 89);
-        assert_that!(sut.static_config().max_publishers(), eq 4);
-        assert_that!(sut.static_config().max_subscribers(), eq 5);
-        assert_that!(sut.static_config().has_safe_overflow(), eq false);
-        assert_that!(sut.static_config().history_size(), eq 6);
-        assert_that!(sut.static_config().subscriber_max_borrowed_samples(), eq 7);
-        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 8);
-
-        let sut2 = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open()
+        let publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()
             .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        assert_that!(sut2.static_config().max_nodes(), eq 89);
-        assert_that!(sut2.static_config().max_publishers(), eq 4);
-        assert_that!(sut2.static_config().max_subscribers(), eq 5);
-        assert_that!(sut2.static_config().has_safe_overflow(), eq false);
-        assert_that!(sut2.static_config().history_size(), eq 6);
-        assert_that!(sut2.static_config().subscriber_max_borrowed_samples(), eq 7);
-        assert_that!(sut2.static_config().subscriber_max_buffer_size(), eq 8);
+        for value in 0..4 {
+            // every second send misses the deadline, a received sample resets the misses
+            assert_that!(publisher.send_copy(2 * value), eq Ok(1));
+            assert_that!(publisher.send_copy(2 * value + 1), eq Ok(0));
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq 2 * value);
+        }
+
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 1);
+        assert_that!(subscriber.receive().unwrap(), is_none);
     }
 
-    #[test]
-    fn settings_can_be_modified_via_custom_config<Sut: Service>() {
-        let service_name = generate_name();
-        let config = generate_isolated_config();
-        let mut custom_config = config.clone();
-        custom_config.defaults.publish_subscribe.max_nodes = 2;
-        custom_config.defaults.publish_subscribe.max_publishers = 9;
-        custom_config.defaults.publish_subscribe.max_subscribers = 10;
-        custom_config
-            .defaults
-            .publish_subscribe
-            .enable_safe_overflow = false;
-        custom_config
-            .defaults
-            .publish_subscribe
-            .publisher_history_size = 11;
-        custom_config
-            .defaults
-            .publish_subscribe
-            .subscriber_max_borrowed_samples = 12;
-        custom_config
-            .defaults
-            .publish_subscribe
-            .subscriber_max_buffer_size = 13;
-        let node_1 = NodeBuilder::new()
-            .config(&custom_config)
-            .create::<Sut>()
-            .unwrap();
-        let node_2 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+    fn send_to_group<Sut: Service>(publisher: &Publisher<Sut, u64, ()>, group: u64, value: u64) {
+        let mut sample = publisher.loan_uninit().unwrap().write_payload(value);
+        sample.set_coalescence_group(group);
+        sample.send().unwrap();
+    }
 
-        let sut = node_1
-            .service_builder(&service_name)
+    fn create_coalescing_service<Sut: Service>(
+        node: &Node<Sut>,
+        window: Duration,
+    ) -> iceoryx2::service::port_factory::publish_subscribe::PortFactory<Sut, u64, ()> {
+        node.service_builder(&generate_name())
             .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(8)
+            .subscriber_max_borrowed_samples(2)
+            .coalescing_window(window)
             .create()
-            .unwrap();
+            .unwrap()
+    }
 
-        assert_that!(sut.static_config().max_nodes(), eq 2);
-        assert_that!(sut.static_config().max_publishers(), eq 9);
-        assert_that!(sut.static_config().max_subscribers(), eq 10);
-        assert_that!(sut.static_config().has_safe_overflow(), eq false);
-        assert_that!(sut.static_config().history_size(), eq 11);
-        assert_that!(sut.static_config().subscriber_max_borrowed_samples(), eq 12);
-        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 13);
+    #[test]
+    fn coalescing_delivers_only_the_most_recent_sample_to_slow_subscriber<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = create_coalescing_service(&node, Duration::from_secs(3600));
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let sut2 = node_2
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open()
-            .unwrap();
+        for value in 0..5 {
+            send_to_group(&publisher, 0, value);
+        }
 
-        // NOTE: although node_2 did specify a config with default values, since
-        // node_1 was created first, the values of that node have to be preset
-        assert_that!(sut2.static_config().max_nodes(), eq 2);
-        assert_that!(sut2.static_config().max_publishers(), eq 9);
-        assert_that!(sut2.static_config().max_subscribers(), eq 10);
-        assert_that!(sut2.static_config().has_safe_overflow(), eq false);
-        assert_that!(sut2.static_config().history_size(), eq 11);
-        assert_that!(sut2.static_config().subscriber_max_borrowed_samples(), eq 12);
-        assert_that!(sut2.static_config().subscriber_max_buffer_size(), eq 13);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 4);
+        assert_that!(sample.header().sequence_number(), eq 5);
+        assert_that!(sample.header().send_timestamp(), is_some);
+        drop(sample);
+        assert_that!(subscriber.receive().unwrap(), is_none);
     }
 
     #[test]
-    fn number_of_publishers_works<Sut: Service>() {
-        let service_name = generate_name();
+    fn coalescing_delivers_every_sample_to_fast_subscriber<Sut: Service>() {
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const MAX_PUBLISHERS: usize = 8;
-
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .max_publishers(MAX_PUBLISHERS)
-            .create()
-            .unwrap();
-
-        let sut2 = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open()
-            .unwrap();
+        let sut = create_coalescing_service(&node, Duration::from_secs(3600));
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let mut publishers = vec![];
+        for value in 0..5 {
+            send_to_group(&publisher, 0, value);
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq value);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
 
-        for i in 0..MAX_PUBLISHERS / 2 {
-            publishers.push(sut.publisher_builder().create().unwrap());
-            assert_that!(sut.dynamic_config().number_of_publishers(), eq 2 * i + 1);
-            assert_that!(sut2.dynamic_config().number_of_publishers(), eq 2 * i + 1);
-            assert_that!(sut.dynamic_config().number_of_subscribers(), eq 0);
-            assert_that!(sut2.dynamic_config().number_of_subscribers(), eq 0);
+    #[test]
+    fn coalescing_keeps_the_most_recent_sample_of_every_group<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = create_coalescing_service(&node, Duration::from_secs(3600));
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-            publishers.push(sut2.publisher_builder().create().unwrap());
-            assert_that!(sut.dynamic_config().number_of_publishers(), eq 2 * i + 2);
-            assert_that!(sut2.dynamic_config().number_of_publishers(), eq 2 * i + 2);
-            assert_that!(sut.dynamic_config().number_of_subscribers(), eq 0);
-            assert_that!(sut2.dynamic_config().number_of_subscribers(), eq 0);
-        }
+        send_to_group(&publisher, 1, 10);
+        send_to_group(&publisher, 1, 11);
+        send_to_group(&publisher, 2, 20);
+        send_to_group(&publisher, 2, 21);
+        send_to_group(&publisher, 2, 22);
+        send_to_group(&publisher, 1, 12);
 
-        for i in 0..MAX_PUBLISHERS {
-            publishers.pop();
-            assert_that!(sut.dynamic_config().number_of_publishers(), eq MAX_PUBLISHERS - i - 1);
-            assert_that!(sut2.dynamic_config().number_of_publishers(), eq MAX_PUBLISHERS - i - 1);
-        }
+        assert_that!(subscriber.has_samples(), eq Ok(true));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 11);
+        assert_that!(subscriber.has_samples(), eq Ok(true));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 22);
+        assert_that!(sample.header().coalescence_group(), eq 2);
+        drop(sample);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 12);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(subscriber.has_samples(), eq Ok(false));
     }
 
     #[test]
-    fn type_informations_are_correct<Sut: Service>() {
-        type Header = iceoryx2::service::header::publish_subscribe::Header;
-        type PayloadType = u64;
+    fn coalescing_does_not_collapse_samples_beyond_the_window<Sut: Service>() {
+        const WINDOW: Duration = Duration::from_millis(100);
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = create_coalescing_service(&node, WINDOW);
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let service_name = generate_name();
+        send_to_group(&publisher, 0, 1);
+        send_to_group(&publisher, 0, 2);
+        std::thread::sleep(WINDOW * 2);
+        send_to_group(&publisher, 0, 3);
+        send_to_group(&publisher, 0, 4);
 
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<PayloadType>()
-            .user_header::<SomeUserHeader>()
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 2);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 4);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn coalescing_requires_a_spare_borrowed_sample<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = create_coalescing_service(&node, Duration::from_secs(3600));
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .max_borrowed_samples(1)
             .create()
             .unwrap();
 
-        let d = sut.static_config().message_type_details();
-        assert_that!(d.header.variant, eq TypeVariant::FixedSize);
-        assert_that!(d.header.type_name, eq core::any::type_name::<Header>());
-        assert_that!(d.header.size, eq core::mem::size_of::<Header>());
-        assert_that!(d.header.alignment, eq core::mem::align_of::<Header>());
-        assert_that!(d.user_header.variant, eq TypeVariant::FixedSize);
-        assert_that!(d.user_header.type_name, eq core::any::type_name::<SomeUserHeader>());
-        assert_that!(d.user_header.size, eq core::mem::size_of::<SomeUserHeader>());
-        assert_that!(d.user_header.alignment, eq core::mem::align_of::<SomeUserHeader>());
-        assert_that!(d.payload.variant, eq TypeVariant::FixedSize);
-        assert_that!(d.payload.type_name, eq core::any::type_name::<PayloadType>());
-        assert_that!(d.payload.size, eq core::mem::size_of::<PayloadType>());
-        assert_that!(d.payload.alignment, eq core::mem::align_of::<PayloadType>());
+        for value in 0..3 {
+            send_to_group(&publisher, 0, value);
+        }
+
+        for value in 0..3 {
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq value);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
     }
 
     #[test]
-    fn slice_type_informations_are_correct<Sut: Service>() {
-        type Header = iceoryx2::service::header::publish_subscribe::Header;
-        type PayloadType = u64;
-
+    fn free_list_is_the_default_pool_allocation_strategy<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[PayloadType]>()
+            .publish_subscribe::<u64>()
             .create()
             .unwrap();
 
-        let d = sut.static_config().message_type_details();
-        assert_that!(d.header.variant, eq TypeVariant::FixedSize);
-        assert_that!(d.header.type_name, eq core::any::type_name::<Header>());
-        assert_that!(d.header.size, eq core::mem::size_of::<Header>());
-        assert_that!(d.header.alignment, eq core::mem::align_of::<Header>());
-        assert_that!(d.user_header.variant, eq TypeVariant::FixedSize);
-        assert_that!(d.user_header.type_name, eq core::any::type_name::<()>());
-        assert_that!(d.user_header.size, eq core::mem::size_of::<()>());
-        assert_that!(d.user_header.alignment, eq core::mem::align_of::<()>());
-        assert_that!(d.payload.variant, eq TypeVariant::Dynamic);
-        assert_that!(d.payload.type_name, eq core::any::type_name::<PayloadType>());
-        assert_that!(d.payload.size, eq core::mem::size_of::<PayloadType>());
-        assert_that!(d.payload.alignment, eq core::mem::align_of::<PayloadType>());
+        assert_that!(sut.static_config().pool_allocation_strategy(), eq PoolAllocationStrategy::FreeList);
     }
 
     #[test]
-    fn number_of_subscribers_works<Sut: Service>() {
+    fn bump_pool_allocation_strategy_recycles_samples<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 4;
+        const NUMBER_OF_ITERATIONS: usize = 64;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const MAX_SUBSCRIBERS: usize = 8;
-
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_subscribers(MAX_SUBSCRIBERS)
+            .subscriber_max_buffer_size(2)
+            .pool_allocation_strategy(PoolAllocationStrategy::Bump)
             .create()
             .unwrap();
 
-        let sut2 = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open()
+        let publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(NUMBER_OF_SAMPLES)
+            .create()
             .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let mut subscribers = vec![];
-
-        for i in 0..MAX_SUBSCRIBERS / 2 {
-            subscribers.push(sut.subscriber_builder().create().unwrap());
-            assert_that!(sut.dynamic_config().number_of_subscribers(), eq 2 * i + 1);
-            assert_that!(sut2.dynamic_config().number_of_subscribers(), eq 2 * i + 1);
-            assert_that!(sut.dynamic_config().number_of_publishers(), eq 0);
-            assert_that!(sut2.dynamic_config().number_of_publishers(), eq 0);
+        for n in 0..NUMBER_OF_ITERATIONS as u64 {
+            let mut samples = vec![];
+            for k in 0..2 {
+                samples.push(publisher.loan_uninit().unwrap().write_payload(2 * n + k));
+            }
 
-            subscribers.push(sut2.subscriber_builder().create().unwrap());
-            assert_that!(sut.dynamic_config().number_of_subscribers(), eq 2 * i + 2);
-            assert_that!(sut2.dynamic_config().number_of_subscribers(), eq 2 * i + 2);
-            assert_that!(sut.dynamic_config().number_of_publishers(), eq 0);
-            assert_that!(sut2.dynamic_config().number_of_publishers(), eq 0);
-        }
+            for sample in samples {
+                sample.send().unwrap();
+            }
 
-        for i in 0..MAX_SUBSCRIBERS {
-            subscribers.pop();
-            assert_that!(sut.dynamic_config().number_of_subscribers(), eq MAX_SUBSCRIBERS - i - 1);
-            assert_that!(sut2.dynamic_config().number_of_subscribers(), eq MAX_SUBSCRIBERS - i - 1);
+            for k in 0..2 {
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(*sample, eq 2 * n + k);
+            }
         }
     }
 
     #[test]
-    fn max_number_of_nodes_works<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_max_message_size_requirement<Sut: Service>() {
         let service_name = generate_name();
-        const MAX_NODES: usize = 8;
-
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .max_nodes(MAX_NODES)
+            .publish_subscribe::<[u8; 64]>()
+            .max_message_size(16)
             .create();
         assert_that!(sut, is_ok);
+        assert_that!(sut.as_ref().unwrap().static_config().max_message_size(), eq Some(16));
 
-        let mut nodes = vec![];
-        let mut services = vec![];
-
-        nodes.push(node);
-        services.push(sut.unwrap());
-
-        for _ in 1..MAX_NODES {
-            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-            let sut = node
-                .service_builder(&service_name)
-                .publish_subscribe::<u64>()
-                .open();
-            assert_that!(sut, is_ok);
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8; 64]>()
+            .max_message_size(17)
+            .open();
 
-            nodes.push(node);
-            services.push(sut.unwrap());
-        }
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::DoesNotSupportRequestedMaxMessageSize
+        );
 
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
+        let sut3 = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<[u8; 64]>()
+            .max_message_size(16)
             .open();
+        assert_that!(sut3, is_ok);
+    }
 
-        assert_that!(sut, is_err);
-        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::ExceedsMaxNumberOfNodes);
-
-        nodes.pop();
-        services.pop();
-
+    #[test]
+    fn create_fails_when_pool_capacity_is_below_required_minimum<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open();
+        let min_pool_capacity = 2 * (3 + 1)
+            + 1
+            + config
+                .defaults
+                .publish_subscribe
+                .publisher_max_loaned_samples;
+        let create_service = |pool_capacity| {
+            node.service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .max_subscribers(2)
+                .subscriber_max_buffer_size(3)
+                .subscriber_max_borrowed_samples(1)
+                .history_size(1)
+                .pool_capacity(pool_capacity)
+                .create()
+        };
+
+        assert_that!(create_service(min_pool_capacity - 1).err(), eq Some(PublishSubscribeCreateError::PoolCapacityBelowRequiredMinimum));
 
+        let sut = create_service(min_pool_capacity);
         assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap().static_config().pool_capacity(), eq Some(min_pool_capacity));
     }
 
     #[test]
-    fn simple_communication_works_subscriber_created_first<Sut: Service>() {
+    fn publisher_data_segment_provides_the_pool_capacity<Sut: Service>() {
+        const POOL_CAPACITY: usize = 512;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
+            .pool_capacity(POOL_CAPACITY)
             .create()
             .unwrap();
+        assert_that!(sut.config_provenance().pool_capacity(), eq ConfigSource::Builder);
 
-        let sut2 = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open()
-            .unwrap();
-
-        let subscriber = sut.subscriber_builder().create().unwrap();
-        let publisher = sut2.publisher_builder().create().unwrap();
-        assert_that!(subscriber.update_connections(), is_ok);
-
-        assert_that!(publisher.send_copy(1234), is_ok);
-        assert_that!(publisher.send_copy(4567), is_ok);
-
-        let result = subscriber.receive().unwrap();
-        assert_that!(result, is_some);
-        let sample = result.unwrap();
-        assert_that!(*sample, eq 1234);
-        assert_that!(*sample.payload(), eq 1234);
+        let _publisher = sut.publisher_builder().create().unwrap();
 
-        let result = subscriber.receive().unwrap();
-        assert_that!(result, is_some);
-        let sample_2 = result.unwrap();
-        assert_that!(*sample_2, eq 4567);
-        assert_that!(*sample_2.payload(), eq 4567);
+        let mut number_of_samples = vec![];
+        sut.dynamic_config().__internal_list_publishers(|details| {
+            number_of_samples.push(details.number_of_samples)
+        });
+        assert_that!(number_of_samples, eq vec![POOL_CAPACITY]);
     }
 
     #[test]
-    fn simple_communication_works_publisher_created_first<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_pool_capacity_requirement<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .create()
-            .unwrap();
+            .pool_capacity(256)
+            .create();
+        assert_that!(sut, is_ok);
 
         let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .open()
-            .unwrap();
-
-        let publisher = sut.publisher_builder().create().unwrap();
-        let subscriber = sut2.subscriber_builder().create().unwrap();
-        assert_that!(publisher.update_connections(), is_ok);
-
-        assert_that!(publisher.send_copy(1234), is_ok);
-        assert_that!(publisher.send_copy(4567), is_ok);
-
-        let result = subscriber.receive().unwrap();
-        assert_that!(result, is_some);
-        assert_that!(*result.unwrap(), eq 1234);
+            .pool_capacity(257)
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::DoesNotSupportRequestedPoolCapacity));
 
-        let result = subscriber.receive().unwrap();
-        assert_that!(result, is_some);
-        assert_that!(*result.unwrap(), eq 4567);
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .pool_capacity(256)
+            .open();
+        assert_that!(sut3, is_ok);
     }
 
     #[test]
-    fn custom_payload_alignment_cannot_be_smaller_than_payload_type_alignment<Sut: Service>() {
+    fn samples_are_not_cache_line_aligned_by_default<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .payload_alignment(Alignment::new(1).unwrap())
+            .publish_subscribe::<u8>()
             .create()
             .unwrap();
 
-        assert_that!(sut.static_config().message_type_details().payload.alignment, eq core::mem::align_of::<u64>());
+        assert_that!(sut.static_config().sample_cache_line_aligned(), eq false);
+        assert_that!(sut.static_config().sample_alignment(), lt CACHE_LINE_SIZE);
     }
 
     #[test]
-    fn all_samples_are_correctly_aligned<Sut: Service>() {
-        const BUFFER_SIZE: usize = 100;
-        const ALIGNMENT: usize = 512;
+    fn cache_line_aligned_samples_start_at_cache_line_boundary<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 4;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
-        let service_pub = node
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .subscriber_max_buffer_size(BUFFER_SIZE)
-            .subscriber_max_borrowed_samples(BUFFER_SIZE)
-            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
+            .publish_subscribe::<u8>()
+            .sample_cache_line_aligned(true)
             .create()
             .unwrap();
 
-        let service_sub = node
+        assert_that!(sut.static_config().sample_cache_line_aligned(), eq true);
+        assert_that!(sut.static_config().sample_alignment(), eq CACHE_LINE_SIZE);
+
+        let opened_sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
+            .publish_subscribe::<u8>()
             .open()
             .unwrap();
+        assert_that!(opened_sut.static_config().sample_cache_line_aligned(), eq true);
 
-        let subscriber = service_sub.subscriber_builder().create().unwrap();
-        let publisher = service_pub.publisher_builder().create().unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+        let subscriber = opened_sut.subscriber_builder().create().unwrap();
+        // the u8 payload directly follows the header at the start of the sample
+        let header_size = sut.static_config().message_type_details().header.size;
 
         let mut samples = vec![];
-        for n in 0..BUFFER_SIZE {
-            let mut sample = publisher.loan().unwrap();
-            *sample.payload_mut() = n * 1920;
+        for n in 0..NUMBER_OF_SAMPLES {
+            let sample = publisher.loan_uninit().unwrap().write_payload(n as u8);
+            assert_that!((sample.payload() as *const u8 as usize - header_size) % CACHE_LINE_SIZE, eq 0);
+            samples.push(sample);
+        }
 
-            let payload_address = (sample.payload() as *const usize) as usize;
-            assert_that!(payload_address % ALIGNMENT, eq 0);
+        for sample in samples {
             sample.send().unwrap();
-
-            let recv_sample = subscriber.receive().unwrap().unwrap();
-            let recv_payload_address = (recv_sample.payload() as *const usize) as usize;
-            assert_that!(recv_payload_address % ALIGNMENT, eq 0);
-            assert_that!(*recv_sample, eq n * 1920);
-
-            samples.push(recv_sample);
+            let received_sample = subscriber.receive().unwrap().unwrap();
+            assert_that!((received_sample.payload() as *const u8 as usize - header_size) % CACHE_LINE_SIZE, eq 0);
         }
     }
 
     #[test]
-    fn publisher_reclaims_all_samples_after_disconnect<Sut: Service>() {
+    fn cache_line_aligned_slice_samples_start_at_cache_line_boundary<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 4;
+        const SLICE_LEN: usize = 3;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const RECONNECTIONS: usize = 20;
-        const MAX_SUBSCRIBERS: usize = 10;
-
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .max_publishers(1)
-            .max_subscribers(MAX_SUBSCRIBERS)
-            .history_size(0)
-            .subscriber_max_borrowed_samples(1)
-            .subscriber_max_buffer_size(3)
+            .publish_subscribe::<[u8]>()
+            .sample_cache_line_aligned(true)
             .create()
             .unwrap();
 
-        let publisher = sut.publisher_builder().create().unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_LEN)
+            .max_loaned_samples(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
 
-        for n in 0..MAX_SUBSCRIBERS {
-            for _ in 0..RECONNECTIONS {
-                let mut subscribers = vec![];
-                for _ in 0..n {
-                    subscribers.push(sut.subscriber_builder().create());
-                }
+        // the u8 payload directly follows the header at the start of the sample
+        let header_size = sut.static_config().message_type_details().header.size;
 
-                assert_that!(publisher.send_copy(1234), eq Ok(n));
-                assert_that!(publisher.send_copy(4567), eq Ok(n));
-                assert_that!(publisher.send_copy(789), eq Ok(n));
-                subscribers.clear();
-                assert_that!(publisher.send_copy(789), eq Ok(0));
-                assert_that!(publisher.send_copy(789), eq Ok(0));
-            }
+        let mut samples = vec![];
+        for _ in 0..NUMBER_OF_SAMPLES {
+            let sample = publisher.loan_slice(SLICE_LEN).unwrap();
+            assert_that!((sample.payload().as_ptr() as usize - header_size) % CACHE_LINE_SIZE, eq 0);
+            samples.push(sample);
         }
     }
 
     #[test]
-    fn publisher_updates_connections_after_reconnect<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_history_requirement<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const RECONNECTIONS: usize = 20;
-        const MAX_SUBSCRIBERS: usize = 10;
-
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_publishers(1)
-            .max_subscribers(MAX_SUBSCRIBERS)
-            .history_size(0)
-            .subscriber_max_borrowed_samples(1)
-            .subscriber_max_buffer_size(3)
-            .create()
-            .unwrap();
+            .history_size(2)
+            .create();
+        assert_that!(sut, is_ok);
 
-        let publisher = sut.publisher_builder().create().unwrap();
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(3)
+            .open();
 
-        for n in 0..MAX_SUBSCRIBERS {
-            for _ in 0..RECONNECTIONS {
-                let mut subscribers = vec![];
-                for _ in 0..n {
-                    subscribers.push(sut.subscriber_builder().create().unwrap());
-                }
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize
+        );
 
-                assert_that!(publisher.send_copy(1234), eq Ok(n));
-                for subscriber in &subscribers {
-                    assert_that!(subscriber.receive().unwrap(), is_some);
-                }
-            }
-        }
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(1)
+            .open();
+
+        assert_that!(sut2, is_ok);
     }
 
     #[test]
-    fn subscriber_updates_connections_after_reconnect<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_subscriber_max_borrow_requirement<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const RECONNECTIONS: usize = 20;
-        const MAX_PUBLISHERS: usize = 10;
-
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_publishers(MAX_PUBLISHERS)
-            .history_size(0)
-            .subscriber_max_borrowed_samples(1)
-            .subscriber_max_buffer_size(1)
-            .create()
-            .unwrap();
+            .subscriber_max_borrowed_samples(2)
+            .create();
+        assert_that!(sut, is_ok);
 
-        let subscriber = sut.subscriber_builder().create().unwrap();
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(3)
+            .open();
 
-        for n in 0..MAX_PUBLISHERS {
-            for k in 0..RECONNECTIONS {
-                let mut publishers = vec![];
-                for _ in 0..n {
-                    publishers.push(sut.publisher_builder().create().unwrap());
-                }
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples
+        );
 
-                for publisher in publishers {
-                    let payload: u64 = (n * k + 12) as _;
-                    assert_that!(publisher.send_copy(payload), eq Ok(1));
-                    let sample = subscriber.receive().unwrap();
-                    assert_that!(sample, is_some);
-                    assert_that!(*sample.unwrap(), eq payload);
-                }
-            }
-        }
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(1)
+            .open();
+
+        assert_that!(sut2, is_ok);
     }
 
     #[test]
-    fn concurrent_communication_with_subscriber_reconnects_does_not_deadlock<Sut: Service>() {
-        let _watch_dog = Watchdog::new();
-
-        const NUMBER_OF_SUBSCRIBER_THREADS: usize = 2;
-        const NUMBER_OF_RECONNECTIONS: usize = 50;
-
-        let create_service_barrier = Barrier::new(2);
+    fn open_fails_when_service_does_not_satisfy_subscriber_max_buffer_size_requirement<
+        Sut: Service,
+    >() {
         let service_name = generate_name();
-        let keep_running = AtomicBool::new(true);
         let config = generate_isolated_config();
-        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .create();
+        assert_that!(sut, is_ok);
 
-        thread::scope(|s| {
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(3)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize
+        );
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .open();
+
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_alignment_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .payload_alignment(Alignment::new(128).unwrap())
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .payload_alignment(Alignment::new(512).unwrap())
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatibleTypes
+        );
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .payload_alignment(Alignment::new(16).unwrap())
+            .open();
+
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_does_not_fail_when_service_owner_is_dropped<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        drop(sut);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_all_previous_owners_have_been_dropped<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        drop(sut);
+        drop(sut2);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut3, is_err);
+        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_or_create_creates_service_if_it_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .open_or_create();
+
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn open_or_create_opens_service_if_it_does_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create();
+
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn max_publishers_and_subscribers_is_set_to_config_default<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let defaults = &Config::global_config().defaults;
+
+        assert_that!(
+            sut.static_config().max_publishers(), eq
+            defaults.publish_subscribe.max_publishers
+        );
+        assert_that!(
+            sut.static_config().max_subscribers(), eq
+            defaults.publish_subscribe.max_subscribers
+        );
+    }
+
+    #[test]
+    fn open_uses_predefined_settings_when_nothing_is_specified<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_nodes(89)
+            .max_publishers(4)
+            .max_subscribers(5)
+            .enable_safe_overflow(false)
+            .history_size(6)
+            .subscriber_max_borrowed_samples(7)
+            .subscriber_max_buffer_size(8)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_nodes(), eq 89);
+        assert_that!(sut.static_config().max_publishers(), eq 4);
+        assert_that!(sut.static_config().max_subscribers(), eq 5);
+        assert_that!(sut.static_config().has_safe_overflow(), eq false);
+        assert_that!(sut.static_config().history_size(), eq 6);
+        assert_that!(sut.static_config().subscriber_max_borrowed_samples(), eq 7);
+        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 8);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        assert_that!(sut2.static_config().max_nodes(), eq 89);
+        assert_that!(sut2.static_config().max_publishers(), eq 4);
+        assert_that!(sut2.static_config().max_subscribers(), eq 5);
+        assert_that!(sut2.static_config().has_safe_overflow(), eq false);
+        assert_that!(sut2.static_config().history_size(), eq 6);
+        assert_that!(sut2.static_config().subscriber_max_borrowed_samples(), eq 7);
+        assert_that!(sut2.static_config().subscriber_max_buffer_size(), eq 8);
+    }
+
+    #[test]
+    fn settings_can_be_modified_via_custom_config<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let mut custom_config = config.clone();
+        custom_config.defaults.publish_subscribe.max_nodes = 2;
+        custom_config.defaults.publish_subscribe.max_publishers = 9;
+        custom_config.defaults.publish_subscribe.max_subscribers = 10;
+        custom_config
+            .defaults
+            .publish_subscribe
+            .enable_safe_overflow = false;
+        custom_config
+            .defaults
+            .publish_subscribe
+            .publisher_history_size = 11;
+        custom_config
+            .defaults
+            .publish_subscribe
+            .subscriber_max_borrowed_samples = 12;
+        custom_config
+            .defaults
+            .publish_subscribe
+            .subscriber_max_buffer_size = 13;
+        let node_1 = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+        let node_2 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node_1
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_nodes(), eq 2);
+        assert_that!(sut.static_config().max_publishers(), eq 9);
+        assert_that!(sut.static_config().max_subscribers(), eq 10);
+        assert_that!(sut.static_config().has_safe_overflow(), eq false);
+        assert_that!(sut.static_config().history_size(), eq 11);
+        assert_that!(sut.static_config().subscriber_max_borrowed_samples(), eq 12);
+        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 13);
+
+        let sut2 = node_2
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        // NOTE: although node_2 did specify a config with default values, since
+        // node_1 was created first, the values of that node have to be preset
+        assert_that!(sut2.static_config().max_nodes(), eq 2);
+        assert_that!(sut2.static_config().max_publishers(), eq 9);
+        assert_that!(sut2.static_config().max_subscribers(), eq 10);
+        assert_that!(sut2.static_config().has_safe_overflow(), eq false);
+        assert_that!(sut2.static_config().history_size(), eq 11);
+        assert_that!(sut2.static_config().subscriber_max_borrowed_samples(), eq 12);
+        assert_that!(sut2.static_config().subscriber_max_buffer_size(), eq 13);
+    }
+
+    #[test]
+    fn number_of_publishers_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const MAX_PUBLISHERS: usize = 8;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(MAX_PUBLISHERS)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let mut publishers = vec![];
+
+        for i in 0..MAX_PUBLISHERS / 2 {
+            publishers.push(sut.publisher_builder().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_publishers(), eq 2 * i + 1);
+            assert_that!(sut2.dynamic_config().number_of_publishers(), eq 2 * i + 1);
+            assert_that!(sut.dynamic_config().number_of_subscribers(), eq 0);
+            assert_that!(sut2.dynamic_config().number_of_subscribers(), eq 0);
+
+            publishers.push(sut2.publisher_builder().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_publishers(), eq 2 * i + 2);
+            assert_that!(sut2.dynamic_config().number_of_publishers(), eq 2 * i + 2);
+            assert_that!(sut.dynamic_config().number_of_subscribers(), eq 0);
+            assert_that!(sut2.dynamic_config().number_of_subscribers(), eq 0);
+        }
+
+        for i in 0..MAX_PUBLISHERS {
+            publishers.pop();
+            assert_that!(sut.dynamic_config().number_of_publishers(), eq MAX_PUBLISHERS - i - 1);
+            assert_that!(sut2.dynamic_config().number_of_publishers(), eq MAX_PUBLISHERS - i - 1);
+        }
+    }
+
+    #[test]
+    fn type_informations_are_correct<Sut: Service>() {
+        type Header = iceoryx2::service::header::publish_subscribe::Header;
+        type PayloadType = u64;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service_name = generate_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PayloadType>()
+            .user_header::<SomeUserHeader>()
+            .create()
+            .unwrap();
+
+        let d = sut.static_config().message_type_details();
+        assert_that!(d.header.variant, eq TypeVariant::FixedSize);
+        assert_that!(d.header.type_name, eq core::any::type_name::<Header>());
+        // without send timestamp the sample stores the header without its seconds
+        assert_that!(d.header.size, eq core::mem::size_of::<Header>() - core::mem::size_of::<u64>());
+        assert_that!(d.header.alignment, eq core::mem::align_of::<Header>());
+        assert_that!(d.user_header.variant, eq TypeVariant::FixedSize);
+        assert_that!(d.user_header.type_name, eq core::any::type_name::<SomeUserHeader>());
+        assert_that!(d.user_header.size, eq core::mem::size_of::<SomeUserHeader>());
+        assert_that!(d.user_header.alignment, eq core::mem::align_of::<SomeUserHeader>());
+        assert_that!(d.payload.variant, eq TypeVariant::FixedSize);
+        assert_that!(d.payload.type_name, eq core::any::type_name::<PayloadType>());
+        assert_that!(d.payload.size, eq core::mem::size_of::<PayloadType>());
+        assert_that!(d.payload.alignment, eq core::mem::align_of::<PayloadType>());
+    }
+
+    #[test]
+    fn slice_type_informations_are_correct<Sut: Service>() {
+        type Header = iceoryx2::service::header::publish_subscribe::Header;
+        type PayloadType = u64;
+
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[PayloadType]>()
+            .create()
+            .unwrap();
+
+        let d = sut.static_config().message_type_details();
+        assert_that!(d.header.variant, eq TypeVariant::FixedSize);
+        assert_that!(d.header.type_name, eq core::any::type_name::<Header>());
+        // without send timestamp the sample stores the header without its seconds
+        assert_that!(d.header.size, eq core::mem::size_of::<Header>() - core::mem::size_of::<u64>());
+        assert_that!(d.header.alignment, eq core::mem::align_of::<Header>());
+        assert_that!(d.user_header.variant, eq TypeVariant::FixedSize);
+        assert_that!(d.user_header.type_name, eq core::any::type_name::<()>());
+        assert_that!(d.user_header.size, eq core::mem::size_of::<()>());
+        assert_that!(d.user_header.alignment, eq core::mem::align_of::<()>());
+        assert_that!(d.payload.variant, eq TypeVariant::Dynamic);
+        assert_that!(d.payload.type_name, eq core::any::type_name::<PayloadType>());
+        assert_that!(d.payload.size, eq core::mem::size_of::<PayloadType>());
+        assert_that!(d.payload.alignment, eq core::mem::align_of::<PayloadType>());
+    }
+
+    #[test]
+    fn number_of_subscribers_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const MAX_SUBSCRIBERS: usize = 8;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(MAX_SUBSCRIBERS)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let mut subscribers = vec![];
+
+        for i in 0..MAX_SUBSCRIBERS / 2 {
+            subscribers.push(sut.subscriber_builder().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_subscribers(), eq 2 * i + 1);
+            assert_that!(sut2.dynamic_config().number_of_subscribers(), eq 2 * i + 1);
+            assert_that!(sut.dynamic_config().number_of_publishers(), eq 0);
+            assert_that!(sut2.dynamic_config().number_of_publishers(), eq 0);
+
+            subscribers.push(sut2.subscriber_builder().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_subscribers(), eq 2 * i + 2);
+            assert_that!(sut2.dynamic_config().number_of_subscribers(), eq 2 * i + 2);
+            assert_that!(sut.dynamic_config().number_of_publishers(), eq 0);
+            assert_that!(sut2.dynamic_config().number_of_publishers(), eq 0);
+        }
+
+        for i in 0..MAX_SUBSCRIBERS {
+            subscribers.pop();
+            assert_that!(sut.dynamic_config().number_of_subscribers(), eq MAX_SUBSCRIBERS - i - 1);
+            assert_that!(sut2.dynamic_config().number_of_subscribers(), eq MAX_SUBSCRIBERS - i - 1);
+        }
+    }
+
+    #[test]
+    fn max_number_of_nodes_works<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_NODES: usize = 8;
+
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_nodes(MAX_NODES)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let mut nodes = vec![];
+        let mut services = vec![];
+
+        nodes.push(node);
+        services.push(sut.unwrap());
+
+        for _ in 1..MAX_NODES {
+            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+            let sut = node
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .open();
+            assert_that!(sut, is_ok);
+
+            nodes.push(node);
+            services.push(sut.unwrap());
+        }
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::ExceedsMaxNumberOfNodes);
+
+        nodes.pop();
+        services.pop();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn simple_communication_works_subscriber_created_first<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut2.publisher_builder().create().unwrap();
+        assert_that!(subscriber.update_connections(), is_ok);
+
+        assert_that!(publisher.send_copy(1234), is_ok);
+        assert_that!(publisher.send_copy(4567), is_ok);
+
+        let result = subscriber.receive().unwrap();
+        assert_that!(result, is_some);
+        let sample = result.unwrap();
+        assert_that!(*sample, eq 1234);
+        assert_that!(*sample.payload(), eq 1234);
+
+        let result = subscriber.receive().unwrap();
+        assert_that!(result, is_some);
+        let sample_2 = result.unwrap();
+        assert_that!(*sample_2, eq 4567);
+        assert_that!(*sample_2.payload(), eq 4567);
+    }
+
+    #[test]
+    fn simple_communication_works_publisher_created_first<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut2.subscriber_builder().create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+
+        assert_that!(publisher.send_copy(1234), is_ok);
+        assert_that!(publisher.send_copy(4567), is_ok);
+
+        let result = subscriber.receive().unwrap();
+        assert_that!(result, is_some);
+        assert_that!(*result.unwrap(), eq 1234);
+
+        let result = subscriber.receive().unwrap();
+        assert_that!(result, is_some);
+        assert_that!(*result.unwrap(), eq 4567);
+    }
+
+    #[test]
+    fn custom_payload_alignment_cannot_be_smaller_than_payload_type_alignment<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .payload_alignment(Alignment::new(1).unwrap())
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().message_type_details().payload.alignment, eq core::mem::align_of::<u64>());
+    }
+
+    #[test]
+    fn all_samples_are_correctly_aligned<Sut: Service>() {
+        const BUFFER_SIZE: usize = 100;
+        const ALIGNMENT: usize = 512;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service_pub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(BUFFER_SIZE)
+            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
+            .create()
+            .unwrap();
+
+        let service_sub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .open()
+            .unwrap();
+
+        let subscriber = service_sub.subscriber_builder().create().unwrap();
+        let publisher = service_pub.publisher_builder().create().unwrap();
+
+        let mut samples = vec![];
+        for n in 0..BUFFER_SIZE {
+            let mut sample = publisher.loan().unwrap();
+            *sample.payload_mut() = n * 1920;
+
+            let payload_address = (sample.payload() as *const usize) as usize;
+            assert_that!(payload_address % ALIGNMENT, eq 0);
+            sample.send().unwrap();
+
+            let recv_sample = subscriber.receive().unwrap().unwrap();
+            let recv_payload_address = (recv_sample.payload() as *const usize) as usize;
+            assert_that!(recv_payload_address % ALIGNMENT, eq 0);
+            assert_that!(*recv_sample, eq n * 1920);
+
+            samples.push(recv_sample);
+        }
+    }
+
+    #[test]
+    fn publisher_reclaims_all_samples_after_disconnect<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const RECONNECTIONS: usize = 20;
+        const MAX_SUBSCRIBERS: usize = 10;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(1)
+            .max_subscribers(MAX_SUBSCRIBERS)
+            .history_size(0)
+            .subscriber_max_borrowed_samples(1)
+            .subscriber_max_buffer_size(3)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        for n in 0..MAX_SUBSCRIBERS {
+            for _ in 0..RECONNECTIONS {
+                let mut subscribers = vec![];
+                for _ in 0..n {
+                    subscribers.push(sut.subscriber_builder().create());
+                }
+
+                assert_that!(publisher.send_copy(1234), eq Ok(n));
+                assert_that!(publisher.send_copy(4567), eq Ok(n));
+                assert_that!(publisher.send_copy(789), eq Ok(n));
+                subscribers.clear();
+                assert_that!(publisher.send_copy(789), eq Ok(0));
+                assert_that!(publisher.send_copy(789), eq Ok(0));
+            }
+        }
+    }
+
+    #[test]
+    fn publisher_updates_connections_after_reconnect<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const RECONNECTIONS: usize = 20;
+        const MAX_SUBSCRIBERS: usize = 10;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(1)
+            .max_subscribers(MAX_SUBSCRIBERS)
+            .history_size(0)
+            .subscriber_max_borrowed_samples(1)
+            .subscriber_max_buffer_size(3)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        for n in 0..MAX_SUBSCRIBERS {
+            for _ in 0..RECONNECTIONS {
+                let mut subscribers = vec![];
+                for _ in 0..n {
+                    subscribers.push(sut.subscriber_builder().create().unwrap());
+                }
+
+                assert_that!(publisher.send_copy(1234), eq Ok(n));
+                for subscriber in &subscribers {
+                    assert_that!(subscriber.receive().unwrap(), is_some);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn subscriber_updates_connections_after_reconnect<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const RECONNECTIONS: usize = 20;
+        const MAX_PUBLISHERS: usize = 10;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(MAX_PUBLISHERS)
+            .history_size(0)
+            .subscriber_max_borrowed_samples(1)
+            .subscriber_max_buffer_size(1)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for n in 0..MAX_PUBLISHERS {
+            for k in 0..RECONNECTIONS {
+                let mut publishers = vec![];
+                for _ in 0..n {
+                    publishers.push(sut.publisher_builder().create().unwrap());
+                }
+
+                for publisher in publishers {
+                    let payload: u64 = (n * k + 12) as _;
+                    assert_that!(publisher.send_copy(payload), eq Ok(1));
+                    let sample = subscriber.receive().unwrap();
+                    assert_that!(sample, is_some);
+                    assert_that!(*sample.unwrap(), eq payload);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn concurrent_communication_with_subscriber_reconnects_does_not_deadlock<Sut: Service>() {
+        let _watch_dog = Watchdog::new();
+
+        const NUMBER_OF_SUBSCRIBER_THREADS: usize = 2;
+        const NUMBER_OF_RECONNECTIONS: usize = 50;
+
+        let create_service_barrier = Barrier::new(2);
+        let service_name = generate_name();
+        let keep_running = AtomicBool::new(true);
+        let config = generate_isolated_config();
+        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+
+        thread::scope(|s| {
             s.spawn(|| {
                 let sut2 = node
                     .lock()
@@ -1228,1369 +2218,3076 @@ mod service_publish_subscribe {
                     .service_builder(&service_name)
                     .publish_subscribe::<u64>()
                     .create()
-                    .unwrap();
-                let publisher = sut2.publisher_builder().create().unwrap();
+                    .unwrap();
+                let publisher = sut2.publisher_builder().create().unwrap();
+
+                create_service_barrier.wait();
+                let mut counter = 1u64;
+
+                while keep_running.load(Ordering::Relaxed) {
+                    assert_that!(publisher.send_copy(counter), is_ok);
+                    counter += 1;
+                }
+            });
+
+            create_service_barrier.wait();
+            let mut threads = vec![];
+            for _ in 0..NUMBER_OF_SUBSCRIBER_THREADS {
+                threads.push(s.spawn(|| {
+                    let sut = node
+                        .lock()
+                        .unwrap()
+                        .service_builder(&service_name)
+                        .publish_subscribe::<u64>()
+                        .open()
+                        .unwrap();
+
+                    let mut latest_counter = 0u64;
+                    for _ in 0..NUMBER_OF_RECONNECTIONS {
+                        let subscriber = sut.subscriber_builder().create().unwrap();
+
+                        loop {
+                            if let Some(counter) = subscriber.receive().unwrap() {
+                                assert_that!(latest_counter, lt * counter);
+                                latest_counter = *counter;
+                                break;
+                            }
+                        }
+                    }
+                }));
+            }
+
+            for t in threads {
+                t.join().unwrap();
+            }
+            keep_running.store(false, Ordering::Relaxed);
+        });
+    }
+
+    #[test]
+    fn concurrent_communication_with_publisher_reconnects_does_not_deadlock<Sut: Service>() {
+        let _watch_dog = Watchdog::new();
+
+        const NUMBER_OF_PUBLISHER_THREADS: usize = 2;
+        const NUMBER_OF_RECONNECTIONS: usize = 50;
+
+        let create_service_barrier = Barrier::new(1 + NUMBER_OF_PUBLISHER_THREADS);
+        let service_name = generate_name();
+        let keep_running = AtomicBool::new(true);
+        let reconnection_cycle = AtomicUsize::new(0);
+        let config = generate_isolated_config();
+        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+
+        thread::scope(|s| {
+            s.spawn(|| {
+                let sut = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .max_publishers(NUMBER_OF_PUBLISHER_THREADS)
+                    .open_or_create()
+                    .unwrap();
+
+                let subscriber = sut.subscriber_builder().create().unwrap();
+                create_service_barrier.wait();
+
+                while keep_running.load(Ordering::Relaxed) {
+                    if let Some(_) = subscriber.receive().unwrap() {
+                        if reconnection_cycle.fetch_add(1, Ordering::Relaxed)
+                            == NUMBER_OF_RECONNECTIONS
+                        {
+                            keep_running.store(false, Ordering::Relaxed);
+                        }
+                    }
+                }
+            });
+
+            for _ in 0..NUMBER_OF_PUBLISHER_THREADS {
+                s.spawn(|| {
+                    let sut2 = node
+                        .lock()
+                        .unwrap()
+                        .service_builder(&service_name)
+                        .publish_subscribe::<u64>()
+                        .max_publishers(NUMBER_OF_PUBLISHER_THREADS)
+                        .open_or_create()
+                        .unwrap();
+
+                    create_service_barrier.wait();
+
+                    while keep_running.load(Ordering::Relaxed) {
+                        let publisher = sut2.publisher_builder().create().unwrap();
+
+                        let current_cycle = reconnection_cycle.load(Ordering::Relaxed);
+                        let mut counter = 1u64;
+                        while current_cycle == reconnection_cycle.load(Ordering::Relaxed)
+                            && keep_running.load(Ordering::Relaxed)
+                        {
+                            assert_that!(publisher.send_copy(counter), is_ok);
+                            counter += 1;
+                        }
+                    }
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn communication_with_max_subscribers_and_publishers<Sut: Service>() {
+        const MAX_PUB: usize = 4;
+        const MAX_SUB: usize = 6;
+        const NUMBER_OF_ITERATIONS: u64 = 128;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(MAX_PUB)
+            .max_subscribers(MAX_SUB)
+            .create()
+            .unwrap();
+
+        let mut publishers = vec![];
+        publishers.reserve(MAX_PUB);
+
+        for _ in 0..MAX_PUB {
+            publishers.push(sut.publisher_builder().create().unwrap());
+        }
+
+        let mut subscribers = vec![];
+        subscribers.reserve(MAX_SUB);
+
+        for _ in 0..MAX_SUB {
+            subscribers.push(sut.subscriber_builder().create().unwrap());
+        }
+
+        let mut counter = 0;
+        for _ in 0..NUMBER_OF_ITERATIONS {
+            for publisher in &mut publishers {
+                assert_that!(publisher.send_copy(counter), is_ok);
+
+                for subscriber in &subscribers {
+                    let sample = subscriber.receive().unwrap();
+                    assert_that!(sample, is_some);
+                    assert_that!(*sample.unwrap(), eq counter);
+                }
+                counter += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn multi_channel_communication_with_max_subscribers_and_publishers<Sut: Service>() {
+        const MAX_PUB: usize = 5;
+        const MAX_SUB: usize = 7;
+        const NUMBER_OF_ITERATIONS: u64 = 128;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(MAX_PUB)
+            .max_subscribers(MAX_SUB)
+            .create()
+            .unwrap();
+
+        let mut channels = vec![];
+        channels.reserve(MAX_PUB + MAX_SUB);
+
+        for _ in 0..MAX_PUB + MAX_SUB {
+            channels.push(
+                node.service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open()
+                    .unwrap(),
+            );
+        }
+
+        let mut publishers = vec![];
+        publishers.reserve(MAX_PUB);
+
+        for c in channels.iter().take(MAX_PUB) {
+            publishers.push(c.publisher_builder().create().unwrap());
+        }
+
+        let mut subscribers = vec![];
+        subscribers.reserve(MAX_SUB);
+
+        for i in 0..MAX_SUB {
+            subscribers.push(channels[i + MAX_PUB].subscriber_builder().create().unwrap());
+        }
+
+        let mut counter = 0;
+        for _ in 0..NUMBER_OF_ITERATIONS {
+            for publisher in &mut publishers {
+                assert_that!(publisher.send_copy(counter), is_ok);
+
+                for subscriber in &subscribers {
+                    let sample = subscriber.receive().unwrap();
+                    assert_that!(sample, is_some);
+                    assert_that!(*sample.unwrap(), eq counter);
+                }
+                counter += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn publish_safely_overflows_when_enabled<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 2;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(2 * i + 25), is_ok);
+        }
+
+        for i in 0..BUFFER_SIZE {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq 2 * i + 25);
+        }
+    }
+
+    #[test]
+    fn publish_does_not_overflow_when_deactivated<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 5;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .enable_safe_overflow(false)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(2 * i + 25), is_ok);
+        }
+
+        for i in 0..BUFFER_SIZE {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq i);
+        }
+    }
+
+    #[test]
+    fn publish_non_overflow_with_greater_history_than_buffer_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .enable_safe_overflow(false)
+            .history_size(12)
+            .subscriber_max_buffer_size(11)
+            .create();
+
+        assert_that!(sut, is_err);
+        assert_that!(
+            sut.err().unwrap(), eq
+            PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize
+        );
+    }
+
+    #[test]
+    fn publish_history_is_delivered_on_subscription<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(3)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(29), is_ok);
+        assert_that!(sut_publisher.send_copy(32), is_ok);
+        assert_that!(sut_publisher.send_copy(35), is_ok);
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        for i in 0..BUFFER_SIZE {
+            let data = sut_subscriber.receive().unwrap();
+            assert_that!(data, is_some);
+            assert_that!(*data.unwrap(), eq 29 + (i + 1) * 3 )
+        }
+    }
+
+    #[test]
+    fn publish_history_of_zero_works<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(29), is_ok);
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        let data = sut_subscriber.receive().unwrap();
+        assert_that!(data, is_none);
+    }
+
+    fn payload_of(sample: &HistorySample) -> u64 {
+        assert_that!(sample.payload_bytes(), len core::mem::size_of::<u64>());
+        // SAFETY: u64 has no padding bytes, therefore all bytes are initialized
+        unsafe { core::ptr::read_unaligned(sample.payload_bytes().as_ptr().cast::<u64>()) }
+    }
+
+    #[test]
+    fn snapshot_history_copies_retained_samples_of_every_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(3)
+            .create()
+            .unwrap();
+
+        let publisher_1 = sut.publisher_builder().create().unwrap();
+        let publisher_2 = sut.publisher_builder().create().unwrap();
+        for value in 1..=5 {
+            assert_that!(publisher_1.send_copy(value), is_ok);
+        }
+        assert_that!(publisher_2.send_copy(10), is_ok);
+
+        let snapshot = sut.snapshot_history();
+        let payloads_of = |publisher_id| {
+            snapshot
+                .iter()
+                .filter(|sample| sample.header().publisher_id() == publisher_id)
+                .map(payload_of)
+                .collect::<Vec<u64>>()
+        };
+
+        assert_that!(snapshot, len 4);
+        assert_that!(payloads_of(publisher_1.id()), eq vec![3, 4, 5]);
+        assert_that!(payloads_of(publisher_2.id()), eq vec![10]);
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 0);
+
+        // the history is still delivered to a new subscriber
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(publisher_1.update_connections(), is_ok);
+        assert_that!(subscriber.receive().unwrap(), is_some);
+    }
+
+    #[test]
+    fn snapshot_history_ignores_dropped_publishers<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(2)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(publisher.send_copy(1), is_ok);
+        drop(publisher);
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut.snapshot_history(), len 0);
+
+        assert_that!(publisher.send_copy(2), is_ok);
+        let snapshot = sut.snapshot_history();
+        assert_that!(snapshot, len 1);
+        assert_that!(payload_of(&snapshot[0]), eq 2);
+    }
+
+    #[test]
+    fn snapshot_history_without_history_is_empty<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(publisher.send_copy(1), is_ok);
+        assert_that!(sut.snapshot_history(), len 0);
+    }
+
+    #[test]
+    fn publish_send_copy_with_huge_overflow_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 5;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .max_publishers(1)
+            .max_subscribers(2)
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(1)
+            .create()
+            .unwrap();
+
+        let mut subscribers = vec![];
+        for _ in 0..2 {
+            let sut_subscriber = sut.subscriber_builder().create();
+            subscribers.push(sut_subscriber);
+        }
+
+        for _ in 0..BUFFER_SIZE * 100 {
+            assert_that!(sut_publisher.send_copy(889), is_ok);
+        }
+    }
+
+    fn publisher_never_goes_out_of_memory_impl<Sut: Service>(
+        buffer_size: usize,
+        history_size: usize,
+        max_borrow: usize,
+        max_subscribers: usize,
+        max_loan: usize,
+    ) {
+        const ITERATIONS: usize = 16;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .max_publishers(1)
+            .max_subscribers(max_subscribers)
+            .enable_safe_overflow(true)
+            .history_size(history_size)
+            .subscriber_max_buffer_size(buffer_size)
+            .subscriber_max_borrowed_samples(max_borrow)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(max_loan)
+            .create()
+            .unwrap();
+
+        let mut subscribers = vec![];
+        for _ in 0..max_subscribers {
+            let sut_subscriber = sut.subscriber_builder().create().unwrap();
+            subscribers.push(sut_subscriber);
+        }
+
+        for _ in 0..ITERATIONS {
+            // max out borrow
+            let mut borrowed_samples = vec![];
+            while borrowed_samples.len() < max_borrow * max_subscribers {
+                for _ in 0..buffer_size {
+                    assert_that!(sut_publisher.send_copy(889), is_ok);
+                }
+
+                for (i, s) in subscribers.iter().enumerate().take(max_subscribers) {
+                    while let Ok(Some(sample)) = s.receive() {
+                        borrowed_samples.push((i, sample));
+                    }
+                }
+            }
+
+            // max out buffer
+            for _ in 0..buffer_size {
+                assert_that!(sut_publisher.send_copy(8127), is_ok);
+            }
+
+            // max out history
+            for _ in 0..history_size {
+                assert_that!(sut_publisher.send_copy(17283), is_ok);
+            }
+
+            // max out loan
+            let mut loaned_samples = vec![];
+            for _ in 0..max_loan {
+                let sample = sut_publisher.loan_uninit();
+                assert_that!(sample, is_ok);
+                loaned_samples.push(sample);
+            }
+
+            let sample = sut_publisher.loan_uninit();
+            assert_that!(sample, is_err);
+            assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+
+            // cleanup
+            borrowed_samples.clear();
+            loaned_samples.clear();
+            for s in subscribers.iter().take(max_subscribers) {
+                while let Ok(Some(_)) = s.receive() {}
+            }
+        }
+    }
+
+    #[test]
+    fn publisher_never_goes_out_of_memory_with_huge_max_loan<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        const HISTORY_SIZE: usize = 0;
+        const MAX_BORROW: usize = 1;
+        const MAX_SUBSCRIBERS: usize = 1;
+        const MAX_LOAN: usize = 100;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[test]
+    fn publisher_never_goes_out_of_memory_with_huge_max_subscribers<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        const HISTORY_SIZE: usize = 0;
+        const MAX_BORROW: usize = 1;
+        const MAX_SUBSCRIBERS: usize = 50;
+        const MAX_LOAN: usize = 1;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[test]
+    fn publisher_never_goes_out_of_memory_with_huge_max_borrow<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        const HISTORY_SIZE: usize = 0;
+        const MAX_BORROW: usize = 100;
+        const MAX_SUBSCRIBERS: usize = 1;
+        const MAX_LOAN: usize = 1;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[test]
+    fn publisher_never_goes_out_of_memory_with_huge_history_size<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        const HISTORY_SIZE: usize = 100;
+        const MAX_BORROW: usize = 1;
+        const MAX_SUBSCRIBERS: usize = 1;
+        const MAX_LOAN: usize = 1;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[test]
+    fn publisher_never_goes_out_of_memory_with_huge_buffer_size<Sut: Service>() {
+        const BUFFER_SIZE: usize = 3;
+        const HISTORY_SIZE: usize = 0;
+        const MAX_BORROW: usize = 1;
+        const MAX_SUBSCRIBERS: usize = 1;
+        const MAX_LOAN: usize = 1;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    #[test]
+    fn publisher_never_goes_out_of_memory_with_huge_values<Sut: Service>() {
+        const BUFFER_SIZE: usize = 29;
+        const HISTORY_SIZE: usize = 31;
+        const MAX_BORROW: usize = 12;
+        const MAX_SUBSCRIBERS: usize = 25;
+        const MAX_LOAN: usize = 35;
+
+        publisher_never_goes_out_of_memory_impl::<Sut>(
+            BUFFER_SIZE,
+            HISTORY_SIZE,
+            MAX_BORROW,
+            MAX_SUBSCRIBERS,
+            MAX_LOAN,
+        );
+    }
+
+    fn retrieve_channel_capacity_is_never_exceeded<Sut: Service>(
+        publisher_borrow_size: usize,
+        buffer_size: usize,
+        max_borrow: usize,
+    ) {
+        const ITERATIONS: usize = 16;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .max_publishers(1)
+            .max_subscribers(1)
+            .enable_safe_overflow(false)
+            .history_size(0)
+            .subscriber_max_buffer_size(buffer_size)
+            .subscriber_max_borrowed_samples(max_borrow)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(publisher_borrow_size)
+            .create()
+            .unwrap();
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut borrowed_samples = vec![];
+        let mut cached_samples = vec![];
+
+        let mut send_sample = || {
+            if borrowed_samples.is_empty() {
+                for _ in 0..publisher_borrow_size {
+                    borrowed_samples.push(sut_publisher.loan().unwrap());
+                }
+            }
+
+            let sample = borrowed_samples.pop().unwrap();
+            sample.send().unwrap();
+        };
+
+        for _ in 0..ITERATIONS {
+            for _ in 0..max_borrow {
+                send_sample();
+                cached_samples.push(sut_subscriber.receive().unwrap().unwrap());
+            }
+
+            for _ in 0..buffer_size {
+                send_sample();
+            }
+
+            cached_samples.clear();
+            for _ in 0..buffer_size {
+                sut_subscriber.receive().unwrap().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn retrieve_channel_capacity_is_never_exceeded_with_large_publisher_borrow_size<
+        Sut: Service,
+    >() {
+        const PUBLISHER_BORROW_SIZE: usize = 10;
+        const BUFFER_SIZE: usize = 1;
+        const MAX_BORROW: usize = 1;
+        retrieve_channel_capacity_is_never_exceeded::<Sut>(
+            PUBLISHER_BORROW_SIZE,
+            BUFFER_SIZE,
+            MAX_BORROW,
+        );
+    }
+
+    #[test]
+    fn retrieve_channel_capacity_is_never_exceeded_with_large_buffer_size<Sut: Service>() {
+        const PUBLISHER_BORROW_SIZE: usize = 1;
+        const BUFFER_SIZE: usize = 10;
+        const MAX_BORROW: usize = 1;
+        retrieve_channel_capacity_is_never_exceeded::<Sut>(
+            PUBLISHER_BORROW_SIZE,
+            BUFFER_SIZE,
+            MAX_BORROW,
+        );
+    }
+
+    #[test]
+    fn retrieve_channel_capacity_is_never_exceeded_with_large_max_borrow<Sut: Service>() {
+        const PUBLISHER_BORROW_SIZE: usize = 1;
+        const BUFFER_SIZE: usize = 1;
+        const MAX_BORROW: usize = 10;
+
+        retrieve_channel_capacity_is_never_exceeded::<Sut>(
+            PUBLISHER_BORROW_SIZE,
+            BUFFER_SIZE,
+            MAX_BORROW,
+        );
+    }
+
+    #[test]
+    fn retrieve_channel_capacity_is_never_exceeded_with_large_settings<Sut: Service>() {
+        const PUBLISHER_BORROW_SIZE: usize = 20;
+        const BUFFER_SIZE: usize = 14;
+        const MAX_BORROW: usize = 15;
+
+        retrieve_channel_capacity_is_never_exceeded::<Sut>(
+            PUBLISHER_BORROW_SIZE,
+            BUFFER_SIZE,
+            MAX_BORROW,
+        );
+    }
+
+    #[test]
+    fn retrieve_channel_capacity_is_never_exceeded_with_small_settings<Sut: Service>() {
+        const PUBLISHER_BORROW_SIZE: usize = 1;
+        const BUFFER_SIZE: usize = 1;
+        const MAX_BORROW: usize = 1;
+
+        retrieve_channel_capacity_is_never_exceeded::<Sut>(
+            PUBLISHER_BORROW_SIZE,
+            BUFFER_SIZE,
+            MAX_BORROW,
+        );
+    }
+
+    #[test]
+    fn creating_max_supported_amount_of_ports_work<Sut: Service>() {
+        const MAX_PUBLISHERS: usize = 4;
+        const MAX_SUBSCRIBERS: usize = 8;
+
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(MAX_PUBLISHERS)
+            .max_subscribers(MAX_SUBSCRIBERS)
+            .create()
+            .unwrap();
+
+        let mut publishers = vec![];
+        let mut subscribers = vec![];
+
+        // acquire all possible ports
+        for _ in 0..MAX_PUBLISHERS {
+            let publisher = sut.publisher_builder().create();
+            assert_that!(publisher, is_ok);
+            publishers.push(publisher);
+        }
+
+        for _ in 0..MAX_SUBSCRIBERS {
+            let subscriber = sut.subscriber_builder().create();
+            assert_that!(subscriber, is_ok);
+            subscribers.push(subscriber);
+        }
+
+        // create additional ports and fail
+        let publisher = sut.publisher_builder().create();
+        assert_that!(publisher, is_err);
+        assert_that!(
+            publisher.err().unwrap(), eq
+            PublisherCreateError::ExceedsMaxSupportedPublishers
+        );
+
+        let subscriber = sut.subscriber_builder().create();
+        assert_that!(subscriber, is_err);
+        assert_that!(
+            subscriber.err().unwrap(), eq
+            SubscriberCreateError::ExceedsMaxSupportedSubscribers
+        );
+
+        // remove a publisher and subscriber
+        assert_that!(publishers.remove(0), is_ok);
+        assert_that!(subscribers.remove(0), is_ok);
+
+        // create additional ports shall work again
+        let publisher = sut.publisher_builder().create();
+        assert_that!(publisher, is_ok);
+
+        let subscriber = sut.subscriber_builder().create();
+        assert_that!(subscriber, is_ok);
+    }
+
+    #[test]
+    fn set_max_nodes_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_nodes(0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_nodes(), eq 1);
+    }
+
+    #[test]
+    fn set_max_publishers_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        config.defaults.publish_subscribe.max_publishers = 0;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_publishers(), eq 1);
+
+        let publisher = sut.publisher_builder().create();
+        assert_that!(publisher, is_ok);
+    }
+
+    #[test]
+    fn set_max_subscribers_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_subscribers(), eq 1);
+    }
+
+    #[test]
+    fn set_subscriber_max_borrowed_samples_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().subscriber_max_borrowed_samples(), eq 1);
+    }
+
+    #[test]
+    fn set_buffer_size_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 1);
+    }
+
+    #[test]
+    fn does_exist_works_single<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
+
+        drop(_sut);
+
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+    }
+
+    #[test]
+    fn destroy_service_with_ports_of_alive_process_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let result = Sut::destroy(&service_name, &config, MessagingPattern::PublishSubscribe);
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq ServiceDestroyError::ServiceInUse(2));
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
+
+        publisher.send_copy(1234).unwrap();
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+
+        drop(publisher);
+
+        let result = Sut::destroy(&service_name, &config, MessagingPattern::PublishSubscribe);
+        assert_that!(result.err().unwrap(), eq ServiceDestroyError::ServiceInUse(1));
+
+        drop(subscriber);
+
+        let result = Sut::destroy(&service_name, &config, MessagingPattern::PublishSubscribe);
+        assert_that!(result.err().unwrap(), eq ServiceDestroyError::ServiceStillOpened);
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
+    }
+
+    #[test]
+    fn destroy_forced_removes_service_with_ports_of_alive_process<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        let result = unsafe {
+            Sut::destroy_forced(&service_name, &config, MessagingPattern::PublishSubscribe)
+        };
+        assert_that!(result, is_ok);
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+
+        // the resources of the service handle and the port are gone, they must not be dropped
+        core::mem::forget(publisher);
+        core::mem::forget(sut);
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn does_exist_works_many<Sut: Service>() {
+        const NUMBER_OF_SERVICES: usize = 8;
+
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let mut services = vec![];
+        let mut service_names = vec![];
+
+        for i in 0..NUMBER_OF_SERVICES {
+            let service_name = generate_name();
+            assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+
+            services.push(
+                node.service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .create()
+                    .unwrap(),
+            );
+            service_names.push(service_name);
+
+            for s in service_names.iter().take(i + 1) {
+                assert_that!(Sut::does_exist(s, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
+            }
+        }
+
+        for i in 0..NUMBER_OF_SERVICES {
+            for s in service_names.iter().take(NUMBER_OF_SERVICES - i) {
+                assert_that!(Sut::does_exist(s, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
+            }
+
+            for s in service_names
+                .iter()
+                .take(NUMBER_OF_SERVICES)
+                .skip(NUMBER_OF_SERVICES - i)
+            {
+                assert_that!(Sut::does_exist(s, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+            }
+
+            services.pop();
+        }
+    }
+
+    #[test]
+    fn list_works<Sut: Service>() {
+        const NUMBER_OF_SERVICES: usize = 8;
+
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let mut services = vec![];
+        let mut service_names = vec![];
+
+        let contains_service_names = |names, state: Vec<ServiceDetails<Sut>>| {
+            for n in names {
+                let mut name_found = false;
+                for s in &state {
+                    if *s.static_details.name() == n {
+                        name_found = true;
+                        break;
+                    }
+                }
+
+                if !name_found {
+                    return false;
+                }
+            }
+
+            true
+        };
+
+        for i in 0..NUMBER_OF_SERVICES {
+            let service_name = generate_name();
+
+            services.push(
+                node.service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .create()
+                    .unwrap(),
+            );
+            service_names.push(service_name);
+
+            let mut service_list = vec![];
+            Sut::list(&config, |s| {
+                service_list.push(s);
+                CallbackProgression::Continue
+            })
+            .unwrap();
+            assert_that!(service_list, len i + 1);
+
+            assert_that!(contains_service_names(service_names.clone(), service_list), eq true);
+        }
+
+        for i in 0..NUMBER_OF_SERVICES {
+            services.pop();
+            service_names.pop();
+
+            let mut service_list = vec![];
+            Sut::list(&config, |s| {
+                service_list.push(s);
+                CallbackProgression::Continue
+            })
+            .unwrap();
+            assert_that!(service_list, len NUMBER_OF_SERVICES - i - 1);
+            assert_that!(contains_service_names(service_names.clone(), service_list), eq true);
+        }
+    }
+
+    #[test]
+    fn dropping_service_keeps_established_communication<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        drop(sut);
+
+        const PAYLOAD: u64 = 98129312938;
+
+        assert_that!(publisher.send_copy(PAYLOAD), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq PAYLOAD);
+    }
+    #[test]
+    fn ports_of_dropped_service_block_new_service_creation<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        drop(sut);
+
+        assert_that!(node.service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create().err().unwrap(),
+            eq PublishSubscribeCreateError::AlreadyExists);
+
+        drop(subscriber);
+
+        assert_that!(node.service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create().err().unwrap(),
+            eq PublishSubscribeCreateError::AlreadyExists);
+
+        drop(publisher);
+
+        assert_that!(
+            node.service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .create(),
+            is_ok
+        );
+    }
+
+    #[test]
+    fn service_can_be_opened_when_there_is_a_publisher<Sut: Service>() {
+        let payload = 1809723987;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        drop(sut);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut, is_ok);
+        drop(sut);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeCreateError::AlreadyExists);
+        drop(subscriber);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        publisher.send_copy(payload).unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.payload(), eq payload);
+
+        drop(subscriber);
+        drop(sut);
+        drop(publisher);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn service_can_be_opened_when_there_is_a_subscriber<Sut: Service>() {
+        let payload = 59123544;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        drop(sut);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut, is_ok);
+        drop(sut);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeCreateError::AlreadyExists);
+        drop(publisher);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        publisher.send_copy(payload).unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.payload(), eq payload);
+
+        drop(publisher);
+        drop(sut);
+        drop(subscriber);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+    }
 
-                create_service_barrier.wait();
-                let mut counter = 1u64;
+    #[test]
+    fn subscriber_can_decrease_buffer_size<Sut: Service>() {
+        const BUFFER_SIZE: usize = 16;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-                while keep_running.load(Ordering::Relaxed) {
-                    assert_that!(publisher.send_copy(counter), is_ok);
-                    counter += 1;
-                }
-            });
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
 
-            create_service_barrier.wait();
-            let mut threads = vec![];
-            for _ in 0..NUMBER_OF_SUBSCRIBER_THREADS {
-                threads.push(s.spawn(|| {
-                    let sut = node
-                        .lock()
-                        .unwrap()
-                        .service_builder(&service_name)
-                        .publish_subscribe::<u64>()
-                        .open()
-                        .unwrap();
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .open()
+            .unwrap();
 
-                    let mut latest_counter = 0u64;
-                    for _ in 0..NUMBER_OF_RECONNECTIONS {
-                        let subscriber = sut.subscriber_builder().create().unwrap();
+        for i in 1..=BUFFER_SIZE {
+            let publisher_before_sub = sut2.publisher_builder().create().unwrap();
+            let subscriber = sut.subscriber_builder().buffer_size(i).create().unwrap();
+            let publisher_after_sub = sut2.publisher_builder().create().unwrap();
 
-                        loop {
-                            if let Some(counter) = subscriber.receive().unwrap() {
-                                assert_that!(latest_counter, lt * counter);
-                                latest_counter = *counter;
-                                break;
-                            }
-                        }
-                    }
-                }));
+            assert_that!(subscriber.buffer_size(), eq i);
+
+            for n in 0..i * 2 {
+                assert_that!(publisher_before_sub.send_copy(n), is_ok);
             }
 
-            for t in threads {
-                t.join().unwrap();
+            for n in 0..i {
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(*sample, eq i + n);
             }
-            keep_running.store(false, Ordering::Relaxed);
-        });
+
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_none);
+
+            for n in 0..i * 2 {
+                assert_that!(publisher_after_sub.send_copy(n as _), is_ok);
+            }
+
+            for n in 0..i {
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(*sample, eq i + n);
+            }
+
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_none);
+        }
     }
 
     #[test]
-    fn concurrent_communication_with_publisher_reconnects_does_not_deadlock<Sut: Service>() {
-        let _watch_dog = Watchdog::new();
+    fn subscriber_creation_fails_when_buffer_size_exceeds_service_max<Sut: Service>() {
+        const BUFFER_SIZE: usize = 16;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        const NUMBER_OF_PUBLISHER_THREADS: usize = 2;
-        const NUMBER_OF_RECONNECTIONS: usize = 50;
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
 
-        let create_service_barrier = Barrier::new(1 + NUMBER_OF_PUBLISHER_THREADS);
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let subscriber = sut2
+            .subscriber_builder()
+            .buffer_size(BUFFER_SIZE + 1)
+            .create();
+        assert_that!(subscriber, is_err);
+        assert_that!(subscriber.err().unwrap(), eq SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService);
+    }
+
+    #[test]
+    fn subscriber_buffer_size_is_at_least_one<Sut: Service>() {
         let service_name = generate_name();
-        let keep_running = AtomicBool::new(true);
-        let reconnection_cycle = AtomicUsize::new(0);
         let config = generate_isolated_config();
-        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
 
-        thread::scope(|s| {
-            s.spawn(|| {
-                let sut = node
-                    .lock()
-                    .unwrap()
-                    .service_builder(&service_name)
-                    .publish_subscribe::<u64>()
-                    .max_publishers(NUMBER_OF_PUBLISHER_THREADS)
-                    .open_or_create()
-                    .unwrap();
+        let subscriber = sut.subscriber_builder().buffer_size(0).create().unwrap();
+        assert_that!(subscriber.buffer_size(), eq 1);
+    }
 
-                let subscriber = sut.subscriber_builder().create().unwrap();
-                create_service_barrier.wait();
+    #[test]
+    fn subscriber_can_decrease_max_borrowed_samples<Sut: Service>() {
+        const MAX_BORROWED_SAMPLES: usize = 4;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-                while keep_running.load(Ordering::Relaxed) {
-                    if let Some(_) = subscriber.receive().unwrap() {
-                        if reconnection_cycle.fetch_add(1, Ordering::Relaxed)
-                            == NUMBER_OF_RECONNECTIONS
-                        {
-                            keep_running.store(false, Ordering::Relaxed);
-                        }
-                    }
-                }
-            });
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(MAX_BORROWED_SAMPLES + 1)
+            .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .create()
+            .unwrap();
 
-            for _ in 0..NUMBER_OF_PUBLISHER_THREADS {
-                s.spawn(|| {
-                    let sut2 = node
-                        .lock()
-                        .unwrap()
-                        .service_builder(&service_name)
-                        .publish_subscribe::<u64>()
-                        .max_publishers(NUMBER_OF_PUBLISHER_THREADS)
-                        .open_or_create()
-                        .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
 
-                    create_service_barrier.wait();
+        for i in 1..=MAX_BORROWED_SAMPLES {
+            let subscriber = sut
+                .subscriber_builder()
+                .max_borrowed_samples(i)
+                .create()
+                .unwrap();
+            assert_that!(subscriber.max_borrowed_samples(), eq i);
 
-                    while keep_running.load(Ordering::Relaxed) {
-                        let publisher = sut2.publisher_builder().create().unwrap();
+            for n in 0..=i {
+                assert_that!(publisher.send_copy(n), is_ok);
+            }
 
-                        let current_cycle = reconnection_cycle.load(Ordering::Relaxed);
-                        let mut counter = 1u64;
-                        while current_cycle == reconnection_cycle.load(Ordering::Relaxed)
-                            && keep_running.load(Ordering::Relaxed)
-                        {
-                            assert_that!(publisher.send_copy(counter), is_ok);
-                            counter += 1;
-                        }
-                    }
-                });
+            let mut samples = vec![];
+            for _ in 0..i {
+                samples.push(subscriber.receive().unwrap().unwrap());
+            }
+
+            assert_that!(subscriber.receive().err(), eq Some(SubscriberReceiveError::ExceedsMaxBorrowedSamples));
+            samples.pop();
+            assert_that!(subscriber.receive().unwrap(), is_some);
+        }
+    }
+
+    #[test]
+    fn subscriber_creation_fails_when_max_borrowed_samples_exceeds_service_max<Sut: Service>() {
+        const MAX_BORROWED_SAMPLES: usize = 4;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .max_borrowed_samples(MAX_BORROWED_SAMPLES + 1)
+            .create();
+        assert_that!(subscriber.err(), eq Some(SubscriberCreateError::MaxBorrowedSamplesExceedsMaxSupportedBorrowedSamplesOfService));
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(subscriber.max_borrowed_samples(), eq MAX_BORROWED_SAMPLES);
+
+        let subscriber = sut
+            .subscriber_builder()
+            .max_borrowed_samples(0)
+            .create()
+            .unwrap();
+        assert_that!(subscriber.max_borrowed_samples(), eq 1);
+    }
+
+    #[test]
+    fn packed_payload_with_misaligned_fields_is_delivered<Sut: Service>() {
+        #[derive(Debug, Clone, Copy)]
+        #[repr(C, packed)]
+        struct Packed {
+            flag: u8,
+            value: u64,
+        }
+
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Packed>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().message_type_details().payload.alignment, eq 1);
+        assert_that!(sut.static_config().message_type_details().payload.size, eq 9);
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(Packed { flag: 1, value: 0x1122334455667788 }), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!({ sample.flag }, eq 1);
+        assert_that!({ sample.value }, eq 0x1122334455667788);
+    }
+
+    #[cfg(all(unix, feature = "tokio"))]
+    #[test]
+    fn receive_async_completes_when_sample_arrives<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
+        const TIMEOUT: Duration = Duration::from_secs(10);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        tokio_runtime().block_on(async {
+            let mut future = core::pin::pin!(subscriber.receive_async());
+            assert_that!(poll_once(future.as_mut()).is_pending(), eq true);
+
+            publisher.send_copy(8127).unwrap();
+
+            match tokio::time::timeout(TIMEOUT, future).await {
+                Ok(Ok(sample)) => assert_that!(*sample, eq 8127),
+                _ => test_fail!("receive_async was not woken up by the sent sample"),
             }
         });
     }
 
+    #[cfg(all(unix, feature = "tokio"))]
+    #[test]
+    fn receive_async_is_woken_up_by_publisher_of_another_thread<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
+        const TIMEOUT: Duration = Duration::from_secs(10);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let is_received = Barrier::new(2);
+
+        thread::scope(|s| {
+            s.spawn(|| {
+                let publisher = sut.publisher_builder().create().unwrap();
+                thread::sleep(Duration::from_millis(50));
+                publisher.send_copy(3719).unwrap();
+                // the sample is lost when the publisher goes out of scope before it is received
+                is_received.wait();
+            });
+
+            tokio_runtime().block_on(async {
+                let sample = tokio::time::timeout(TIMEOUT, subscriber.receive_async()).await;
+                is_received.wait();
+                match sample {
+                    Ok(Ok(sample)) => assert_that!(*sample, eq 3719),
+                    _ => test_fail!("receive_async was not woken up by the sent sample"),
+                }
+            });
+        });
+    }
+
+    #[cfg(all(unix, feature = "tokio"))]
     #[test]
-    fn communication_with_max_subscribers_and_publishers<Sut: Service>() {
-        const MAX_PUB: usize = 4;
-        const MAX_SUB: usize = 6;
-        const NUMBER_OF_ITERATIONS: u64 = 128;
+    fn sample_signal_is_only_notified_while_receive_async_waits<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_publishers(MAX_PUB)
-            .max_subscribers(MAX_SUB)
             .create()
             .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let sample_signal = subscriber.sample_signal_file_descriptor().unwrap();
 
-        let mut publishers = vec![];
-        publishers.reserve(MAX_PUB);
+        publisher.send_copy(1).unwrap();
+        assert_that!(is_readable(sample_signal), eq false);
 
-        for _ in 0..MAX_PUB {
-            publishers.push(sut.publisher_builder().create().unwrap());
+        let runtime = tokio_runtime();
+        let _runtime_context = runtime.enter();
+        let future = core::pin::pin!(subscriber.receive_async());
+        match poll_once(future) {
+            Poll::Ready(Ok(sample)) => assert_that!(*sample, eq 1),
+            _ => test_fail!("receive_async did not complete with the available sample"),
         }
 
-        let mut subscribers = vec![];
-        subscribers.reserve(MAX_SUB);
-
-        for _ in 0..MAX_SUB {
-            subscribers.push(sut.subscriber_builder().create().unwrap());
+        {
+            let mut future = core::pin::pin!(subscriber.receive_async());
+            assert_that!(poll_once(future.as_mut()).is_pending(), eq true);
         }
 
-        let mut counter = 0;
-        for _ in 0..NUMBER_OF_ITERATIONS {
-            for publisher in &mut publishers {
-                assert_that!(publisher.send_copy(counter), is_ok);
-
-                for subscriber in &subscribers {
-                    let sample = subscriber.receive().unwrap();
-                    assert_that!(sample, is_some);
-                    assert_that!(*sample.unwrap(), eq counter);
-                }
-                counter += 1;
-            }
-        }
+        publisher.send_copy(2).unwrap();
+        assert_that!(is_readable(sample_signal), eq false);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 2);
     }
 
+    #[cfg(all(unix, feature = "tokio"))]
     #[test]
-    fn multi_channel_communication_with_max_subscribers_and_publishers<Sut: Service>() {
-        const MAX_PUB: usize = 5;
-        const MAX_SUB: usize = 7;
-        const NUMBER_OF_ITERATIONS: u64 = 128;
+    fn dropping_pending_receive_async_future_does_not_lose_samples<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
-        let _sut = node
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .max_publishers(MAX_PUB)
-            .max_subscribers(MAX_SUB)
+            .publish_subscribe::<[u8]>()
             .create()
             .unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let mut channels = vec![];
-        channels.reserve(MAX_PUB + MAX_SUB);
-
-        for _ in 0..MAX_PUB + MAX_SUB {
-            channels.push(
-                node.service_builder(&service_name)
-                    .publish_subscribe::<u64>()
-                    .open()
-                    .unwrap(),
-            );
+        let runtime = tokio_runtime();
+        let _runtime_context = runtime.enter();
+        {
+            let mut future = core::pin::pin!(subscriber.receive_async());
+            assert_that!(poll_once(future.as_mut()).is_pending(), eq true);
         }
 
-        let mut publishers = vec![];
-        publishers.reserve(MAX_PUB);
+        publisher.send_slice_copy(&[1, 2, 3]).unwrap();
 
-        for c in channels.iter().take(MAX_PUB) {
-            publishers.push(c.publisher_builder().create().unwrap());
-        }
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq [1, 2, 3]);
+    }
 
-        let mut subscribers = vec![];
-        subscribers.reserve(MAX_SUB);
+    #[test]
+    fn sliced_service_works<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 91;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
 
-        for i in 0..MAX_SUB {
-            subscribers.push(channels[i + MAX_PUB].subscriber_builder().create().unwrap());
-        }
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let mut counter = 0;
-        for _ in 0..NUMBER_OF_ITERATIONS {
-            for publisher in &mut publishers {
-                assert_that!(publisher.send_copy(counter), is_ok);
+        for n in 0..=MAX_ELEMENTS {
+            let sample = publisher.loan_slice_uninit(n).unwrap();
+            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
 
-                for subscriber in &subscribers {
-                    let sample = subscriber.receive().unwrap();
-                    assert_that!(sample, is_some);
-                    assert_that!(*sample.unwrap(), eq counter);
-                }
-                counter += 1;
+            let recv_sample = subscriber.receive().unwrap().unwrap();
+
+            assert_that!(recv_sample.payload(), len n);
+            for (i, element) in recv_sample.payload().iter().enumerate() {
+                assert_that!(*element, eq i as u64 * 25);
             }
         }
     }
 
     #[test]
-    fn publish_safely_overflows_when_enabled<Sut: Service>() {
+    fn sliced_service_delivers_number_of_elements_in_header<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 17;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const BUFFER_SIZE: usize = 2;
-
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .enable_safe_overflow(true)
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .publish_subscribe::<[u32]>()
             .create()
             .unwrap();
 
-        let publisher = sut.publisher_builder().create().unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .create()
+            .unwrap();
         let subscriber = sut.subscriber_builder().create().unwrap();
 
-        for i in 0..BUFFER_SIZE {
-            assert_that!(publisher.send_copy(i), is_ok);
-        }
+        for n in (0..=MAX_ELEMENTS).rev() {
+            let payload: Vec<u32> = (0..n as u32).map(|i| i * 3).collect();
+            assert_that!(publisher.send_slice_copy(&payload), eq Ok(1));
 
-        for i in 0..BUFFER_SIZE {
-            assert_that!(publisher.send_copy(2 * i + 25), is_ok);
-        }
+            let recv_sample = subscriber.receive().unwrap().unwrap();
 
-        for i in 0..BUFFER_SIZE {
-            let sample = subscriber.receive().unwrap().unwrap();
-            assert_that!(*sample, eq 2 * i + 25);
+            assert_that!(recv_sample.header().number_of_elements(), eq n as u64);
+            assert_that!(recv_sample.len(), eq n);
+            assert_that!(recv_sample.is_empty(), eq n == 0);
+            assert_that!(recv_sample.as_slice(), eq payload.as_slice());
         }
     }
 
     #[test]
-    fn publish_does_not_overflow_when_deactivated<Sut: Service>() {
+    fn send_slice_copy_fails_when_slice_exceeds_max_slice_len<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 4;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const BUFFER_SIZE: usize = 5;
-
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .enable_safe_overflow(false)
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .publish_subscribe::<[u32]>()
             .create()
             .unwrap();
 
         let publisher = sut
             .publisher_builder()
-            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .initial_max_slice_len(MAX_ELEMENTS)
             .create()
             .unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
-
-        for i in 0..BUFFER_SIZE {
-            assert_that!(publisher.send_copy(i), is_ok);
-        }
-
-        for i in 0..BUFFER_SIZE {
-            assert_that!(publisher.send_copy(2 * i + 25), is_ok);
-        }
 
-        for i in 0..BUFFER_SIZE {
-            let sample = subscriber.receive().unwrap().unwrap();
-            assert_that!(*sample, eq i);
-        }
+        let payload = [0u32; MAX_ELEMENTS + 1];
+        let result = publisher.send_slice_copy(&payload);
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq PublisherSendError::LoanError(PublisherLoanError::ExceedsMaxLoanSize));
     }
 
     #[test]
-    fn publish_non_overflow_with_greater_history_than_buffer_fails<Sut: Service>() {
+    fn slice_aligned_service_works<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 91;
+        const ALIGNMENT: usize = 64;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let sut = node
+        let service_pub = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .enable_safe_overflow(false)
-            .history_size(12)
-            .subscriber_max_buffer_size(11)
-            .create();
+            .publish_subscribe::<[u64]>()
+            .subscriber_max_buffer_size(MAX_ELEMENTS + 1)
+            .subscriber_max_borrowed_samples(MAX_ELEMENTS + 1)
+            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
+            .create()
+            .unwrap();
 
-        assert_that!(sut, is_err);
-        assert_that!(
-            sut.err().unwrap(), eq
-            PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize
-        );
+        let service_sub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .open()
+            .unwrap();
+
+        let publisher = service_pub
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = service_sub.subscriber_builder().create().unwrap();
+
+        let mut samples = vec![];
+        for n in 0..=MAX_ELEMENTS {
+            let sample = publisher.loan_slice_uninit(n).unwrap();
+            assert_that!((sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
+            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
+
+            let recv_sample = subscriber.receive().unwrap().unwrap();
+
+            assert_that!((recv_sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
+            assert_that!(recv_sample.payload(), len n);
+            for (i, element) in recv_sample.payload().iter().enumerate() {
+                assert_that!(*element, eq i as u64 * 25);
+            }
+            samples.push(recv_sample);
+        }
     }
 
     #[test]
-    fn publish_history_is_delivered_on_subscription<Sut: Service>() {
-        const BUFFER_SIZE: usize = 2;
+    fn slice_of_over_aligned_elements_respects_element_alignment<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 9;
+        const ALIGNMENT: usize = 128;
+
+        #[derive(Debug, Default, Clone, Copy, PartialEq)]
+        #[repr(C, align(128))]
+        struct OverAligned {
+            value: u64,
+        }
+
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .history_size(3)
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .publish_subscribe::<[OverAligned]>()
             .create()
             .unwrap();
+        assert_that!(sut.static_config().message_type_details().payload.alignment, eq ALIGNMENT);
+        assert_that!(sut.static_config().message_type_details().payload.variant, eq TypeVariant::Dynamic);
 
-        let sut_publisher = sut.publisher_builder().create().unwrap();
-        assert_that!(sut_publisher.send_copy(29), is_ok);
-        assert_that!(sut_publisher.send_copy(32), is_ok);
-        assert_that!(sut_publisher.send_copy(35), is_ok);
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let sut_subscriber = sut.subscriber_builder().create().unwrap();
-        assert_that!(sut_publisher.update_connections(), is_ok);
+        for n in 1..=MAX_ELEMENTS {
+            let sample = publisher.loan_slice_uninit(n).unwrap();
+            assert_that!((sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
+            sample
+                .write_from_fn(|i| OverAligned { value: i as u64 })
+                .send()
+                .unwrap();
 
-        for i in 0..BUFFER_SIZE {
-            let data = sut_subscriber.receive().unwrap();
-            assert_that!(data, is_some);
-            assert_that!(*data.unwrap(), eq 29 + (i + 1) * 3 )
+            let recv_sample = subscriber.receive().unwrap().unwrap();
+            assert_that!((recv_sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
+            assert_that!(recv_sample.payload(), len n);
+            for (i, element) in recv_sample.payload().iter().enumerate() {
+                assert_that!(element.value, eq i as u64);
+            }
         }
     }
 
     #[test]
-    fn publish_history_of_zero_works<Sut: Service>() {
-        const BUFFER_SIZE: usize = 2;
+    fn simple_communication_with_user_header_works<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .history_size(0)
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .publish_subscribe::<u64>()
+            .user_header::<SomeUserHeader>()
             .create()
             .unwrap();
 
-        let sut_publisher = sut.publisher_builder().create().unwrap();
-        assert_that!(sut_publisher.send_copy(29), is_ok);
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<SomeUserHeader>()
+            .open()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut2.publisher_builder().create().unwrap();
+        assert_that!(subscriber.update_connections(), is_ok);
+        let mut sample = publisher.loan().unwrap();
+
+        for i in 0..1024 {
+            sample.user_header_mut().value[i] = i as u64;
+        }
+        *sample.payload_mut() = 1829731;
+        sample.send().unwrap();
 
-        let sut_subscriber = sut.subscriber_builder().create().unwrap();
-        assert_that!(sut_publisher.update_connections(), is_ok);
+        let result = subscriber.receive().unwrap();
+        assert_that!(result, is_some);
+        let sample = result.unwrap();
+        assert_that!(*sample, eq 1829731);
+        assert_that!(*sample.payload(), eq 1829731);
 
-        let data = sut_subscriber.receive().unwrap();
-        assert_that!(data, is_none);
+        for i in 0..1024 {
+            assert_that!(sample.user_header().value[i], eq i as u64);
+        }
     }
 
     #[test]
-    fn publish_send_copy_with_huge_overflow_works<Sut: Service>() {
+    fn same_payload_type_but_different_user_header_does_not_connect<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const BUFFER_SIZE: usize = 5;
 
-        let sut = node
+        let _sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .max_publishers(1)
-            .max_subscribers(2)
-            .history_size(0)
-            .subscriber_max_buffer_size(BUFFER_SIZE)
-            .subscriber_max_borrowed_samples(1)
-            .create()
-            .unwrap();
-
-        let sut_publisher = sut
-            .publisher_builder()
-            .max_loaned_samples(1)
+            .publish_subscribe::<u64>()
+            .user_header::<SomeUserHeader>()
             .create()
             .unwrap();
 
-        let mut subscribers = vec![];
-        for _ in 0..2 {
-            let sut_subscriber = sut.subscriber_builder().create();
-            subscribers.push(sut_subscriber);
-        }
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
 
-        for _ in 0..BUFFER_SIZE * 100 {
-            assert_that!(sut_publisher.send_copy(889), is_ok);
-        }
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
-    fn publisher_never_goes_out_of_memory_impl<Sut: Service>(
-        buffer_size: usize,
-        history_size: usize,
-        max_borrow: usize,
-        max_subscribers: usize,
-        max_loan: usize,
-    ) {
-        const ITERATIONS: usize = 16;
+    #[test]
+    fn create_with_zero_sized_payload_type_fails<Sut: Service>() {
+        #[derive(Debug)]
+        struct Empty;
+
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .max_publishers(1)
-            .max_subscribers(max_subscribers)
-            .enable_safe_overflow(true)
-            .history_size(history_size)
-            .subscriber_max_buffer_size(buffer_size)
-            .subscriber_max_borrowed_samples(max_borrow)
-            .create()
-            .unwrap();
-
-        let sut_publisher = sut
-            .publisher_builder()
-            .max_loaned_samples(max_loan)
-            .create()
-            .unwrap();
-
-        let mut subscribers = vec![];
-        for _ in 0..max_subscribers {
-            let sut_subscriber = sut.subscriber_builder().create().unwrap();
-            subscribers.push(sut_subscriber);
-        }
-
-        for _ in 0..ITERATIONS {
-            // max out borrow
-            let mut borrowed_samples = vec![];
-            while borrowed_samples.len() < max_borrow * max_subscribers {
-                for _ in 0..buffer_size {
-                    assert_that!(sut_publisher.send_copy(889), is_ok);
-                }
+            .publish_subscribe::<()>()
+            .create();
+        assert_that!(sut.err(), eq Some(PublishSubscribeCreateError::PayloadTypeHasZeroSize));
 
-                for (i, s) in subscribers.iter().enumerate().take(max_subscribers) {
-                    while let Ok(Some(sample)) = s.receive() {
-                        borrowed_samples.push((i, sample));
-                    }
-                }
-            }
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[Empty]>()
+            .create();
+        assert_that!(sut.err(), eq Some(PublishSubscribeCreateError::PayloadTypeHasZeroSize));
 
-            // max out buffer
-            for _ in 0..buffer_size {
-                assert_that!(sut_publisher.send_copy(8127), is_ok);
-            }
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Empty>()
+            .open_or_create();
+        assert_that!(sut.err(), eq Some(PublishSubscribeOpenOrCreateError::PublishSubscribeCreateError(
+            PublishSubscribeCreateError::PayloadTypeHasZeroSize
+        )));
 
-            // max out history
-            for _ in 0..history_size {
-                assert_that!(sut_publisher.send_copy(17283), is_ok);
-            }
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u8>()
+            .create();
+        assert_that!(sut, is_ok);
+    }
 
-            // max out loan
-            let mut loaned_samples = vec![];
-            for _ in 0..max_loan {
-                let sample = sut_publisher.loan_uninit();
-                assert_that!(sample, is_ok);
-                loaned_samples.push(sample);
-            }
+    #[test]
+    fn create_fails_when_required_sample_slots_exceed_limit<Sut: Service>() {
+        // 2 publishers * (3 subscribers * (4 buffer + 1 borrowed) + 2 history + 2 loaned)
+        const REQUIRED_SAMPLE_SLOTS: usize = 38;
+        let create_service = |max_sample_slots: usize| {
+            let mut config = generate_isolated_config();
+            // the defaults must fit into the limit, otherwise the node rejects the config
+            config.defaults.publish_subscribe.max_publishers = 1;
+            config.defaults.publish_subscribe.max_subscribers = 1;
+            config.defaults.publish_subscribe.subscriber_max_buffer_size = 1;
+            config
+                .defaults
+                .publish_subscribe
+                .subscriber_max_borrowed_samples = 1;
+            config.defaults.publish_subscribe.publisher_history_size = 0;
+            config.defaults.publish_subscribe.max_sample_slots = max_sample_slots;
+            config
+                .defaults
+                .publish_subscribe
+                .publisher_max_loaned_samples = 2;
+            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-            let sample = sut_publisher.loan_uninit();
-            assert_that!(sample, is_err);
-            assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedSamples);
+            node.service_builder(&generate_name())
+                .publish_subscribe::<u64>()
+                .max_publishers(2)
+                .max_subscribers(3)
+                .subscriber_max_buffer_size(4)
+                .subscriber_max_borrowed_samples(1)
+                .history_size(2)
+                .create()
+        };
 
-            // cleanup
-            borrowed_samples.clear();
-            loaned_samples.clear();
-            for s in subscribers.iter().take(max_subscribers) {
-                while let Ok(Some(_)) = s.receive() {}
-            }
-        }
+        assert_that!(create_service(REQUIRED_SAMPLE_SLOTS), is_ok);
+        assert_that!(create_service(REQUIRED_SAMPLE_SLOTS - 1).err(), eq Some(PublishSubscribeCreateError::ExceedsMaxSampleSlots));
     }
 
     #[test]
-    fn publisher_never_goes_out_of_memory_with_huge_max_loan<Sut: Service>() {
-        const BUFFER_SIZE: usize = 1;
-        const HISTORY_SIZE: usize = 0;
-        const MAX_BORROW: usize = 1;
-        const MAX_SUBSCRIBERS: usize = 1;
-        const MAX_LOAN: usize = 100;
+    fn node_rejects_config_that_defines_zero_subscriber_max_buffer_size<Sut: Service>() {
+        let mut config = generate_isolated_config();
+        config.defaults.publish_subscribe.subscriber_max_buffer_size = 0;
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
+        let sut = NodeBuilder::new().config(&config).create::<Sut>();
+        assert_that!(sut.err(), eq Some(NodeCreationFailure::InvalidConfig(
+            ConfigValidationError::SubscriberMaxBufferSizeIsZero)));
     }
 
     #[test]
-    fn publisher_never_goes_out_of_memory_with_huge_max_subscribers<Sut: Service>() {
-        const BUFFER_SIZE: usize = 1;
-        const HISTORY_SIZE: usize = 0;
-        const MAX_BORROW: usize = 1;
-        const MAX_SUBSCRIBERS: usize = 50;
-        const MAX_LOAN: usize = 1;
+    fn node_rejects_config_that_defines_zero_subscriber_max_borrowed_samples<Sut: Service>() {
+        let mut config = generate_isolated_config();
+        config
+            .defaults
+            .publish_subscribe
+            .subscriber_max_borrowed_samples = 0;
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
+        let sut = NodeBuilder::new().config(&config).create::<Sut>();
+        assert_that!(sut.err(), eq Some(NodeCreationFailure::InvalidConfig(
+            ConfigValidationError::SubscriberMaxBorrowedSamplesIsZero)));
     }
 
     #[test]
-    fn publisher_never_goes_out_of_memory_with_huge_max_borrow<Sut: Service>() {
-        const BUFFER_SIZE: usize = 1;
-        const HISTORY_SIZE: usize = 0;
-        const MAX_BORROW: usize = 100;
-        const MAX_SUBSCRIBERS: usize = 1;
-        const MAX_LOAN: usize = 1;
+    fn older_schema_can_read_common_prefix_of_newer_schema<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
-    }
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PoseV2>()
+            .enable_schema_evolution()
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
 
-    #[test]
-    fn publisher_never_goes_out_of_memory_with_huge_history_size<Sut: Service>() {
-        const BUFFER_SIZE: usize = 1;
-        const HISTORY_SIZE: usize = 100;
-        const MAX_BORROW: usize = 1;
-        const MAX_SUBSCRIBERS: usize = 1;
-        const MAX_LOAN: usize = 1;
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PoseV1>()
+            .enable_schema_evolution()
+            .open();
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        let subscriber = sut2.subscriber_builder().create().unwrap();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
+        assert_that!(publisher.send_copy(PoseV2 { x: 1, y: 2, heading: 3 }), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq PoseV1 { x: 1, y: 2 });
     }
 
     #[test]
-    fn publisher_never_goes_out_of_memory_with_huge_buffer_size<Sut: Service>() {
-        const BUFFER_SIZE: usize = 3;
-        const HISTORY_SIZE: usize = 0;
-        const MAX_BORROW: usize = 1;
-        const MAX_SUBSCRIBERS: usize = 1;
-        const MAX_LOAN: usize = 1;
+    fn newer_schema_cannot_open_service_with_older_schema<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PoseV1>()
+            .enable_schema_evolution()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PoseV2>()
+            .enable_schema_evolution()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
     }
 
     #[test]
-    fn publisher_never_goes_out_of_memory_with_huge_values<Sut: Service>() {
-        const BUFFER_SIZE: usize = 29;
-        const HISTORY_SIZE: usize = 31;
-        const MAX_BORROW: usize = 12;
-        const MAX_SUBSCRIBERS: usize = 25;
-        const MAX_LOAN: usize = 35;
+    fn schema_that_is_no_prefix_cannot_open_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        publisher_never_goes_out_of_memory_impl::<Sut>(
-            BUFFER_SIZE,
-            HISTORY_SIZE,
-            MAX_BORROW,
-            MAX_SUBSCRIBERS,
-            MAX_LOAN,
-        );
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PoseV2>()
+            .enable_schema_evolution()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PoseWithoutY>()
+            .enable_schema_evolution()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PoseV1>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
     }
 
-    fn retrieve_channel_capacity_is_never_exceeded<Sut: Service>(
-        publisher_borrow_size: usize,
-        buffer_size: usize,
-        max_borrow: usize,
-    ) {
-        const ITERATIONS: usize = 16;
+    #[test]
+    fn send_timestamp_is_recorded_when_enabled<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .max_publishers(1)
-            .max_subscribers(1)
-            .enable_safe_overflow(false)
-            .history_size(0)
-            .subscriber_max_buffer_size(buffer_size)
-            .subscriber_max_borrowed_samples(max_borrow)
+            .publish_subscribe::<u64>()
+            .user_header::<u64>()
+            .enable_send_timestamp(true)
             .create()
             .unwrap();
 
-        let sut_publisher = sut
+        let static_config = sut.static_config();
+        assert_that!(static_config.has_send_timestamp(), eq true);
+        assert_that!(static_config.message_type_details().header.size, eq core::mem::size_of::<Header>());
+
+        let publisher = sut
             .publisher_builder()
-            .max_loaned_samples(publisher_borrow_size)
+            .enable_send_timestamp()
             .create()
             .unwrap();
-        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        let mut borrowed_samples = vec![];
-        let mut cached_samples = vec![];
+        let before_send = Time::now_with_clock(ClockType::Monotonic).unwrap();
+        let mut sample = publisher.loan().unwrap();
+        assert_that!(sample.header().send_timestamp(), is_none);
+        *sample.user_header_mut() = 123;
+        *sample.payload_mut() = 456;
+        sample.send().unwrap();
+        let after_send = Time::now_with_clock(ClockType::Monotonic).unwrap();
 
-        let mut send_sample = || {
-            if borrowed_samples.is_empty() {
-                for _ in 0..publisher_borrow_size {
-                    borrowed_samples.push(sut_publisher.loan().unwrap());
-                }
-            }
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.user_header(), eq 123);
+        assert_that!(*sample.payload(), eq 456);
+
+        let send_timestamp = sample.header().send_timestamp().unwrap();
+        assert_that!(send_timestamp.clock_type(), eq ClockType::Monotonic);
+        assert_that!(send_timestamp.as_duration(), ge before_send.as_duration());
+        assert_that!(send_timestamp.as_duration(), le after_send.as_duration());
+        let header = *sample.header();
+        assert_that!(header.send_timestamp(), eq Some(send_timestamp));
+
+        let publisher_without_send_timestamp = sut.publisher_builder().create().unwrap();
+        publisher_without_send_timestamp.send_copy(789).unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.payload(), eq 789);
+        assert_that!(sample.header().send_timestamp(), is_none);
+    }
 
-            let sample = borrowed_samples.pop().unwrap();
-            sample.send().unwrap();
-        };
+    #[test]
+    fn send_timestamp_is_not_recorded_when_disabled<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        for _ in 0..ITERATIONS {
-            for _ in 0..max_borrow {
-                send_sample();
-                cached_samples.push(sut_subscriber.receive().unwrap().unwrap());
-            }
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
 
-            for _ in 0..buffer_size {
-                send_sample();
-            }
+        let static_config = sut.static_config();
+        assert_that!(static_config.has_send_timestamp(), eq false);
+        assert_that!(static_config.message_type_details().header.size, eq core::mem::size_of::<Header>() - core::mem::size_of::<u64>());
 
-            cached_samples.clear();
-            for _ in 0..buffer_size {
-                sut_subscriber.receive().unwrap().unwrap();
-            }
-        }
-    }
+        assert_that!(sut.publisher_builder().enable_send_timestamp().create().err(),
+            eq Some(PublisherCreateError::SendTimestampNotSupported));
 
-    #[test]
-    fn retrieve_channel_capacity_is_never_exceeded_with_large_publisher_borrow_size<
-        Sut: Service,
-    >() {
-        const PUBLISHER_BORROW_SIZE: usize = 10;
-        const BUFFER_SIZE: usize = 1;
-        const MAX_BORROW: usize = 1;
-        retrieve_channel_capacity_is_never_exceeded::<Sut>(
-            PUBLISHER_BORROW_SIZE,
-            BUFFER_SIZE,
-            MAX_BORROW,
-        );
-    }
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        publisher.send_copy(123).unwrap();
 
-    #[test]
-    fn retrieve_channel_capacity_is_never_exceeded_with_large_buffer_size<Sut: Service>() {
-        const PUBLISHER_BORROW_SIZE: usize = 1;
-        const BUFFER_SIZE: usize = 10;
-        const MAX_BORROW: usize = 1;
-        retrieve_channel_capacity_is_never_exceeded::<Sut>(
-            PUBLISHER_BORROW_SIZE,
-            BUFFER_SIZE,
-            MAX_BORROW,
-        );
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.payload(), eq 123);
+        assert_that!(sample.header().send_timestamp(), is_none);
     }
 
     #[test]
-    fn retrieve_channel_capacity_is_never_exceeded_with_large_max_borrow<Sut: Service>() {
-        const PUBLISHER_BORROW_SIZE: usize = 1;
-        const BUFFER_SIZE: usize = 1;
-        const MAX_BORROW: usize = 10;
-
-        retrieve_channel_capacity_is_never_exceeded::<Sut>(
-            PUBLISHER_BORROW_SIZE,
-            BUFFER_SIZE,
-            MAX_BORROW,
-        );
-    }
+    fn send_timestamp_is_recorded_with_coalescing_window<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-    #[test]
-    fn retrieve_channel_capacity_is_never_exceeded_with_large_settings<Sut: Service>() {
-        const PUBLISHER_BORROW_SIZE: usize = 20;
-        const BUFFER_SIZE: usize = 14;
-        const MAX_BORROW: usize = 15;
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .coalescing_window(Duration::from_secs(1))
+            .create()
+            .unwrap();
 
-        retrieve_channel_capacity_is_never_exceeded::<Sut>(
-            PUBLISHER_BORROW_SIZE,
-            BUFFER_SIZE,
-            MAX_BORROW,
-        );
-    }
+        let static_config = sut.static_config();
+        assert_that!(static_config.has_send_timestamp(), eq true);
+        assert_that!(static_config.message_type_details().header.size, eq core::mem::size_of::<Header>());
 
-    #[test]
-    fn retrieve_channel_capacity_is_never_exceeded_with_small_settings<Sut: Service>() {
-        const PUBLISHER_BORROW_SIZE: usize = 1;
-        const BUFFER_SIZE: usize = 1;
-        const MAX_BORROW: usize = 1;
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        publisher.send_copy(123).unwrap();
 
-        retrieve_channel_capacity_is_never_exceeded::<Sut>(
-            PUBLISHER_BORROW_SIZE,
-            BUFFER_SIZE,
-            MAX_BORROW,
-        );
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.payload(), eq 123);
+        assert_that!(sample.header().send_timestamp(), is_some);
     }
 
     #[test]
-    fn creating_max_supported_amount_of_ports_work<Sut: Service>() {
-        const MAX_PUBLISHERS: usize = 4;
-        const MAX_SUBSCRIBERS: usize = 8;
-
+    fn open_adopts_the_send_timestamp_of_the_service_when_not_defined<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_publishers(MAX_PUBLISHERS)
-            .max_subscribers(MAX_SUBSCRIBERS)
+            .enable_send_timestamp(true)
             .create()
             .unwrap();
 
-        let mut publishers = vec![];
-        let mut subscribers = vec![];
-
-        // acquire all possible ports
-        for _ in 0..MAX_PUBLISHERS {
-            let publisher = sut.publisher_builder().create();
-            assert_that!(publisher, is_ok);
-            publishers.push(publisher);
-        }
-
-        for _ in 0..MAX_SUBSCRIBERS {
-            let subscriber = sut.subscriber_builder().create();
-            assert_that!(subscriber, is_ok);
-            subscribers.push(subscriber);
-        }
-
-        // create additional ports and fail
-        let publisher = sut.publisher_builder().create();
-        assert_that!(publisher, is_err);
-        assert_that!(
-            publisher.err().unwrap(), eq
-            PublisherCreateError::ExceedsMaxSupportedPublishers
-        );
-
-        let subscriber = sut.subscriber_builder().create();
-        assert_that!(subscriber, is_err);
-        assert_that!(
-            subscriber.err().unwrap(), eq
-            SubscriberCreateError::ExceedsMaxSupportedSubscribers
-        );
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_send_timestamp(false)
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
 
-        // remove a publisher and subscriber
-        assert_that!(publishers.remove(0), is_ok);
-        assert_that!(subscribers.remove(0), is_ok);
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        assert_that!(sut3.static_config().has_send_timestamp(), eq true);
 
-        // create additional ports shall work again
-        let publisher = sut.publisher_builder().create();
-        assert_that!(publisher, is_ok);
+        let publisher = sut3
+            .publisher_builder()
+            .enable_send_timestamp()
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        publisher.send_copy(123).unwrap();
 
-        let subscriber = sut.subscriber_builder().create();
-        assert_that!(subscriber, is_ok);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.payload(), eq 123);
+        assert_that!(sample.header().send_timestamp(), is_some);
     }
 
     #[test]
-    fn set_max_nodes_to_zero_adjusts_it_to_one<Sut: Service>() {
+    fn older_schema_cannot_create_publisher<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .max_nodes(0)
+            .publish_subscribe::<PoseV2>()
+            .enable_schema_evolution()
             .create()
             .unwrap();
 
-        assert_that!(sut.static_config().max_nodes(), eq 1);
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PoseV1>()
+            .enable_schema_evolution()
+            .open()
+            .unwrap();
+
+        assert_that!(sut2.publisher_builder().create().err(), eq Some(PublisherCreateError::OutdatedPayloadSchema));
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PoseV2>()
+            .enable_schema_evolution()
+            .open()
+            .unwrap();
+
+        assert_that!(sut.publisher_builder().create(), is_ok);
+        assert_that!(sut3.publisher_builder().create(), is_ok);
     }
 
     #[test]
-    fn set_max_publishers_to_zero_adjusts_it_to_one<Sut: Service>() {
+    fn create_with_custom_payload_type_works<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
+
+        let _sut = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&TypeDetail::__internal_new::<u64>(
+                    TypeVariant::FixedSize,
+                ))
+                .create()
+                .unwrap()
+        };
+
+        let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_publishers(0)
-            .create()
-            .unwrap();
+            .open();
+
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u32>()
+            .open();
+
+        assert_that!(sut3, is_err);
+        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    fn frame_type_detail(size: usize, alignment: usize) -> TypeDetail {
+        // the same type compiled with different padding rules
+        let mut type_detail = TypeDetail::__internal_new::<u64>(TypeVariant::FixedSize);
+        type_detail.type_name = "Frame".to_string();
+        type_detail.size = size;
+        type_detail.alignment = alignment;
+        type_detail
+    }
+
+    fn open_frame_service<Sut: Service>(
+        node: &Node<Sut>,
+        service_name: &ServiceName,
+        type_detail: &TypeDetail,
+        tolerate_trailing_padding: bool,
+    ) -> Result<
+        iceoryx2::service::port_factory::publish_subscribe::PortFactory<
+            Sut,
+            [CustomPayloadMarker],
+            (),
+        >,
+        PublishSubscribeOpenError,
+    > {
+        unsafe {
+            node.service_builder(service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(type_detail)
+                .tolerate_trailing_padding(tolerate_trailing_padding)
+                .open()
+        }
+    }
+
+    #[test]
+    fn open_with_trailing_padding_difference_works_only_when_tolerated<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&frame_type_detail(16, 8))
+                .create()
+                .unwrap()
+        };
 
-        assert_that!(sut.static_config().max_publishers(), eq 1);
+        let without_padding = frame_type_detail(12, 4);
+        let sut = open_frame_service(&node, &service_name, &without_padding, false);
+        assert_that!(sut.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut = open_frame_service(&node, &service_name, &without_padding, true);
+        assert_that!(sut, is_ok);
     }
 
     #[test]
-    fn set_max_subscribers_to_zero_adjusts_it_to_one<Sut: Service>() {
+    fn open_with_different_fields_fails_even_when_trailing_padding_is_tolerated<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .max_subscribers(0)
-            .create()
-            .unwrap();
 
-        assert_that!(sut.static_config().max_subscribers(), eq 1);
+        let _sut = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&frame_type_detail(16, 8))
+                .create()
+                .unwrap()
+        };
+
+        for (size, alignment) in [(24, 8), (8, 8), (4, 4), (20, 4)] {
+            let sut = open_frame_service(
+                &node,
+                &service_name,
+                &frame_type_detail(size, alignment),
+                true,
+            );
+            assert_that!(sut.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+        }
     }
 
     #[test]
-    fn set_subscriber_max_borrowed_samples_to_zero_adjusts_it_to_one<Sut: Service>() {
+    fn create_with_custom_user_header_type_works<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .subscriber_max_borrowed_samples(0)
-            .create()
-            .unwrap();
+        const HEADER_SIZE: usize = 1024;
 
-        assert_that!(sut.static_config().subscriber_max_borrowed_samples(), eq 1);
-    }
+        let sut_pub = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[u8]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_user_header_type_details(&TypeDetail::__internal_new::<
+                    [u64; HEADER_SIZE],
+                >(TypeVariant::FixedSize))
+                .create()
+                .unwrap()
+        };
 
-    #[test]
-    fn set_buffer_size_to_zero_adjusts_it_to_one<Sut: Service>() {
-        let service_name = generate_name();
-        let config = generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
+        let sut_sub = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[u8]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_user_header_type_details(&TypeDetail::__internal_new::<
+                    [u64; HEADER_SIZE],
+                >(TypeVariant::FixedSize))
+                .open()
+                .unwrap()
+        };
+
+        let sut3 = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .subscriber_max_buffer_size(0)
-            .create()
-            .unwrap();
+            .publish_subscribe::<[u8]>()
+            .open();
 
-        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 1);
+        assert_that!(sut3, is_err);
+        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let publisher = sut_pub.publisher_builder().create().unwrap();
+        let subscriber = sut_sub.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan_slice(1).unwrap();
+        let header = (sample.user_header_mut() as *mut CustomHeaderMarker) as *mut u64;
+        for i in 0..HEADER_SIZE {
+            unsafe { *header.add(i) = (4 * i + 1) as u64 };
+        }
+        sample.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let header = (sample.user_header() as *const CustomHeaderMarker) as *const u64;
+
+        for i in 0..HEADER_SIZE {
+            assert_that!(unsafe { *header.add(i) }, eq(4 * i + 1) as u64);
+        }
     }
 
     #[test]
-    fn does_exist_works_single<Sut: Service>() {
+    fn open_with_custom_payload_type_works<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
 
         let _sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<u128>()
             .create()
             .unwrap();
 
-        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
-        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
+        let sut2 = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&TypeDetail::__internal_new::<u128>(
+                    TypeVariant::FixedSize,
+                ))
+                .open()
+        };
 
-        drop(_sut);
+        assert_that!(sut2, is_ok);
 
-        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+        let sut3 = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&TypeDetail::__internal_new::<u64>(
+                    TypeVariant::FixedSize,
+                ))
+                .open()
+        };
+
+        assert_that!(sut3, is_err);
+        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
     #[test]
-    fn does_exist_works_many<Sut: Service>() {
-        const NUMBER_OF_SERVICES: usize = 8;
+    fn open_error_display_works<Sut: Service>() {
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotExist), eq
+                                  "PublishSubscribeOpenError::DoesNotExist");
+        assert_that!(format!("{}", PublishSubscribeOpenError::InternalFailure), eq
+                                  "PublishSubscribeOpenError::InternalFailure");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleTypes), eq
+                                  "PublishSubscribeOpenError::IncompatibleTypes");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleMessagingPattern), eq
+                                  "PublishSubscribeOpenError::IncompatibleMessagingPattern");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleAttributes), eq
+                                  "PublishSubscribeOpenError::IncompatibleAttributes");
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize), eq
+                                  "PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize");
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize), eq
+                                  "PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize");
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples), eq
+                                  "PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples");
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers), eq
+                                  "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers");
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers), eq
+                                  "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers");
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes), eq
+                                  "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleOverflowBehavior), eq
+                                  "PublishSubscribeOpenError::IncompatibleOverflowBehavior");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleLastValueMode), eq
+                                  "PublishSubscribeOpenError::IncompatibleLastValueMode");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatiblePoolAllocationStrategy), eq
+                                  "PublishSubscribeOpenError::IncompatiblePoolAllocationStrategy");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleCoalescingWindow), eq
+                                  "PublishSubscribeOpenError::IncompatibleCoalescingWindow");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleSlowSubscriberEviction), eq
+                                  "PublishSubscribeOpenError::IncompatibleSlowSubscriberEviction");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleSampleMemoryPinning), eq
+                                  "PublishSubscribeOpenError::IncompatibleSampleMemoryPinning");
+        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMaxMessageSize), eq
+                                  "PublishSubscribeOpenError::DoesNotSupportRequestedMaxMessageSize");
+        assert_that!(format!("{}", PublishSubscribeOpenError::InsufficientPermissions), eq
+                                  "PublishSubscribeOpenError::InsufficientPermissions");
+        assert_that!(format!("{}", PublishSubscribeOpenError::ServiceInCorruptedState), eq
+                                  "PublishSubscribeOpenError::ServiceInCorruptedState");
+        assert_that!(format!("{}", PublishSubscribeOpenError::HangsInCreation), eq
+                                  "PublishSubscribeOpenError::HangsInCreation");
+        assert_that!(format!("{}", PublishSubscribeOpenError::ExceedsMaxNumberOfNodes), eq
+                                  "PublishSubscribeOpenError::ExceedsMaxNumberOfNodes");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IsMarkedForDestruction), eq
+                                  "PublishSubscribeOpenError::IsMarkedForDestruction");
+        assert_that!(format!("{}", PublishSubscribeOpenError::StaleServiceHandle), eq
+                                  "PublishSubscribeOpenError::StaleServiceHandle");
+    }
+
+    #[test]
+    fn create_error_display_works<Sut: Service>() {
+        assert_that!(format!("{}", PublishSubscribeCreateError::ServiceInCorruptedState), eq
+                                  "PublishSubscribeCreateError::ServiceInCorruptedState");
+        assert_that!(format!("{}", PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize), eq
+                                  "PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize");
+        assert_that!(format!("{}", PublishSubscribeCreateError::PayloadTypeHasZeroSize), eq
+                                  "PublishSubscribeCreateError::PayloadTypeHasZeroSize");
+        assert_that!(format!("{}", PublishSubscribeCreateError::ExceedsMaxSampleSlots), eq
+                                  "PublishSubscribeCreateError::ExceedsMaxSampleSlots");
+        assert_that!(format!("{}", PublishSubscribeCreateError::SubscriberMaxBufferSizeIsZero), eq
+                                  "PublishSubscribeCreateError::SubscriberMaxBufferSizeIsZero");
+        assert_that!(format!("{}", PublishSubscribeCreateError::SubscriberMaxBorrowedSamplesIsZero), eq
+                                  "PublishSubscribeCreateError::SubscriberMaxBorrowedSamplesIsZero");
+        assert_that!(format!("{}", PublishSubscribeCreateError::AlreadyExists), eq
+                                  "PublishSubscribeCreateError::AlreadyExists");
+        assert_that!(format!("{}", PublishSubscribeCreateError::InsufficientPermissions), eq
+                                  "PublishSubscribeCreateError::InsufficientPermissions");
+        assert_that!(format!("{}", PublishSubscribeCreateError::InternalFailure), eq
+                                  "PublishSubscribeCreateError::InternalFailure");
+        assert_that!(format!("{}", PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance), eq
+                                  "PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance");
+    }
 
+    #[test]
+    fn has_samples_tracks_receivable_samples_in_subscriber<Sut: Service>() {
+        let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut services = vec![];
-        let mut service_names = vec![];
-
-        for i in 0..NUMBER_OF_SERVICES {
-            let service_name = generate_name();
-            assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
 
-            services.push(
-                node.service_builder(&service_name)
-                    .publish_subscribe::<u64>()
-                    .create()
-                    .unwrap(),
-            );
-            service_names.push(service_name);
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
 
-            for s in service_names.iter().take(i + 1) {
-                assert_that!(Sut::does_exist(s, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
-            }
-        }
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
 
-        for i in 0..NUMBER_OF_SERVICES {
-            for s in service_names.iter().take(NUMBER_OF_SERVICES - i) {
-                assert_that!(Sut::does_exist(s, &config, MessagingPattern::PublishSubscribe).unwrap(), eq true);
-            }
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
+        assert_that!(publisher.send_copy(1234), is_ok);
+        assert_that!(subscriber.has_samples().unwrap(), eq true);
 
-            for s in service_names
-                .iter()
-                .take(NUMBER_OF_SERVICES)
-                .skip(NUMBER_OF_SERVICES - i)
-            {
-                assert_that!(Sut::does_exist(s, &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
-            }
+        let _ = subscriber.receive().unwrap();
 
-            services.pop();
-        }
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
     }
 
     #[test]
-    fn list_works<Sut: Service>() {
-        const NUMBER_OF_SERVICES: usize = 8;
-
+    fn subscriber_can_still_receive_sample_when_publisher_was_disconnected<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 4;
+        let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut services = vec![];
-        let mut service_names = vec![];
-
-        let contains_service_names = |names, state: Vec<ServiceDetails<Sut>>| {
-            for n in names {
-                let mut name_found = false;
-                for s in &state {
-                    if *s.static_details.name() == n {
-                        name_found = true;
-                        break;
-                    }
-                }
-
-                if !name_found {
-                    return false;
-                }
-            }
-
-            true
-        };
-
-        for i in 0..NUMBER_OF_SERVICES {
-            let service_name = generate_name();
-
-            services.push(
-                node.service_builder(&service_name)
-                    .publish_subscribe::<u64>()
-                    .create()
-                    .unwrap(),
-            );
-            service_names.push(service_name);
 
-            let mut service_list = vec![];
-            Sut::list(&config, |s| {
-                service_list.push(s);
-                CallbackProgression::Continue
-            })
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .max_publishers(1)
+            .create()
             .unwrap();
-            assert_that!(service_list, len i + 1);
 
-            assert_that!(contains_service_names(service_names.clone(), service_list), eq true);
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i), is_ok);
         }
 
-        for i in 0..NUMBER_OF_SERVICES {
-            services.pop();
-            service_names.pop();
+        drop(publisher);
 
-            let mut service_list = vec![];
-            Sut::list(&config, |s| {
-                service_list.push(s);
-                CallbackProgression::Continue
-            })
-            .unwrap();
-            assert_that!(service_list, len NUMBER_OF_SERVICES - i - 1);
-            assert_that!(contains_service_names(service_names.clone(), service_list), eq true);
+        for i in 0..NUMBER_OF_SAMPLES {
+            let result = subscriber.receive().unwrap();
+            assert_that!(result, is_some);
+            let sample = result.unwrap();
+            assert_that!(*sample, eq i);
         }
     }
 
     #[test]
-    fn dropping_service_keeps_established_communication<Sut: Service>() {
+    fn subscriber_disconnected_publisher_does_not_block_new_publishers<Sut: Service>() {
+        set_log_level(LogLevel::Error);
+        const NUMBER_OF_SAMPLES: usize = 4;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .max_publishers(1)
             .create()
             .unwrap();
 
         let publisher = sut.publisher_builder().create().unwrap();
         let subscriber = sut.subscriber_builder().create().unwrap();
 
-        drop(sut);
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
 
-        const PAYLOAD: u64 = 98129312938;
+        drop(publisher);
 
-        assert_that!(publisher.send_copy(PAYLOAD), eq Ok(1));
-        assert_that!(*subscriber.receive().unwrap().unwrap(), eq PAYLOAD);
+        let _publisher = sut.publisher_builder().create().unwrap();
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            let result = subscriber.receive().unwrap();
+            assert_that!(result, is_some);
+            let sample = result.unwrap();
+            assert_that!(*sample, eq i);
+        }
     }
+
     #[test]
-    fn ports_of_dropped_service_block_new_service_creation<Sut: Service>() {
+    fn subscriber_acquires_samples_of_disconnected_publisher_first<Sut: Service>() {
+        set_log_level(LogLevel::Error);
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(2)
+            .max_publishers(1)
             .create()
             .unwrap();
 
-        let subscriber = sut.subscriber_builder().create().unwrap();
         let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        drop(sut);
+        assert_that!(publisher.send_copy(123), is_ok);
 
-        assert_that!(node.service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create().err().unwrap(),
-            eq PublishSubscribeCreateError::AlreadyExists);
+        drop(publisher);
 
-        drop(subscriber);
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(publisher.send_copy(456), is_ok);
 
-        assert_that!(node.service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create().err().unwrap(),
-            eq PublishSubscribeCreateError::AlreadyExists);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 123);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 456);
+    }
 
-        drop(publisher);
+    #[test]
+    fn communication_with_custom_payload_works<Sut: Service>() {
+        set_log_level(LogLevel::Error);
+        const NUMBER_OF_ELEMENTS: usize = 1;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let mut type_details = TypeDetail::__internal_new::<u8>(TypeVariant::FixedSize);
+        type_details.size = 1024;
+        type_details.alignment = 1024;
 
-        assert_that!(
+        let sut = unsafe {
             node.service_builder(&service_name)
-                .publish_subscribe::<u64>()
-                .create(),
-            is_ok
-        );
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&type_details)
+                .create()
+                .unwrap()
+        };
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let sample = unsafe { publisher.loan_custom_payload(NUMBER_OF_ELEMENTS).unwrap() };
+        assert_that!(sample.payload(), len type_details.size);
+        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment), eq 0);
+        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
+
+        unsafe { sample.assume_init().send().unwrap() };
+
+        let sample = unsafe { subscriber.receive_custom_payload().unwrap().unwrap() };
+        assert_that!(sample.payload(), len type_details.size);
+        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment), eq 0);
+        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
     }
 
     #[test]
-    fn service_can_be_opened_when_there_is_a_publisher<Sut: Service>() {
-        let payload = 1809723987;
+    fn communication_with_custom_slice_payload_works<Sut: Service>() {
+        set_log_level(LogLevel::Error);
+        const NUMBER_OF_ELEMENTS: usize = 7;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create()
-            .unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
-        let publisher = sut.publisher_builder().create().unwrap();
+        let mut type_details = TypeDetail::__internal_new::<u8>(TypeVariant::Dynamic);
+        type_details.size = 2048;
+        type_details.alignment = 2048;
 
-        drop(sut);
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open();
-        assert_that!(sut, is_ok);
-        drop(sut);
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create();
-        assert_that!(sut.err().unwrap(), eq PublishSubscribeCreateError::AlreadyExists);
-        drop(subscriber);
+        let sut = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&type_details)
+                .create()
+                .unwrap()
+        };
 
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open()
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .create()
             .unwrap();
         let subscriber = sut.subscriber_builder().create().unwrap();
-        publisher.send_copy(payload).unwrap();
-        let sample = subscriber.receive().unwrap().unwrap();
-        assert_that!(*sample.payload(), eq payload);
 
-        drop(subscriber);
-        drop(sut);
-        drop(publisher);
+        let sample = unsafe { publisher.loan_custom_payload(NUMBER_OF_ELEMENTS).unwrap() };
+        assert_that!(sample.payload(), len type_details.size * NUMBER_OF_ELEMENTS);
+        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment), eq 0);
+        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
 
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open();
-        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create();
-        assert_that!(sut, is_ok);
+        unsafe { sample.assume_init().send().unwrap() };
+
+        let sample = unsafe { subscriber.receive_custom_payload().unwrap().unwrap() };
+        assert_that!(sample.payload(), len type_details.size * NUMBER_OF_ELEMENTS);
+        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment), eq 0);
+        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
     }
 
     #[test]
-    fn service_can_be_opened_when_there_is_a_subscriber<Sut: Service>() {
-        let payload = 59123544;
+    fn send_increasing_samples_with_static_allocation_strategy_fails<Sut: Service>() {
+        const SLICE_SIZE: usize = 1024;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
+
+        let service_pub = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<[u8]>()
             .create()
             .unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
-        let publisher = sut.publisher_builder().create().unwrap();
-
-        drop(sut);
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open();
-        assert_that!(sut, is_ok);
-        drop(sut);
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create();
-        assert_that!(sut.err().unwrap(), eq PublishSubscribeCreateError::AlreadyExists);
-        drop(publisher);
 
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open()
+        let publisher = service_pub
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_SIZE)
+            .allocation_strategy(AllocationStrategy::Static)
+            .create()
             .unwrap();
-        let publisher = sut.publisher_builder().create().unwrap();
-        publisher.send_copy(payload).unwrap();
-        let sample = subscriber.receive().unwrap().unwrap();
-        assert_that!(*sample.payload(), eq payload);
 
-        drop(publisher);
-        drop(sut);
-        drop(subscriber);
+        let sample = publisher.loan_slice(SLICE_SIZE - 1);
+        assert_that!(sample, is_ok);
 
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open();
-        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .create();
-        assert_that!(sut, is_ok);
+        let sample = publisher.loan_slice(SLICE_SIZE);
+        assert_that!(sample, is_ok);
+
+        let sample = publisher.loan_slice(SLICE_SIZE + 1);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err(), eq Some(PublisherLoanError::ExceedsMaxLoanSize));
     }
 
-    #[test]
-    fn subscriber_can_decrease_buffer_size<Sut: Service>() {
-        const BUFFER_SIZE: usize = 16;
+    fn send_and_receives_increasing_samples_works<Sut: Service>(
+        allocation_strategy: AllocationStrategy,
+    ) {
+        const ITERATIONS: usize = 128;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let sut = node
+        let service_pub = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .publish_subscribe::<[u8]>()
             .create()
             .unwrap();
 
-        let sut2 = node
+        let service_sub = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
+            .publish_subscribe::<[u8]>()
             .open()
             .unwrap();
 
-        for i in 1..=BUFFER_SIZE {
-            let publisher_before_sub = sut2.publisher_builder().create().unwrap();
-            let subscriber = sut.subscriber_builder().buffer_size(i).create().unwrap();
-            let publisher_after_sub = sut2.publisher_builder().create().unwrap();
-
-            assert_that!(subscriber.buffer_size(), eq i);
+        let publisher = service_pub
+            .publisher_builder()
+            .initial_max_slice_len(1)
+            .allocation_strategy(allocation_strategy)
+            .create()
+            .unwrap();
 
-            for n in 0..i * 2 {
-                assert_that!(publisher_before_sub.send_copy(n), is_ok);
-            }
+        let subscriber = service_sub.subscriber_builder().create().unwrap();
 
-            for n in 0..i {
-                let sample = subscriber.receive().unwrap().unwrap();
-                assert_that!(*sample, eq i + n);
+        for n in 0..ITERATIONS {
+            let sample_size = (n + 1) * 32;
+            let mut sample = publisher.loan_slice(sample_size).unwrap();
+            for byte in sample.payload_mut() {
+                *byte = n as u8;
             }
 
-            let sample = subscriber.receive().unwrap();
-            assert_that!(sample, is_none);
-
-            for n in 0..i * 2 {
-                assert_that!(publisher_after_sub.send_copy(n as _), is_ok);
-            }
+            sample.send().unwrap();
 
-            for n in 0..i {
-                let sample = subscriber.receive().unwrap().unwrap();
-                assert_that!(*sample, eq i + n);
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len sample_size);
+            for byte in sample.payload() {
+                assert_that!(*byte, eq n as u8);
             }
-
-            let sample = subscriber.receive().unwrap();
-            assert_that!(sample, is_none);
         }
     }
 
     #[test]
-    fn subscriber_creation_fails_when_buffer_size_exceeds_service_max<Sut: Service>() {
-        const BUFFER_SIZE: usize = 16;
+    fn send_and_receives_increasing_samples_works_for_best_fit_allocation_strategy<Sut: Service>() {
+        send_and_receives_increasing_samples_works::<Sut>(AllocationStrategy::BestFit);
+    }
+
+    #[test]
+    fn send_and_receives_increasing_samples_works_for_power_of_two_allocation_strategy<
+        Sut: Service,
+    >() {
+        send_and_receives_increasing_samples_works::<Sut>(AllocationStrategy::PowerOfTwo);
+    }
+
+    fn send_and_receives_increasing_samples_with_overflow_works<Sut: Service>(
+        allocation_strategy: AllocationStrategy,
+    ) {
+        const SUBSCRIBER_MAX_BUFFER_SIZE: usize = 5;
+        const ITERATIONS: usize = 128;
+        const REPETITIONS: usize = 13;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let _sut = node
+        let service_pub = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .publish_subscribe::<[u8]>()
+            .subscriber_max_buffer_size(SUBSCRIBER_MAX_BUFFER_SIZE)
             .create()
             .unwrap();
 
-        let sut2 = node
+        let service_sub = node
             .service_builder(&service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<[u8]>()
             .open()
             .unwrap();
 
-        let subscriber = sut2
-            .subscriber_builder()
-            .buffer_size(BUFFER_SIZE + 1)
-            .create();
-        assert_that!(subscriber, is_err);
-        assert_that!(subscriber.err().unwrap(), eq SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService);
-    }
-
-    #[test]
-    fn subscriber_buffer_size_is_at_least_one<Sut: Service>() {
-        let service_name = generate_name();
-        let config = generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
+        let publisher = service_pub
+            .publisher_builder()
+            .initial_max_slice_len(1)
+            .allocation_strategy(allocation_strategy)
             .create()
             .unwrap();
 
-        let subscriber = sut.subscriber_builder().buffer_size(0).create().unwrap();
-        assert_that!(subscriber.buffer_size(), eq 1);
+        let subscriber = service_sub.subscriber_builder().create().unwrap();
+
+        for n in 0..ITERATIONS {
+            let sample_size = (n + 1) * 32;
+            for _ in 0..REPETITIONS {
+                let mut sample = publisher.loan_slice(sample_size).unwrap();
+                for byte in sample.payload_mut() {
+                    *byte = n as u8;
+                }
+
+                sample.send().unwrap();
+            }
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len sample_size);
+            for byte in sample.payload() {
+                assert_that!(*byte, eq n as u8);
+            }
+        }
     }
 
     #[test]
-    fn sliced_service_works<Sut: Service>() {
-        const MAX_ELEMENTS: usize = 91;
+    fn send_and_receives_increasing_samples_with_overflow_for_best_fit_allocation_strategy<
+        Sut: Service,
+    >() {
+        send_and_receives_increasing_samples_with_overflow_works::<Sut>(
+            AllocationStrategy::BestFit,
+        );
+    }
+
+    #[test]
+    fn send_and_receives_increasing_samples_with_overflow_for_power_of_two_allocation_strategy<
+        Sut: Service,
+    >() {
+        send_and_receives_increasing_samples_with_overflow_works::<Sut>(
+            AllocationStrategy::PowerOfTwo,
+        );
+    }
+
+    fn deliver_history_with_increasing_samples_works<Sut: Service>(
+        allocation_strategy: AllocationStrategy,
+    ) {
+        const SUBSCRIBER_MAX_BUFFER_SIZE: usize = 12;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
+
+        let service_pub = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u64]>()
+            .publish_subscribe::<[u8]>()
+            .subscriber_max_buffer_size(SUBSCRIBER_MAX_BUFFER_SIZE)
+            .history_size(SUBSCRIBER_MAX_BUFFER_SIZE)
             .create()
             .unwrap();
 
-        let publisher = sut
+        let service_sub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .open()
+            .unwrap();
+
+        let publisher = service_pub
             .publisher_builder()
-            .initial_max_slice_len(MAX_ELEMENTS)
+            .initial_max_slice_len(1)
+            .allocation_strategy(allocation_strategy)
             .create()
             .unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
 
-        for n in 0..=MAX_ELEMENTS {
-            let sample = publisher.loan_slice_uninit(n).unwrap();
-            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
+        for n in 0..SUBSCRIBER_MAX_BUFFER_SIZE {
+            let sample_size = (n + 1) * 32;
+            let mut sample = publisher.loan_slice(sample_size).unwrap();
+            for byte in sample.payload_mut() {
+                *byte = n as u8;
+            }
 
-            let recv_sample = subscriber.receive().unwrap().unwrap();
+            sample.send().unwrap();
+        }
 
-            assert_that!(recv_sample.payload(), len n);
-            for (i, element) in recv_sample.payload().iter().enumerate() {
-                assert_that!(*element, eq i as u64 * 25);
+        let subscriber = service_sub.subscriber_builder().create().unwrap();
+        publisher.update_connections().unwrap();
+
+        for n in 0..SUBSCRIBER_MAX_BUFFER_SIZE {
+            let sample_size = (n + 1) * 32;
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len sample_size);
+            for byte in sample.payload() {
+                assert_that!(*byte, eq n as u8);
             }
         }
     }
 
     #[test]
-    fn slice_aligned_service_works<Sut: Service>() {
-        const MAX_ELEMENTS: usize = 91;
-        const ALIGNMENT: usize = 64;
+    fn deliver_history_with_increasing_samples_works_for_best_fit_allocation_strategy<
+        Sut: Service,
+    >() {
+        deliver_history_with_increasing_samples_works::<Sut>(AllocationStrategy::BestFit);
+    }
+
+    #[test]
+    fn deliver_history_with_increasing_samples_works_for_power_of_two_allocation_strategy<
+        Sut: Service,
+    >() {
+        deliver_history_with_increasing_samples_works::<Sut>(AllocationStrategy::PowerOfTwo);
+    }
+
+    #[test]
+    fn does_not_leak_when_subscriber_has_smaller_buffer_size_than_history_size<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        const HISTORY_SIZE: usize = 1000;
+        const REPETITIONS: usize = 10;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let service_pub = node
-            .service_builder(&service_name)
-            .publish_subscribe::<[u64]>()
-            .subscriber_max_buffer_size(MAX_ELEMENTS + 1)
-            .subscriber_max_borrowed_samples(MAX_ELEMENTS + 1)
-            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
-            .create()
-            .unwrap();
+        let finish_setup = Barrier::new(2);
+        let start = Barrier::new(2);
+        let end = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            let update_connection_thread = s.spawn(|| {
+                let service = node
+                    .service_builder(&service_name)
+                    .publish_subscribe::<usize>()
+                    .max_publishers(1)
+                    .max_subscribers(1)
+                    .subscriber_max_borrowed_samples(1)
+                    .history_size(HISTORY_SIZE)
+                    .subscriber_max_buffer_size(HISTORY_SIZE)
+                    .create()
+                    .unwrap();
+
+                let publisher = service
+                    .publisher_builder()
+                    .max_loaned_samples(1)
+                    .create()
+                    .unwrap();
+
+                for n in 0..HISTORY_SIZE {
+                    publisher.send_copy(n).unwrap();
+                }
+
+                finish_setup.wait();
+
+                for _ in 0..REPETITIONS {
+                    start.wait();
+
+                    publisher.update_connections().unwrap();
+
+                    end.wait();
+                }
+            });
+
+            let new_subscriber_thread = s.spawn(|| {
+                finish_setup.wait();
+
+                let service = node
+                    .service_builder(&service_name)
+                    .publish_subscribe::<usize>()
+                    .open()
+                    .unwrap();
 
-        let service_sub = node
-            .service_builder(&service_name)
-            .publish_subscribe::<[u64]>()
-            .open()
-            .unwrap();
+                for _ in 0..REPETITIONS {
+                    let subscriber = service
+                        .subscriber_builder()
+                        .buffer_size(1)
+                        .create()
+                        .unwrap();
+                    start.wait();
 
-        let publisher = service_pub
-            .publisher_builder()
-            .initial_max_slice_len(MAX_ELEMENTS)
-            .create()
-            .unwrap();
-        let subscriber = service_sub.subscriber_builder().create().unwrap();
+                    let mut previous_value = 0;
+                    for _ in 0..HISTORY_SIZE {
+                        let sample = subscriber.receive().unwrap();
+                        if let Some(sample) = sample {
+                            assert_that!(*sample, ge previous_value);
+                            previous_value = *sample;
+                        }
+                    }
 
-        let mut samples = vec![];
-        for n in 0..=MAX_ELEMENTS {
-            let sample = publisher.loan_slice_uninit(n).unwrap();
-            assert_that!((sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
-            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
+                    end.wait();
+                }
+            });
 
-            let recv_sample = subscriber.receive().unwrap().unwrap();
+            update_connection_thread.join().unwrap();
+            new_subscriber_thread.join().unwrap();
+        });
+    }
 
-            assert_that!((recv_sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
-            assert_that!(recv_sample.payload(), len n);
-            for (i, element) in recv_sample.payload().iter().enumerate() {
-                assert_that!(*element, eq i as u64 * 25);
-            }
-            samples.push(recv_sample);
-        }
+    fn generate_directory(config: &Config, entry: &[u8]) -> Path {
+        let mut directory = *config.global.root_path();
+        directory
+            .add_path_entry(&Path::new(entry).unwrap())
+            .unwrap();
+        directory
     }
 
     #[test]
-    fn simple_communication_with_user_header_works<Sut: Service>() {
+    fn services_with_same_name_in_different_directories_are_independent<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let directory_a = generate_directory(&config, b"directory_a");
+        let directory_b = generate_directory(&config, b"directory_b");
 
-        let sut = node
+        let sut_a = node
             .service_builder(&service_name)
+            .in_directory(&directory_a)
             .publish_subscribe::<u64>()
-            .user_header::<SomeUserHeader>()
-            .create()
-            .unwrap();
+            .create();
+        assert_that!(sut_a, is_ok);
+        let sut_a = sut_a.unwrap();
 
-        let sut2 = node
+        let sut_b = node
             .service_builder(&service_name)
+            .in_directory(&directory_b)
             .publish_subscribe::<u64>()
-            .user_header::<SomeUserHeader>()
-            .open()
-            .unwrap();
+            .create();
+        assert_that!(sut_b, is_ok);
+        let sut_b = sut_b.unwrap();
 
-        let subscriber = sut.subscriber_builder().create().unwrap();
-        let publisher = sut2.publisher_builder().create().unwrap();
-        assert_that!(subscriber.update_connections(), is_ok);
-        let mut sample = publisher.loan().unwrap();
+        let sut_default = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut_default, is_ok);
 
-        for i in 0..1024 {
-            sample.user_header_mut().value[i] = i as u64;
-        }
-        *sample.payload_mut() = 1829731;
-        sample.send().unwrap();
+        let publisher_a = sut_a.publisher_builder().create().unwrap();
+        let subscriber_a = sut_a.subscriber_builder().create().unwrap();
+        let subscriber_b = sut_b.subscriber_builder().create().unwrap();
 
-        let result = subscriber.receive().unwrap();
-        assert_that!(result, is_some);
-        let sample = result.unwrap();
-        assert_that!(*sample, eq 1829731);
-        assert_that!(*sample.payload(), eq 1829731);
+        assert_that!(publisher_a.send_copy(1234), eq Ok(1));
 
-        for i in 0..1024 {
-            assert_that!(sample.user_header().value[i], eq i as u64);
-        }
+        let sample = subscriber_a.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 1234);
+        assert_that!(subscriber_b.receive().unwrap(), is_none);
     }
 
     #[test]
-    fn same_payload_type_but_different_user_header_does_not_connect<Sut: Service>() {
+    fn service_in_directory_can_only_be_opened_from_the_same_directory<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let directory = generate_directory(&config, b"some_directory");
 
         let _sut = node
             .service_builder(&service_name)
+            .in_directory(&directory)
             .publish_subscribe::<u64>()
-            .user_header::<SomeUserHeader>()
             .create()
             .unwrap();
 
@@ -2598,693 +5295,775 @@ mod service_publish_subscribe {
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
             .open();
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
 
-        assert_that!(sut2, is_err);
-        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+        let sut3 = node
+            .service_builder(&service_name)
+            .in_directory(&directory)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut3, is_ok);
     }
 
     #[test]
-    fn create_with_custom_payload_type_works<Sut: Service>() {
+    fn ports_of_service_in_directory_are_independent_of_the_nodes_root_path<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let directory = generate_directory(&config, b"shared_directory");
+        let mut other_config = config.clone();
+        other_config
+            .global
+            .set_root_path(&generate_directory(&config, b"other_root"));
 
-        let _sut = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&TypeDetail::__internal_new::<u64>(
-                    TypeVariant::FixedSize,
-                ))
-                .create()
-                .unwrap()
-        };
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let other_node = NodeBuilder::new()
+            .config(&other_config)
+            .create::<Sut>()
+            .unwrap();
 
-        let sut2 = node
+        let sut = node
+            .service_builder(&service_name)
+            .in_directory(&directory)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let sut2 = other_node
             .service_builder(&service_name)
+            .in_directory(&directory)
             .publish_subscribe::<u64>()
             .open();
-
         assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
 
-        let sut3 = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u32>()
-            .open();
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut2.subscriber_builder().create();
+        assert_that!(subscriber, is_ok);
+        let subscriber = subscriber.unwrap();
 
-        assert_that!(sut3, is_err);
-        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+        assert_that!(publisher.send_copy(5678), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 5678);
     }
 
     #[test]
-    fn create_with_custom_user_header_type_works<Sut: Service>() {
-        let service_name = generate_name();
+    fn list_in_directory_lists_only_services_of_that_directory<Sut: Service>() {
+        let service_name_a = generate_name();
+        let service_name_b = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const HEADER_SIZE: usize = 1024;
-
-        let sut_pub = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[u8]>()
-                .user_header::<CustomHeaderMarker>()
-                .__internal_set_user_header_type_details(&TypeDetail::__internal_new::<
-                    [u64; HEADER_SIZE],
-                >(TypeVariant::FixedSize))
-                .create()
-                .unwrap()
-        };
-
-        let sut_sub = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[u8]>()
-                .user_header::<CustomHeaderMarker>()
-                .__internal_set_user_header_type_details(&TypeDetail::__internal_new::<
-                    [u64; HEADER_SIZE],
-                >(TypeVariant::FixedSize))
-                .open()
-                .unwrap()
-        };
-
-        let sut3 = node
-            .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
-            .open();
-
-        assert_that!(sut3, is_err);
-        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
-
-        let publisher = sut_pub.publisher_builder().create().unwrap();
-        let subscriber = sut_sub.subscriber_builder().create().unwrap();
-
-        let mut sample = publisher.loan_slice(1).unwrap();
-        let header = (sample.user_header_mut() as *mut CustomHeaderMarker) as *mut u64;
-        for i in 0..HEADER_SIZE {
-            unsafe { *header.add(i) = (4 * i + 1) as u64 };
-        }
-        sample.send().unwrap();
+        let directory = generate_directory(&config, b"listed_directory");
 
-        let sample = subscriber.receive().unwrap().unwrap();
-        let header = (sample.user_header() as *const CustomHeaderMarker) as *const u64;
+        let _sut_a = node
+            .service_builder(&service_name_a)
+            .in_directory(&directory)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let _sut_b = node
+            .service_builder(&service_name_b)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
 
-        for i in 0..HEADER_SIZE {
-            assert_that!(unsafe { *header.add(i) }, eq(4 * i + 1) as u64);
-        }
+        let mut listed_services = vec![];
+        Sut::list_in_directory(&config, Some(&directory), |service| {
+            listed_services.push(service.static_details.name().clone());
+            assert_that!(service.dynamic_details, is_some);
+            assert_that!(service.dynamic_details.unwrap().nodes, len 1);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(listed_services, len 1);
+        assert_that!(listed_services[0], eq service_name_a);
+
+        let mut listed_services = vec![];
+        Sut::list_in_directory(&config, None, |service| {
+            listed_services.push(service.static_details.name().clone());
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(listed_services, len 1);
+        assert_that!(listed_services[0], eq service_name_b);
     }
 
     #[test]
-    fn open_with_custom_payload_type_works<Sut: Service>() {
+    fn destroy_in_directory_removes_service_of_that_directory<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let directory = generate_directory(&config, b"destroyed_directory");
 
-        let _sut = node
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u128>()
+            .in_directory(&directory)
+            .publish_subscribe::<u64>()
             .create()
             .unwrap();
 
-        let sut2 = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&TypeDetail::__internal_new::<u128>(
-                    TypeVariant::FixedSize,
-                ))
-                .open()
-        };
-
-        assert_that!(sut2, is_ok);
+        let result = Sut::destroy(&service_name, &config, MessagingPattern::PublishSubscribe);
+        assert_that!(result.err(), eq Some(ServiceDestroyError::DoesNotExist));
 
-        let sut3 = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&TypeDetail::__internal_new::<u64>(
-                    TypeVariant::FixedSize,
-                ))
-                .open()
+        let result = Sut::destroy_in_directory(
+            &service_name,
+            &config,
+            Some(&directory),
+            MessagingPattern::PublishSubscribe,
+        );
+        assert_that!(result.err(), eq Some(ServiceDestroyError::ServiceStillOpened));
+
+        let result = unsafe {
+            Sut::destroy_forced_in_directory(
+                &service_name,
+                &config,
+                Some(&directory),
+                MessagingPattern::PublishSubscribe,
+            )
         };
-
-        assert_that!(sut3, is_err);
-        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
-    }
-
-    #[test]
-    fn open_error_display_works<Sut: Service>() {
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotExist), eq
-                                  "PublishSubscribeOpenError::DoesNotExist");
-        assert_that!(format!("{}", PublishSubscribeOpenError::InternalFailure), eq
-                                  "PublishSubscribeOpenError::InternalFailure");
-        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleTypes), eq
-                                  "PublishSubscribeOpenError::IncompatibleTypes");
-        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleMessagingPattern), eq
-                                  "PublishSubscribeOpenError::IncompatibleMessagingPattern");
-        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleAttributes), eq
-                                  "PublishSubscribeOpenError::IncompatibleAttributes");
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize), eq
-                                  "PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize");
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize), eq
-                                  "PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize");
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples), eq
-                                  "PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples");
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers), eq
-                                  "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers");
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers), eq
-                                  "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers");
-        assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes), eq
-                                  "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes");
-        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleOverflowBehavior), eq
-                                  "PublishSubscribeOpenError::IncompatibleOverflowBehavior");
-        assert_that!(format!("{}", PublishSubscribeOpenError::InsufficientPermissions), eq
-                                  "PublishSubscribeOpenError::InsufficientPermissions");
-        assert_that!(format!("{}", PublishSubscribeOpenError::ServiceInCorruptedState), eq
-                                  "PublishSubscribeOpenError::ServiceInCorruptedState");
-        assert_that!(format!("{}", PublishSubscribeOpenError::HangsInCreation), eq
-                                  "PublishSubscribeOpenError::HangsInCreation");
-        assert_that!(format!("{}", PublishSubscribeOpenError::ExceedsMaxNumberOfNodes), eq
-                                  "PublishSubscribeOpenError::ExceedsMaxNumberOfNodes");
-        assert_that!(format!("{}", PublishSubscribeOpenError::IsMarkedForDestruction), eq
-                                  "PublishSubscribeOpenError::IsMarkedForDestruction");
-    }
-
-    #[test]
-    fn create_error_display_works<Sut: Service>() {
-        assert_that!(format!("{}", PublishSubscribeCreateError::ServiceInCorruptedState), eq
-                                  "PublishSubscribeCreateError::ServiceInCorruptedState");
-        assert_that!(format!("{}", PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize), eq
-                                  "PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize");
-        assert_that!(format!("{}", PublishSubscribeCreateError::AlreadyExists), eq
-                                  "PublishSubscribeCreateError::AlreadyExists");
-        assert_that!(format!("{}", PublishSubscribeCreateError::InsufficientPermissions), eq
-                                  "PublishSubscribeCreateError::InsufficientPermissions");
-        assert_that!(format!("{}", PublishSubscribeCreateError::InternalFailure), eq
-                                  "PublishSubscribeCreateError::InternalFailure");
-        assert_that!(format!("{}", PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance), eq
-                                  "PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance");
+        assert_that!(result, is_ok);
+
+        // the resources of the service handle are gone, it must not be dropped
+        core::mem::forget(sut);
+
+        let mut number_of_services = 0;
+        Sut::list_in_directory(&config, Some(&directory), |_| {
+            number_of_services += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_services, eq 0);
+
+        let result = Sut::destroy_in_directory(
+            &service_name,
+            &config,
+            Some(&directory),
+            MessagingPattern::PublishSubscribe,
+        );
+        assert_that!(result.err(), eq Some(ServiceDestroyError::DoesNotExist));
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .in_directory(&directory)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
     }
 
     #[test]
-    fn has_samples_tracks_receivable_samples_in_subscriber<Sut: Service>() {
+    fn statistics_contains_number_of_ports<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
             .create()
             .unwrap();
 
-        let subscriber = sut.subscriber_builder().create().unwrap();
-        let publisher = sut.publisher_builder().create().unwrap();
-
-        assert_that!(subscriber.has_samples().unwrap(), eq false);
-        assert_that!(publisher.send_copy(1234), is_ok);
-        assert_that!(subscriber.has_samples().unwrap(), eq true);
+        let statistics = sut.statistics();
+        assert_that!(statistics.number_of_publishers(), eq 0);
+        assert_that!(statistics.number_of_subscribers(), eq 0);
+        assert_that!(statistics.number_of_failed_loans(), eq 0);
+        assert_that!(statistics.number_of_overflows(), eq 0);
 
-        let _ = subscriber.receive().unwrap();
+        let _publisher = sut.publisher_builder().create().unwrap();
+        let _subscriber_1 = sut.subscriber_builder().create().unwrap();
+        let _subscriber_2 = sut.subscriber_builder().create().unwrap();
 
-        assert_that!(subscriber.has_samples().unwrap(), eq false);
+        let later_statistics = sut.statistics();
+        assert_that!(later_statistics.number_of_publishers(), eq 1);
+        assert_that!(later_statistics.number_of_subscribers(), eq 2);
+        assert_that!(later_statistics.timestamp().as_duration(), ge statistics.timestamp().as_duration());
     }
 
     #[test]
-    fn subscriber_can_still_receive_sample_when_publisher_was_disconnected<Sut: Service>() {
-        const NUMBER_OF_SAMPLES: usize = 4;
+    fn statistics_counts_failed_loans_and_overflows<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
-            .max_publishers(1)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
             .create()
             .unwrap();
 
-        let publisher = sut.publisher_builder().create().unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
-
-        for i in 0..NUMBER_OF_SAMPLES {
-            assert_that!(publisher.send_copy(i), is_ok);
-        }
+        let publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(1)
+            .create()
+            .unwrap();
+        let _subscriber = sut.subscriber_builder().create().unwrap();
 
-        drop(publisher);
+        let sample = publisher.loan().unwrap();
+        assert_that!(publisher.loan().err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+        drop(sample);
+        assert_that!(sut.statistics().number_of_failed_loans(), eq 1);
 
-        for i in 0..NUMBER_OF_SAMPLES {
-            let result = subscriber.receive().unwrap();
-            assert_that!(result, is_some);
-            let sample = result.unwrap();
-            assert_that!(*sample, eq i);
+        for n in 0..BUFFER_SIZE as u64 + 3 {
+            publisher.send_copy(n).unwrap();
         }
+        assert_that!(sut.statistics().number_of_overflows(), eq 3);
+        assert_that!(sut.dynamic_config().number_of_overflows(), eq 3);
     }
 
     #[test]
-    fn subscriber_disconnected_publisher_does_not_block_new_publishers<Sut: Service>() {
-        set_log_level(LogLevel::Error);
-        const NUMBER_OF_SAMPLES: usize = 4;
+    fn peak_number_of_ports_survives_port_churn<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
-            .max_publishers(1)
+            .publish_subscribe::<u64>()
+            .max_publishers(4)
+            .max_subscribers(4)
             .create()
             .unwrap();
 
-        let publisher = sut.publisher_builder().create().unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
-
-        for i in 0..NUMBER_OF_SAMPLES {
-            assert_that!(publisher.send_copy(i), is_ok);
-        }
+        assert_that!(sut.dynamic_config().peak_publishers(), eq 0);
+        assert_that!(sut.dynamic_config().peak_subscribers(), eq 0);
 
-        drop(publisher);
+        let publishers: Vec<_> = (0..2)
+            .map(|_| sut.publisher_builder().create().unwrap())
+            .collect();
+        let subscribers: Vec<_> = (0..3)
+            .map(|_| sut.subscriber_builder().create().unwrap())
+            .collect();
+        drop(publishers);
+        drop(subscribers);
 
         let _publisher = sut.publisher_builder().create().unwrap();
+        let _subscriber = sut.subscriber_builder().create().unwrap();
 
-        for i in 0..NUMBER_OF_SAMPLES {
-            let result = subscriber.receive().unwrap();
-            assert_that!(result, is_some);
-            let sample = result.unwrap();
-            assert_that!(*sample, eq i);
-        }
+        assert_that!(sut.dynamic_config().number_of_publishers(), eq 1);
+        assert_that!(sut.dynamic_config().peak_publishers(), eq 2);
+        assert_that!(sut.dynamic_config().peak_subscribers(), eq 3);
+        assert_that!(sut.statistics().peak_publishers(), eq 2);
+        assert_that!(sut.statistics().peak_subscribers(), eq 3);
     }
 
     #[test]
-    fn subscriber_acquires_samples_of_disconnected_publisher_first<Sut: Service>() {
-        set_log_level(LogLevel::Error);
+    fn peak_active_samples_tracks_samples_in_use<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .subscriber_max_buffer_size(2)
-            .max_publishers(1)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .history_size(0)
             .create()
             .unwrap();
 
         let publisher = sut.publisher_builder().create().unwrap();
         let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut.dynamic_config().peak_active_samples(), eq 0);
 
-        assert_that!(publisher.send_copy(123), is_ok);
+        for n in 0..3 {
+            publisher.send_copy(n).unwrap();
+        }
+        assert_that!(sut.dynamic_config().peak_active_samples(), eq 3);
 
+        while let Some(sample) = subscriber.receive().unwrap() {
+            drop(sample);
+        }
+        publisher.send_copy(3).unwrap();
         drop(publisher);
 
-        let publisher = sut.publisher_builder().create().unwrap();
-        assert_that!(publisher.send_copy(456), is_ok);
-
-        let sample = subscriber.receive().unwrap().unwrap();
-        assert_that!(*sample, eq 123);
-        let sample = subscriber.receive().unwrap().unwrap();
-        assert_that!(*sample, eq 456);
+        assert_that!(sut.dynamic_config().peak_active_samples(), eq 3);
+        assert_that!(sut.statistics().peak_active_samples(), eq 3);
     }
 
     #[test]
-    fn communication_with_custom_payload_works<Sut: Service>() {
-        set_log_level(LogLevel::Error);
-        const NUMBER_OF_ELEMENTS: usize = 1;
+    fn memory_statistics_track_allocated_sample_bytes<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut type_details = TypeDetail::__internal_new::<u8>(TypeVariant::FixedSize);
-        type_details.size = 1024;
-        type_details.alignment = 1024;
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .history_size(0)
+            .create()
+            .unwrap();
 
-        let sut = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&type_details)
-                .create()
-                .unwrap()
-        };
+        assert_that!(sut.memory_statistics().data_segments(), len 0);
+        assert_that!(sut.memory_statistics().reserved_bytes(), eq 0);
 
         let publisher = sut.publisher_builder().create().unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
-
-        let sample = unsafe { publisher.loan_custom_payload(NUMBER_OF_ELEMENTS).unwrap() };
-        assert_that!(sample.payload(), len type_details.size);
-        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment), eq 0);
-        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
-
-        unsafe { sample.assume_init().send().unwrap() };
-
-        let sample = unsafe { subscriber.receive_custom_payload().unwrap().unwrap() };
-        assert_that!(sample.payload(), len type_details.size);
-        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment), eq 0);
-        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
+        let _subscriber = sut.subscriber_builder().create().unwrap();
+        let statistics = sut.memory_statistics();
+        assert_that!(statistics.data_segments(), len 1);
+        assert_that!(statistics.data_segments()[0].publisher_id(), eq publisher.id());
+        assert_that!(statistics.reserved_bytes(), eq statistics.data_segments()[0].size());
+        assert_that!(statistics.reserved_bytes(), gt 0);
+        assert_that!(statistics.allocated_sample_bytes(), eq 0);
+
+        let sample = publisher.loan().unwrap();
+        let sample_bytes = sut.memory_statistics().allocated_sample_bytes();
+        assert_that!(sample_bytes, ge core::mem::size_of::<u64>());
+
+        publisher.send_copy(1).unwrap();
+        assert_that!(sut.memory_statistics().allocated_sample_bytes(), eq 2 * sample_bytes);
+        drop(sample);
+        assert_that!(sut.memory_statistics().allocated_sample_bytes(), eq sample_bytes);
+
+        // the delivered sample is released when the publisher is removed from the service
+        drop(publisher);
+        let statistics = sut.memory_statistics();
+        assert_that!(statistics.data_segments(), len 0);
+        assert_that!(statistics.allocated_sample_bytes(), eq 0);
+        assert_that!(statistics.peak_allocated_sample_bytes(), eq 2 * sample_bytes);
     }
 
     #[test]
-    fn communication_with_custom_slice_payload_works<Sut: Service>() {
-        set_log_level(LogLevel::Error);
-        const NUMBER_OF_ELEMENTS: usize = 7;
+    fn memory_statistics_report_the_mapped_size_of_a_resized_data_segment<Sut: Service>() {
+        const SLICE_SIZE: usize = 8;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut type_details = TypeDetail::__internal_new::<u8>(TypeVariant::Dynamic);
-        type_details.size = 2048;
-        type_details.alignment = 2048;
-
-        let sut = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&type_details)
-                .create()
-                .unwrap()
-        };
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
 
         let publisher = sut
             .publisher_builder()
-            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .initial_max_slice_len(SLICE_SIZE)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
             .create()
             .unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
+        let initial_size = sut.memory_statistics().reserved_bytes();
+        assert_that!(initial_size, gt 0);
 
-        let sample = unsafe { publisher.loan_custom_payload(NUMBER_OF_ELEMENTS).unwrap() };
-        assert_that!(sample.payload(), len type_details.size * NUMBER_OF_ELEMENTS);
-        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment), eq 0);
-        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
+        let small_sample = publisher.loan_slice(SLICE_SIZE).unwrap();
+        assert_that!(sut.memory_statistics().reserved_bytes(), eq initial_size);
 
-        unsafe { sample.assume_init().send().unwrap() };
+        // the in-use sample keeps the initial segment mapped next to the resized one
+        let large_sample = publisher.loan_slice(SLICE_SIZE * 128).unwrap();
+        let resized_size = sut.memory_statistics().reserved_bytes();
+        assert_that!(resized_size, gt initial_size);
+        assert_that!(sut.memory_statistics().data_segments()[0].size(), eq resized_size);
 
-        let sample = unsafe { subscriber.receive_custom_payload().unwrap().unwrap() };
-        assert_that!(sample.payload(), len type_details.size * NUMBER_OF_ELEMENTS);
-        assert_that!((sample.payload().as_ptr() as usize % type_details.alignment), eq 0);
-        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
+        drop(small_sample);
+        let statistics = sut.memory_statistics();
+        assert_that!(statistics.reserved_bytes(), lt resized_size);
+        assert_that!(statistics.reserved_bytes(), gt initial_size);
+
+        drop(large_sample);
+        drop(publisher);
+        assert_that!(sut.memory_statistics().reserved_bytes(), eq 0);
     }
 
     #[test]
-    fn send_increasing_samples_with_static_allocation_strategy_fails<Sut: Service>() {
-        const SLICE_SIZE: usize = 1024;
+    fn peak_allocated_sample_bytes_survives_publisher_reconnect<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
-        let service_pub = node
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
+            .publish_subscribe::<u64>()
             .create()
             .unwrap();
 
-        let publisher = service_pub
+        let publisher = sut
             .publisher_builder()
-            .initial_max_slice_len(SLICE_SIZE)
-            .allocation_strategy(AllocationStrategy::Static)
+            .max_loaned_samples(3)
             .create()
             .unwrap();
+        let samples: Vec<_> = (0..3).map(|_| publisher.loan().unwrap()).collect();
+        let peak = sut.memory_statistics().allocated_sample_bytes();
+        drop(samples);
+        drop(publisher);
 
-        let sample = publisher.loan_slice(SLICE_SIZE - 1);
-        assert_that!(sample, is_ok);
-
-        let sample = publisher.loan_slice(SLICE_SIZE);
-        assert_that!(sample, is_ok);
-
-        let sample = publisher.loan_slice(SLICE_SIZE + 1);
-        assert_that!(sample, is_err);
-        assert_that!(sample.err(), eq Some(PublisherLoanError::ExceedsMaxLoanSize));
+        let publisher = sut.publisher_builder().create().unwrap();
+        let _sample = publisher.loan().unwrap();
+        let statistics = sut.memory_statistics();
+        assert_that!(statistics.allocated_sample_bytes(), eq peak / 3);
+        assert_that!(statistics.peak_allocated_sample_bytes(), eq peak);
+        assert_that!(sut.dynamic_config().peak_allocated_sample_bytes(), eq peak);
     }
 
-    fn send_and_receives_increasing_samples_works<Sut: Service>(
-        allocation_strategy: AllocationStrategy,
-    ) {
-        const ITERATIONS: usize = 128;
+    #[test]
+    fn wait_for_change_without_change_times_out<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(50);
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
-        let service_pub = node
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
+            .publish_subscribe::<u64>()
             .create()
             .unwrap();
 
-        let service_sub = node
-            .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
-            .open()
-            .unwrap();
+        let start = std::time::Instant::now();
+        let result = sut.dynamic_config().wait_for_change(TIMEOUT);
+        assert_that!(result, eq Err(WaitForChangeError::Timeout));
+        assert_that!(start.elapsed(), ge TIMEOUT);
 
-        let publisher = service_pub
-            .publisher_builder()
-            .initial_max_slice_len(1)
-            .allocation_strategy(allocation_strategy)
+        assert_that!(format!("{}", WaitForChangeError::Timeout), eq "WaitForChangeError::Timeout");
+    }
+
+    #[test]
+    fn wait_for_change_wakes_up_when_ports_connect_and_disconnect<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        const TIMEOUT: Duration = Duration::from_secs(10);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
             .create()
             .unwrap();
+        let barrier = Barrier::new(2);
 
-        let subscriber = service_sub.subscriber_builder().create().unwrap();
+        thread::scope(|s| {
+            s.spawn(|| {
+                let dynamic_config = sut.dynamic_config();
+                barrier.wait();
+                assert_that!(dynamic_config.wait_for_change(TIMEOUT), eq Ok(ChangeKind::Subscribers));
+                barrier.wait();
+                assert_that!(dynamic_config.wait_for_change(TIMEOUT), eq Ok(ChangeKind::Publishers));
+                barrier.wait();
+                assert_that!(dynamic_config.wait_for_change(TIMEOUT), eq Ok(ChangeKind::Subscribers));
+                barrier.wait();
+            });
 
-        for n in 0..ITERATIONS {
-            let sample_size = (n + 1) * 32;
-            let mut sample = publisher.loan_slice(sample_size).unwrap();
-            for byte in sample.payload_mut() {
-                *byte = n as u8;
-            }
+            barrier.wait();
+            std::thread::sleep(Duration::from_millis(10));
+            let subscriber = sut.subscriber_builder().create().unwrap();
+            barrier.wait();
+            std::thread::sleep(Duration::from_millis(10));
+            let _publisher = sut.publisher_builder().create().unwrap();
+            barrier.wait();
+            std::thread::sleep(Duration::from_millis(10));
+            drop(subscriber);
+            barrier.wait();
+        });
+    }
 
-            sample.send().unwrap();
+    #[test]
+    fn wait_for_change_wakes_up_all_waiters<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        const NUMBER_OF_WAITERS: usize = 4;
+        const TIMEOUT: Duration = Duration::from_secs(10);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let barrier = Barrier::new(NUMBER_OF_WAITERS + 1);
 
-            let sample = subscriber.receive().unwrap().unwrap();
-            assert_that!(sample.payload(), len sample_size);
-            for byte in sample.payload() {
-                assert_that!(*byte, eq n as u8);
+        thread::scope(|s| {
+            for _ in 0..NUMBER_OF_WAITERS {
+                s.spawn(|| {
+                    let dynamic_config = sut.dynamic_config();
+                    barrier.wait();
+                    assert_that!(dynamic_config.wait_for_change(TIMEOUT), eq Ok(ChangeKind::Publishers));
+                });
             }
-        }
-    }
 
-    #[test]
-    fn send_and_receives_increasing_samples_works_for_best_fit_allocation_strategy<Sut: Service>() {
-        send_and_receives_increasing_samples_works::<Sut>(AllocationStrategy::BestFit);
+            barrier.wait();
+            std::thread::sleep(Duration::from_millis(10));
+            let _publisher = sut.publisher_builder().create().unwrap();
+        });
     }
 
     #[test]
-    fn send_and_receives_increasing_samples_works_for_power_of_two_allocation_strategy<
-        Sut: Service,
-    >() {
-        send_and_receives_increasing_samples_works::<Sut>(AllocationStrategy::PowerOfTwo);
+    fn attach_via_service_handle_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let serialized = toml::to_string(&sut.service_handle()).unwrap();
+        let handle: ServiceHandle = toml::from_str(&serialized).unwrap();
+        assert_that!(handle, eq sut.service_handle());
+        assert_that!(handle.name(), eq & service_name);
+        assert_that!(handle.service_id(), eq sut.service_id());
+        assert_that!(handle.storage_generation(), eq sut.storage_generation());
+
+        let other_node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut2 = other_node
+            .service_builder(handle.name())
+            .publish_subscribe::<u64>()
+            .attach(&handle)
+            .unwrap();
+        assert_that!(sut2.service_id(), eq sut.service_id());
+        assert_that!(sut2.storage_generation(), eq sut.storage_generation());
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut2.subscriber_builder().create().unwrap();
+        assert_that!(publisher.send_copy(8921), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 8921);
+
+        let mut number_of_nodes = 0;
+        sut.nodes(|_| {
+            number_of_nodes += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_nodes, eq 2);
     }
 
-    fn send_and_receives_increasing_samples_with_overflow_works<Sut: Service>(
-        allocation_strategy: AllocationStrategy,
-    ) {
-        const SUBSCRIBER_MAX_BUFFER_SIZE: usize = 5;
-        const ITERATIONS: usize = 128;
-        const REPETITIONS: usize = 13;
+    #[test]
+    fn attach_via_stale_service_handle_fails<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
-        let service_pub = node
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
-            .subscriber_max_buffer_size(SUBSCRIBER_MAX_BUFFER_SIZE)
+            .publish_subscribe::<u64>()
             .create()
             .unwrap();
+        let handle = sut.service_handle();
+        drop(sut);
 
-        let service_sub = node
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
-            .open()
-            .unwrap();
+            .publish_subscribe::<u64>()
+            .attach(&handle);
+        assert_that!(sut.err(), eq Some(PublishSubscribeOpenError::DoesNotExist));
 
-        let publisher = service_pub
-            .publisher_builder()
-            .initial_max_slice_len(1)
-            .allocation_strategy(allocation_strategy)
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
             .create()
             .unwrap();
+        let other_node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = other_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .attach(&handle);
+        assert_that!(sut.err(), eq Some(PublishSubscribeOpenError::StaleServiceHandle));
+    }
 
-        let subscriber = service_sub.subscriber_builder().create().unwrap();
-
-        for n in 0..ITERATIONS {
-            let sample_size = (n + 1) * 32;
-            for _ in 0..REPETITIONS {
-                let mut sample = publisher.loan_slice(sample_size).unwrap();
-                for byte in sample.payload_mut() {
-                    *byte = n as u8;
-                }
+    #[test]
+    fn attach_via_service_handle_with_modified_static_config_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(2)
+            .create()
+            .unwrap();
 
-                sample.send().unwrap();
-            }
+        let serialized = toml::to_string(&sut.service_handle()).unwrap();
+        assert_that!(serialized.contains("max_subscribers = 2"), eq true);
+        let handle: ServiceHandle =
+            toml::from_str(&serialized.replace("max_subscribers = 2", "max_subscribers = 64"))
+                .unwrap();
 
-            let sample = subscriber.receive().unwrap().unwrap();
-            assert_that!(sample.payload(), len sample_size);
-            for byte in sample.payload() {
-                assert_that!(*byte, eq n as u8);
-            }
-        }
+        let other_node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut2 = other_node
+            .service_builder(handle.name())
+            .publish_subscribe::<u64>()
+            .attach(&handle);
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::StaleServiceHandle));
     }
 
     #[test]
-    fn send_and_receives_increasing_samples_with_overflow_for_best_fit_allocation_strategy<
-        Sut: Service,
-    >() {
-        send_and_receives_increasing_samples_with_overflow_works::<Sut>(
-            AllocationStrategy::BestFit,
-        );
-    }
+    fn attach_via_service_handle_of_different_service_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
 
-    #[test]
-    fn send_and_receives_increasing_samples_with_overflow_for_power_of_two_allocation_strategy<
-        Sut: Service,
-    >() {
-        send_and_receives_increasing_samples_with_overflow_works::<Sut>(
-            AllocationStrategy::PowerOfTwo,
-        );
+        let sut2 = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .attach(&sut.service_handle());
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::StaleServiceHandle));
     }
 
-    fn deliver_history_with_increasing_samples_works<Sut: Service>(
-        allocation_strategy: AllocationStrategy,
-    ) {
-        const SUBSCRIBER_MAX_BUFFER_SIZE: usize = 12;
+    #[test]
+    fn attach_via_service_handle_with_incompatible_type_fails<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
-        let service_pub = node
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
-            .subscriber_max_buffer_size(SUBSCRIBER_MAX_BUFFER_SIZE)
-            .history_size(SUBSCRIBER_MAX_BUFFER_SIZE)
+            .publish_subscribe::<u64>()
             .create()
             .unwrap();
 
-        let service_sub = node
+        let sut2 = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u8]>()
-            .open()
-            .unwrap();
+            .publish_subscribe::<u32>()
+            .attach(&sut.service_handle());
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
 
-        let publisher = service_pub
-            .publisher_builder()
-            .initial_max_slice_len(1)
-            .allocation_strategy(allocation_strategy)
-            .create()
-            .unwrap();
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
 
-        for n in 0..SUBSCRIBER_MAX_BUFFER_SIZE {
-            let sample_size = (n + 1) * 32;
-            let mut sample = publisher.loan_slice(sample_size).unwrap();
-            for byte in sample.payload_mut() {
-                *byte = n as u8;
-            }
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
 
-            sample.send().unwrap();
-        }
+mod service_publish_subscribe_dynamic_config_open {
+    use core::time::Duration;
+    use std::thread;
 
-        let subscriber = service_sub.subscriber_builder().create().unwrap();
-        publisher.update_connections().unwrap();
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::service_id::ServiceId;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::named_concept::{NamedConceptConfiguration, NamedConceptMgmt};
 
-        for n in 0..SUBSCRIBER_MAX_BUFFER_SIZE {
-            let sample_size = (n + 1) * 32;
-            let sample = subscriber.receive().unwrap().unwrap();
-            assert_that!(sample.payload(), len sample_size);
-            for byte in sample.payload() {
-                assert_that!(*byte, eq n as u8);
-            }
-        }
-    }
+    // The process local storages of local::Service cannot be removed behind the back of their
+    // owner, therefore the slow creator is only simulated with ipc::Service.
 
-    #[test]
-    fn deliver_history_with_increasing_samples_works_for_best_fit_allocation_strategy<
-        Sut: Service,
-    >() {
-        deliver_history_with_increasing_samples_works::<Sut>(AllocationStrategy::BestFit);
+    fn remove_dynamic_config(config: &Config, service_id: &ServiceId) {
+        let storage_config = <<<ipc::Service as Service>::DynamicStorage as NamedConceptMgmt>::Configuration>::default()
+            .prefix(&config.global.prefix)
+            .suffix(&config.global.service.dynamic_config_storage_suffix)
+            .path_hint(config.global.root_path());
+        let name = FileName::new(service_id.as_str().as_bytes()).unwrap();
+
+        assert_that!(unsafe { <<ipc::Service as Service>::DynamicStorage as NamedConceptMgmt>::remove_cfg(&name, &storage_config) }, eq Ok(true));
     }
 
-    #[test]
-    fn deliver_history_with_increasing_samples_works_for_power_of_two_allocation_strategy<
-        Sut: Service,
-    >() {
-        deliver_history_with_increasing_samples_works::<Sut>(AllocationStrategy::PowerOfTwo);
+    fn remove_static_config(config: &Config, service_id: &ServiceId) {
+        let mut path = *config.global.root_path();
+        path.add_path_entry(&config.global.service.directory)
+            .unwrap();
+        let storage_config = <<<ipc::Service as Service>::StaticStorage as NamedConceptMgmt>::Configuration>::default()
+            .prefix(&config.global.prefix)
+            .suffix(&config.global.service.static_config_storage_suffix)
+            .path_hint(&path);
+        let name = FileName::new(service_id.as_str().as_bytes()).unwrap();
+
+        assert_that!(unsafe { <<ipc::Service as Service>::StaticStorage as NamedConceptMgmt>::remove_cfg(&name, &storage_config) }, eq Ok(true));
     }
 
     #[test]
-    fn does_not_leak_when_subscriber_has_smaller_buffer_size_than_history_size<Sut: Service>() {
+    fn open_retries_to_open_dynamic_config_of_slow_creator() {
         let _watchdog = Watchdog::new();
-        const HISTORY_SIZE: usize = 1000;
-        const REPETITIONS: usize = 10;
-        let service_name = generate_name();
-        let config = generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_service_name();
+        let mut config = generate_isolated_config();
+        config.global.service.dynamic_config_open_retries = 500;
+        config.global.service.dynamic_config_open_retry_delay = Duration::from_millis(1);
+        let creator = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let opener = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
 
-        let finish_setup = Barrier::new(2);
-        let start = Barrier::new(2);
-        let end = Barrier::new(2);
+        let old_service = creator
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let service_id = old_service.service_id().clone();
+        // the static config is present but the dynamic config is not yet available, like
+        // while a creator is still initializing the service
+        remove_dynamic_config(&config, &service_id);
 
-        std::thread::scope(|s| {
-            let update_connection_thread = s.spawn(|| {
-                let service = node
-                    .service_builder(&service_name)
-                    .publish_subscribe::<usize>()
-                    .max_publishers(1)
-                    .max_subscribers(1)
-                    .subscriber_max_borrowed_samples(1)
-                    .history_size(HISTORY_SIZE)
-                    .subscriber_max_buffer_size(HISTORY_SIZE)
-                    .create()
+        thread::scope(|s| {
+            s.spawn(|| {
+                thread::sleep(Duration::from_millis(50));
+                remove_static_config(&config, &service_id);
+                let late_creator = NodeBuilder::new()
+                    .config(&config)
+                    .create::<ipc::Service>()
                     .unwrap();
-
-                let publisher = service
-                    .publisher_builder()
-                    .max_loaned_samples(1)
+                let _late_service = late_creator
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
                     .create()
                     .unwrap();
-
-                for n in 0..HISTORY_SIZE {
-                    publisher.send_copy(n).unwrap();
-                }
-
-                finish_setup.wait();
-
-                for _ in 0..REPETITIONS {
-                    start.wait();
-
-                    publisher.update_connections().unwrap();
-
-                    end.wait();
-                }
+                thread::sleep(Duration::from_millis(500));
             });
 
-            let new_subscriber_thread = s.spawn(|| {
-                finish_setup.wait();
-
-                let service = node
-                    .service_builder(&service_name)
-                    .publish_subscribe::<usize>()
-                    .open()
-                    .unwrap();
-
-                for _ in 0..REPETITIONS {
-                    let subscriber = service
-                        .subscriber_builder()
-                        .buffer_size(1)
-                        .create()
-                        .unwrap();
-                    start.wait();
+            let sut = opener
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .open();
+            assert_that!(sut, is_ok);
+        });
+    }
 
-                    let mut previous_value = 0;
-                    for _ in 0..HISTORY_SIZE {
-                        let sample = subscriber.receive().unwrap();
-                        if let Some(sample) = sample {
-                            assert_that!(*sample, ge previous_value);
-                            previous_value = *sample;
-                        }
-                    }
+    #[test]
+    fn open_fails_when_dynamic_config_is_not_available_after_all_retries() {
+        let service_name = generate_service_name();
+        let mut config = generate_isolated_config();
+        config.global.service.dynamic_config_open_retries = 3;
+        config.global.service.dynamic_config_open_retry_delay = Duration::from_millis(10);
+        let creator = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let opener = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
 
-                    end.wait();
-                }
-            });
+        let service = creator
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        remove_dynamic_config(&config, service.service_id());
 
-            update_connection_thread.join().unwrap();
-            new_subscriber_thread.join().unwrap();
-        });
+        let start = std::time::Instant::now();
+        let sut = opener
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut.err(), eq Some(PublishSubscribeOpenError::ServiceInCorruptedState));
+        assert_that!(start.elapsed(), ge Duration::from_millis(30));
     }
 
-    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
-    mod ipc {}
+    #[test]
+    fn storage_failures_are_exposed_as_error_source() {
+        use iceoryx2::service::builder::publish_subscribe::{
+            PublishSubscribeCreateError, PublishSubscribeOpenOrCreateError,
+        };
+        use iceoryx2_cal::dynamic_storage::{DynamicStorageCreateError, DynamicStorageOpenError};
+        use std::error::Error;
 
-    #[instantiate_tests(<iceoryx2::service::local::Service>)]
-    mod local {}
+        let sut = PublishSubscribeOpenOrCreateError::PublishSubscribeCreateError(
+            PublishSubscribeCreateError::DynamicStorageCreationFailure(
+                DynamicStorageCreateError::InitializationFailed,
+            ),
+        );
+        let cause = sut.source().and_then(|e| e.source()).map(|e| e.to_string());
+        assert_that!(cause, eq Some(DynamicStorageCreateError::InitializationFailed.to_string()));
+
+        let sut = PublishSubscribeOpenError::DynamicStorageOpenFailure(
+            DynamicStorageOpenError::VersionMismatch,
+        );
+        let cause = sut.source().map(|e| e.to_string());
+        assert_that!(cause, eq Some(DynamicStorageOpenError::VersionMismatch.to_string()));
+        assert_that!(PublishSubscribeOpenError::DoesNotExist.source().is_none(), eq true);
+    }
 }
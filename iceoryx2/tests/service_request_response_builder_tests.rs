@@ -17,6 +17,7 @@ mod service_request_response {
     use iceoryx2::service::builder::request_response::{
         RequestResponseCreateError, RequestResponseOpenError,
     };
+    use iceoryx2::service::builder::CreationOutcome;
     use iceoryx2::testing::*;
     use iceoryx2_bb_testing::assert_that;
 
@@ -99,6 +100,30 @@ mod service_request_response {
         assert_that!(sut_open, is_ok);
     }
 
+    #[test]
+    fn open_or_create_with_outcome_reports_whether_the_service_was_created<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let (_sut_create, outcome) = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open_or_create_with_outcome()
+            .unwrap();
+
+        assert_that!(outcome, eq CreationOutcome::Created);
+
+        let (_sut_open, outcome) = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open_or_create_with_outcome()
+            .unwrap();
+
+        assert_that!(outcome, eq CreationOutcome::Opened);
+    }
+
     #[test]
     fn when_created_service_goes_out_of_scope_the_service_is_removed<Sut: Service>() {
         let service_name = generate_service_name();
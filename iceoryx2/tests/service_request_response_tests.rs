@@ -0,0 +1,255 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_request_response {
+    use iceoryx2::port::client::{ClientCreateError, RequestLoanError};
+    use iceoryx2::port::server::{ResponseLoanError, ResponseSendError, ServerCreateError};
+    use iceoryx2::port::update_connections::UpdateConnections;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::request_response::PortFactory;
+    use iceoryx2::service::port_factory::PortFactory as _;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn create_service<Sut: Service>(node: &Node<Sut>) -> PortFactory<Sut, u64, (), u64, ()> {
+        node.service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap()
+    }
+
+    #[test]
+    fn request_is_received_and_answered<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(42).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 1);
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(*active_request, eq 42);
+        assert_that!(active_request.header().client_port_id(), eq client.id());
+        assert_that!(active_request.header().request_id(), eq pending_response.request_id());
+        active_request.send_copy(43).unwrap();
+        active_request.send_copy(44).unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 43);
+        assert_that!(response.header().server_port_id(), eq server.id());
+        assert_that!(response.header().request_id(), eq pending_response.request_id());
+        assert_that!(*pending_response.receive().unwrap().unwrap(), eq 44);
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn responses_are_correlated_with_their_request<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response_1 = client.send_copy(10).unwrap();
+        let pending_response_2 = client.send_copy(20).unwrap();
+
+        while let Some(active_request) = server.receive().unwrap() {
+            active_request.send_copy(*active_request + 1).unwrap();
+        }
+
+        // the response of the first request is buffered while the second one is received
+        assert_that!(*pending_response_2.receive().unwrap().unwrap(), eq 21);
+        assert_that!(pending_response_2.receive().unwrap(), is_none);
+        assert_that!(*pending_response_1.receive().unwrap().unwrap(), eq 11);
+        assert_that!(pending_response_1.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn loaned_request_and_response_can_be_written<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let request = client.loan_uninit().unwrap().write_payload(7);
+        let pending_response = request.send().unwrap();
+
+        let active_request = server.receive().unwrap().unwrap();
+        let mut response = active_request.loan().unwrap();
+        *response = *active_request * 3;
+        response.send().unwrap();
+
+        assert_that!(*pending_response.receive().unwrap().unwrap(), eq 21);
+    }
+
+    #[test]
+    fn number_of_clients_and_servers_is_tracked<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+
+        let server = service.server_builder().create().unwrap();
+        let client_1 = service.client_builder().create().unwrap();
+        let client_2 = service.client_builder().create().unwrap();
+        assert_that!(service.dynamic_config().number_of_servers(), eq 1);
+        assert_that!(service.dynamic_config().number_of_clients(), eq 2);
+
+        drop(client_1);
+        assert_that!(service.dynamic_config().number_of_clients(), eq 1);
+        drop(server);
+        assert_that!(service.dynamic_config().number_of_servers(), eq 0);
+        assert_that!(client_2.number_of_server_connections(), eq 1);
+        client_2.update_connections().unwrap();
+        assert_that!(client_2.number_of_server_connections(), eq 0);
+    }
+
+    #[test]
+    fn creating_more_ports_than_supported_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .max_clients(1)
+            .max_servers(1)
+            .create()
+            .unwrap();
+
+        let _server = service.server_builder().create().unwrap();
+        let _client = service.client_builder().create().unwrap();
+
+        assert_that!(service.server_builder().create().err(), eq Some(ServerCreateError::ExceedsMaxSupportedServers));
+        assert_that!(service.client_builder().create().err(), eq Some(ClientCreateError::ExceedsMaxSupportedClients));
+    }
+
+    #[test]
+    fn client_cannot_exceed_max_active_requests<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .max_active_requests(2)
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(1).unwrap();
+        let request = client.loan().unwrap();
+        assert_that!(client.loan().err(), eq Some(RequestLoanError::ExceedsMaxActiveRequests));
+
+        drop(request);
+        let request = client.loan().unwrap();
+        drop(pending_response);
+        assert_that!(client.loan(), is_ok);
+        drop(request);
+    }
+
+    #[test]
+    fn server_cannot_exceed_max_active_responses<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .max_active_responses(1)
+            .create()
+            .unwrap();
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let _pending_response = client.send_copy(1).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+
+        let response = active_request.loan().unwrap();
+        assert_that!(active_request.loan().err(), eq Some(ResponseLoanError::ExceedsMaxActiveResponses));
+        response.send().unwrap();
+        assert_that!(active_request.loan(), is_ok);
+    }
+
+    #[test]
+    fn responses_of_released_pending_response_are_discarded<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(1).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        drop(pending_response);
+
+        active_request.send_copy(2).unwrap();
+        let pending_response = client.send_copy(3).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        active_request.send_copy(4).unwrap();
+
+        assert_that!(*pending_response.receive().unwrap().unwrap(), eq 4);
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn response_loan_fails_when_client_is_gone<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(5).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        let response = active_request.loan().unwrap();
+        assert_that!(active_request.is_connected(), eq true);
+
+        drop(pending_response);
+        drop(client);
+
+        // the request stays readable while the server holds it
+        assert_that!(*active_request, eq 5);
+        assert_that!(active_request.is_connected(), eq false);
+        assert_that!(active_request.loan().err(), eq Some(ResponseLoanError::ClientDisconnected));
+        assert_that!(response.send().err(), eq Some(ResponseSendError::ClientDisconnected));
+    }
+
+    #[test]
+    fn request_is_delivered_to_all_servers<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let server_1 = service.server_builder().create().unwrap();
+        let server_2 = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(8).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 2);
+
+        server_1.receive().unwrap().unwrap().send_copy(1).unwrap();
+        server_2.receive().unwrap().unwrap().send_copy(2).unwrap();
+
+        let mut responses = vec![];
+        while let Some(response) = pending_response.receive().unwrap() {
+            responses.push(*response);
+        }
+        responses.sort();
+        assert_that!(responses, eq vec![1, 2]);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -31,6 +31,7 @@ mod service_static_config_message_type_details {
             type_name: core::any::type_name::<Tmp>().to_string(),
             size: 0,
             alignment: 1,
+            schema: None,
         };
         assert_that!(sut, eq expected);
 
@@ -40,6 +41,7 @@ mod service_static_config_message_type_details {
             type_name: core::any::type_name::<i64>().to_string(),
             size: 8,
             alignment: ALIGNMENT,
+            schema: None,
         };
 
         assert_that!(sut, eq expected);
@@ -50,6 +52,7 @@ mod service_static_config_message_type_details {
             type_name: core::any::type_name::<TypeDetail>().to_string(),
             size: size_of::<TypeDetail>(),
             alignment: ALIGNMENT,
+            schema: None,
         };
 
         assert_that!(sut, eq expected);
@@ -19,13 +19,17 @@ mod service {
 
     use iceoryx2::node::NodeView;
     use iceoryx2::prelude::*;
-    use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
+    use iceoryx2::service::builder::event::{
+        EventCreateError, EventOpenError, EventOpenOrCreateError,
+    };
     use iceoryx2::service::builder::publish_subscribe::{
-        PublishSubscribeCreateError, PublishSubscribeOpenError,
+        PublishSubscribeCreateError, PublishSubscribeOpenError, PublishSubscribeOpenOrCreateError,
     };
+    use iceoryx2::service::builder::CreationOutcome;
     use iceoryx2::service::messaging_pattern::MessagingPattern;
     use iceoryx2::service::port_factory::{event, publish_subscribe};
-    use iceoryx2::service::{ServiceDetailsError, ServiceListError};
+    use iceoryx2::service::static_config::StaticConfigFormat;
+    use iceoryx2::service::{ServiceDestroyError, ServiceDetailsError, ServiceListError};
     use iceoryx2::testing::*;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
@@ -45,6 +49,7 @@ mod service {
         type Factory: PortFactory;
         type CreateError: core::fmt::Debug;
         type OpenError: core::fmt::Debug;
+        type OpenOrCreateError: core::fmt::Debug;
 
         fn new() -> Self;
         fn create(
@@ -59,6 +64,11 @@ mod service {
             service_name: &ServiceName,
             attributes: &AttributeVerifier,
         ) -> Result<Self::Factory, Self::OpenError>;
+        fn open_or_create_with_outcome(
+            &self,
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+        ) -> Result<(Self::Factory, CreationOutcome), Self::OpenOrCreateError>;
         fn messaging_pattern() -> MessagingPattern;
 
         fn assert_create_error(error: Self::CreateError);
@@ -84,6 +94,7 @@ mod service {
         type Factory = publish_subscribe::PortFactory<Sut, u64, ()>;
         type CreateError = PublishSubscribeCreateError;
         type OpenError = PublishSubscribeOpenError;
+        type OpenOrCreateError = PublishSubscribeOpenOrCreateError;
 
         fn new() -> Self {
             Self { _data: PhantomData }
@@ -113,6 +124,18 @@ mod service {
                 .create_with_attributes(attributes)
         }
 
+        fn open_or_create_with_outcome(
+            &self,
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+        ) -> Result<(Self::Factory, CreationOutcome), Self::OpenOrCreateError> {
+            let number_of_nodes = (SystemInfo::NumberOfCpuCores.value()).clamp(128, 1024);
+            node.service_builder(service_name)
+                .publish_subscribe::<u64>()
+                .max_nodes(number_of_nodes)
+                .open_or_create_with_outcome()
+        }
+
         fn assert_attribute_error(error: Self::OpenError) {
             assert_that!(error, eq PublishSubscribeOpenError::IncompatibleAttributes);
         }
@@ -149,6 +172,7 @@ mod service {
         type Factory = event::PortFactory<Sut>;
         type CreateError = EventCreateError;
         type OpenError = EventOpenError;
+        type OpenOrCreateError = EventOpenOrCreateError;
 
         fn new() -> Self {
             Self { _data: PhantomData }
@@ -178,6 +202,18 @@ mod service {
                 .create_with_attributes(attributes)
         }
 
+        fn open_or_create_with_outcome(
+            &self,
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+        ) -> Result<(Self::Factory, CreationOutcome), Self::OpenOrCreateError> {
+            let number_of_nodes = (SystemInfo::NumberOfCpuCores.value()).clamp(128, 1024);
+            node.service_builder(service_name)
+                .event()
+                .max_nodes(number_of_nodes)
+                .open_or_create_with_outcome()
+        }
+
         fn assert_attribute_error(error: Self::OpenError) {
             assert_that!(error, eq EventOpenError::IncompatibleAttributes);
         }
@@ -247,6 +283,48 @@ mod service {
         assert_that!(received_event, eq Some(EVENT_ID));
     }
 
+    #[test]
+    fn opened_service_has_same_storage_generation_as_created_service<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let test = Factory::new();
+
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+        let sut2 = test
+            .open(&node, &service_name, &AttributeVerifier::new())
+            .unwrap();
+
+        assert_that!(sut.storage_generation(), eq sut2.storage_generation());
+    }
+
+    #[test]
+    fn recreated_service_has_different_storage_generation<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let test = Factory::new();
+
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+        let old_generation = sut.storage_generation();
+        drop(sut);
+
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+        assert_that!(sut.storage_generation(), gt old_generation);
+    }
+
     #[test]
     fn concurrent_creating_services_with_unique_names_is_successful<
         Sut: Service,
@@ -335,6 +413,88 @@ mod service {
         });
     }
 
+    #[test]
+    fn open_or_create_with_outcome_reports_created_and_opened<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let (sut, outcome) = test
+            .open_or_create_with_outcome(&node, &service_name)
+            .unwrap();
+        assert_that!(outcome, eq CreationOutcome::Created);
+
+        let (sut2, outcome) = test
+            .open_or_create_with_outcome(&node, &service_name)
+            .unwrap();
+        assert_that!(outcome, eq CreationOutcome::Opened);
+        assert_that!(sut.service_id(), eq sut2.service_id());
+
+        drop(sut);
+        drop(sut2);
+
+        let (_sut, outcome) = test
+            .open_or_create_with_outcome(&node, &service_name)
+            .unwrap();
+        assert_that!(outcome, eq CreationOutcome::Created);
+    }
+
+    #[test]
+    fn concurrent_open_or_create_with_outcome_reports_exactly_one_creation<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let _watch_dog = Watchdog::new();
+        let number_of_threads = (SystemInfo::NumberOfCpuCores.value()).clamp(2, 4);
+        const NUMBER_OF_ITERATIONS: usize = 25;
+        let test = Factory::new();
+
+        let barrier_enter = Barrier::new(number_of_threads);
+        let barrier_exit = Barrier::new(number_of_threads);
+        let service_name = generate_name();
+
+        let config = generate_isolated_config();
+        std::thread::scope(|s| {
+            let mut threads = vec![];
+            for _ in 0..number_of_threads {
+                threads.push(s.spawn(|| {
+                    let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                    let mut outcomes = vec![];
+                    for _ in 0..NUMBER_OF_ITERATIONS {
+                        barrier_enter.wait();
+
+                        let (sut, outcome) = test
+                            .open_or_create_with_outcome(&node, &service_name)
+                            .unwrap();
+                        outcomes.push(outcome);
+
+                        // keep the service alive until every thread has opened or created it
+                        barrier_exit.wait();
+                        drop(sut);
+                    }
+                    outcomes
+                }));
+            }
+
+            let mut number_of_creations = vec![0; NUMBER_OF_ITERATIONS];
+            for thread in threads {
+                for (i, outcome) in thread.join().unwrap().iter().enumerate() {
+                    if *outcome == CreationOutcome::Created {
+                        number_of_creations[i] += 1;
+                    }
+                }
+            }
+
+            for n in number_of_creations {
+                assert_that!(n, eq 1);
+            }
+        });
+    }
+
     #[test]
     fn concurrent_opening_and_closing_services_with_same_name_is_handled_gracefully<
         Sut: Service,
@@ -422,6 +582,47 @@ mod service {
         assert_that!(sut_open.attributes(), eq defined_attributes.attributes());
     }
 
+    #[test]
+    fn static_config_can_be_stored_in_every_format<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let defined_attributes = AttributeSpecifier::new()
+            .define("1. Hello", "Hypnotoad")
+            .define("2. No more", "Coffee");
+        let mut config = generate_isolated_config();
+        let mut opener_config = config.clone();
+
+        // every service is opened by a node that is configured with the other format
+        for (format, opener_format) in [
+            (StaticConfigFormat::Toml, StaticConfigFormat::Json),
+            (StaticConfigFormat::Json, StaticConfigFormat::Toml),
+        ] {
+            let service_name = generate_name();
+            config.global.service.static_config_format = format;
+            opener_config.global.service.static_config_format = opener_format;
+            let node_1 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+            let node_2 = NodeBuilder::new()
+                .config(&opener_config)
+                .create::<Sut>()
+                .unwrap();
+
+            let sut_create = test
+                .create(&node_1, &service_name, &defined_attributes)
+                .unwrap();
+
+            let sut_open = test
+                .open(&node_2, &service_name, &AttributeVerifier::new())
+                .unwrap();
+            assert_that!(sut_open.service_id(), eq sut_create.service_id());
+            assert_that!(sut_open.attributes(), eq defined_attributes.attributes());
+
+            let details = Sut::details(&service_name, &opener_config, Factory::messaging_pattern())
+                .unwrap()
+                .unwrap();
+            assert_that!(details.static_details.service_id(), eq sut_create.service_id());
+            assert_that!(details.static_details.attributes(), eq defined_attributes.attributes());
+        }
+    }
+
     #[test]
     fn opener_succeeds_when_attributes_do_match<Sut: Service, Factory: SutFactory<Sut>>() {
         let test = Factory::new();
@@ -582,6 +783,63 @@ mod service {
         assert_that!(sut_open, is_ok);
     }
 
+    #[test]
+    fn opener_succeeds_when_one_attribute_alternative_does_match<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node_1 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let node_2 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let defined_attributes = AttributeSpecifier::new()
+            .define("codec", "h265")
+            .define("resolution", "4k");
+        let _sut_create = test
+            .create(&node_1, &service_name, &defined_attributes)
+            .unwrap();
+
+        let sut_open = test.open(
+            &node_2,
+            &service_name,
+            &AttributeVerifier::new()
+                .require_key("resolution")
+                .require_any_of(&[("codec", "h264"), ("codec", "h265")]),
+        );
+
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn opener_fails_when_no_attribute_alternative_does_match<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node_1 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let node_2 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let defined_attributes = AttributeSpecifier::new()
+            .define("codec", "av1")
+            .define("resolution", "4k");
+        let _sut_create = test
+            .create(&node_1, &service_name, &defined_attributes)
+            .unwrap();
+
+        let sut_open = test.open(
+            &node_2,
+            &service_name,
+            &AttributeVerifier::new()
+                .require_key("resolution")
+                .require_any_of(&[("codec", "h264"), ("codec", "h265")]),
+        );
+
+        assert_that!(sut_open, is_err);
+        Factory::assert_attribute_error(sut_open.err().unwrap());
+    }
+
     #[test]
     fn details_error_display_works<Sut: Service, Factory: SutFactory<Sut>>() {
         assert_that!(format!("{}", ServiceDetailsError::FailedToOpenStaticServiceInfo), eq
@@ -615,6 +873,110 @@ mod service {
                                   "ServiceListError::InternalError");
     }
 
+    #[test]
+    fn destroy_error_display_works<Sut: Service, Factory: SutFactory<Sut>>() {
+        assert_that!(format!("{}", ServiceDestroyError::DoesNotExist), eq
+                                  "ServiceDestroyError::DoesNotExist");
+
+        assert_that!(format!("{}", ServiceDestroyError::ServiceInUse(3)), eq
+                                  "ServiceDestroyError::ServiceInUse(3)");
+
+        assert_that!(format!("{}", ServiceDestroyError::ServiceStillOpened), eq
+                                  "ServiceDestroyError::ServiceStillOpened");
+
+        assert_that!(format!("{}", ServiceDestroyError::PortResourcesNotRemoved(2)), eq
+                                  "ServiceDestroyError::PortResourcesNotRemoved(2)");
+
+        assert_that!(format!("{}", ServiceDestroyError::InsufficientPermissions), eq
+                                  "ServiceDestroyError::InsufficientPermissions");
+
+        assert_that!(format!("{}", ServiceDestroyError::VersionMismatch), eq
+                                  "ServiceDestroyError::VersionMismatch");
+
+        assert_that!(format!("{}", ServiceDestroyError::InternalError), eq
+                                  "ServiceDestroyError::InternalError");
+    }
+
+    #[test]
+    fn names_differing_in_case_are_different_services_by_default<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = ServiceName::new(&format!("My/Funk/{}", generate_name())).unwrap();
+        let lowercase_service_name = ServiceName::new(&service_name.to_lowercase()).unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test.create(&node, &service_name, &AttributeSpecifier::new());
+        assert_that!(sut, is_ok);
+
+        let sut_lowercase = test.create(&node, &lowercase_service_name, &AttributeSpecifier::new());
+        assert_that!(sut_lowercase, is_ok);
+    }
+
+    #[test]
+    fn names_differing_in_case_collide_with_case_insensitive_names<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = ServiceName::new(&format!("My/Funk/{}", generate_name())).unwrap();
+        let lowercase_service_name = ServiceName::new(&service_name.to_lowercase()).unwrap();
+        let uppercase_service_name = ServiceName::new(&service_name.to_uppercase()).unwrap();
+        let mut config = generate_isolated_config();
+        config.global.service.case_insensitive_names = true;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+        assert_that!(sut.name(), eq & lowercase_service_name);
+
+        let sut_lowercase = test.create(&node, &lowercase_service_name, &AttributeSpecifier::new());
+        assert_that!(sut_lowercase, is_err);
+        Factory::assert_create_error(sut_lowercase.err().unwrap());
+
+        let sut_uppercase = test.open(&node, &uppercase_service_name, &AttributeVerifier::new());
+        assert_that!(sut_uppercase, is_ok);
+        let sut_uppercase = sut_uppercase.unwrap();
+        assert_that!(sut_uppercase.service_id(), eq sut.service_id());
+
+        assert_that!(Sut::does_exist(&uppercase_service_name, &config, Factory::messaging_pattern()).unwrap(), eq true);
+    }
+
+    #[test]
+    fn destroy_non_existing_service_fails<Sut: Service, Factory: SutFactory<Sut>>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+
+        let result = Sut::destroy(&service_name, &config, Factory::messaging_pattern());
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq ServiceDestroyError::DoesNotExist);
+    }
+
+    #[test]
+    fn destroy_opened_service_fails<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        let result = Sut::destroy(&service_name, &config, Factory::messaging_pattern());
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq ServiceDestroyError::ServiceStillOpened);
+        assert_that!(Sut::does_exist(&service_name, &config, Factory::messaging_pattern()).unwrap(), eq true);
+
+        drop(sut);
+
+        let result = Sut::destroy(&service_name, &config, Factory::messaging_pattern());
+        assert_that!(result.err().unwrap(), eq ServiceDestroyError::DoesNotExist);
+    }
+
     #[test]
     fn list_services_works<Sut: Service, Factory: SutFactory<Sut>>() {
         const NUMBER_OF_SERVICES: usize = 16;
@@ -974,3 +1336,178 @@ mod service {
         mod publish_subscribe {}
     }
 }
+
+#[generic_tests::define]
+mod service_storage_migration {
+    use core::time::Duration;
+
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::PortFactory;
+    use iceoryx2::service::static_config::STORAGE_FORMAT_VERSION;
+    use iceoryx2::service::{
+        ServiceDetailsError, StorageMigrationError, StorageMigrationFailure, UnmigratableService,
+    };
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::named_concept::{
+        NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+    };
+    use iceoryx2_cal::static_storage::{StaticStorage, StaticStorageBuilder};
+
+    fn static_storage_config<Sut: Service>(
+        config: &Config,
+    ) -> <Sut::StaticStorage as NamedConceptMgmt>::Configuration {
+        let mut path = *config.global.root_path();
+        path.add_path_entry(&config.global.service.directory)
+            .unwrap();
+        <<Sut::StaticStorage as NamedConceptMgmt>::Configuration>::default()
+            .prefix(&config.global.prefix)
+            .suffix(&config.global.service.static_config_storage_suffix)
+            .path_hint(&path)
+    }
+
+    /// Creates a publish-subscribe service, removes it again and stores the static config it
+    /// had in the layout before the storage format version and the later settings existed.
+    fn store_legacy_service<Sut: Service>(
+        config: &Config,
+        service_name: &ServiceName,
+        modify: fn(String) -> String,
+    ) -> FileName {
+        let node = NodeBuilder::new().config(config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let uuid = FileName::new(service.service_id().as_str().as_bytes()).unwrap();
+
+        let storage_config = static_storage_config::<Sut>(config);
+        let reader = <<Sut::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+            Sut::StaticStorage,
+        >>::new(&uuid)
+        .config(&storage_config)
+        .has_ownership(false)
+        .open(Duration::ZERO)
+        .unwrap();
+        let mut content = vec![0u8; reader.len() as usize];
+        reader.read(&mut content).unwrap();
+        drop(reader);
+        drop(service);
+        drop(node);
+
+        let legacy_content = String::from_utf8(content)
+            .unwrap()
+            .lines()
+            .filter(|line| {
+                !line.starts_with("storage_format_version")
+                    && !line.starts_with("pool_allocation_strategy")
+                    && !line.starts_with("pin_sample_memory")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let legacy_content = modify(legacy_content);
+
+        let mut storage = <<Sut::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+            Sut::StaticStorage,
+        >>::new(&uuid)
+        .config(&storage_config)
+        .has_ownership(true)
+        .create(legacy_content.as_bytes())
+        .unwrap();
+        storage.release_ownership();
+
+        uuid
+    }
+
+    fn remove_legacy_service<Sut: Service>(config: &Config, uuid: &FileName) {
+        let storage_config = static_storage_config::<Sut>(config);
+        assert_that!(unsafe { <Sut::StaticStorage as NamedConceptMgmt>::remove_cfg(uuid, &storage_config) }, eq Ok(true));
+    }
+
+    #[test]
+    fn migrate_storage_converts_static_config_of_older_storage_format<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let uuid = store_legacy_service::<Sut>(&config, &service_name, |content| content);
+
+        let details = Sut::details(&service_name, &config, MessagingPattern::PublishSubscribe);
+        assert_that!(details.err(), eq Some(ServiceDetailsError::FailedToDeserializeStaticServiceInfo));
+
+        assert_that!(Sut::migrate_storage(&config, 0, STORAGE_FORMAT_VERSION), eq Ok(1));
+
+        let details = Sut::details(&service_name, &config, MessagingPattern::PublishSubscribe)
+            .unwrap()
+            .unwrap();
+        assert_that!(details.static_details.name(), eq & service_name);
+        assert_that!(details.static_details.storage_format_version(), eq STORAGE_FORMAT_VERSION);
+        assert_that!(details.dynamic_details, is_none);
+
+        // all services are up to date, nothing is left to migrate
+        assert_that!(Sut::migrate_storage(&config, 0, STORAGE_FORMAT_VERSION), eq Ok(0));
+
+        remove_legacy_service::<Sut>(&config, &uuid);
+    }
+
+    #[test]
+    fn migrate_storage_reports_services_with_incompatible_header_layout<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let uuid = store_legacy_service::<Sut>(&config, &service_name, |content| {
+            content.replace(
+                "\"iceoryx2::service::header::publish_subscribe::Header\"",
+                "\"legacy::Header\"",
+            )
+        });
+
+        assert_that!(Sut::migrate_storage(&config, 0, STORAGE_FORMAT_VERSION), eq Err(
+            StorageMigrationError::RequiresManualRecreation(vec![UnmigratableService {
+                uuid,
+                service_name: Some(service_name.clone()),
+                reason: StorageMigrationFailure::IncompatibleHeaderLayout,
+            }])
+        ));
+
+        // the service remains untouched
+        let details = Sut::details(&service_name, &config, MessagingPattern::PublishSubscribe);
+        assert_that!(details.err(), eq Some(ServiceDetailsError::FailedToDeserializeStaticServiceInfo));
+
+        remove_legacy_service::<Sut>(&config, &uuid);
+    }
+
+    #[test]
+    fn migrate_storage_does_not_touch_services_of_the_current_storage_format<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(Sut::migrate_storage(&config, 0, STORAGE_FORMAT_VERSION), eq Ok(0));
+
+        let details = Sut::details(&service_name, &config, MessagingPattern::PublishSubscribe)
+            .unwrap()
+            .unwrap();
+        assert_that!(details.static_details.storage_format_version(), eq STORAGE_FORMAT_VERSION);
+        assert_that!(details.dynamic_details, is_some);
+    }
+
+    #[test]
+    fn migrate_storage_to_unknown_version_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+
+        assert_that!(Sut::migrate_storage(&config, 0, STORAGE_FORMAT_VERSION + 1), eq Err(StorageMigrationError::UnsupportedMigration));
+        assert_that!(Sut::migrate_storage(&config, STORAGE_FORMAT_VERSION + 1, STORAGE_FORMAT_VERSION), eq Err(StorageMigrationError::UnsupportedMigration));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -14,17 +14,32 @@
 mod subscriber {
     use iceoryx2::service::builder::publish_subscribe::CustomPayloadMarker;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+    use std::cell::RefCell;
     use std::collections::HashSet;
+    use std::rc::Rc;
 
+    use iceoryx2::port::port_identifiers::UniquePublisherId;
+    use iceoryx2::port::publisher::BatchLoanPolicy;
+    use iceoryx2::port::update_connections::UpdateConnections;
+    use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
+    use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2::service::port_factory::PortFactory as _;
     use iceoryx2::{
-        node::NodeBuilder,
-        port::subscriber::{SubscriberCreateError, SubscriberReceiveError},
+        node::{Node, NodeBuilder},
+        port::subscriber::{
+            DeadLetterReason, SamplesSinceError, Subscriber, SubscriberCreateError,
+            SubscriberReceiveError,
+        },
         service::{service_name::ServiceName, Service},
         testing::*,
     };
+    use iceoryx2_bb_posix::clock::{nanosleep, ClockType, Time};
+    use iceoryx2_bb_posix::memory_lock::MemoryLockCreationError;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
 
+    const TIMING: core::time::Duration = core::time::Duration::from_millis(2);
+
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
             "listener_tests_{}",
@@ -37,6 +52,11 @@ mod subscriber {
     fn receive_error_display_works<S: Service>() {
         assert_that!(
             format!("{}", SubscriberReceiveError::ExceedsMaxBorrowedSamples), eq "SubscriberReceiveError::ExceedsMaxBorrowedSamples");
+        assert_that!(
+            format!("{}", SubscriberReceiveError::Disconnected), eq "SubscriberReceiveError::Disconnected");
+        assert_that!(
+            format!("{}", SubscriberReceiveError::UnableToPinSampleMemory(MemoryLockCreationError::UnableToLock)),
+            eq "SubscriberReceiveError::UnableToPinSampleMemory(UnableToLock)");
     }
 
     #[test]
@@ -45,6 +65,29 @@ mod subscriber {
             format!("{}", SubscriberCreateError::ExceedsMaxSupportedSubscribers), eq "SubscriberCreateError::ExceedsMaxSupportedSubscribers");
         assert_that!(
             format!("{}", SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService), eq "SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService");
+        assert_that!(
+            format!("{}", SubscriberCreateError::BufferTooSmallForHistory { buffer: 1, history: 2 }), eq "SubscriberCreateError::BufferTooSmallForHistory { buffer: 1, history: 2 }");
+        assert_that!(
+            format!("{}", SubscriberCreateError::MaxBorrowedSamplesExceedsMaxSupportedBorrowedSamplesOfService), eq "SubscriberCreateError::MaxBorrowedSamplesExceedsMaxSupportedBorrowedSamplesOfService");
+    }
+
+    fn create_service<Sut: Service>(node: &Node<Sut>) -> PortFactory<Sut, u64, ()> {
+        node.service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(8)
+            .create()
+            .unwrap()
+    }
+
+    fn collect_samples_since<Sut: Service>(
+        subscriber: &Subscriber<Sut, u64, ()>,
+        since: Time,
+    ) -> Vec<u64> {
+        subscriber
+            .samples_since(since)
+            .unwrap()
+            .map(|sample| *sample.unwrap())
+            .collect()
     }
 
     #[test]
@@ -98,6 +141,1276 @@ mod subscriber {
         let _sample = sut.receive();
     }
 
+    fn create_service_with_history<Sut: Service>(
+        node: &Node<Sut>,
+        history_size: usize,
+    ) -> PortFactory<Sut, u64, ()> {
+        node.service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .history_size(history_size)
+            .subscriber_max_buffer_size(8)
+            .create()
+            .unwrap()
+    }
+
+    #[test]
+    fn subscriber_with_buffer_smaller_than_history_can_be_created_by_default<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service_with_history(&node, 4);
+        let publisher = service.publisher_builder().create().unwrap();
+
+        for n in 0..4 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let sut = service
+            .subscriber_builder()
+            .buffer_size(2)
+            .create()
+            .unwrap();
+        publisher.update_connections().unwrap();
+
+        let mut received = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            received.push(*sample);
+        }
+        assert_that!(received, eq vec![2, 3]);
+    }
+
+    #[test]
+    fn subscriber_with_buffer_smaller_than_history_fails_when_required<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service_with_history(&node, 4);
+
+        let sut = service
+            .subscriber_builder()
+            .buffer_size(2)
+            .require_buffer_for_history(true)
+            .create();
+
+        assert_that!(sut.err(), eq Some(SubscriberCreateError::BufferTooSmallForHistory { buffer: 2, history: 4 }));
+    }
+
+    #[test]
+    fn subscriber_with_buffer_of_history_size_can_be_created_when_required<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service_with_history(&node, 4);
+
+        let sut = service
+            .subscriber_builder()
+            .buffer_size(4)
+            .require_buffer_for_history(true)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut = service
+            .subscriber_builder()
+            .require_buffer_for_history(true)
+            .create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn subscriber_node_name_is_stored_in_dynamic_config<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+
+        let subscriber_1 = service
+            .subscriber_builder()
+            .node_name("camera-node")
+            .create()
+            .unwrap();
+        let subscriber_2 = service.subscriber_builder().create().unwrap();
+
+        let subscribers = service.dynamic_config().subscribers();
+
+        assert_that!(subscribers, len 2);
+        for info in subscribers {
+            if info.subscriber_id() == subscriber_1.id() {
+                assert_that!(*info.node_name(), eq b"camera-node");
+            } else {
+                assert_that!(info.subscriber_id(), eq subscriber_2.id());
+                assert_that!(info.node_name().is_empty(), eq true);
+            }
+        }
+    }
+
+    #[test]
+    fn sent_samples_have_increasing_publish_timestamp<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        let before = Time::now_realtime().unwrap();
+        publisher.send_copy(1).unwrap();
+        nanosleep(TIMING).unwrap();
+        publisher.send_copy(2).unwrap();
+
+        let sample_1 = sut.receive().unwrap().unwrap();
+        let sample_2 = sut.receive().unwrap().unwrap();
+        let timestamp_1 = sample_1.header().publish_timestamp().as_duration();
+        let timestamp_2 = sample_2.header().publish_timestamp().as_duration();
+
+        assert_that!(before.as_duration(), le timestamp_1);
+        assert_that!(timestamp_1, lt timestamp_2);
+    }
+
+    #[test]
+    fn samples_since_returns_all_samples_when_oldest_is_newer<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        let since = Time::now_realtime().unwrap();
+        for n in 0..4 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        assert_that!(collect_samples_since(&sut, since), eq vec![0, 1, 2, 3]);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn samples_since_returns_nothing_when_all_samples_are_older<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        for n in 0..4 {
+            publisher.send_copy(n).unwrap();
+        }
+        nanosleep(TIMING).unwrap();
+        let since = Time::now_realtime().unwrap();
+
+        assert_that!(collect_samples_since(&sut, since), len 0);
+        assert_that!(sut.has_samples().unwrap(), eq false);
+    }
+
+    #[test]
+    fn samples_since_skips_older_samples<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(0).unwrap();
+        publisher.send_copy(1).unwrap();
+        nanosleep(TIMING).unwrap();
+        let since = Time::now_realtime().unwrap();
+        nanosleep(TIMING).unwrap();
+        publisher.send_copy(2).unwrap();
+        publisher.send_copy(3).unwrap();
+
+        assert_that!(collect_samples_since(&sut, since), eq vec![2, 3]);
+    }
+
+    #[test]
+    fn samples_since_fails_when_time_is_not_acquired_with_realtime_clock<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(0).unwrap();
+        let since = Time::now_with_clock(ClockType::Monotonic).unwrap();
+
+        assert_that!(sut.samples_since(since).err(), eq Some(SamplesSinceError::IncompatibleClockType));
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 0);
+    }
+
+    #[test]
+    fn sample_metadata_returns_header_of_next_sample<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.sample_metadata().unwrap(), is_none);
+
+        let before_send = Time::now_realtime().unwrap();
+        publisher.send_copy(0).unwrap();
+        publisher.send_copy(1).unwrap();
+
+        let header = sut.sample_metadata().unwrap().unwrap();
+        assert_that!(header.publisher_id(), eq publisher.id());
+        assert_that!(header.sequence_number(), eq 1);
+        assert_that!(header.publish_timestamp().as_duration(), ge before_send.as_duration());
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 0);
+        assert_that!(sut.sample_metadata().unwrap().unwrap().sequence_number(), eq 2);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 1);
+        assert_that!(sut.sample_metadata().unwrap(), is_none);
+    }
+
+    #[test]
+    fn sample_metadata_does_not_consume_the_sample<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        for n in 0..4 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        for _ in 0..3 {
+            let header = sut.sample_metadata().unwrap().unwrap();
+            assert_that!(header.sequence_number(), eq 1);
+        }
+
+        for n in 0..4 {
+            let sample = sut.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+        }
+        assert_that!(sut.sample_metadata().unwrap(), is_none);
+    }
+
+    #[test]
+    fn peek_returns_next_sample_without_consuming_it<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.peek().unwrap(), is_none);
+
+        publisher.send_copy(10).unwrap();
+        publisher.send_copy(11).unwrap();
+
+        assert_that!(*sut.peek().unwrap().unwrap().payload(), eq 10);
+        assert_that!(*sut.peek().unwrap().unwrap().payload(), eq 10);
+        assert_that!(sut.has_samples().unwrap(), eq true);
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 10);
+        assert_that!(*sut.peek().unwrap().unwrap().payload(), eq 11);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 11);
+        assert_that!(sut.peek().unwrap(), is_none);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn peeked_sample_counts_against_max_borrowed_samples<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sut.peek().err(), eq Some(SubscriberReceiveError::ExceedsMaxBorrowedSamples));
+        drop(sample);
+
+        assert_that!(*sut.peek().unwrap().unwrap().payload(), eq 2);
+        // the peeked sample is handed out by receive and still occupies the borrow slot
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 2);
+        publisher.send_copy(3).unwrap();
+        assert_that!(sut.receive().err(), eq Some(SubscriberReceiveError::ExceedsMaxBorrowedSamples));
+    }
+
+    #[test]
+    fn peeked_sample_stays_valid_when_buffer_overflows<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(0).unwrap();
+        assert_that!(*sut.peek().unwrap().unwrap().payload(), eq 0);
+
+        for n in 1..=4 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        assert_that!(*sut.peek().unwrap().unwrap().payload(), eq 0);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 0);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 3);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 4);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn drain_to_vec_returns_all_buffered_samples<Sut: Service>() {
+        const BUFFER_SIZE: usize = 4;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.drain_to_vec().unwrap(), len 0);
+
+        for n in 0..BUFFER_SIZE as u64 {
+            publisher_1.send_copy(n).unwrap();
+            publisher_2.send_copy(n + 10).unwrap();
+        }
+
+        let mut drained = sut.drain_to_vec().unwrap();
+        drained.sort();
+        assert_that!(drained, eq vec![0, 1, 2, 3, 10, 11, 12, 13]);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn receive_latest_returns_newest_sample_and_empties_buffer<Sut: Service>() {
+        const BUFFER_SIZE: usize = 5;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.receive_latest().unwrap(), is_none);
+
+        for n in 0..BUFFER_SIZE as u64 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let sample = sut.receive_latest().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq BUFFER_SIZE as u64 - 1);
+        assert_that!(sut.has_samples().unwrap(), eq false);
+        assert_that!(sut.receive_latest().unwrap(), is_none);
+    }
+
+    #[test]
+    fn receive_latest_returns_newest_sample_of_all_publishers<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(3)
+            .enable_send_timestamp(true)
+            .create()
+            .unwrap();
+        let publisher_1 = service
+            .publisher_builder()
+            .enable_send_timestamp()
+            .create()
+            .unwrap();
+        let publisher_2 = service
+            .publisher_builder()
+            .enable_send_timestamp()
+            .create()
+            .unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher_2.send_copy(20).unwrap();
+        publisher_2.send_copy(21).unwrap();
+        std::thread::sleep(core::time::Duration::from_millis(1));
+        publisher_1.send_copy(10).unwrap();
+
+        let sample = sut.receive_latest().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 10);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn receive_with_gap_info_reports_dropped_samples<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.receive_with_gap_info().unwrap(), is_none);
+
+        publisher.send_copy(0).unwrap();
+        let (sample, gap) = sut.receive_with_gap_info().unwrap().unwrap();
+        assert_that!(*sample, eq 0);
+        assert_that!(gap, eq 0);
+        drop(sample);
+
+        // the buffer holds only the last two samples, the samples 1, 2 and 3 are dropped
+        for n in 1..6 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let (sample, gap) = sut.receive_with_gap_info().unwrap().unwrap();
+        assert_that!(*sample, eq 4);
+        assert_that!(gap, eq 3);
+        drop(sample);
+
+        let (sample, gap) = sut.receive_with_gap_info().unwrap().unwrap();
+        assert_that!(*sample, eq 5);
+        assert_that!(gap, eq 0);
+    }
+
+    #[test]
+    fn receive_with_gap_info_tracks_every_publisher_separately<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher_1.send_copy(0).unwrap();
+        publisher_2.send_copy(10).unwrap();
+        let mut gaps = vec![];
+        while let Some((sample, gap)) = sut.receive_with_gap_info().unwrap() {
+            gaps.push((*sample, gap));
+        }
+        gaps.sort();
+        assert_that!(gaps, eq vec![(0, 0), (10, 0)]);
+
+        publisher_1.send_copy(1).unwrap();
+        publisher_1.send_copy(2).unwrap();
+        publisher_2.send_copy(11).unwrap();
+        publisher_2.send_copy(12).unwrap();
+        publisher_2.send_copy(13).unwrap();
+        let mut gaps = vec![];
+        while let Some((sample, gap)) = sut.receive_with_gap_info().unwrap() {
+            gaps.push((*sample, gap));
+        }
+        gaps.sort();
+        assert_that!(gaps, eq vec![(2, 1), (13, 2)]);
+    }
+
+    #[test]
+    fn subscriber_resuming_from_sequence_skips_older_history<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service_with_history(&node, 3);
+        let publisher = service.publisher_builder().create().unwrap();
+
+        // the samples 0..5 have the sequence numbers 1..6
+        for n in 0..5 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let sut = service
+            .subscriber_builder()
+            .resume_from(publisher.id(), 4)
+            .create()
+            .unwrap();
+        publisher.update_connections().unwrap();
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sample.header().sequence_number(), eq 5);
+        assert_that!(*sample, eq 4);
+        drop(sample);
+        assert_that!(sut.receive().unwrap(), is_none);
+
+        publisher.send_copy(5).unwrap();
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 5);
+    }
+
+    #[test]
+    fn subscriber_with_history_max_age_receives_only_recent_history<Sut: Service>() {
+        const MAX_AGE: core::time::Duration = core::time::Duration::from_millis(100);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service_with_history(&node, 4);
+        let publisher = service.publisher_builder().create().unwrap();
+
+        publisher.send_copy(0).unwrap();
+        publisher.send_copy(1).unwrap();
+        nanosleep(MAX_AGE * 2).unwrap();
+        publisher.send_copy(2).unwrap();
+        publisher.send_copy(3).unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .history_max_age(MAX_AGE)
+            .create()
+            .unwrap();
+        publisher.update_connections().unwrap();
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 2);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 3);
+        assert_that!(sut.receive().unwrap(), is_none);
+
+        publisher.send_copy(4).unwrap();
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 4);
+    }
+
+    #[test]
+    fn history_max_age_is_measured_with_the_monotonic_send_timestamp<Sut: Service>() {
+        const MAX_AGE: core::time::Duration = core::time::Duration::from_millis(100);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .history_size(4)
+            .subscriber_max_buffer_size(8)
+            .enable_send_timestamp(true)
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .enable_send_timestamp()
+            .create()
+            .unwrap();
+        let observer = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(0).unwrap();
+        publisher.send_copy(1).unwrap();
+        nanosleep(MAX_AGE * 2).unwrap();
+        publisher.send_copy(2).unwrap();
+        publisher.send_copy(3).unwrap();
+
+        let mut send_timestamps = vec![];
+        while let Some(sample) = observer.receive().unwrap() {
+            let send_timestamp = sample.header().send_timestamp().unwrap();
+            assert_that!(send_timestamp.clock_type(), eq ClockType::Monotonic);
+            send_timestamps.push((*sample, send_timestamp.as_duration()));
+        }
+
+        let sut = service
+            .subscriber_builder()
+            .history_max_age(MAX_AGE)
+            .create()
+            .unwrap();
+        let connected_at = Time::now_with_clock(ClockType::Monotonic).unwrap();
+        publisher.update_connections().unwrap();
+
+        let mut received = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            received.push(*sample);
+        }
+
+        let expected: Vec<u64> = send_timestamps
+            .iter()
+            .filter(|(_, sent_at)| connected_at.as_duration().saturating_sub(*sent_at) <= MAX_AGE)
+            .map(|(value, _)| *value)
+            .collect();
+        assert_that!(received, eq expected);
+        assert_that!(received, eq vec![2, 3]);
+    }
+
+    #[test]
+    fn subscriber_receives_only_requested_history<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service_with_history(&node, 4);
+        let publisher = service.publisher_builder().create().unwrap();
+
+        for n in 0..4 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let sut = service
+            .subscriber_builder()
+            .request_history(2)
+            .create()
+            .unwrap();
+        assert_that!(sut.granted_history(), eq 2);
+        publisher.update_connections().unwrap();
+
+        for n in 2..4 {
+            let sample = sut.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+            assert_that!(sample.is_historical(), eq true);
+        }
+        assert_that!(sut.receive().unwrap(), is_none);
+
+        publisher.send_copy(4).unwrap();
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 4);
+        assert_that!(sample.is_historical(), eq false);
+    }
+
+    #[test]
+    fn sample_that_delivers_the_history_is_not_historical<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service_with_history(&node, 4);
+        let publisher = service.publisher_builder().create().unwrap();
+
+        for n in 0..2 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let sut = service.subscriber_builder().create().unwrap();
+        // the history is delivered when the publisher detects the subscriber while sending
+        publisher.send_copy(2).unwrap();
+
+        for n in 0..2 {
+            let sample = sut.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+            assert_that!(sample.is_historical(), eq true);
+        }
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 2);
+        assert_that!(sample.is_historical(), eq false);
+    }
+
+    #[test]
+    fn subscriber_requested_history_is_capped_to_history_size<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service_with_history(&node, 3);
+
+        let sut = service.subscriber_builder().create().unwrap();
+        assert_that!(sut.granted_history(), eq 3);
+
+        let sut = service
+            .subscriber_builder()
+            .request_history(5)
+            .create()
+            .unwrap();
+        assert_that!(sut.granted_history(), eq 3);
+
+        let sut = service
+            .subscriber_builder()
+            .request_history(0)
+            .create()
+            .unwrap();
+        assert_that!(sut.granted_history(), eq 0);
+    }
+
+    #[test]
+    fn receive_timeout_returns_available_sample_immediately<Sut: Service>() {
+        const TIMEOUT: core::time::Duration = core::time::Duration::from_secs(10);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service_with_history(&node, 0);
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(7).unwrap();
+        let start = Time::now().unwrap();
+        let sample = sut.receive_timeout(TIMEOUT).unwrap();
+        assert_that!(start.elapsed().unwrap(), lt TIMEOUT);
+        assert_that!(*sample.unwrap(), eq 7);
+    }
+
+    #[test]
+    fn receive_timeout_returns_none_when_timeout_passed<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service_with_history(&node, 0);
+        let _publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        let start = Time::now().unwrap();
+        assert_that!(sut.receive_timeout(TIMING * 10).unwrap(), is_none);
+        assert_that!(start.elapsed().unwrap(), ge TIMING * 10);
+    }
+
+    #[test]
+    fn receive_timeout_wakes_up_when_sample_arrives<Sut: Service>() {
+        const TIMEOUT: core::time::Duration = core::time::Duration::from_secs(10);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+        let is_received = std::sync::Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                let service = node
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open()
+                    .unwrap();
+                let publisher = service.publisher_builder().create().unwrap();
+                nanosleep(TIMING * 10).unwrap();
+                publisher.send_copy(42).unwrap();
+                // the samples of a publisher that is gone are discarded
+                is_received.wait();
+            });
+
+            let start = Time::now().unwrap();
+            let sample = sut.receive_timeout(TIMEOUT);
+            is_received.wait();
+            assert_that!(start.elapsed().unwrap(), lt TIMEOUT);
+            assert_that!(*sample.unwrap().unwrap(), eq 42);
+        });
+    }
+
+    #[test]
+    fn receive_timeout_wakes_up_when_only_a_part_of_a_batch_is_delivered<Sut: Service>() {
+        const TIMEOUT: core::time::Duration = core::time::Duration::from_secs(10);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()
+            .unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+        let is_received = std::sync::Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let publisher = service
+                    .publisher_builder()
+                    .max_loaned_samples(2)
+                    .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+                    .create()
+                    .unwrap();
+                nanosleep(TIMING * 10).unwrap();
+                let samples = publisher
+                    .loan_batch(2, BatchLoanPolicy::WaitForAll)
+                    .unwrap()
+                    .into_iter()
+                    .map(|mut sample| {
+                        *sample.payload_mut() = 42;
+                        sample
+                    })
+                    .collect();
+                // the subscriber receives only the first sample of the batch
+                assert_that!(publisher.send_batch(samples).unwrap(), eq 0);
+                is_received.wait();
+            });
+
+            let start = Time::now().unwrap();
+            let sample = sut.receive_timeout(TIMEOUT);
+            is_received.wait();
+            assert_that!(start.elapsed().unwrap(), lt TIMEOUT);
+            assert_that!(*sample.unwrap().unwrap(), eq 42);
+        });
+    }
+
+    #[test]
+    fn receive_timeout_waits_again_when_a_wakeup_provides_no_sample<Sut: Service>() {
+        let timeout = TIMING * 50;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let (publisher_id_sender, publisher_id_receiver) = std::sync::mpsc::channel();
+        let (subscriber_ready_sender, subscriber_ready_receiver) = std::sync::mpsc::channel();
+        let publisher_config = &config;
+        let publisher_service_name = &service_name;
+
+        std::thread::scope(|s| {
+            s.spawn(move || {
+                let node = NodeBuilder::new()
+                    .config(publisher_config)
+                    .create::<Sut>()
+                    .unwrap();
+                let service = node
+                    .service_builder(publisher_service_name)
+                    .publish_subscribe::<u64>()
+                    .open()
+                    .unwrap();
+                let publisher = service.publisher_builder().create().unwrap();
+                publisher_id_sender.send(publisher.id()).unwrap();
+                subscriber_ready_receiver.recv().unwrap();
+                nanosleep(TIMING * 5).unwrap();
+                publisher.send_copy(1).unwrap();
+            });
+
+            // the sample with the sequence number 1 is delivered but discarded by the subscriber
+            let sut = service
+                .subscriber_builder()
+                .resume_from(publisher_id_receiver.recv().unwrap(), 1)
+                .create()
+                .unwrap();
+            subscriber_ready_sender.send(()).unwrap();
+
+            let start = Time::now().unwrap();
+            assert_that!(sut.receive_timeout(timeout).unwrap(), is_none);
+            assert_that!(start.elapsed().unwrap(), ge timeout);
+        });
+    }
+
+    #[test]
+    fn subscriber_first_sample_does_not_miss_deadline<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let sut_without_deadline = service.subscriber_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .deadline(TIMING)
+            .create()
+            .unwrap();
+        assert_that!(sut_without_deadline.deadline(), is_none);
+        assert_that!(sut.deadline(), eq Some(TIMING));
+
+        nanosleep(TIMING * 2).unwrap();
+        assert_that!(sut.deadline_missed(), eq false);
+
+        publisher.send_copy(1).unwrap();
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 1);
+        assert_that!(sut.deadline_missed(), eq false);
+
+        nanosleep(TIMING * 2).unwrap();
+        publisher.send_copy(2).unwrap();
+        assert_that!(*sut_without_deadline.receive().unwrap().unwrap(), eq 1);
+        assert_that!(*sut_without_deadline.receive().unwrap().unwrap(), eq 2);
+        assert_that!(sut_without_deadline.deadline_missed(), eq false);
+    }
+
+    #[test]
+    fn subscriber_detects_missed_deadline_between_samples<Sut: Service>() {
+        const DEADLINE: core::time::Duration = core::time::Duration::from_millis(50);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .deadline(DEADLINE)
+            .create()
+            .unwrap();
+
+        publisher.send_copy(1).unwrap();
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 1);
+        nanosleep(DEADLINE * 2).unwrap();
+        publisher.send_copy(2).unwrap();
+
+        // the gap between the arrivals of both samples is missing the deadline
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 2);
+        assert_that!(sut.deadline_missed(), eq true);
+        assert_that!(sut.deadline_missed(), eq false);
+
+        publisher.send_copy(3).unwrap();
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 3);
+        assert_that!(sut.deadline_missed(), eq false);
+    }
+
+    #[test]
+    fn subscriber_deadline_ignores_the_publish_timestamps_of_multiple_publishers<Sut: Service>() {
+        const DEADLINE: core::time::Duration = core::time::Duration::from_millis(50);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .deadline(DEADLINE)
+            .create()
+            .unwrap();
+
+        // the publish timestamps of both publishers are skewed by more than the deadline but
+        // the samples arrive without a gap
+        publisher_1.send_copy(1).unwrap();
+        nanosleep(DEADLINE * 2).unwrap();
+        publisher_2.send_copy(2).unwrap();
+        publisher_1.send_copy(3).unwrap();
+
+        let mut received = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            received.push(*sample);
+        }
+        received.sort();
+        assert_that!(received, eq vec![1, 2, 3]);
+        assert_that!(sut.deadline_missed(), eq false);
+
+        nanosleep(DEADLINE * 2).unwrap();
+        publisher_2.send_copy(4).unwrap();
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 4);
+        assert_that!(sut.deadline_missed(), eq true);
+        assert_that!(sut.deadline_missed(), eq false);
+    }
+
+    #[test]
+    fn subscriber_detects_missed_deadline_when_publisher_stops_sending<Sut: Service>() {
+        const DEADLINE: core::time::Duration = core::time::Duration::from_millis(50);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .deadline(DEADLINE)
+            .create()
+            .unwrap();
+
+        publisher.send_copy(1).unwrap();
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 1);
+
+        nanosleep(DEADLINE * 2).unwrap();
+        assert_that!(sut.deadline_missed(), eq true);
+        // the ongoing silence is reported once per deadline period
+        assert_that!(sut.deadline_missed(), eq false);
+        nanosleep(DEADLINE * 2).unwrap();
+        assert_that!(sut.deadline_missed(), eq true);
+    }
+
+    #[test]
+    fn subscriber_resuming_from_sequence_skips_only_samples_of_the_given_publisher<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let other_publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .resume_from(publisher.id(), 1)
+            .resume_from(publisher.id(), 2)
+            .create()
+            .unwrap();
+
+        for n in 0..3 {
+            publisher.send_copy(n).unwrap();
+        }
+        other_publisher.send_copy(10).unwrap();
+
+        let mut received = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            received.push(*sample);
+        }
+        received.sort();
+        assert_that!(received, eq vec![2, 10]);
+    }
+
+    #[test]
+    fn subscriber_resuming_from_sequence_skips_live_samples<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .resume_from(publisher.id(), 3)
+            .create()
+            .unwrap();
+
+        for n in 0..4 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sample.header().sequence_number(), eq 4);
+        assert_that!(*sample, eq 3);
+        drop(sample);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    type DeadLetters = Rc<RefCell<Vec<(u64, DeadLetterReason)>>>;
+
+    fn create_subscriber_with_dead_letters<Sut: Service>(
+        service: &PortFactory<Sut, u64, ()>,
+        resume_from: Option<(UniquePublisherId, u64)>,
+    ) -> (Subscriber<Sut, u64, ()>, DeadLetters) {
+        let dead_letters = DeadLetters::default();
+        let sink = dead_letters.clone();
+        let mut builder = service
+            .subscriber_builder()
+            .dead_letter(move |header, reason| {
+                sink.borrow_mut().push((header.sequence_number(), reason))
+            });
+        if let Some((publisher_id, sequence)) = resume_from {
+            builder = builder.resume_from(publisher_id, sequence);
+        }
+
+        (builder.create().unwrap(), dead_letters)
+    }
+
+    #[test]
+    fn dead_letter_sink_receives_samples_filtered_by_resume_from<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let (sut, dead_letters) =
+            create_subscriber_with_dead_letters(&service, Some((publisher.id(), 2)));
+
+        for n in 0..3 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 2);
+        assert_that!(*dead_letters.borrow(), eq vec![
+            (1, DeadLetterReason::Filtered),
+            (2, DeadLetterReason::Filtered)
+        ]);
+    }
+
+    #[test]
+    fn dead_letter_sink_receives_samples_filtered_by_samples_since<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let (sut, dead_letters) = create_subscriber_with_dead_letters(&service, None);
+
+        publisher.send_copy(0).unwrap();
+        nanosleep(TIMING).unwrap();
+        let since = Time::now_realtime().unwrap();
+        publisher.send_copy(1).unwrap();
+
+        assert_that!(collect_samples_since(&sut, since), eq vec![1]);
+        assert_that!(*dead_letters.borrow(), eq vec![(1, DeadLetterReason::Filtered)]);
+    }
+
+    #[test]
+    fn dead_letter_sink_receives_coalesced_samples<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(8)
+            .subscriber_max_borrowed_samples(2)
+            .coalescing_window(core::time::Duration::from_secs(3600))
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let (sut, dead_letters) = create_subscriber_with_dead_letters(&service, None);
+
+        for n in 0..3 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 2);
+        assert_that!(*dead_letters.borrow(), eq vec![
+            (1, DeadLetterReason::Coalesced),
+            (2, DeadLetterReason::Coalesced)
+        ]);
+    }
+
+    #[test]
+    fn dead_letter_sink_receives_samples_superseded_by_receive_latest<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let (sut, dead_letters) = create_subscriber_with_dead_letters(&service, None);
+
+        for n in 0..3 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        assert_that!(*sut.receive_latest().unwrap().unwrap(), eq 2);
+        assert_that!(*dead_letters.borrow(), eq vec![
+            (1, DeadLetterReason::Superseded),
+            (2, DeadLetterReason::Superseded)
+        ]);
+    }
+
+    #[test]
+    fn dead_letter_sink_receives_samples_exceeding_the_expired_connection_buffer<Sut: Service>() {
+        let mut config = generate_isolated_config();
+        config
+            .defaults
+            .publish_subscribe
+            .subscriber_expired_connection_buffer = 0;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let (sut, dead_letters) = create_subscriber_with_dead_letters(&service, None);
+
+        publisher.send_copy(0).unwrap();
+        publisher.send_copy(1).unwrap();
+        drop(publisher);
+
+        assert_that!(sut.receive().unwrap(), is_none);
+        assert_that!(*dead_letters.borrow(), eq vec![
+            (1, DeadLetterReason::Overflow),
+            (2, DeadLetterReason::Overflow)
+        ]);
+    }
+
+    #[test]
+    fn on_sample_callback_is_called_for_every_sample_in_wait<Sut: Service + 'static>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        let received = Rc::new(RefCell::new(vec![]));
+        let received_in_callback = received.clone();
+        let _guard = sut.on_sample(move |sample| received_in_callback.borrow_mut().push(**sample));
+
+        // every sample is released after the callback, otherwise the single borrow slot
+        // would block the delivery of the following samples
+        for n in 0..3 {
+            publisher.send_copy(n).unwrap();
+        }
+        assert_that!(*received.borrow(), len 0);
+
+        node.wait(TIMING).unwrap();
+        assert_that!(*received.borrow(), eq vec![0, 1, 2]);
+
+        node.wait(TIMING).unwrap();
+        assert_that!(*received.borrow(), len 3);
+
+        publisher.send_copy(3).unwrap();
+        node.wait(TIMING).unwrap();
+        assert_that!(*received.borrow(), eq vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn on_sample_callback_is_removed_when_guard_is_dropped<Sut: Service + 'static>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        let counter = Rc::new(RefCell::new(0));
+        let counter_in_callback = counter.clone();
+        let guard = sut.on_sample(move |_| *counter_in_callback.borrow_mut() += 1);
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 1);
+
+        drop(guard);
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 0);
+
+        publisher.send_copy(1).unwrap();
+        node.wait(TIMING).unwrap();
+        assert_that!(*counter.borrow(), eq 0);
+    }
+
+    #[test]
+    fn on_sample_callback_can_remove_itself<Sut: Service + 'static>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        let counter = Rc::new(RefCell::new(0));
+        let guard = Rc::new(RefCell::new(None));
+        let counter_in_callback = counter.clone();
+        let guard_in_callback = guard.clone();
+        *guard.borrow_mut() = Some(sut.on_sample(move |_| {
+            *counter_in_callback.borrow_mut() += 1;
+            drop(guard_in_callback.borrow_mut().take());
+        }));
+
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+        node.wait(TIMING).unwrap();
+        // the subscriber is removed after the dispatch, the remaining sample is still handled
+        assert_that!(*counter.borrow(), eq 2);
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 0);
+
+        publisher.send_copy(3).unwrap();
+        node.wait(TIMING).unwrap();
+        assert_that!(*counter.borrow(), eq 2);
+    }
+
+    #[test]
+    fn on_sample_callback_is_only_called_on_the_registering_thread<Sut: Service + 'static>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        let counter = Rc::new(RefCell::new(0));
+        let counter_in_callback = counter.clone();
+        let _guard = sut.on_sample(move |_| *counter_in_callback.borrow_mut() += 1);
+
+        publisher.send_copy(1).unwrap();
+        let node = std::thread::spawn(move || {
+            node.wait(TIMING).unwrap();
+            node
+        })
+        .join()
+        .unwrap();
+        assert_that!(*counter.borrow(), eq 0);
+
+        node.wait(TIMING).unwrap();
+        assert_that!(*counter.borrow(), eq 1);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -0,0 +1,202 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod wildcard_subscriber {
+    use core::time::Duration;
+
+    use iceoryx2::port::wildcard_subscriber::WildcardSubscriber;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const LONG_SCAN_INTERVAL: Duration = Duration::from_secs(3600);
+
+    fn generate_prefix() -> String {
+        format!(
+            "wildcard_subscriber_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        )
+    }
+
+    fn create_service<Sut: Service>(node: &Node<Sut>, name: &str) -> PortFactory<Sut, u64, ()> {
+        node.service_builder(&ServiceName::new(name).unwrap())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap()
+    }
+
+    fn receive_all<Sut: Service>(sut: &mut WildcardSubscriber<Sut, u64, ()>) -> Vec<(String, u64)> {
+        let mut received = vec![];
+        while let Some((name, sample)) = sut.receive().unwrap() {
+            received.push((name.as_str().to_string(), *sample));
+        }
+        received.sort();
+        received
+    }
+
+    #[test]
+    fn wildcard_subscriber_receives_from_all_matching_services<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let prefix = generate_prefix();
+        let camera_0 = create_service(&node, &format!("{prefix}/camera/0"));
+        let camera_1 = create_service(&node, &format!("{prefix}/camera/1"));
+        let lidar = create_service(&node, &format!("{prefix}/lidar"));
+        let publisher_0 = camera_0.publisher_builder().create().unwrap();
+        let publisher_1 = camera_1.publisher_builder().create().unwrap();
+        let publisher_2 = lidar.publisher_builder().create().unwrap();
+
+        let mut sut = WildcardSubscriber::<Sut, u64, ()>::new(
+            &node,
+            &format!("{prefix}/camera/*"),
+            LONG_SCAN_INTERVAL,
+        );
+        sut.update_services().unwrap();
+        assert_that!(sut.connected_services(), len 2);
+
+        publisher_0.send_copy(10).unwrap();
+        publisher_1.send_copy(11).unwrap();
+        publisher_2.send_copy(12).unwrap();
+
+        assert_that!(receive_all(&mut sut), eq vec![
+            (format!("{prefix}/camera/0"), 10),
+            (format!("{prefix}/camera/1"), 11)
+        ]);
+    }
+
+    #[test]
+    fn wildcard_subscriber_ignores_services_with_other_types<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let prefix = generate_prefix();
+        let service = node
+            .service_builder(&ServiceName::new(&format!("{prefix}/x")).unwrap())
+            .publish_subscribe::<u8>()
+            .create()
+            .unwrap();
+        let _publisher = service.publisher_builder().create().unwrap();
+
+        let mut sut = WildcardSubscriber::<Sut, u64, ()>::new(
+            &node,
+            &format!("{prefix}*"),
+            LONG_SCAN_INTERVAL,
+        );
+        sut.update_services().unwrap();
+
+        assert_that!(sut.connected_services(), len 0);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn wildcard_subscriber_connects_to_new_services_on_scan<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let prefix = generate_prefix();
+        let mut sut =
+            WildcardSubscriber::<Sut, u64, ()>::new(&node, &format!("{prefix}/*"), Duration::ZERO);
+        assert_that!(sut.receive().unwrap(), is_none);
+
+        let service = create_service(&node, &format!("{prefix}/late"));
+        let publisher = service.publisher_builder().create().unwrap();
+        // the subscriber is created on the next scan and receives only the samples sent afterwards
+        assert_that!(sut.receive().unwrap(), is_none);
+        assert_that!(sut.connected_services(), len 1);
+
+        publisher.send_copy(42).unwrap();
+        assert_that!(receive_all(&mut sut), eq vec![(format!("{prefix}/late"), 42)]);
+    }
+
+    #[test]
+    fn wildcard_subscriber_does_not_connect_to_services_without_publishers<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let prefix = generate_prefix();
+        let service = create_service(&node, &format!("{prefix}/idle"));
+
+        let mut sut = WildcardSubscriber::<Sut, u64, ()>::new(
+            &node,
+            &format!("{prefix}/*"),
+            LONG_SCAN_INTERVAL,
+        );
+        sut.update_services().unwrap();
+        assert_that!(sut.connected_services(), len 0);
+
+        let _publisher = service.publisher_builder().create().unwrap();
+        sut.update_services().unwrap();
+        assert_that!(sut.connected_services(), len 1);
+    }
+
+    #[test]
+    fn wildcard_subscriber_closes_subscriber_when_service_disappears<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let prefix = generate_prefix();
+        let service = create_service(&node, &format!("{prefix}/gone"));
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let mut sut = WildcardSubscriber::<Sut, u64, ()>::new(
+            &node,
+            &format!("{prefix}/*"),
+            LONG_SCAN_INTERVAL,
+        );
+        sut.update_services().unwrap();
+        assert_that!(sut.connected_services(), len 1);
+
+        publisher.send_copy(7).unwrap();
+        assert_that!(receive_all(&mut sut), eq vec![(format!("{prefix}/gone"), 7)]);
+
+        drop(publisher);
+        drop(service);
+        sut.update_services().unwrap();
+        assert_that!(sut.connected_services(), len 0);
+
+        // the service was only kept alive by the closed subscriber
+        assert_that!(Sut::does_exist(&ServiceName::new(&format!("{prefix}/gone")).unwrap(), &config, MessagingPattern::PublishSubscribe).unwrap(), eq false);
+    }
+
+    #[test]
+    fn wildcard_subscriber_keeps_subscriber_until_samples_of_gone_service_are_received<
+        Sut: Service,
+    >() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let prefix = generate_prefix();
+        let service = create_service(&node, &format!("{prefix}/gone"));
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let mut sut = WildcardSubscriber::<Sut, u64, ()>::new(
+            &node,
+            &format!("{prefix}/*"),
+            LONG_SCAN_INTERVAL,
+        );
+        sut.update_services().unwrap();
+
+        publisher.send_copy(8).unwrap();
+        drop(publisher);
+        drop(service);
+        sut.update_services().unwrap();
+        assert_that!(sut.connected_services(), len 1);
+
+        assert_that!(receive_all(&mut sut), eq vec![(format!("{prefix}/gone"), 8)]);
+        sut.update_services().unwrap();
+        assert_that!(sut.connected_services(), len 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}